@@ -245,16 +245,18 @@ impl<T: frame_system::Config> pallet_nfts::WeightInfo for WeightInfo<T> {
 	}
 	/// Storage: `Nfts::CollectionRoleOf` (r:1 w:0)
 	/// Proof: `Nfts::CollectionRoleOf` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Auctions` (r:1 w:0)
+	/// Proof: `Nfts::Auctions` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::ItemConfigOf` (r:1 w:1)
 	/// Proof: `Nfts::ItemConfigOf` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	fn lock_item_transfer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `362`
-		//  Estimated: `3534`
+		//  Estimated: `3648`
 		// Minimum execution time: 19_622_000 picoseconds.
 		Weight::from_parts(20_507_000, 0)
-			.saturating_add(Weight::from_parts(0, 3534))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(Weight::from_parts(0, 3648))
+			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Nfts::CollectionRoleOf` (r:1 w:0)
@@ -772,4 +774,32 @@ impl<T: frame_system::Config> pallet_nfts::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 2954).saturating_mul(n.into()))
 	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn create_auction() -> Weight {
+		<pallet_nfts::weights::SubstrateWeight<T> as pallet_nfts::WeightInfo>::create_auction()
+	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn place_bid() -> Weight {
+		<pallet_nfts::weights::SubstrateWeight<T> as pallet_nfts::WeightInfo>::place_bid()
+	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn cancel_auction() -> Weight {
+		<pallet_nfts::weights::SubstrateWeight<T> as pallet_nfts::WeightInfo>::cancel_auction()
+	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn settle_auction() -> Weight {
+		<pallet_nfts::weights::SubstrateWeight<T> as pallet_nfts::WeightInfo>::settle_auction()
+	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn set_royalty() -> Weight {
+		<pallet_nfts::weights::SubstrateWeight<T> as pallet_nfts::WeightInfo>::set_royalty()
+	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn clear_royalty() -> Weight {
+		<pallet_nfts::weights::SubstrateWeight<T> as pallet_nfts::WeightInfo>::clear_royalty()
+	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn pay_royalties(n: u32, ) -> Weight {
+		<pallet_nfts::weights::SubstrateWeight<T> as pallet_nfts::WeightInfo>::pay_royalties(n)
+	}
 }
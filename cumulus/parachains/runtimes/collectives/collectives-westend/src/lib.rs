@@ -261,6 +261,7 @@ impl pallet_multisig::Config for Runtime {
 	type DepositBase = DepositBase;
 	type DepositFactor = DepositFactor;
 	type MaxSignatories = ConstU32<100>;
+	type OperationExpiry = ();
 	type WeightInfo = weights::pallet_multisig::WeightInfo<Runtime>;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
 }
@@ -389,6 +390,7 @@ impl pallet_proxy::Config for Runtime {
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
+	type SpendingLimits = ();
 }
 
 parameter_types! {
@@ -608,6 +610,8 @@ impl pallet_alliance::Config for Runtime {
 
 parameter_types! {
 	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) * RuntimeBlockWeights::get().max_block;
+	pub const MaxCronTasks: u32 = 50;
+	pub const CronGracePeriod: u64 = 10 * 60;
 }
 
 #[cfg(not(feature = "runtime-benchmarks"))]
@@ -632,6 +636,9 @@ impl pallet_scheduler::Config for Runtime {
 	type OriginPrivilegeCmp = EqualOrGreatestRootCmp;
 	type Preimages = Preimage;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
+	type TimeProvider = Timestamp;
+	type MaxCronTasks = MaxCronTasks;
+	type CronGracePeriod = CronGracePeriod;
 }
 
 parameter_types! {
@@ -169,4 +169,16 @@ impl<T: frame_system::Config> pallet_treasury::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn spend_in_installments(n: u32, ) -> Weight {
+		<pallet_treasury::weights::SubstrateWeight<T> as pallet_treasury::WeightInfo>::spend_in_installments(n)
+	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn approve_milestone() -> Weight {
+		<pallet_treasury::weights::SubstrateWeight<T> as pallet_treasury::WeightInfo>::approve_milestone()
+	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn void_milestone_spend() -> Weight {
+		<pallet_treasury::weights::SubstrateWeight<T> as pallet_treasury::WeightInfo>::void_milestone_spend()
+	}
 }
@@ -177,4 +177,20 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn clear_expired() -> Weight {
+		<pallet_multisig::weights::SubstrateWeight<T> as pallet_multisig::WeightInfo>::clear_expired()
+	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn create_managed(s: u32, ) -> Weight {
+		<pallet_multisig::weights::SubstrateWeight<T> as pallet_multisig::WeightInfo>::create_managed(s)
+	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn rotate_managed(s: u32, ) -> Weight {
+		<pallet_multisig::weights::SubstrateWeight<T> as pallet_multisig::WeightInfo>::rotate_managed(s)
+	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn destroy_managed() -> Weight {
+		<pallet_multisig::weights::SubstrateWeight<T> as pallet_multisig::WeightInfo>::destroy_managed()
+	}
 }
@@ -222,22 +222,17 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
-	/// Storage: `Identity::Registrars` (r:1 w:0)
-	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
-	/// Storage: `Identity::IdentityOf` (r:1 w:1)
-	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(804), added: 3279, mode: `MaxEncodedLen`)
-	/// The range of component `r` is `[1, 19]`.
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
 	fn provide_judgement(r: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `509 + r * (57 ±0)`
-		//  Estimated: `4269`
-		// Minimum execution time: 21_462_000 picoseconds.
-		Weight::from_parts(22_392_004, 0)
-			.saturating_add(Weight::from_parts(0, 4269))
-			// Standard Error: 2_148
-			.saturating_add(Weight::from_parts(114_592, 0).saturating_mul(r.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
+		<pallet_identity::weights::SubstrateWeight<T> as pallet_identity::WeightInfo>::provide_judgement(r)
+	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn provide_judgement_with_evidence(r: u32, ) -> Weight {
+		<pallet_identity::weights::SubstrateWeight<T> as pallet_identity::WeightInfo>::provide_judgement_with_evidence(r)
+	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn refund_expired_judgement(r: u32, ) -> Weight {
+		<pallet_identity::weights::SubstrateWeight<T> as pallet_identity::WeightInfo>::refund_expired_judgement(r)
 	}
 	/// Storage: `Identity::SubsOf` (r:1 w:1)
 	/// Proof: `Identity::SubsOf` (`max_values`: None, `max_size`: Some(3258), added: 5733, mode: `MaxEncodedLen`)
@@ -49,6 +49,9 @@ impl pallet_conviction_voting::Config for Runtime {
 	type Polls = Referenda;
 	type BlockNumberProvider = System;
 	type VotingHooks = ();
+	type PrivateClasses = frame_support::traits::Nothing;
+	type RevealWindow = ();
+	type UnrevealedLockPeriods = ConstU32<32>;
 }
 
 parameter_types! {
@@ -188,6 +188,8 @@ impl<T: frame_system::Config> pallet_recovery::WeightInfo for WeightInfo<T> {
 	/// Proof: `Recovery::Recoverable` (`max_values`: None, `max_size`: Some(351), added: 2826, mode: `MaxEncodedLen`)
 	/// Storage: `Recovery::ActiveRecoveries` (r:1 w:1)
 	/// Proof: `Recovery::ActiveRecoveries` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::Inheritances` (r:1 w:1)
+	/// Proof: `Recovery::Inheritances` (`max_values`: None, `max_size`: Some(361), added: 2836, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 9]`.
 	fn poke_deposit(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -198,7 +200,35 @@ impl<T: frame_system::Config> pallet_recovery::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 3854))
 			// Standard Error: 13_466
 			.saturating_add(Weight::from_parts(135_039, 0).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn create_inheritance(n: u32, ) -> Weight {
+		<pallet_recovery::weights::SubstrateWeight<T> as pallet_recovery::WeightInfo>::create_inheritance(n)
+	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn heartbeat(n: u32, ) -> Weight {
+		<pallet_recovery::weights::SubstrateWeight<T> as pallet_recovery::WeightInfo>::heartbeat(n)
+	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn initiate_inheritance(n: u32, ) -> Weight {
+		<pallet_recovery::weights::SubstrateWeight<T> as pallet_recovery::WeightInfo>::initiate_inheritance(n)
+	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn cancel_inheritance_claim(n: u32, ) -> Weight {
+		<pallet_recovery::weights::SubstrateWeight<T> as pallet_recovery::WeightInfo>::cancel_inheritance_claim(n)
+	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn claim_inheritance(n: u32, ) -> Weight {
+		<pallet_recovery::weights::SubstrateWeight<T> as pallet_recovery::WeightInfo>::claim_inheritance(n)
+	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn remove_inheritance(n: u32, ) -> Weight {
+		<pallet_recovery::weights::SubstrateWeight<T> as pallet_recovery::WeightInfo>::remove_inheritance(n)
+	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn observe_activity(n: u32, ) -> Weight {
+		<pallet_recovery::weights::SubstrateWeight<T> as pallet_recovery::WeightInfo>::observe_activity(n)
 	}
 }
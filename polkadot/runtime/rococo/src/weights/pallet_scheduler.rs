@@ -288,4 +288,24 @@ impl<T: frame_system::Config> pallet_scheduler::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn service_cron_base(s: u32, ) -> Weight {
+		<pallet_scheduler::weights::SubstrateWeight<T> as pallet_scheduler::WeightInfo>::service_cron_base(s)
+	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn service_cron_task() -> Weight {
+		<pallet_scheduler::weights::SubstrateWeight<T> as pallet_scheduler::WeightInfo>::service_cron_task()
+	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn schedule_cron(s: u32, ) -> Weight {
+		<pallet_scheduler::weights::SubstrateWeight<T> as pallet_scheduler::WeightInfo>::schedule_cron(s)
+	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn set_cron_schedule(s: u32, ) -> Weight {
+		<pallet_scheduler::weights::SubstrateWeight<T> as pallet_scheduler::WeightInfo>::set_cron_schedule(s)
+	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn cancel_cron(s: u32, ) -> Weight {
+		<pallet_scheduler::weights::SubstrateWeight<T> as pallet_scheduler::WeightInfo>::cancel_cron(s)
+	}
 }
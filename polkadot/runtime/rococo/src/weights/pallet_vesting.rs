@@ -51,212 +51,52 @@ use core::marker::PhantomData;
 /// Weight functions for `pallet_vesting`.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
-	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
-	/// The range of component `l` is `[0, 49]`.
-	/// The range of component `s` is `[1, 28]`.
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
 	fn vest_locked(l: u32, s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `277 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 34_644_000 picoseconds.
-		Weight::from_parts(34_294_075, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 2_502
-			.saturating_add(Weight::from_parts(33_997, 0).saturating_mul(l.into()))
-			// Standard Error: 4_451
-			.saturating_add(Weight::from_parts(82_158, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(2))
+		<pallet_vesting::weights::SubstrateWeight<T> as pallet_vesting::WeightInfo>::vest_locked(l, s)
 	}
-	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
-	/// The range of component `l` is `[0, 49]`.
-	/// The range of component `s` is `[1, 28]`.
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
 	fn vest_unlocked(l: u32, s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `277 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 37_051_000 picoseconds.
-		Weight::from_parts(37_406_778, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 2_143
-			.saturating_add(Weight::from_parts(25_479, 0).saturating_mul(l.into()))
-			// Standard Error: 3_813
-			.saturating_add(Weight::from_parts(59_478, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(2))
+		<pallet_vesting::weights::SubstrateWeight<T> as pallet_vesting::WeightInfo>::vest_unlocked(l, s)
 	}
-	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
-	/// Storage: `System::Account` (r:1 w:1)
-	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// The range of component `l` is `[0, 49]`.
-	/// The range of component `s` is `[1, 28]`.
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
 	fn vest_other_locked(l: u32, s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `380 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 36_942_000 picoseconds.
-		Weight::from_parts(36_397_510, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 2_194
-			.saturating_add(Weight::from_parts(43_154, 0).saturating_mul(l.into()))
-			// Standard Error: 3_904
-			.saturating_add(Weight::from_parts(78_209, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<pallet_vesting::weights::SubstrateWeight<T> as pallet_vesting::WeightInfo>::vest_other_locked(l, s)
 	}
-	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
-	/// Storage: `System::Account` (r:1 w:1)
-	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// The range of component `l` is `[0, 49]`.
-	/// The range of component `s` is `[1, 28]`.
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
 	fn vest_other_unlocked(l: u32, s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `380 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 39_129_000 picoseconds.
-		Weight::from_parts(39_172_044, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 2_803
-			.saturating_add(Weight::from_parts(33_251, 0).saturating_mul(l.into()))
-			// Standard Error: 4_988
-			.saturating_add(Weight::from_parts(83_808, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<pallet_vesting::weights::SubstrateWeight<T> as pallet_vesting::WeightInfo>::vest_other_unlocked(l, s)
 	}
-	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
-	/// Storage: `System::Account` (r:1 w:1)
-	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
-	/// The range of component `l` is `[0, 49]`.
-	/// The range of component `s` is `[0, 27]`.
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
 	fn vested_transfer(l: u32, s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `451 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 75_395_000 picoseconds.
-		Weight::from_parts(77_352_873, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 4_000
-			.saturating_add(Weight::from_parts(33_084, 0).saturating_mul(l.into()))
-			// Standard Error: 7_117
-			.saturating_add(Weight::from_parts(78_022, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<pallet_vesting::weights::SubstrateWeight<T> as pallet_vesting::WeightInfo>::vested_transfer(l, s)
 	}
-	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
-	/// Storage: `System::Account` (r:2 w:2)
-	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
-	/// The range of component `l` is `[0, 49]`.
-	/// The range of component `s` is `[0, 27]`.
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
 	fn force_vested_transfer(l: u32, s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `554 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `6196`
-		// Minimum execution time: 76_648_000 picoseconds.
-		Weight::from_parts(77_970_331, 0)
-			.saturating_add(Weight::from_parts(0, 6196))
-			// Standard Error: 3_437
-			.saturating_add(Weight::from_parts(45_910, 0).saturating_mul(l.into()))
-			// Standard Error: 6_116
-			.saturating_add(Weight::from_parts(105_157, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(4))
+		<pallet_vesting::weights::SubstrateWeight<T> as pallet_vesting::WeightInfo>::force_vested_transfer(l, s)
 	}
-	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
-	/// The range of component `l` is `[0, 49]`.
-	/// The range of component `s` is `[2, 28]`.
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
 	fn not_unlocking_merge_schedules(l: u32, s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `277 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 34_725_000 picoseconds.
-		Weight::from_parts(35_867_784, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 2_233
-			.saturating_add(Weight::from_parts(30_025, 0).saturating_mul(l.into()))
-			// Standard Error: 4_125
-			.saturating_add(Weight::from_parts(56_964, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(2))
+		<pallet_vesting::weights::SubstrateWeight<T> as pallet_vesting::WeightInfo>::not_unlocking_merge_schedules(l, s)
 	}
-	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
-	/// The range of component `l` is `[0, 49]`.
-	/// The range of component `s` is `[2, 28]`.
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
 	fn unlocking_merge_schedules(l: u32, s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `277 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 37_897_000 picoseconds.
-		Weight::from_parts(37_679_187, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 2_178
-			.saturating_add(Weight::from_parts(37_935, 0).saturating_mul(l.into()))
-			// Standard Error: 4_022
-			.saturating_add(Weight::from_parts(89_640, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(2))
+		<pallet_vesting::weights::SubstrateWeight<T> as pallet_vesting::WeightInfo>::unlocking_merge_schedules(l, s)
 	}
-	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
-	/// Storage: `System::Account` (r:1 w:1)
-	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// The range of component `l` is `[0, 49]`.
-	/// The range of component `s` is `[2, 28]`.
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
 	fn force_remove_vesting_schedule(l: u32, s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `451 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 42_163_000 picoseconds.
-		Weight::from_parts(42_827_949, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 2_205
-			.saturating_add(Weight::from_parts(28_809, 0).saturating_mul(l.into()))
-			// Standard Error: 4_073
-			.saturating_add(Weight::from_parts(67_463, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<pallet_vesting::weights::SubstrateWeight<T> as pallet_vesting::WeightInfo>::force_remove_vesting_schedule(l, s)
+	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn grant_vesting(l: u32, s: u32, ) -> Weight {
+		<pallet_vesting::weights::SubstrateWeight<T> as pallet_vesting::WeightInfo>::grant_vesting(l, s)
+	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn revoke_vesting(l: u32, s: u32, ) -> Weight {
+		<pallet_vesting::weights::SubstrateWeight<T> as pallet_vesting::WeightInfo>::revoke_vesting(l, s)
+	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn claw_back(l: u32, s: u32, ) -> Weight {
+		<pallet_vesting::weights::SubstrateWeight<T> as pallet_vesting::WeightInfo>::claw_back(l, s)
 	}
 }
@@ -535,6 +535,8 @@ impl pallet_vesting::Config for Runtime {
 	type WeightInfo = ();
 	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
 	type BlockNumberProvider = System;
+	type RevokeOrigin = frame_system::EnsureRoot<AccountId>;
+	type MaxCurvePoints = ConstU32<12>;
 	const MAX_VESTING_SCHEDULES: u32 = 28;
 }
 
@@ -201,4 +201,16 @@ impl<T: frame_system::Config> pallet_conviction_voting::WeightInfo for WeightInf
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn commit_vote() -> Weight {
+		<pallet_conviction_voting::weights::SubstrateWeight<T> as pallet_conviction_voting::WeightInfo>::commit_vote()
+	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn reveal_vote() -> Weight {
+		<pallet_conviction_voting::weights::SubstrateWeight<T> as pallet_conviction_voting::WeightInfo>::reveal_vote()
+	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn remove_commitment() -> Weight {
+		<pallet_conviction_voting::weights::SubstrateWeight<T> as pallet_conviction_voting::WeightInfo>::remove_commitment()
+	}
 }
@@ -100,6 +100,12 @@ pub enum Subcommand {
 	/// Import blocks.
 	ImportBlocks(sc_cli::ImportBlocksCmd),
 
+	/// Export the finalized state into a snapshot file.
+	ExportStateSnapshot(sc_cli::ExportStateSnapshotCmd),
+
+	/// Bootstrap an empty database from a state snapshot file.
+	ImportStateSnapshot(sc_cli::ImportStateSnapshotCmd),
+
 	/// Remove the whole chain.
 	PurgeChain(sc_cli::PurgeChainCmd),
 
@@ -543,6 +543,7 @@ pub fn new_full_base<N: NetworkBackend<Block, <Block as BlockT>::Hash>>(
 			Some(keystore_container.keystore()),
 			mixnet_notification_service
 				.expect("`NotificationService` exists since mixnet was enabled; qed"),
+			Box::new(task_manager.spawn_handle()),
 		);
 		task_manager.spawn_handle().spawn("mixnet", None, mixnet);
 	}
@@ -387,6 +387,7 @@ parameter_types! {
 	pub const DepositBase: Balance = deposit(1, 88);
 	// Additional storage item size of 32 bytes.
 	pub const DepositFactor: Balance = deposit(0, 32);
+	pub const MultisigOperationExpiry: Option<BlockNumber> = Some(28 * DAYS);
 }
 
 impl pallet_multisig::Config for Runtime {
@@ -396,6 +397,7 @@ impl pallet_multisig::Config for Runtime {
 	type DepositBase = DepositBase;
 	type DepositFactor = DepositFactor;
 	type MaxSignatories = ConstU32<100>;
+	type OperationExpiry = MultisigOperationExpiry;
 	type WeightInfo = pallet_multisig::weights::SubstrateWeight<Runtime>;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
 }
@@ -429,6 +431,11 @@ pub enum ProxyType {
 	NonTransfer,
 	Governance,
 	Staking,
+	/// Transfers of the native token and at most one asset, limited per day.
+	Allowance {
+		native: Balance,
+		asset: Option<(u32, Balance)>,
+	},
 }
 impl Default for ProxyType {
 	fn default() -> Self {
@@ -460,6 +467,16 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 			ProxyType::Staking => {
 				matches!(c, RuntimeCall::Staking(..) | RuntimeCall::FastUnstake(..))
 			},
+			ProxyType::Allowance { .. } => matches!(
+				c,
+				RuntimeCall::Balances(
+					pallet_balances::Call::transfer_allow_death { .. } |
+						pallet_balances::Call::transfer_keep_alive { .. }
+				) | RuntimeCall::Assets(
+					pallet_assets::Call::transfer { .. } |
+						pallet_assets::Call::transfer_keep_alive { .. }
+				) | RuntimeCall::Utility(..)
+			),
 		}
 	}
 	fn is_superset(&self, o: &Self) -> bool {
@@ -467,12 +484,59 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 			(x, y) if x == y => true,
 			(ProxyType::Any, _) => true,
 			(_, ProxyType::Any) => false,
+			(ProxyType::NonTransfer, ProxyType::Allowance { .. }) => false,
 			(ProxyType::NonTransfer, _) => true,
 			_ => false,
 		}
 	}
 }
 
+/// Daily limits on the transfers of [`ProxyType::Allowance`] proxies.
+pub struct ProxySpendingLimits;
+impl pallet_proxy::SpendingLimits<ProxyType, RuntimeCall, BlockNumber> for ProxySpendingLimits {
+	type AssetKind = NativeOrWithId<u32>;
+	type Balance = Balance;
+	// the native currency and the allowed asset.
+	type MaxAssets = ConstU32<2>;
+
+	fn limit(
+		proxy_type: &ProxyType,
+		asset_kind: &NativeOrWithId<u32>,
+	) -> Option<pallet_proxy::SpendingLimit<Balance, BlockNumber>> {
+		let ProxyType::Allowance { native, asset } = proxy_type else { return None };
+		let amount = match asset_kind {
+			NativeOrWithId::Native => *native,
+			// assets other than the allowed one cannot be spent at all.
+			NativeOrWithId::WithId(id) =>
+				asset.filter(|(allowed, _)| allowed == id).map_or(0, |(_, amount)| amount),
+		};
+		Some(pallet_proxy::SpendingLimit { amount, period: DAYS })
+	}
+
+	fn outflow(call: &RuntimeCall) -> Option<(NativeOrWithId<u32>, Balance)> {
+		match call {
+			RuntimeCall::Balances(
+				pallet_balances::Call::transfer_allow_death { value, .. } |
+				pallet_balances::Call::transfer_keep_alive { value, .. },
+			) => Some((NativeOrWithId::Native, *value)),
+			RuntimeCall::Assets(
+				pallet_assets::Call::transfer { id, amount, .. } |
+				pallet_assets::Call::transfer_keep_alive { id, amount, .. },
+			) => Some((NativeOrWithId::WithId(id.0), *amount)),
+			_ => None,
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn benchmark_outflow() -> Option<(ProxyType, RuntimeCall)> {
+		let call = pallet_balances::Call::transfer_keep_alive {
+			dest: sp_runtime::MultiAddress::Id(AccountId::new([0; 32])),
+			value: 1,
+		};
+		Some((ProxyType::Allowance { native: Balance::MAX, asset: None }, call.into()))
+	}
+}
+
 impl pallet_proxy::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -487,6 +551,7 @@ impl pallet_proxy::Config for Runtime {
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
+	type SpendingLimits = ProxySpendingLimits;
 }
 
 parameter_types! {
@@ -509,6 +574,9 @@ impl pallet_scheduler::Config for Runtime {
 	type OriginPrivilegeCmp = EqualPrivilegeOnly;
 	type Preimages = Preimage;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
+	type TimeProvider = Timestamp;
+	type MaxCronTasks = ConstU32<128>;
+	type CronGracePeriod = ConstU64<{ 10 * 60 }>;
 }
 
 impl pallet_glutton::Config for Runtime {
@@ -632,6 +700,11 @@ impl pallet_assets_freezer::Config<AssetsFreezerInstance> for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 }
 
+impl pallet_assets_holder::Config<Instance1> for Runtime {
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type RuntimeEvent = RuntimeEvent;
+}
+
 impl pallet_asset_conversion_tx_payment::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type AssetId = NativeOrWithId<u32>;
@@ -1017,6 +1090,9 @@ impl pallet_conviction_voting::Config for Runtime {
 	type Polls = Referenda;
 	type BlockNumberProvider = System;
 	type VotingHooks = ();
+	type PrivateClasses = Nothing;
+	type RevealWindow = ();
+	type UnrevealedLockPeriods = ConstU32<32>;
 }
 
 parameter_types! {
@@ -1325,6 +1401,7 @@ impl pallet_treasury::Config for Runtime {
 	type Paymaster = PayAssetFromAccount<NativeAndAssets, TreasuryAccount>;
 	type BalanceConverter = AssetRate;
 	type PayoutPeriod = SpendPayoutPeriod;
+	type MaxInstallments = ConstU32<24>;
 	type BlockNumberProvider = System;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = PalletTreasuryArguments;
@@ -1683,6 +1760,7 @@ impl pallet_identity::Config for Runtime {
 	type UsernameGracePeriod = ConstU32<{ 30 * DAYS }>;
 	type MaxSuffixLength = ConstU32<7>;
 	type MaxUsernameLength = ConstU32<32>;
+	type JudgementPeriod = ConstU32<{ 7 * DAYS }>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 	type WeightInfo = pallet_identity::weights::SubstrateWeight<Runtime>;
@@ -1752,6 +1830,8 @@ impl pallet_vesting::Config for Runtime {
 	type WeightInfo = pallet_vesting::weights::SubstrateWeight<Runtime>;
 	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
 	type BlockNumberProvider = System;
+	type RevokeOrigin = EnsureRoot<AccountId>;
+	type MaxCurvePoints = ConstU32<12>;
 	// `VestingInfo` encode length is 36bytes. 28 schedules gets encoded as 1009 bytes, which is the
 	// highest number of schedules that encodes less than 2^10.
 	const MAX_VESTING_SCHEDULES: u32 = 28;
@@ -1821,7 +1901,7 @@ impl pallet_assets::Config<Instance1> for Runtime {
 	type MetadataDepositPerByte = MetadataDepositPerByte;
 	type ApprovalDeposit = ApprovalDeposit;
 	type StringLimit = StringLimit;
-	type Holder = ();
+	type Holder = AssetsHolder;
 	type Freezer = ();
 	type Extra = ();
 	type CallbackHandle = ();
@@ -1900,6 +1980,8 @@ impl pallet_asset_conversion::Config for Runtime {
 	type LiquidityWithdrawalFee = LiquidityWithdrawalFee;
 	type WeightInfo = pallet_asset_conversion::weights::SubstrateWeight<Runtime>;
 	type MaxSwapPathLength = ConstU32<4>;
+	type MaxPriceObservations = ConstU32<64>;
+	type PriceObservationPeriod = ConstU32<10>;
 	type MintMinLiquidity = MintMinLiquidity;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
@@ -1952,6 +2034,52 @@ impl pallet_asset_rewards::Config for Runtime {
 	type BenchmarkHelper = AssetRewardsBenchmarkHelper;
 }
 
+pub type NativeAndAssetsHolder =
+	UnionOf<Balances, AssetsHolder, NativeFromLeft, NativeOrWithId<u32>, AccountId>;
+
+/// Benchmark Helper
+#[cfg(feature = "runtime-benchmarks")]
+pub struct PaymentStreamsBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_payment_streams::benchmarking::BenchmarkHelper<NativeOrWithId<u32>, AccountId, Balance>
+	for PaymentStreamsBenchmarkHelper
+{
+	fn asset_kind() -> NativeOrWithId<u32> {
+		NativeOrWithId::Native
+	}
+	fn fund(_asset_kind: &NativeOrWithId<u32>, who: &AccountId, amount: Balance) {
+		use frame_support::traits::fungible::Mutate;
+		let _ = Balances::mint_into(who, amount);
+	}
+}
+
+parameter_types! {
+	pub const StreamCreationHoldReason: RuntimeHoldReason =
+		RuntimeHoldReason::PaymentStreams(pallet_payment_streams::HoldReason::StreamCreation);
+	// 1 item, 142 bytes into the storage on stream creation.
+	pub const StreamCreationDeposit: Balance = deposit(1, 142);
+}
+
+impl pallet_payment_streams::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetKind = NativeOrWithId<u32>;
+	type Balance = Balance;
+	type Assets = NativeAndAssetsHolder;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Clock = System;
+	type MomentToBalance = ConvertInto;
+	type Consideration = HoldConsideration<
+		AccountId,
+		Balances,
+		StreamCreationHoldReason,
+		ConstantStoragePrice<StreamCreationDeposit, Balance>,
+	>;
+	type WeightInfo = pallet_payment_streams::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = PaymentStreamsBenchmarkHelper;
+}
+
 impl pallet_asset_conversion_ops::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type PriorAccountIdConverter = pallet_asset_conversion::AccountIdConverterNoSeed<(
@@ -2028,7 +2156,9 @@ parameter_types! {
 	pub const ApprovalsLimit: u32 = 20;
 	pub const ItemAttributesApprovalsLimit: u32 = 20;
 	pub const MaxTips: u32 = 10;
+	pub const MaxRoyaltyRecipients: u32 = 10;
 	pub const MaxDeadlineDuration: BlockNumber = 12 * 30 * DAYS;
+	pub const AuctionExtensionPeriod: BlockNumber = 10 * MINUTES;
 }
 
 impl pallet_uniques::Config for Runtime {
@@ -2125,6 +2255,8 @@ impl pallet_nfts::Config for Runtime {
 	type CollectionId = u32;
 	type ItemId = u32;
 	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type NativeBalance = Balances;
 	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
 	type CollectionDeposit = CollectionDeposit;
 	type ItemDeposit = ItemDeposit;
@@ -2137,7 +2269,9 @@ impl pallet_nfts::Config for Runtime {
 	type ApprovalsLimit = ApprovalsLimit;
 	type ItemAttributesApprovalsLimit = ItemAttributesApprovalsLimit;
 	type MaxTips = MaxTips;
+	type MaxRoyaltyRecipients = MaxRoyaltyRecipients;
 	type MaxDeadlineDuration = MaxDeadlineDuration;
+	type AuctionExtensionPeriod = AuctionExtensionPeriod;
 	type MaxAttributesPerCall = MaxAttributesPerCall;
 	type Features = Features;
 	type OffchainSignature = Signature;
@@ -2292,6 +2426,7 @@ impl pallet_statement::Config for Runtime {
 	type MaxAllowedStatements = MaxAllowedStatements;
 	type MinAllowedBytes = MinAllowedBytes;
 	type MaxAllowedBytes = MaxAllowedBytes;
+	type TopicLimits = ();
 }
 
 parameter_types! {
@@ -2517,6 +2652,38 @@ impl pallet_meta_tx::Config for Runtime {
 	type Extension = pallet_meta_tx::WeightlessExtension<Runtime>;
 }
 
+/// Benchmark Helper
+#[cfg(feature = "runtime-benchmarks")]
+pub struct MetaTxSponsorBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_meta_tx_sponsor::benchmarking::BenchmarkHelper<u32, AccountId, Balance>
+	for MetaTxSponsorBenchmarkHelper
+{
+	fn create_asset(who: &AccountId, amount: Balance) -> u32 {
+		use frame_support::traits::fungibles::{Create, Inspect, Mutate};
+		let asset = 1;
+		if !<Assets as Inspect<AccountId>>::asset_exists(asset) {
+			let _ = <Assets as Create<AccountId>>::create(asset, who.clone(), true, 1);
+		}
+		let _ = <Assets as Mutate<AccountId>>::mint_into(asset, who, amount);
+		asset
+	}
+}
+
+impl pallet_meta_tx_sponsor::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Assets = Assets;
+	type CapPeriod = ConstU32<DAYS>;
+	type MaxSponsoredCalls = ConstU32<16>;
+	type MaxNameLen = ConstU32<64>;
+	type WeightInfo = pallet_meta_tx_sponsor::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = MetaTxSponsorBenchmarkHelper;
+}
+
 #[frame_support::runtime]
 mod runtime {
 	use super::*;
@@ -2792,8 +2959,17 @@ mod runtime {
 	#[runtime::pallet_index(84)]
 	pub type AssetsFreezer = pallet_assets_freezer::Pallet<Runtime, Instance1>;
 
+	#[runtime::pallet_index(85)]
+	pub type AssetsHolder = pallet_assets_holder::Pallet<Runtime, Instance1>;
+
+	#[runtime::pallet_index(86)]
+	pub type PaymentStreams = pallet_payment_streams::Pallet<Runtime>;
+
 	#[runtime::pallet_index(89)]
 	pub type MetaTx = pallet_meta_tx::Pallet<Runtime>;
+
+	#[runtime::pallet_index(90)]
+	pub type MetaTxSponsor = pallet_meta_tx_sponsor::Pallet<Runtime>;
 }
 
 /// The address format for describing accounts.
@@ -2884,6 +3060,8 @@ type Migrations = (
 	pallet_alliance::migration::Migration<Runtime>,
 	pallet_contracts::Migration<Runtime>,
 	pallet_identity::migration::versioned::V0ToV1<Runtime, IDENTITY_MIGRATION_KEY_LIMIT>,
+	pallet_identity::migration::versioned::V2ToV3<Runtime>,
+	pallet_multisig::migrations::v2::MigrateToV2<Runtime>,
 );
 
 type EventRecord = frame_system::EventRecord<
@@ -2997,6 +3175,7 @@ mod benches {
 		[pallet_democracy, Democracy]
 		[pallet_asset_conversion, AssetConversion]
 		[pallet_asset_rewards, AssetRewards]
+		[pallet_payment_streams, PaymentStreams]
 		[pallet_asset_conversion_tx_payment, AssetConversionTxPayment]
 		[pallet_transaction_payment, TransactionPayment]
 		[pallet_election_provider_multi_phase, ElectionProviderMultiPhase]
@@ -3050,6 +3229,7 @@ mod benches {
 		[pallet_asset_conversion_ops, AssetConversionMigration]
 		[pallet_verify_signature, VerifySignature]
 		[pallet_meta_tx, MetaTx]
+		[pallet_meta_tx_sponsor, MetaTxSponsor]
 	);
 }
 
@@ -3409,6 +3589,15 @@ pallet_revive::impl_runtime_apis_plus_revive!(
 		fn get_reserves(asset1: NativeOrWithId<u32>, asset2: NativeOrWithId<u32>) -> Option<(Balance, Balance)> {
 			AssetConversion::get_reserves(asset1, asset2).ok()
 		}
+
+		fn quote_price_twap(
+			asset1: NativeOrWithId<u32>,
+			asset2: NativeOrWithId<u32>,
+			amount: Balance,
+			window: u32,
+		) -> Option<Balance> {
+			AssetConversion::quote_price_twap(&asset1, &asset2, amount, window)
+		}
 	}
 
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentCallApi<Block, Balance, RuntimeCall>
@@ -3470,6 +3659,12 @@ pallet_revive::impl_runtime_apis_plus_revive!(
 		fn collection_attribute(collection: u32, key: Vec<u8>) -> Option<Vec<u8>> {
 			<Nfts as Inspect<AccountId>>::collection_attribute(&collection, &key)
 		}
+
+		fn royalty(collection: u32, item: u32) -> Option<Vec<(AccountId, u16)>> {
+			Nfts::royalty(collection, item).map(|recipients| {
+				recipients.into_iter().map(|r| (r.account, r.basis_points)).collect()
+			})
+		}
 	}
 
 	#[api_version(5)]
@@ -3630,6 +3825,20 @@ pallet_revive::impl_runtime_apis_plus_revive!(
 		}
 	}
 
+	impl pallet_payment_streams::PaymentStreamsApi<Block, AccountId, NativeOrWithId<u32>, Balance>
+		for Runtime
+	{
+		fn claimable(stream_id: pallet_payment_streams::StreamId) -> Option<Balance> {
+			PaymentStreams::claimable(stream_id)
+		}
+
+		fn claimable_by(
+			recipient: AccountId,
+		) -> Vec<(pallet_payment_streams::StreamId, NativeOrWithId<u32>, Balance)> {
+			PaymentStreams::claimable_by(recipient)
+		}
+	}
+
 	#[cfg(feature = "try-runtime")]
 	impl frame_try_runtime::TryRuntime<Block> for Runtime {
 		fn on_runtime_upgrade(checks: frame_try_runtime::UpgradeCheckSelect) -> (Weight, Weight) {
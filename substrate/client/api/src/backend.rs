@@ -18,7 +18,7 @@
 
 //! Substrate Client data backend
 
-use std::collections::HashSet;
+use std::{collections::HashSet, sync::Arc};
 
 use parking_lot::RwLock;
 
@@ -521,6 +521,38 @@ impl From<CallContext> for TrieCacheContext {
 	}
 }
 
+/// Secondary index of the storage keys modified by every imported block.
+///
+/// Backends may maintain this index to answer historical state queries (storage diffs between a
+/// block and its parent, or the history of a single key) without walking the state tries.
+pub trait KeyChangesIndex<Block: BlockT>: Send + Sync {
+	/// Returns the sorted list of keys modified by the block with the given `hash`.
+	///
+	/// Keys of the main trie are returned when `child_info` is `None`, otherwise the keys of the
+	/// given child trie. Returns `None` if the block was not indexed.
+	fn changed_keys(
+		&self,
+		hash: Block::Hash,
+		child_info: Option<&ChildInfo>,
+	) -> sp_blockchain::Result<Option<Vec<StorageKey>>>;
+
+	/// Returns the number of the last finalized block covered by [`Self::key_changes`].
+	fn last_finalized(&self) -> sp_blockchain::Result<Option<NumberFor<Block>>>;
+
+	/// Returns the finalized blocks in the inclusive range `first..=last` that modified `key`,
+	/// ordered by block number.
+	///
+	/// Returns `None` if part of the range is not finalized yet or was finalized before the index
+	/// was enabled. Changes of non-finalized blocks can be found with [`Self::changed_keys`].
+	fn key_changes(
+		&self,
+		key: &StorageKey,
+		child_info: Option<&ChildInfo>,
+		first: NumberFor<Block>,
+		last: NumberFor<Block>,
+	) -> sp_blockchain::Result<Option<Vec<(NumberFor<Block>, Block::Hash)>>>;
+}
+
 /// Client backend.
 ///
 /// Manages the data layer.
@@ -603,6 +635,11 @@ pub trait Backend<Block: BlockT>: AuxStore + Send + Sync {
 	/// Returns a handle to offchain storage.
 	fn offchain_storage(&self) -> Option<Self::OffchainStorage>;
 
+	/// Returns a handle to the key changes index, if the backend maintains one.
+	fn key_changes_index(&self) -> Option<Arc<dyn KeyChangesIndex<Block>>> {
+		None
+	}
+
 	/// Pin the block to keep body, justification and state available after pruning.
 	/// Number of pins are reference counted. Users need to make sure to perform
 	/// one call to [`Self::unpin_block`] per call to [`Self::pin_block`].
@@ -78,6 +78,7 @@ impl ChainInfoCmd {
 			source: config.database.clone(),
 			blocks_pruning: config.blocks_pruning,
 			metrics_registry: None,
+			key_changes_index: config.key_changes_index,
 		};
 		let backend = sc_service::new_db_backend::<B>(db_config)?;
 		let info: ChainInfo<B> = backend.blockchain().info().into();
@@ -29,6 +29,14 @@ pub struct DatabaseParams {
 	/// Limit the memory the database cache can use.
 	#[arg(long = "db-cache", value_name = "MiB")]
 	pub database_cache_size: Option<usize>,
+
+	/// Maintain an index of the storage keys modified by every imported block.
+	///
+	/// The index is used to answer historical state queries, like `archive_v1_storageDiff` and
+	/// `archive_v1_keyHistory`, without walking the state tries. Only blocks imported while the
+	/// index is enabled are covered by it.
+	#[arg(long)]
+	pub key_changes_index: bool,
 }
 
 impl DatabaseParams {
@@ -41,4 +49,9 @@ impl DatabaseParams {
 	pub fn database_cache_size(&self) -> Option<usize> {
 		self.database_cache_size
 	}
+
+	/// Whether the key changes index should be maintained.
+	pub fn key_changes_index(&self) -> bool {
+		self.key_changes_index
+	}
 }
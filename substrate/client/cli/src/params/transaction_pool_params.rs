@@ -17,7 +17,8 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use clap::{Args, ValueEnum};
-use sc_transaction_pool::TransactionPoolOptions;
+use sc_transaction_pool::{PoolLimit, PoolPriorityLane, PoolSenderLimit, TransactionPoolOptions};
+use std::path::PathBuf;
 
 /// Type of transaction pool to be used
 #[derive(Debug, Clone, Copy, ValueEnum)]
@@ -59,6 +60,53 @@ pub struct TransactionPoolParams {
 	/// The type of transaction pool to be instantiated.
 	#[arg(long, value_enum, default_value_t = TransactionPoolType::SingleState)]
 	pub pool_type: TransactionPoolType,
+
+	/// Path of the transaction pool journal.
+	///
+	/// When set, the content of the pool is persisted to this file and restored (after being
+	/// revalidated) on the next start of the node. Only supported by the fork-aware transaction
+	/// pool.
+	#[arg(long, value_name = "PATH")]
+	pub pool_journal_path: Option<PathBuf>,
+
+	/// Maximum number of transactions of a single sender in each of the ready and future queues.
+	///
+	/// Only external transactions are limited. Disabled by default.
+	#[arg(long, value_name = "COUNT")]
+	pub pool_sender_limit: Option<usize>,
+
+	/// Maximum number of kilobytes of transactions of a single sender in each of the ready and
+	/// future queues.
+	///
+	/// Only external transactions are limited. Disabled by default.
+	#[arg(long, value_name = "COUNT")]
+	pub pool_sender_kbytes: Option<usize>,
+
+	/// Number of leading bytes of the first tag provided by a transaction that identify its
+	/// sender.
+	///
+	/// The default matches the `(AccountId, Nonce)` tag of FRAME-based runtimes using 32-byte
+	/// account ids.
+	#[arg(long, value_name = "BYTES", default_value_t = 32)]
+	pub pool_sender_tag_prefix: usize,
+
+	/// Number of transactions in the ready queue reserved for local and operational transactions.
+	///
+	/// A tenth of it is reserved in the future queue. Disabled by default.
+	#[arg(long, value_name = "COUNT")]
+	pub pool_reserved_limit: Option<usize>,
+
+	/// Number of kilobytes in the ready queue reserved for local and operational transactions.
+	///
+	/// A tenth of it is reserved in the future queue. Disabled by default.
+	#[arg(long, value_name = "COUNT")]
+	pub pool_reserved_kbytes: Option<usize>,
+
+	/// Minimal priority of an external transaction to use the reserved capacity of the pool.
+	///
+	/// If not set, only local transactions use the reserved capacity.
+	#[arg(long, value_name = "PRIORITY")]
+	pub pool_reserved_min_priority: Option<u64>,
 }
 
 impl TransactionPoolParams {
@@ -71,5 +119,41 @@ impl TransactionPoolParams {
 			self.pool_type.into(),
 			is_dev,
 		)
+		.with_journal_path(self.pool_journal_path.clone())
+		.with_sender_limit(self.sender_limit())
+		.with_priority_lane(self.priority_lane())
+	}
+
+	/// Returns the per-sender limits, if enabled.
+	fn sender_limit(&self) -> Option<PoolSenderLimit> {
+		if self.pool_sender_limit.is_none() && self.pool_sender_kbytes.is_none() {
+			return None
+		}
+
+		let limit = PoolLimit {
+			count: self.pool_sender_limit.unwrap_or(self.pool_limit),
+			total_bytes: self.pool_sender_kbytes.unwrap_or(self.pool_kbytes) * 1024,
+		};
+		Some(PoolSenderLimit {
+			tag_prefix_len: self.pool_sender_tag_prefix,
+			ready: limit.clone(),
+			future: limit,
+		})
+	}
+
+	/// Returns the capacity reserved for the priority lane, if enabled.
+	fn priority_lane(&self) -> Option<PoolPriorityLane> {
+		if self.pool_reserved_limit.is_none() && self.pool_reserved_kbytes.is_none() {
+			return None
+		}
+
+		let count = self.pool_reserved_limit.unwrap_or_default();
+		let total_bytes = self.pool_reserved_kbytes.unwrap_or_default() * 1024;
+		let factor = 10;
+		Some(PoolPriorityLane {
+			min_priority: self.pool_reserved_min_priority,
+			ready: PoolLimit { count, total_bytes },
+			future: PoolLimit { count: count / factor, total_bytes: total_bytes / factor },
+		})
 	}
 }
@@ -35,6 +35,9 @@ mod codes {
 	pub const CONSENSUS_ERROR: i32 = 14_000;
 	pub const INHERENTS_ERROR: i32 = 15_000;
 	pub const BLOCKCHAIN_ERROR: i32 = 16_000;
+	pub const SNAPSHOT_NOT_FOUND: i32 = 17_000;
+	pub const REVERT_FAILED: i32 = 18_000;
+	pub const TOO_MANY_BLOCKS: i32 = 19_000;
 	pub const UNKNOWN_ERROR: i32 = 20_000;
 }
 
@@ -61,6 +64,15 @@ pub enum Error {
 	/// Supplied parent_hash doesn't exist in chain
 	#[error("Supplied parent_hash: {0} doesn't exist in chain")]
 	BlockNotFound(String),
+	/// No snapshot was taken at the supplied block hash
+	#[error("No snapshot was taken at block: {0}")]
+	SnapshotNotFound(String),
+	/// The chain couldn't be reverted to the requested block
+	#[error("Revert failed: {0}")]
+	RevertFailed(String),
+	/// More blocks than [`crate::rpc::MAX_MINED_BLOCKS`] were requested at once
+	#[error("Cannot mine {0} blocks at once, the maximum is {max}", max = crate::rpc::MAX_MINED_BLOCKS)]
+	TooManyBlocks(u32),
 	/// Some string error
 	#[error("{0}")]
 	StringError(String),
@@ -97,6 +109,9 @@ impl Error {
 			ConsensusError(_) => codes::CONSENSUS_ERROR,
 			InherentError(_) => codes::INHERENTS_ERROR,
 			BlockchainError(_) => codes::BLOCKCHAIN_ERROR,
+			SnapshotNotFound(_) => codes::SNAPSHOT_NOT_FOUND,
+			RevertFailed(_) => codes::REVERT_FAILED,
+			TooManyBlocks(_) => codes::TOO_MANY_BLOCKS,
 			SendError(_) | Canceled(_) => codes::SERVER_SHUTTING_DOWN,
 			_ => codes::UNKNOWN_ERROR,
 		}
@@ -23,23 +23,30 @@ use futures::prelude::*;
 use futures_timer::Delay;
 use prometheus_endpoint::Registry;
 use sc_client_api::{
-	backend::{Backend as ClientBackend, Finalizer},
+	backend::{
+		Backend as ClientBackend, Finalizer, ImportNotificationAction, ImportSummary, LockImportRun,
+	},
 	client::BlockchainEvents,
+	BlockBackend,
 };
 use sc_consensus::{
 	block_import::{BlockImport, BlockImportParams, ForkChoiceStrategy},
 	import_queue::{BasicQueue, BoxBlockImport, Verifier},
 };
 use sp_blockchain::HeaderBackend;
-use sp_consensus::{Environment, Proposer, SelectChain};
+use sp_consensus::{BlockOrigin, Environment, Proposer, SelectChain};
 use sp_core::traits::SpawnNamed;
 use sp_inherents::CreateInherentDataProviders;
-use sp_runtime::{traits::Block as BlockT, ConsensusEngineId};
+use sp_runtime::{
+	traits::{Block as BlockT, Header as HeaderT},
+	ConsensusEngineId,
+};
 use std::{marker::PhantomData, sync::Arc, time::Duration};
 
 mod error;
 mod finalize_block;
 mod seal_block;
+mod time_travel;
 
 pub mod consensus;
 pub mod rpc;
@@ -50,8 +57,9 @@ pub use self::{
 	finalize_block::{finalize_block, FinalizeBlockParams},
 	rpc::{CreatedBlock, EngineCommand},
 	seal_block::{seal_block, SealBlockParams, MAX_PROPOSAL_DURATION},
+	time_travel::TimeTravel,
 };
-use sc_transaction_pool_api::TransactionPool;
+use sc_transaction_pool_api::{TransactionPool, TransactionSource};
 use sp_api::ProvideRuntimeApi;
 
 const LOG_TARGET: &str = "manual-seal";
@@ -87,7 +95,7 @@ where
 }
 
 /// Params required to start the manual sealing authorship task.
-pub struct ManualSealParams<B: BlockT, BI, E, C: ProvideRuntimeApi<B>, TP, SC, CS, CIDP, P> {
+pub struct ManualSealParams<B: BlockT, BI, E, C: ProvideRuntimeApi<B>, TP, SC, CS, CIDP, P, CB> {
 	/// Block import instance.
 	pub block_import: BI,
 
@@ -112,6 +120,10 @@ pub struct ManualSealParams<B: BlockT, BI, E, C: ProvideRuntimeApi<B>, TP, SC, C
 
 	/// Something that can create the inherent data providers.
 	pub create_inherent_data_providers: CIDP,
+
+	/// Client backend, used to revert the chain to snapshots. [`EngineCommand::Revert`] fails if
+	/// it isn't provided.
+	pub backend: Option<Arc<CB>>,
 }
 
 /// Params required to start the instant sealing authorship task.
@@ -161,11 +173,17 @@ pub async fn run_manual_seal<B, BI, CB, E, C, TP, SC, CS, CIDP, P>(
 		select_chain,
 		consensus_data_provider,
 		create_inherent_data_providers,
-	}: ManualSealParams<B, BI, E, C, TP, SC, CS, CIDP, P>,
+		backend,
+	}: ManualSealParams<B, BI, E, C, TP, SC, CS, CIDP, P, CB>,
 ) where
 	B: BlockT + 'static,
 	BI: BlockImport<B, Error = sp_consensus::Error> + Send + Sync + 'static,
-	C: HeaderBackend<B> + Finalizer<B, CB> + ProvideRuntimeApi<B> + 'static,
+	C: HeaderBackend<B>
+		+ BlockBackend<B>
+		+ Finalizer<B, CB>
+		+ LockImportRun<B, CB>
+		+ ProvideRuntimeApi<B>
+		+ 'static,
 	CB: ClientBackend<B> + 'static,
 	E: Environment<B> + 'static,
 	E::Proposer: Proposer<B, Proof = P>,
@@ -175,6 +193,9 @@ pub async fn run_manual_seal<B, BI, CB, E, C, TP, SC, CS, CIDP, P>(
 	CIDP: CreateInherentDataProviders<B, ()>,
 	P: codec::Encode + Send + Sync + 'static,
 {
+	let mut time_travel = TimeTravel::default();
+	let mut snapshots = Vec::<(B::Hash, TimeTravel)>::new();
+
 	while let Some(command) = commands_stream.next().await {
 		match command {
 			EngineCommand::SealNewBlock { create_empty, finalize, parent_hash, sender } => {
@@ -190,6 +211,7 @@ pub async fn run_manual_seal<B, BI, CB, E, C, TP, SC, CS, CIDP, P>(
 					pool: pool.clone(),
 					client: client.clone(),
 					create_inherent_data_providers: &create_inherent_data_providers,
+					time_travel: &mut time_travel,
 				})
 				.await;
 			},
@@ -204,8 +226,149 @@ pub async fn run_manual_seal<B, BI, CB, E, C, TP, SC, CS, CIDP, P>(
 				})
 				.await
 			},
+			EngineCommand::SetNextBlockTimestamp { timestamp, mut sender } => {
+				time_travel.next_timestamp = Some(timestamp);
+				rpc::send_result(&mut sender, Ok(()))
+			},
+			EngineCommand::IncreaseTime { millis, mut sender } =>
+				rpc::send_result(&mut sender, Ok(time_travel.increase(millis))),
+			EngineCommand::Snapshot { mut sender } => {
+				let best_hash = client.info().best_hash;
+				snapshots.push((best_hash, time_travel));
+				rpc::send_result(&mut sender, Ok(best_hash))
+			},
+			EngineCommand::Revert { hash, mut sender } => {
+				let result = match snapshots.iter().rposition(|(snapshot, _)| *snapshot == hash) {
+					Some(index) =>
+						revert_to(&*client, backend.as_deref(), &*pool, hash).await.map(|()| {
+							time_travel = snapshots[index].1;
+							snapshots.truncate(index);
+						}),
+					None => Err(Error::SnapshotNotFound(format!("{}", hash))),
+				};
+				rpc::send_result(&mut sender, result)
+			},
+			EngineCommand::Mine { count, mut sender, .. } if count > rpc::MAX_MINED_BLOCKS =>
+				rpc::send_result(&mut sender, Err(Error::TooManyBlocks(count))),
+			EngineCommand::Mine { count, interval, finalize, mut sender } => {
+				let mut created = Vec::with_capacity(count as usize);
+				let mut result = Ok(());
+				for index in 0..count {
+					if let Some(interval) = interval.filter(|_| index > 0) {
+						time_travel.increase(interval);
+					}
+
+					let (block_sender, block_receiver) = futures::channel::oneshot::channel();
+					seal_block(SealBlockParams {
+						sender: Some(block_sender),
+						parent_hash: None,
+						finalize,
+						create_empty: true,
+						env: &mut env,
+						select_chain: &select_chain,
+						block_import: &mut block_import,
+						consensus_data_provider: consensus_data_provider.as_deref(),
+						pool: pool.clone(),
+						client: client.clone(),
+						create_inherent_data_providers: &create_inherent_data_providers,
+						time_travel: &mut time_travel,
+					})
+					.await;
+
+					match block_receiver.await {
+						Ok(Ok(block)) => created.push(block),
+						Ok(Err(err)) => {
+							result = Err(err);
+							break
+						},
+						Err(err) => {
+							result = Err(err.into());
+							break
+						},
+					}
+				}
+				rpc::send_result(&mut sender, result.map(|()| created))
+			},
+		}
+	}
+}
+
+/// Revert the best chain of `client` to the block with the given `hash`.
+///
+/// Finalized blocks are reverted as well. An import notification is sent for the new best block
+/// and the extrinsics of the reverted blocks are resubmitted to the pool, so that the pool and the
+/// chain subscribers follow the revert.
+async fn revert_to<B, C, CB, TP>(
+	client: &C,
+	backend: Option<&CB>,
+	pool: &TP,
+	hash: B::Hash,
+) -> Result<(), Error>
+where
+	B: BlockT,
+	C: HeaderBackend<B> + BlockBackend<B> + LockImportRun<B, CB>,
+	CB: ClientBackend<B>,
+	TP: TransactionPool<Block = B>,
+{
+	let backend =
+		backend.ok_or_else(|| Error::RevertFailed("the authorship task has no backend".into()))?;
+	let number = client.number(hash)?.ok_or_else(|| Error::BlockNotFound(format!("{}", hash)))?;
+	if client.hash(number)? != Some(hash) {
+		return Err(Error::RevertFailed(format!("{} is not on the best chain", hash)))
+	}
+
+	// The reverted blocks can't be looked up by hash anymore once reverted, collect their
+	// extrinsics beforehand.
+	let info = client.info();
+	let mut reverted_bodies = Vec::new();
+	let mut reverted_hash = info.best_hash;
+	while reverted_hash != hash {
+		let header = client
+			.header(reverted_hash)?
+			.ok_or_else(|| Error::BlockNotFound(format!("{}", reverted_hash)))?;
+		reverted_bodies.push(client.block_body(reverted_hash)?.unwrap_or_default());
+		reverted_hash = *header.parent_hash();
+	}
+
+	let to_revert = info.best_number.saturating_sub(number);
+	backend.revert(to_revert, true)?;
+
+	let best_hash = client.info().best_hash;
+	if best_hash != hash {
+		return Err(Error::RevertFailed(format!("best block is {} instead of {}", best_hash, hash)))
+	}
+
+	let header = client.header(hash)?.ok_or_else(|| Error::BlockNotFound(format!("{}", hash)))?;
+	client.lock_import_and_run(|operation| {
+		operation.notify_imported = Some(ImportSummary {
+			hash,
+			origin: BlockOrigin::Own,
+			header,
+			is_new_best: true,
+			storage_changes: None,
+			tree_route: None,
+			import_notification_action: ImportNotificationAction::Both,
+		});
+		Ok::<_, Error>(())
+	})?;
+
+	// Oldest blocks first, so that the transactions of an account keep their nonce order. The
+	// inherents are rejected by the pool.
+	let reverted_extrinsics = reverted_bodies.into_iter().rev().flatten().collect::<Vec<_>>();
+	if !reverted_extrinsics.is_empty() {
+		if let Err(err) =
+			pool.submit_at(hash, TransactionSource::External, reverted_extrinsics).await
+		{
+			log::warn!(
+				target: LOG_TARGET,
+				"Failed to resubmit the extrinsics of the reverted blocks: {}",
+				err
+			);
 		}
 	}
+
+	log::info!(target: LOG_TARGET, "Reverted {} blocks, best block is now {}", to_revert, hash);
+	Ok(())
 }
 
 /// runs the background authorship task for the instant seal engine.
@@ -224,7 +387,12 @@ pub async fn run_instant_seal<B, BI, CB, E, C, TP, SC, CIDP, P>(
 ) where
 	B: BlockT + 'static,
 	BI: BlockImport<B, Error = sp_consensus::Error> + Send + Sync + 'static,
-	C: HeaderBackend<B> + Finalizer<B, CB> + ProvideRuntimeApi<B> + 'static,
+	C: HeaderBackend<B>
+		+ BlockBackend<B>
+		+ Finalizer<B, CB>
+		+ LockImportRun<B, CB>
+		+ ProvideRuntimeApi<B>
+		+ 'static,
 	CB: ClientBackend<B> + 'static,
 	E: Environment<B> + 'static,
 	E::Proposer: Proposer<B, Proof = P>,
@@ -251,6 +419,7 @@ pub async fn run_instant_seal<B, BI, CB, E, C, TP, SC, CIDP, P>(
 		select_chain,
 		consensus_data_provider,
 		create_inherent_data_providers,
+		backend: None,
 	})
 	.await
 }
@@ -274,7 +443,12 @@ pub async fn run_instant_seal_and_finalize<B, BI, CB, E, C, TP, SC, CIDP, P>(
 ) where
 	B: BlockT + 'static,
 	BI: BlockImport<B, Error = sp_consensus::Error> + Send + Sync + 'static,
-	C: HeaderBackend<B> + Finalizer<B, CB> + ProvideRuntimeApi<B> + 'static,
+	C: HeaderBackend<B>
+		+ BlockBackend<B>
+		+ Finalizer<B, CB>
+		+ LockImportRun<B, CB>
+		+ ProvideRuntimeApi<B>
+		+ 'static,
 	CB: ClientBackend<B> + 'static,
 	E: Environment<B> + 'static,
 	E::Proposer: Proposer<B, Proof = P>,
@@ -301,6 +475,7 @@ pub async fn run_instant_seal_and_finalize<B, BI, CB, E, C, TP, SC, CIDP, P>(
 		select_chain,
 		consensus_data_provider,
 		create_inherent_data_providers,
+		backend: None,
 	})
 	.await
 }
@@ -346,6 +521,7 @@ pub async fn run_delayed_finalize<B, CB, C, S>(
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use rpc::ManualSealApiServer;
 	use sc_basic_authorship::ProposerFactory;
 	use sc_consensus::ImportedAux;
 	use sc_transaction_pool::{BasicPool, FullChainApi, Options, RevalidationType};
@@ -440,6 +616,7 @@ mod tests {
 			select_chain,
 			create_inherent_data_providers: |_, _| async { Ok(()) },
 			consensus_data_provider: None,
+			backend: None,
 		}));
 
 		// submit a transaction to pool.
@@ -518,6 +695,7 @@ mod tests {
 			select_chain,
 			create_inherent_data_providers: |_, _| async { Ok(()) },
 			consensus_data_provider: None,
+			backend: None,
 		}));
 
 		let delay_sec = 5;
@@ -594,6 +772,7 @@ mod tests {
 			select_chain,
 			consensus_data_provider: None,
 			create_inherent_data_providers: |_, _| async { Ok(()) },
+			backend: None,
 		}));
 
 		// submit a transaction to pool.
@@ -678,6 +857,7 @@ mod tests {
 			select_chain,
 			consensus_data_provider: None,
 			create_inherent_data_providers: |_, _| async { Ok(()) },
+			backend: None,
 		}));
 
 		// submit a transaction to pool.
@@ -783,6 +963,7 @@ mod tests {
 			// use a provider that pushes some post digest data
 			consensus_data_provider: Some(Box::new(TestDigestProvider { _client: client.clone() })),
 			create_inherent_data_providers: |_, _| async { Ok(()) },
+			backend: None,
 		}));
 
 		let (tx, rx) = futures::channel::oneshot::channel();
@@ -800,4 +981,80 @@ mod tests {
 		let header = client.header(created_block.hash).unwrap().unwrap();
 		assert_eq!(header.number, 1);
 	}
+
+	#[tokio::test]
+	async fn manual_seal_mine_snapshot_and_revert() {
+		let builder = TestClientBuilder::new();
+		let backend = builder.backend();
+		let (client, select_chain) = builder.build_with_longest_chain();
+		let client = Arc::new(client);
+		let spawner = sp_core::testing::TaskExecutor::new();
+		let genesis_hash = client.info().genesis_hash;
+		let pool = Arc::new(BasicPool::with_revalidation_type(
+			Options::default(),
+			true.into(),
+			api(),
+			None,
+			RevalidationType::Full,
+			spawner.clone(),
+			0,
+			genesis_hash,
+			genesis_hash,
+		));
+		let env = ProposerFactory::new(spawner.clone(), client.clone(), pool.clone(), None, None);
+		let (sink, commands_stream) = futures::channel::mpsc::channel(1024);
+		let rpc = rpc::ManualSeal::new(sink);
+
+		// spawn the background authorship task
+		tokio::spawn(run_manual_seal(ManualSealParams {
+			block_import: client.clone(),
+			env,
+			client: client.clone(),
+			pool: pool.clone(),
+			commands_stream,
+			select_chain,
+			consensus_data_provider: None,
+			create_inherent_data_providers: |_, _| async { Ok(()) },
+			backend: Some(backend),
+		}));
+
+		assert_eq!(rpc.snapshot().await.unwrap(), genesis_hash);
+
+		let created = rpc.mine(3, Some(1_000), true).await.unwrap();
+		assert_eq!(created.len(), 3);
+		assert_eq!(client.info().best_number, 3);
+		assert_eq!(client.info().best_hash, created[2].hash);
+		assert_eq!(client.info().finalized_hash, created[2].hash);
+		assert_eq!(rpc.snapshot().await.unwrap(), created[2].hash);
+
+		assert_matches::assert_matches!(
+			rpc.mine(rpc::MAX_MINED_BLOCKS + 1, None, false).await,
+			Err(Error::TooManyBlocks(_))
+		);
+		assert_eq!(client.info().best_number, 3);
+
+		// finalized blocks are reverted as well.
+		let mut import_notifications = client.import_notification_stream();
+		assert!(rpc.revert(genesis_hash).await.unwrap());
+		assert_eq!(client.info().best_hash, genesis_hash);
+		assert_eq!(client.info().finalized_hash, genesis_hash);
+
+		// the new best block is announced.
+		let notification = import_notifications.next().await.unwrap();
+		assert_eq!(notification.hash, genesis_hash);
+		assert!(notification.is_new_best);
+
+		// reverting discards the snapshot and the ones taken after it.
+		assert_matches::assert_matches!(
+			rpc.revert(genesis_hash).await,
+			Err(Error::SnapshotNotFound(_))
+		);
+		assert_matches::assert_matches!(
+			rpc.revert(created[2].hash).await,
+			Err(Error::SnapshotNotFound(_))
+		);
+
+		let created = rpc.mine(1, None, false).await.unwrap();
+		assert_eq!(client.header(created[0].hash).unwrap().unwrap().number, 1);
+	}
 }
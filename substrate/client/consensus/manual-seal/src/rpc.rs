@@ -28,6 +28,9 @@ use sc_consensus::ImportedAux;
 use serde::{Deserialize, Serialize};
 use sp_runtime::EncodedJustification;
 
+/// Maximum number of blocks sealed by a single [`EngineCommand::Mine`].
+pub const MAX_MINED_BLOCKS: u32 = 1_000;
+
 /// Sender passed to the authorship task to report errors or successes.
 pub type Sender<T> = Option<oneshot::Sender<std::result::Result<T, Error>>>;
 
@@ -59,6 +62,46 @@ pub enum EngineCommand<Hash> {
 		/// finalization justification
 		justification: Option<EncodedJustification>,
 	},
+	/// Tells the engine to use the supplied timestamp for the next block
+	SetNextBlockTimestamp {
+		/// timestamp of the next block, in milliseconds
+		timestamp: u64,
+		/// sender to report errors/success to the rpc.
+		sender: Sender<()>,
+	},
+	/// Tells the engine to move the timestamp of the following blocks forward
+	IncreaseTime {
+		/// milliseconds to add to the timestamp of the following blocks
+		millis: u64,
+		/// sender to report the total time offset, in milliseconds, to the rpc.
+		sender: Sender<i64>,
+	},
+	/// Tells the engine to remember the current best block, so that the chain can later be
+	/// reverted to it with [`EngineCommand::Revert`]
+	Snapshot {
+		/// sender to report the hash of the snapshotted block to the rpc.
+		sender: Sender<Hash>,
+	},
+	/// Tells the engine to revert the chain to a previously snapshotted block
+	///
+	/// the snapshot and all the ones taken after it are discarded.
+	Revert {
+		/// hash of the snapshotted block
+		hash: Hash,
+		/// sender to report errors/success to the rpc.
+		sender: Sender<()>,
+	},
+	/// Tells the engine to seal `count` blocks, allowing empty ones
+	Mine {
+		/// number of blocks to seal, at most [`MAX_MINED_BLOCKS`]
+		count: u32,
+		/// milliseconds to add to the timestamp between two consecutive blocks
+		interval: Option<u64>,
+		/// instantly finalize the blocks?
+		finalize: bool,
+		/// sender to report errors/success to the rpc.
+		sender: Sender<Vec<CreatedBlock<Hash>>>,
+	},
 }
 
 /// RPC trait that provides methods for interacting with the manual-seal authorship task over rpc.
@@ -80,6 +123,36 @@ pub trait ManualSealApi<Hash> {
 		hash: Hash,
 		justification: Option<EncodedJustification>,
 	) -> Result<bool, Error>;
+
+	/// Instructs the manual-seal authorship task to use the given timestamp, in milliseconds, for
+	/// the next block
+	#[method(name = "engine_setNextBlockTimestamp")]
+	async fn set_next_block_timestamp(&self, timestamp: u64) -> Result<bool, Error>;
+
+	/// Instructs the manual-seal authorship task to move the timestamp of the following blocks
+	/// forward by the given number of milliseconds, returning the total time offset
+	#[method(name = "engine_increaseTime")]
+	async fn increase_time(&self, millis: u64) -> Result<i64, Error>;
+
+	/// Instructs the manual-seal authorship task to snapshot the current best block, returning its
+	/// hash
+	#[method(name = "engine_snapshot")]
+	async fn snapshot(&self) -> Result<Hash, Error>;
+
+	/// Instructs the manual-seal authorship task to revert the chain to a snapshotted block
+	#[method(name = "engine_revert")]
+	async fn revert(&self, hash: Hash) -> Result<bool, Error>;
+
+	/// Instructs the manual-seal authorship task to create `count` blocks, even if they are empty
+	///
+	/// At most [`MAX_MINED_BLOCKS`] blocks can be created by a single call.
+	#[method(name = "engine_mine")]
+	async fn mine(
+		&self,
+		count: u32,
+		interval: Option<u64>,
+		finalize: bool,
+	) -> Result<Vec<CreatedBlock<Hash>>, Error>;
 }
 
 /// A struct that implements the [`ManualSealApiServer`].
@@ -103,6 +176,17 @@ impl<Hash> ManualSeal<Hash> {
 	pub fn new(import_block_channel: mpsc::Sender<EngineCommand<Hash>>) -> Self {
 		Self { import_block_channel }
 	}
+
+	/// Send the command built by `command` to the authorship task and wait for its result.
+	async fn send_command<T>(
+		&self,
+		command: impl FnOnce(Sender<T>) -> EngineCommand<Hash>,
+	) -> Result<T, Error> {
+		let mut sink = self.import_block_channel.clone();
+		let (sender, receiver) = oneshot::channel();
+		sink.send(command(Some(sender))).await?;
+		receiver.await?
+	}
 }
 
 #[async_trait]
@@ -143,6 +227,36 @@ impl<Hash: Send + 'static> ManualSealApiServer<Hash> for ManualSeal<Hash> {
 		sink.send(command).await?;
 		receiver.await.map(|_| true).map_err(Into::into)
 	}
+
+	async fn set_next_block_timestamp(&self, timestamp: u64) -> Result<bool, Error> {
+		self.send_command(|sender| EngineCommand::SetNextBlockTimestamp { timestamp, sender })
+			.await
+			.map(|_| true)
+	}
+
+	async fn increase_time(&self, millis: u64) -> Result<i64, Error> {
+		self.send_command(|sender| EngineCommand::IncreaseTime { millis, sender }).await
+	}
+
+	async fn snapshot(&self) -> Result<Hash, Error> {
+		self.send_command(|sender| EngineCommand::Snapshot { sender }).await
+	}
+
+	async fn revert(&self, hash: Hash) -> Result<bool, Error> {
+		self.send_command(|sender| EngineCommand::Revert { hash, sender })
+			.await
+			.map(|_| true)
+	}
+
+	async fn mine(
+		&self,
+		count: u32,
+		interval: Option<u64>,
+		finalize: bool,
+	) -> Result<Vec<CreatedBlock<Hash>>, Error> {
+		self.send_command(|sender| EngineCommand::Mine { count, interval, finalize, sender })
+			.await
+	}
 }
 
 /// report any errors or successes encountered by the authorship task back
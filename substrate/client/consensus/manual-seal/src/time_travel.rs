@@ -0,0 +1,115 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Manipulation of the timestamp inherent of the sealed blocks.
+
+use crate::Error;
+use sp_inherents::InherentData;
+use sp_timestamp::{InherentType, INHERENT_IDENTIFIER};
+
+/// Adjustments applied by the authorship task to the timestamp inherent of the blocks it seals.
+///
+/// The timestamp provided by the inherent data providers is shifted by `offset`, unless a
+/// timestamp was pinned for the next block. In that case the next block uses the pinned timestamp
+/// and the offset is updated so that the following blocks keep counting from it.
+///
+/// Blocks without a timestamp inherent are left untouched.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TimeTravel {
+	/// Milliseconds added to the timestamp provided by the inherent data providers.
+	pub offset: i64,
+	/// Timestamp, in milliseconds, of the next sealed block.
+	pub next_timestamp: Option<u64>,
+}
+
+impl TimeTravel {
+	/// Move the chain time forward by `millis`, returning the new offset.
+	pub fn increase(&mut self, millis: u64) -> i64 {
+		self.offset = self.offset.saturating_add(millis.try_into().unwrap_or(i64::MAX));
+		self.offset
+	}
+
+	/// Rewrite the timestamp inherent of `inherent_data`.
+	///
+	/// Returns the timestamp originally provided, to be passed to [`Self::note_sealed`] once the
+	/// block has been imported.
+	pub fn apply(&self, inherent_data: &mut InherentData) -> Result<Option<u64>, Error> {
+		let Some(provided) = inherent_data.get_data::<InherentType>(&INHERENT_IDENTIFIER)? else {
+			return Ok(None)
+		};
+		let provided = provided.as_millis();
+		let timestamp = match self.next_timestamp {
+			Some(next) => next,
+			None => provided.saturating_add_signed(self.offset),
+		};
+		inherent_data.replace_data(INHERENT_IDENTIFIER, &InherentType::new(timestamp));
+
+		Ok(Some(provided))
+	}
+
+	/// Note that a block has been sealed, `provided` being the timestamp returned by
+	/// [`Self::apply`].
+	pub fn note_sealed(&mut self, provided: u64) {
+		if let Some(next) = self.next_timestamp.take() {
+			let offset = i128::from(next) - i128::from(provided);
+			self.offset = offset.clamp(i64::MIN.into(), i64::MAX.into()) as i64;
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn sealed_timestamp(time_travel: &mut TimeTravel, provided: u64) -> u64 {
+		let mut inherent_data = InherentData::new();
+		inherent_data
+			.put_data(INHERENT_IDENTIFIER, &InherentType::new(provided))
+			.unwrap();
+		let provided = time_travel.apply(&mut inherent_data).unwrap().unwrap();
+		time_travel.note_sealed(provided);
+		inherent_data
+			.get_data::<InherentType>(&INHERENT_IDENTIFIER)
+			.unwrap()
+			.unwrap()
+			.as_millis()
+	}
+
+	#[test]
+	fn time_travel_adjusts_timestamps() {
+		let mut time_travel = TimeTravel::default();
+		assert_eq!(sealed_timestamp(&mut time_travel, 1_000), 1_000);
+
+		assert_eq!(time_travel.increase(500), 500);
+		assert_eq!(sealed_timestamp(&mut time_travel, 2_000), 2_500);
+
+		// The pinned timestamp is used once, later blocks count from it.
+		time_travel.next_timestamp = Some(10_000);
+		assert_eq!(sealed_timestamp(&mut time_travel, 3_000), 10_000);
+		assert_eq!(time_travel, TimeTravel { offset: 7_000, next_timestamp: None });
+		assert_eq!(sealed_timestamp(&mut time_travel, 4_000), 11_000);
+
+		// Going back in time is allowed.
+		time_travel.next_timestamp = Some(1_000);
+		assert_eq!(sealed_timestamp(&mut time_travel, 5_000), 1_000);
+		assert_eq!(sealed_timestamp(&mut time_travel, 6_000), 2_000);
+
+		// Blocks without a timestamp are left untouched.
+		assert_eq!(time_travel.apply(&mut InherentData::new()).unwrap(), None);
+	}
+}
@@ -0,0 +1,383 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Optional index of the storage keys modified by every block.
+//!
+//! Two kinds of entries are kept in the [`columns::KEY_CHANGES`] column:
+//!
+//! - for every indexed block, the sorted list of keys it modified, keyed by the block hash;
+//! - for every modified key, the `(number, hash)` pairs of the finalized blocks that modified it,
+//!   split into chunks of [`KEY_HISTORY_CHUNK`] consecutive block numbers.
+//!
+//! The per-block entries are written on import and removed with the block when its fork is
+//! discarded. The key histories are only extended on finality, once per finalizing transaction, so
+//! they never contain blocks of discarded forks and every history chunk is written at most once
+//! per transaction.
+
+use crate::{columns, DbHash};
+use codec::{Decode, Encode};
+use sc_client_api::backend::KeyChangesIndex;
+use sp_core::storage::{ChildInfo, StorageKey};
+use sp_database::{Database, Transaction};
+use sp_runtime::traits::{Block as BlockT, NumberFor, UniqueSaturatedInto};
+use sp_state_machine::{ChildStorageCollection, StorageCollection};
+use std::{
+	collections::{BTreeMap, BTreeSet},
+	marker::PhantomData,
+	sync::Arc,
+};
+
+/// Number of consecutive block numbers sharing the same key history entry.
+const KEY_HISTORY_CHUNK: u64 = 1024;
+
+/// Prefix of the per-block changed keys entries.
+const CHANGED_KEYS_PREFIX: &[u8] = b"key_changes:block";
+/// Prefix of the per-key history entries.
+const KEY_HISTORY_PREFIX: &[u8] = b"key_changes:history";
+/// The `(first, last)` finalized block numbers covered by the key histories.
+const HISTORY_RANGE: &[u8] = b"key_changes:range";
+
+/// Keys modified by a block, as `(child storage key, key)` pairs. Keys of the main trie have no
+/// child storage key.
+pub(crate) type ChangedKeys = Vec<(Option<Vec<u8>>, Vec<u8>)>;
+
+/// Database-backed [`KeyChangesIndex`].
+pub(crate) struct KeyChangesDb<Block: BlockT> {
+	db: Arc<dyn Database<DbHash>>,
+	_phantom: PhantomData<Block>,
+}
+
+impl<Block: BlockT> KeyChangesDb<Block> {
+	pub fn new(db: Arc<dyn Database<DbHash>>) -> Self {
+		Self { db, _phantom: PhantomData }
+	}
+
+	/// Record the keys modified by the block `hash` in the transaction.
+	///
+	/// The keys are returned, so that they can be added to the key histories if the block is
+	/// finalized by the same transaction.
+	pub fn note_block(
+		&self,
+		tx: &mut Transaction<DbHash>,
+		hash: Block::Hash,
+		storage_updates: &StorageCollection,
+		child_storage_updates: &ChildStorageCollection,
+	) -> ChangedKeys {
+		let changed: BTreeSet<(Option<Vec<u8>>, Vec<u8>)> = storage_updates
+			.iter()
+			.map(|(key, _)| (None, key.clone()))
+			.chain(child_storage_updates.iter().flat_map(|(child, updates)| {
+				updates.iter().map(move |(key, _)| (Some(child.clone()), key.clone()))
+			}))
+			.collect();
+
+		let changed: ChangedKeys = changed.into_iter().collect();
+		tx.set_from_vec(columns::KEY_CHANGES, &changed_keys_key(&hash), changed.encode());
+		changed
+	}
+
+	/// Add the blocks finalized by the transaction to the key histories.
+	///
+	/// `finalized` must be ordered by block number. `imported` are the keys modified by the block
+	/// imported in the same transaction, if any. Blocks that were imported before the index was
+	/// enabled restart the covered range after them.
+	pub fn note_finalized(
+		&self,
+		tx: &mut Transaction<DbHash>,
+		finalized: impl IntoIterator<Item = (NumberFor<Block>, Block::Hash)>,
+		imported: Option<&(Block::Hash, ChangedKeys)>,
+	) -> sp_blockchain::Result<()> {
+		let mut range = self.history_range()?;
+		let mut histories = BTreeMap::<Vec<u8>, Vec<(NumberFor<Block>, Block::Hash)>>::new();
+
+		for (number, hash) in finalized {
+			// Blocks finalized again, e.g. when re-importing them, are already in the histories.
+			if range.is_some_and(|(_, last)| number <= last) {
+				continue
+			}
+
+			let changed = match imported {
+				Some((imported_hash, changed)) if *imported_hash == hash => Some(changed.clone()),
+				_ => self.read_changed_keys(&hash)?,
+			};
+			let Some(changed) = changed else {
+				// The histories can only cover a contiguous range of blocks.
+				histories.clear();
+				range = None;
+				continue
+			};
+
+			for (child, key) in changed {
+				histories
+					.entry(history_key(child.as_deref(), &key, chunk_of::<Block>(number)))
+					.or_default()
+					.push((number, hash));
+			}
+			range = Some((range.map_or(number, |(first, _)| first), number));
+		}
+
+		for (history_key, entries) in histories {
+			let mut history = self.read_history(&history_key)?;
+			history.extend(entries);
+			tx.set_from_vec(columns::KEY_CHANGES, &history_key, history.encode());
+		}
+		if let Some(range) = range {
+			tx.set_from_vec(columns::KEY_CHANGES, HISTORY_RANGE, range.encode());
+		}
+
+		Ok(())
+	}
+
+	/// Remove the keys modified by the discarded block `hash`.
+	pub fn prune_block(&self, tx: &mut Transaction<DbHash>, hash: Block::Hash) {
+		tx.remove(columns::KEY_CHANGES, &changed_keys_key(&hash));
+	}
+
+	fn history_range(&self) -> sp_blockchain::Result<Option<(NumberFor<Block>, NumberFor<Block>)>> {
+		self.db
+			.get(columns::KEY_CHANGES, HISTORY_RANGE)
+			.map(|raw| Decode::decode(&mut &raw[..]))
+			.transpose()
+			.map_err(|_| sp_blockchain::Error::Backend("Error decoding key changes range".into()))
+	}
+
+	fn read_changed_keys(&self, hash: &Block::Hash) -> sp_blockchain::Result<Option<ChangedKeys>> {
+		self.db
+			.get(columns::KEY_CHANGES, &changed_keys_key(hash))
+			.map(|raw| Decode::decode(&mut &raw[..]))
+			.transpose()
+			.map_err(|_| sp_blockchain::Error::Backend("Error decoding changed keys".into()))
+	}
+
+	fn read_history(
+		&self,
+		history_key: &[u8],
+	) -> sp_blockchain::Result<Vec<(NumberFor<Block>, Block::Hash)>> {
+		match self.db.get(columns::KEY_CHANGES, history_key) {
+			Some(raw) => Decode::decode(&mut &raw[..]).map_err(|_| {
+				sp_blockchain::Error::Backend("Error decoding key changes history".into())
+			}),
+			None => Ok(Vec::new()),
+		}
+	}
+}
+
+impl<Block: BlockT> KeyChangesIndex<Block> for KeyChangesDb<Block> {
+	fn changed_keys(
+		&self,
+		hash: Block::Hash,
+		child_info: Option<&ChildInfo>,
+	) -> sp_blockchain::Result<Option<Vec<StorageKey>>> {
+		let Some(changed) = self.read_changed_keys(&hash)? else { return Ok(None) };
+
+		let child = child_info.map(|info| info.storage_key());
+		Ok(Some(
+			changed
+				.into_iter()
+				.filter(|(key_child, _)| key_child.as_deref() == child)
+				.map(|(_, key)| StorageKey(key))
+				.collect(),
+		))
+	}
+
+	fn last_finalized(&self) -> sp_blockchain::Result<Option<NumberFor<Block>>> {
+		Ok(self.history_range()?.map(|(_, last)| last))
+	}
+
+	fn key_changes(
+		&self,
+		key: &StorageKey,
+		child_info: Option<&ChildInfo>,
+		first: NumberFor<Block>,
+		last: NumberFor<Block>,
+	) -> sp_blockchain::Result<Option<Vec<(NumberFor<Block>, Block::Hash)>>> {
+		match self.history_range()? {
+			Some((from, to)) if from <= first && last <= to => {},
+			_ => return Ok(None),
+		}
+
+		let child = child_info.map(|info| info.storage_key());
+		let mut changes = Vec::new();
+		for chunk in chunk_of::<Block>(first)..=chunk_of::<Block>(last) {
+			let history = self.read_history(&history_key(child, &key.0, chunk))?;
+			changes.extend(
+				history.into_iter().filter(|(number, _)| first <= *number && *number <= last),
+			);
+		}
+		changes.sort_by_key(|(number, _)| *number);
+
+		Ok(Some(changes))
+	}
+}
+
+fn chunk_of<Block: BlockT>(number: NumberFor<Block>) -> u64 {
+	UniqueSaturatedInto::<u64>::unique_saturated_into(number) / KEY_HISTORY_CHUNK
+}
+
+fn changed_keys_key<H: Encode>(hash: &H) -> Vec<u8> {
+	let mut key = CHANGED_KEYS_PREFIX.to_vec();
+	hash.using_encoded(|s| key.extend(s));
+	key
+}
+
+fn history_key(child: Option<&[u8]>, key: &[u8], chunk: u64) -> Vec<u8> {
+	let mut history_key = KEY_HISTORY_PREFIX.to_vec();
+	history_key.extend(sp_core::blake2_256(&(child, key).encode()));
+	history_key.extend(chunk.to_be_bytes());
+	history_key
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tests::Block;
+	use sp_core::H256;
+
+	fn note(
+		index: &KeyChangesDb<Block>,
+		hash: H256,
+		keys: &[&[u8]],
+		child_keys: &[&[u8]],
+	) -> (H256, ChangedKeys) {
+		let updates = keys.iter().map(|key| (key.to_vec(), Some(vec![1]))).collect();
+		let child_updates =
+			vec![(b"child".to_vec(), child_keys.iter().map(|key| (key.to_vec(), None)).collect())];
+		let mut tx = Transaction::new();
+		let changed = index.note_block(&mut tx, hash, &updates, &child_updates);
+		index.db.commit(tx).unwrap();
+		(hash, changed)
+	}
+
+	fn finalize(index: &KeyChangesDb<Block>, blocks: &[(u64, H256)]) {
+		let mut tx = Transaction::new();
+		index.note_finalized(&mut tx, blocks.iter().copied(), None).unwrap();
+		index.db.commit(tx).unwrap();
+	}
+
+	#[test]
+	fn key_changes_write_read() {
+		let db = Arc::new(sp_database::MemDb::default());
+		let index = KeyChangesDb::<Block>::new(db);
+		let child_info = ChildInfo::new_default(b"child");
+
+		assert_eq!(index.changed_keys(H256::repeat_byte(1), None).unwrap(), None);
+		assert_eq!(index.key_changes(&StorageKey(b"a".to_vec()), None, 0, 10).unwrap(), None);
+
+		note(&index, H256::repeat_byte(1), &[b"b", b"a"], &[b"c"]);
+		note(&index, H256::repeat_byte(2), &[b"a"], &[]);
+		note(&index, H256::repeat_byte(3), &[b"b"], &[]);
+		note(&index, H256::repeat_byte(4), &[b"a"], &[]);
+
+		assert_eq!(
+			index.changed_keys(H256::repeat_byte(1), None).unwrap(),
+			Some(vec![StorageKey(b"a".to_vec()), StorageKey(b"b".to_vec())]),
+		);
+		assert_eq!(
+			index.changed_keys(H256::repeat_byte(1), Some(&child_info)).unwrap(),
+			Some(vec![StorageKey(b"c".to_vec())]),
+		);
+		assert_eq!(
+			index.changed_keys(H256::repeat_byte(2), Some(&child_info)).unwrap(),
+			Some(vec![])
+		);
+
+		// Unfinalized blocks are not in the histories.
+		assert_eq!(index.last_finalized().unwrap(), None);
+		assert_eq!(index.key_changes(&StorageKey(b"a".to_vec()), None, 1, 1).unwrap(), None);
+
+		// Block 3 is on a fork which is never finalized.
+		finalize(&index, &[(1, H256::repeat_byte(1)), (2, H256::repeat_byte(2))]);
+		finalize(&index, &[(2000, H256::repeat_byte(4))]);
+		assert_eq!(index.last_finalized().unwrap(), Some(2000));
+
+		// Blocks outside of the finalized range are unknown.
+		assert_eq!(index.key_changes(&StorageKey(b"a".to_vec()), None, 0, 10).unwrap(), None);
+		assert_eq!(index.key_changes(&StorageKey(b"a".to_vec()), None, 1, 3000).unwrap(), None);
+		assert_eq!(
+			index.key_changes(&StorageKey(b"a".to_vec()), None, 1, 2000).unwrap(),
+			Some(vec![
+				(1, H256::repeat_byte(1)),
+				(2, H256::repeat_byte(2)),
+				(2000, H256::repeat_byte(4))
+			]),
+		);
+		assert_eq!(
+			index.key_changes(&StorageKey(b"b".to_vec()), None, 1, 2000).unwrap(),
+			Some(vec![(1, H256::repeat_byte(1))]),
+		);
+		assert_eq!(
+			index.key_changes(&StorageKey(b"c".to_vec()), Some(&child_info), 1, 10).unwrap(),
+			Some(vec![(1, H256::repeat_byte(1))]),
+		);
+		assert_eq!(
+			index.key_changes(&StorageKey(b"c".to_vec()), None, 1, 10).unwrap(),
+			Some(vec![])
+		);
+
+		// Finalizing a block again does not duplicate its entries.
+		finalize(&index, &[(2, H256::repeat_byte(2))]);
+		assert_eq!(
+			index.key_changes(&StorageKey(b"a".to_vec()), None, 2, 2).unwrap(),
+			Some(vec![(2, H256::repeat_byte(2))]),
+		);
+
+		// Discarded blocks are removed.
+		let mut tx = Transaction::new();
+		index.prune_block(&mut tx, H256::repeat_byte(3));
+		index.db.commit(tx).unwrap();
+		assert_eq!(index.changed_keys(H256::repeat_byte(3), None).unwrap(), None);
+	}
+
+	#[test]
+	fn key_changes_of_block_imported_and_finalized_together() {
+		let db = Arc::new(sp_database::MemDb::default());
+		let index = KeyChangesDb::<Block>::new(db);
+
+		let mut tx = Transaction::new();
+		let updates = vec![(b"a".to_vec(), Some(vec![1]))];
+		let changed = index.note_block(&mut tx, H256::repeat_byte(1), &updates, &vec![]);
+		let imported = (H256::repeat_byte(1), changed);
+		index
+			.note_finalized(&mut tx, [(1, H256::repeat_byte(1))], Some(&imported))
+			.unwrap();
+		index.db.commit(tx).unwrap();
+
+		assert_eq!(
+			index.key_changes(&StorageKey(b"a".to_vec()), None, 1, 1).unwrap(),
+			Some(vec![(1, H256::repeat_byte(1))]),
+		);
+	}
+
+	#[test]
+	fn unindexed_finalized_block_restarts_the_range() {
+		let db = Arc::new(sp_database::MemDb::default());
+		let index = KeyChangesDb::<Block>::new(db);
+
+		note(&index, H256::repeat_byte(1), &[b"a"], &[]);
+		note(&index, H256::repeat_byte(3), &[b"a"], &[]);
+		finalize(&index, &[(1, H256::repeat_byte(1))]);
+		// Block 2 was imported before the index was enabled.
+		finalize(&index, &[(2, H256::repeat_byte(2)), (3, H256::repeat_byte(3))]);
+
+		assert_eq!(index.key_changes(&StorageKey(b"a".to_vec()), None, 1, 3).unwrap(), None);
+		assert_eq!(
+			index.key_changes(&StorageKey(b"a".to_vec()), None, 3, 3).unwrap(),
+			Some(vec![(3, H256::repeat_byte(3))]),
+		);
+	}
+}
@@ -33,6 +33,7 @@ pub mod offchain;
 pub mod bench;
 
 mod children;
+mod key_changes;
 mod parity_db;
 mod pinned_blocks_cache;
 mod record_stats_state;
@@ -316,6 +317,12 @@ pub struct DatabaseSettings {
 
 	/// Prometheus metrics registry.
 	pub metrics_registry: Option<Registry>,
+
+	/// Maintain an index of the storage keys modified by every imported block.
+	///
+	/// The index speeds up historical state queries, such as storage diffs and key histories,
+	/// at the cost of additional disk space. Only blocks imported while it is enabled are indexed.
+	pub key_changes_index: bool,
 }
 
 /// Block pruning settings.
@@ -439,6 +446,8 @@ pub(crate) mod columns {
 	pub const AUX: u32 = 8;
 	/// Offchain workers local storage
 	pub const OFFCHAIN: u32 = 9;
+	/// Index of the storage keys modified by every block, see [`crate::key_changes`].
+	pub const KEY_CHANGES: u32 = 10;
 	/// Transactions
 	pub const TRANSACTION: u32 = 11;
 	pub const BODY_INDEX: u32 = 12;
@@ -1119,6 +1128,7 @@ pub struct Backend<Block: BlockT> {
 	state_usage: Arc<StateUsageStats>,
 	genesis_state: RwLock<Option<Arc<DbGenesisStorage<Block>>>>,
 	shared_trie_cache: Option<sp_trie::cache::SharedTrieCache<HashingFor<Block>>>,
+	key_changes: Option<Arc<key_changes::KeyChangesDb<Block>>>,
 }
 
 impl<Block: BlockT> Backend<Block> {
@@ -1162,6 +1172,24 @@ impl<Block: BlockT> Backend<Block> {
 	pub fn new_test_with_tx_storage(
 		blocks_pruning: BlocksPruning,
 		canonicalization_delay: u64,
+	) -> Self {
+		Self::new_test_with_settings(blocks_pruning, canonicalization_delay, false)
+	}
+
+	/// Create new memory-backed client backend maintaining the key changes index, for tests.
+	#[cfg(any(test, feature = "test-helpers"))]
+	pub fn new_test_with_key_changes_index(
+		blocks_pruning: BlocksPruning,
+		canonicalization_delay: u64,
+	) -> Self {
+		Self::new_test_with_settings(blocks_pruning, canonicalization_delay, true)
+	}
+
+	#[cfg(any(test, feature = "test-helpers"))]
+	fn new_test_with_settings(
+		blocks_pruning: BlocksPruning,
+		canonicalization_delay: u64,
+		key_changes_index: bool,
 	) -> Self {
 		let db = kvdb_memorydb::create(crate::utils::NUM_COLUMNS);
 		let db = sp_database::as_database(db);
@@ -1176,6 +1204,7 @@ impl<Block: BlockT> Backend<Block> {
 			source: DatabaseSource::Custom { db, require_create_flag: true },
 			blocks_pruning,
 			metrics_registry: None,
+			key_changes_index,
 		};
 
 		Self::new(db_setting, canonicalization_delay).expect("failed to create test-db")
@@ -1267,6 +1296,9 @@ impl<Block: BlockT> Backend<Block> {
 			blocks_pruning: config.blocks_pruning,
 			genesis_state: RwLock::new(None),
 			shared_trie_cache,
+			key_changes: config
+				.key_changes_index
+				.then(|| Arc::new(key_changes::KeyChangesDb::new(db.clone()))),
 		};
 
 		// Older DB versions have no last state key. Check if the state is available and set it.
@@ -1483,6 +1515,7 @@ impl<Block: BlockT> Backend<Block> {
 		};
 
 		let mut block_gap_updated = false;
+		let mut imported_changed_keys = None;
 
 		let mut current_transaction_justifications: HashMap<Block::Hash, Justification> =
 			HashMap::new();
@@ -1614,6 +1647,17 @@ impl<Block: BlockT> Backend<Block> {
 					}
 				}
 				self.state_usage.tally_writes(ops, bytes);
+				if let Some(key_changes) = &self.key_changes {
+					imported_changed_keys = Some((
+						hash,
+						key_changes.note_block(
+							&mut transaction,
+							hash,
+							&operation.storage_updates,
+							&operation.child_storage_updates,
+						),
+					));
+				}
 				let number_u64 = number.saturated_into::<u64>();
 				let commit = self
 					.storage
@@ -1839,6 +1883,14 @@ impl<Block: BlockT> Backend<Block> {
 			}
 		}
 
+		if let Some(key_changes) = &self.key_changes {
+			key_changes.note_finalized(
+				&mut transaction,
+				meta_updates.iter().filter(|m| m.is_finalized).map(|m| (m.number, m.hash)),
+				imported_changed_keys.as_ref(),
+			)?;
+		}
+
 		self.storage.db.commit(transaction)?;
 
 		// Apply all in-memory state changes.
@@ -1954,6 +2006,9 @@ impl<Block: BlockT> Backend<Block> {
 		for &hash in displaced.displaced_blocks.iter() {
 			self.blockchain.insert_persisted_body_if_pinned(hash)?;
 			self.prune_block(transaction, BlockId::<Block>::hash(hash))?;
+			if let Some(key_changes) = &self.key_changes {
+				key_changes.prune_block(transaction, hash);
+			}
 		}
 		Ok(())
 	}
@@ -2204,6 +2259,10 @@ impl<Block: BlockT> sc_client_api::backend::Backend<Block> for Backend<Block> {
 			true,
 		)?;
 
+		if let Some(key_changes) = &self.key_changes {
+			key_changes.note_finalized(&mut transaction, [(m.number, m.hash)], None)?;
+		}
+
 		self.storage.db.commit(transaction)?;
 		self.blockchain.update_meta(m);
 		Ok(())
@@ -2255,6 +2314,10 @@ impl<Block: BlockT> sc_client_api::backend::Backend<Block> for Backend<Block> {
 		Some(self.offchain_storage.clone())
 	}
 
+	fn key_changes_index(&self) -> Option<Arc<dyn sc_client_api::backend::KeyChangesIndex<Block>>> {
+		self.key_changes.clone().map(|index| index as Arc<_>)
+	}
+
 	fn usage_info(&self) -> Option<UsageInfo> {
 		let (io_stats, state_stats) = self.io_stats.take_or_else(|| {
 			(
@@ -2814,6 +2877,7 @@ pub(crate) mod tests {
 				source: DatabaseSource::Custom { db: backing, require_create_flag: false },
 				blocks_pruning: BlocksPruning::KeepFinalized,
 				metrics_registry: None,
+				key_changes_index: false,
 			},
 			0,
 		)
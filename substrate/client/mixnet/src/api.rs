@@ -21,7 +21,7 @@ use futures::{
 	channel::{mpsc, oneshot},
 	SinkExt,
 };
-use sp_core::Bytes;
+use sp_core::{Bytes, H256};
 use std::future::Future;
 
 /// The other end of an [`Api`]. This should be passed to [`run`](super::run::run).
@@ -56,11 +56,50 @@ impl Api {
 		&mut self,
 		extrinsic: Bytes,
 	) -> impl Future<Output = Result<(), Error>> {
-		let (reply_sender, reply_receiver) = oneshot::channel();
-		let res = self
-			.request_sender
-			.feed(Request::SubmitExtrinsic { extrinsic, reply_sender })
+		self.request(|reply_sender| Request::SubmitExtrinsic { extrinsic, reply_sender })
+			.await
+	}
+
+	/// Call a runtime function at the given block via the mixnet. If `at` is `None`, the call is
+	/// made at the best block known by the destination mixnode.
+	///
+	/// The returned `Future`s behave as for [`submit_extrinsic`](Self::submit_extrinsic). The
+	/// second one resolves to the encoded result of the call.
+	pub async fn state_call(
+		&mut self,
+		at: Option<H256>,
+		method: String,
+		data: Bytes,
+	) -> impl Future<Output = Result<Bytes, Error>> {
+		let reply = self
+			.request(|reply_sender| Request::StateCall { at, method, data, reply_sender })
+			.await;
+		async move { reply.await.map(Bytes) }
+	}
+
+	/// Read a storage value at the given block via the mixnet. If `at` is `None`, the value is read
+	/// at the best block known by the destination mixnode.
+	///
+	/// The returned `Future`s behave as for [`submit_extrinsic`](Self::submit_extrinsic).
+	pub async fn state_get_storage(
+		&mut self,
+		at: Option<H256>,
+		key: Bytes,
+	) -> impl Future<Output = Result<Option<Bytes>, Error>> {
+		let reply = self
+			.request(|reply_sender| Request::StateGetStorage { at, key, reply_sender })
 			.await;
+		async move { reply.await.map(|value| value.map(Bytes)) }
+	}
+
+	/// Push the request built by `request` into the mixnet service queue. See
+	/// [`submit_extrinsic`](Self::submit_extrinsic) for a description of the returned `Future`s.
+	async fn request<T>(
+		&mut self,
+		request: impl FnOnce(oneshot::Sender<Result<T, Error>>) -> Request,
+	) -> impl Future<Output = Result<T, Error>> {
+		let (reply_sender, reply_receiver) = oneshot::channel();
+		let res = self.request_sender.feed(request(reply_sender)).await;
 		async move {
 			res.map_err(|_| Error::ServiceUnavailable)?;
 			reply_receiver.await.map_err(|_| Error::ServiceUnavailable)?
@@ -45,6 +45,27 @@ pub struct SubstrateConfig {
 	/// Maximum number of extrinsics being actively submitted. If a submit extrinsic request's
 	/// delay elapses and we are already at this limit, the request will simply be dropped.
 	pub max_pending_extrinsics: usize,
+
+	/// Maximum number of state query requests waiting for their delay to elapse. When at the
+	/// limit, any state query requests that arrive will simply be dropped. If this is 0, state
+	/// query requests are refused.
+	pub query_queue_capacity: usize,
+	/// Mean delay between receiving a state query request and executing the query. This should
+	/// really be the same for all nodes!
+	pub mean_query_delay: Duration,
+	/// Maximum number of fragments in a state query reply. Each reply fragment requires a SURB,
+	/// so this is multiplied by `surb_factor` to determine the number of SURBs to include in
+	/// state query requests. Replies which do not fit are replaced with an error.
+	pub max_query_reply_fragments: usize,
+	/// Maximum number of state queries being actively executed. If a state query request's delay
+	/// elapses and we are already at this limit, the request will simply be dropped.
+	pub max_pending_queries: usize,
+	/// Time after which a state query that is still executing is answered with an error. The
+	/// execution itself runs to completion and keeps counting towards `max_pending_queries`.
+	pub query_timeout: Duration,
+	/// Runtime API methods that may be called by state query requests, eg
+	/// `"AccountNonceApi_account_nonce"`. Calls to any other method are refused.
+	pub query_allowed_methods: Vec<String>,
 }
 
 impl Default for SubstrateConfig {
@@ -59,6 +80,21 @@ impl Default for SubstrateConfig {
 			extrinsic_queue_capacity: 50,
 			mean_extrinsic_delay: Duration::from_secs(1),
 			max_pending_extrinsics: 20,
+
+			query_queue_capacity: 50,
+			mean_query_delay: Duration::from_millis(100),
+			max_query_reply_fragments: 4,
+			max_pending_queries: 4,
+			query_timeout: Duration::from_secs(2),
+			query_allowed_methods: [
+				"Core_version",
+				"AccountNonceApi_account_nonce",
+				"TransactionPaymentApi_query_info",
+				"TransactionPaymentApi_query_fee_details",
+			]
+			.into_iter()
+			.map(Into::into)
+			.collect(),
 		}
 	}
 }
@@ -28,6 +28,10 @@ pub enum RemoteErr {
 	/// Failed to decode the request.
 	#[error("Failed to decode the request: {0}")]
 	Decode(String),
+	/// The reply, of the given size in bytes, does not fit in the reply fragments allowed by the
+	/// destination mixnode.
+	#[error("The reply ({0} bytes) is too large to be sent back over the mixnet")]
+	ReplyTooLarge(u32),
 }
 
 /// Mixnet error.
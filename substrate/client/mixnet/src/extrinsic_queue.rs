@@ -18,7 +18,8 @@
 
 //! [`ExtrinsicQueue`] is a queue for extrinsics received from the mixnet. These extrinsics are
 //! explicitly delayed by a random amount, to decorrelate the times at which they are received from
-//! the times at which they are broadcast to peers.
+//! the times at which they are broadcast to peers. The same queue is used to delay state queries
+//! received from the mixnet before executing them.
 
 use mixnet::reply_manager::ReplyContext;
 use std::{cmp::Ordering, collections::BinaryHeap, time::Instant};
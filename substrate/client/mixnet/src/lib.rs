@@ -32,6 +32,7 @@ mod peer_id;
 mod protocol;
 mod request;
 mod run;
+mod state_query;
 mod sync_with_runtime;
 
 pub use self::{
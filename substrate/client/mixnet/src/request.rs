@@ -17,18 +17,19 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 //! Sender-side request logic. Some things from this module are also used on the receiver side, eg
-//! [`extrinsic_delay`], but most of the receiver-side request logic lives elsewhere.
+//! [`extrinsic_delay`] and [`query_delay`], but most of the receiver-side request logic lives
+//! elsewhere.
 
 use super::{config::SubstrateConfig, error::Error};
 use blake2::{
 	digest::{consts::U16, Mac},
 	Blake2bMac,
 };
-use codec::{Decode, DecodeAll};
+use codec::{Decode, DecodeAll, Encode};
 use futures::channel::oneshot;
 use log::debug;
 use mixnet::core::{Delay, MessageId, PostErr, Scattered};
-use sp_core::Bytes;
+use sp_core::{Bytes, H256};
 use std::time::Duration;
 
 const LOG_TARGET: &str = "mixnet";
@@ -53,6 +54,10 @@ fn send_reply<T: Decode>(reply_sender: oneshot::Sender<Result<T, Error>>, mut da
 
 /// First byte of a submit extrinsic request, identifying it as such.
 pub const SUBMIT_EXTRINSIC: u8 = 1;
+/// First byte of a state call request, identifying it as such.
+pub const STATE_CALL: u8 = 2;
+/// First byte of a get storage request, identifying it as such.
+pub const STATE_GET_STORAGE: u8 = 3;
 
 const EXTRINSIC_DELAY_PERSONA: &[u8; 16] = b"submit-extrn-dly";
 
@@ -65,10 +70,35 @@ pub fn extrinsic_delay(message_id: &MessageId, config: &SubstrateConfig) -> Dura
 	delay.to_duration(config.mean_extrinsic_delay)
 }
 
+const QUERY_DELAY_PERSONA: &[u8; 16] = b"state-query--dly";
+
+/// Returns the artificial delay that should be inserted between receipt of a state query request
+/// with the given message ID and execution of the query.
+pub fn query_delay(message_id: &MessageId, config: &SubstrateConfig) -> Duration {
+	let h = Blake2bMac::<U16>::new_with_salt_and_personal(message_id, b"", QUERY_DELAY_PERSONA)
+		.expect("Key, salt, and persona sizes are fixed and small enough");
+	let delay = Delay::exp(h.finalize().into_bytes().as_ref());
+	delay.to_duration(config.mean_query_delay)
+}
+
 /// Request parameters and local reply channel. Stored by the
 /// [`RequestManager`](mixnet::request_manager::RequestManager).
 pub enum Request {
-	SubmitExtrinsic { extrinsic: Bytes, reply_sender: oneshot::Sender<Result<(), Error>> },
+	SubmitExtrinsic {
+		extrinsic: Bytes,
+		reply_sender: oneshot::Sender<Result<(), Error>>,
+	},
+	StateCall {
+		at: Option<H256>,
+		method: String,
+		data: Bytes,
+		reply_sender: oneshot::Sender<Result<Vec<u8>, Error>>,
+	},
+	StateGetStorage {
+		at: Option<H256>,
+		key: Bytes,
+		reply_sender: oneshot::Sender<Result<Option<Vec<u8>>, Error>>,
+	},
 }
 
 impl Request {
@@ -76,6 +106,8 @@ impl Request {
 	fn send_err(self, err: Error) {
 		match self {
 			Request::SubmitExtrinsic { reply_sender, .. } => send_err(reply_sender, err),
+			Request::StateCall { reply_sender, .. } => send_err(reply_sender, err),
+			Request::StateGetStorage { reply_sender, .. } => send_err(reply_sender, err),
 		}
 	}
 
@@ -83,6 +115,8 @@ impl Request {
 	pub fn send_reply(self, data: &[u8]) {
 		match self {
 			Request::SubmitExtrinsic { reply_sender, .. } => send_reply(reply_sender, data),
+			Request::StateCall { reply_sender, .. } => send_reply(reply_sender, data),
+			Request::StateGetStorage { reply_sender, .. } => send_reply(reply_sender, data),
 		}
 	}
 }
@@ -94,18 +128,30 @@ impl mixnet::request_manager::Request for Request {
 		match self {
 			Request::SubmitExtrinsic { extrinsic, .. } =>
 				f([&[SUBMIT_EXTRINSIC], extrinsic.as_ref()].as_slice().into()),
+			Request::StateCall { at, method, data, .. } => {
+				let query = (at, method, data.as_ref()).encode();
+				f([&[STATE_CALL], query.as_slice()].as_slice().into())
+			},
+			Request::StateGetStorage { at, key, .. } => {
+				let query = (at, key.as_ref()).encode();
+				f([&[STATE_GET_STORAGE], query.as_slice()].as_slice().into())
+			},
 		}
 	}
 
 	fn num_surbs(&self, context: &Self::Context) -> usize {
 		match self {
 			Request::SubmitExtrinsic { .. } => context.surb_factor,
+			Request::StateCall { .. } | Request::StateGetStorage { .. } =>
+				context.surb_factor * context.max_query_reply_fragments,
 		}
 	}
 
 	fn handling_delay(&self, message_id: &MessageId, context: &Self::Context) -> Duration {
 		match self {
 			Request::SubmitExtrinsic { .. } => extrinsic_delay(message_id, context),
+			Request::StateCall { .. } | Request::StateGetStorage { .. } =>
+				query_delay(message_id, context),
 		}
 	}
 
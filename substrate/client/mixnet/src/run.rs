@@ -26,15 +26,19 @@ use super::{
 	maybe_inf_delay::MaybeInfDelay,
 	packet_dispatcher::PacketDispatcher,
 	peer_id::to_core_peer_id,
-	request::{extrinsic_delay, Request, SUBMIT_EXTRINSIC},
+	request::{
+		extrinsic_delay, query_delay, Request, STATE_CALL, STATE_GET_STORAGE, SUBMIT_EXTRINSIC,
+	},
+	state_query::{max_reply_size, StateQuery},
 	sync_with_runtime::sync_with_runtime,
 };
 use bytes::Bytes;
 use codec::{Decode, DecodeAll, Encode};
 use futures::{
-	future::{pending, Either},
+	channel::oneshot,
+	future::{pending, select, Either},
 	stream::FuturesUnordered,
-	FutureExt, StreamExt,
+	Future, FutureExt, StreamExt,
 };
 use log::{debug, error, trace, warn};
 use mixnet::{
@@ -42,7 +46,7 @@ use mixnet::{
 	reply_manager::{ReplyContext, ReplyManager},
 	request_manager::RequestManager,
 };
-use sc_client_api::{BlockchainEvents, HeaderBackend};
+use sc_client_api::{Backend, BlockchainEvents, ExecutorProvider, HeaderBackend, StorageProvider};
 use sc_network::{
 	service::traits::{NetworkService, NotificationEvent, ValidationResult},
 	NetworkPeers, NetworkStateInfo, NotificationService, ProtocolName,
@@ -52,6 +56,7 @@ use sc_transaction_pool_api::{
 };
 use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_consensus::SyncOracle;
+use sp_core::traits::SpawnNamed;
 use sp_keystore::{KeystoreExt, KeystorePtr};
 use sp_mixnet::{runtime_api::MixnetApi, types::Mixnode};
 use sp_runtime::{
@@ -68,21 +73,22 @@ const LOG_TARGET: &str = "mixnet";
 
 const MIN_BLOCKS_BETWEEN_REGISTRATION_ATTEMPTS: u32 = 3;
 
-fn complete_submit_extrinsic<X>(
+fn complete_request<X, T: Encode>(
 	reply_manager: &mut ReplyManager,
 	reply_context: ReplyContext,
-	data: Result<(), RemoteErr>,
+	data: Result<T, RemoteErr>,
 	mixnet: &mut Mixnet<X>,
 ) {
 	reply_manager.complete(reply_context, data.encode(), mixnet);
 }
 
-fn handle_packet<X, E: Decode>(
+fn handle_packet<X, E: Decode, B: Block>(
 	packet: &Packet,
 	mixnet: &mut Mixnet<X>,
 	request_manager: &mut RequestManager<Request>,
 	reply_manager: &mut ReplyManager,
 	extrinsic_queue: &mut ExtrinsicQueue<E>,
+	query_queue: &mut ExtrinsicQueue<StateQuery<B>>,
 	config: &SubstrateConfig,
 ) {
 	match mixnet.handle_packet(packet) {
@@ -103,7 +109,7 @@ fn handle_packet<X, E: Decode>(
 					let extrinsic = match E::decode_all(&mut encoded_extrinsic) {
 						Ok(extrinsic) => extrinsic,
 						Err(err) => {
-							complete_submit_extrinsic(
+							complete_request::<_, ()>(
 								reply_manager,
 								reply_context,
 								Err(RemoteErr::Decode(format!("Bad extrinsic: {}", err))),
@@ -117,6 +123,45 @@ fn handle_packet<X, E: Decode>(
 						Instant::now() + extrinsic_delay(reply_context.message_id(), config);
 					extrinsic_queue.insert(deadline, extrinsic, reply_context);
 				},
+				[kind @ (STATE_CALL | STATE_GET_STORAGE), encoded_query @ ..] => {
+					if config.query_queue_capacity == 0 {
+						complete_request::<_, ()>(
+							reply_manager,
+							reply_context,
+							Err(RemoteErr::Other("State queries are not served".into())),
+							mixnet,
+						);
+						return
+					}
+
+					if !query_queue.has_space() {
+						debug!(target: LOG_TARGET, "No space in query queue; dropping request");
+						// We don't send a reply in this case; we want the requester to retry
+						reply_manager.abandon(reply_context);
+						return
+					}
+
+					let query = match StateQuery::decode(*kind, encoded_query) {
+						Ok(query) => query,
+						Err(err) => {
+							complete_request::<_, ()>(
+								reply_manager,
+								reply_context,
+								Err(RemoteErr::Decode(format!("Bad state query: {}", err))),
+								mixnet,
+							);
+							return
+						},
+					};
+
+					if let Err(err) = query.check_allowed(&config.query_allowed_methods) {
+						complete_request::<_, ()>(reply_manager, reply_context, Err(err), mixnet);
+						return
+					}
+
+					let deadline = Instant::now() + query_delay(reply_context.message_id(), config);
+					query_queue.insert(deadline, query, reply_context);
+				},
 				_ => {
 					debug!(target: LOG_TARGET, "Unrecognised request; discarding");
 					// To keep things simple we don't bother sending a reply in this case. The
@@ -140,13 +185,49 @@ fn handle_packet<X, E: Decode>(
 	}
 }
 
+/// Execute a state query on a blocking task. `running` is dropped once the execution completes,
+/// which may be after the returned future resolves to a timeout error.
+fn execute_state_query<B, BE, C>(
+	spawner: &dyn SpawnNamed,
+	client: Arc<C>,
+	query: StateQuery<B>,
+	max_reply_size: usize,
+	timeout: Duration,
+	running: Arc<()>,
+) -> impl Future<Output = Result<Vec<u8>, RemoteErr>>
+where
+	B: Block,
+	BE: Backend<B>,
+	C: HeaderBackend<B> + ExecutorProvider<B> + StorageProvider<B, BE> + Send + Sync + 'static,
+{
+	let (reply_sender, reply_receiver) = oneshot::channel();
+	spawner.spawn_blocking(
+		"mixnet-state-query",
+		Some("mixnet"),
+		async move {
+			let _ = reply_sender.send(query.execute(&*client, max_reply_size));
+			drop(running);
+		}
+		.boxed(),
+	);
+
+	async move {
+		match select(reply_receiver, futures_timer::Delay::new(timeout)).await {
+			Either::Left((Ok(reply), _)) => Ok(reply),
+			Either::Left((Err(_), _)) => Err(RemoteErr::Other("State query failed".into())),
+			Either::Right(_) => Err(RemoteErr::Other("State query timed out".into())),
+		}
+	}
+}
+
 fn time_until(instant: Instant) -> Duration {
 	instant.saturating_duration_since(Instant::now())
 }
 
 /// Run the mixnet service. If `keystore` is `None`, the service will not attempt to register the
-/// local node as a mixnode, even if `config.register` is `true`.
-pub async fn run<B, C, S, P>(
+/// local node as a mixnode, even if `config.register` is `true`. State queries received from the
+/// mixnet are executed on blocking tasks spawned with `spawner`.
+pub async fn run<B, BE, C, S, P>(
 	config: Config,
 	mut api_backend: ApiBackend,
 	client: Arc<C>,
@@ -156,9 +237,18 @@ pub async fn run<B, C, S, P>(
 	transaction_pool: Arc<P>,
 	keystore: Option<KeystorePtr>,
 	mut notification_service: Box<dyn NotificationService>,
+	spawner: Box<dyn SpawnNamed>,
 ) where
 	B: Block,
-	C: BlockchainEvents<B> + ProvideRuntimeApi<B> + HeaderBackend<B>,
+	BE: Backend<B>,
+	C: BlockchainEvents<B>
+		+ ProvideRuntimeApi<B>
+		+ HeaderBackend<B>
+		+ ExecutorProvider<B>
+		+ StorageProvider<B, BE>
+		+ Send
+		+ Sync
+		+ 'static,
 	C::Api: MixnetApi<B>,
 	S: SyncOracle,
 	P: TransactionPool<Block = B> + LocalTransactionPool<Block = B> + 'static,
@@ -182,6 +272,7 @@ pub async fn run<B, C, S, P>(
 	let mut request_manager = RequestManager::new(config.request_manager);
 	let mut reply_manager = ReplyManager::new(config.reply_manager);
 	let mut extrinsic_queue = ExtrinsicQueue::new(config.substrate.extrinsic_queue_capacity);
+	let mut query_queue = ExtrinsicQueue::new(config.substrate.query_queue_capacity);
 
 	let mut finality_notifications = client.finality_notification_stream();
 	// Import notifications only used for triggering registration attempts
@@ -196,6 +287,10 @@ pub async fn run<B, C, S, P>(
 	let mut next_retry_delay = MaybeInfDelay::new(None);
 	let mut next_extrinsic_delay = MaybeInfDelay::new(None);
 	let mut submit_extrinsic_results = FuturesUnordered::new();
+	let mut next_query_delay = MaybeInfDelay::new(None);
+	let mut query_replies = FuturesUnordered::new();
+	let running_queries = Arc::new(());
+	let max_query_reply_size = max_reply_size(config.substrate.max_query_reply_fragments);
 
 	loop {
 		let mut next_request = if request_manager.has_space() {
@@ -265,7 +360,7 @@ pub async fn run<B, C, S, P>(
 					match notification.as_ref().try_into() {
 						Ok(packet) => handle_packet(packet,
 							&mut mixnet, &mut request_manager, &mut reply_manager,
-							&mut extrinsic_queue, &config.substrate),
+							&mut extrinsic_queue, &mut query_queue, &config.substrate),
 						Err(_) => debug!(target: LOG_TARGET,
 							"Dropped incorrectly sized packet ({} bytes) from {peer}",
 							notification.len(),
@@ -344,7 +439,42 @@ pub async fn run<B, C, S, P>(
 					Ok(_) => Ok(()),
 					Err(err) => Err(RemoteErr::Other(err.to_string())),
 				};
-				complete_submit_extrinsic(&mut reply_manager, reply_context, res, &mut mixnet);
+				complete_request(&mut reply_manager, reply_context, res, &mut mixnet);
+			}
+
+			_ = next_query_delay => {
+				if let Some((query, reply_context)) = query_queue.pop() {
+					// The strong count is one more than the number of running queries
+					if Arc::strong_count(&running_queries) <= config.substrate.max_pending_queries {
+						let reply = execute_state_query(
+							&*spawner,
+							client.clone(),
+							query,
+							max_query_reply_size,
+							config.substrate.query_timeout,
+							running_queries.clone(),
+						);
+						query_replies.push(async move { (reply.await, reply_context) });
+					} else {
+						// There are already too many running queries, just drop this one. We
+						// don't send a reply; we want the requester to retry.
+						debug!(target: LOG_TARGET,
+							"Too many running state queries; dropped state query request");
+						reply_manager.abandon(reply_context);
+					}
+				} else {
+					warn!(target: LOG_TARGET,
+						"Next query deadline reached, but no query in queue; this is a bug");
+				}
+			}
+
+			reply_reply_context = query_replies.select_next_some() => {
+				let (reply, reply_context) = reply_reply_context;
+				match reply {
+					Ok(reply) => reply_manager.complete(reply_context, reply, &mut mixnet),
+					Err(err) => complete_request::<_, ()>(&mut reply_manager, reply_context,
+						Err(err), &mut mixnet),
+				}
 			}
 		}
 
@@ -387,5 +517,9 @@ pub async fn run<B, C, S, P>(
 		if extrinsic_queue.next_deadline_changed() {
 			next_extrinsic_delay.reset(extrinsic_queue.next_deadline().map(time_until));
 		}
+
+		if query_queue.next_deadline_changed() {
+			next_query_delay.reset(query_queue.next_deadline().map(time_until));
+		}
 	}
 }
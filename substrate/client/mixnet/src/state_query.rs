@@ -0,0 +1,225 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Receiver-side handling of state query requests. These let nodes read the chain state without
+//! revealing which keys or runtime calls they are interested in.
+
+use super::{
+	error::RemoteErr,
+	request::{STATE_CALL, STATE_GET_STORAGE},
+};
+use codec::{DecodeAll, Encode};
+use sc_client_api::{Backend, CallExecutor, ExecutorProvider, HeaderBackend, StorageProvider};
+use sp_core::{storage::StorageKey, traits::CallContext};
+use sp_runtime::traits::Block;
+
+/// Conservative estimate of the number of reply bytes carried by a single mixnet fragment: the
+/// 2 KiB fragment payload minus the fragment header.
+const REPLY_FRAGMENT_DATA_SIZE: usize = 2_000;
+
+/// Returns the maximum size in bytes of an encoded reply that fits in `max_fragments` fragments.
+pub fn max_reply_size(max_fragments: usize) -> usize {
+	max_fragments.saturating_mul(REPLY_FRAGMENT_DATA_SIZE)
+}
+
+/// A state query received from the mixnet. If no block hash is given, the query is executed at
+/// the best block.
+pub enum StateQuery<B: Block> {
+	Call { at: Option<B::Hash>, method: String, data: Vec<u8> },
+	GetStorage { at: Option<B::Hash>, key: Vec<u8> },
+}
+
+impl<B: Block> StateQuery<B> {
+	/// Decode a state query request. `kind` is the first byte of the request and `data` the rest.
+	pub fn decode(kind: u8, mut data: &[u8]) -> Result<Self, codec::Error> {
+		match kind {
+			STATE_CALL => {
+				let (at, method, data) = DecodeAll::decode_all(&mut data)?;
+				Ok(Self::Call { at, method, data })
+			},
+			STATE_GET_STORAGE => {
+				let (at, key) = DecodeAll::decode_all(&mut data)?;
+				Ok(Self::GetStorage { at, key })
+			},
+			_ => Err("Not a state query".into()),
+		}
+	}
+
+	/// Returns `Err` with the name of the called method if the query calls a runtime API method
+	/// which is not in `allowed_methods`. Storage reads are always allowed.
+	pub fn check_allowed(&self, allowed_methods: &[String]) -> Result<(), RemoteErr> {
+		match self {
+			Self::Call { method, .. } if !allowed_methods.contains(method) =>
+				Err(RemoteErr::Other(format!("Runtime API method {method} is not allowed"))),
+			_ => Ok(()),
+		}
+	}
+
+	/// Execute the query, returning the encoded reply. Replies larger than `max_reply_size` bytes
+	/// are replaced with a [`RemoteErr::ReplyTooLarge`] error.
+	///
+	/// This may run the runtime, so it should not be called from an async task.
+	pub fn execute<C, BE>(self, client: &C, max_reply_size: usize) -> Vec<u8>
+	where
+		C: HeaderBackend<B> + ExecutorProvider<B> + StorageProvider<B, BE>,
+		BE: Backend<B>,
+	{
+		let remote_err = |err: sc_client_api::blockchain::Error| RemoteErr::Other(err.to_string());
+		let reply = match self {
+			Self::Call { at, method, data } => {
+				let at = at.unwrap_or_else(|| client.info().best_hash);
+				let res = client.executor().call(at, &method, &data, CallContext::Offchain);
+				res.map_err(remote_err).encode()
+			},
+			Self::GetStorage { at, key } => {
+				let at = at.unwrap_or_else(|| client.info().best_hash);
+				let res = client.storage(at, &StorageKey(key));
+				res.map(|value| value.map(|value| value.0)).map_err(remote_err).encode()
+			},
+		};
+
+		if reply.len() > max_reply_size {
+			let size = reply.len().try_into().unwrap_or(u32::MAX);
+			return Err::<(), _>(RemoteErr::ReplyTooLarge(size)).encode()
+		}
+		reply
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{error::Error, request::Request};
+	use codec::Decode;
+	use futures::{channel::oneshot, executor::block_on};
+	use sp_core::H256;
+	use sp_runtime::testing::{Block as RawBlock, MockCallU64, TestXt};
+	use sp_version::RuntimeVersion;
+	use substrate_test_runtime_client::{prelude::*, runtime};
+
+	type Block = RawBlock<TestXt<MockCallU64, ()>>;
+
+	#[test]
+	fn decode_state_queries() {
+		let at = H256::repeat_byte(1);
+
+		let call = (Some(at), "Core_version", vec![1u8, 2]).encode();
+		assert!(matches!(
+			StateQuery::<Block>::decode(STATE_CALL, &call),
+			Ok(StateQuery::Call { at: Some(a), method, data })
+				if a == at && method == "Core_version" && data == vec![1, 2]
+		));
+
+		let get_storage = (None::<H256>, vec![3u8]).encode();
+		assert!(matches!(
+			StateQuery::<Block>::decode(STATE_GET_STORAGE, &get_storage),
+			Ok(StateQuery::GetStorage { at: None, key }) if key == vec![3]
+		));
+
+		// Trailing bytes and unknown request kinds are rejected.
+		let mut trailing = get_storage.clone();
+		trailing.push(0);
+		assert!(StateQuery::<Block>::decode(STATE_GET_STORAGE, &trailing).is_err());
+		assert!(StateQuery::<Block>::decode(STATE_CALL, &get_storage).is_err());
+		assert!(StateQuery::<Block>::decode(0, &get_storage).is_err());
+	}
+
+	#[test]
+	fn check_allowed_methods() {
+		let allowed = vec!["Core_version".to_string()];
+		let call = |method: &str| StateQuery::<Block>::Call {
+			at: None,
+			method: method.into(),
+			data: Vec::new(),
+		};
+
+		assert!(call("Core_version").check_allowed(&allowed).is_ok());
+		assert!(call("Core_execute_block").check_allowed(&allowed).is_err());
+		assert!(StateQuery::<Block>::GetStorage { at: None, key: Vec::new() }
+			.check_allowed(&allowed)
+			.is_ok());
+	}
+
+	/// Decode a query as encoded by the requester, execute it and feed the reply back to the
+	/// requester side.
+	fn round_trip<T: Decode>(
+		client: &TestClient,
+		kind: u8,
+		query: Vec<u8>,
+		max_reply_size: usize,
+		request: impl FnOnce(oneshot::Sender<Result<T, Error>>) -> Request,
+	) -> Result<T, Error> {
+		let (reply_sender, reply_receiver) = oneshot::channel();
+		let request = request(reply_sender);
+
+		let query = StateQuery::<runtime::Block>::decode(kind, &query).unwrap();
+		request.send_reply(&query.execute(client, max_reply_size));
+
+		block_on(reply_receiver).unwrap()
+	}
+
+	#[test]
+	fn state_query_round_trip() {
+		let client = TestClientBuilder::new().build();
+		let best_hash = client.info().best_hash;
+
+		let version = round_trip(
+			&client,
+			STATE_CALL,
+			(Some(best_hash), "Core_version", Vec::<u8>::new()).encode(),
+			max_reply_size(4),
+			|reply_sender| Request::StateCall {
+				at: Some(best_hash),
+				method: "Core_version".into(),
+				data: Vec::new().into(),
+				reply_sender,
+			},
+		)
+		.unwrap();
+		assert_eq!(
+			RuntimeVersion::decode(&mut &version[..]).unwrap(),
+			client.runtime_version_at(best_hash).unwrap(),
+		);
+
+		let key = sp_core::storage::well_known_keys::CODE.to_vec();
+		let code = round_trip(
+			&client,
+			STATE_GET_STORAGE,
+			(None::<H256>, key.clone()).encode(),
+			usize::MAX,
+			|reply_sender| Request::StateGetStorage {
+				at: None,
+				key: key.clone().into(),
+				reply_sender,
+			},
+		)
+		.unwrap();
+		assert_eq!(code, client.storage(best_hash, &StorageKey(key.clone())).unwrap().map(|c| c.0));
+
+		// The runtime code does not fit in a few fragments.
+		let err = round_trip(
+			&client,
+			STATE_GET_STORAGE,
+			(None::<H256>, key.clone()).encode(),
+			max_reply_size(4),
+			|reply_sender| Request::StateGetStorage { at: None, key: key.into(), reply_sender },
+		)
+		.unwrap_err();
+		assert!(matches!(err, Error::Remote(RemoteErr::ReplyTooLarge(_))));
+	}
+}
@@ -22,11 +22,27 @@ pub mod error;
 
 use error::Error;
 use jsonrpsee::proc_macros::rpc;
-use sp_core::Bytes;
+use sp_core::{Bytes, H256};
 
 #[rpc(client, server)]
 pub trait MixnetApi {
 	/// Submit encoded extrinsic over the mixnet for inclusion in block.
 	#[method(name = "mixnet_submitExtrinsic")]
 	async fn submit_extrinsic(&self, extrinsic: Bytes) -> Result<(), Error>;
+
+	/// Call a runtime function at a block over the mixnet, without revealing the call to the
+	/// nodes serving it. Returns the encoded result of the call.
+	#[method(name = "mixnet_stateCall")]
+	async fn state_call(
+		&self,
+		method: String,
+		data: Bytes,
+		at: Option<H256>,
+	) -> Result<Bytes, Error>;
+
+	/// Read a storage value at a block over the mixnet, without revealing the key to the nodes
+	/// serving it.
+	#[method(name = "mixnet_stateGetStorage")]
+	async fn state_get_storage(&self, key: Bytes, at: Option<H256>)
+		-> Result<Option<Bytes>, Error>;
 }
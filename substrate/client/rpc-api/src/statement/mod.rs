@@ -22,6 +22,9 @@ use jsonrpsee::{core::RpcResult, proc_macros::rpc};
 use sp_core::Bytes;
 
 pub mod error;
+pub mod subscription;
+
+pub use subscription::{StatementEvent, TopicFilter};
 
 /// Substrate statement RPC API
 #[rpc(client, server)]
@@ -92,4 +95,21 @@ pub trait StatementApi {
 	/// Remove a statement from the store.
 	#[method(name = "statement_remove")]
 	fn remove(&self, statement_hash: [u8; 32]) -> RpcResult<()>;
+
+	/// Subscribe to statements matching `topic_filter`. If `dest` is given, only statements whose
+	/// decryption key is identified as `dest` are matched, otherwise only statements that have no
+	/// `DecryptionKey` field.
+	///
+	/// All currently known matching statements are reported first, followed by statements newly
+	/// accepted by the store. Matching statements that are evicted or expired are reported as
+	/// well.
+	///
+	/// This method is unsafe, as the statements already known may be numerous.
+	#[subscription(
+		name = "statement_subscribeStatements" => "statement_statement",
+		unsubscribe = "statement_unsubscribeStatements",
+		item = StatementEvent,
+		with_extensions,
+	)]
+	fn subscribe_statements(&self, topic_filter: TopicFilter, dest: Option<[u8; 32]>);
 }
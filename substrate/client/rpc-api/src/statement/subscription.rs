@@ -0,0 +1,79 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Types for statement store subscriptions.
+
+use serde::{Deserialize, Serialize};
+use sp_core::Bytes;
+
+/// Topic filter of a statement subscription.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TopicFilter {
+	/// Match statements regardless of their topics.
+	Any,
+	/// Match statements which include all of the given topics.
+	MatchAll(Vec<[u8; 32]>),
+	/// Match statements which include at least one of the given topics.
+	MatchAny(Vec<[u8; 32]>),
+}
+
+/// Statement subscription event.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "event")]
+pub enum StatementEvent {
+	/// A SCALE-encoded statement matching the filter. Reported for statements known when the
+	/// subscription started and for newly accepted ones.
+	New {
+		/// The SCALE-encoded statement.
+		statement: Bytes,
+	},
+	/// A matching statement was evicted to make room for a higher priority statement.
+	Evicted {
+		/// Hash of the evicted statement.
+		hash: [u8; 32],
+	},
+	/// A matching statement was removed from the store and marked as expired.
+	Expired {
+		/// Hash of the expired statement.
+		hash: [u8; 32],
+	},
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn topic_filter_serialize_works() {
+		assert_eq!(serde_json::to_string(&TopicFilter::Any).unwrap(), r#""any""#);
+		let filter = TopicFilter::MatchAll(vec![[1u8; 32]]);
+		let json = serde_json::to_string(&filter).unwrap();
+		assert!(json.starts_with(r#"{"matchAll":[[1,1,"#));
+		assert_eq!(serde_json::from_str::<TopicFilter>(&json).unwrap(), filter);
+	}
+
+	#[test]
+	fn statement_event_serialize_works() {
+		let event = StatementEvent::New { statement: vec![1, 2, 3].into() };
+		assert_eq!(
+			serde_json::to_string(&event).unwrap(),
+			r#"{"event":"new","statement":"0x010203"}"#
+		);
+	}
+}
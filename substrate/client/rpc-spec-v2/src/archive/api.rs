@@ -24,7 +24,8 @@ use crate::{
 		types::MethodResult,
 	},
 	common::events::{
-		ArchiveStorageDiffEvent, ArchiveStorageDiffItem, ArchiveStorageEvent, StorageQuery,
+		ArchiveKeyHistoryEvent, ArchiveStorageDiffEvent, ArchiveStorageDiffItem,
+		ArchiveStorageEvent, StorageQuery,
 	},
 };
 use jsonrpsee::proc_macros::rpc;
@@ -130,4 +131,26 @@ pub trait ArchiveApi<Hash> {
 		items: Vec<ArchiveStorageDiffItem<String>>,
 		previous_hash: Option<Hash>,
 	);
+
+	/// Returns the values of a key written by the canonical blocks between `from_height` and
+	/// `to_height` (inclusive).
+	///
+	/// Requires the node to maintain the key changes index, otherwise a `keyHistoryError` event
+	/// is generated. A single query can cover at most 100 000 blocks.
+	///
+	/// # Unstable
+	///
+	/// This method is unstable and can change in minor or patch releases.
+	#[subscription(
+		name = "archive_v1_keyHistory" => "archive_v1_keyHistoryEvent",
+		unsubscribe = "archive_v1_stopKeyHistory",
+		item = ArchiveKeyHistoryEvent,
+	)]
+	fn archive_v1_key_history(
+		&self,
+		key: String,
+		from_height: u64,
+		to_height: u64,
+		child_trie: Option<String>,
+	);
 }
@@ -20,14 +20,15 @@
 
 use crate::{
 	archive::{
-		archive_storage::ArchiveStorageDiff,
+		archive_storage::{ArchiveKeyHistory, ArchiveStorageDiff},
 		error::{Error as ArchiveError, Infallible},
 		types::MethodResult,
 		ArchiveApiServer,
 	},
 	common::{
 		events::{
-			ArchiveStorageDiffEvent, ArchiveStorageDiffItem, ArchiveStorageEvent, StorageQuery,
+			ArchiveKeyHistoryEvent, ArchiveStorageDiffEvent, ArchiveStorageDiffItem,
+			ArchiveStorageEvent, StorageQuery,
 		},
 		storage::{QueryResult, StorageSubscriptionClient},
 	},
@@ -63,6 +64,9 @@ pub(crate) const LOG_TARGET: &str = "rpc-spec-v2::archive";
 /// its down buffer capacity per connection as well.
 const STORAGE_QUERY_BUF: usize = 16;
 
+/// The maximum number of blocks covered by a single `archive_keyHistory` query.
+const KEY_HISTORY_MAX_RANGE: u64 = 100_000;
+
 /// An API for archive RPC calls.
 pub struct Archive<BE: Backend<Block>, Block: BlockT, Client> {
 	/// Substrate client.
@@ -102,6 +106,40 @@ fn parse_hex_param(param: String) -> Result<Vec<u8>, ArchiveError> {
 	array_bytes::hex2bytes(&param).map_err(|_| ArchiveError::InvalidParam(param))
 }
 
+/// Parse the block height parameter as a block number.
+fn parse_height<Block: BlockT>(height: u64) -> Result<NumberFor<Block>, ArchiveError> {
+	U256::from(height)
+		.try_into()
+		.map_err(|_| ArchiveError::InvalidParam(format!("Invalid block height: {}", height)))
+}
+
+/// Parse the parameters of the `archive_keyHistory` method.
+fn parse_key_history_params<Block: BlockT>(
+	key: String,
+	from_height: u64,
+	to_height: u64,
+	child_trie: Option<String>,
+) -> Result<(StorageKey, Option<ChildInfo>, NumberFor<Block>, NumberFor<Block>), ArchiveError> {
+	let key = StorageKey(parse_hex_param(key)?);
+	let child_trie = child_trie
+		.map(|child_trie| parse_hex_param(child_trie))
+		.transpose()?
+		.map(ChildInfo::new_default_from_vec);
+
+	if from_height > to_height {
+		return Err(ArchiveError::InvalidParam(format!(
+			"Invalid block range: {from_height} > {to_height}"
+		)))
+	}
+	if to_height - from_height >= KEY_HISTORY_MAX_RANGE {
+		return Err(ArchiveError::InvalidParam(format!(
+			"Block range exceeds the maximum of {KEY_HISTORY_MAX_RANGE} blocks"
+		)))
+	}
+
+	Ok((key, child_trie, parse_height::<Block>(from_height)?, parse_height::<Block>(to_height)?))
+}
+
 #[async_trait]
 impl<BE, Block, Client> ArchiveApiServer<Block::Hash> for Archive<BE, Block, Client>
 where
@@ -145,9 +183,7 @@ where
 	}
 
 	fn archive_v1_hash_by_height(&self, height: u64) -> Result<Vec<String>, ArchiveError> {
-		let height: NumberFor<Block> = U256::from(height)
-			.try_into()
-			.map_err(|_| ArchiveError::InvalidParam(format!("Invalid block height: {}", height)))?;
+		let height = parse_height::<Block>(height)?;
 
 		let finalized_num = self.client.info().finalized_number;
 
@@ -273,6 +309,7 @@ where
 		previous_hash: Option<Block::Hash>,
 	) {
 		let storage_client = ArchiveStorageDiff::new(self.client.clone());
+		let key_changes = self.backend.key_changes_index();
 		let client = self.client.clone();
 
 		log::trace!(target: LOG_TARGET, "Storage diff subscription started");
@@ -280,15 +317,25 @@ where
 		let fut = async move {
 			let Ok(mut sink) = pending.accept().await.map(Subscription::from) else { return };
 
-			let previous_hash = if let Some(previous_hash) = previous_hash {
-				previous_hash
+			let (previous_hash, is_parent) = if let Some(previous_hash) = previous_hash {
+				let is_parent = matches!(
+					client.header(hash),
+					Ok(Some(header)) if *header.parent_hash() == previous_hash
+				);
+				(previous_hash, is_parent)
 			} else {
 				let Ok(Some(current_header)) = client.header(hash) else {
 					let message = format!("Block header is not present: {hash}");
 					let _ = sink.send(&ArchiveStorageDiffEvent::err(message)).await;
 					return
 				};
-				*current_header.parent_hash()
+				(*current_header.parent_hash(), true)
+			};
+
+			// The key changes index records the changes relative to the parent block.
+			let storage_client = match key_changes {
+				Some(key_changes) if is_parent => storage_client.with_key_changes(key_changes),
+				_ => storage_client,
 			};
 
 			let (tx, mut rx) = tokio::sync::mpsc::channel(STORAGE_QUERY_BUF);
@@ -306,6 +353,79 @@ where
 
 		self.executor.spawn("substrate-rpc-subscription", Some("rpc"), fut.boxed());
 	}
+
+	fn archive_v1_key_history(
+		&self,
+		pending: PendingSubscriptionSink,
+		key: String,
+		from_height: u64,
+		to_height: u64,
+		child_trie: Option<String>,
+	) {
+		let key_changes = self.backend.key_changes_index();
+		let client = self.client.clone();
+
+		let fut = async move {
+			let Ok(mut sink) = pending.accept().await.map(Subscription::from) else { return };
+
+			let Some(key_changes) = key_changes else {
+				let message = "The key changes index is not enabled".to_string();
+				let _ = sink.send(&ArchiveKeyHistoryEvent::err(message)).await;
+				return
+			};
+
+			let (key, child_trie, from, to) =
+				match parse_key_history_params::<Block>(key, from_height, to_height, child_trie) {
+					Ok(params) => params,
+					Err(error) => {
+						let _ = sink.send(&ArchiveKeyHistoryEvent::err(error.to_string())).await;
+						return
+					},
+				};
+
+			let (tx, mut rx) = tokio::sync::mpsc::channel(STORAGE_QUERY_BUF);
+			let history_fut = ArchiveKeyHistory::<Client, Block, BE>::new(client, key_changes)
+				.handle_query(key, child_trie, from, to, tx);
+
+			// We don't care about the return value of this join, errors are propagated via the
+			// sink.
+			let _ =
+				futures::future::join(history_fut, process_key_history_events(&mut rx, &mut sink))
+					.await;
+		};
+
+		self.executor.spawn("substrate-rpc-subscription", Some("rpc"), fut.boxed());
+	}
+}
+
+/// Sends all the events of the key_history method to the sink.
+async fn process_key_history_events(
+	rx: &mut mpsc::Receiver<ArchiveKeyHistoryEvent>,
+	sink: &mut Subscription,
+) {
+	loop {
+		tokio::select! {
+			_ = sink.closed() => {
+				return
+			},
+
+			maybe_event = rx.recv() => {
+				let Some(event) = maybe_event else {
+					break;
+				};
+
+				if event.is_done() {
+					log::debug!(target: LOG_TARGET, "Finished processing key history query");
+				} else if event.is_err() {
+					log::debug!(target: LOG_TARGET, "Error encountered while processing key history query");
+				}
+
+				if sink.send(&event).await.is_err() {
+					return
+				}
+			}
+		}
+	}
 }
 
 /// Sends all the events of the storage_diff method to the sink.
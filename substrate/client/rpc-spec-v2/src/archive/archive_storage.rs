@@ -20,23 +20,30 @@
 
 use std::{
 	collections::{hash_map::Entry, HashMap},
+	marker::PhantomData,
 	sync::Arc,
 };
 
 use itertools::Itertools;
-use sc_client_api::{Backend, ChildInfo, StorageKey, StorageProvider};
-use sp_runtime::traits::Block as BlockT;
+use sc_client_api::{Backend, ChildInfo, KeyChangesIndex, StorageKey, StorageProvider};
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{
+	traits::{Block as BlockT, NumberFor},
+	SaturatedConversion,
+};
 
 use super::error::Error as ArchiveError;
 use crate::{
 	archive::archive::LOG_TARGET,
 	common::{
 		events::{
-			ArchiveStorageDiffEvent, ArchiveStorageDiffItem, ArchiveStorageDiffOperationType,
-			ArchiveStorageDiffResult, ArchiveStorageDiffType, StorageResult,
+			ArchiveKeyHistoryEvent, ArchiveKeyHistoryResult, ArchiveStorageDiffEvent,
+			ArchiveStorageDiffItem, ArchiveStorageDiffOperationType, ArchiveStorageDiffResult,
+			ArchiveStorageDiffType, StorageResult,
 		},
 		storage::Storage,
 	},
+	hex_string,
 };
 use tokio::sync::mpsc;
 
@@ -82,13 +89,26 @@ enum FetchedStorage {
 	Both { value: StorageResult, hash: StorageResult },
 }
 
-pub struct ArchiveStorageDiff<Client, Block, BE> {
+pub struct ArchiveStorageDiff<Client, Block: BlockT, BE> {
 	client: Storage<Client, Block, BE>,
+	/// Index of the keys changed by every block.
+	///
+	/// Only provided when the diff is computed against the parent block.
+	key_changes: Option<Arc<dyn KeyChangesIndex<Block>>>,
 }
 
-impl<Client, Block, BE> ArchiveStorageDiff<Client, Block, BE> {
+impl<Client, Block: BlockT, BE> ArchiveStorageDiff<Client, Block, BE> {
 	pub fn new(client: Arc<Client>) -> Self {
-		Self { client: Storage::new(client) }
+		Self { client: Storage::new(client), key_changes: None }
+	}
+
+	/// Use the provided index to find the changed keys instead of walking the tries.
+	///
+	/// The index records the keys changed relative to the parent block, so it must only be
+	/// provided when diffing a block against its parent.
+	pub fn with_key_changes(mut self, key_changes: Arc<dyn KeyChangesIndex<Block>>) -> Self {
+		self.key_changes = Some(key_changes);
+		self
 	}
 }
 
@@ -199,6 +219,118 @@ where
 		true
 	}
 
+	/// Fetch the storage of the key and send it as a diff of the given operation type.
+	///
+	/// Returns `false` if the sender has been closed.
+	#[allow(clippy::too_many_arguments)]
+	fn send_diff(
+		&self,
+		hash: Block::Hash,
+		previous_hash: Block::Hash,
+		key: StorageKey,
+		operation_type: ArchiveStorageDiffOperationType,
+		fetch_type: FetchStorageType,
+		maybe_child_trie: Option<ChildInfo>,
+		maybe_child_trie_str: Option<String>,
+		tx: &mpsc::Sender<ArchiveStorageDiffEvent>,
+	) -> Result<bool, String> {
+		let maybe_result = match operation_type {
+			ArchiveStorageDiffOperationType::Added =>
+				self.fetch_storage(hash, key, maybe_child_trie, fetch_type)?,
+			ArchiveStorageDiffOperationType::Deleted =>
+				self.fetch_storage(previous_hash, key, maybe_child_trie, fetch_type)?,
+			ArchiveStorageDiffOperationType::Modified => {
+				let Some(storage_result) =
+					self.fetch_storage(hash, key.clone(), maybe_child_trie.clone(), fetch_type)?
+				else {
+					return Ok(true)
+				};
+
+				let Some(previous_storage_result) =
+					self.fetch_storage(previous_hash, key, maybe_child_trie, fetch_type)?
+				else {
+					return Ok(true)
+				};
+
+				// For modified records we need to check the actual storage values.
+				if storage_result == previous_storage_result {
+					return Ok(true)
+				}
+
+				Some(storage_result)
+			},
+		};
+
+		Ok(match maybe_result {
+			Some(storage_result) =>
+				Self::send_result(tx, storage_result, operation_type, maybe_child_trie_str),
+			None => true,
+		})
+	}
+
+	/// Produce the diff from the keys recorded by the key changes index.
+	///
+	/// Returns `false` if the block is not covered by the index.
+	fn handle_indexed_queries(
+		&self,
+		hash: Block::Hash,
+		previous_hash: Block::Hash,
+		items: &[DiffDetails],
+		maybe_child_trie: Option<ChildInfo>,
+		maybe_child_trie_str: Option<String>,
+		tx: &mpsc::Sender<ArchiveStorageDiffEvent>,
+	) -> Result<bool, String> {
+		let Some(key_changes) = &self.key_changes else { return Ok(false) };
+		let Some(changed_keys) = key_changes
+			.changed_keys(hash, maybe_child_trie.as_ref())
+			.map_err(|error| error.to_string())?
+		else {
+			return Ok(false)
+		};
+
+		log::trace!(
+			target: LOG_TARGET,
+			"handle_indexed_queries: hash={:?}, changed_keys={}",
+			hash,
+			changed_keys.len()
+		);
+
+		for key in changed_keys {
+			let Some(fetch_type) = Self::belongs_to_query(&key, items) else {
+				// The key does not belong the the query items.
+				continue;
+			};
+
+			let exists = self.client.query_hash(hash, &key, maybe_child_trie.as_ref())?.is_some();
+			let existed = self
+				.client
+				.query_hash(previous_hash, &key, maybe_child_trie.as_ref())?
+				.is_some();
+			let operation_type = match (exists, existed) {
+				(true, false) => ArchiveStorageDiffOperationType::Added,
+				(false, true) => ArchiveStorageDiffOperationType::Deleted,
+				(true, true) => ArchiveStorageDiffOperationType::Modified,
+				// Inserted and removed within the same block.
+				(false, false) => continue,
+			};
+
+			if !self.send_diff(
+				hash,
+				previous_hash,
+				key,
+				operation_type,
+				fetch_type,
+				maybe_child_trie.clone(),
+				maybe_child_trie_str.clone(),
+				tx,
+			)? {
+				break
+			}
+		}
+
+		Ok(true)
+	}
+
 	fn handle_trie_queries_inner(
 		&self,
 		hash: Block::Hash,
@@ -211,6 +343,17 @@ where
 		let maybe_child_trie_str =
 			items.first().and_then(|item| item.child_trie_key_string.clone());
 
+		if self.handle_indexed_queries(
+			hash,
+			previous_hash,
+			&items,
+			maybe_child_trie.clone(),
+			maybe_child_trie_str.clone(),
+			tx,
+		)? {
+			return Ok(())
+		}
+
 		// Iterator over the current block and previous block
 		// at the same time to compare the keys. This approach effectively
 		// leverages backpressure to avoid memory consumption.
@@ -232,54 +375,17 @@ where
 				continue;
 			};
 
-			let maybe_result = match operation_type {
-				ArchiveStorageDiffOperationType::Added =>
-					self.fetch_storage(hash, key.clone(), maybe_child_trie.clone(), fetch_type)?,
-				ArchiveStorageDiffOperationType::Deleted => self.fetch_storage(
-					previous_hash,
-					key.clone(),
-					maybe_child_trie.clone(),
-					fetch_type,
-				)?,
-				ArchiveStorageDiffOperationType::Modified => {
-					let Some(storage_result) = self.fetch_storage(
-						hash,
-						key.clone(),
-						maybe_child_trie.clone(),
-						fetch_type,
-					)?
-					else {
-						continue
-					};
-
-					let Some(previous_storage_result) = self.fetch_storage(
-						previous_hash,
-						key.clone(),
-						maybe_child_trie.clone(),
-						fetch_type,
-					)?
-					else {
-						continue
-					};
-
-					// For modified records we need to check the actual storage values.
-					if storage_result == previous_storage_result {
-						continue
-					}
-
-					Some(storage_result)
-				},
-			};
-
-			if let Some(storage_result) = maybe_result {
-				if !Self::send_result(
-					&tx,
-					storage_result,
-					operation_type,
-					maybe_child_trie_str.clone(),
-				) {
-					return Ok(())
-				}
+			if !self.send_diff(
+				hash,
+				previous_hash,
+				key,
+				operation_type,
+				fetch_type,
+				maybe_child_trie.clone(),
+				maybe_child_trie_str.clone(),
+				tx,
+			)? {
+				return Ok(())
 			}
 		}
 
@@ -296,7 +402,10 @@ where
 		previous_hash: Block::Hash,
 		tx: mpsc::Sender<ArchiveStorageDiffEvent>,
 	) -> Result<(), tokio::task::JoinError> {
-		let this = ArchiveStorageDiff { client: self.client.clone() };
+		let this = ArchiveStorageDiff {
+			client: self.client.clone(),
+			key_changes: self.key_changes.clone(),
+		};
 
 		tokio::task::spawn_blocking(move || {
 			// Deduplicate the items.
@@ -350,6 +459,155 @@ where
 	}
 }
 
+/// The number of blocks read at once from the key histories by `archive_keyHistory`.
+const KEY_HISTORY_STEP: u64 = 1024;
+
+/// Implementation of the `archive_keyHistory` method.
+pub struct ArchiveKeyHistory<Client, Block: BlockT, BE> {
+	client: Arc<Client>,
+	key_changes: Arc<dyn KeyChangesIndex<Block>>,
+	_phantom: PhantomData<BE>,
+}
+
+impl<Client, Block: BlockT, BE> ArchiveKeyHistory<Client, Block, BE> {
+	pub fn new(client: Arc<Client>, key_changes: Arc<dyn KeyChangesIndex<Block>>) -> Self {
+		Self { client, key_changes, _phantom: PhantomData }
+	}
+}
+
+impl<Client, Block, BE> ArchiveKeyHistory<Client, Block, BE>
+where
+	Block: BlockT + 'static,
+	BE: Backend<Block> + 'static,
+	Client: StorageProvider<Block, BE> + HeaderBackend<Block> + 'static,
+{
+	fn handle_query_inner(
+		&self,
+		key: &StorageKey,
+		child_trie: Option<&ChildInfo>,
+		from: NumberFor<Block>,
+		to: NumberFor<Block>,
+		tx: &mpsc::Sender<ArchiveKeyHistoryEvent>,
+	) -> Result<(), String> {
+		let (from, to): (u64, u64) = (from.saturated_into(), to.saturated_into());
+		let last_finalized: Option<u64> = self
+			.key_changes
+			.last_finalized()
+			.map_err(|error| error.to_string())?
+			.map(|number| number.saturated_into());
+
+		let mut last_value = None;
+		let mut next = from;
+
+		// The finalized blocks are read from the key histories one step at a time, so that the
+		// changes are sent as soon as they are found.
+		while let Some(last_finalized) = last_finalized.filter(|last| next <= to.min(*last)) {
+			let end = to.min(last_finalized).min(next.saturating_add(KEY_HISTORY_STEP - 1));
+			let Some(changes) = self
+				.key_changes
+				.key_changes(key, child_trie, next.saturated_into(), end.saturated_into())
+				.map_err(|error| error.to_string())?
+			else {
+				return Err(format!("Block {next} is not covered by the key changes index"))
+			};
+
+			for (number, hash) in changes {
+				if !self.send_value(key, child_trie, number, hash, &mut last_value, tx)? {
+					return Ok(())
+				}
+			}
+			next = end + 1;
+		}
+
+		// The blocks that are not finalized yet are looked up on the best chain.
+		while next <= to {
+			let number = next.saturated_into();
+			let Some(hash) = self.client.hash(number).map_err(|error| error.to_string())? else {
+				break
+			};
+			let Some(changed) = self
+				.key_changes
+				.changed_keys(hash, child_trie)
+				.map_err(|error| error.to_string())?
+			else {
+				return Err(format!("Block {next} is not covered by the key changes index"))
+			};
+
+			if changed.binary_search(key).is_ok() &&
+				!self.send_value(key, child_trie, number, hash, &mut last_value, tx)?
+			{
+				return Ok(())
+			}
+			next += 1;
+		}
+
+		let _ = tx.blocking_send(ArchiveKeyHistoryEvent::KeyHistoryDone);
+		Ok(())
+	}
+
+	/// Send the value of the key at the block, unless it is the same as the `last_value` sent.
+	///
+	/// Returns `false` if the receiver was dropped.
+	fn send_value(
+		&self,
+		key: &StorageKey,
+		child_trie: Option<&ChildInfo>,
+		number: NumberFor<Block>,
+		hash: Block::Hash,
+		last_value: &mut Option<Option<String>>,
+		tx: &mpsc::Sender<ArchiveKeyHistoryEvent>,
+	) -> Result<bool, String> {
+		let value = match child_trie {
+			Some(child_trie) => self.client.child_storage(hash, child_trie, key),
+			None => self.client.storage(hash, key),
+		}
+		.map_err(|error| error.to_string())?
+		.map(|data| hex_string(&data.0));
+
+		// Writes that left the value unchanged are not reported.
+		if last_value.as_ref() == Some(&value) {
+			return Ok(true)
+		}
+		*last_value = Some(value.clone());
+
+		let event = ArchiveKeyHistoryEvent::KeyHistory(ArchiveKeyHistoryResult {
+			block_hash: hex_string(&hash.as_ref()),
+			block_number: number.saturated_into(),
+			value,
+		});
+		Ok(tx.blocking_send(event).is_ok())
+	}
+
+	/// Fetch the values of the key written by the canonical blocks in the `from..=to` range.
+	///
+	/// The values are sent to the provided `tx` sender to leverage the backpressure mechanism.
+	pub async fn handle_query(
+		self,
+		key: StorageKey,
+		child_trie: Option<ChildInfo>,
+		from: NumberFor<Block>,
+		to: NumberFor<Block>,
+		tx: mpsc::Sender<ArchiveKeyHistoryEvent>,
+	) -> Result<(), tokio::task::JoinError> {
+		tokio::task::spawn_blocking(move || {
+			log::trace!(
+				target: LOG_TARGET,
+				"handle_query: key={:?}, from={:?}, to={:?}",
+				key,
+				from,
+				to
+			);
+
+			if let Err(error) = self.handle_query_inner(&key, child_trie.as_ref(), from, to, &tx) {
+				log::trace!(target: LOG_TARGET, "handle_query: sending error={:?}", error);
+
+				let _ = tx.blocking_send(ArchiveKeyHistoryEvent::err(error));
+			}
+		})
+		.await
+	}
+}
+
 /// The result of the `lexicographic_diff` method.
 #[derive(Debug, PartialEq)]
 enum Diff<T> {
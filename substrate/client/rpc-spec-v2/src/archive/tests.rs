@@ -19,9 +19,10 @@
 use crate::{
 	archive::MethodResult,
 	common::events::{
-		ArchiveStorageDiffEvent, ArchiveStorageDiffItem, ArchiveStorageDiffOperationType,
-		ArchiveStorageDiffResult, ArchiveStorageDiffType, ArchiveStorageEvent, StorageQuery,
-		StorageQueryType, StorageResult, StorageResultType,
+		ArchiveKeyHistoryEvent, ArchiveKeyHistoryResult, ArchiveStorageDiffEvent,
+		ArchiveStorageDiffItem, ArchiveStorageDiffOperationType, ArchiveStorageDiffResult,
+		ArchiveStorageDiffType, ArchiveStorageEvent, StorageQuery, StorageQueryType, StorageResult,
+		StorageResultType,
 	},
 	hex_string,
 };
@@ -48,7 +49,7 @@ use sp_runtime::{
 use std::{collections::HashMap, sync::Arc};
 use substrate_test_runtime::Transfer;
 use substrate_test_runtime_client::{
-	prelude::*, runtime, Backend, BlockBuilderExt, Client, ClientBlockImportExt,
+	prelude::*, runtime, Backend, BlockBuilderExt, BlocksPruning, Client, ClientBlockImportExt,
 };
 
 const CHAIN_GENESIS: [u8; 32] = [0; 32];
@@ -999,3 +1000,85 @@ async fn archive_storage_diff_invalid_params() {
 		ArchiveStorageDiffEvent::StorageDiffError(ref err) if err.error.contains("Header was not found")
 	);
 }
+
+#[tokio::test]
+async fn archive_key_history_without_index() {
+	let (_, api) = setup_api();
+
+	let mut sub = api
+		.subscribe_unbounded("archive_v1_keyHistory", rpc_params![hex_string(&KEY), 0, 1])
+		.await
+		.unwrap();
+
+	let event = get_next_event::<ArchiveKeyHistoryEvent>(&mut sub).await;
+	assert_matches!(event,
+		ArchiveKeyHistoryEvent::KeyHistoryError(ref err) if err.error.contains("index is not enabled")
+	);
+}
+
+#[tokio::test]
+async fn archive_key_history() {
+	let backend = Arc::new(Backend::new_test_with_key_changes_index(BlocksPruning::KeepAll, 0));
+	let client = Arc::new(TestClientBuilder::with_backend(backend.clone()).build());
+	let api = Archive::new(
+		client.clone(),
+		backend,
+		CHAIN_GENESIS,
+		Arc::new(TokioTestExecutor::default()),
+	)
+	.into_rpc();
+
+	// Block 1 sets the key, block 2 leaves it untouched and block 3 removes it.
+	let mut hashes = vec![client.chain_info().genesis_hash];
+	for (number, change) in [(1, Some(Some(VALUE.to_vec()))), (2, None), (3, Some(None))] {
+		let mut builder = BlockBuilderBuilder::new(&*client)
+			.on_parent_block(hashes[number - 1])
+			.with_parent_block_number(number as u64 - 1)
+			.build()
+			.unwrap();
+		if let Some(value) = change {
+			builder.push_storage_change(KEY.to_vec(), value).unwrap();
+		}
+		let block = builder.build().unwrap().block;
+		hashes.push(block.header.hash());
+		client.import(BlockOrigin::Own, block).await.unwrap();
+	}
+	// Blocks 1 and 2 are read from the key histories, block 3 from its changed keys.
+	client.finalize_block(hashes[2], None).unwrap();
+
+	let mut sub = api
+		.subscribe_unbounded("archive_v1_keyHistory", rpc_params![hex_string(&KEY), 1, 3])
+		.await
+		.unwrap();
+
+	assert_eq!(
+		get_next_event::<ArchiveKeyHistoryEvent>(&mut sub).await,
+		ArchiveKeyHistoryEvent::KeyHistory(ArchiveKeyHistoryResult {
+			block_hash: hex_string(&hashes[1].as_ref()),
+			block_number: 1,
+			value: Some(hex_string(&VALUE)),
+		}),
+	);
+	assert_eq!(
+		get_next_event::<ArchiveKeyHistoryEvent>(&mut sub).await,
+		ArchiveKeyHistoryEvent::KeyHistory(ArchiveKeyHistoryResult {
+			block_hash: hex_string(&hashes[3].as_ref()),
+			block_number: 3,
+			value: None,
+		}),
+	);
+	assert_eq!(
+		get_next_event::<ArchiveKeyHistoryEvent>(&mut sub).await,
+		ArchiveKeyHistoryEvent::KeyHistoryDone,
+	);
+
+	// The block range is capped.
+	let mut sub = api
+		.subscribe_unbounded("archive_v1_keyHistory", rpc_params![hex_string(&KEY), 0, 100_000])
+		.await
+		.unwrap();
+	let event = get_next_event::<ArchiveKeyHistoryEvent>(&mut sub).await;
+	assert_matches!(event,
+		ArchiveKeyHistoryEvent::KeyHistoryError(ref err) if err.error.contains("exceeds the maximum")
+	);
+}
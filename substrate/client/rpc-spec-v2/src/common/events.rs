@@ -262,6 +262,53 @@ impl ArchiveStorageDiffEvent {
 	}
 }
 
+/// The value of a key written by a block, reported by the `archive_keyHistory` method.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveKeyHistoryResult {
+	/// The hex-encoded hash of the block.
+	pub block_hash: String,
+	/// The height of the block.
+	pub block_number: u64,
+	/// The hex-encoded value of the key after the block, or `None` if the key was removed.
+	pub value: Option<String>,
+}
+
+/// The event generated by the `archive_keyHistory` method.
+///
+/// The `archive_keyHistory` can generate the following events:
+///  - `keyHistory` event - generated for every block that changed the value of the key.
+///  - `keyHistoryError` event - generated when an error is produced.
+///  - `keyHistoryDone` event - generated when the `archive_keyHistory` method completed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "event")]
+pub enum ArchiveKeyHistoryEvent {
+	/// The `keyHistory` event.
+	KeyHistory(ArchiveKeyHistoryResult),
+	/// The `keyHistoryError` event.
+	KeyHistoryError(ArchiveStorageMethodErr),
+	/// The `keyHistoryDone` event.
+	KeyHistoryDone,
+}
+
+impl ArchiveKeyHistoryEvent {
+	/// Create a new `ArchiveKeyHistoryEvent::KeyHistoryError` event.
+	pub fn err(error: String) -> Self {
+		Self::KeyHistoryError(ArchiveStorageMethodErr { error })
+	}
+
+	/// Checks if the event is a `KeyHistoryDone` event.
+	pub fn is_done(&self) -> bool {
+		matches!(self, Self::KeyHistoryDone)
+	}
+
+	/// Checks if the event is a `KeyHistoryError` event.
+	pub fn is_err(&self) -> bool {
+		matches!(self, Self::KeyHistoryError(_))
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -503,4 +550,52 @@ mod tests {
 		let dec: PaginatedStorageQuery<&str> = serde_json::from_str(exp).unwrap();
 		assert_eq!(dec, item);
 	}
+
+	#[test]
+	fn archive_key_history_event() {
+		let event = ArchiveKeyHistoryEvent::KeyHistory(ArchiveKeyHistoryResult {
+			block_hash: "0x1".into(),
+			block_number: 2,
+			value: Some("0x3".into()),
+		});
+		// Encode
+		let ser = serde_json::to_string(&event).unwrap();
+		let exp = r#"{"event":"keyHistory","blockHash":"0x1","blockNumber":2,"value":"0x3"}"#;
+		assert_eq!(ser, exp);
+		// Decode
+		let dec: ArchiveKeyHistoryEvent = serde_json::from_str(exp).unwrap();
+		assert_eq!(dec, event);
+
+		// Removed key.
+		let event = ArchiveKeyHistoryEvent::KeyHistory(ArchiveKeyHistoryResult {
+			block_hash: "0x1".into(),
+			block_number: 2,
+			value: None,
+		});
+		// Encode
+		let ser = serde_json::to_string(&event).unwrap();
+		let exp = r#"{"event":"keyHistory","blockHash":"0x1","blockNumber":2,"value":null}"#;
+		assert_eq!(ser, exp);
+		// Decode
+		let dec: ArchiveKeyHistoryEvent = serde_json::from_str(exp).unwrap();
+		assert_eq!(dec, event);
+
+		let event = ArchiveKeyHistoryEvent::err("error".into());
+		// Encode
+		let ser = serde_json::to_string(&event).unwrap();
+		let exp = r#"{"event":"keyHistoryError","error":"error"}"#;
+		assert_eq!(ser, exp);
+		// Decode
+		let dec: ArchiveKeyHistoryEvent = serde_json::from_str(exp).unwrap();
+		assert_eq!(dec, event);
+
+		let event = ArchiveKeyHistoryEvent::KeyHistoryDone;
+		// Encode
+		let ser = serde_json::to_string(&event).unwrap();
+		let exp = r#"{"event":"keyHistoryDone"}"#;
+		assert_eq!(ser, exp);
+		// Decode
+		let dec: ArchiveKeyHistoryEvent = serde_json::from_str(exp).unwrap();
+		assert_eq!(dec, event);
+	}
 }
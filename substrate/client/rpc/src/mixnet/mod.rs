@@ -22,7 +22,7 @@ use jsonrpsee::core::async_trait;
 use sc_mixnet::Api;
 use sc_rpc_api::mixnet::error::Error;
 pub use sc_rpc_api::mixnet::MixnetApiServer;
-use sp_core::Bytes;
+use sp_core::{Bytes, H256};
 
 /// Mixnet API.
 pub struct Mixnet(futures::lock::Mutex<Api>);
@@ -44,4 +44,29 @@ impl MixnetApiServer for Mixnet {
 		};
 		Ok(fut.await.map_err(Error)?)
 	}
+
+	async fn state_call(
+		&self,
+		method: String,
+		data: Bytes,
+		at: Option<H256>,
+	) -> Result<Bytes, Error> {
+		let fut = {
+			let mut api = self.0.lock().await;
+			api.state_call(at, method, data).await
+		};
+		Ok(fut.await.map_err(Error)?)
+	}
+
+	async fn state_get_storage(
+		&self,
+		key: Bytes,
+		at: Option<H256>,
+	) -> Result<Option<Bytes>, Error> {
+		let fut = {
+			let mut api = self.0.lock().await;
+			api.state_get_storage(at, key).await
+		};
+		Ok(fut.await.map_err(Error)?)
+	}
 }
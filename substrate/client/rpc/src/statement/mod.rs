@@ -18,26 +18,50 @@
 
 //! Substrate statement store API.
 
+use crate::{
+	utils::{spawn_subscription_task, BoundedVecDeque, Subscription},
+	SubscriptionTaskExecutor,
+};
 use codec::{Decode, Encode};
+use futures::StreamExt;
 use jsonrpsee::{
 	core::{async_trait, RpcResult},
-	Extensions,
+	types::ErrorObject,
+	Extensions, PendingSubscriptionSink,
 };
 /// Re-export the API for backward compatibility.
 pub use sc_rpc_api::statement::{error::Error, StatementApiServer};
+use sc_rpc_api::statement::{StatementEvent, TopicFilter};
 use sp_core::Bytes;
-use sp_statement_store::{StatementSource, SubmitResult};
+use sp_statement_store::{StatementSource, SubmitResult, SubscriptionFilter};
 use std::sync::Arc;
 
+/// Number of events that may be buffered for a subscription.
+const SUBSCRIPTION_BUFFER_SIZE: usize = 256;
+
 /// Statement store API
 pub struct StatementStore {
 	store: Arc<dyn sp_statement_store::StatementStore>,
+	/// Executor to spawn subscriptions.
+	executor: SubscriptionTaskExecutor,
 }
 
 impl StatementStore {
 	/// Create new instance of Offchain API.
-	pub fn new(store: Arc<dyn sp_statement_store::StatementStore>) -> Self {
-		StatementStore { store }
+	pub fn new(
+		store: Arc<dyn sp_statement_store::StatementStore>,
+		executor: SubscriptionTaskExecutor,
+	) -> Self {
+		StatementStore { store, executor }
+	}
+}
+
+fn to_rpc_event(event: sp_statement_store::StatementEvent) -> StatementEvent {
+	match event {
+		sp_statement_store::StatementEvent::New(statement) =>
+			StatementEvent::New { statement: statement.encode().into() },
+		sp_statement_store::StatementEvent::Evicted(hash) => StatementEvent::Evicted { hash },
+		sp_statement_store::StatementEvent::Expired(hash) => StatementEvent::Expired { hash },
 	}
 }
 
@@ -141,4 +165,58 @@ impl StatementApiServer for StatementStore {
 	fn remove(&self, hash: [u8; 32]) -> RpcResult<()> {
 		Ok(self.store.remove(&hash).map_err(|e| Error::StatementStore(e.to_string()))?)
 	}
+
+	fn subscribe_statements(
+		&self,
+		pending: PendingSubscriptionSink,
+		ext: &Extensions,
+		topic_filter: TopicFilter,
+		dest: Option<[u8; 32]>,
+	) {
+		if let Err(err) = sc_rpc_api::check_if_safe(ext) {
+			spawn_subscription_task(&self.executor, pending.reject(ErrorObject::from(err)));
+			return
+		}
+
+		let topics = match topic_filter {
+			TopicFilter::Any => sp_statement_store::TopicFilter::Any,
+			TopicFilter::MatchAll(topics) => sp_statement_store::TopicFilter::MatchAll(topics),
+			TopicFilter::MatchAny(topics) => sp_statement_store::TopicFilter::MatchAny(topics),
+		};
+		let filter = SubscriptionFilter { topics, decryption_key: dest };
+		let (existing, events) = match self.store.subscribe_statements(filter) {
+			Ok(subscription) => subscription,
+			Err(e) => {
+				spawn_subscription_task(
+					&self.executor,
+					pending.reject(Error::StatementStore(e.to_string())),
+				);
+				return
+			},
+		};
+
+		let store = self.store.clone();
+		let fut = async move {
+			let Ok(sink) = pending.accept().await else { return };
+			let subscription = Subscription::from(sink);
+
+			// Statements already in the store are fetched and sent one at a time, so that only
+			// their hashes are kept in memory while the subscriber catches up.
+			for hash in existing {
+				let Ok(Some(statement)) = store.statement(&hash) else { continue };
+				let event = StatementEvent::New { statement: statement.encode().into() };
+				if subscription.send(&event).await.is_err() {
+					return
+				}
+			}
+
+			subscription
+				.pipe_from_stream(
+					events.map(to_rpc_event),
+					BoundedVecDeque::new(SUBSCRIPTION_BUFFER_SIZE),
+				)
+				.await
+		};
+		spawn_subscription_task(&self.executor, fut);
+	}
 }
@@ -0,0 +1,653 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Offline snapshots of the finalized state.
+//!
+//! A snapshot is a SCALE encoded stream made of:
+//! - the [`SNAPSHOT_MAGIC`] bytes followed by the format version;
+//! - the number of chunks and the digest of the snapshot, followed by the genesis hash, header and
+//!   justifications of the snapshot block;
+//! - a sequence of `Some(chunk)` values, each holding a batch of key-value pairs of the main trie
+//!   or of a child trie, together with the hash of the batch;
+//! - a terminating `None`.
+//!
+//! The digest starts from the hash of the encoded block and is chained with the hash of every
+//! chunk, so it covers the whole snapshot while both the writer and the reader only ever hold a
+//! single chunk.
+//!
+//! Importing a snapshot feeds the block and its state to the import queue, in the same way state
+//! sync does, so the node can start from the snapshot block without any network peer.
+
+use crate::error::Error;
+use codec::{Decode, Encode, IoReader as CodecIoReader};
+use futures::{future, prelude::*};
+use log::info;
+use sc_client_api::{BlockBackend, HeaderBackend, StorageProvider};
+use sc_consensus::{
+	import_queue::{BlockImportError, BlockImportStatus, ImportQueue, IncomingBlock, Link},
+	ImportedState,
+};
+use sp_consensus::BlockOrigin;
+use sp_core::storage::{well_known_keys, ChildInfo};
+use sp_runtime::{
+	traits::{Block as BlockT, Header as HeaderT, NumberFor, Zero},
+	Justifications,
+};
+use sp_state_machine::{KeyValueStates, KeyValueStorageLevel};
+use std::{
+	collections::BTreeMap,
+	io::{Read, Seek, SeekFrom, Write},
+	pin::Pin,
+	sync::{
+		atomic::{AtomicBool, Ordering},
+		Arc,
+	},
+	task::Poll,
+};
+
+/// Magic bytes at the start of every snapshot.
+const SNAPSHOT_MAGIC: [u8; 8] = *b"sbstsnap";
+
+/// Version of the snapshot format.
+const SNAPSHOT_VERSION: u32 = 2;
+
+/// Offset of the chunk count and digest, right after the magic bytes and the version.
+const DIGEST_OFFSET: u64 = 12;
+
+/// Size in bytes of the keys and values after which a chunk is written out.
+const CHUNK_SIZE: usize = 4 * 1024 * 1024;
+
+/// The block the snapshot was taken at.
+///
+/// The chunk count and digest come first, so their encoding has a fixed size and position and
+/// the exporter can fill them in once all the chunks are written.
+#[derive(Encode, Decode)]
+struct SnapshotHeader<B: BlockT> {
+	/// Number of chunks in the snapshot.
+	chunks: u64,
+	/// Digest of the snapshot, see [`SnapshotDigest`].
+	digest: [u8; 32],
+	genesis_hash: B::Hash,
+	header: B::Header,
+	justifications: Option<Justifications>,
+}
+
+/// A batch of key-value pairs of the main trie or of a child trie.
+#[derive(Encode, Decode)]
+struct SnapshotChunk {
+	/// Unprefixed storage key of the child trie, `None` for the main trie.
+	child_storage_key: Option<Vec<u8>>,
+	key_values: Vec<(Vec<u8>, Vec<u8>)>,
+	/// Blake2-256 hash of the encoded child storage key and key-value pairs.
+	hash: [u8; 32],
+}
+
+impl SnapshotChunk {
+	fn new(child_storage_key: Option<Vec<u8>>, key_values: Vec<(Vec<u8>, Vec<u8>)>) -> Self {
+		let hash = sp_core::blake2_256(&(&child_storage_key, &key_values).encode());
+		Self { child_storage_key, key_values, hash }
+	}
+
+	fn is_valid(&self) -> bool {
+		sp_core::blake2_256(&(&self.child_storage_key, &self.key_values).encode()) == self.hash
+	}
+}
+
+/// Running digest of a snapshot, updated with every chunk.
+struct SnapshotDigest {
+	chunks: u64,
+	digest: [u8; 32],
+}
+
+impl SnapshotDigest {
+	fn new<B: BlockT>(header: &SnapshotHeader<B>) -> Self {
+		let digest = sp_core::blake2_256(
+			&(&header.genesis_hash, &header.header, &header.justifications).encode(),
+		);
+		Self { chunks: 0, digest }
+	}
+
+	fn push(&mut self, chunk: &SnapshotChunk) {
+		self.chunks += 1;
+		self.digest = sp_core::blake2_256(&(self.digest, chunk.hash).encode());
+	}
+}
+
+/// Splits the key-value pairs of a trie into chunks written to the output.
+struct ChunkWriter<W> {
+	output: W,
+	key_values: Vec<(Vec<u8>, Vec<u8>)>,
+	size: usize,
+	digest: SnapshotDigest,
+	entries: usize,
+}
+
+impl<W: Write> ChunkWriter<W> {
+	fn new(output: W, digest: SnapshotDigest) -> Self {
+		Self { output, key_values: Vec::new(), size: 0, digest, entries: 0 }
+	}
+
+	fn push(
+		&mut self,
+		child_storage_key: Option<&[u8]>,
+		key: Vec<u8>,
+		value: Vec<u8>,
+	) -> Result<(), Error> {
+		self.size += key.len() + value.len();
+		self.key_values.push((key, value));
+		if self.size >= CHUNK_SIZE {
+			self.flush(child_storage_key)?;
+		}
+		Ok(())
+	}
+
+	/// Write out the pending key-value pairs. Must be called at the end of every trie.
+	fn flush(&mut self, child_storage_key: Option<&[u8]>) -> Result<(), Error> {
+		if self.key_values.is_empty() {
+			return Ok(())
+		}
+
+		self.entries += self.key_values.len();
+		self.size = 0;
+		let chunk = SnapshotChunk::new(
+			child_storage_key.map(|key| key.to_vec()),
+			std::mem::take(&mut self.key_values),
+		);
+		self.digest.push(&chunk);
+		self.output.write_all(&Some(chunk).encode())?;
+		Ok(())
+	}
+
+	fn finish(mut self) -> Result<(SnapshotDigest, usize), Error> {
+		self.output.write_all(&None::<SnapshotChunk>.encode())?;
+		Ok((self.digest, self.entries))
+	}
+}
+
+/// Write a complete snapshot of the given block and tries to the output.
+///
+/// The chunk count and digest are written as placeholders first and filled in at the end.
+fn write_state_snapshot<B: BlockT, W: Write + Seek>(
+	output: &mut W,
+	header: SnapshotHeader<B>,
+	write_chunks: impl FnOnce(&mut ChunkWriter<&mut W>) -> Result<(), Error>,
+) -> Result<(u64, usize), Error> {
+	let digest = SnapshotDigest::new(&header);
+
+	output.write_all(&SNAPSHOT_MAGIC)?;
+	output.write_all(&SNAPSHOT_VERSION.encode())?;
+	output.write_all(&header.encode())?;
+
+	let mut writer = ChunkWriter::new(&mut *output, digest);
+	write_chunks(&mut writer)?;
+	let (digest, entries) = writer.finish()?;
+
+	output.seek(SeekFrom::Start(DIGEST_OFFSET))?;
+	output.write_all(&(digest.chunks, digest.digest).encode())?;
+	output.seek(SeekFrom::End(0))?;
+	output.flush()?;
+
+	Ok((digest.chunks, entries))
+}
+
+/// Export the header, justifications and full state of the last finalized block.
+pub fn export_state_snapshot<B, BA, C>(
+	client: Arc<C>,
+	mut output: impl Write + Seek,
+) -> Result<(), Error>
+where
+	B: BlockT,
+	BA: sc_client_api::backend::Backend<B>,
+	C: HeaderBackend<B> + BlockBackend<B> + StorageProvider<B, BA>,
+{
+	let info = client.info();
+	let hash = info.finalized_hash;
+	let header = client
+		.header(hash)?
+		.ok_or_else(|| Error::Other(format!("Header of block {hash:?} not found")))?;
+	let number = *header.number();
+	let justifications = client.justifications(hash)?;
+
+	info!("Exporting state snapshot of block #{number} ({hash:?})");
+
+	let header = SnapshotHeader::<B> {
+		chunks: 0,
+		digest: [0; 32],
+		genesis_hash: info.genesis_hash,
+		header,
+		justifications,
+	};
+	let (chunks, entries) = write_state_snapshot(&mut output, header, |writer| {
+		let mut child_storage_keys = Vec::new();
+
+		for (key, value) in client.storage_pairs(hash, None, None)? {
+			// Child trie roots are recomputed on import, only the child trie contents are
+			// exported.
+			if let Some(child_storage_key) =
+				key.0.strip_prefix(well_known_keys::DEFAULT_CHILD_STORAGE_KEY_PREFIX)
+			{
+				child_storage_keys.push(child_storage_key.to_vec());
+				continue
+			}
+
+			writer.push(None, key.0, value.0)?;
+		}
+		writer.flush(None)?;
+
+		for child_storage_key in child_storage_keys {
+			let child_info = ChildInfo::new_default(&child_storage_key);
+			for key in client.child_storage_keys(hash, child_info.clone(), None, None)? {
+				if let Some(value) = client.child_storage(hash, &child_info, &key)? {
+					writer.push(Some(&child_storage_key), key.0, value.0)?;
+				}
+			}
+			writer.flush(Some(&child_storage_key))?;
+		}
+
+		Ok(())
+	})?;
+	info!("🎉 Exported {entries} storage entries in {chunks} chunks");
+
+	Ok(())
+}
+
+/// Decode the next value of the snapshot.
+fn decode_next<T: Decode, R: Read>(input: &mut CodecIoReader<R>, what: &str) -> Result<T, Error> {
+	T::decode(input).map_err(|e| Error::Other(format!("Failed to decode snapshot {what}: {e}")))
+}
+
+/// Reads the chunks of a snapshot one at a time.
+///
+/// Every chunk is checked against its hash as it is read. The chunk count and digest of the
+/// header are checked once the terminator is reached, so a snapshot is only known to be complete
+/// after [`SnapshotReader::next_chunk`] returned `Ok(None)`.
+struct SnapshotReader<B: BlockT, R> {
+	input: CodecIoReader<R>,
+	header: SnapshotHeader<B>,
+	digest: SnapshotDigest,
+	finished: bool,
+}
+
+impl<B: BlockT, R: Read> SnapshotReader<B, R> {
+	/// Read the snapshot header, checking that the snapshot belongs to the given chain.
+	fn new(input: R, genesis_hash: B::Hash) -> Result<Self, Error> {
+		let mut input = CodecIoReader(input);
+
+		let magic: [u8; 8] = decode_next(&mut input, "magic")?;
+		if magic != SNAPSHOT_MAGIC {
+			return Err(Error::Other("Input is not a state snapshot".into()))
+		}
+		let version: u32 = decode_next(&mut input, "version")?;
+		if version != SNAPSHOT_VERSION {
+			return Err(Error::Other(format!("Unsupported state snapshot version {version}")))
+		}
+
+		let header: SnapshotHeader<B> = decode_next(&mut input, "header")?;
+		if header.genesis_hash != genesis_hash {
+			return Err(Error::Other(format!(
+				"Snapshot was taken on a different chain, genesis {:?} != {:?}",
+				header.genesis_hash, genesis_hash,
+			)))
+		}
+
+		let digest = SnapshotDigest::new(&header);
+		Ok(Self { input, header, digest, finished: false })
+	}
+
+	/// Read and verify the next chunk, `None` once all the chunks were read.
+	fn next_chunk(&mut self) -> Result<Option<SnapshotChunk>, Error> {
+		if self.finished {
+			return Ok(None)
+		}
+
+		match decode_next::<Option<SnapshotChunk>, _>(&mut self.input, "chunk")? {
+			Some(chunk) => {
+				if !chunk.is_valid() {
+					return Err(Error::Other(format!(
+						"Snapshot chunk #{} is corrupted",
+						self.digest.chunks
+					)))
+				}
+				self.digest.push(&chunk);
+				if self.digest.chunks > self.header.chunks {
+					return Err(Error::Other(format!(
+						"Snapshot has more than the {} chunks announced by its header",
+						self.header.chunks,
+					)))
+				}
+				Ok(Some(chunk))
+			},
+			None => {
+				self.finished = true;
+				if self.digest.chunks != self.header.chunks {
+					return Err(Error::Other(format!(
+						"Snapshot is truncated, read {} of {} chunks",
+						self.digest.chunks, self.header.chunks,
+					)))
+				}
+				if self.digest.digest != self.header.digest {
+					return Err(Error::Other("Snapshot digest does not match its header".into()))
+				}
+				Ok(None)
+			},
+		}
+	}
+}
+
+/// Read and verify a snapshot, returning the block it was taken at and its state.
+///
+/// The chunks are streamed from the input, only their key-value pairs are kept. Nothing is
+/// returned unless the whole snapshot matches its digest.
+fn read_state_snapshot<B: BlockT>(
+	input: impl Read,
+	genesis_hash: B::Hash,
+) -> Result<(SnapshotHeader<B>, KeyValueStates), Error> {
+	let mut reader = SnapshotReader::<B, _>::new(input, genesis_hash)?;
+
+	let mut top = Vec::new();
+	let mut children: BTreeMap<Vec<u8>, Vec<(Vec<u8>, Vec<u8>)>> = BTreeMap::new();
+	while let Some(chunk) = reader.next_chunk()? {
+		match chunk.child_storage_key {
+			Some(child_storage_key) =>
+				children.entry(child_storage_key).or_default().extend(chunk.key_values),
+			None => top.extend(chunk.key_values),
+		}
+	}
+
+	let mut levels = vec![KeyValueStorageLevel {
+		state_root: Vec::new(),
+		parent_storage_keys: Vec::new(),
+		key_values: top,
+	}];
+	levels.extend(children.into_iter().map(|(child_storage_key, key_values)| {
+		let prefixed_storage_key =
+			ChildInfo::new_default(&child_storage_key).prefixed_storage_key().into_inner();
+		KeyValueStorageLevel {
+			state_root: Vec::new(),
+			parent_storage_keys: vec![prefixed_storage_key],
+			key_values,
+		}
+	}));
+
+	Ok((reader.header, KeyValueStates(levels)))
+}
+
+/// Import a state snapshot into an empty database.
+///
+/// The state root of the imported state is checked against the snapshot header. The block is
+/// imported as finalized, the blocks before it are left to be downloaded by the sync.
+pub fn import_state_snapshot<B, IQ, C>(
+	client: Arc<C>,
+	mut import_queue: IQ,
+	input: impl Read + Send + 'static,
+) -> Pin<Box<dyn Future<Output = Result<(), Error>> + Send>>
+where
+	C: HeaderBackend<B> + Send + Sync + 'static,
+	B: BlockT,
+	IQ: ImportQueue<B> + 'static,
+{
+	struct WaitLink {
+		imported: AtomicBool,
+		has_error: AtomicBool,
+	}
+
+	impl<B: BlockT> Link<B> for WaitLink {
+		fn blocks_processed(
+			&self,
+			_imported: usize,
+			_num_expected_blocks: usize,
+			results: Vec<(Result<BlockImportStatus<NumberFor<B>>, BlockImportError>, B::Hash)>,
+		) {
+			for result in results {
+				if let (Err(err), hash) = result {
+					log::warn!(
+						"There was an error importing the snapshot block {:?}: {}",
+						hash,
+						err
+					);
+					self.has_error.store(true, Ordering::Release);
+				}
+			}
+			self.imported.store(true, Ordering::Release);
+		}
+	}
+
+	let info = client.info();
+	if !info.best_number.is_zero() {
+		return future::ready(Err(Error::Other(format!(
+			"State snapshots can only be imported into an empty database, best block is #{}",
+			info.best_number,
+		))))
+		.boxed()
+	}
+
+	let (snapshot, state) = match read_state_snapshot::<B>(input, info.genesis_hash) {
+		Ok(snapshot) => snapshot,
+		Err(e) => return future::ready(Err(e)).boxed(),
+	};
+	let hash = snapshot.header.hash();
+	let number = *snapshot.header.number();
+
+	info!("Importing state snapshot of block #{number} ({hash:?})");
+
+	import_queue.service_ref().import_blocks(
+		BlockOrigin::NetworkInitialSync,
+		vec![IncomingBlock {
+			hash,
+			header: Some(snapshot.header),
+			body: None,
+			indexed_body: None,
+			justifications: snapshot.justifications,
+			origin: None,
+			allow_missing_state: true,
+			import_existing: true,
+			skip_execution: true,
+			state: Some(ImportedState { block: hash, state }),
+		}],
+	);
+
+	let mut link = WaitLink { imported: AtomicBool::new(false), has_error: AtomicBool::new(false) };
+	let import = future::poll_fn(move |cx| {
+		import_queue.poll_actions(cx, &mut link);
+
+		if link.has_error.load(Ordering::Acquire) {
+			return Poll::Ready(Err(Error::Other(format!(
+				"Failed to import the state snapshot of block #{number}"
+			))))
+		}
+		if link.imported.load(Ordering::Acquire) {
+			info!("🎉 Imported state snapshot. Best: #{}", client.info().best_number);
+			return Poll::Ready(Ok(()))
+		}
+
+		cx.waker().wake_by_ref();
+		Poll::Pending
+	});
+	Box::pin(import)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sc_consensus::{BasicQueue, BlockImportParams, Verifier};
+	use sp_core::storage::StorageKey;
+	use sp_runtime::testing::{Block as RawBlock, Header, MockCallU64, TestXt};
+	use std::io::Cursor;
+	use substrate_test_runtime_client::{prelude::*, runtime};
+
+	type Block = RawBlock<TestXt<MockCallU64, ()>>;
+
+	fn snapshot(chunks: Vec<SnapshotChunk>) -> Vec<u8> {
+		let header = SnapshotHeader::<Block> {
+			chunks: 0,
+			digest: [0; 32],
+			genesis_hash: Default::default(),
+			header: Header::new_from_number(10),
+			justifications: None,
+		};
+		let mut output = Cursor::new(Vec::new());
+		write_state_snapshot(&mut output, header, |writer| {
+			for chunk in chunks {
+				for (key, value) in chunk.key_values {
+					writer.push(chunk.child_storage_key.as_deref(), key, value)?;
+				}
+				writer.flush(chunk.child_storage_key.as_deref())?;
+			}
+			Ok(())
+		})
+		.unwrap();
+		output.into_inner()
+	}
+
+	#[test]
+	fn snapshot_round_trip() {
+		let output = snapshot(vec![
+			SnapshotChunk::new(None, vec![(b"a".to_vec(), b"1".to_vec())]),
+			SnapshotChunk::new(Some(b"child".to_vec()), vec![(b"b".to_vec(), b"2".to_vec())]),
+		]);
+
+		let (header, state) =
+			read_state_snapshot::<Block>(&output[..], Default::default()).unwrap();
+		assert_eq!(header.chunks, 2);
+		assert_eq!(header.header, Header::new_from_number(10));
+		assert_eq!(state.0.len(), 2);
+		assert!(state.0[0].parent_storage_keys.is_empty());
+		assert_eq!(state.0[0].key_values, vec![(b"a".to_vec(), b"1".to_vec())]);
+		assert_eq!(
+			state.0[1].parent_storage_keys,
+			vec![ChildInfo::new_default(b"child").prefixed_storage_key().into_inner()],
+		);
+		assert_eq!(state.0[1].key_values, vec![(b"b".to_vec(), b"2".to_vec())]);
+	}
+
+	#[test]
+	fn snapshot_rejects_corrupted_chunk() {
+		let mut output =
+			snapshot(vec![SnapshotChunk::new(None, vec![(b"a".to_vec(), b"1".to_vec())])]);
+		// Flip the value stored in the chunk, just before the chunk hash and terminator.
+		let value_position = output.len() - 32 - 2;
+		output[value_position] ^= 1;
+
+		let err = read_state_snapshot::<Block>(&output[..], Default::default()).err().unwrap();
+		assert!(err.to_string().contains("corrupted"));
+	}
+
+	#[test]
+	fn snapshot_rejects_truncated_snapshot() {
+		let chunk = |key: &[u8]| SnapshotChunk::new(None, vec![(key.to_vec(), b"1".to_vec())]);
+		let output = snapshot(vec![chunk(b"a"), chunk(b"b")]);
+		let last_chunk = Some(chunk(b"b")).encode();
+
+		// Drop the last chunk, keeping the terminator.
+		let mut truncated = output[..output.len() - last_chunk.len() - 1].to_vec();
+		truncated.push(0);
+
+		let err = read_state_snapshot::<Block>(&truncated[..], Default::default()).err().unwrap();
+		assert!(err.to_string().contains("truncated"));
+	}
+
+	#[test]
+	fn snapshot_rejects_wrong_digest() {
+		let mut output =
+			snapshot(vec![SnapshotChunk::new(None, vec![(b"a".to_vec(), b"1".to_vec())])]);
+		// The first byte of the digest, right after the chunk count.
+		output[DIGEST_OFFSET as usize + 8] ^= 1;
+
+		let err = read_state_snapshot::<Block>(&output[..], Default::default()).err().unwrap();
+		assert!(err.to_string().contains("digest"));
+	}
+
+	#[test]
+	fn snapshot_rejects_other_chain() {
+		let output = snapshot(vec![]);
+
+		let err = read_state_snapshot::<Block>(&output[..], [1; 32].into()).err().unwrap();
+		assert!(err.to_string().contains("different chain"));
+	}
+
+	struct PassThroughVerifier;
+
+	#[async_trait::async_trait]
+	impl Verifier<runtime::Block> for PassThroughVerifier {
+		async fn verify(
+			&self,
+			mut block: BlockImportParams<runtime::Block>,
+		) -> Result<BlockImportParams<runtime::Block>, String> {
+			block.finalized = true;
+			block.fork_choice = Some(sc_consensus::ForkChoiceStrategy::LongestChain);
+			Ok(block)
+		}
+	}
+
+	#[test]
+	fn export_import_round_trip() {
+		let child_info = ChildInfo::new_default(b"child");
+		let client_builder = || {
+			TestClientBuilder::new()
+				.add_extra_storage(b"key".to_vec(), b"value".to_vec())
+				.add_extra_child_storage(
+					&child_info,
+					b"child_key".to_vec(),
+					b"child_value".to_vec(),
+				)
+		};
+
+		let client = Arc::new(client_builder().build());
+		let block = BlockBuilderBuilder::new(&*client)
+			.on_parent_block(client.chain_info().genesis_hash)
+			.with_parent_block_number(0)
+			.build()
+			.unwrap()
+			.build()
+			.unwrap()
+			.block;
+		let hash = block.hash();
+		block_on(client.import_as_final(BlockOrigin::Own, block)).unwrap();
+
+		let mut output = Cursor::new(Vec::new());
+		export_state_snapshot(client.clone(), &mut output).unwrap();
+
+		let imported = Arc::new(client_builder().build());
+		let import_queue = BasicQueue::new(
+			PassThroughVerifier,
+			Box::new(imported.clone()),
+			None,
+			&sp_core::testing::TaskExecutor::new(),
+			None,
+		);
+		block_on(import_state_snapshot(
+			imported.clone(),
+			import_queue,
+			Cursor::new(output.into_inner()),
+		))
+		.unwrap();
+
+		assert_eq!(imported.info().finalized_hash, hash);
+		let storage_pairs = |client: &substrate_test_runtime_client::TestClient| {
+			client.storage_pairs(hash, None, None).unwrap().collect::<Vec<_>>()
+		};
+		assert_eq!(storage_pairs(&imported), storage_pairs(&client));
+		assert_eq!(
+			imported
+				.child_storage(hash, &child_info, &StorageKey(b"child_key".to_vec()))
+				.unwrap()
+				.map(|value| value.0),
+			Some(b"child_value".to_vec()),
+		);
+	}
+}
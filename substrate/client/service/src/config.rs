@@ -78,6 +78,8 @@ pub struct Configuration {
 	///
 	/// NOTE: only finalized blocks are subject for removal!
 	pub blocks_pruning: BlocksPruning,
+	/// Maintain an index of the storage keys modified by every block.
+	pub key_changes_index: bool,
 	/// Chain configuration.
 	pub chain_spec: Box<dyn ChainSpec>,
 	/// Runtime executor configuration.
@@ -236,6 +238,7 @@ impl Configuration {
 			source: self.database.clone(),
 			blocks_pruning: self.blocks_pruning,
 			metrics_registry: self.prometheus_registry().cloned(),
+			key_changes_index: self.key_changes_index,
 		}
 	}
 }
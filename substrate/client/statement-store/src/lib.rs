@@ -43,6 +43,12 @@
 //! explicitly with the `remove` function) the statement is marked as expired. Expired statements
 //! can't be added to the store for `Options::purge_after_sec` seconds. This is to prevent old
 //! statements from being propagated on the network.
+//!
+//! Subscriptions.
+//!
+//! Clients may subscribe to statements matching a `SubscriptionFilter`. Subscribers receive all
+//! matching statements known at the time of subscription and are then notified about every
+//! matching statement that is accepted, evicted or expired.
 
 #![warn(missing_docs)]
 #![warn(unused_extern_crates)]
@@ -52,10 +58,11 @@ mod metrics;
 pub use sp_statement_store::{Error, StatementStore, MAX_TOPICS};
 
 use metrics::MetricsLink as PrometheusMetrics;
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use prometheus_endpoint::Registry as PrometheusRegistry;
 use sc_keystore::LocalKeystore;
-use sp_api::ProvideRuntimeApi;
+use sc_utils::mpsc::{tracing_unbounded, TracingUnboundedSender};
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_core::{crypto::UncheckedFrom, hexdisplay::HexDisplay, traits::SpawnNamed, Decode, Encode};
 use sp_runtime::traits::Block as BlockT;
@@ -64,7 +71,7 @@ use sp_statement_store::{
 		InvalidStatement, StatementSource, StatementStoreExt, ValidStatement, ValidateStatement,
 	},
 	AccountId, BlockHash, Channel, DecryptionKey, Hash, NetworkPriority, Proof, Result, Statement,
-	SubmitResult, Topic,
+	StatementEvent, StatementEventStream, SubmitResult, SubscriptionFilter, Topic, TopicFilter,
 };
 use std::{
 	collections::{BTreeMap, HashMap, HashSet},
@@ -82,6 +89,8 @@ const DEFAULT_MAX_TOTAL_SIZE: usize = 64 * 1024 * 1024;
 
 const MAINTENANCE_PERIOD: std::time::Duration = std::time::Duration::from_secs(30);
 
+const SUBSCRIPTION_WARNING_THRESHOLD: usize = 100_000;
+
 mod col {
 	pub const META: u8 = 0;
 	pub const STATEMENTS: u8 = 1;
@@ -127,6 +136,14 @@ struct StatementsForAccount {
 	data_size: usize,
 }
 
+#[derive(Default)]
+struct StatementsForTopic {
+	// Statements ordered by priority, with their `Data` field size.
+	by_priority: BTreeMap<PriorityKey, usize>,
+	// Sum of all `Data` field sizes.
+	data_size: usize,
+}
+
 /// Store configuration
 pub struct Options {
 	/// Maximum statement allowed in the store. Once this limit is reached lower-priority
@@ -149,14 +166,18 @@ impl Default for Options {
 	}
 }
 
+/// Topics and decryption key of an indexed statement.
+type TopicsAndKey = ([Option<Topic>; MAX_TOPICS], Option<DecryptionKey>);
+
 #[derive(Default)]
 struct Index {
 	by_topic: HashMap<Topic, HashSet<Hash>>,
 	by_dec_key: HashMap<Option<DecryptionKey>, HashSet<Hash>>,
-	topics_and_keys: HashMap<Hash, ([Option<Topic>; MAX_TOPICS], Option<DecryptionKey>)>,
+	topics_and_keys: HashMap<Hash, TopicsAndKey>,
 	entries: HashMap<Hash, (AccountId, Priority, usize)>,
 	expired: HashMap<Hash, u64>, // Value is expiration timestamp.
 	accounts: HashMap<AccountId, StatementsForAccount>,
+	topics: HashMap<Topic, StatementsForTopic>,
 	options: Options,
 	total_size: usize,
 }
@@ -184,8 +205,22 @@ where
 			// Validate against the finalized state.
 			self.client.info().finalized_hash
 		});
-		api.validate_statement(block, source, statement)
-			.map_err(|_| InvalidStatement::InternalError)?
+		let version = api
+			.api_version::<dyn ValidateStatement<Block>>(block)
+			.map_err(|_| InvalidStatement::InternalError)?;
+		match version {
+			Some(1) => {
+				#[allow(deprecated)]
+				{
+					api.validate_statement_before_version_2(block, source, statement)
+						.map_err(|_| InvalidStatement::InternalError)?
+						.map(Into::into)
+				}
+			},
+			_ => api
+				.validate_statement(block, source, statement)
+				.map_err(|_| InvalidStatement::InternalError)?,
+		}
 	}
 }
 
@@ -206,6 +241,12 @@ pub struct Store {
 	// Used for testing
 	time_override: Option<u64>,
 	metrics: PrometheusMetrics,
+	subscribers: Mutex<Vec<Subscriber>>,
+}
+
+struct Subscriber {
+	filter: SubscriptionFilter,
+	sender: TracingUnboundedSender<StatementEvent>,
 }
 
 enum IndexQuery {
@@ -215,7 +256,9 @@ enum IndexQuery {
 }
 
 enum MaybeInserted {
-	Inserted(HashSet<Hash>),
+	/// The statement was inserted, evicting the given statements. The count is the number of
+	/// statements evicted because of the limits of a topic.
+	Inserted(Vec<(Hash, TopicsAndKey)>, u64),
 	Ignored,
 }
 
@@ -225,10 +268,20 @@ impl Index {
 	}
 
 	fn insert_new(&mut self, hash: Hash, account: AccountId, statement: &Statement) {
+		let priority = Priority(statement.priority().unwrap_or(0));
 		let mut all_topics = [None; MAX_TOPICS];
 		let mut nt = 0;
 		while let Some(t) = statement.topic(nt) {
 			self.by_topic.entry(t).or_default().insert(hash);
+			let topic_info = self.topics.entry(t).or_default();
+			// A topic may be repeated in several slots, its statements are only counted once.
+			if topic_info
+				.by_priority
+				.insert(PriorityKey { hash, priority }, statement.data_len())
+				.is_none()
+			{
+				topic_info.data_size += statement.data_len();
+			}
 			all_topics[nt] = Some(t);
 			nt += 1;
 		}
@@ -237,7 +290,6 @@ impl Index {
 		if nt > 0 || key.is_some() {
 			self.topics_and_keys.insert(hash, (all_topics, key));
 		}
-		let priority = Priority(statement.priority().unwrap_or(0));
 		self.entries.insert(hash, (account, priority, statement.data_len()));
 		self.total_size += statement.data_len();
 		let account_info = self.accounts.entry(account).or_default();
@@ -305,6 +357,33 @@ impl Index {
 		Ok(())
 	}
 
+	fn iterate_filter(
+		&self,
+		filter: &SubscriptionFilter,
+		mut f: impl FnMut(&Hash) -> Result<()>,
+	) -> Result<()> {
+		match &filter.topics {
+			TopicFilter::Any => self.iterate_with(filter.decryption_key, &[], f),
+			TopicFilter::MatchAll(topics) => self.iterate_with(filter.decryption_key, topics, f),
+			TopicFilter::MatchAny(topics) => {
+				let mut seen = HashSet::new();
+				for topic in topics {
+					self.iterate_with(
+						filter.decryption_key,
+						std::slice::from_ref(topic),
+						|hash| {
+							if seen.insert(*hash) {
+								f(hash)?
+							}
+							Ok(())
+						},
+					)?;
+				}
+				Ok(())
+			},
+		}
+	}
+
 	fn maintain(&mut self, current_time: u64) -> Vec<Hash> {
 		// Purge previously expired messages.
 		let mut purged = Vec::new();
@@ -320,10 +399,11 @@ impl Index {
 		purged
 	}
 
-	fn make_expired(&mut self, hash: &Hash, current_time: u64) -> bool {
+	fn make_expired(&mut self, hash: &Hash, current_time: u64) -> Option<TopicsAndKey> {
 		if let Some((account, priority, len)) = self.entries.remove(hash) {
 			self.total_size -= len;
-			if let Some((topics, key)) = self.topics_and_keys.remove(hash) {
+			let topics_and_key = self.topics_and_keys.remove(hash);
+			if let Some((topics, key)) = topics_and_key {
 				for t in topics.into_iter().flatten() {
 					if let std::collections::hash_map::Entry::Occupied(mut set) =
 						self.by_topic.entry(t)
@@ -333,6 +413,17 @@ impl Index {
 							set.remove_entry();
 						}
 					}
+					if let std::collections::hash_map::Entry::Occupied(mut topic_rec) =
+						self.topics.entry(t)
+					{
+						let key = PriorityKey { hash: *hash, priority };
+						if let Some(len) = topic_rec.get_mut().by_priority.remove(&key) {
+							topic_rec.get_mut().data_size -= len;
+						}
+						if topic_rec.get().by_priority.is_empty() {
+							topic_rec.remove_entry();
+						}
+					}
 				}
 				if let std::collections::hash_map::Entry::Occupied(mut set) =
 					self.by_dec_key.entry(key)
@@ -359,9 +450,9 @@ impl Index {
 				}
 			}
 			log::trace!(target: LOG_TARGET, "Expired statement {:?}", HexDisplay::from(hash));
-			true
+			Some(topics_and_key.unwrap_or(([None; MAX_TOPICS], None)))
 		} else {
-			false
+			None
 		}
 	}
 
@@ -452,6 +543,57 @@ impl Index {
 				would_free_size += len;
 			}
 		}
+		// Check the limits of the statement topics, evicting lower priority statements of any
+		// account sharing the topic if needed.
+		let mut topic_evictions = 0;
+		let no_statements = StatementsForTopic::default();
+		for limit in &validation.topic_limits {
+			if !(0..MAX_TOPICS).any(|i| statement.topic(i) == Some(limit.topic)) {
+				continue
+			}
+			let (max_size, max_count) = (limit.max_size as usize, limit.max_count as usize);
+			let topic_rec = self.topics.get(&limit.topic).unwrap_or(&no_statements);
+			// Statements already evicted above don't count towards the limits.
+			let (mut size, mut count) = (topic_rec.data_size, topic_rec.by_priority.len());
+			for (entry, len) in topic_rec.by_priority.iter() {
+				if evicted.contains(&entry.hash) {
+					size -= len;
+					count -= 1;
+				}
+			}
+			let mut candidates = topic_rec.by_priority.iter();
+			while size + statement_len > max_size || count + 1 > max_count {
+				let Some((entry, len)) = candidates.next() else {
+					log::debug!(
+						target: LOG_TARGET,
+						"Ignored statement {:?} exceeding the limits of topic {:?}",
+						HexDisplay::from(&hash),
+						HexDisplay::from(&limit.topic),
+					);
+					return MaybeInserted::Ignored
+				};
+				if evicted.contains(&entry.hash) {
+					// Already accounted for above
+					continue
+				}
+				if entry.priority >= priority {
+					log::debug!(
+						target: LOG_TARGET,
+						"Ignored message due to topic {:?} constraints {:?} {:?} < {:?}",
+						HexDisplay::from(&limit.topic),
+						HexDisplay::from(&hash),
+						priority,
+						entry.priority,
+					);
+					return MaybeInserted::Ignored
+				}
+				evicted.insert(entry.hash);
+				topic_evictions += 1;
+				would_free_size += len;
+				size -= len;
+				count -= 1;
+			}
+		}
 		// Now check global constraints as well.
 		if !((self.total_size - would_free_size + statement_len <= self.options.max_total_size) &&
 			self.entries.len() + 1 - evicted.len() <= self.options.max_total_statements)
@@ -466,11 +608,12 @@ impl Index {
 			return MaybeInserted::Ignored
 		}
 
-		for h in &evicted {
-			self.make_expired(h, current_time);
-		}
+		let evicted = evicted
+			.into_iter()
+			.filter_map(|h| self.make_expired(&h, current_time).map(|info| (h, info)))
+			.collect();
 		self.insert_new(hash, *account, statement);
-		MaybeInserted::Inserted(evicted)
+		MaybeInserted::Inserted(evicted, topic_evictions)
 	}
 }
 
@@ -573,6 +716,7 @@ impl Store {
 			keystore,
 			time_override: None,
 			metrics: PrometheusMetrics::new(prometheus),
+			subscribers: Mutex::new(Vec::new()),
 		};
 		store.populate()?;
 		Ok(store)
@@ -639,35 +783,49 @@ impl Store {
 		let mut result = Vec::new();
 		let index = self.index.read();
 		index.iterate_with(key, match_all_topics, |hash| {
-			match self.db.get(col::STATEMENTS, hash).map_err(|e| Error::Db(e.to_string()))? {
-				Some(entry) => {
-					if let Ok(statement) = Statement::decode(&mut entry.as_slice()) {
-						if let Some(data) = f(statement) {
-							result.push(data);
-						}
-					} else {
-						// DB inconsistency
-						log::warn!(
-							target: LOG_TARGET,
-							"Corrupt statement {:?}",
-							HexDisplay::from(hash)
-						);
-					}
-				},
-				None => {
-					// DB inconsistency
-					log::warn!(
-						target: LOG_TARGET,
-						"Missing statement {:?}",
-						HexDisplay::from(hash)
-					);
-				},
+			if let Some(data) = self.read_indexed(hash)?.and_then(&mut f) {
+				result.push(data);
 			}
 			Ok(())
 		})?;
 		Ok(result)
 	}
 
+	/// Read a statement that is present in the index from the database.
+	fn read_indexed(&self, hash: &Hash) -> Result<Option<Statement>> {
+		match self.db.get(col::STATEMENTS, hash).map_err(|e| Error::Db(e.to_string()))? {
+			Some(entry) => {
+				if let Ok(statement) = Statement::decode(&mut entry.as_slice()) {
+					return Ok(Some(statement))
+				}
+				// DB inconsistency
+				log::warn!(target: LOG_TARGET, "Corrupt statement {:?}", HexDisplay::from(hash));
+			},
+			None => {
+				// DB inconsistency
+				log::warn!(target: LOG_TARGET, "Missing statement {:?}", HexDisplay::from(hash));
+			},
+		}
+		Ok(None)
+	}
+
+	/// Send a statement event to all subscribers whose filter matches the given topics and key.
+	/// Subscribers which are no longer listening are dropped.
+	fn notify_subscribers(
+		&self,
+		event: StatementEvent,
+		topics: &[Option<Topic>; MAX_TOPICS],
+		key: Option<DecryptionKey>,
+	) {
+		let topics: Vec<_> = topics.iter().flatten().copied().collect();
+		self.subscribers.lock().retain(|subscriber| {
+			if !subscriber.filter.matches(&topics, key) {
+				return !subscriber.sender.is_closed()
+			}
+			subscriber.sender.unbounded_send(event.clone()).is_ok()
+		});
+	}
+
 	/// Perform periodic store maintenance
 	pub fn maintain(&self) {
 		log::trace!(target: LOG_TARGET, "Started store maintenance");
@@ -915,14 +1073,14 @@ impl StatementStore for Store {
 		{
 			let mut index = self.index.write();
 
-			let evicted =
+			let (evicted, topic_evictions) =
 				match index.insert(hash, &statement, &account_id, &validation, current_time) {
 					MaybeInserted::Ignored => return SubmitResult::Ignored,
-					MaybeInserted::Inserted(evicted) => evicted,
+					MaybeInserted::Inserted(evicted, topic_evictions) => (evicted, topic_evictions),
 				};
 
 			commit.push((col::STATEMENTS, hash.to_vec(), Some(statement.encode())));
-			for hash in evicted {
+			for (hash, _) in &evicted {
 				commit.push((col::STATEMENTS, hash.to_vec(), None));
 				commit.push((col::EXPIRED, hash.to_vec(), Some((hash, current_time).encode())));
 			}
@@ -935,6 +1093,19 @@ impl StatementStore for Store {
 				);
 				return SubmitResult::InternalError(Error::Db(e.to_string()))
 			}
+			self.metrics.report(|metrics| metrics.topic_evictions.inc_by(topic_evictions));
+
+			// Subscribers are notified while the index lock is held, so that they don't miss or
+			// receive duplicates of statements that are submitted while they subscribe.
+			for (hash, (topics, key)) in evicted {
+				self.notify_subscribers(StatementEvent::Evicted(hash), &topics, key);
+			}
+			let mut topics = [None; MAX_TOPICS];
+			for (i, topic) in topics.iter_mut().enumerate() {
+				*topic = statement.topic(i);
+			}
+			let key = statement.decryption_key();
+			self.notify_subscribers(StatementEvent::New(statement), &topics, key);
 		} // Release index lock
 		self.metrics.report(|metrics| metrics.submitted_statements.inc());
 		let network_priority = NetworkPriority::High;
@@ -947,7 +1118,7 @@ impl StatementStore for Store {
 		let current_time = self.timestamp();
 		{
 			let mut index = self.index.write();
-			if index.make_expired(hash, current_time) {
+			if let Some((topics, key)) = index.make_expired(hash, current_time) {
 				let commit = [
 					(col::STATEMENTS, hash.to_vec(), None),
 					(col::EXPIRED, hash.to_vec(), Some((hash, current_time).encode())),
@@ -961,21 +1132,48 @@ impl StatementStore for Store {
 					);
 					return Err(Error::Db(e.to_string()))
 				}
+				self.notify_subscribers(StatementEvent::Expired(*hash), &topics, key);
 			}
 		}
 		Ok(())
 	}
+
+	/// Subscribe to statements matching `filter`.
+	fn subscribe_statements(
+		&self,
+		filter: SubscriptionFilter,
+	) -> Result<(Vec<Hash>, StatementEventStream)> {
+		// Hold the index lock until the subscriber is registered, so that no statement is
+		// submitted in between.
+		let index = self.index.read();
+		let mut existing = Vec::new();
+		index.iterate_filter(&filter, |hash| {
+			existing.push(*hash);
+			Ok(())
+		})?;
+		let (sender, receiver) =
+			tracing_unbounded("mpsc_statement_store_subscription", SUBSCRIPTION_WARNING_THRESHOLD);
+		self.subscribers.lock().push(Subscriber { filter, sender });
+		log::trace!(
+			target: LOG_TARGET,
+			"New subscription, {} existing statements matched",
+			existing.len()
+		);
+		Ok((existing, Box::pin(receiver)))
+	}
 }
 
 #[cfg(test)]
 mod tests {
 	use crate::Store;
+	use futures::{FutureExt, StreamExt};
 	use sc_keystore::Keystore;
 	use sp_core::{Decode, Encode, Pair};
 	use sp_statement_store::{
-		runtime_api::{InvalidStatement, ValidStatement, ValidateStatement},
+		runtime_api::{InvalidStatement, TopicLimit, ValidStatement, ValidateStatement},
 		AccountId, Channel, DecryptionKey, NetworkPriority, Proof, SignatureVerificationResult,
-		Statement, StatementSource, StatementStore, SubmitResult, Topic,
+		Statement, StatementEvent, StatementSource, StatementStore, SubmitResult,
+		SubscriptionFilter, Topic, TopicFilter,
 	};
 
 	type Extrinsic = sp_runtime::OpaqueExtrinsic;
@@ -1007,9 +1205,10 @@ mod tests {
 				_source: StatementSource,
 				statement: Statement,
 			) -> std::result::Result<ValidStatement, InvalidStatement> {
-				use crate::tests::account;
+				use crate::tests::{account, topic};
 				match statement.verify_signature() {
-					SignatureVerificationResult::Valid(_) => Ok(ValidStatement{max_count: 100, max_size: 1000}),
+					SignatureVerificationResult::Valid(_) =>
+						Ok(ValidStatement{max_count: 100, max_size: 1000, topic_limits: vec![]}),
 					SignatureVerificationResult::Invalid => Err(InvalidStatement::BadProof),
 					SignatureVerificationResult::NoSignature => {
 						if let Some(Proof::OnChain { block_hash, .. }) = statement.proof() {
@@ -1021,7 +1220,11 @@ mod tests {
 									Some(a) if a == account(4) => (4, 1000),
 									_ => (2, 2000),
 								};
-								Ok(ValidStatement{ max_count, max_size })
+								// Statements with topic 100 are limited to 2 statements and 1000 bytes.
+								let topic_limits = vec![
+									TopicLimit { topic: topic(100), max_count: 2, max_size: 1000 },
+								];
+								Ok(ValidStatement{ max_count, max_size, topic_limits })
 							} else {
 								Err(InvalidStatement::BadProof)
 							}
@@ -1291,6 +1494,78 @@ mod tests {
 		assert_eq!(expected_statements, statements);
 	}
 
+	#[test]
+	fn topic_constraints() {
+		let (store, _temp) = test_store();
+		let with_topic = |account_id, priority, topic_id, data_len| {
+			let mut statement = statement(account_id, priority, None, data_len);
+			statement.set_topic(0, topic(topic_id));
+			statement
+		};
+
+		let statement1 = with_topic(1, 10, 100, 100);
+		let statement2 = with_topic(2, 20, 100, 100);
+		let statement3 = with_topic(3, 30, 100, 100);
+		for statement in [&statement1, &statement2, &statement3] {
+			assert_eq!(
+				store.submit(statement.clone(), StatementSource::Network),
+				SubmitResult::New(NetworkPriority::High)
+			);
+		}
+		// The topic count limit evicted the lowest priority statement, of another account.
+		assert_eq!(store.statement(&statement1.hash()).unwrap(), None);
+		assert_eq!(store.broadcasts(&[topic(100)]).unwrap().len(), 2);
+
+		// Statements with a priority lower than all of the topic ones are ignored.
+		assert_eq!(
+			store.submit(with_topic(4, 5, 100, 100), StatementSource::Network),
+			SubmitResult::Ignored
+		);
+
+		// The topic size limit evicts as many statements as needed.
+		let statement5 = with_topic(5, 40, 100, 900);
+		assert_eq!(
+			store.submit(statement5.clone(), StatementSource::Network),
+			SubmitResult::New(NetworkPriority::High)
+		);
+		assert_eq!(store.statement(&statement2.hash()).unwrap(), None);
+		assert_eq!(store.statement(&statement3.hash()).unwrap(), Some(statement3));
+		assert_eq!(
+			store.submit(with_topic(6, 50, 100, 1001), StatementSource::Network),
+			SubmitResult::Ignored
+		);
+
+		// Other topics are not limited.
+		for priority in 1..=3 {
+			assert_eq!(
+				store.submit(with_topic(4, priority, 101, 10), StatementSource::Network),
+				SubmitResult::New(NetworkPriority::High)
+			);
+		}
+		assert_eq!(store.broadcasts(&[topic(101)]).unwrap().len(), 3);
+
+		let index = store.index.read();
+		assert_eq!(index.topics.get(&topic(100)).unwrap().data_size, 1000);
+		assert_eq!(index.topics.get(&topic(101)).unwrap().by_priority.len(), 3);
+	}
+
+	#[test]
+	fn repeated_topic_is_counted_once() {
+		let (store, _temp) = test_store();
+		let mut statement = statement(1, 1, None, 100);
+		statement.set_topic(0, topic(100));
+		statement.set_topic(1, topic(100));
+		let hash = statement.hash();
+		store.submit(statement, StatementSource::Network);
+
+		let mut index = store.index.write();
+		assert_eq!(index.topics.get(&topic(100)).unwrap().data_size, 100);
+
+		index.make_expired(&hash, 0);
+		assert!(index.topics.get(&topic(100)).is_none());
+		assert!(index.by_topic.get(&topic(100)).is_none());
+	}
+
 	#[test]
 	fn expired_statements_are_purged() {
 		use super::DEFAULT_PURGE_AFTER_SEC;
@@ -1503,4 +1778,88 @@ mod tests {
 		// exactly one element, equal to the expected plaintext
 		assert_eq!(retrieved, vec![plaintext_good]);
 	}
+
+	#[test]
+	fn subscription_returns_existing_and_new_statements() {
+		let (store, _tmp) = test_store();
+		let s0 = signed_statement_with_topics(0, &[topic(1)], None);
+		let s1 = signed_statement_with_topics(1, &[topic(2)], None);
+		let s2 = signed_statement_with_topics(2, &[topic(1), topic(2)], Some(dec_key(3)));
+		for s in [&s0, &s1, &s2] {
+			store.submit(s.clone(), StatementSource::Network);
+		}
+
+		let filter = SubscriptionFilter {
+			topics: TopicFilter::MatchAll(vec![topic(1)]),
+			decryption_key: None,
+		};
+		let (existing, mut stream) = store.subscribe_statements(filter).unwrap();
+		assert_eq!(existing, vec![s0.hash()]);
+
+		let s3 = signed_statement_with_topics(3, &[topic(1), topic(3)], None);
+		let s4 = signed_statement_with_topics(4, &[topic(3)], None);
+		store.submit(s3.clone(), StatementSource::Network);
+		store.submit(s4, StatementSource::Network);
+		assert_eq!(stream.next().now_or_never(), Some(Some(StatementEvent::New(s3))));
+		assert!(stream.next().now_or_never().is_none());
+	}
+
+	#[test]
+	fn subscription_matches_any_topic_and_key() {
+		let (store, _tmp) = test_store();
+		let s0 = signed_statement_with_topics(0, &[topic(1)], Some(dec_key(7)));
+		let s1 = signed_statement_with_topics(1, &[topic(2)], Some(dec_key(7)));
+		let s2 = signed_statement_with_topics(2, &[topic(1), topic(2)], Some(dec_key(7)));
+		let s3 = signed_statement_with_topics(3, &[topic(1)], None);
+		for s in [&s0, &s1, &s2, &s3] {
+			store.submit(s.clone(), StatementSource::Network);
+		}
+
+		let filter = SubscriptionFilter {
+			topics: TopicFilter::MatchAny(vec![topic(1), topic(2)]),
+			decryption_key: Some(dec_key(7)),
+		};
+		let (existing, _stream) = store.subscribe_statements(filter).unwrap();
+		let mut got = existing;
+		got.sort();
+		let mut expected = vec![s0.hash(), s1.hash(), s2.hash()];
+		expected.sort();
+		assert_eq!(got, expected);
+	}
+
+	#[test]
+	fn subscription_reports_evicted_and_expired_statements() {
+		let (store, _tmp) = test_store();
+		let source = StatementSource::Network;
+		let filter = SubscriptionFilter { topics: TopicFilter::Any, decryption_key: None };
+		let (existing, mut stream) = store.subscribe_statements(filter).unwrap();
+		assert!(existing.is_empty());
+
+		// Account 1 may only have one statement, so the second one evicts the first.
+		let first = statement(1, 1, None, 100);
+		let second = statement(1, 2, None, 100);
+		assert_eq!(store.submit(first.clone(), source), SubmitResult::New(NetworkPriority::High));
+		assert_eq!(store.submit(second.clone(), source), SubmitResult::New(NetworkPriority::High));
+		store.remove(&second.hash()).unwrap();
+
+		assert_eq!(stream.next().now_or_never(), Some(Some(StatementEvent::New(first.clone()))));
+		assert_eq!(stream.next().now_or_never(), Some(Some(StatementEvent::Evicted(first.hash()))));
+		assert_eq!(stream.next().now_or_never(), Some(Some(StatementEvent::New(second.clone()))));
+		assert_eq!(
+			stream.next().now_or_never(),
+			Some(Some(StatementEvent::Expired(second.hash())))
+		);
+		assert!(stream.next().now_or_never().is_none());
+	}
+
+	#[test]
+	fn dropped_subscribers_are_removed() {
+		let (store, _tmp) = test_store();
+		let filter = SubscriptionFilter { topics: TopicFilter::Any, decryption_key: None };
+		let (_, stream) = store.subscribe_statements(filter).unwrap();
+		assert_eq!(store.subscribers.lock().len(), 1);
+		drop(stream);
+		store.submit(signed_statement(0), StatementSource::Network);
+		assert!(store.subscribers.lock().is_empty());
+	}
 }
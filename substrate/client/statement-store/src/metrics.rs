@@ -48,6 +48,7 @@ pub struct Metrics {
 	pub submitted_statements: Counter<U64>,
 	pub validations_invalid: Counter<U64>,
 	pub statements_pruned: Counter<U64>,
+	pub topic_evictions: Counter<U64>,
 }
 
 impl Metrics {
@@ -74,6 +75,13 @@ impl Metrics {
 				)?,
 				registry,
 			)?,
+			topic_evictions: register(
+				Counter::new(
+					"substrate_sub_statement_store_topic_evictions",
+					"Total number of statements evicted because of the limits of a topic",
+				)?,
+				registry,
+			)?,
 		})
 	}
 }
@@ -21,7 +21,10 @@
 use crate::{
 	common::api::FullChainApi,
 	fork_aware_txpool::ForkAwareTxPool as ForkAwareFullPool,
-	graph::{base_pool::Transaction, ChainApi, ExtrinsicFor, ExtrinsicHash, IsValidator, Options},
+	graph::{
+		base_pool::{PriorityLane, SenderLimit, Transaction},
+		ChainApi, ExtrinsicFor, ExtrinsicHash, IsValidator, Options,
+	},
 	single_state_txpool::BasicPool as SingleStateFullPool,
 	TransactionPoolWrapper, LOG_TARGET,
 };
@@ -29,7 +32,7 @@ use prometheus_endpoint::Registry as PrometheusRegistry;
 use sc_transaction_pool_api::{LocalTransactionPool, MaintainedTransactionPool};
 use sp_core::traits::SpawnEssentialNamed;
 use sp_runtime::traits::Block as BlockT;
-use std::{marker::PhantomData, sync::Arc, time::Duration};
+use std::{marker::PhantomData, path::PathBuf, sync::Arc, time::Duration};
 
 /// The type of transaction pool.
 #[derive(Debug, Clone)]
@@ -45,11 +48,16 @@ pub enum TransactionPoolType {
 pub struct TransactionPoolOptions {
 	txpool_type: TransactionPoolType,
 	options: Options,
+	journal_path: Option<PathBuf>,
 }
 
 impl Default for TransactionPoolOptions {
 	fn default() -> Self {
-		Self { txpool_type: TransactionPoolType::SingleState, options: Default::default() }
+		Self {
+			txpool_type: TransactionPoolType::SingleState,
+			options: Default::default(),
+			journal_path: None,
+		}
 	}
 }
 
@@ -81,7 +89,27 @@ impl TransactionPoolOptions {
 			Duration::from_secs(30 * 60)
 		};
 
-		TransactionPoolOptions { options, txpool_type }
+		TransactionPoolOptions { options, txpool_type, journal_path: None }
+	}
+
+	/// Sets the per-sender limits of the ready and future queues.
+	pub fn with_sender_limit(mut self, sender_limit: Option<SenderLimit>) -> Self {
+		self.options.sender_limit = sender_limit;
+		self
+	}
+
+	/// Sets the capacity of the ready and future queues reserved for the priority lane.
+	pub fn with_priority_lane(mut self, priority_lane: Option<PriorityLane>) -> Self {
+		self.options.priority_lane = priority_lane;
+		self
+	}
+
+	/// Sets the path of the persistent journal of the transaction pool.
+	///
+	/// The journal is only supported by the fork-aware transaction pool.
+	pub fn with_journal_path(mut self, journal_path: Option<PathBuf>) -> Self {
+		self.journal_path = journal_path;
+		self
 	}
 
 	/// Creates predefined options for benchmarking
@@ -98,8 +126,11 @@ impl TransactionPoolOptions {
 				},
 				reject_future_transactions: false,
 				ban_time: Duration::from_secs(30 * 60),
+				sender_limit: None,
+				priority_lane: None,
 			},
 			txpool_type: TransactionPoolType::SingleState,
+			journal_path: None,
 		}
 	}
 }
@@ -229,23 +260,39 @@ where
 			txpool_type = ?self.options.txpool_type,
 			ready = ?self.options.options.ready,
 			future = ?self.options.options.future,
+			journal_path = ?self.options.journal_path,
 			"Creating transaction pool"
 		);
 		TransactionPoolWrapper::<Block, Client>(match self.options.txpool_type {
-			TransactionPoolType::SingleState => Box::new(SingleStateFullPool::new_full(
-				self.options.options,
-				self.is_validator,
-				self.prometheus,
-				self.spawner,
-				self.client,
-			)),
-			TransactionPoolType::ForkAware => Box::new(ForkAwareFullPool::new_full(
-				self.options.options,
-				self.is_validator,
-				self.prometheus,
-				self.spawner,
-				self.client,
-			)),
+			TransactionPoolType::SingleState => {
+				if self.options.journal_path.is_some() {
+					tracing::warn!(
+						target: LOG_TARGET,
+						"Transaction pool journal is only supported by the fork-aware pool, ignoring"
+					);
+				}
+				Box::new(SingleStateFullPool::new_full(
+					self.options.options,
+					self.is_validator,
+					self.prometheus,
+					self.spawner,
+					self.client,
+				))
+			},
+			TransactionPoolType::ForkAware => {
+				let journal_spawner = self.spawner.clone();
+				let pool = ForkAwareFullPool::new_full(
+					self.options.options,
+					self.is_validator,
+					self.prometheus,
+					self.spawner,
+					self.client,
+				);
+				Box::new(match self.options.journal_path {
+					Some(path) => pool.with_journal(path, journal_spawner),
+					None => pool,
+				})
+			},
 		})
 	}
 }
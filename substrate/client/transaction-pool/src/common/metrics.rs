@@ -18,10 +18,15 @@
 
 //! Transaction pool Prometheus metrics for implementation of Chain API.
 
-use prometheus_endpoint::{register, Counter, PrometheusError, Registry, U64};
+use prometheus_endpoint::{
+	register, Counter, CounterVec, GaugeVec, Opts, PrometheusError, Registry, U64,
+};
 use std::sync::Arc;
 
-use crate::LOG_TARGET;
+use crate::{
+	graph::base_pool::{Lane, LaneStatus},
+	LOG_TARGET,
+};
 
 /// Provides interface to register the specific metrics in the Prometheus register.
 pub(crate) trait MetricsRegistrant {
@@ -107,3 +112,87 @@ impl ApiMetricsExt for Option<Arc<ApiMetrics>> {
 		}
 	}
 }
+
+/// The reason of the transaction eviction by the limits enforcement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum EvictionReason {
+	/// The sender of the transaction exceeded the per-sender limits.
+	SenderLimit,
+	/// The normal lane exceeded the capacity not reserved for the priority lane.
+	LaneLimit,
+	/// The queue exceeded its limits.
+	QueueLimit,
+}
+
+impl EvictionReason {
+	fn as_str(&self) -> &'static str {
+		match self {
+			EvictionReason::SenderLimit => "sender_limit",
+			EvictionReason::LaneLimit => "lane_limit",
+			EvictionReason::QueueLimit => "queue_limit",
+		}
+	}
+}
+
+/// Link to the metrics of the queue lanes and limits enforcement.
+pub(crate) type LaneMetricsLink = GenericMetricsLink<LaneMetrics>;
+
+/// Transaction pool Prometheus metrics of the queue lanes and limits enforcement.
+pub struct LaneMetrics {
+	/// Number of transactions in the queues, by queue and lane.
+	pub lane_transactions: GaugeVec<U64>,
+	/// Total number of transactions evicted from the queues, by reason.
+	pub evicted_transactions: CounterVec<U64>,
+}
+
+impl MetricsRegistrant for LaneMetrics {
+	fn register(registry: &Registry) -> Result<Box<Self>, PrometheusError> {
+		Ok(Box::from(Self {
+			lane_transactions: register(
+				GaugeVec::new(
+					Opts::new(
+						"substrate_sub_txpool_lane_transactions",
+						"Number of transactions in the queues, by queue and lane",
+					),
+					&["queue", "lane"],
+				)?,
+				registry,
+			)?,
+			evicted_transactions: register(
+				CounterVec::new(
+					Opts::new(
+						"substrate_sub_txpool_evicted_transactions",
+						"Total number of transactions evicted from the queues by limits enforcement",
+					),
+					&["reason"],
+				)?,
+				registry,
+			)?,
+		}))
+	}
+}
+
+impl LaneMetrics {
+	/// Reports the occupancy of the queues by the lanes.
+	pub(crate) fn report_status(&self, status: &LaneStatus) {
+		for (queue, lane, count) in [
+			("ready", Lane::Priority, status.ready_priority),
+			("ready", Lane::Normal, status.ready_normal),
+			("future", Lane::Priority, status.future_priority),
+			("future", Lane::Normal, status.future_normal),
+		] {
+			self.lane_transactions
+				.with_label_values(&[queue, lane.as_str()])
+				.set(count as u64);
+		}
+	}
+
+	/// Reports the transactions evicted for the given reason.
+	pub(crate) fn report_evictions(&self, reason: EvictionReason, count: usize) {
+		if count > 0 {
+			self.evicted_transactions
+				.with_label_values(&[reason.as_str()])
+				.inc_by(count as u64);
+		}
+	}
+}
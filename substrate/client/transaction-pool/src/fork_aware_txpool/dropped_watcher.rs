@@ -115,6 +115,11 @@ where
 	///
 	/// Intended to ba called when transactions were finalized or their finality timed out.
 	RemoveTransactions(Vec<ExtrinsicHash<ChainApi>>),
+	/// Reports given extrinsic hashes as invalid.
+	///
+	/// Intended to be called for transactions that are not referenced by any view, e.g.
+	/// transactions restored from the journal which no longer pass the validation.
+	InvalidateTransactions(Vec<ExtrinsicHash<ChainApi>>),
 }
 
 impl<ChainApi> Debug for Command<ChainApi>
@@ -126,6 +131,7 @@ where
 			Command::AddView(..) => write!(f, "AddView"),
 			Command::RemoveView(..) => write!(f, "RemoveView"),
 			Command::RemoveTransactions(..) => write!(f, "RemoveTransactions"),
+			Command::InvalidateTransactions(..) => write!(f, "InvalidateTransactions"),
 		}
 	}
 }
@@ -164,6 +170,9 @@ where
 
 	/// Transactions that need to be notified as dropped.
 	pending_dropped_transactions: Vec<ExtrinsicHash<ChainApi>>,
+
+	/// Transactions that need to be notified as invalid.
+	pending_invalid_transactions: Vec<ExtrinsicHash<ChainApi>>,
 }
 
 impl<C> MultiViewDropWatcherContext<C>
@@ -240,6 +249,18 @@ where
 					self.future_transaction_views.remove(xt);
 				});
 			},
+			Command::InvalidateTransactions(xts) => {
+				log_xt_trace!(
+					target: LOG_TARGET,
+					xts.clone(),
+					"dropped_watcher: invalidated xt"
+				);
+				xts.iter().for_each(|xt| {
+					self.ready_transaction_views.remove(xt);
+					self.future_transaction_views.remove(xt);
+				});
+				self.pending_invalid_transactions.extend(xts);
+			},
 		}
 	}
 
@@ -316,6 +337,10 @@ where
 
 	/// Gets pending dropped transactions if any.
 	fn get_pending_dropped_transaction(&mut self) -> Option<DroppedTransaction<ExtrinsicHash<C>>> {
+		if let Some(tx_hash) = self.pending_invalid_transactions.pop() {
+			return Some(DroppedTransaction::new_invalid(tx_hash))
+		}
+
 		while let Some(tx_hash) = self.pending_dropped_transactions.pop() {
 			// never drop transaction that was seen as ready. It may not have a referencing
 			// view now, but such fork can appear.
@@ -352,6 +377,7 @@ where
 			ready_transaction_views: Default::default(),
 			future_transaction_views: Default::default(),
 			pending_dropped_transactions: Default::default(),
+			pending_invalid_transactions: Default::default(),
 		};
 
 		let stream_map = futures::stream::unfold(ctx, |mut ctx| async move {
@@ -434,6 +460,18 @@ where
 				trace!(target: LOG_TARGET, "dropped_watcher: remove_transactions send message failed: {e}");
 			});
 	}
+
+	/// Reports given transactions as invalid.
+	///
+	/// The transactions are sent out as [`DroppedReason::Invalid`] by the [`StreamOfDropped`].
+	pub fn invalidate_transactions(&self, xts: impl IntoIterator<Item = ExtrinsicHash<ChainApi>>) {
+		let _ = self
+			.controller
+			.unbounded_send(Command::InvalidateTransactions(xts.into_iter().collect()))
+			.map_err(|e| {
+				trace!(target: LOG_TARGET, "dropped_watcher: invalidate_transactions send message failed: {e}");
+			});
+	}
 }
 
 #[cfg(test)]
@@ -588,4 +626,23 @@ mod dropped_watcher_tests {
 		let handle = tokio::spawn(async move { output_stream.take(1).collect::<Vec<_>>().await });
 		assert_eq!(handle.await.unwrap(), vec![DroppedTransaction::new_enforced_by_limts(tx_hash)]);
 	}
+
+	#[tokio::test]
+	async fn test06() {
+		sp_tracing::try_init_simple();
+		let (watcher, output_stream) = MultiViewDroppedWatcher::new();
+
+		let tx_hash0 = H256::repeat_byte(0x0a);
+		let tx_hash1 = H256::repeat_byte(0x0b);
+
+		watcher.invalidate_transactions([tx_hash0, tx_hash1]);
+		let handle = tokio::spawn(async move { output_stream.take(2).collect::<Vec<_>>().await });
+		assert_eq!(
+			handle.await.unwrap(),
+			vec![
+				DroppedTransaction::new_invalid(tx_hash1),
+				DroppedTransaction::new_invalid(tx_hash0)
+			]
+		);
+	}
 }
@@ -24,12 +24,16 @@ use super::{
 	metrics::{EventsMetricsCollector, MetricsLink as PrometheusMetrics},
 	multi_view_listener::MultiViewListener,
 	tx_mem_pool::{InsertionInfo, TxMemPool, TXMEMPOOL_TRANSACTION_LIMIT_MULTIPLIER},
+	tx_mem_pool_journal::TxMemPoolJournal,
 	view::View,
 	view_store::ViewStore,
 };
 use crate::{
 	api::FullChainApi,
-	common::tracing_log_xt::{log_xt_debug, log_xt_trace},
+	common::{
+		metrics::LaneMetricsLink,
+		tracing_log_xt::{log_xt_debug, log_xt_trace},
+	},
 	enactment_state::{EnactmentAction, EnactmentState},
 	fork_aware_txpool::{
 		dropped_watcher::{DroppedReason, DroppedTransaction},
@@ -43,6 +47,7 @@ use crate::{
 	ReadyIteratorFor, LOG_TARGET,
 };
 use async_trait::async_trait;
+use codec::Decode;
 use futures::{
 	channel::oneshot,
 	future::{self},
@@ -61,11 +66,14 @@ use sp_core::traits::SpawnEssentialNamed;
 use sp_runtime::{
 	generic::BlockId,
 	traits::{Block as BlockT, NumberFor},
-	transaction_validity::{TransactionTag as Tag, TransactionValidityError, ValidTransaction},
+	transaction_validity::{
+		InvalidTransaction, TransactionTag as Tag, TransactionValidityError, ValidTransaction,
+	},
 	Saturating,
 };
 use std::{
 	collections::{BTreeMap, HashMap, HashSet},
+	path::PathBuf,
 	pin::Pin,
 	sync::Arc,
 	time::Instant,
@@ -78,6 +86,9 @@ use tracing::{debug, info, trace, warn};
 /// as stale and are subject to cleanup.
 const FINALITY_TIMEOUT_THRESHOLD: usize = 128;
 
+/// The maximum number of transactions restored from the journal that are validated concurrently.
+const JOURNAL_RESTORE_CONCURRENCY: usize = 64;
+
 /// Fork aware transaction pool task, that needs to be polled.
 pub type ForkAwareTxPoolTask = Pin<Box<dyn Future<Output = ()> + Send>>;
 
@@ -149,6 +160,9 @@ where
 	/// Prometheus's metrics endpoint.
 	metrics: PrometheusMetrics,
 
+	/// Prometheus's metrics of the queue lanes and limits enforcement, shared by all views.
+	lane_metrics: LaneMetricsLink,
+
 	/// Collector of transaction statuses updates, reports transaction events metrics.
 	events_metrics_collector: EventsMetricsCollector<ChainApi>,
 
@@ -183,6 +197,9 @@ where
 	/// Intended to be used in the finality stall cleanups and also as a cache for all in-block
 	/// transactions.
 	included_transactions: Mutex<BTreeMap<HashAndNumber<Block>, Vec<ExtrinsicHash<ChainApi>>>>,
+
+	/// The optional persistent journal of the mempool.
+	journal: Option<TxMemPoolJournal>,
 }
 
 impl<ChainApi, Block> ForkAwareTxPool<ChainApi, Block>
@@ -274,10 +291,12 @@ where
 				options,
 				is_validator: false.into(),
 				metrics: Default::default(),
+				lane_metrics: Default::default(),
 				events_metrics_collector: EventsMetricsCollector::default(),
 				finality_timeout_threshold: finality_timeout_threshold
 					.unwrap_or(FINALITY_TIMEOUT_THRESHOLD),
 				included_transactions: Default::default(),
+				journal: None,
 			},
 			combined_tasks,
 		)
@@ -407,13 +426,85 @@ where
 			import_notification_sink,
 			options,
 			metrics,
+			lane_metrics: LaneMetricsLink::new(prometheus),
 			events_metrics_collector,
 			is_validator,
 			finality_timeout_threshold: FINALITY_TIMEOUT_THRESHOLD,
 			included_transactions: Default::default(),
+			journal: None,
 		}
 	}
 
+	/// Enables the persistent journal of the mempool, stored at the given path.
+	///
+	/// The transactions persisted in the journal are revalidated and restored into the mempool on
+	/// the first maintenance of the pool. The journal is written by a blocking task spawned using
+	/// the provided spawner.
+	pub fn with_journal(mut self, path: PathBuf, spawner: impl SpawnEssentialNamed) -> Self {
+		self.journal = Some(TxMemPoolJournal::new(path, spawner));
+		self
+	}
+
+	/// Restores the transactions persisted in the journal into the mempool.
+	///
+	/// Transactions are revalidated at the given block. The ones that are no longer valid are
+	/// reported as invalid by the dropped watcher. Restored transactions are unwatched and keep
+	/// their original source and insertion time.
+	///
+	/// Does nothing if the journal is not enabled or was already restored.
+	async fn restore_from_journal(&self, at: Block::Hash) {
+		let Some(entries) = self.journal.as_ref().and_then(|journal| journal.take_pending()) else {
+			return
+		};
+		let start = Instant::now();
+
+		let validations = entries.into_iter().filter_map(|entry| {
+			let xt = match Block::Extrinsic::decode(&mut &entry.tx[..]) {
+				Ok(xt) => Arc::from(xt),
+				Err(error) => {
+					debug!(target: LOG_TARGET, %error, "Failed to decode journal transaction");
+					return None
+				},
+			};
+			let source = entry.timed_source();
+			Some(
+				self.api
+					.validate_transaction(at, source.source, Arc::clone(&xt))
+					.map(move |result| (source, xt, result)),
+			)
+		});
+
+		let mut valid = Vec::new();
+		let mut invalid = Vec::new();
+		let results = stream::iter(validations)
+			.buffer_unordered(JOURNAL_RESTORE_CONCURRENCY)
+			.collect::<Vec<_>>()
+			.await;
+		for (source, xt, result) in results {
+			match result {
+				Ok(Ok(_)) |
+				Ok(Err(TransactionValidityError::Invalid(InvalidTransaction::Future))) =>
+					valid.push((source, xt)),
+				_ => invalid.push(self.tx_hash(&xt)),
+			}
+		}
+
+		let restored =
+			self.mempool.extend_restored(valid).into_iter().filter(Result::is_ok).count();
+		log_xt_trace!(target: LOG_TARGET, &invalid, "fatp::restore_from_journal invalid");
+		let invalid_count = invalid.len();
+		self.view_store.dropped_stream_controller.invalidate_transactions(invalid);
+
+		info!(
+			target: LOG_TARGET,
+			?at,
+			restored,
+			invalid = invalid_count,
+			duration = ?start.elapsed(),
+			"Restored transactions from the journal"
+		);
+	}
+
 	/// Get access to the underlying api
 	pub fn api(&self) -> &ChainApi {
 		&self.api
@@ -1212,6 +1303,7 @@ where
 					at.clone(),
 					self.options.clone(),
 					self.metrics.clone(),
+					self.lane_metrics.clone(),
 					self.is_validator.clone(),
 				)
 			};
@@ -1756,6 +1848,7 @@ where
 		);
 
 		self.view_store.finish_background_revalidations().await;
+		self.restore_from_journal(event.hash()).await;
 
 		let prev_finalized_block = self.enactment_state.lock().recent_finalized_block();
 
@@ -1804,6 +1897,7 @@ where
 			ChainEvent::NewBestBlock { .. } => {},
 			ChainEvent::Finalized { hash, ref tree_route } => {
 				self.handle_finalized(hash, tree_route).await;
+				self.persist_journal();
 
 				trace!(
 					target: LOG_TARGET,
@@ -1840,6 +1934,40 @@ where
 	}
 }
 
+impl<ChainApi, Block> ForkAwareTxPool<ChainApi, Block>
+where
+	Block: BlockT,
+	ChainApi: graph::ChainApi<Block = Block> + 'static,
+{
+	/// Hands the content of the mempool to the background writer of the journal, if it is
+	/// enabled.
+	///
+	/// The journal is left untouched until the transactions persisted in it were restored.
+	fn persist_journal(&self) {
+		let Some(journal) = self.journal.as_ref() else { return };
+		if journal.has_pending() {
+			return
+		}
+
+		journal.store_in_background(|| self.mempool.journal_entries());
+	}
+}
+
+impl<ChainApi, Block> Drop for ForkAwareTxPool<ChainApi, Block>
+where
+	Block: BlockT,
+	ChainApi: graph::ChainApi<Block = Block> + 'static,
+{
+	fn drop(&mut self) {
+		let Some(journal) = self.journal.as_ref() else { return };
+		if journal.has_pending() {
+			return
+		}
+
+		journal.store(self.mempool.journal_entries());
+	}
+}
+
 impl<Block, Client> ForkAwareTxPool<FullChainApi<Client, Block>, Block>
 where
 	Block: BlockT,
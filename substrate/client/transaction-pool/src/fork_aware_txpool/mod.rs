@@ -119,6 +119,14 @@
 //! are also periodically verified at every finalized block and removed from the *mempool* if no
 //! longer valid. This is process is called [*mempool* revalidation](#mempool-pruningrevalidation).
 //!
+//! #### Persistent journal
+//! Optionally the content of the *mempool* can be persisted to a journal file (see
+//! [`ForkAwareTxPool::with_journal`]). The journal is written in the background on finalized
+//! blocks, at most once every 30 seconds, and when the pool is dropped. On the first maintain after
+//! the start of the node the journaled transactions are revalidated at the new block and
+//! re-inserted into the *mempool* as unwatched transactions, keeping their original source. The
+//! ones that are no longer valid are reported as invalid.
+//!
 //! ## Flows
 //!
 //! The transaction pool internally is executing numerous tasks. This includes handling submitted
@@ -305,6 +313,11 @@
 //! while not referenced by the others), what means that transaction can also be
 //! [removed][`dropped_monitor_task`] from the *mempool*.
 //!
+//! The [`Options`] may additionally configure per-sender limits and the capacity reserved for the
+//! priority lane (local and operational transactions). These are enforced by every view in the
+//! same [`enforce_limits`][`vp::enforce_limits`] process, so transactions evicted this way are
+//! handled by the [`StreamOfDropped`] like any other transactions dropped due to limits.
+//!
 //!
 //! ## API Considerations
 //! Refer to github issue: <https://github.com/paritytech/polkadot-sdk/issues/5491>
@@ -373,6 +386,7 @@ mod metrics;
 mod multi_view_listener;
 mod revalidation_worker;
 mod tx_mem_pool;
+mod tx_mem_pool_journal;
 mod view;
 mod view_store;
 
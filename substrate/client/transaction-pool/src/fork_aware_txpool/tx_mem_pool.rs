@@ -36,6 +36,7 @@ use std::{
 	time::Instant,
 };
 
+use codec::Encode;
 use futures::FutureExt;
 use itertools::Itertools;
 use parking_lot::RwLock;
@@ -58,6 +59,7 @@ use crate::{
 use super::{
 	metrics::MetricsLink as PrometheusMetrics,
 	multi_view_listener::MultiViewListener,
+	tx_mem_pool_journal::JournalEntry,
 	view_store::{ViewStore, ViewStoreSubmitOutcome},
 };
 
@@ -157,6 +159,24 @@ where
 		}
 	}
 
+	/// Creates a new instance of wrapper for unwatched transaction restored from the journal.
+	///
+	/// The original source and insertion time of the transaction are preserved.
+	fn new_restored(
+		source: TimedTransactionSource,
+		tx: ExtrinsicFor<ChainApi>,
+		bytes: usize,
+	) -> Self {
+		Self {
+			watched: false,
+			tx,
+			source,
+			validated_at: AtomicU64::new(0),
+			bytes,
+			priority: None.into(),
+		}
+	}
+
 	/// Provides a clone of actual transaction body.
 	///
 	/// Operation is cheap, as the body is `Arc`.
@@ -461,6 +481,24 @@ where
 		self.try_insert(hash, TxInMemPool::new_watched(source, xt.clone(), length))
 	}
 
+	/// Adds transactions restored from the journal as unwatched transactions, not exceeding the
+	/// limit.
+	///
+	/// Returns the vector of results for each transaction, the order corresponds to the input
+	/// vector.
+	pub(super) fn extend_restored(
+		&self,
+		xts: Vec<(TimedTransactionSource, ExtrinsicFor<ChainApi>)>,
+	) -> Vec<Result<InsertionInfo<ExtrinsicHash<ChainApi>>, sc_transaction_pool_api::error::Error>>
+	{
+		xts.into_iter()
+			.map(|(source, xt)| {
+				let (hash, length) = self.api.hash_and_length(&xt);
+				self.try_insert(hash, TxInMemPool::new_restored(source, xt, length))
+			})
+			.collect()
+	}
+
 	/// Clones and returns a `HashMap` of references to all transactions in the memory pool.
 	pub(super) fn clone_transactions(
 		&self,
@@ -639,6 +677,23 @@ where
 	}
 }
 
+impl<ChainApi, Block> TxMemPool<ChainApi, Block>
+where
+	Block: BlockT,
+	ChainApi: graph::ChainApi<Block = Block> + 'static,
+{
+	/// Returns the journal entries for all transactions in the memory pool, ordered by insertion
+	/// time.
+	pub(super) fn journal_entries(&self) -> Vec<JournalEntry> {
+		self.transactions
+			.read()
+			.values()
+			.sorted_by_key(|tx| tx.source.timestamp)
+			.map(|tx| JournalEntry::new(tx.tx.encode(), &tx.source))
+			.collect()
+	}
+}
+
 #[cfg(test)]
 mod tx_mem_pool_tests {
 	use substrate_test_runtime::{AccountId, Extrinsic, ExtrinsicBuilder, Transfer, H256};
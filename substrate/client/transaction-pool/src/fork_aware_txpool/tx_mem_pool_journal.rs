@@ -0,0 +1,313 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Persistent journal of the transactions kept in the [`TxMemPool`].
+//!
+//! The journal is a snapshot of the mempool, which is rewritten on finalized blocks (at most once
+//! per [`JOURNAL_STORE_INTERVAL`]) and when the pool is dropped. The snapshots taken on finalized
+//! blocks are written by a blocking background task, so the maintenance of the pool never waits
+//! for the disk. It allows the pool to survive node restarts: the persisted transactions are
+//! revalidated and re-inserted into the mempool on the first maintenance after startup.
+//!
+//! [`TxMemPool`]: super::tx_mem_pool::TxMemPool
+
+use codec::{Decode, Encode};
+use futures::{channel::mpsc, FutureExt, StreamExt};
+use parking_lot::Mutex;
+use sc_transaction_pool_api::TransactionSource;
+use sp_core::traits::SpawnEssentialNamed;
+use std::{
+	fs, io,
+	path::PathBuf,
+	sync::Arc,
+	time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+use tracing::{debug, warn};
+
+use crate::{graph::base_pool::TimedTransactionSource, LOG_TARGET};
+
+/// The version of the journal file format.
+const JOURNAL_VERSION: u32 = 1;
+
+/// The minimum interval between two snapshots taken on finalized blocks.
+const JOURNAL_STORE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// A transaction persisted in the journal.
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+pub(super) struct JournalEntry {
+	/// The encoded transaction.
+	pub(super) tx: Vec<u8>,
+	/// The source of the transaction.
+	pub(super) source: TransactionSource,
+	/// When the transaction was inserted into the mempool, in milliseconds since the unix epoch.
+	pub(super) inserted_at: Option<u64>,
+}
+
+impl JournalEntry {
+	/// Creates a new entry for the given encoded transaction and its source.
+	pub(super) fn new(tx: Vec<u8>, source: &TimedTransactionSource) -> Self {
+		let inserted_at = source.timestamp.and_then(|timestamp| {
+			let since_epoch = SystemTime::now().checked_sub(timestamp.elapsed())?;
+			Some(since_epoch.duration_since(UNIX_EPOCH).ok()?.as_millis() as u64)
+		});
+		Self { tx, source: source.source, inserted_at }
+	}
+
+	/// Returns the source of the transaction, with the insertion time converted back to an
+	/// [`Instant`].
+	pub(super) fn timed_source(&self) -> TimedTransactionSource {
+		let timestamp = self.inserted_at.and_then(|inserted_at| {
+			let age = SystemTime::now()
+				.duration_since(UNIX_EPOCH + Duration::from_millis(inserted_at))
+				.unwrap_or_default();
+			Instant::now().checked_sub(age)
+		});
+		TimedTransactionSource { source: self.source, timestamp }
+	}
+}
+
+/// A snapshot of the mempool, numbered in the order it was taken.
+type Snapshot = (u64, Vec<JournalEntry>);
+
+/// The journal file, shared with the background writer.
+struct JournalFile {
+	/// The path of the journal file.
+	path: PathBuf,
+	/// The number of the last snapshot written to the file.
+	written: Mutex<u64>,
+}
+
+impl JournalFile {
+	/// Replaces the content of the journal with the given snapshot.
+	///
+	/// Snapshots older than the one already written are ignored. The journal is first written to
+	/// a temporary file which is then renamed, so a crash never leaves a partially written
+	/// journal behind.
+	fn store(&self, (number, entries): &Snapshot) {
+		let mut written = self.written.lock();
+		if *number <= *written {
+			return
+		}
+
+		let result = (|| {
+			if let Some(parent) = self.path.parent() {
+				fs::create_dir_all(parent)?;
+			}
+			let tmp_path = self.path.with_extension("tmp");
+			fs::write(&tmp_path, (JOURNAL_VERSION, entries).encode())?;
+			fs::rename(&tmp_path, &self.path)
+		})();
+
+		match result {
+			Ok(()) => {
+				*written = *number;
+				debug!(target: LOG_TARGET, count = entries.len(), "txpool journal stored");
+			},
+			Err(error) => warn!(target: LOG_TARGET, %error, "Failed to store txpool journal"),
+		}
+	}
+}
+
+/// The journal of the [`TxMemPool`](super::tx_mem_pool::TxMemPool).
+pub(super) struct TxMemPoolJournal {
+	/// The journal file.
+	file: Arc<JournalFile>,
+	/// The entries loaded at startup, until they are restored into the mempool.
+	pending: Mutex<Option<Vec<JournalEntry>>>,
+	/// The number of the last snapshot and the time it was taken.
+	last_snapshot: Mutex<(u64, Option<Instant>)>,
+	/// The latest snapshot, waiting to be written by the background writer.
+	next_snapshot: Arc<Mutex<Option<Snapshot>>>,
+	/// Wakes up the background writer.
+	writer_wakeup: mpsc::Sender<()>,
+}
+
+impl TxMemPoolJournal {
+	/// Opens the journal at the given path and loads its entries.
+	///
+	/// The background writer is spawned using the provided spawner. A missing or unreadable
+	/// journal is treated as empty.
+	pub(super) fn new(path: PathBuf, spawner: impl SpawnEssentialNamed) -> Self {
+		let pending = Self::load(&path);
+		debug!(target: LOG_TARGET, ?path, count = pending.len(), "txpool journal loaded");
+
+		let file = Arc::new(JournalFile { path, written: Mutex::new(0) });
+		let next_snapshot = Arc::new(Mutex::new(None::<Snapshot>));
+		let (writer_wakeup, mut wakeups) = mpsc::channel(1);
+
+		let writer = {
+			let file = file.clone();
+			let next_snapshot = next_snapshot.clone();
+			async move {
+				while wakeups.next().await.is_some() {
+					let snapshot = next_snapshot.lock().take();
+					if let Some(snapshot) = snapshot {
+						file.store(&snapshot);
+					}
+				}
+			}
+		};
+		spawner.spawn_essential_blocking(
+			"txpool-journal",
+			Some("transaction-pool"),
+			writer.boxed(),
+		);
+
+		Self {
+			file,
+			pending: Mutex::new(Some(pending)),
+			last_snapshot: Mutex::new((0, None)),
+			next_snapshot,
+			writer_wakeup,
+		}
+	}
+
+	/// Reads the entries of the journal file.
+	fn load(path: &PathBuf) -> Vec<JournalEntry> {
+		let data = match fs::read(path) {
+			Ok(data) => data,
+			Err(error) if error.kind() == io::ErrorKind::NotFound => return Vec::new(),
+			Err(error) => {
+				warn!(target: LOG_TARGET, ?path, %error, "Failed to read txpool journal");
+				return Vec::new()
+			},
+		};
+
+		match <(u32, Vec<JournalEntry>)>::decode(&mut &data[..]) {
+			Ok((JOURNAL_VERSION, entries)) => entries,
+			Ok((version, _)) => {
+				warn!(target: LOG_TARGET, ?path, version, "Unsupported txpool journal version");
+				Vec::new()
+			},
+			Err(error) => {
+				warn!(target: LOG_TARGET, ?path, %error, "Failed to decode txpool journal");
+				Vec::new()
+			},
+		}
+	}
+
+	/// Takes the entries that were loaded at startup.
+	///
+	/// Returns `None` if they were already taken.
+	pub(super) fn take_pending(&self) -> Option<Vec<JournalEntry>> {
+		self.pending.lock().take()
+	}
+
+	/// Returns `true` if the entries loaded at startup were not restored yet.
+	pub(super) fn has_pending(&self) -> bool {
+		self.pending.lock().is_some()
+	}
+
+	/// Hands a snapshot of the mempool to the background writer.
+	///
+	/// Does nothing if the previous snapshot was taken less than [`JOURNAL_STORE_INTERVAL`] ago,
+	/// in which case `entries` is not called. A snapshot that was not written yet is replaced.
+	pub(super) fn store_in_background(&self, entries: impl FnOnce() -> Vec<JournalEntry>) {
+		let number = {
+			let mut last_snapshot = self.last_snapshot.lock();
+			if last_snapshot.1.is_some_and(|taken| taken.elapsed() < JOURNAL_STORE_INTERVAL) {
+				return
+			}
+			*last_snapshot = (last_snapshot.0 + 1, Some(Instant::now()));
+			last_snapshot.0
+		};
+
+		*self.next_snapshot.lock() = Some((number, entries()));
+		// The channel is only full if the writer was already woken up.
+		let _ = self.writer_wakeup.clone().try_send(());
+	}
+
+	/// Replaces the content of the journal with the given entries, waiting for the write.
+	pub(super) fn store(&self, entries: Vec<JournalEntry>) {
+		let number = {
+			let mut last_snapshot = self.last_snapshot.lock();
+			*last_snapshot = (last_snapshot.0 + 1, Some(Instant::now()));
+			last_snapshot.0
+		};
+		self.next_snapshot.lock().take();
+		self.file.store(&(number, entries));
+	}
+}
+
+#[cfg(test)]
+mod tx_mem_pool_journal_tests {
+	use super::*;
+	use sp_core::testing::TaskExecutor;
+
+	fn journal(path: &PathBuf) -> TxMemPoolJournal {
+		TxMemPoolJournal::new(path.clone(), TaskExecutor::new())
+	}
+
+	#[test]
+	fn store_and_load_roundtrip() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("journal");
+		let source = TimedTransactionSource::new_external(true);
+		let entries = vec![
+			JournalEntry::new(vec![1, 2, 3], &source),
+			JournalEntry::new(vec![4, 5], &TimedTransactionSource::new_local(false)),
+		];
+
+		journal(&path).store(entries.clone());
+
+		let journal = journal(&path);
+		assert!(journal.has_pending());
+		assert_eq!(journal.take_pending(), Some(entries.clone()));
+		assert!(!journal.has_pending());
+		assert_eq!(journal.take_pending(), None);
+
+		let restored = entries[0].timed_source();
+		assert_eq!(restored.source, TransactionSource::External);
+		let drift = restored.timestamp.unwrap().duration_since(source.timestamp.unwrap());
+		assert!(drift < Duration::from_secs(1));
+		assert_eq!(entries[1].timed_source().timestamp, None);
+	}
+
+	#[test]
+	fn missing_or_corrupted_journal_is_empty() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("journal");
+		assert_eq!(journal(&path).take_pending(), Some(vec![]));
+
+		fs::write(&path, [0xff; 3]).unwrap();
+		assert_eq!(journal(&path).take_pending(), Some(vec![]));
+	}
+
+	#[test]
+	fn background_stores_are_debounced() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("journal");
+		let journal = journal(&path);
+		let entry = JournalEntry::new(vec![1], &TimedTransactionSource::new_external(false));
+
+		journal.store_in_background(|| vec![entry.clone()]);
+		// Taken too early after the previous snapshot, the entries are not even collected.
+		journal.store_in_background(|| panic!("snapshot should be debounced"));
+
+		let start = Instant::now();
+		while *journal.file.written.lock() == 0 {
+			assert!(start.elapsed() < Duration::from_secs(10), "snapshot was not written");
+			std::thread::sleep(Duration::from_millis(10));
+		}
+		assert_eq!(TxMemPoolJournal::load(&path), vec![entry]);
+
+		// A synchronous store is never overwritten by an older snapshot.
+		journal.store(vec![]);
+		assert_eq!(TxMemPoolJournal::load(&path), vec![]);
+	}
+}
@@ -25,7 +25,7 @@
 
 use super::metrics::MetricsLink as PrometheusMetrics;
 use crate::{
-	common::tracing_log_xt::log_xt_trace,
+	common::{metrics::LaneMetricsLink, tracing_log_xt::log_xt_trace},
 	graph::{
 		self, base_pool::TimedTransactionSource, BlockHash, ExtrinsicFor, ExtrinsicHash,
 		IsValidator, TransactionFor, ValidatedPoolSubmitOutcome, ValidatedTransaction,
@@ -263,6 +263,7 @@ where
 		at: HashAndNumber<ChainApi::Block>,
 		options: graph::Options,
 		metrics: PrometheusMetrics,
+		lane_metrics: LaneMetricsLink,
 		is_validator: IsValidator,
 	) -> (
 		Self,
@@ -278,7 +279,8 @@ where
 					is_validator,
 					api,
 					event_handler,
-				),
+				)
+				.with_lane_metrics(lane_metrics),
 				at,
 				revalidation_worker_channels: Mutex::from(None),
 				metrics,
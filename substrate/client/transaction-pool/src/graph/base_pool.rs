@@ -20,7 +20,13 @@
 //!
 //! For a more full-featured pool, have a look at the `pool` module.
 
-use std::{cmp::Ordering, collections::HashSet, fmt, hash, sync::Arc, time::Instant};
+use std::{
+	cmp::Ordering,
+	collections::{HashMap, HashSet},
+	fmt, hash,
+	sync::Arc,
+	time::Instant,
+};
 
 use crate::LOG_TARGET;
 use sc_transaction_pool_api::{error, InPoolTransaction, PoolStatus};
@@ -468,72 +474,291 @@ impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> BasePool<Hash,
 		let mut removed = vec![];
 
 		while ready.is_exceeded(self.ready.len(), self.ready.bytes()) {
-			// find the worst transaction
-			let worst =
-				self.ready.fold::<Option<TransactionRef<Hash, Ex>>, _>(None, |worst, current| {
-					let transaction = &current.transaction;
-					worst
-						.map(|worst| {
-							// Here we don't use `TransactionRef`'s ordering implementation because
-							// while it prefers priority like need here, it also prefers older
-							// transactions for inclusion purposes and limit enforcement needs to
-							// prefer newer transactions instead and drop the older ones.
-							match worst.transaction.priority.cmp(&transaction.transaction.priority)
-							{
-								Ordering::Less => worst,
-								Ordering::Equal =>
-									if worst.insertion_id > transaction.insertion_id {
-										transaction.clone()
-									} else {
-										worst
-									},
-								Ordering::Greater => transaction.clone(),
-							}
-						})
-						.or_else(|| Some(transaction.clone()))
-				});
-
-			if let Some(worst) = worst {
-				removed.append(&mut self.remove_subtree(&[worst.transaction.hash.clone()]))
+			if let Some(worst) = self.worst_ready(|_| true) {
+				removed.append(&mut self.remove_subtree(&[worst]))
 			} else {
 				break
 			}
 		}
 
 		while future.is_exceeded(self.future.len(), self.future.bytes()) {
-			// find the worst transaction
-			let worst = self.future.fold(|worst, current| match worst {
-				None => Some(current.clone()),
-				Some(worst) => Some(
-					match (worst.transaction.source.timestamp, current.transaction.source.timestamp)
+			if let Some(worst) = self.worst_future(|_| true) {
+				removed.append(&mut self.remove_subtree(&[worst]))
+			} else {
+				break
+			}
+		}
+
+		removed
+	}
+
+	/// Makes sure that no single sender exceeds the per-sender limits of the queues.
+	///
+	/// Only external transactions are subject to the per-sender limits. For every sender exceeding
+	/// the limit its most recently imported transactions are removed, along with all transactions
+	/// that depend on them. Removed transactions are returned.
+	pub fn enforce_sender_limits(
+		&mut self,
+		limit: &SenderLimit,
+	) -> Vec<Arc<Transaction<Hash, Ex>>> {
+		let mut removed = vec![];
+
+		let usage = self.ready.fold(HashMap::new(), |mut usage, current| {
+			let transaction = &current.transaction.transaction;
+			if let Some(sender) = limit.sender_of(transaction) {
+				usage
+					.entry(sender.to_vec())
+					.or_insert_with(QueueUsage::default)
+					.add(current.transaction.insertion_id, transaction);
+			}
+			usage
+		});
+		self.enforce_queue_usage(usage, &limit.ready, limit, &mut removed);
+
+		let usage = self
+			.future
+			.fold(|usage, current| {
+				let mut usage = usage.unwrap_or_else(HashMap::new);
+				let transaction = &current.transaction;
+				if let Some(sender) = limit.sender_of(transaction) {
+					usage
+						.entry(sender.to_vec())
+						.or_insert_with(QueueUsage::default)
+						.add(current.imported_at, transaction);
+				}
+				Some(usage)
+			})
+			.unwrap_or_default();
+		self.enforce_queue_usage(usage, &limit.future, limit, &mut removed);
+
+		removed
+	}
+
+	/// Removes the most recent transactions of every sender of `usage` exceeding the `limit`.
+	///
+	/// The usage is computed once by the caller and kept up to date with the removed
+	/// transactions, including the dependent transactions of other senders.
+	fn enforce_queue_usage<R: Ord>(
+		&mut self,
+		mut usage: HashMap<Vec<u8>, QueueUsage<Hash, R>>,
+		limit: &Limit,
+		sender_limit: &SenderLimit,
+		removed: &mut Vec<Arc<Transaction<Hash, Ex>>>,
+	) {
+		// sorted, so that the removed transactions don't depend on the order of the map
+		let mut exceeding = usage
+			.iter()
+			.filter(|(_, usage)| limit.is_exceeded(usage.count, usage.bytes))
+			.map(|(sender, _)| sender.clone())
+			.collect::<Vec<_>>();
+		exceeding.sort();
+
+		for sender in exceeding {
+			loop {
+				let Some(sender_usage) = usage.get_mut(&sender) else { break };
+				if !limit.is_exceeded(sender_usage.count, sender_usage.bytes) {
+					break
+				}
+				let Some(newest) = sender_usage.pop_newest() else { break };
+
+				// The transaction might have already been removed as a dependency of another one.
+				for tx in self.remove_subtree(&[newest]) {
+					if let Some(usage) =
+						sender_limit.sender_of(&tx).and_then(|sender| usage.get_mut(sender))
 					{
-						(Some(worst_timestamp), Some(current_timestamp)) => {
-							if worst_timestamp > current_timestamp {
-								current.clone()
-							} else {
-								worst
-							}
-						},
-						_ =>
-							if worst.imported_at > current.imported_at {
-								current.clone()
-							} else {
-								worst
-							},
-					},
-				),
+						usage.remove(&tx);
+					}
+					removed.push(tx);
+				}
+			}
+		}
+	}
+
+	/// Makes sure that the transactions in the normal lane leave the capacity reserved for the
+	/// priority lane free.
+	///
+	/// The normal lane may only use the queue limits decreased by the capacity reserved by the
+	/// `lane`. The worst normal lane transactions (as in [`Self::enforce_limits`]) are removed
+	/// until the normal lane fits into its limits. Removed transactions are returned.
+	pub fn enforce_lane_limits(
+		&mut self,
+		ready: &Limit,
+		future: &Limit,
+		lane: &PriorityLane,
+	) -> Vec<Arc<Transaction<Hash, Ex>>> {
+		let mut removed = vec![];
+		let mut status = self.lane_status(Some(lane));
+		let is_normal = |tx: &Transaction<Hash, Ex>| lane.lane_of(tx) == Lane::Normal;
+
+		let ready = ready.without(&lane.ready);
+		if ready.is_exceeded(status.ready_normal, status.ready_normal_bytes) {
+			// The worst transactions first, as in `worst_ready`.
+			let mut candidates = self.ready.fold(Vec::new(), |mut candidates, current| {
+				let transaction = &current.transaction.transaction;
+				if is_normal(transaction) {
+					candidates.push((
+						transaction.priority,
+						current.transaction.insertion_id,
+						transaction.hash.clone(),
+					));
+				}
+				candidates
 			});
+			candidates.sort_unstable_by(|a, b| (a.0, a.1).cmp(&(b.0, b.1)));
 
-			if let Some(worst) = worst {
-				removed.append(&mut self.remove_subtree(&[worst.transaction.hash.clone()]))
-			} else {
-				break
+			for (_, _, worst) in candidates {
+				if !ready.is_exceeded(status.ready_normal, status.ready_normal_bytes) {
+					break
+				}
+				for tx in self.remove_subtree(&[worst]) {
+					if is_normal(&tx) {
+						status.ready_normal -= 1;
+						status.ready_normal_bytes -= tx.bytes;
+					}
+					removed.push(tx);
+				}
+			}
+		}
+
+		let future = future.without(&lane.future);
+		if future.is_exceeded(status.future_normal, status.future_normal_bytes) {
+			// The transactions occupying the queue for the longest time first, as in
+			// `worst_future`.
+			let mut candidates = self
+				.future
+				.fold(|candidates, current| {
+					let mut candidates = candidates.unwrap_or_else(Vec::new);
+					if is_normal(&current.transaction) {
+						let since =
+							current.transaction.source.timestamp.unwrap_or(current.imported_at);
+						candidates.push((since, current.transaction.hash.clone()));
+					}
+					Some(candidates)
+				})
+				.unwrap_or_default();
+			candidates.sort_unstable_by_key(|(since, _)| *since);
+
+			for (_, worst) in candidates {
+				if !future.is_exceeded(status.future_normal, status.future_normal_bytes) {
+					break
+				}
+				for tx in self.remove_subtree(&[worst]) {
+					if is_normal(&tx) {
+						status.future_normal -= 1;
+						status.future_normal_bytes -= tx.bytes;
+					}
+					removed.push(tx);
+				}
 			}
 		}
 
 		removed
 	}
 
+	/// Returns the occupancy of the queues by the transactions of every lane.
+	///
+	/// If no priority `lane` is given, all transactions are considered to be in the normal lane.
+	pub fn lane_status(&self, lane: Option<&PriorityLane>) -> LaneStatus {
+		let lane_of =
+			|tx: &Transaction<Hash, Ex>| lane.map_or(Lane::Normal, |lane| lane.lane_of(tx));
+		let mut status = self.ready.fold(LaneStatus::default(), |mut status, current| {
+			let transaction = &current.transaction.transaction;
+			match lane_of(transaction) {
+				Lane::Priority => {
+					status.ready_priority += 1;
+					status.ready_priority_bytes += transaction.bytes;
+				},
+				Lane::Normal => {
+					status.ready_normal += 1;
+					status.ready_normal_bytes += transaction.bytes;
+				},
+			}
+			status
+		});
+		for transaction in self.future.all() {
+			match lane_of(transaction) {
+				Lane::Priority => {
+					status.future_priority += 1;
+					status.future_priority_bytes += transaction.bytes;
+				},
+				Lane::Normal => {
+					status.future_normal += 1;
+					status.future_normal_bytes += transaction.bytes;
+				},
+			}
+		}
+		status
+	}
+
+	/// Finds the worst transaction in the ready queue among the transactions matching `filter`.
+	///
+	/// The transaction with the lowest priority is the worst one. If the priority is the same, the
+	/// oldest transaction is considered worse.
+	fn worst_ready(&self, filter: impl Fn(&Transaction<Hash, Ex>) -> bool) -> Option<Hash> {
+		self.ready
+			.fold::<Option<TransactionRef<Hash, Ex>>, _>(None, |worst, current| {
+				let transaction = &current.transaction;
+				if !filter(&transaction.transaction) {
+					return worst
+				}
+				worst
+					.map(|worst| {
+						// Here we don't use `TransactionRef`'s ordering implementation because
+						// while it prefers priority like need here, it also prefers older
+						// transactions for inclusion purposes and limit enforcement needs to
+						// prefer newer transactions instead and drop the older ones.
+						match worst.transaction.priority.cmp(&transaction.transaction.priority) {
+							Ordering::Less => worst,
+							Ordering::Equal =>
+								if worst.insertion_id > transaction.insertion_id {
+									transaction.clone()
+								} else {
+									worst
+								},
+							Ordering::Greater => transaction.clone(),
+						}
+					})
+					.or_else(|| Some(transaction.clone()))
+			})
+			.map(|worst| worst.transaction.hash.clone())
+	}
+
+	/// Finds the worst transaction in the future queue among the transactions matching `filter`.
+	///
+	/// The transaction that occupies the queue for the longest time is the worst one.
+	fn worst_future(&mut self, filter: impl Fn(&Transaction<Hash, Ex>) -> bool) -> Option<Hash> {
+		self.future
+			.fold(|worst, current| {
+				if !filter(&current.transaction) {
+					return worst
+				}
+				match worst {
+					None => Some(current.clone()),
+					Some(worst) => Some(
+						match (
+							worst.transaction.source.timestamp,
+							current.transaction.source.timestamp,
+						) {
+							(Some(worst_timestamp), Some(current_timestamp)) => {
+								if worst_timestamp > current_timestamp {
+									current.clone()
+								} else {
+									worst
+								}
+							},
+							_ =>
+								if worst.imported_at > current.imported_at {
+									current.clone()
+								} else {
+									worst
+								},
+						},
+					),
+				}
+			})
+			.map(|worst| worst.transaction.hash.clone())
+	}
+
 	/// Removes all transactions represented by the hashes and all other transactions
 	/// that depend on them.
 	///
@@ -628,6 +853,156 @@ impl Limit {
 	pub fn is_exceeded(&self, count: usize, bytes: usize) -> bool {
 		self.count < count || self.total_bytes < bytes
 	}
+
+	/// Returns the limit decreased by the given reserved capacity.
+	fn without(&self, reserved: &Limit) -> Limit {
+		Limit {
+			count: self.count.saturating_sub(reserved.count),
+			total_bytes: self.total_bytes.saturating_sub(reserved.total_bytes),
+		}
+	}
+}
+
+/// Per-sender queue limits.
+///
+/// The transaction pool has no notion of the transaction sender, so the sender is identified by
+/// the leading `tag_prefix_len` bytes of the first tag provided by the transaction. This matches
+/// the `(AccountId, Nonce)` tag provided by the FRAME `CheckNonce` transaction extension, for which
+/// `tag_prefix_len` is the length of the encoded account id (32 bytes for `AccountId32`).
+#[derive(Debug, Clone)]
+pub struct SenderLimit {
+	/// Number of leading bytes of the first provided tag identifying the sender.
+	pub tag_prefix_len: usize,
+	/// Limit of transactions of a single sender in the ready queue.
+	pub ready: Limit,
+	/// Limit of transactions of a single sender in the future queue.
+	pub future: Limit,
+}
+
+impl SenderLimit {
+	/// Returns the sender of the transaction, if the transaction is subject to per-sender limits.
+	///
+	/// Only external transactions are limited.
+	fn sender_of<'a, Hash, Ex>(&self, tx: &'a Transaction<Hash, Ex>) -> Option<&'a [u8]> {
+		if tx.source.source != TransactionSource::External {
+			return None
+		}
+		tx.provides.first().and_then(|tag| tag.get(..self.tag_prefix_len))
+	}
+}
+
+/// The lane of the transaction in the queues.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lane {
+	/// Local and operational transactions, allowed to use the capacity reserved by the
+	/// [`PriorityLane`].
+	Priority,
+	/// All other transactions.
+	Normal,
+}
+
+impl Lane {
+	/// Returns the name of the lane, as used in the metrics.
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Lane::Priority => "priority",
+			Lane::Normal => "normal",
+		}
+	}
+}
+
+/// Capacity of the queues reserved for the transactions in the priority lane.
+///
+/// Local transactions are always in the priority lane. Operational transactions have their
+/// priority boosted by the runtime, so they can be put into the priority lane by setting
+/// `min_priority` accordingly.
+#[derive(Debug, Clone)]
+pub struct PriorityLane {
+	/// Minimal priority of an external transaction to be put into the priority lane.
+	///
+	/// If `None`, only local transactions are in the priority lane.
+	pub min_priority: Option<Priority>,
+	/// Capacity of the ready queue reserved for the priority lane.
+	pub ready: Limit,
+	/// Capacity of the future queue reserved for the priority lane.
+	pub future: Limit,
+}
+
+impl PriorityLane {
+	/// Returns the lane of the given transaction.
+	pub fn lane_of<Hash, Ex>(&self, tx: &Transaction<Hash, Ex>) -> Lane {
+		if tx.source.source == TransactionSource::Local ||
+			self.min_priority.is_some_and(|min_priority| tx.priority >= min_priority)
+		{
+			Lane::Priority
+		} else {
+			Lane::Normal
+		}
+	}
+}
+
+/// Occupancy of the queues by the transactions of every lane.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LaneStatus {
+	/// Number of priority lane transactions in the ready queue.
+	pub ready_priority: usize,
+	/// Sum of encoding lengths of priority lane transactions in the ready queue.
+	pub ready_priority_bytes: usize,
+	/// Number of normal lane transactions in the ready queue.
+	pub ready_normal: usize,
+	/// Sum of encoding lengths of normal lane transactions in the ready queue.
+	pub ready_normal_bytes: usize,
+	/// Number of priority lane transactions in the future queue.
+	pub future_priority: usize,
+	/// Sum of encoding lengths of priority lane transactions in the future queue.
+	pub future_priority_bytes: usize,
+	/// Number of normal lane transactions in the future queue.
+	pub future_normal: usize,
+	/// Sum of encoding lengths of normal lane transactions in the future queue.
+	pub future_normal_bytes: usize,
+}
+
+/// Usage of a queue by a single sender, keeping track of its transactions by recency.
+struct QueueUsage<Hash, R> {
+	count: usize,
+	bytes: usize,
+	/// The transactions of the sender, sorted by recency once all of them were added.
+	transactions: Vec<(R, Hash)>,
+	sorted: bool,
+}
+
+impl<Hash, R> Default for QueueUsage<Hash, R> {
+	fn default() -> Self {
+		Self { count: 0, bytes: 0, transactions: Vec::new(), sorted: false }
+	}
+}
+
+impl<Hash, R: Ord> QueueUsage<Hash, R> {
+	/// Accounts the transaction imported at the given `recency`.
+	fn add<Ex>(&mut self, recency: R, tx: &Transaction<Hash, Ex>)
+	where
+		Hash: Clone,
+	{
+		self.count += 1;
+		self.bytes += tx.bytes;
+		self.transactions.push((recency, tx.hash.clone()));
+		self.sorted = false;
+	}
+
+	/// Stops accounting the removed transaction.
+	fn remove<Ex>(&mut self, tx: &Transaction<Hash, Ex>) {
+		self.count = self.count.saturating_sub(1);
+		self.bytes = self.bytes.saturating_sub(tx.bytes);
+	}
+
+	/// Returns the most recent transaction that was not returned yet.
+	fn pop_newest(&mut self) -> Option<Hash> {
+		if !self.sorted {
+			self.transactions.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+			self.sorted = true;
+		}
+		self.transactions.pop().map(|(_, hash)| hash)
+	}
 }
 
 #[cfg(test)]
@@ -1274,4 +1649,164 @@ source: TimedTransactionSource { source: TransactionSource::External, timestamp:
 		assert_eq!(pool.reject_future_transactions, true);
 		assert_eq!(pool.future.len(), 1);
 	}
+
+	#[test]
+	fn should_enforce_sender_limits() {
+		// given
+		let mut pool = pool();
+		let limit = SenderLimit {
+			tag_prefix_len: 1,
+			ready: Limit { count: 2, total_bytes: 1024 },
+			future: Limit { count: 1, total_bytes: 1024 },
+		};
+		for (hash, provides, requires) in [
+			(1, vec![0xa, 1], vec![]),
+			(2, vec![0xa, 2], vec![]),
+			(3, vec![0xa, 3], vec![]),
+			(4, vec![0xb, 1], vec![]),
+			(5, vec![0xa, 5], vec![vec![0xa, 4]]),
+			(6, vec![0xa, 6], vec![vec![0xa, 4]]),
+		] {
+			pool.import(Transaction {
+				hash,
+				provides: vec![provides],
+				requires,
+				..default_tx().clone()
+			})
+			.unwrap();
+		}
+		assert_eq!(pool.ready().count(), 4);
+		assert_eq!(pool.futures().count(), 2);
+
+		// when
+		let removed = pool.enforce_sender_limits(&limit);
+
+		// then
+		let removed = removed.iter().map(|tx| tx.hash).collect::<Vec<_>>();
+		assert_eq!(removed.len(), 2);
+		assert!(removed.contains(&3));
+		assert!(removed.contains(&5) || removed.contains(&6));
+		assert_eq!(pool.ready().count(), 3);
+		assert_eq!(pool.futures().count(), 1);
+	}
+
+	#[test]
+	fn should_account_dependent_transactions_of_other_senders() {
+		// given
+		let mut pool = pool();
+		let limit = SenderLimit {
+			tag_prefix_len: 1,
+			ready: Limit { count: 1, total_bytes: 1024 },
+			future: Limit { count: 1, total_bytes: 1024 },
+		};
+		for (hash, provides, requires) in [
+			(1, vec![0xa, 1], vec![]),
+			(2, vec![0xa, 2], vec![]),
+			(3, vec![0xb, 1], vec![vec![0xa, 2]]),
+			(4, vec![0xb, 2], vec![]),
+		] {
+			pool.import(Transaction {
+				hash,
+				provides: vec![provides],
+				requires,
+				..default_tx().clone()
+			})
+			.unwrap();
+		}
+		assert_eq!(pool.ready().count(), 4);
+
+		// when
+		let removed = pool.enforce_sender_limits(&limit);
+
+		// then
+		// removing the newest transaction of `0xa` also removes the dependent transaction of
+		// `0xb`, which brings `0xb` back within the limit.
+		let mut removed = removed.iter().map(|tx| tx.hash).collect::<Vec<_>>();
+		removed.sort();
+		assert_eq!(removed, vec![2, 3]);
+		assert_eq!(pool.ready().count(), 2);
+	}
+
+	#[test]
+	fn should_not_enforce_sender_limits_for_local_transactions() {
+		// given
+		let mut pool = pool();
+		let limit = SenderLimit {
+			tag_prefix_len: 1,
+			ready: Limit { count: 1, total_bytes: 1024 },
+			future: Limit { count: 1, total_bytes: 1024 },
+		};
+		for hash in 1..=3 {
+			pool.import(Transaction {
+				hash,
+				provides: vec![vec![0xa, hash as u8]],
+				source: TimedTransactionSource::new_local(false),
+				..default_tx().clone()
+			})
+			.unwrap();
+		}
+
+		// when
+		let removed = pool.enforce_sender_limits(&limit);
+
+		// then
+		assert!(removed.is_empty());
+		assert_eq!(pool.ready().count(), 3);
+	}
+
+	#[test]
+	fn should_reserve_capacity_for_priority_lane() {
+		// given
+		let mut pool = pool();
+		let ready = Limit { count: 4, total_bytes: 1024 };
+		let future = Limit { count: 4, total_bytes: 1024 };
+		let lane = PriorityLane {
+			min_priority: Some(100),
+			ready: Limit { count: 2, total_bytes: 0 },
+			future: Limit { count: 0, total_bytes: 0 },
+		};
+		for (hash, priority, source) in [
+			(1, 5, TimedTransactionSource::new_external(false)),
+			(2, 4, TimedTransactionSource::new_external(false)),
+			(3, 6, TimedTransactionSource::new_external(false)),
+			(4, 1, TimedTransactionSource::new_local(false)),
+			(5, 100, TimedTransactionSource::new_external(false)),
+		] {
+			pool.import(Transaction {
+				hash,
+				priority,
+				provides: vec![vec![hash as u8]],
+				source,
+				..default_tx().clone()
+			})
+			.unwrap();
+		}
+		assert_eq!(
+			pool.lane_status(Some(&lane)),
+			LaneStatus {
+				ready_priority: 2,
+				ready_priority_bytes: 2,
+				ready_normal: 3,
+				ready_normal_bytes: 3,
+				..Default::default()
+			}
+		);
+
+		// when
+		let removed = pool.enforce_lane_limits(&ready, &future, &lane);
+
+		// then
+		assert_eq!(removed.iter().map(|tx| tx.hash).collect::<Vec<_>>(), vec![2]);
+		assert_eq!(
+			pool.lane_status(Some(&lane)),
+			LaneStatus {
+				ready_priority: 2,
+				ready_priority_bytes: 2,
+				ready_normal: 2,
+				ready_normal_bytes: 2,
+				..Default::default()
+			}
+		);
+		assert_eq!(pool.lane_status(None).ready_normal, 4);
+	}
 }
@@ -16,7 +16,10 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{common::tracing_log_xt::log_xt_trace, LOG_TARGET};
+use crate::{
+	common::{metrics::LaneMetricsLink, tracing_log_xt::log_xt_trace},
+	LOG_TARGET,
+};
 use futures::{channel::mpsc::Receiver, Future};
 use indexmap::IndexMap;
 use sc_transaction_pool_api::error;
@@ -146,6 +149,10 @@ pub struct Options {
 	pub reject_future_transactions: bool,
 	/// How long the extrinsic is banned for.
 	pub ban_time: Duration,
+	/// Per-sender limits of the queues. Disabled if `None`.
+	pub sender_limit: Option<base::SenderLimit>,
+	/// Capacity of the queues reserved for the priority lane. Disabled if `None`.
+	pub priority_lane: Option<base::PriorityLane>,
 }
 
 impl Default for Options {
@@ -155,6 +162,8 @@ impl Default for Options {
 			future: base::Limit { count: 512, total_bytes: 1 * 1024 * 1024 },
 			reject_future_transactions: false,
 			ban_time: Duration::from_secs(60 * 30),
+			sender_limit: None,
+			priority_lane: None,
 		}
 	}
 }
@@ -217,6 +226,14 @@ impl<B: ChainApi, L: EventHandler<B>> Pool<B, L> {
 		}
 	}
 
+	/// Sets the Prometheus metrics of the queue lanes and limits enforcement.
+	pub(crate) fn with_lane_metrics(mut self, lane_metrics: LaneMetricsLink) -> Self {
+		Arc::get_mut(&mut self.validated_pool)
+			.expect("validated pool is not shared before the pool is built; qed")
+			.set_lane_metrics(lane_metrics);
+		self
+	}
+
 	/// Imports a bunch of unverified extrinsics to the pool
 	pub async fn submit_at(
 		&self,
@@ -21,7 +21,13 @@ use std::{
 	sync::Arc,
 };
 
-use crate::{common::tracing_log_xt::log_xt_trace, LOG_TARGET};
+use crate::{
+	common::{
+		metrics::{EvictionReason, LaneMetricsLink},
+		tracing_log_xt::log_xt_trace,
+	},
+	LOG_TARGET,
+};
 use futures::channel::mpsc::{channel, Sender};
 use indexmap::IndexMap;
 use parking_lot::{Mutex, RwLock};
@@ -165,6 +171,7 @@ pub struct ValidatedPool<B: ChainApi, L: EventHandler<B>> {
 	pub(crate) pool: RwLock<base::BasePool<ExtrinsicHash<B>, ExtrinsicFor<B>>>,
 	import_notification_sinks: Mutex<Vec<Sender<ExtrinsicHash<B>>>>,
 	rotator: PoolRotator<ExtrinsicHash<B>>,
+	lane_metrics: LaneMetricsLink,
 }
 
 impl<B: ChainApi, L: EventHandler<B>> Clone for ValidatedPool<B, L> {
@@ -177,6 +184,7 @@ impl<B: ChainApi, L: EventHandler<B>> Clone for ValidatedPool<B, L> {
 			pool: RwLock::from(self.pool.read().clone()),
 			import_notification_sinks: Default::default(),
 			rotator: self.rotator.clone(),
+			lane_metrics: self.lane_metrics.clone(),
 		}
 	}
 }
@@ -248,9 +256,15 @@ impl<B: ChainApi, L: EventHandler<B>> ValidatedPool<B, L> {
 			pool: RwLock::new(base_pool),
 			import_notification_sinks: Default::default(),
 			rotator,
+			lane_metrics: Default::default(),
 		}
 	}
 
+	/// Sets the Prometheus metrics of the queue lanes and limits enforcement.
+	pub(crate) fn set_lane_metrics(&mut self, lane_metrics: LaneMetricsLink) {
+		self.lane_metrics = lane_metrics;
+	}
+
 	/// Bans given set of hashes.
 	pub fn ban(&self, now: &Instant, hashes: impl IntoIterator<Item = ExtrinsicHash<B>>) {
 		self.rotator.ban(now, hashes)
@@ -372,52 +386,88 @@ impl<B: ChainApi, L: EventHandler<B>> ValidatedPool<B, L> {
 	}
 
 	fn enforce_limits(&self) -> HashSet<ExtrinsicHash<B>> {
-		let status = self.pool.read().status();
 		let ready_limit = &self.options.ready;
 		let future_limit = &self.options.future;
 
-		if ready_limit.is_exceeded(status.ready, status.ready_bytes) ||
-			future_limit.is_exceeded(status.future, status.future_bytes)
-		{
-			debug!(
-				target: LOG_TARGET,
-				ready_count = ready_limit.count,
-				ready_kb = ready_limit.total_bytes / 1024,
-				future_count = future_limit.count,
-				future_kb = future_limit.total_bytes / 1024,
-				"Enforcing limits"
-			);
+		// without per-sender or lane limits, only check the queue limits, which does not require
+		// the write lock
+		if self.options.sender_limit.is_none() && self.options.priority_lane.is_none() {
+			let status = self.pool.read().status();
+			if !ready_limit.is_exceeded(status.ready, status.ready_bytes) &&
+				!future_limit.is_exceeded(status.future, status.future_bytes)
+			{
+				return Default::default()
+			}
+		}
 
-			// clean up the pool
-			let removed = {
-				let mut pool = self.pool.write();
-				let removed = pool
-					.enforce_limits(ready_limit, future_limit)
-					.into_iter()
-					.map(|x| x.hash)
-					.collect::<HashSet<_>>();
-				// ban all removed transactions
-				self.rotator.ban(&Instant::now(), removed.iter().copied());
-				removed
-			};
-			if !removed.is_empty() {
-				trace!(
+		// clean up the pool
+		let removed = {
+			let mut pool = self.pool.write();
+			let mut removed = vec![];
+
+			if let Some(sender_limit) = &self.options.sender_limit {
+				let evicted = pool.enforce_sender_limits(sender_limit);
+				self.lane_metrics.report(|metrics| {
+					metrics.report_evictions(EvictionReason::SenderLimit, evicted.len())
+				});
+				removed.extend(evicted);
+			}
+
+			if let Some(priority_lane) = &self.options.priority_lane {
+				let evicted = pool.enforce_lane_limits(ready_limit, future_limit, priority_lane);
+				self.lane_metrics.report(|metrics| {
+					metrics.report_evictions(EvictionReason::LaneLimit, evicted.len())
+				});
+				removed.extend(evicted);
+			}
+
+			let status = pool.status();
+			if ready_limit.is_exceeded(status.ready, status.ready_bytes) ||
+				future_limit.is_exceeded(status.future, status.future_bytes)
+			{
+				debug!(
 					target: LOG_TARGET,
-					dropped_count = removed.len(),
+					ready_count = ready_limit.count,
+					ready_kb = ready_limit.total_bytes / 1024,
+					future_count = future_limit.count,
+					future_kb = future_limit.total_bytes / 1024,
 					"Enforcing limits"
 				);
+
+				let evicted = pool.enforce_limits(ready_limit, future_limit);
+				self.lane_metrics.report(|metrics| {
+					metrics.report_evictions(EvictionReason::QueueLimit, evicted.len())
+				});
+				removed.extend(evicted);
+			}
+
+			if let Some(priority_lane) = &self.options.priority_lane {
+				self.lane_metrics.report(|metrics| {
+					metrics.report_status(&pool.lane_status(Some(priority_lane)))
+				});
 			}
 
+			let removed = removed.into_iter().map(|x| x.hash).collect::<HashSet<_>>();
+			// ban all removed transactions
+			self.rotator.ban(&Instant::now(), removed.iter().copied());
+			removed
+		};
+
+		if !removed.is_empty() {
+			trace!(
+				target: LOG_TARGET,
+				dropped_count = removed.len(),
+				"Enforcing limits"
+			);
+
 			// run notifications
 			let mut event_dispatcher = self.event_dispatcher.write();
 			for h in &removed {
 				event_dispatcher.limits_enforced(h);
 			}
-
-			removed
-		} else {
-			Default::default()
 		}
+
+		removed
 	}
 
 	/// Import a single extrinsic and starts to watch their progress in the pool.
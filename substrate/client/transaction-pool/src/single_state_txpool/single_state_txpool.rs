@@ -27,6 +27,7 @@ use crate::{
 	common::{
 		enactment_state::{EnactmentAction, EnactmentState},
 		error,
+		metrics::LaneMetricsLink,
 		tracing_log_xt::log_xt_trace,
 	},
 	graph::{
@@ -192,11 +193,10 @@ where
 		best_block_hash: Block::Hash,
 		finalized_hash: Block::Hash,
 	) -> Self {
-		let pool = Arc::new(graph::Pool::new_with_staticly_sized_rotator(
-			options,
-			is_validator,
-			pool_api.clone(),
-		));
+		let pool = Arc::new(
+			graph::Pool::new_with_staticly_sized_rotator(options, is_validator, pool_api.clone())
+				.with_lane_metrics(LaneMetricsLink::new(prometheus)),
+		);
 		let (revalidation_queue, background_task) = match revalidation_type {
 			RevalidationType::Light =>
 				(revalidation::RevalidationQueue::new(pool_api.clone(), pool.clone()), None),
@@ -92,6 +92,7 @@ parameter_types! {
 	pub const MaxRegistrars: u32 = 20;
 	pub const PendingUsernameExpiration: u64 = 100;
 	pub const UsernameGracePeriod: u64 = 10;
+	pub const JudgementPeriod: u64 = 100;
 }
 ord_parameter_types! {
 	pub const One: u64 = 1;
@@ -133,6 +134,7 @@ impl pallet_identity::Config for Test {
 	type UsernameGracePeriod = UsernameGracePeriod;
 	type MaxSuffixLength = ConstU32<7>;
 	type MaxUsernameLength = ConstU32<32>;
+	type JudgementPeriod = JudgementPeriod;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = BenchmarkHelper;
 	type WeightInfo = ();
@@ -18,7 +18,7 @@
 //! Asset Conversion pallet benchmarking.
 
 use super::*;
-use crate::Pallet as AssetConversion;
+use crate::{curves::SwapCurve, Pallet as AssetConversion};
 use alloc::vec;
 use core::marker::PhantomData;
 use frame_benchmarking::{v2::*, whitelisted_caller};
@@ -30,6 +30,7 @@ use frame_support::{
 	},
 };
 use frame_system::RawOrigin as SystemOrigin;
+use sp_arithmetic::Percent;
 use sp_core::Get;
 
 /// Benchmark Helper
@@ -166,6 +167,45 @@ where
 	(lp_token, liquidity1, liquidity2)
 }
 
+/// Create a pool of `kind` with some liquidity in it, to read the curve and reserves of a hop from.
+fn create_pool_of_kind<T: Config>(
+	caller: &T::AccountId,
+	kind: impl FnOnce(&T::AssetKind) -> PoolKind<T::AssetKind>,
+) -> (T::AssetKind, T::AssetKind)
+where
+	T::Assets: Create<T::AccountId> + Mutate<T::AccountId>,
+{
+	let (asset1, asset2) = T::BenchmarkHelper::create_pair(0, 1);
+	let (liquidity1, liquidity2) = valid_liquidity_amount::<T>(
+		T::Assets::minimum_balance(asset1.clone()),
+		T::Assets::minimum_balance(asset2.clone()),
+	);
+	create_fee_asset::<T>(caller);
+	create_asset::<T>(caller, &asset1, liquidity1, true);
+	create_asset::<T>(caller, &asset2, liquidity2, true);
+	let lp_token = AssetConversion::<T>::get_next_pool_asset_id();
+	mint_setup_fee_asset::<T>(caller, &asset1, &asset2, &lp_token);
+
+	assert_ok!(AssetConversion::<T>::create_pool_with_kind(
+		SystemOrigin::Signed(caller.clone()).into(),
+		Box::new(asset1.clone()),
+		Box::new(asset2.clone()),
+		Box::new(kind(&asset1)),
+	));
+	assert_ok!(AssetConversion::<T>::add_liquidity(
+		SystemOrigin::Signed(caller.clone()).into(),
+		Box::new(asset1.clone()),
+		Box::new(asset2.clone()),
+		liquidity1,
+		liquidity2,
+		T::Balance::one(),
+		T::Balance::zero(),
+		caller.clone(),
+	));
+
+	(asset1, asset2)
+}
+
 fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 	let events = frame_system::Pallet::<T>::events();
 	let system_event: <T as frame_system::Config>::RuntimeEvent = generic_event.into();
@@ -199,6 +239,31 @@ mod benchmarks {
 		);
 	}
 
+	#[benchmark]
+	fn create_pool_with_kind() {
+		let caller: T::AccountId = whitelisted_caller();
+		let (asset1, asset2) = T::BenchmarkHelper::create_pair(0, 1);
+		create_asset::<T>(&caller, &asset1, T::Assets::minimum_balance(asset1.clone()), true);
+		create_asset::<T>(&caller, &asset2, T::Assets::minimum_balance(asset2.clone()), true);
+
+		let lp_token = AssetConversion::<T>::get_next_pool_asset_id();
+		create_fee_asset::<T>(&caller);
+		mint_setup_fee_asset::<T>(&caller, &asset1, &asset2, &lp_token);
+		let kind = PoolKind::Weighted { asset: asset1.clone(), weight: Percent::from_percent(80) };
+
+		#[extrinsic_call]
+		_(
+			SystemOrigin::Signed(caller.clone()),
+			Box::new(asset1.clone()),
+			Box::new(asset2.clone()),
+			Box::new(kind.clone()),
+		);
+
+		let pool_id = T::PoolLocator::pool_id(&asset1, &asset2).unwrap();
+		assert_eq!(PoolKinds::<T>::get(&pool_id), Some(kind.clone()));
+		assert_last_event::<T>(Event::PoolKindSet { pool_id, kind }.into());
+	}
+
 	#[benchmark]
 	fn add_liquidity() {
 		let caller: T::AccountId = whitelisted_caller();
@@ -410,5 +475,84 @@ mod benchmarks {
 		assert_last_event::<T>(Event::Touched { pool_id, who: caller }.into());
 	}
 
+	/// Pricing of one hop through a stable swap pool, on top of the constant product pricing
+	/// included in the swap benchmarks.
+	///
+	/// The curve and reserves of the hop are read from a stable swap pool, while the pricing
+	/// itself uses reserves as unbalanced as possible and the maximum amplification, which
+	/// maximises the Newton iterations needed to solve the invariant.
+	#[benchmark]
+	fn stable_swap_hop() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let (asset1, asset2) = create_pool_of_kind::<T>(&caller, |_| PoolKind::StableSwap {
+			amplification: MAX_AMPLIFICATION,
+		});
+		let reserve_in = T::Balance::from(1_000u32);
+		let reserve_out = T::Balance::from(u32::MAX).saturating_mul(1_000u32.into());
+		let amount = reserve_out / 2u32.into();
+
+		let (curve, reserves, amount_out, amount_in);
+		#[block]
+		{
+			curve = AssetConversion::<T>::swap_curve(&asset1, &asset2);
+			reserves = AssetConversion::<T>::get_reserves(asset1, asset2);
+			let curve = SwapCurve::StableSwap { amplification: MAX_AMPLIFICATION };
+			amount_out =
+				AssetConversion::<T>::get_amount_out_for(curve, &amount, &reserve_in, &reserve_out);
+			amount_in =
+				AssetConversion::<T>::get_amount_in_for(curve, &amount, &reserve_in, &reserve_out);
+		}
+
+		assert_eq!(curve, Ok(SwapCurve::StableSwap { amplification: MAX_AMPLIFICATION }));
+		assert_ok!(reserves);
+		assert_ok!(amount_out);
+		assert_ok!(amount_in);
+		Ok(())
+	}
+
+	/// Pricing of one hop through a weighted pool, on top of the constant product pricing
+	/// included in the swap benchmarks.
+	///
+	/// The curve and reserves of the hop are read from a weighted pool, while the pricing itself
+	/// uses weights as far apart as possible, so that the root taken by `fixed_pow_ratio_up` is of
+	/// the highest degree, and large amounts, so that its search range is widest.
+	#[benchmark]
+	fn weighted_hop() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let (asset1, asset2) = create_pool_of_kind::<T>(&caller, |asset| PoolKind::Weighted {
+			asset: asset.clone(),
+			weight: Percent::from_percent(1),
+		});
+		let reserve_in = T::Balance::from(1_000u32);
+		let reserve_out = T::Balance::from(u32::MAX).saturating_mul(1_000u32.into());
+		let amount_in = T::Balance::from(u32::MAX);
+		let amount_out = reserve_out - T::Balance::from(1_000u32);
+
+		let (curve, reserves, quoted_out, quoted_in);
+		#[block]
+		{
+			curve = AssetConversion::<T>::swap_curve(&asset1, &asset2);
+			reserves = AssetConversion::<T>::get_reserves(asset1, asset2);
+			quoted_out = AssetConversion::<T>::get_amount_out_for(
+				SwapCurve::Weighted { weight_in: 1, weight_out: 99 },
+				&amount_in,
+				&reserve_in,
+				&reserve_out,
+			);
+			quoted_in = AssetConversion::<T>::get_amount_in_for(
+				SwapCurve::Weighted { weight_in: 99, weight_out: 1 },
+				&amount_out,
+				&reserve_in,
+				&reserve_out,
+			);
+		}
+
+		assert_ok!(curve);
+		assert_ok!(reserves);
+		assert_ok!(quoted_out);
+		assert_ok!(quoted_in);
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(AssetConversion, crate::mock::new_test_ext(), crate::mock::Test);
 }
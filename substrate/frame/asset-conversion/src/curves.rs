@@ -0,0 +1,549 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Pricing of swaps for the various [`PoolKind`]s.
+//!
+//! All the calculations round in favour of the pool.
+
+use super::*;
+use frame_support::{ensure, weights::Weight};
+use sp_arithmetic::{
+	helpers_128bit::{gcd, multiply_by_rational_with_rounding},
+	FixedPointNumber, FixedU128, Percent, Rounding, SignedRounding,
+};
+
+/// The maximum number of iterations when solving the stable swap invariant.
+const MAX_ITERATIONS: u32 = 255;
+
+/// The curve of a pool, oriented for a swap from one of its assets to the other.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum SwapCurve {
+	/// See [`PoolKind::ConstantProduct`].
+	ConstantProduct,
+	/// See [`PoolKind::StableSwap`].
+	StableSwap { amplification: u32 },
+	/// See [`PoolKind::Weighted`]. The weights are reduced to their lowest terms.
+	Weighted { weight_in: u32, weight_out: u32 },
+}
+
+impl SwapCurve {
+	/// Orient `kind` for a swap from `asset_in`.
+	pub(crate) fn new<AssetKind: PartialEq>(
+		kind: &PoolKind<AssetKind>,
+		asset_in: &AssetKind,
+	) -> Self {
+		match kind {
+			PoolKind::ConstantProduct => Self::ConstantProduct,
+			PoolKind::StableSwap { amplification } =>
+				Self::StableSwap { amplification: *amplification },
+			PoolKind::Weighted { asset, weight } => {
+				let weight = u32::from(weight.deconstruct());
+				let (weight_in, weight_out) =
+					if asset == asset_in { (weight, 100 - weight) } else { (100 - weight, weight) };
+				let divisor = gcd(weight_in.into(), weight_out.into()).max(1) as u32;
+				Self::Weighted { weight_in: weight_in / divisor, weight_out: weight_out / divisor }
+			},
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The curve of the pool of `asset_in` and `asset_out`, oriented for a swap from `asset_in`.
+	pub(crate) fn swap_curve(
+		asset_in: &T::AssetKind,
+		asset_out: &T::AssetKind,
+	) -> Result<SwapCurve, Error<T>> {
+		let pool_id = T::PoolLocator::pool_id(asset_in, asset_out)
+			.map_err(|_| Error::<T>::InvalidAssetPair)?;
+		Ok(PoolKinds::<T>::get(pool_id)
+			.map_or(SwapCurve::ConstantProduct, |kind| SwapCurve::new(&kind, asset_in)))
+	}
+
+	/// The weight of pricing every hop of a swap along a path of `path_len` assets on the most
+	/// expensive curve.
+	///
+	/// This comes on top of the swap benchmarks, which price every hop on the constant product
+	/// curve. It includes reading the curve and reserves of every hop, so the proof size of the
+	/// hops is accounted for even if the swap benchmarks under-estimate it.
+	pub fn swap_curves_weight(path_len: u32) -> Weight {
+		T::WeightInfo::stable_swap_hop()
+			.max(T::WeightInfo::weighted_hop())
+			.saturating_mul(path_len.saturating_sub(1).into())
+	}
+
+	/// Ensure that `kind` is a valid curve for a pool of `asset1` and `asset2`.
+	pub(crate) fn ensure_valid_pool_kind(
+		kind: &PoolKind<T::AssetKind>,
+		asset1: &T::AssetKind,
+		asset2: &T::AssetKind,
+	) -> Result<(), Error<T>> {
+		let valid = match kind {
+			PoolKind::ConstantProduct => true,
+			PoolKind::StableSwap { amplification } =>
+				(1..=MAX_AMPLIFICATION).contains(amplification),
+			PoolKind::Weighted { asset, weight } =>
+				(asset == asset1 || asset == asset2) &&
+					!weight.is_zero() &&
+					*weight < Percent::one(),
+		};
+		ensure!(valid, Error::<T>::InvalidPoolKind);
+		Ok(())
+	}
+
+	/// Calculates amount out of a swap on a pool with the given `curve`.
+	///
+	/// Given an input amount of an asset and pair reserves, returns the maximum output amount
+	/// of the other asset.
+	pub(crate) fn get_amount_out_for(
+		curve: SwapCurve,
+		amount_in: &T::Balance,
+		reserve_in: &T::Balance,
+		reserve_out: &T::Balance,
+	) -> Result<T::Balance, Error<T>> {
+		match curve {
+			SwapCurve::ConstantProduct => Self::get_amount_out(amount_in, reserve_in, reserve_out),
+			SwapCurve::StableSwap { amplification } =>
+				Self::get_stable_amount_out(amount_in, reserve_in, reserve_out, amplification),
+			SwapCurve::Weighted { weight_in, weight_out } => Self::get_weighted_amount_out(
+				amount_in,
+				reserve_in,
+				reserve_out,
+				weight_in,
+				weight_out,
+			),
+		}
+	}
+
+	/// Calculates amount in of a swap on a pool with the given `curve`.
+	///
+	/// Given an output amount of an asset and pair reserves, returns a required input amount
+	/// of the other asset.
+	pub(crate) fn get_amount_in_for(
+		curve: SwapCurve,
+		amount_out: &T::Balance,
+		reserve_in: &T::Balance,
+		reserve_out: &T::Balance,
+	) -> Result<T::Balance, Error<T>> {
+		match curve {
+			SwapCurve::ConstantProduct => Self::get_amount_in(amount_out, reserve_in, reserve_out),
+			SwapCurve::StableSwap { amplification } =>
+				Self::get_stable_amount_in(amount_out, reserve_in, reserve_out, amplification),
+			SwapCurve::Weighted { weight_in, weight_out } => Self::get_weighted_amount_in(
+				amount_out,
+				reserve_in,
+				reserve_out,
+				weight_in,
+				weight_out,
+			),
+		}
+	}
+
+	/// Calculates the amount out of a swap at the current price of a pool with the given
+	/// `curve`, ignoring both the fee and the price impact of the swap.
+	pub(crate) fn quote_for(
+		curve: SwapCurve,
+		amount_in: &T::Balance,
+		reserve_in: &T::Balance,
+		reserve_out: &T::Balance,
+	) -> Result<T::Balance, Error<T>> {
+		match curve {
+			SwapCurve::ConstantProduct => Self::quote(amount_in, reserve_in, reserve_out),
+			SwapCurve::StableSwap { amplification } => {
+				let x = T::HigherPrecisionBalance::from(*reserve_in);
+				let y = T::HigherPrecisionBalance::from(*reserve_out);
+				let d = Self::stable_invariant(x, y, amplification)?;
+				// The price of the stable swap invariant is `(k + y) / (k + x)` with
+				// `k = 4 * A * n^n * (x * y / D)^2 / D`.
+				let xy = Self::high_mul_div(x, y, d)?;
+				let k = Self::high_mul_div(xy, xy, d)?
+					.checked_mul(&Self::ann(amplification))
+					.ok_or(Error::<T>::Overflow)?
+					.checked_mul(&4u32.into())
+					.ok_or(Error::<T>::Overflow)?;
+				let numerator = k.checked_add(&y).ok_or(Error::<T>::Overflow)?;
+				let denominator = k.checked_add(&x).ok_or(Error::<T>::Overflow)?;
+				Self::high_mul_div(
+					T::HigherPrecisionBalance::from(*amount_in),
+					numerator,
+					denominator,
+				)?
+				.try_into()
+				.map_err(|_| Error::<T>::Overflow)
+			},
+			SwapCurve::Weighted { weight_in, weight_out } => {
+				// The price of the weighted curve is `(y / w_y) / (x / w_x)`.
+				let numerator = T::HigherPrecisionBalance::from(*reserve_out)
+					.checked_mul(&weight_in.into())
+					.ok_or(Error::<T>::Overflow)?;
+				let denominator = T::HigherPrecisionBalance::from(*reserve_in)
+					.checked_mul(&weight_out.into())
+					.ok_or(Error::<T>::Overflow)?;
+				Self::high_mul_div(
+					T::HigherPrecisionBalance::from(*amount_in),
+					numerator,
+					denominator,
+				)?
+				.try_into()
+				.map_err(|_| Error::<T>::Overflow)
+			},
+		}
+	}
+
+	/// Calculates amount out of a swap on a stable swap pool.
+	pub fn get_stable_amount_out(
+		amount_in: &T::Balance,
+		reserve_in: &T::Balance,
+		reserve_out: &T::Balance,
+		amplification: u32,
+	) -> Result<T::Balance, Error<T>> {
+		let reserve_in = T::HigherPrecisionBalance::from(*reserve_in);
+		let reserve_out = T::HigherPrecisionBalance::from(*reserve_out);
+
+		if reserve_in.is_zero() || reserve_out.is_zero() {
+			return Err(Error::<T>::ZeroLiquidity)
+		}
+
+		let amount_in_with_fee = Self::high_mul_div(
+			T::HigherPrecisionBalance::from(*amount_in),
+			T::HigherPrecisionBalance::from(1000u32) - T::LPFee::get().into(),
+			1000u32.into(),
+		)?;
+
+		let d = Self::stable_invariant(reserve_in, reserve_out, amplification)?;
+		let new_reserve_in =
+			reserve_in.checked_add(&amount_in_with_fee).ok_or(Error::<T>::Overflow)?;
+		let new_reserve_out = Self::stable_reserve(new_reserve_in, d, amplification)?;
+
+		// Round down by subtracting one unit.
+		let result = reserve_out
+			.checked_sub(&new_reserve_out)
+			.and_then(|result| result.checked_sub(&One::one()))
+			.unwrap_or_else(Zero::zero);
+
+		result.try_into().map_err(|_| Error::<T>::Overflow)
+	}
+
+	/// Calculates amount in of a swap on a stable swap pool.
+	pub fn get_stable_amount_in(
+		amount_out: &T::Balance,
+		reserve_in: &T::Balance,
+		reserve_out: &T::Balance,
+		amplification: u32,
+	) -> Result<T::Balance, Error<T>> {
+		let amount_out = T::HigherPrecisionBalance::from(*amount_out);
+		let reserve_in = T::HigherPrecisionBalance::from(*reserve_in);
+		let reserve_out = T::HigherPrecisionBalance::from(*reserve_out);
+
+		if reserve_in.is_zero() || reserve_out.is_zero() {
+			Err(Error::<T>::ZeroLiquidity)?
+		}
+
+		if amount_out >= reserve_out {
+			Err(Error::<T>::AmountOutTooHigh)?
+		}
+
+		let d = Self::stable_invariant(reserve_in, reserve_out, amplification)?;
+		let new_reserve_in = Self::stable_reserve(reserve_out - amount_out, d, amplification)?;
+
+		// Round up by adding one unit.
+		let amount_in_with_fee = new_reserve_in
+			.checked_sub(&reserve_in)
+			.ok_or(Error::<T>::Overflow)?
+			.checked_add(&One::one())
+			.ok_or(Error::<T>::Overflow)?;
+
+		let result = Self::high_mul_div(
+			amount_in_with_fee,
+			1000u32.into(),
+			T::HigherPrecisionBalance::from(1000u32) - T::LPFee::get().into(),
+		)?
+		.checked_add(&One::one())
+		.ok_or(Error::<T>::Overflow)?;
+
+		result.try_into().map_err(|_| Error::<T>::Overflow)
+	}
+
+	/// Calculates amount out of a swap on a weighted pool.
+	pub fn get_weighted_amount_out(
+		amount_in: &T::Balance,
+		reserve_in: &T::Balance,
+		reserve_out: &T::Balance,
+		weight_in: u32,
+		weight_out: u32,
+	) -> Result<T::Balance, Error<T>> {
+		if reserve_in.is_zero() || reserve_out.is_zero() {
+			return Err(Error::<T>::ZeroLiquidity)
+		}
+
+		let amount_in: u128 = (*amount_in).try_into().map_err(|_| Error::<T>::Overflow)?;
+		let amount_in_with_fee = multiply_by_rational_with_rounding(
+			amount_in,
+			(1000u32 - T::LPFee::get()).into(),
+			1000,
+			Rounding::Down,
+		)
+		.ok_or(Error::<T>::Overflow)?;
+		let reserve_in: u128 = (*reserve_in).try_into().map_err(|_| Error::<T>::Overflow)?;
+		let reserve_out: u128 = (*reserve_out).try_into().map_err(|_| Error::<T>::Overflow)?;
+
+		// amount_out = reserve_out * (1 - (reserve_in / (reserve_in + amount_in))^(w_in / w_out))
+		let new_reserve_in =
+			reserve_in.checked_add(amount_in_with_fee).ok_or(Error::<T>::Overflow)?;
+		let ratio =
+			fixed_from_rational_up(reserve_in, new_reserve_in).ok_or(Error::<T>::Overflow)?;
+		let ratio = fixed_pow_ratio_up(ratio, weight_in, weight_out).ok_or(Error::<T>::Overflow)?;
+
+		let result = FixedU128::one().saturating_sub(ratio).saturating_mul_int(reserve_out);
+
+		result.try_into().map_err(|_| Error::<T>::Overflow)
+	}
+
+	/// Calculates amount in of a swap on a weighted pool.
+	pub fn get_weighted_amount_in(
+		amount_out: &T::Balance,
+		reserve_in: &T::Balance,
+		reserve_out: &T::Balance,
+		weight_in: u32,
+		weight_out: u32,
+	) -> Result<T::Balance, Error<T>> {
+		if reserve_in.is_zero() || reserve_out.is_zero() {
+			Err(Error::<T>::ZeroLiquidity)?
+		}
+
+		if amount_out >= reserve_out {
+			Err(Error::<T>::AmountOutTooHigh)?
+		}
+
+		let amount_out: u128 = (*amount_out).try_into().map_err(|_| Error::<T>::Overflow)?;
+		let reserve_in: u128 = (*reserve_in).try_into().map_err(|_| Error::<T>::Overflow)?;
+		let reserve_out: u128 = (*reserve_out).try_into().map_err(|_| Error::<T>::Overflow)?;
+
+		// amount_in = reserve_in * ((reserve_out / (reserve_out - amount_out))^(w_out / w_in) - 1)
+		let ratio = fixed_from_rational_up(reserve_out, reserve_out - amount_out)
+			.ok_or(Error::<T>::Overflow)?;
+		let ratio = fixed_pow_ratio_up(ratio, weight_out, weight_in).ok_or(Error::<T>::Overflow)?;
+		let amount_in_with_fee = multiply_by_rational_with_rounding(
+			ratio.saturating_sub(FixedU128::one()).into_inner(),
+			reserve_in,
+			FixedU128::DIV,
+			Rounding::Up,
+		)
+		.ok_or(Error::<T>::Overflow)?;
+
+		let result = multiply_by_rational_with_rounding(
+			amount_in_with_fee,
+			1000,
+			(1000u32 - T::LPFee::get()).into(),
+			Rounding::Up,
+		)
+		.and_then(|result| result.checked_add(1))
+		.ok_or(Error::<T>::Overflow)?;
+
+		result.try_into().map_err(|_| Error::<T>::Overflow)
+	}
+
+	/// The stable swap invariant `D` of a pool with reserves `x` and `y`.
+	///
+	/// `D` is the solution of `A * n^n * (x + y) + D = A * n^n * D + D^(n + 1) / (n^n * x * y)`,
+	/// found with Newton's method.
+	fn stable_invariant(
+		x: T::HigherPrecisionBalance,
+		y: T::HigherPrecisionBalance,
+		amplification: u32,
+	) -> Result<T::HigherPrecisionBalance, Error<T>> {
+		let sum = x.checked_add(&y).ok_or(Error::<T>::Overflow)?;
+		if sum.is_zero() {
+			return Ok(sum)
+		}
+
+		let ann = Self::ann(amplification);
+		let two = T::HigherPrecisionBalance::from(2u32);
+		let x2 = x.checked_mul(&two).ok_or(Error::<T>::Overflow)?;
+		let y2 = y.checked_mul(&two).ok_or(Error::<T>::Overflow)?;
+
+		let mut d = sum;
+		for _ in 0..MAX_ITERATIONS {
+			// d_p = D^3 / (n^n * x * y)
+			let d_p = Self::high_mul_div(Self::high_mul_div(d, d, x2)?, d, y2)?;
+			let previous = d;
+			let numerator = ann
+				.checked_mul(&sum)
+				.and_then(|n| n.checked_add(&d_p.checked_mul(&two)?))
+				.and_then(|n| n.checked_mul(&d))
+				.ok_or(Error::<T>::Overflow)?;
+			let denominator = (ann - One::one())
+				.checked_mul(&d)
+				.and_then(|n| n.checked_add(&d_p.checked_mul(&3u32.into())?))
+				.ok_or(Error::<T>::Overflow)?;
+			d = numerator.checked_div(&denominator).ok_or(Error::<T>::Overflow)?;
+			if abs_diff(d, previous) <= One::one() {
+				return Ok(d)
+			}
+		}
+		Err(Error::<T>::CurveDidNotConverge)
+	}
+
+	/// The reserve of one asset of a stable swap pool with invariant `d`, given the reserve `x`
+	/// of the other one.
+	fn stable_reserve(
+		x: T::HigherPrecisionBalance,
+		d: T::HigherPrecisionBalance,
+		amplification: u32,
+	) -> Result<T::HigherPrecisionBalance, Error<T>> {
+		let ann = Self::ann(amplification);
+		let two = T::HigherPrecisionBalance::from(2u32);
+
+		// c = D^3 / (n^n * x * A * n^n)
+		let c = Self::high_mul_div(
+			Self::high_mul_div(d, d, x.checked_mul(&two).ok_or(Error::<T>::Overflow)?)?,
+			d,
+			ann.checked_mul(&two).ok_or(Error::<T>::Overflow)?,
+		)?;
+		// b = x + D / (A * n^n)
+		let b = x.checked_add(&(d / ann)).ok_or(Error::<T>::Overflow)?;
+
+		let mut y = d;
+		for _ in 0..MAX_ITERATIONS {
+			let previous = y;
+			// y = (y^2 + c) / (2 * y + b - D)
+			let numerator =
+				y.checked_mul(&y).and_then(|n| n.checked_add(&c)).ok_or(Error::<T>::Overflow)?;
+			let denominator = y
+				.checked_mul(&two)
+				.and_then(|n| n.checked_add(&b))
+				.and_then(|n| n.checked_sub(&d))
+				.ok_or(Error::<T>::Overflow)?;
+			y = numerator.checked_div(&denominator).ok_or(Error::<T>::Overflow)?;
+			if abs_diff(y, previous) <= One::one() {
+				return Ok(y)
+			}
+		}
+		Err(Error::<T>::CurveDidNotConverge)
+	}
+
+	/// `A * n^n` for the two assets of a pool.
+	fn ann(amplification: u32) -> T::HigherPrecisionBalance {
+		T::HigherPrecisionBalance::from(amplification.saturating_mul(4))
+	}
+
+	fn high_mul_div(
+		a: T::HigherPrecisionBalance,
+		b: T::HigherPrecisionBalance,
+		c: T::HigherPrecisionBalance,
+	) -> Result<T::HigherPrecisionBalance, Error<T>> {
+		a.checked_mul(&b)
+			.ok_or(Error::<T>::Overflow)?
+			.checked_div(&c)
+			.ok_or(Error::<T>::Overflow)
+	}
+}
+
+fn abs_diff<N: PartialOrd + core::ops::Sub<Output = N>>(a: N, b: N) -> N {
+	if a > b {
+		a - b
+	} else {
+		b - a
+	}
+}
+
+/// `a / b` in fixed point, rounded up. `None` on overflow or if `b` is zero.
+fn fixed_from_rational_up(a: u128, b: u128) -> Option<FixedU128> {
+	if b == 0 {
+		return None
+	}
+	multiply_by_rational_with_rounding(FixedU128::DIV, a, b, Rounding::Up)
+		.map(FixedU128::from_inner)
+}
+
+/// `base^exp` in fixed point, with every multiplication rounded according to `rounding`.
+fn fixed_pow(base: FixedU128, exp: u32, rounding: SignedRounding) -> Option<FixedU128> {
+	let mut result = FixedU128::one();
+	let mut base = base;
+	let mut exp = exp;
+	while exp > 0 {
+		if exp & 1 == 1 {
+			result = result.const_checked_mul_with_rounding(base, rounding)?;
+		}
+		exp >>= 1;
+		if exp > 0 {
+			base = base.const_checked_mul_with_rounding(base, rounding)?;
+		}
+	}
+	Some(result)
+}
+
+/// `base^(num / den)` in fixed point, rounded up. `None` on overflow.
+fn fixed_pow_ratio_up(base: FixedU128, num: u32, den: u32) -> Option<FixedU128> {
+	let power = fixed_pow(base, num, SignedRounding::High)?;
+	if den <= 1 {
+		return Some(power)
+	}
+
+	// The smallest `root` such that `root^den >= power`, which lies between `power` and one.
+	let one = FixedU128::one().into_inner();
+	let (mut low, mut high) = (power.into_inner().min(one), power.into_inner().max(one));
+	while low < high {
+		let mid = low + (high - low) / 2;
+		let mid_power = fixed_pow(FixedU128::from_inner(mid), den, SignedRounding::Low);
+		// An overflow means that `mid^den` is above any representable `power`.
+		if mid_power.map_or(true, |mid_power| mid_power >= power) {
+			high = mid;
+		} else {
+			low = mid + 1;
+		}
+	}
+	Some(FixedU128::from_inner(high))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn fixed(n: u128, d: u128) -> FixedU128 {
+		FixedU128::from_rational(n, d)
+	}
+
+	#[test]
+	fn fixed_pow_ratio_works() {
+		assert_eq!(fixed_pow_ratio_up(fixed(1, 2), 2, 1), Some(fixed(1, 4)));
+		assert_eq!(fixed_pow_ratio_up(fixed(1, 4), 1, 2), Some(fixed(1, 2)));
+		assert_eq!(fixed_pow_ratio_up(fixed(8, 1), 2, 3), Some(fixed(4, 1)));
+		assert_eq!(fixed_pow_ratio_up(FixedU128::one(), 7, 3), Some(FixedU128::one()));
+
+		// Results are rounded up.
+		let root = fixed_pow_ratio_up(fixed(1, 2), 1, 2).unwrap();
+		assert!(fixed_pow(root, 2, SignedRounding::Low).unwrap() >= fixed(1, 2));
+		let below = FixedU128::from_inner(root.into_inner() - 1);
+		assert!(fixed_pow(below, 2, SignedRounding::Low).unwrap() < fixed(1, 2));
+	}
+
+	#[test]
+	fn swap_curve_orientation_works() {
+		let kind = PoolKind::Weighted { asset: 1u32, weight: Percent::from_percent(80) };
+		assert_eq!(SwapCurve::new(&kind, &1), SwapCurve::Weighted { weight_in: 4, weight_out: 1 });
+		assert_eq!(SwapCurve::new(&kind, &2), SwapCurve::Weighted { weight_in: 1, weight_out: 4 });
+
+		let kind = PoolKind::Weighted { asset: 1u32, weight: Percent::from_percent(33) };
+		assert_eq!(
+			SwapCurve::new(&kind, &2),
+			SwapCurve::Weighted { weight_in: 67, weight_out: 33 }
+		);
+
+		let kind = PoolKind::StableSwap { amplification: 100 };
+		assert_eq!(SwapCurve::new(&kind, &2u32), SwapCurve::StableSwap { amplification: 100 });
+	}
+}
@@ -24,6 +24,8 @@
 //! This pallet allows you to:
 //!
 //!  - [create a liquidity pool](`Pallet::create_pool()`) for 2 assets
+//!  - [create a liquidity pool with a specific curve](`Pallet::create_pool_with_kind()`), see
+//!    [`PoolKind`]
 //!  - [provide the liquidity](`Pallet::add_liquidity()`) and receive back an LP token
 //!  - [exchange the LP token back to assets](`Pallet::remove_liquidity()`)
 //!  - [swap a specific amount of assets for another](`Pallet::swap_exact_tokens_for_tokens()`) if
@@ -34,12 +36,25 @@
 //!    a runtime call endpoint
 //!  - [query the size of a liquidity pool](`AssetConversionApi::get_reserves`) via a runtime api
 //!    endpoint.
+//!  - [query a time weighted average price](`AssetConversionApi::quote_price_twap`) via a runtime
+//!    api endpoint, or through the [`TwapOracle`] trait.
 //!
 //! The `quote_price_exact_tokens_for_tokens` and `quote_price_tokens_for_exact_tokens` functions
 //! both take a path parameter of the route to take. If you want to swap from native asset to
 //! non-native asset 1, you would pass in a path of `[DOT, 1]` or `[1, DOT]`. If you want to swap
 //! from non-native asset 1 to non-native asset 2, you would pass in a path of `[1, DOT, 2]`.
 //!
+//! Pools created with [`Pallet::create_pool`] price swaps with the constant product curve. Pools of
+//! pegged assets may instead use the [stable swap](`PoolKind::StableSwap`) curve, which offers far
+//! less slippage around the peg, and pools may also be [weighted](`PoolKind::Weighted`) in favour
+//! of one of their assets. Paths may go through pools of different kinds, each hop being priced by
+//! the curve of its own pool. Liquidity is always added and removed in proportion to the reserves.
+//!
+//! Spot prices can be moved at will within a block, so every pool also keeps a short history of
+//! its [cumulative prices](`PriceObservation`), from which time weighted average prices are
+//! derived. [`TwapConversion`] exposes them to consumers of a `pallet-asset-rate` like conversion,
+//! such as the `asset-tx-payment` transaction extension.
+//!
 //! (For an example of configuring this pallet to use `Location` as an asset id, see the
 //! cumulus repo).
 //!
@@ -56,8 +71,10 @@
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+mod curves;
 #[cfg(test)]
 mod mock;
+mod oracle;
 mod swap;
 #[cfg(test)]
 mod tests;
@@ -65,6 +82,7 @@ mod types;
 pub mod weights;
 #[cfg(feature = "runtime-benchmarks")]
 pub use benchmarking::{BenchmarkHelper, NativeOrWithIdFactory};
+pub use oracle::*;
 pub use pallet::*;
 pub use swap::*;
 pub use types::*;
@@ -126,7 +144,8 @@ pub mod pallet {
 			+ Unsigned
 			+ From<u32>
 			+ From<Self::Balance>
-			+ TryInto<Self::Balance>;
+			+ TryInto<Self::Balance>
+			+ Copy;
 
 		/// Type of asset class, sourced from [`Config::Assets`], utilized to offer liquidity to a
 		/// pool.
@@ -186,6 +205,17 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxSwapPathLength: Get<u32>;
 
+		/// The max number of price observations kept for each pool.
+		///
+		/// Together with [`Config::PriceObservationPeriod`], bounds the window over which average
+		/// prices can be queried.
+		#[pallet::constant]
+		type MaxPriceObservations: Get<u32>;
+
+		/// The min number of blocks between two price observations of a pool.
+		#[pallet::constant]
+		type PriceObservationPeriod: Get<BlockNumberFor<Self>>;
+
 		/// The pallet's id, used for deriving its sovereign account ID.
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
@@ -209,6 +239,21 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type NextPoolAssetId<T: Config> = StorageValue<_, T::PoolAssetId, OptionQuery>;
 
+	/// The curve of a pool, if it is not [`PoolKind::ConstantProduct`].
+	#[pallet::storage]
+	pub type PoolKinds<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::PoolId, PoolKind<T::AssetKind>, OptionQuery>;
+
+	/// The most recent price observations of each pool, oldest first.
+	#[pallet::storage]
+	pub type PriceObservations<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::PoolId,
+		BoundedVec<PriceObservation<BlockNumberFor<T>>, T::MaxPriceObservations>,
+		ValueQuery,
+	>;
+
 	// Pallet's events.
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -296,6 +341,13 @@ pub mod pallet {
 			/// The account initiating the touch.
 			who: T::AccountId,
 		},
+		/// A pool has been created with a curve other than the constant product one.
+		PoolKindSet {
+			/// The ID of the pool.
+			pool_id: T::PoolId,
+			/// The curve of the pool.
+			kind: PoolKind<T::AssetKind>,
+		},
 	}
 
 	#[pallet::error]
@@ -349,6 +401,12 @@ pub mod pallet {
 		IncorrectPoolAssetId,
 		/// The destination account cannot exist with the swapped funds.
 		BelowMinimum,
+		/// The pool kind is not valid for the pair of assets.
+		InvalidPoolKind,
+		/// The price of the swap could not be calculated on the curve of the pool.
+		CurveDidNotConverge,
+		/// The price history of the pool does not cover the requested window.
+		InsufficientPriceHistory,
 	}
 
 	#[pallet::hooks]
@@ -358,6 +416,10 @@ pub mod pallet {
 				T::MaxSwapPathLength::get() > 1,
 				"the `MaxSwapPathLength` should be greater than 1",
 			);
+			assert!(
+				T::MaxPriceObservations::get() > 0,
+				"the `MaxPriceObservations` should be greater than 0",
+			);
 		}
 	}
 
@@ -376,50 +438,7 @@ pub mod pallet {
 			asset2: Box<T::AssetKind>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-			ensure!(asset1 != asset2, Error::<T>::InvalidAssetPair);
-
-			// prepare pool_id
-			let pool_id = T::PoolLocator::pool_id(&asset1, &asset2)
-				.map_err(|_| Error::<T>::InvalidAssetPair)?;
-			ensure!(!Pools::<T>::contains_key(&pool_id), Error::<T>::PoolExists);
-
-			let pool_account =
-				T::PoolLocator::address(&pool_id).map_err(|_| Error::<T>::InvalidAssetPair)?;
-
-			// pay the setup fee
-			let fee =
-				Self::withdraw(T::PoolSetupFeeAsset::get(), &sender, T::PoolSetupFee::get(), true)?;
-			T::PoolSetupFeeTarget::on_unbalanced(fee);
-
-			if T::Assets::should_touch(*asset1.clone(), &pool_account) {
-				T::Assets::touch(*asset1, &pool_account, &sender)?
-			};
-
-			if T::Assets::should_touch(*asset2.clone(), &pool_account) {
-				T::Assets::touch(*asset2, &pool_account, &sender)?
-			};
-
-			let lp_token = NextPoolAssetId::<T>::get()
-				.or(T::PoolAssetId::initial_value())
-				.ok_or(Error::<T>::IncorrectPoolAssetId)?;
-			let next_lp_token_id = lp_token.increment().ok_or(Error::<T>::IncorrectPoolAssetId)?;
-			NextPoolAssetId::<T>::set(Some(next_lp_token_id));
-
-			T::PoolAssets::create(lp_token.clone(), pool_account.clone(), false, 1u32.into())?;
-			if T::PoolAssets::should_touch(lp_token.clone(), &pool_account) {
-				T::PoolAssets::touch(lp_token.clone(), &pool_account, &sender)?
-			};
-
-			let pool_info = PoolInfo { lp_token: lp_token.clone() };
-			Pools::<T>::insert(pool_id.clone(), pool_info);
-
-			Self::deposit_event(Event::PoolCreated {
-				creator: sender,
-				pool_id,
-				pool_account,
-				lp_token,
-			});
-
+			Self::do_create_pool(sender, *asset1, *asset2, PoolKind::ConstantProduct)?;
 			Ok(())
 		}
 
@@ -505,6 +524,7 @@ pub mod pallet {
 				Error::<T>::AmountTwoLessThanMinimal
 			);
 
+			Self::observe_price(&asset1, &asset2);
 			T::Assets::transfer(*asset1, &sender, &pool_account, amount1, Preserve)?;
 			T::Assets::transfer(*asset2, &sender, &pool_account, amount2, Preserve)?;
 
@@ -608,6 +628,7 @@ pub mod pallet {
 				Polite,
 			)?;
 
+			Self::observe_price(&asset1, &asset2);
 			T::Assets::transfer(*asset1, &pool_account, &withdraw_to, amount1, Expendable)?;
 			T::Assets::transfer(*asset2, &pool_account, &withdraw_to, amount2, Expendable)?;
 
@@ -632,7 +653,10 @@ pub mod pallet {
 		/// [`AssetConversionApi::quote_price_exact_tokens_for_tokens`] runtime call can be called
 		/// for a quote.
 		#[pallet::call_index(3)]
-		#[pallet::weight(T::WeightInfo::swap_exact_tokens_for_tokens(path.len() as u32))]
+		#[pallet::weight(
+			T::WeightInfo::swap_exact_tokens_for_tokens(path.len() as u32)
+				.saturating_add(Pallet::<T>::swap_curves_weight(path.len() as u32))
+		)]
 		pub fn swap_exact_tokens_for_tokens(
 			origin: OriginFor<T>,
 			path: Vec<Box<T::AssetKind>>,
@@ -660,7 +684,10 @@ pub mod pallet {
 		/// [`AssetConversionApi::quote_price_tokens_for_exact_tokens`] runtime call can be called
 		/// for a quote.
 		#[pallet::call_index(4)]
-		#[pallet::weight(T::WeightInfo::swap_tokens_for_exact_tokens(path.len() as u32))]
+		#[pallet::weight(
+			T::WeightInfo::swap_tokens_for_exact_tokens(path.len() as u32)
+				.saturating_add(Pallet::<T>::swap_curves_weight(path.len() as u32))
+		)]
 		pub fn swap_tokens_for_exact_tokens(
 			origin: OriginFor<T>,
 			path: Vec<Box<T::AssetKind>>,
@@ -723,9 +750,89 @@ pub mod pallet {
 			Self::deposit_event(Event::Touched { pool_id, who });
 			Ok(Some(T::WeightInfo::touch(refunds_number)).into())
 		}
+
+		/// Creates an empty liquidity pool of `asset1` and `asset2` like
+		/// [`Pallet::create_pool`], but pricing swaps with the curve of the given `kind`.
+		///
+		/// The curve of a pool can't be changed once it is created.
+		///
+		/// Emits `PoolCreated` and, unless the kind is [`PoolKind::ConstantProduct`],
+		/// `PoolKindSet` events when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::create_pool_with_kind())]
+		pub fn create_pool_with_kind(
+			origin: OriginFor<T>,
+			asset1: Box<T::AssetKind>,
+			asset2: Box<T::AssetKind>,
+			kind: Box<PoolKind<T::AssetKind>>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_create_pool(sender, *asset1, *asset2, *kind)?;
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// Create a pool of `asset1` and `asset2` with the curve of the given `kind`.
+		pub(crate) fn do_create_pool(
+			sender: T::AccountId,
+			asset1: T::AssetKind,
+			asset2: T::AssetKind,
+			kind: PoolKind<T::AssetKind>,
+		) -> DispatchResult {
+			ensure!(asset1 != asset2, Error::<T>::InvalidAssetPair);
+			Self::ensure_valid_pool_kind(&kind, &asset1, &asset2)?;
+
+			// prepare pool_id
+			let pool_id = T::PoolLocator::pool_id(&asset1, &asset2)
+				.map_err(|_| Error::<T>::InvalidAssetPair)?;
+			ensure!(!Pools::<T>::contains_key(&pool_id), Error::<T>::PoolExists);
+
+			let pool_account =
+				T::PoolLocator::address(&pool_id).map_err(|_| Error::<T>::InvalidAssetPair)?;
+
+			// pay the setup fee
+			let fee =
+				Self::withdraw(T::PoolSetupFeeAsset::get(), &sender, T::PoolSetupFee::get(), true)?;
+			T::PoolSetupFeeTarget::on_unbalanced(fee);
+
+			if T::Assets::should_touch(asset1.clone(), &pool_account) {
+				T::Assets::touch(asset1, &pool_account, &sender)?
+			};
+
+			if T::Assets::should_touch(asset2.clone(), &pool_account) {
+				T::Assets::touch(asset2, &pool_account, &sender)?
+			};
+
+			let lp_token = NextPoolAssetId::<T>::get()
+				.or(T::PoolAssetId::initial_value())
+				.ok_or(Error::<T>::IncorrectPoolAssetId)?;
+			let next_lp_token_id = lp_token.increment().ok_or(Error::<T>::IncorrectPoolAssetId)?;
+			NextPoolAssetId::<T>::set(Some(next_lp_token_id));
+
+			T::PoolAssets::create(lp_token.clone(), pool_account.clone(), false, 1u32.into())?;
+			if T::PoolAssets::should_touch(lp_token.clone(), &pool_account) {
+				T::PoolAssets::touch(lp_token.clone(), &pool_account, &sender)?
+			};
+
+			let pool_info = PoolInfo { lp_token: lp_token.clone() };
+			Pools::<T>::insert(pool_id.clone(), pool_info);
+
+			Self::deposit_event(Event::PoolCreated {
+				creator: sender,
+				pool_id: pool_id.clone(),
+				pool_account,
+				lp_token,
+			});
+
+			if kind != PoolKind::ConstantProduct {
+				PoolKinds::<T>::insert(pool_id.clone(), kind.clone());
+				Self::deposit_event(Event::PoolKindSet { pool_id, kind });
+			}
+
+			Ok(())
+		}
+
 		/// Swap exactly `amount_in` of asset `path[0]` for asset `path[1]`.
 		/// If an `amount_out_min` is specified, it will return an error if it is unable to acquire
 		/// the amount desired.
@@ -956,6 +1063,12 @@ pub mod pallet {
 			credit_in: CreditOf<T>,
 			path: &BalancePath<T>,
 		) -> Result<CreditOf<T>, (CreditOf<T>, DispatchError)> {
+			for hop in path.windows(2) {
+				if let [(asset1, _), (asset2, _)] = hop {
+					Self::observe_price(asset1, asset2);
+				}
+			}
+
 			let resolve_path = || -> Result<CreditOf<T>, DispatchError> {
 				for pos in 0..=path.len() {
 					if let Some([(asset1, _), (asset2, amount_out)]) = path.get(pos..=pos + 1) {
@@ -1025,7 +1138,7 @@ pub mod pallet {
 
 		/// Get the `owner`'s balance of `asset`, which could be the chain's native asset or another
 		/// fungible. Returns a value in the form of an `Balance`.
-		fn get_balance(owner: &T::AccountId, asset: T::AssetKind) -> T::Balance {
+		pub(crate) fn get_balance(owner: &T::AccountId, asset: T::AssetKind) -> T::Balance {
 			T::Assets::reducible_balance(asset, owner, Expendable, Polite)
 		}
 
@@ -1066,8 +1179,9 @@ pub mod pallet {
 					},
 				};
 				let (reserve_in, reserve_out) = Self::get_reserves(asset1.clone(), asset2.clone())?;
+				let curve = Self::swap_curve(asset1, &asset2)?;
 				balance_path.push((asset2, amount_in));
-				amount_in = Self::get_amount_in(&amount_in, &reserve_in, &reserve_out)?;
+				amount_in = Self::get_amount_in_for(curve, &amount_in, &reserve_in, &reserve_out)?;
 			}
 			balance_path.reverse();
 
@@ -1092,8 +1206,10 @@ pub mod pallet {
 					},
 				};
 				let (reserve_in, reserve_out) = Self::get_reserves(asset1.clone(), asset2.clone())?;
+				let curve = Self::swap_curve(&asset1, asset2)?;
 				balance_path.push((asset1, amount_out));
-				amount_out = Self::get_amount_out(&amount_out, &reserve_in, &reserve_out)?;
+				amount_out =
+					Self::get_amount_out_for(curve, &amount_out, &reserve_in, &reserve_out)?;
 			}
 			Ok(balance_path)
 		}
@@ -1106,14 +1222,15 @@ pub mod pallet {
 			include_fee: bool,
 		) -> Option<T::Balance> {
 			let pool_account = T::PoolLocator::pool_address(&asset1, &asset2).ok()?;
+			let curve = Self::swap_curve(&asset1, &asset2).ok()?;
 
 			let balance1 = Self::get_balance(&pool_account, asset1);
 			let balance2 = Self::get_balance(&pool_account, asset2);
 			if !balance1.is_zero() {
 				if include_fee {
-					Self::get_amount_out(&amount, &balance1, &balance2).ok()
+					Self::get_amount_out_for(curve, &amount, &balance1, &balance2).ok()
 				} else {
-					Self::quote(&amount, &balance1, &balance2).ok()
+					Self::quote_for(curve, &amount, &balance1, &balance2).ok()
 				}
 			} else {
 				None
@@ -1128,14 +1245,16 @@ pub mod pallet {
 			include_fee: bool,
 		) -> Option<T::Balance> {
 			let pool_account = T::PoolLocator::pool_address(&asset1, &asset2).ok()?;
+			let curve = Self::swap_curve(&asset1, &asset2).ok()?;
 
 			let balance1 = Self::get_balance(&pool_account, asset1);
 			let balance2 = Self::get_balance(&pool_account, asset2);
 			if !balance1.is_zero() {
 				if include_fee {
-					Self::get_amount_in(&amount, &balance1, &balance2).ok()
+					Self::get_amount_in_for(curve, &amount, &balance1, &balance2).ok()
 				} else {
-					Self::quote(&amount, &balance2, &balance1).ok()
+					let reverse_curve = Self::swap_curve(&asset2, &asset1).ok()?;
+					Self::quote_for(reverse_curve, &amount, &balance2, &balance1).ok()
 				}
 			} else {
 				None
@@ -1294,6 +1413,7 @@ pub mod pallet {
 sp_api::decl_runtime_apis! {
 	/// This runtime api allows people to query the size of the liquidity pools
 	/// and quote prices for swaps.
+	#[api_version(2)]
 	pub trait AssetConversionApi<Balance, AssetId>
 	where
 		Balance: frame_support::traits::tokens::Balance + MaybeDisplay,
@@ -1323,6 +1443,18 @@ sp_api::decl_runtime_apis! {
 
 		/// Returns the size of the liquidity pool for the given asset pair.
 		fn get_reserves(asset1: AssetId, asset2: AssetId) -> Option<(Balance, Balance)>;
+
+		/// Provides the amount of `asset2` worth the `amount` of `asset1` at the price of their
+		/// pool averaged over the last `window` blocks.
+		///
+		/// Unlike the other quotes, the average price can't be moved by the swaps of a single
+		/// block.
+		fn quote_price_twap(
+			asset1: AssetId,
+			asset2: AssetId,
+			amount: Balance,
+			window: u32,
+		) -> Option<Balance>;
 	}
 }
 
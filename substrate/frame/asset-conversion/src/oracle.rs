@@ -0,0 +1,345 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Time weighted average prices of the pools.
+//!
+//! Before the reserves of a pool change for the first time in a block, the prices of its assets
+//! at the end of the previous change are accumulated into [`PriceObservations`]. Since the swaps
+//! of a block only affect the prices accumulated from the next block on, the average prices can
+//! only be moved by holding the pool off balance over several blocks.
+//!
+//! The prices since the latest observation are read from the reserves of the pool when they are
+//! needed. Assets transferred straight into the pool account, without going through this pallet,
+//! are therefore priced in from the latest observation on, even if they were transferred in the
+//! current block, and move the average prices by the weight of the blocks since then. Such
+//! transfers are donations to the liquidity providers which arbitrageurs can take back, so the
+//! average prices of a pool stay expensive to move, but consumers should use windows spanning many
+//! [`Config::PriceObservationPeriod`]s and keep the liquidity of the pools they rely on deep.
+
+use super::*;
+use codec::Encode;
+use core::marker::PhantomData;
+use frame_support::traits::tokens::{ConversionFromAssetBalance, ConversionToAssetBalance};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_arithmetic::{
+	helpers_128bit::multiply_by_rational_with_rounding, FixedPointNumber, FixedU128, Rounding,
+};
+use sp_runtime::{traits::AtLeast32BitUnsigned, SaturatedConversion};
+
+/// Trait providing time weighted average prices between asset classes.
+///
+/// Unlike the spot prices of [`QuotePrice`], the average prices can't be moved by swaps made
+/// within a single block, which makes them suitable to value collateral or fees.
+pub trait TwapOracle {
+	/// Measurement units of the asset classes for pricing.
+	type Balance: Balance;
+	/// Type representing the kind of assets for which the price is being quoted.
+	type AssetKind;
+	/// Type in which the averaging window is measured.
+	type BlockNumber;
+	/// Returns the price of one unit of `base` in units of `quote`, averaged over the last
+	/// `window` blocks.
+	///
+	/// If the pool does not exist or its price history does not cover the `window`, `None` is
+	/// returned.
+	fn twap(
+		base: &Self::AssetKind,
+		quote: &Self::AssetKind,
+		window: Self::BlockNumber,
+	) -> Option<FixedU128>;
+	/// Quotes the amount of `quote` worth the `amount` of `base` at the price averaged over the
+	/// last `window` blocks.
+	///
+	/// If the pool does not exist or its price history does not cover the `window`, `None` is
+	/// returned.
+	fn quote_price_twap(
+		base: &Self::AssetKind,
+		quote: &Self::AssetKind,
+		amount: Self::Balance,
+		window: Self::BlockNumber,
+	) -> Option<Self::Balance>;
+}
+
+impl<T: Config> TwapOracle for Pallet<T> {
+	type Balance = T::Balance;
+	type AssetKind = T::AssetKind;
+	type BlockNumber = BlockNumberFor<T>;
+
+	fn twap(
+		base: &Self::AssetKind,
+		quote: &Self::AssetKind,
+		window: Self::BlockNumber,
+	) -> Option<FixedU128> {
+		Self::twap(base, quote, window)
+	}
+
+	fn quote_price_twap(
+		base: &Self::AssetKind,
+		quote: &Self::AssetKind,
+		amount: Self::Balance,
+		window: Self::BlockNumber,
+	) -> Option<Self::Balance> {
+		Self::quote_price_twap(base, quote, amount, window)
+	}
+}
+
+impl<BlockNumber: AtLeast32BitUnsigned + Copy> PriceObservation<BlockNumber> {
+	/// The observation at `block`, given the prices of the pool since this observation.
+	fn advance(&self, block: BlockNumber, price1: FixedU128, price2: FixedU128) -> Self {
+		let elapsed: u128 = block.saturating_sub(self.block).saturated_into();
+		Self {
+			block,
+			price1_cumulative: self
+				.price1_cumulative
+				.wrapping_add(price1.into_inner().wrapping_mul(elapsed)),
+			price2_cumulative: self
+				.price2_cumulative
+				.wrapping_add(price2.into_inner().wrapping_mul(elapsed)),
+		}
+	}
+
+	/// The observation at `block`, which lies between this observation and the `next` one.
+	fn interpolate(&self, next: &Self, block: BlockNumber) -> Self {
+		let span: u128 = next.block.saturating_sub(self.block).saturated_into();
+		if span.is_zero() {
+			return self.clone()
+		}
+		let elapsed: u128 = block.saturating_sub(self.block).saturated_into::<u128>().min(span);
+		let lerp = |from: u128, to: u128| {
+			let delta = multiply_by_rational_with_rounding(
+				to.wrapping_sub(from),
+				elapsed,
+				span,
+				Rounding::Down,
+			);
+			from.wrapping_add(delta.unwrap_or_default())
+		};
+		Self {
+			block,
+			price1_cumulative: lerp(self.price1_cumulative, next.price1_cumulative),
+			price2_cumulative: lerp(self.price2_cumulative, next.price2_cumulative),
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Returns the price of one unit of `base` in units of `quote`, averaged over the last
+	/// `window` blocks.
+	///
+	/// The blocks since the latest observation are priced at the current reserves of the pool,
+	/// including any assets transferred straight into the pool account in this block. See the
+	/// [module docs](self).
+	pub fn twap(
+		base: &T::AssetKind,
+		quote: &T::AssetKind,
+		window: BlockNumberFor<T>,
+	) -> Option<FixedU128> {
+		if window.is_zero() || base == quote {
+			return None
+		}
+		let pool_id = T::PoolLocator::pool_id(base, quote).ok()?;
+		let pool_account = T::PoolLocator::address(&pool_id).ok()?;
+		let observations = PriceObservations::<T>::get(&pool_id);
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let start = now.checked_sub(&window)?;
+		let index = observations.iter().rposition(|observation| observation.block <= start)?;
+
+		let (price1, price2) = Self::pool_prices(&pool_account, base, quote);
+		let current = observations.last()?.advance(now, price1, price2);
+		let next = observations.get(index + 1).unwrap_or(&current);
+		let past = observations.get(index)?.interpolate(next, start);
+
+		let cumulative = if Self::is_first_asset(base, quote) {
+			current.price1_cumulative.wrapping_sub(past.price1_cumulative)
+		} else {
+			current.price2_cumulative.wrapping_sub(past.price2_cumulative)
+		};
+		Some(FixedU128::from_inner(cumulative / window.saturated_into::<u128>()))
+	}
+
+	/// Quotes the amount of `quote` worth the `amount` of `base` at the price averaged over the
+	/// last `window` blocks.
+	pub fn quote_price_twap(
+		base: &T::AssetKind,
+		quote: &T::AssetKind,
+		amount: T::Balance,
+		window: BlockNumberFor<T>,
+	) -> Option<T::Balance> {
+		Self::twap(base, quote, window)?.checked_mul_int(amount)
+	}
+
+	/// Accumulates the prices of the pool of `asset1` and `asset2` into its observations.
+	///
+	/// Must be called before its reserves change.
+	pub(crate) fn observe_price(asset1: &T::AssetKind, asset2: &T::AssetKind) {
+		let Ok(pool_id) = T::PoolLocator::pool_id(asset1, asset2) else { return };
+		let Ok(pool_account) = T::PoolLocator::address(&pool_id) else { return };
+		let mut observations = PriceObservations::<T>::get(&pool_id);
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let observation = match observations.last() {
+			// The prices were already accumulated in this block.
+			Some(latest) if latest.block >= now => return,
+			Some(latest) => {
+				let (price1, price2) = Self::pool_prices(&pool_account, asset1, asset2);
+				latest.advance(now, price1, price2)
+			},
+			None => PriceObservation { block: now, ..Default::default() },
+		};
+
+		// Observations are kept at least `PriceObservationPeriod` apart, the latest one moving
+		// forward until then.
+		let len = observations.len();
+		let merge = len > 1 &&
+			observations.get(len - 2).map_or(false, |previous| {
+				now < previous.block.saturating_add(T::PriceObservationPeriod::get())
+			});
+		if merge {
+			if let Some(latest) = observations.last_mut() {
+				*latest = observation;
+			}
+		} else {
+			if observations.is_full() {
+				observations.remove(0);
+			}
+			let _ = observations.try_push(observation);
+		}
+		PriceObservations::<T>::insert(&pool_id, observations);
+	}
+
+	/// The current prices of the first asset of the pool in the second one and vice versa.
+	fn pool_prices(
+		pool_account: &T::AccountId,
+		asset1: &T::AssetKind,
+		asset2: &T::AssetKind,
+	) -> (FixedU128, FixedU128) {
+		let (first, second) =
+			if Self::is_first_asset(asset1, asset2) { (asset1, asset2) } else { (asset2, asset1) };
+		let reserve1 = Self::get_balance(pool_account, first.clone());
+		let reserve2 = Self::get_balance(pool_account, second.clone());
+		if reserve1.is_zero() || reserve2.is_zero() {
+			return (FixedU128::zero(), FixedU128::zero())
+		}
+		(
+			Self::marginal_price(first, second, reserve1, reserve2),
+			Self::marginal_price(second, first, reserve2, reserve1),
+		)
+	}
+
+	/// The marginal price of `asset_in` in `asset_out` on the curve of their pool, given its
+	/// reserves.
+	fn marginal_price(
+		asset_in: &T::AssetKind,
+		asset_out: &T::AssetKind,
+		reserve_in: T::Balance,
+		reserve_out: T::Balance,
+	) -> FixedU128 {
+		// Quoting the whole reserve keeps the precision of the price. Should the curve fail to
+		// quote it, fall back to the ratio of the reserves.
+		let quote = Self::swap_curve(asset_in, asset_out)
+			.and_then(|curve| Self::quote_for(curve, &reserve_in, &reserve_in, &reserve_out))
+			.unwrap_or(reserve_out);
+		FixedU128::saturating_from_rational(
+			quote.saturated_into::<u128>(),
+			reserve_in.saturated_into::<u128>(),
+		)
+	}
+
+	/// Whether `asset1` is the first asset of its pool with `asset2` for the observations.
+	fn is_first_asset(asset1: &T::AssetKind, asset2: &T::AssetKind) -> bool {
+		asset1.encode() <= asset2.encode()
+	}
+}
+
+/// Converts balances between the `Native` asset and the other assets at the price of their pool
+/// averaged over the last `Window` blocks.
+///
+/// It may be used in place of `pallet-asset-rate`, for instance to convert the fees of the
+/// `asset-tx-payment` transaction extension.
+pub struct TwapConversion<T, Native, Window>(PhantomData<(T, Native, Window)>);
+
+impl<T, Native, Window> ConversionToAssetBalance<T::Balance, T::AssetKind, T::Balance>
+	for TwapConversion<T, Native, Window>
+where
+	T: Config,
+	Native: Get<T::AssetKind>,
+	Window: Get<BlockNumberFor<T>>,
+{
+	type Error = Error<T>;
+
+	fn to_asset_balance(
+		balance: T::Balance,
+		asset_id: T::AssetKind,
+	) -> Result<T::Balance, Self::Error> {
+		let native = Native::get();
+		if asset_id == native {
+			return Ok(balance)
+		}
+		Pallet::<T>::quote_price_twap(&native, &asset_id, balance, Window::get())
+			.ok_or(Error::<T>::InsufficientPriceHistory)
+	}
+}
+
+impl<T, Native, Window> ConversionFromAssetBalance<T::Balance, T::AssetKind, T::Balance>
+	for TwapConversion<T, Native, Window>
+where
+	T: Config,
+	Native: Get<T::AssetKind>,
+	Window: Get<BlockNumberFor<T>>,
+{
+	type Error = Error<T>;
+
+	fn from_asset_balance(
+		balance: T::Balance,
+		asset_id: T::AssetKind,
+	) -> Result<T::Balance, Self::Error> {
+		let native = Native::get();
+		if asset_id == native {
+			return Ok(balance)
+		}
+		Pallet::<T>::quote_price_twap(&asset_id, &native, balance, Window::get())
+			.ok_or(Error::<T>::InsufficientPriceHistory)
+	}
+
+	/// Seeds the price history of the pool of the `asset_id` with the native asset, at a price of
+	/// one over the whole `Window`, moving to the end of the window if needed.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn ensure_successful(asset_id: T::AssetKind) {
+		let native = Native::get();
+		let Ok(pool_id) = T::PoolLocator::pool_id(&asset_id, &native) else { return };
+		let window = Window::get();
+		let mut now = frame_system::Pallet::<T>::block_number();
+		if now < window {
+			now = window;
+			frame_system::Pallet::<T>::set_block_number(now);
+		}
+		let cumulative = FixedU128::one().into_inner().saturating_mul(window.saturated_into());
+		let observations = alloc::vec![
+			PriceObservation { block: now - window, ..Default::default() },
+			PriceObservation {
+				block: now,
+				price1_cumulative: cumulative,
+				price2_cumulative: cumulative,
+			},
+		];
+		PriceObservations::<T>::insert(
+			&pool_id,
+			frame_support::BoundedVec::truncate_from(observations),
+		);
+	}
+}
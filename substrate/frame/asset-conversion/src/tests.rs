@@ -24,10 +24,10 @@ use frame_support::{
 		fungible::{Inspect as FungibleInspect, NativeOrWithId},
 		fungibles,
 		fungibles::{Inspect, InspectEnumerable},
-		Get,
+		ConstU64, Get,
 	},
 };
-use sp_arithmetic::Permill;
+use sp_arithmetic::{FixedPointNumber, FixedU128, Percent, Permill};
 use sp_runtime::{DispatchError, TokenError};
 
 fn events() -> Vec<Event<Test>> {
@@ -2493,3 +2493,704 @@ fn swap_credit_invalid_path() {
 		assert_eq!(error, (expected_credit_in, Error::<Test>::InvalidPath.into()));
 	});
 }
+
+fn create_pool_with_liquidity(
+	user: u128,
+	asset1: NativeOrWithId<u32>,
+	asset2: NativeOrWithId<u32>,
+	kind: PoolKind<NativeOrWithId<u32>>,
+	liquidity1: u128,
+	liquidity2: u128,
+) {
+	assert_ok!(AssetConversion::create_pool_with_kind(
+		RuntimeOrigin::signed(user),
+		Box::new(asset1.clone()),
+		Box::new(asset2.clone()),
+		Box::new(kind),
+	));
+	assert_ok!(AssetConversion::add_liquidity(
+		RuntimeOrigin::signed(user),
+		Box::new(asset1),
+		Box::new(asset2),
+		liquidity1,
+		liquidity2,
+		1,
+		1,
+		user,
+	));
+}
+
+#[test]
+fn can_create_pool_with_kind() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+		let token_3 = NativeOrWithId::WithId(3);
+		create_tokens(user, vec![token_2.clone(), token_3.clone()]);
+
+		let kind = PoolKind::StableSwap { amplification: 100 };
+		assert_ok!(AssetConversion::create_pool_with_kind(
+			RuntimeOrigin::signed(user),
+			Box::new(token_2.clone()),
+			Box::new(token_1.clone()),
+			Box::new(kind.clone()),
+		));
+
+		let pool_id = (token_1.clone(), token_2.clone());
+		assert_eq!(PoolKinds::<Test>::get(&pool_id), Some(kind.clone()));
+		assert!(events().contains(&Event::<Test>::PoolKindSet { pool_id: pool_id.clone(), kind }));
+		assert_eq!(pools(), vec![pool_id]);
+
+		// constant product pools don't need to store their kind.
+		assert_ok!(AssetConversion::create_pool_with_kind(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_3.clone()),
+			Box::new(PoolKind::ConstantProduct),
+		));
+		let pool_id = (token_1.clone(), token_3.clone());
+		assert!(Pools::<Test>::contains_key(&pool_id));
+		assert!(!PoolKinds::<Test>::contains_key(&pool_id));
+		assert!(!events().iter().any(|e| matches!(e, Event::<Test>::PoolKindSet { .. })));
+	});
+}
+
+#[test]
+fn create_pool_with_invalid_kind_fails() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+		create_tokens(user, vec![token_2.clone()]);
+
+		for kind in [
+			PoolKind::StableSwap { amplification: 0 },
+			PoolKind::StableSwap { amplification: MAX_AMPLIFICATION + 1 },
+			PoolKind::Weighted {
+				asset: NativeOrWithId::WithId(3),
+				weight: Percent::from_percent(50),
+			},
+			PoolKind::Weighted { asset: token_2.clone(), weight: Percent::zero() },
+			PoolKind::Weighted { asset: token_2.clone(), weight: Percent::one() },
+		] {
+			assert_noop!(
+				AssetConversion::create_pool_with_kind(
+					RuntimeOrigin::signed(user),
+					Box::new(token_1.clone()),
+					Box::new(token_2.clone()),
+					Box::new(kind),
+				),
+				Error::<Test>::InvalidPoolKind
+			);
+		}
+	});
+}
+
+#[test]
+fn stable_swap_has_less_slippage_for_pegged_assets() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let (token_2, token_3, token_4, token_5) = (
+			NativeOrWithId::WithId(2),
+			NativeOrWithId::WithId(3),
+			NativeOrWithId::WithId(4),
+			NativeOrWithId::WithId(5),
+		);
+		create_tokens(
+			user,
+			vec![token_2.clone(), token_3.clone(), token_4.clone(), token_5.clone()],
+		);
+		for id in 2..=5 {
+			assert_ok!(Assets::mint(RuntimeOrigin::signed(user), id, user, 2_000_000));
+		}
+
+		let liquidity = 1_000_000;
+		create_pool_with_liquidity(
+			user,
+			token_2.clone(),
+			token_3.clone(),
+			PoolKind::StableSwap { amplification: 100 },
+			liquidity,
+			liquidity,
+		);
+		create_pool_with_liquidity(
+			user,
+			token_4.clone(),
+			token_5.clone(),
+			PoolKind::ConstantProduct,
+			liquidity,
+			liquidity,
+		);
+
+		// at the peg, both curves quote the same price.
+		assert_eq!(
+			AssetConversion::quote_price_exact_tokens_for_tokens(
+				token_2.clone(),
+				token_3.clone(),
+				1_000,
+				false
+			),
+			Some(1_000)
+		);
+		assert_eq!(
+			AssetConversion::quote_price_exact_tokens_for_tokens(
+				token_4.clone(),
+				token_5.clone(),
+				1_000,
+				false
+			),
+			Some(1_000)
+		);
+
+		let amount_in = 100_000;
+		let stable_out = AssetConversion::quote_price_exact_tokens_for_tokens(
+			token_2.clone(),
+			token_3.clone(),
+			amount_in,
+			true,
+		)
+		.unwrap();
+		let constant_product_out = AssetConversion::quote_price_exact_tokens_for_tokens(
+			token_4.clone(),
+			token_5.clone(),
+			amount_in,
+			true,
+		)
+		.unwrap();
+		assert_eq!(stable_out, 99_650);
+		assert_eq!(constant_product_out, 90_661);
+
+		let prior_balance = balance(user, token_3.clone());
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			RuntimeOrigin::signed(user),
+			bvec![token_2.clone(), token_3.clone()],
+			amount_in,
+			1,
+			user,
+			false,
+		));
+		assert_eq!(balance(user, token_3.clone()), prior_balance + stable_out);
+
+		// the price moves against the trader after the swap.
+		assert!(
+			AssetConversion::quote_price_exact_tokens_for_tokens(
+				token_2.clone(),
+				token_3.clone(),
+				1_000,
+				false
+			)
+			.unwrap() < 1_000
+		);
+
+		// the pool can't be drained.
+		assert_noop!(
+			AssetConversion::swap_tokens_for_exact_tokens(
+				RuntimeOrigin::signed(user),
+				bvec![token_2.clone(), token_3.clone()],
+				liquidity,
+				u128::MAX,
+				user,
+				false,
+			),
+			Error::<Test>::AmountOutTooHigh
+		);
+	});
+}
+
+#[test]
+fn can_swap_on_weighted_pool() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_2 = NativeOrWithId::WithId(2);
+		let token_3 = NativeOrWithId::WithId(3);
+		create_tokens(user, vec![token_2.clone(), token_3.clone()]);
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 1_000_000));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 3, user, 1_000_000));
+
+		// 80% of the value of the pool is in `token_2`, so both assets have the same price.
+		create_pool_with_liquidity(
+			user,
+			token_2.clone(),
+			token_3.clone(),
+			PoolKind::Weighted { asset: token_2.clone(), weight: Percent::from_percent(80) },
+			800_000,
+			200_000,
+		);
+
+		assert_eq!(
+			AssetConversion::quote_price_exact_tokens_for_tokens(
+				token_2.clone(),
+				token_3.clone(),
+				1_000,
+				false
+			),
+			Some(1_000)
+		);
+		assert_eq!(
+			AssetConversion::quote_price_tokens_for_exact_tokens(
+				token_2.clone(),
+				token_3.clone(),
+				1_000,
+				false
+			),
+			Some(1_000)
+		);
+
+		let amount_in = 1_000;
+		let quoted_out = AssetConversion::quote_price_exact_tokens_for_tokens(
+			token_2.clone(),
+			token_3.clone(),
+			amount_in,
+			true,
+		);
+		assert_eq!(quoted_out, Some(993));
+		assert_eq!(
+			AssetConversion::get_weighted_amount_out(&amount_in, &800_000, &200_000, 4, 1),
+			Ok(993)
+		);
+
+		let prior_balance = balance(user, token_3.clone());
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			RuntimeOrigin::signed(user),
+			bvec![token_2.clone(), token_3.clone()],
+			amount_in,
+			993,
+			user,
+			false,
+		));
+		assert_eq!(balance(user, token_3.clone()), prior_balance + 993);
+
+		// swapping back for the same amount costs more because of the fee and price impact.
+		let amount_out = 993;
+		let quoted_in = AssetConversion::quote_price_tokens_for_exact_tokens(
+			token_3.clone(),
+			token_2.clone(),
+			amount_out,
+			true,
+		)
+		.unwrap();
+		assert!(quoted_in > amount_out);
+
+		let prior_balance = balance(user, token_3.clone());
+		assert_ok!(AssetConversion::swap_tokens_for_exact_tokens(
+			RuntimeOrigin::signed(user),
+			bvec![token_3.clone(), token_2.clone()],
+			amount_out,
+			quoted_in,
+			user,
+			false,
+		));
+		assert_eq!(balance(user, token_3.clone()), prior_balance - quoted_in);
+	});
+}
+
+#[test]
+fn can_swap_across_mixed_pool_kinds() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+		let token_3 = NativeOrWithId::WithId(3);
+		create_tokens(user, vec![token_2.clone(), token_3.clone()]);
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 2_000_000));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 2_000_000));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 3, user, 2_000_000));
+
+		create_pool_with_liquidity(
+			user,
+			token_1.clone(),
+			token_2.clone(),
+			PoolKind::StableSwap { amplification: 100 },
+			1_000_000,
+			1_000_000,
+		);
+		create_pool_with_liquidity(
+			user,
+			token_1.clone(),
+			token_3.clone(),
+			PoolKind::Weighted { asset: token_1.clone(), weight: Percent::from_percent(20) },
+			200_000,
+			800_000,
+		);
+
+		// each hop is priced by the curve of its pool.
+		let (reserve_2, reserve_native_1) =
+			AssetConversion::get_reserves(token_2.clone(), token_1.clone()).unwrap();
+		let (reserve_native_3, reserve_3) =
+			AssetConversion::get_reserves(token_1.clone(), token_3.clone()).unwrap();
+		let amount_in = 10_000;
+		let amount_native =
+			AssetConversion::get_stable_amount_out(&amount_in, &reserve_2, &reserve_native_1, 100)
+				.unwrap();
+		let expected_out = AssetConversion::get_weighted_amount_out(
+			&amount_native,
+			&reserve_native_3,
+			&reserve_3,
+			1,
+			4,
+		)
+		.unwrap();
+		// little slippage on the stable pool, more on the weighted one.
+		assert!(amount_native > 9_950 && amount_native < amount_in);
+		assert!(expected_out > 9_600 && expected_out < amount_native);
+
+		let prior_balance = balance(user, token_3.clone());
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			RuntimeOrigin::signed(user),
+			bvec![token_2.clone(), token_1.clone(), token_3.clone()],
+			amount_in,
+			expected_out,
+			user,
+			false,
+		));
+		assert_eq!(balance(user, token_3.clone()), prior_balance + expected_out);
+
+		// and in the opposite direction for an exact amount out.
+		let (reserve_native_1, reserve_2) =
+			AssetConversion::get_reserves(token_1.clone(), token_2.clone()).unwrap();
+		let (reserve_3, reserve_native_3) =
+			AssetConversion::get_reserves(token_3.clone(), token_1.clone()).unwrap();
+		let amount_out = 5_000;
+		let amount_native =
+			AssetConversion::get_stable_amount_in(&amount_out, &reserve_native_1, &reserve_2, 100)
+				.unwrap();
+		let expected_in = AssetConversion::get_weighted_amount_in(
+			&amount_native,
+			&reserve_3,
+			&reserve_native_3,
+			4,
+			1,
+		)
+		.unwrap();
+
+		let prior_balance_2 = balance(user, token_2.clone());
+		let prior_balance_3 = balance(user, token_3.clone());
+		assert_ok!(AssetConversion::swap_tokens_for_exact_tokens(
+			RuntimeOrigin::signed(user),
+			bvec![token_3.clone(), token_1.clone(), token_2.clone()],
+			amount_out,
+			expected_in,
+			user,
+			false,
+		));
+		assert_eq!(balance(user, token_2.clone()), prior_balance_2 + amount_out);
+		assert_eq!(balance(user, token_3.clone()), prior_balance_3 - expected_in);
+	});
+}
+
+#[test]
+fn quote_price_matches_execution_for_stable_swap() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let user2 = 2;
+		let token_2 = NativeOrWithId::WithId(2);
+		let token_3 = NativeOrWithId::WithId(3);
+		create_tokens(user, vec![token_2.clone(), token_3.clone()]);
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 1_000_000));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 3, user, 1_000_000));
+
+		// an unbalanced pool.
+		create_pool_with_liquidity(
+			user,
+			token_2.clone(),
+			token_3.clone(),
+			PoolKind::StableSwap { amplification: 50 },
+			700_000,
+			300_000,
+		);
+
+		let amount = 20_000;
+		let quoted_out = AssetConversion::quote_price_exact_tokens_for_tokens(
+			token_3.clone(),
+			token_2.clone(),
+			amount,
+			true,
+		)
+		.unwrap();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 3, user2, amount));
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			RuntimeOrigin::signed(user2),
+			bvec![token_3.clone(), token_2.clone()],
+			amount,
+			1,
+			user2,
+			false,
+		));
+		assert_eq!(balance(user2, token_2.clone()), quoted_out);
+		assert_eq!(balance(user2, token_3.clone()), 0);
+
+		let quoted_in = AssetConversion::quote_price_tokens_for_exact_tokens(
+			token_3.clone(),
+			token_2.clone(),
+			amount,
+			true,
+		)
+		.unwrap();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 3, user2, quoted_in));
+		assert_ok!(AssetConversion::swap_tokens_for_exact_tokens(
+			RuntimeOrigin::signed(user2),
+			bvec![token_3.clone(), token_2.clone()],
+			amount,
+			quoted_in,
+			user2,
+			false,
+		));
+		assert_eq!(balance(user2, token_2.clone()), quoted_out + amount);
+		assert_eq!(balance(user2, token_3.clone()), 0);
+	});
+}
+
+fn setup_native_pool_for_twap(user: u128, token_2: NativeOrWithId<u32>) {
+	create_tokens(user, vec![token_2.clone()]);
+	assert_ok!(AssetConversion::create_pool(
+		RuntimeOrigin::signed(user),
+		Box::new(NativeOrWithId::Native),
+		Box::new(token_2.clone())
+	));
+	assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 20000));
+	assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 1000));
+	assert_ok!(AssetConversion::add_liquidity(
+		RuntimeOrigin::signed(user),
+		Box::new(NativeOrWithId::Native),
+		Box::new(token_2),
+		10000,
+		200,
+		1,
+		1,
+		user,
+	));
+}
+
+#[test]
+fn twap_is_time_weighted_price() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+		setup_native_pool_for_twap(user, token_2.clone());
+
+		// no history yet.
+		assert_eq!(AssetConversion::twap(&token_2, &token_1, 1), None);
+
+		System::set_block_number(11);
+		assert_eq!(AssetConversion::twap(&token_2, &token_1, 10), Some(FixedU128::from_u32(50)));
+		assert_eq!(
+			<AssetConversion as TwapOracle>::twap(&token_1, &token_2, 10),
+			Some(FixedU128::from_rational(1, 50))
+		);
+		assert_eq!(AssetConversion::quote_price_twap(&token_2, &token_1, 3, 5), Some(150));
+		assert_eq!(AssetConversion::twap(&token_2, &token_1, 11), None);
+		assert_eq!(AssetConversion::twap(&token_2, &token_1, 0), None);
+		assert_eq!(AssetConversion::twap(&token_2, &NativeOrWithId::WithId(3), 10), None);
+
+		// a swap moves the spot price, but not the average price within the same block.
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			RuntimeOrigin::signed(user),
+			bvec![token_2.clone(), token_1.clone()],
+			200,
+			1,
+			user,
+			false,
+		));
+		assert_eq!(
+			AssetConversion::quote_price_exact_tokens_for_tokens(
+				token_2.clone(),
+				token_1.clone(),
+				1,
+				false
+			),
+			Some(12)
+		);
+		assert_eq!(AssetConversion::twap(&token_2, &token_1, 10), Some(FixedU128::from_u32(50)));
+
+		// from the next block on, the new price is accounted for its duration.
+		System::set_block_number(12);
+		let (reserve_1, reserve_2) =
+			AssetConversion::get_reserves(token_1.clone(), token_2.clone()).unwrap();
+		let price = FixedU128::saturating_from_rational(reserve_1, reserve_2);
+		let expected = FixedU128::from_inner(
+			(FixedU128::from_u32(50 * 9).into_inner() + price.into_inner()) / 10,
+		);
+		assert_eq!(AssetConversion::twap(&token_2, &token_1, 10), Some(expected));
+		assert_eq!(AssetConversion::twap(&token_2, &token_1, 1), Some(price));
+	});
+}
+
+#[test]
+fn price_observations_are_bounded() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+		setup_native_pool_for_twap(user, token_2.clone());
+		let pool_id = (token_1.clone(), token_2.clone());
+		let observed_blocks = || {
+			PriceObservations::<Test>::get(&pool_id)
+				.iter()
+				.map(|o| o.block)
+				.collect::<Vec<_>>()
+		};
+
+		let swap = |block| {
+			System::set_block_number(block);
+			assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+				RuntimeOrigin::signed(user),
+				bvec![token_2.clone(), token_1.clone()],
+				10,
+				1,
+				user,
+				false,
+			));
+		};
+
+		assert_eq!(observed_blocks(), vec![1]);
+		for block in 2..=6 {
+			swap(block);
+		}
+		// a second swap in the same block is not observed.
+		swap(6);
+		assert_eq!(observed_blocks(), vec![3, 4, 5, 6]);
+		assert!(AssetConversion::twap(&token_2, &token_1, 3).is_some());
+		assert_eq!(AssetConversion::twap(&token_2, &token_1, 4), None);
+
+		// observations closer than the period move the latest one forward.
+		PriceObservationPeriod::set(&5);
+		swap(7);
+		assert_eq!(observed_blocks(), vec![3, 4, 5, 7]);
+		swap(8);
+		assert_eq!(observed_blocks(), vec![3, 4, 5, 8]);
+		swap(10);
+		assert_eq!(observed_blocks(), vec![4, 5, 8, 10]);
+
+		// liquidity changes are observed too.
+		System::set_block_number(20);
+		assert_ok!(AssetConversion::remove_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			100,
+			0,
+			0,
+			user,
+		));
+		assert_eq!(observed_blocks(), vec![5, 8, 10, 20]);
+	});
+}
+
+fn setup_native_pool_with_kind_for_twap(
+	user: u128,
+	token_2: NativeOrWithId<u32>,
+	kind: PoolKind<NativeOrWithId<u32>>,
+	liquidity1: u128,
+	liquidity2: u128,
+) {
+	create_tokens(user, vec![token_2.clone()]);
+	assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 20000));
+	assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 10000));
+	create_pool_with_liquidity(user, NativeOrWithId::Native, token_2, kind, liquidity1, liquidity2);
+}
+
+#[test]
+fn twap_follows_stable_swap_curve() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+		let kind = PoolKind::StableSwap { amplification: 100 };
+		setup_native_pool_with_kind_for_twap(user, token_2.clone(), kind, 10000, 5000);
+
+		System::set_block_number(11);
+		let (reserve_1, reserve_2) =
+			AssetConversion::get_reserves(token_1.clone(), token_2.clone()).unwrap();
+		let price = AssetConversion::twap(&token_2, &token_1, 10).unwrap();
+
+		// the price is the marginal price of the curve, close to parity rather than the ratio
+		// of the reserves.
+		let quote = AssetConversion::quote_price_exact_tokens_for_tokens(
+			token_2.clone(),
+			token_1.clone(),
+			reserve_2,
+			false,
+		)
+		.unwrap();
+		assert_eq!(price, FixedU128::saturating_from_rational(quote, reserve_2));
+		assert!(price > FixedU128::one());
+		assert!(price < FixedU128::saturating_from_rational(11, 10));
+
+		let reverse_price = AssetConversion::twap(&token_1, &token_2, 10).unwrap();
+		assert!(reverse_price < FixedU128::one());
+		assert!(reverse_price > FixedU128::saturating_from_rational(9, 10));
+		assert_ne!(reverse_price, FixedU128::saturating_from_rational(reserve_2, reserve_1));
+	});
+}
+
+#[test]
+fn twap_follows_weighted_curve() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+		let kind = PoolKind::Weighted { asset: token_1.clone(), weight: Percent::from_percent(80) };
+		setup_native_pool_with_kind_for_twap(user, token_2.clone(), kind, 10000, 200);
+
+		// the price is `(x / w_x) / (y / w_y)`, not the ratio of the reserves.
+		System::set_block_number(11);
+		assert_eq!(
+			AssetConversion::twap(&token_2, &token_1, 10),
+			Some(FixedU128::saturating_from_rational(25, 2))
+		);
+		assert_eq!(
+			AssetConversion::twap(&token_1, &token_2, 10),
+			Some(FixedU128::saturating_from_rational(8, 100))
+		);
+		assert_eq!(AssetConversion::quote_price_twap(&token_2, &token_1, 4, 10), Some(50));
+	});
+}
+
+#[test]
+fn twap_conversion_works() {
+	new_test_ext().execute_with(|| {
+		use frame_support::traits::tokens::{ConversionFromAssetBalance, ConversionToAssetBalance};
+		type Conversion = TwapConversion<Test, Native, ConstU64<10>>;
+
+		let user = 1;
+		let token_2 = NativeOrWithId::WithId(2);
+		setup_native_pool_for_twap(user, token_2.clone());
+
+		System::set_block_number(5);
+		assert_eq!(
+			Conversion::to_asset_balance(1000, token_2.clone()),
+			Err(Error::<Test>::InsufficientPriceHistory)
+		);
+
+		System::set_block_number(11);
+		assert_eq!(Conversion::to_asset_balance(1000, token_2.clone()), Ok(20));
+		assert_eq!(Conversion::from_asset_balance(20, token_2.clone()), Ok(1000));
+		assert_eq!(Conversion::to_asset_balance(1000, NativeOrWithId::Native), Ok(1000));
+		assert_eq!(
+			Conversion::from_asset_balance(20, NativeOrWithId::WithId(3)),
+			Err(Error::<Test>::InsufficientPriceHistory)
+		);
+	});
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+#[test]
+fn twap_conversion_ensure_successful_seeds_price_history() {
+	new_test_ext().execute_with(|| {
+		use frame_support::traits::tokens::ConversionFromAssetBalance;
+		type Conversion = TwapConversion<Test, Native, ConstU64<10>>;
+
+		let token_2 = NativeOrWithId::WithId(2);
+		System::set_block_number(1);
+		assert_eq!(
+			Conversion::from_asset_balance(20, token_2.clone()),
+			Err(Error::<Test>::InsufficientPriceHistory)
+		);
+
+		Conversion::ensure_successful(token_2.clone());
+		assert_eq!(System::block_number(), 10);
+		assert_eq!(Conversion::from_asset_balance(20, token_2), Ok(20));
+	});
+}
@@ -16,10 +16,11 @@
 // limitations under the License.
 
 use super::*;
-use codec::{Decode, Encode, MaxEncodedLen};
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use core::marker::PhantomData;
 use scale_info::TypeInfo;
-use sp_runtime::traits::TryConvert;
+use sp_arithmetic::Percent;
+use sp_runtime::{traits::TryConvert, RuntimeDebug};
 
 /// Represents a swap path with associated asset amounts indicating how much of the asset needs to
 /// be deposited to get the following asset's amount withdrawn (this is inclusive of fees).
@@ -41,6 +42,62 @@ pub struct PoolInfo<PoolAssetId> {
 	pub lp_token: PoolAssetId,
 }
 
+/// A snapshot of the cumulative prices of a pool, see [`TwapOracle`].
+///
+/// The cumulative prices are the sums, over every block since the pool was first observed, of the
+/// `FixedU128` prices of its assets at the end of the previous block. They are stored as the inner
+/// value of a `FixedU128` and wrap around on overflow, which is fine since only their differences
+/// are meaningful.
+///
+/// The first asset of the pool is the one with the lower SCALE encoding.
+#[derive(Decode, Encode, Clone, Default, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct PriceObservation<BlockNumber> {
+	/// The block at which the snapshot was taken.
+	pub block: BlockNumber,
+	/// The cumulative price of the first asset in the second one.
+	pub price1_cumulative: u128,
+	/// The cumulative price of the second asset in the first one.
+	pub price2_cumulative: u128,
+}
+
+/// The maximum amplification coefficient of a [`PoolKind::StableSwap`] pool.
+pub const MAX_AMPLIFICATION: u32 = 1_000_000;
+
+/// The curve used by a pool to price its swaps.
+#[derive(
+	Decode,
+	DecodeWithMemTracking,
+	Encode,
+	Clone,
+	PartialEq,
+	Eq,
+	RuntimeDebug,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+pub enum PoolKind<AssetKind> {
+	/// The constant product curve `x * y = k` of Uniswap V2.
+	ConstantProduct,
+	/// The stable swap invariant of Curve, suited to assets that trade close to parity.
+	///
+	/// The higher the `amplification`, the flatter the curve is around the balanced state. Must
+	/// be between 1 and [`MAX_AMPLIFICATION`].
+	StableSwap {
+		/// The amplification coefficient.
+		amplification: u32,
+	},
+	/// The weighted constant product curve `x^w_x * y^w_y = k` of Balancer.
+	///
+	/// `weight` is the weight of `asset`, the other asset of the pool is weighted
+	/// `100% - weight`. Both weights must be non-zero.
+	Weighted {
+		/// One of the assets of the pool.
+		asset: AssetKind,
+		/// The weight of `asset`.
+		weight: Percent,
+	},
+}
+
 /// Provides means to resolve the `PoolId` and `AccountId` from a pair of assets.
 ///
 /// Resulting `PoolId` remains consistent whether the asset pair is presented as (asset1, asset2)
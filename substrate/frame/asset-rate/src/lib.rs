@@ -56,12 +56,19 @@
 //! * Conversion rates are only used as estimates, and are not designed to be precise or closely
 //!   tracking real world values.
 //! * All conversion rates reflect the ration of some asset to native, e.g. native = asset * rate.
+//!
+//! ### Market Prices
+//!
+//! [`RateOrFallback`] converts at the rates set in this pallet and falls back to another
+//! conversion for the assets without one, e.g. to the time weighted average prices of the
+//! `pallet-asset-conversion` pools with its `TwapConversion`.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 extern crate alloc;
 
 use alloc::boxed::Box;
+use core::marker::PhantomData;
 use frame_support::traits::{
 	fungible::Inspect,
 	tokens::{ConversionFromAssetBalance, ConversionToAssetBalance},
@@ -279,3 +286,55 @@ where
 			.saturating_mul_int(balance))
 	}
 }
+
+/// Converts balances at the rates set in this pallet, falling back to the `Fallback` conversion
+/// for the asset kinds without a rate.
+///
+/// This allows governance to pin the rates of some assets while the others follow market prices,
+/// e.g. those of `pallet_asset_conversion::TwapConversion`.
+pub struct RateOrFallback<T, Fallback>(PhantomData<(T, Fallback)>);
+
+impl<T, Fallback> ConversionFromAssetBalance<BalanceOf<T>, AssetKindOf<T>, BalanceOf<T>>
+	for RateOrFallback<T, Fallback>
+where
+	T: Config,
+	Fallback: ConversionFromAssetBalance<BalanceOf<T>, AssetKindOf<T>, BalanceOf<T>>,
+{
+	type Error = pallet::Error<T>;
+
+	fn from_asset_balance(
+		balance: BalanceOf<T>,
+		asset_kind: AssetKindOf<T>,
+	) -> Result<BalanceOf<T>, pallet::Error<T>> {
+		if pallet::ConversionRateToNative::<T>::contains_key(&asset_kind) {
+			return Pallet::<T>::from_asset_balance(balance, asset_kind)
+		}
+		Fallback::from_asset_balance(balance, asset_kind)
+			.map_err(|_| pallet::Error::<T>::UnknownAssetKind)
+	}
+	/// Set a conversion rate to `1` for the `asset_id`.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn ensure_successful(asset_id: AssetKindOf<T>) {
+		Pallet::<T>::ensure_successful(asset_id);
+	}
+}
+
+impl<T, Fallback> ConversionToAssetBalance<BalanceOf<T>, AssetKindOf<T>, BalanceOf<T>>
+	for RateOrFallback<T, Fallback>
+where
+	T: Config,
+	Fallback: ConversionToAssetBalance<BalanceOf<T>, AssetKindOf<T>, BalanceOf<T>>,
+{
+	type Error = pallet::Error<T>;
+
+	fn to_asset_balance(
+		balance: BalanceOf<T>,
+		asset_kind: AssetKindOf<T>,
+	) -> Result<BalanceOf<T>, pallet::Error<T>> {
+		if pallet::ConversionRateToNative::<T>::contains_key(&asset_kind) {
+			return Pallet::<T>::to_asset_balance(balance, asset_kind)
+		}
+		Fallback::to_asset_balance(balance, asset_kind)
+			.map_err(|_| pallet::Error::<T>::UnknownAssetKind)
+	}
+}
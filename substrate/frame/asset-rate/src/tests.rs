@@ -176,3 +176,48 @@ fn convert_overflow_throws() {
 		assert!(conversion.is_err());
 	});
 }
+
+/// Converts at a fixed rate of `4` for the assets below `100`.
+struct FixedFallback;
+
+impl ConversionFromAssetBalance<BalanceOf<Test>, u32, BalanceOf<Test>> for FixedFallback {
+	type Error = ();
+
+	fn from_asset_balance(
+		balance: BalanceOf<Test>,
+		asset_kind: u32,
+	) -> Result<BalanceOf<Test>, ()> {
+		(asset_kind < 100).then(|| balance * 4).ok_or(())
+	}
+	#[cfg(feature = "runtime-benchmarks")]
+	fn ensure_successful(_: u32) {}
+}
+
+impl ConversionToAssetBalance<BalanceOf<Test>, u32, BalanceOf<Test>> for FixedFallback {
+	type Error = ();
+
+	fn to_asset_balance(balance: BalanceOf<Test>, asset_kind: u32) -> Result<BalanceOf<Test>, ()> {
+		(asset_kind < 100).then(|| balance / 4).ok_or(())
+	}
+}
+
+#[test]
+fn convert_with_fallback_works() {
+	new_test_ext().execute_with(|| {
+		type Conversion = RateOrFallback<Test, FixedFallback>;
+
+		// Without a rate, the fallback is used.
+		assert_eq!(Conversion::from_asset_balance(10, ASSET_ID), Ok(40));
+		assert_eq!(Conversion::to_asset_balance(40, ASSET_ID), Ok(10));
+		assert_eq!(Conversion::from_asset_balance(10, 100), Err(Error::<Test>::UnknownAssetKind));
+
+		// The rate set in the pallet takes precedence.
+		assert_ok!(AssetRate::create(
+			RuntimeOrigin::root(),
+			Box::new(ASSET_ID),
+			FixedU128::from_float(2.51)
+		));
+		assert_eq!(Conversion::from_asset_balance(10, ASSET_ID), Ok(25));
+		assert_eq!(Conversion::to_asset_balance(25, ASSET_ID), Ok(9));
+	});
+}
@@ -400,6 +400,7 @@ impl pallet_proxy::Config for Test {
 	type AnnouncementDepositBase = ConstU64<1>;
 	type AnnouncementDepositFactor = ConstU64<1>;
 	type BlockNumberProvider = frame_system::Pallet<Test>;
+	type SpendingLimits = ();
 }
 
 impl pallet_dummy::Config for Test {}
@@ -21,13 +21,15 @@ use super::*;
 
 use alloc::{collections::btree_map::BTreeMap, vec::Vec};
 use assert_matches::assert_matches;
-use frame_benchmarking::v1::{account, benchmarks_instance_pallet, whitelist_account};
+use frame_benchmarking::v1::{
+	account, benchmarks_instance_pallet, whitelist_account, BenchmarkError,
+};
 use frame_support::{
 	dispatch::RawOrigin,
 	traits::{
 		fungible,
 		tokens::{Fortitude::Polite, Preservation::Expendable},
-		Currency, Get,
+		Contains, Currency, Get,
 	},
 };
 use sp_runtime::traits::Bounded;
@@ -53,6 +55,45 @@ fn fill_voting<T: Config<I>, I: 'static>(
 	(c, r)
 }
 
+/// Create as many ongoing polls as possible up to `MaxVotes` in a private class and return them
+/// along with the class.
+fn fill_private_voting<T: Config<I>, I: 'static>(
+) -> Result<(ClassOf<T, I>, Vec<IndexOf<T, I>>), BenchmarkError> {
+	let class = T::Polls::classes()
+		.into_iter()
+		.find(|class| T::PrivateClasses::contains(class))
+		.ok_or(BenchmarkError::Weightless)?;
+	let mut polls = Vec::new();
+	for _ in 0..T::MaxVotes::get() {
+		match T::Polls::create_ongoing(class.clone()) {
+			Ok(i) => polls.push(i),
+			Err(()) => break,
+		}
+	}
+	if polls.is_empty() {
+		return Err("No private poll to vote on".into())
+	}
+	Ok((class, polls))
+}
+
+/// Commit `vote` of `who` on all `polls`.
+fn commit_votes<T: Config<I>, I: 'static>(
+	who: &T::AccountId,
+	polls: &[IndexOf<T, I>],
+	vote: AccountVote<BalanceOf<T, I>>,
+) -> Result<(), BenchmarkError> {
+	for i in polls {
+		let commitment = ConvictionVoting::<T, I>::commitment_of(who, *i, vote, &[0; 32]);
+		ConvictionVoting::<T, I>::commit_vote(
+			RawOrigin::Signed(who.clone()).into(),
+			*i,
+			commitment,
+			vote.balance(),
+		)?;
+	}
+	Ok(())
+}
+
 fn funded_account<T: Config<I>, I: 'static>(name: &'static str, index: u32) -> T::AccountId {
 	let caller: T::AccountId = account(name, index, SEED);
 	T::Currency::make_free_balance_be(&caller, BalanceOf::<T, I>::max_value());
@@ -287,6 +328,61 @@ benchmarks_instance_pallet! {
 		assert_eq!(orig_usable, <T::Currency as fungible::Inspect<T::AccountId>>::reducible_balance(&caller, Expendable, Polite));
 	}
 
+	commit_vote {
+		let caller = funded_account::<T, I>("caller", 0);
+		whitelist_account!(caller);
+		let account_vote = account_vote::<T, I>(100u32.into());
+
+		let (class, polls) = fill_private_voting::<T, I>()?;
+		// We need to create existing commitments
+		commit_votes::<T, I>(&caller, &polls[1..], account_vote)?;
+
+		let index = polls[0];
+		let commitment = ConvictionVoting::<T, I>::commitment_of(&caller, index, account_vote, &[0; 32]);
+	}: _(RawOrigin::Signed(caller.clone()), index, commitment, account_vote.balance())
+	verify {
+		assert_eq!(VoteCommitments::<T, I>::get(&caller, &class).len(), polls.len());
+	}
+
+	reveal_vote {
+		let caller = funded_account::<T, I>("caller", 0);
+		whitelist_account!(caller);
+		let account_vote = account_vote::<T, I>(100u32.into());
+
+		T::VotingHooks::on_vote_worst_case(&caller);
+
+		let (class, polls) = fill_private_voting::<T, I>()?;
+		commit_votes::<T, I>(&caller, &polls, account_vote)?;
+
+		let index = polls[0];
+		T::RevealWindow::open(index);
+	}: _(RawOrigin::Signed(caller.clone()), index, account_vote, [0; 32])
+	verify {
+		assert_eq!(VoteCommitments::<T, I>::get(&caller, &class).len(), polls.len() - 1);
+		assert_matches!(
+			VotingFor::<T, I>::get(&caller, &class),
+			Voting::Casting(Casting { votes, .. }) if votes.len() == 1
+		);
+	}
+
+	remove_commitment {
+		let caller = funded_account::<T, I>("caller", 0);
+		let voter = funded_account::<T, I>("voter", 0);
+		let voter_lookup = T::Lookup::unlookup(voter.clone());
+		whitelist_account!(caller);
+		let account_vote = account_vote::<T, I>(100u32.into());
+
+		let (class, polls) = fill_private_voting::<T, I>()?;
+		commit_votes::<T, I>(&voter, &polls, account_vote)?;
+
+		// Removing the commitment of a completed poll locks its balance as a penalty.
+		let index = polls[0];
+		assert!(T::Polls::end_ongoing(index, false).is_ok());
+	}: _(RawOrigin::Signed(caller.clone()), voter_lookup, class.clone(), index)
+	verify {
+		assert_eq!(VoteCommitments::<T, I>::get(&voter, &class).len(), polls.len() - 1);
+	}
+
 	impl_benchmark_test_suite!(
 		ConvictionVoting,
 		crate::tests::new_test_ext(),
@@ -23,6 +23,20 @@
 //! ## Overview
 //!
 //! Pallet for managing actual voting in polls.
+//!
+//! ### Private voting
+//!
+//! Polls of the classes in [`Config::PrivateClasses`] are voted on in private. Until the
+//! [`Config::RevealWindow`] of such a poll opens, voters may only [`Call::commit_vote`] a hash of
+//! their vote, locking the balance they intend to vote with. Once the window opens, they
+//! [`Call::reveal_vote`] it, and only then does the vote count in the tally of the poll. Votes
+//! which are not revealed before the poll ends are ignored, and removing them with
+//! [`Call::remove_commitment`] keeps their balance locked for [`Config::UnrevealedLockPeriods`]
+//! vote locking periods.
+//!
+//! Accounts delegating on a class cannot commit votes on it, and accounts with commitments on a
+//! class cannot delegate on it. Votes delegated to an account only follow its votes once they are
+//! revealed.
 
 #![recursion_limit = "256"]
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -33,12 +47,12 @@ use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
 	traits::{
-		fungible, Currency, Get, LockIdentifier, LockableCurrency, PollStatus, Polling,
+		fungible, Contains, Currency, Get, LockIdentifier, LockableCurrency, PollStatus, Polling,
 		ReservableCurrency, WithdrawReasons,
 	},
 };
 use sp_runtime::{
-	traits::{AtLeast32BitUnsigned, Saturating, StaticLookup, Zero},
+	traits::{AtLeast32BitUnsigned, Hash, Saturating, StaticLookup, Zero},
 	ArithmeticError, DispatchError, Perbill,
 };
 
@@ -51,8 +65,8 @@ pub mod weights;
 pub use self::{
 	conviction::Conviction,
 	pallet::*,
-	traits::{Status, VotingHooks},
-	types::{Delegations, Tally, UnvoteScope},
+	traits::{RevealWindow, Status, VotingHooks},
+	types::{Commitment, Delegations, Tally, UnvoteScope},
 	vote::{AccountVote, Casting, Delegating, Vote, Voting},
 	weights::WeightInfo,
 };
@@ -82,6 +96,7 @@ pub type VotingOf<T, I = ()> = Voting<
 #[allow(dead_code)]
 type DelegatingOf<T, I = ()> =
 	Delegating<BalanceOf<T, I>, <T as frame_system::Config>::AccountId, BlockNumberFor<T, I>>;
+pub type CommitmentOf<T, I = ()> = Commitment<<T as frame_system::Config>::Hash, BalanceOf<T, I>>;
 pub type TallyOf<T, I = ()> = Tally<BalanceOf<T, I>, <T as Config<I>>::MaxTurnout>;
 pub type VotesOf<T, I = ()> = BalanceOf<T, I>;
 pub type PollIndexOf<T, I = ()> = <<T as Config<I>>::Polls as Polling<TallyOf<T, I>>>::Index;
@@ -157,6 +172,18 @@ pub mod pallet {
 		/// of the calling function. This means that if the calling function fails, the hook will
 		/// be rolled back without further notice.
 		type VotingHooks: VotingHooks<Self::AccountId, PollIndexOf<Self, I>, BalanceOf<Self, I>>;
+
+		/// The classes of polls which are voted on in private, through `commit_vote` and
+		/// `reveal_vote`.
+		type PrivateClasses: Contains<ClassOf<Self, I>>;
+
+		/// Decides when the votes committed on a poll of a private class may be revealed.
+		type RevealWindow: RevealWindow<PollIndexOf<Self, I>>;
+
+		/// The number of vote locking periods for which the balance of a vote which was committed
+		/// but never revealed remains locked after the poll ends.
+		#[pallet::constant]
+		type UnrevealedLockPeriods: Get<u32>;
 	}
 
 	/// All voting for a particular voter in a particular voting class. We store the balance for the
@@ -184,6 +211,19 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// The votes committed in private by a voter in a particular voting class, which are yet to
+	/// be revealed.
+	#[pallet::storage]
+	pub type VoteCommitments<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		ClassOf<T, I>,
+		BoundedVec<(PollIndexOf<T, I>, CommitmentOf<T, I>), T::MaxVotes>,
+		ValueQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -197,6 +237,11 @@ pub mod pallet {
 		VoteRemoved { who: T::AccountId, vote: AccountVote<BalanceOf<T, I>> },
 		/// The lockup period of a conviction vote expired, and the funds have been unlocked.
 		VoteUnlocked { who: T::AccountId, class: ClassOf<T, I> },
+		/// An account has committed to a vote on a poll, to be revealed later.
+		VoteCommitted { who: T::AccountId, poll_index: PollIndexOf<T, I>, balance: BalanceOf<T, I> },
+		/// A commitment has been removed without being revealed. If `penalised`, its balance
+		/// remains locked for a while.
+		CommitmentRemoved { who: T::AccountId, poll_index: PollIndexOf<T, I>, penalised: bool },
 	}
 
 	#[pallet::error]
@@ -226,6 +271,16 @@ pub mod pallet {
 		ClassNeeded,
 		/// The class ID supplied is invalid.
 		BadClass,
+		/// The poll is not voted on in private.
+		NotPrivate,
+		/// The poll is voted on in private and its votes cannot be revealed yet.
+		RevealWindowClosed,
+		/// The account has already committed to a vote on the poll.
+		AlreadyCommitted,
+		/// The account did not commit to a vote on the poll.
+		NoCommitment,
+		/// The revealed vote does not match the commitment.
+		InvalidReveal,
 	}
 
 	#[pallet::call]
@@ -247,6 +302,12 @@ pub mod pallet {
 			vote: AccountVote<BalanceOf<T, I>>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
+			if let Some((_, class)) = T::Polls::as_ongoing(poll_index) {
+				ensure!(
+					!T::PrivateClasses::contains(&class) || T::RevealWindow::is_open(poll_index),
+					Error::<T, I>::RevealWindowClosed
+				);
+			}
 			Self::try_vote(&who, poll_index, vote)
 		}
 
@@ -411,10 +472,91 @@ pub mod pallet {
 			Self::try_remove_vote(&target, index, Some(class), scope)?;
 			Ok(())
 		}
+
+		/// Commit to a vote in a poll of a private class, without disclosing it.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// - `poll_index`: The index of the poll to vote for.
+		/// - `commitment`: The hash of the vote, as computed by [`Pallet::commitment_of`].
+		/// - `balance`: The amount of balance to lock for the vote. The revealed vote may not use
+		///   more than this.
+		///
+		/// Emits `VoteCommitted`.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::commit_vote())]
+		pub fn commit_vote(
+			origin: OriginFor<T>,
+			#[pallet::compact] poll_index: PollIndexOf<T, I>,
+			commitment: T::Hash,
+			balance: BalanceOf<T, I>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::try_commit(&who, poll_index, commitment, balance)
+		}
+
+		/// Reveal a vote committed to through `commit_vote`, adding it to the tally of the poll.
+		///
+		/// The dispatch origin of this call must be _Signed_, and the reveal window of the poll
+		/// must be open.
+		///
+		/// - `poll_index`: The index of the poll the vote was committed for.
+		/// - `vote`: The vote configuration which was committed.
+		/// - `salt`: The salt which blinded the commitment.
+		///
+		/// Emits `Voted`.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::reveal_vote())]
+		pub fn reveal_vote(
+			origin: OriginFor<T>,
+			#[pallet::compact] poll_index: PollIndexOf<T, I>,
+			vote: AccountVote<BalanceOf<T, I>>,
+			salt: [u8; 32],
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::try_reveal(&who, poll_index, vote, salt)
+		}
+
+		/// Remove a vote commitment which was not revealed.
+		///
+		/// While the poll is ongoing, only the voter may withdraw their commitment. Once it has
+		/// ended, anyone may remove it; if the poll completed, the committed balance remains locked
+		/// for `UnrevealedLockPeriods` vote locking periods after its end.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// - `target`: The account which committed to the vote.
+		/// - `class`: The class of the poll.
+		/// - `index`: The index of the poll the vote was committed for.
+		///
+		/// Emits `CommitmentRemoved`.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::remove_commitment())]
+		pub fn remove_commitment(
+			origin: OriginFor<T>,
+			target: AccountIdLookupOf<T>,
+			class: ClassOf<T, I>,
+			index: PollIndexOf<T, I>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			let scope = if target == who { UnvoteScope::Any } else { UnvoteScope::OnlyExpired };
+			Self::try_remove_commitment(&target, class, index, scope)
+		}
 	}
 }
 
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// The commitment `who` submits to privately vote `vote` on `poll_index`, blinded by `salt`.
+	pub fn commitment_of(
+		who: &T::AccountId,
+		poll_index: PollIndexOf<T, I>,
+		vote: AccountVote<BalanceOf<T, I>>,
+		salt: &[u8; 32],
+	) -> T::Hash {
+		T::Hashing::hash_of(&(who, poll_index, vote, salt))
+	}
+
 	/// Actually enact a vote, if legit.
 	fn try_vote(
 		who: &T::AccountId,
@@ -464,6 +606,123 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		})
 	}
 
+	/// Record a commitment to a private vote, if legit.
+	fn try_commit(
+		who: &T::AccountId,
+		poll_index: PollIndexOf<T, I>,
+		hash: T::Hash,
+		balance: BalanceOf<T, I>,
+	) -> DispatchResult {
+		ensure!(balance <= T::Currency::total_balance(who), Error::<T, I>::InsufficientFunds);
+		let (_, class) = T::Polls::as_ongoing(poll_index).ok_or(Error::<T, I>::NotOngoing)?;
+		ensure!(T::PrivateClasses::contains(&class), Error::<T, I>::NotPrivate);
+		let Voting::Casting(Casting { votes, .. }) = VotingFor::<T, I>::get(who, &class) else {
+			return Err(Error::<T, I>::AlreadyDelegating.into())
+		};
+		VoteCommitments::<T, I>::try_mutate(who, &class, |commitments| -> DispatchResult {
+			let i = match commitments.binary_search_by_key(&poll_index, |i| i.0) {
+				Ok(_) => return Err(Error::<T, I>::AlreadyCommitted.into()),
+				Err(i) => i,
+			};
+			// Commitments turn into votes once revealed, so they share the same limit.
+			ensure!(
+				votes.len().saturating_add(commitments.len()) < T::MaxVotes::get() as usize,
+				Error::<T, I>::MaxVotesReached
+			);
+			commitments
+				.try_insert(i, (poll_index, Commitment { hash, balance }))
+				.map_err(|_| Error::<T, I>::MaxVotesReached)?;
+			Ok(())
+		})?;
+		// Extend the lock to `balance` (rather than setting it) since we don't know what other
+		// votes are in place.
+		Self::extend_lock(who, &class, balance);
+		Self::deposit_event(Event::VoteCommitted { who: who.clone(), poll_index, balance });
+		Ok(())
+	}
+
+	/// Reveal a committed vote and enact it, if legit.
+	fn try_reveal(
+		who: &T::AccountId,
+		poll_index: PollIndexOf<T, I>,
+		vote: AccountVote<BalanceOf<T, I>>,
+		salt: [u8; 32],
+	) -> DispatchResult {
+		let (_, class) = T::Polls::as_ongoing(poll_index).ok_or(Error::<T, I>::NotOngoing)?;
+		ensure!(T::RevealWindow::is_open(poll_index), Error::<T, I>::RevealWindowClosed);
+		VoteCommitments::<T, I>::try_mutate(who, &class, |commitments| -> DispatchResult {
+			let i = commitments
+				.binary_search_by_key(&poll_index, |i| i.0)
+				.map_err(|_| Error::<T, I>::NoCommitment)?;
+			let Commitment { hash, balance } = commitments[i].1;
+			ensure!(
+				hash == Self::commitment_of(who, poll_index, vote, &salt),
+				Error::<T, I>::InvalidReveal
+			);
+			ensure!(vote.balance() <= balance, Error::<T, I>::InsufficientFunds);
+			commitments.remove(i);
+			Ok(())
+		})?;
+		Self::try_vote(who, poll_index, vote)
+	}
+
+	/// Remove the account's unrevealed commitment for the given poll if possible. This is possible
+	/// when:
+	/// - The poll has not finished and the removal is made by the voter.
+	/// - The poll has finished, in which case the committed balance may remain locked as a penalty
+	///   for not revealing the vote.
+	fn try_remove_commitment(
+		who: &T::AccountId,
+		class: ClassOf<T, I>,
+		poll_index: PollIndexOf<T, I>,
+		scope: UnvoteScope,
+	) -> DispatchResult {
+		let penalised = VoteCommitments::<T, I>::try_mutate(
+			who,
+			&class,
+			|commitments| -> Result<bool, DispatchError> {
+				let i = commitments
+					.binary_search_by_key(&poll_index, |i| i.0)
+					.map_err(|_| Error::<T, I>::NoCommitment)?;
+				let (_, commitment) = commitments.remove(i);
+
+				T::Polls::try_access_poll(poll_index, |poll_status| match poll_status {
+					PollStatus::Ongoing(..) => {
+						ensure!(matches!(scope, UnvoteScope::Any), Error::<T, I>::NoPermission);
+						Ok(false)
+					},
+					PollStatus::Completed(end, _) => {
+						let unlock_at = end.saturating_add(
+							T::VoteLockingPeriod::get()
+								.saturating_mul(T::UnrevealedLockPeriods::get().into()),
+						);
+						let now = T::BlockNumberProvider::current_block_number();
+						if now < unlock_at {
+							VotingFor::<T, I>::mutate(who, &class, |voting| {
+								AsMut::<vote::PriorLock<_, _>>::as_mut(voting)
+									.accumulate(unlock_at, commitment.balance)
+							});
+						}
+						Ok(now < unlock_at)
+					},
+					// Poll was cancelled.
+					PollStatus::None => Ok(false),
+				})
+			},
+		)?;
+		Self::deposit_event(Event::CommitmentRemoved { who: who.clone(), poll_index, penalised });
+		Ok(())
+	}
+
+	/// The largest balance committed by `who` to votes in `class` which are yet to be revealed.
+	fn committed_balance(who: &T::AccountId, class: &ClassOf<T, I>) -> BalanceOf<T, I> {
+		VoteCommitments::<T, I>::get(who, class)
+			.iter()
+			.map(|i| i.1.balance)
+			.max()
+			.unwrap_or_else(Zero::zero)
+	}
+
 	/// Remove the account's vote for the given poll if possible. This is possible when:
 	/// - The poll has not finished.
 	/// - The poll has finished and the voter lost their direction.
@@ -620,6 +879,11 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		ensure!(who != target, Error::<T, I>::Nonsense);
 		T::Polls::classes().binary_search(&class).map_err(|_| Error::<T, I>::BadClass)?;
 		ensure!(balance <= T::Currency::total_balance(&who), Error::<T, I>::InsufficientFunds);
+		// Commitments are votes yet to be revealed.
+		ensure!(
+			VoteCommitments::<T, I>::get(&who, &class).is_empty(),
+			Error::<T, I>::AlreadyVoting
+		);
 		let votes =
 			VotingFor::<T, I>::try_mutate(&who, &class, |voting| -> Result<u32, DispatchError> {
 				let old = core::mem::replace(
@@ -721,7 +985,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let class_lock_needed = VotingFor::<T, I>::mutate(who, class, |voting| {
 			voting.rejig(T::BlockNumberProvider::current_block_number());
 			voting.locked_balance()
-		});
+		})
+		.max(Self::committed_balance(who, class));
 		let lock_needed = ClassLocksFor::<T, I>::mutate(who, |locks| {
 			locks.retain(|x| &x.0 != class);
 			if !class_lock_needed.is_zero() {
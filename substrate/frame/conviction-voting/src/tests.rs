@@ -146,6 +146,29 @@ impl Polling<TallyOf<Test>> for TestPolls {
 	}
 }
 
+pub struct TestPrivateClasses;
+impl Contains<u8> for TestPrivateClasses {
+	fn contains(class: &u8) -> bool {
+		*class == 2
+	}
+}
+
+parameter_types! {
+	pub static RevealOpen: bool = false;
+}
+
+pub struct TestRevealWindow;
+impl RevealWindow<u8> for TestRevealWindow {
+	fn is_open(_index: u8) -> bool {
+		RevealOpen::get()
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn open(_index: u8) {
+		RevealOpen::set(true);
+	}
+}
+
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = pallet_balances::Pallet<Self>;
@@ -156,6 +179,9 @@ impl Config for Test {
 	type Polls = TestPolls;
 	type BlockNumberProvider = System;
 	type VotingHooks = HooksHandler;
+	type PrivateClasses = TestPrivateClasses;
+	type RevealWindow = TestRevealWindow;
+	type UnrevealedLockPeriods = ConstU32<2>;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -915,6 +941,179 @@ fn errors_with_remove_vote_work() {
 	});
 }
 
+fn private_polls() {
+	Polls::set(
+		vec![
+			(1, Completed(1, true)),
+			(3, Ongoing(Tally::from_parts(0, 0, 0), 0)),
+			(4, Ongoing(Tally::from_parts(0, 0, 0), 2)),
+			(5, Ongoing(Tally::from_parts(0, 0, 0), 2)),
+			(6, Ongoing(Tally::from_parts(0, 0, 0), 2)),
+			(7, Ongoing(Tally::from_parts(0, 0, 0), 2)),
+		]
+		.into_iter()
+		.collect(),
+	);
+}
+
+fn commit(who: u64, poll_index: u8, vote: AccountVote<u64>) -> DispatchResult {
+	let commitment = Voting::commitment_of(&who, poll_index, vote, &[who as u8; 32]);
+	Voting::commit_vote(RuntimeOrigin::signed(who), poll_index, commitment, vote.balance())
+}
+
+fn reveal(who: u64, poll_index: u8, vote: AccountVote<u64>) -> DispatchResult {
+	Voting::reveal_vote(RuntimeOrigin::signed(who), poll_index, vote, [who as u8; 32])
+}
+
+#[test]
+fn private_voting_works() {
+	new_test_ext().execute_with(|| {
+		private_polls();
+		assert_ok!(commit(1, 4, aye(5, 1)));
+		System::assert_last_event(tests::RuntimeEvent::Voting(Event::VoteCommitted {
+			who: 1,
+			poll_index: 4,
+			balance: 5,
+		}));
+		// the committed balance is locked, but the vote is not counted yet.
+		assert_eq!(Balances::usable_balance(1), 5);
+		assert_eq!(tally(4), Tally::from_parts(0, 0, 0));
+
+		// nothing can be revealed nor voted in the open before the reveal window.
+		assert_noop!(reveal(1, 4, aye(5, 1)), Error::<Test>::RevealWindowClosed);
+		assert_noop!(
+			Voting::vote(RuntimeOrigin::signed(2), 4, nay(5, 1)),
+			Error::<Test>::RevealWindowClosed
+		);
+
+		RevealOpen::set(true);
+		assert_noop!(reveal(1, 4, nay(5, 1)), Error::<Test>::InvalidReveal);
+		assert_noop!(
+			Voting::reveal_vote(RuntimeOrigin::signed(1), 4, aye(5, 1), [0; 32]),
+			Error::<Test>::InvalidReveal
+		);
+		assert_noop!(reveal(2, 4, aye(5, 1)), Error::<Test>::NoCommitment);
+		assert_ok!(reveal(1, 4, aye(5, 1)));
+		System::assert_last_event(tests::RuntimeEvent::Voting(Event::Voted {
+			who: 1,
+			vote: aye(5, 1),
+		}));
+		assert_eq!(tally(4), Tally::from_parts(5, 0, 5));
+		assert!(VoteCommitments::<Test>::get(1, 2).is_empty());
+		assert_noop!(reveal(1, 4, aye(5, 1)), Error::<Test>::NoCommitment);
+
+		// plain votes are accepted once the reveal window is open.
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(2), 4, nay(5, 1)));
+		assert_eq!(tally(4), Tally::from_parts(5, 5, 5));
+	});
+}
+
+#[test]
+fn errors_with_private_voting_work() {
+	new_test_ext().execute_with(|| {
+		private_polls();
+		assert_noop!(commit(1, 3, aye(5, 1)), Error::<Test>::NotPrivate);
+		assert_noop!(commit(1, 1, aye(5, 1)), Error::<Test>::NotOngoing);
+		assert_noop!(commit(1, 4, aye(11, 1)), Error::<Test>::InsufficientFunds);
+
+		assert_ok!(commit(1, 4, aye(5, 1)));
+		assert_noop!(commit(1, 4, nay(5, 1)), Error::<Test>::AlreadyCommitted);
+		assert_ok!(commit(1, 5, aye(5, 1)));
+		assert_ok!(commit(1, 6, aye(5, 1)));
+		assert_noop!(commit(1, 7, aye(5, 1)), Error::<Test>::MaxVotesReached);
+
+		// the revealed vote may not use more than the committed balance.
+		let commitment = Voting::commitment_of(&2, 4, aye(10, 1), &[2; 32]);
+		assert_ok!(Voting::commit_vote(RuntimeOrigin::signed(2), 4, commitment, 5));
+		RevealOpen::set(true);
+		assert_noop!(reveal(2, 4, aye(10, 1)), Error::<Test>::InsufficientFunds);
+	});
+}
+
+#[test]
+fn private_voting_with_delegation_works() {
+	new_test_ext().execute_with(|| {
+		private_polls();
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(2), 2, 1, Conviction::Locked1x, 20));
+		assert_noop!(commit(2, 4, aye(5, 1)), Error::<Test>::AlreadyDelegating);
+
+		// delegated votes only follow the vote of the delegate once revealed.
+		assert_ok!(commit(1, 4, aye(5, 1)));
+		assert_eq!(tally(4), Tally::from_parts(0, 0, 0));
+		assert_noop!(
+			Voting::delegate(RuntimeOrigin::signed(1), 2, 3, Conviction::None, 5),
+			Error::<Test>::AlreadyVoting
+		);
+		RevealOpen::set(true);
+		assert_ok!(reveal(1, 4, aye(5, 1)));
+		assert_eq!(tally(4), Tally::from_parts(25, 0, 25));
+
+		assert_ok!(Voting::undelegate(RuntimeOrigin::signed(2), 2));
+		assert_eq!(tally(4), Tally::from_parts(5, 0, 5));
+	});
+}
+
+#[test]
+fn remove_commitment_works() {
+	new_test_ext().execute_with(|| {
+		private_polls();
+		assert_ok!(commit(1, 4, aye(5, 1)));
+		assert_ok!(commit(1, 5, aye(2, 1)));
+
+		// only the voter may withdraw a commitment while the poll is ongoing.
+		assert_noop!(
+			Voting::remove_commitment(RuntimeOrigin::signed(2), 1, 2, 4),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Voting::remove_commitment(RuntimeOrigin::signed(1), 1, 2, 6),
+			Error::<Test>::NoCommitment
+		);
+		assert_ok!(Voting::remove_commitment(RuntimeOrigin::signed(1), 1, 2, 5));
+		System::assert_last_event(tests::RuntimeEvent::Voting(Event::CommitmentRemoved {
+			who: 1,
+			poll_index: 5,
+			penalised: false,
+		}));
+
+		// a commitment left unrevealed keeps its balance locked after the poll ends.
+		Polls::set(vec![(4, Completed(3, true))].into_iter().collect());
+		assert_ok!(Voting::remove_commitment(RuntimeOrigin::signed(2), 1, 2, 4));
+		System::assert_last_event(tests::RuntimeEvent::Voting(Event::CommitmentRemoved {
+			who: 1,
+			poll_index: 4,
+			penalised: true,
+		}));
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(1), 2, 1));
+		assert_eq!(Balances::usable_balance(1), 5);
+
+		// for `UnrevealedLockPeriods` vote locking periods.
+		run_to(8);
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(1), 2, 1));
+		assert_eq!(Balances::usable_balance(1), 5);
+		run_to(9);
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(1), 2, 1));
+		assert_eq!(Balances::usable_balance(1), 10);
+	});
+}
+
+#[test]
+fn remove_commitment_of_cancelled_poll_works() {
+	new_test_ext().execute_with(|| {
+		private_polls();
+		assert_ok!(commit(1, 4, aye(5, 1)));
+		Polls::set(BTreeMap::new());
+		assert_ok!(Voting::remove_commitment(RuntimeOrigin::signed(2), 1, 2, 4));
+		System::assert_last_event(tests::RuntimeEvent::Voting(Event::CommitmentRemoved {
+			who: 1,
+			poll_index: 4,
+			penalised: false,
+		}));
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(1), 2, 1));
+		assert_eq!(Balances::usable_balance(1), 10);
+	});
+}
+
 thread_local! {
 	static LAST_ON_VOTE_DATA: RefCell<Option<(u64, u8, AccountVote<u64>)>> = RefCell::new(None);
 	static LAST_ON_REMOVE_VOTE_DATA: RefCell<Option<(u64, u8, Status)>> = RefCell::new(None);
@@ -119,3 +119,29 @@ impl<A, I, B> VotingHooks<A, I, B> for () {
 	#[cfg(feature = "runtime-benchmarks")]
 	fn on_remove_vote_worst_case(_who: &A) {}
 }
+
+/// Decides when the votes committed in private on a poll may be revealed.
+///
+/// While the reveal window of a poll of a private voting class is closed, votes on it may only be
+/// committed. Once it opens, committed votes may be revealed, and plain votes cast, until the poll
+/// ends. Only revealed votes make it into the tally.
+pub trait RevealWindow<Index> {
+	/// Whether the votes committed on poll `index` may be revealed right now.
+	fn is_open(index: Index) -> bool;
+
+	/// Open the reveal window of poll `index`.
+	///
+	/// Will be called by benchmarking before revealing a vote in a benchmark.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn open(index: Index);
+}
+
+// Default implementation for RevealWindow, which keeps every poll open for reveals.
+impl<I> RevealWindow<I> for () {
+	fn is_open(_index: I) -> bool {
+		true
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn open(_index: I) {}
+}
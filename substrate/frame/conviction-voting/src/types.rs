@@ -260,6 +260,26 @@ impl<Balance: Saturating> Saturating for Delegations<Balance> {
 	}
 }
 
+/// A vote cast in private on a poll, which is yet to be revealed.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Copy,
+	Clone,
+	PartialEq,
+	Eq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub struct Commitment<Hash, Balance> {
+	/// The hash of the voter, the poll index, the vote and the salt used to blind it.
+	pub hash: Hash,
+	/// The amount of balance locked for the vote. The revealed vote may not use more than this.
+	pub balance: Balance,
+}
+
 /// Whether an `unvote` operation is able to make actions that are not strictly always in the
 /// interest of an account.
 pub enum UnvoteScope {
@@ -84,6 +84,16 @@ impl<T: Config> DelegationInterface for Pallet<T> {
 		)
 	}
 
+	/// Move delegation of `amount` from `from` to `to`, both delegating to `Agent`.
+	fn transfer_delegation(
+		agent: Agent<Self::AccountId>,
+		from: Delegator<Self::AccountId>,
+		to: Delegator<Self::AccountId>,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		Pallet::<T>::do_transfer_delegation(agent, from, to, amount)
+	}
+
 	/// Returns pending slash of the `agent`.
 	fn pending_slash(agent: Agent<Self::AccountId>) -> Option<Self::Balance> {
 		AgentLedgerOuter::<T>::get(&agent.get()).map(|d| d.ledger.pending_slash).ok()
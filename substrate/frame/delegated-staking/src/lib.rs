@@ -267,6 +267,13 @@ pub mod pallet {
 		Slashed { agent: T::AccountId, delegator: T::AccountId, amount: BalanceOf<T> },
 		/// Unclaimed delegation funds migrated to delegator.
 		MigratedDelegation { agent: T::AccountId, delegator: T::AccountId, amount: BalanceOf<T> },
+		/// Delegation to an agent moved from one delegator to another.
+		DelegationTransferred {
+			agent: T::AccountId,
+			from: T::AccountId,
+			to: T::AccountId,
+			amount: BalanceOf<T>,
+		},
 	}
 
 	/// Map of Delegators to their `Delegation`.
@@ -701,6 +708,69 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Moves `amount` of delegation to `agent` from `source` delegator to `destination` delegator.
+	///
+	/// Unlike [`Self::do_migrate_delegation`], the destination may already be delegating to the
+	/// same `agent`. The agent ledger is unaffected since the total delegated amount stays the
+	/// same.
+	fn do_transfer_delegation(
+		agent: Agent<T::AccountId>,
+		source_delegator: Delegator<T::AccountId>,
+		destination_delegator: Delegator<T::AccountId>,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		// get inner type
+		let agent = agent.get();
+		let source_delegator = source_delegator.get();
+		let destination_delegator = destination_delegator.get();
+
+		ensure!(source_delegator != destination_delegator, Error::<T>::InvalidDelegation);
+		ensure!(
+			Delegation::<T>::can_delegate(&destination_delegator, &agent),
+			Error::<T>::InvalidDelegation
+		);
+
+		let mut source_delegation =
+			Delegation::<T>::get(&source_delegator).ok_or(Error::<T>::NotDelegator)?;
+		ensure!(source_delegation.agent == agent, Error::<T>::NotAgent);
+		ensure!(source_delegation.amount >= amount, Error::<T>::NotEnoughFunds);
+
+		// create or top up the delegation of the destination delegator.
+		let destination_amount = Delegation::<T>::get(&destination_delegator)
+			.map_or(Zero::zero(), |d| d.amount)
+			.checked_add(&amount)
+			.ok_or(ArithmeticError::Overflow)?;
+		Delegation::<T>::new(&agent, destination_amount).update(&destination_delegator);
+
+		source_delegation.amount = source_delegation
+			.amount
+			.checked_sub(&amount)
+			.defensive_ok_or(Error::<T>::BadState)?;
+
+		// transfer the held amount in `source_delegator` to `destination_delegator`.
+		T::Currency::transfer_on_hold(
+			&HoldReason::StakingDelegation.into(),
+			&source_delegator,
+			&destination_delegator,
+			amount,
+			Precision::Exact,
+			Restriction::OnHold,
+			Fortitude::Polite,
+		)?;
+
+		// update source delegation.
+		source_delegation.update(&source_delegator);
+
+		Self::deposit_event(Event::<T>::DelegationTransferred {
+			agent,
+			from: source_delegator,
+			to: destination_delegator,
+			amount,
+		});
+
+		Ok(())
+	}
+
 	/// Take slash `amount` from agent's `pending_slash`counter and apply it to `delegator` account.
 	pub fn do_slash(
 		agent: Agent<T::AccountId>,
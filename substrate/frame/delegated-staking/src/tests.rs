@@ -103,6 +103,99 @@ fn create_multiple_delegators() {
 	});
 }
 
+#[test]
+fn transfer_delegation_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		let agent: AccountId = 200;
+		let reward_account: AccountId = 201;
+		let (alice, bob, charlie) = (202, 203, 204);
+		let other_agent: AccountId = 300;
+
+		fund(&agent, 1000);
+		assert_ok!(DelegatedStaking::register_agent(
+			RawOrigin::Signed(agent).into(),
+			reward_account
+		));
+		fund(&alice, 1000);
+		fund(&bob, 1000);
+		assert_ok!(DelegatedStaking::delegate_to_agent(
+			RawOrigin::Signed(alice).into(),
+			agent,
+			500
+		));
+		assert_ok!(DelegatedStaking::delegate_to_agent(RawOrigin::Signed(bob).into(), agent, 100));
+
+		// cannot transfer more than delegated or to oneself.
+		assert_noop!(
+			<DelegatedStaking as DelegationInterface>::transfer_delegation(
+				Agent::from(agent),
+				Delegator::from(alice),
+				Delegator::from(bob),
+				501
+			),
+			Error::<T>::NotEnoughFunds
+		);
+		assert_noop!(
+			<DelegatedStaking as DelegationInterface>::transfer_delegation(
+				Agent::from(agent),
+				Delegator::from(alice),
+				Delegator::from(alice),
+				100
+			),
+			Error::<T>::InvalidDelegation
+		);
+
+		// move part of the delegation to an existing delegator of the same agent.
+		assert_ok!(<DelegatedStaking as DelegationInterface>::transfer_delegation(
+			Agent::from(agent),
+			Delegator::from(alice),
+			Delegator::from(bob),
+			200
+		));
+		assert_eq!(DelegatedStaking::held_balance_of(Delegator::from(alice)), 300);
+		assert_eq!(DelegatedStaking::held_balance_of(Delegator::from(bob)), 300);
+
+		// and the rest to a new delegator, which removes the source delegation.
+		assert_ok!(<DelegatedStaking as DelegationInterface>::transfer_delegation(
+			Agent::from(agent),
+			Delegator::from(alice),
+			Delegator::from(charlie),
+			300
+		));
+		assert!(!DelegatedStaking::is_delegator(&alice));
+		assert_eq!(DelegatedStaking::held_balance_of(Delegator::from(charlie)), 300);
+		assert_eq!(Balances::balance_on_hold(&HoldReason::StakingDelegation.into(), &charlie), 300);
+
+		// the agent is unaffected.
+		assert_eq!(DelegatedStaking::stakeable_balance(Agent::from(agent)), 600);
+		assert_eq!(
+			events_since_last_call().last(),
+			Some(&Event::DelegationTransferred { agent, from: alice, to: charlie, amount: 300 })
+		);
+
+		// cannot move delegation to someone delegating to another agent.
+		fund(&other_agent, 1000);
+		assert_ok!(DelegatedStaking::register_agent(
+			RawOrigin::Signed(other_agent).into(),
+			reward_account
+		));
+		assert_ok!(DelegatedStaking::delegate_to_agent(
+			RawOrigin::Signed(alice).into(),
+			other_agent,
+			100
+		));
+		assert_noop!(
+			<DelegatedStaking as DelegationInterface>::transfer_delegation(
+				Agent::from(agent),
+				Delegator::from(bob),
+				Delegator::from(alice),
+				100
+			),
+			Error::<T>::InvalidDelegation
+		);
+	});
+}
+
 #[test]
 fn agent_restrictions() {
 	// Similar to creating a nomination pool
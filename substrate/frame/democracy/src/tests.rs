@@ -95,6 +95,14 @@ impl pallet_preimage::Config for Test {
 	type Consideration = ();
 }
 
+/// The cron tasks of the scheduler are not used here, so the time does not move.
+pub struct FixedTime;
+impl frame_support::traits::UnixTime for FixedTime {
+	fn now() -> core::time::Duration {
+		core::time::Duration::from_secs(1_700_000_000)
+	}
+}
+
 impl pallet_scheduler::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeOrigin = RuntimeOrigin;
@@ -107,6 +115,9 @@ impl pallet_scheduler::Config for Test {
 	type OriginPrivilegeCmp = EqualPrivilegeOnly;
 	type Preimages = ();
 	type BlockNumberProvider = frame_system::Pallet<Test>;
+	type TimeProvider = FixedTime;
+	type MaxCronTasks = ConstU32<10>;
+	type CronGracePeriod = ConstU64<600>;
 }
 
 #[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
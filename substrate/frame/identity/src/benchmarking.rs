@@ -440,6 +440,81 @@ mod benchmarks {
 
 		Ok(())
 	}
+	#[benchmark]
+	fn provide_judgement_with_evidence(
+		r: Linear<1, { T::MaxRegistrars::get() - 1 }>,
+	) -> Result<(), BenchmarkError> {
+		// The user
+		let user: T::AccountId = account("user", r, SEED);
+		let user_origin =
+			<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(user.clone()));
+		let user_lookup = <T::Lookup as StaticLookup>::unlookup(user.clone());
+		let _ = T::Currency::make_free_balance_be(&user, BalanceOf::<T>::max_value());
+
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+		let _ = T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+
+		add_registrars::<T>(r)?;
+
+		let info = T::IdentityInformation::create_identity_info();
+		let info_hash = T::Hashing::hash_of(&info);
+		Identity::<T>::set_identity(user_origin.clone(), Box::new(info))?;
+
+		let registrar_origin = T::RegistrarOrigin::try_successful_origin()
+			.expect("RegistrarOrigin has no successful origin required for the benchmark");
+		Identity::<T>::add_registrar(registrar_origin, caller_lookup)?;
+		Identity::<T>::request_judgement(user_origin, r, 10u32.into())?;
+		let evidence = Data::BlakeTwo256([1u8; 32]);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			r,
+			user_lookup,
+			Judgement::Reasonable,
+			info_hash,
+			evidence.clone(),
+		);
+
+		assert_last_event::<T>(
+			Event::<T>::JudgementGiven { target: user.clone(), registrar_index: r }.into(),
+		);
+		assert_eq!(JudgementEvidence::<T>::get(&user, r), Some((info_hash, evidence)));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn refund_expired_judgement(
+		r: Linear<1, { T::MaxRegistrars::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let user: T::AccountId = account("user", r, SEED);
+		let user_origin =
+			<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(user.clone()));
+		let user_lookup = T::Lookup::unlookup(user.clone());
+		let _ = T::Currency::make_free_balance_be(&user, BalanceOf::<T>::max_value());
+
+		add_registrars::<T>(r)?;
+
+		let info = T::IdentityInformation::create_identity_info();
+		Identity::<T>::set_identity(user_origin.clone(), Box::new(info))?;
+		Identity::<T>::request_judgement(user_origin, r - 1, 10u32.into())?;
+
+		// Let the registrar miss the deadline.
+		let (_, deadline) = JudgementRequests::<T>::get(&user, r - 1).unwrap();
+		frame_system::Pallet::<T>::set_block_number(deadline + One::one());
+
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), user_lookup, r - 1);
+
+		assert!(!JudgementRequests::<T>::contains_key(&user, r - 1));
+		assert_eq!(RegistrarStats::<T>::get(r - 1).requests_expired, 1);
+
+		Ok(())
+	}
 
 	#[benchmark]
 	fn kill_identity(
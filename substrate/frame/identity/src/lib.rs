@@ -32,6 +32,12 @@
 //! complete removal of the identity, or by the registrar. Judgements are allowed to represent a
 //! portion of funds that have been reserved for the registrar.
 //!
+//! Registrars are expected to answer a judgement request within `JudgementPeriod` blocks. Once
+//! that deadline has passed, anyone may refund the fee to the requester. How registrars perform
+//! against their deadlines is tracked on-chain in [`RegistrarStats`]. Registrars may attach
+//! evidence to a judgement, such as the hash of a statement or of a signed attestation, which is
+//! kept in [`JudgementEvidence`].
+//!
 //! A super-user can remove accounts and in doing so, slash the deposit.
 //!
 //! All accounts may also have a limited number of sub-accounts which may be specified by the owner;
@@ -73,6 +79,7 @@
 //! * `clear_identity` - Remove an account's associated identity; the deposit is returned.
 //! * `request_judgement` - Request a judgement from a registrar, paying a fee.
 //! * `cancel_request` - Cancel the previous request for a judgement.
+//! * `refund_expired_judgement` - Refund the fee of a judgement request whose deadline has passed.
 //! * `accept_username` - Accept a username issued by a username authority.
 //! * `remove_expired_approval` - Remove a username that was issued but never accepted.
 //! * `set_primary_username` - Set a given username as an account's primary.
@@ -89,6 +96,8 @@
 //! * `set_fee` - Set the fee required to be paid for a judgement to be given by the registrar.
 //! * `set_fields` - Set the fields that a registrar cares about in their judgements.
 //! * `provide_judgement` - Provide a judgement to an identity.
+//! * `provide_judgement_with_evidence` - Provide a judgement to an identity, along with evidence
+//!   backing it.
 //!
 //! #### For Username Authorities
 //! * `set_username_for` - Set a username for a given account. The account must approve it.
@@ -133,7 +142,8 @@ use sp_runtime::traits::{
 	AppendZerosInput, Hash, IdentifyAccount, Saturating, StaticLookup, Verify, Zero,
 };
 pub use types::{
-	Data, IdentityInformationProvider, Judgement, RegistrarIndex, RegistrarInfo, Registration,
+	Data, IdentityInformationProvider, Judgement, RegistrarIndex, RegistrarInfo,
+	RegistrarStatistics, Registration,
 };
 pub use weights::WeightInfo;
 
@@ -247,6 +257,11 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxUsernameLength: Get<u32>;
 
+		/// The number of blocks a registrar has to provide a requested judgement. Once elapsed, the
+		/// fee paid for the request can be refunded.
+		#[pallet::constant]
+		type JudgementPeriod: Get<BlockNumberFor<Self>>;
+
 		/// A set of helper functions for benchmarking.
 		/// The default configuration `()` uses the `SR25519` signature schema.
 		#[cfg(feature = "runtime-benchmarks")]
@@ -256,7 +271,7 @@ pub mod pallet {
 		type WeightInfo: WeightInfo;
 	}
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -363,6 +378,47 @@ pub mod pallet {
 	pub type UnbindingUsernames<T: Config> =
 		StorageMap<_, Blake2_128Concat, Username<T>, BlockNumberFor<T>, OptionQuery>;
 
+	/// The pending judgement requests of an account, per registrar. First tuple item is the block
+	/// the judgement was requested in and second is the deadline for the registrar to provide it.
+	///
+	/// TWOX-NOTE: OK ― `AccountId` is a secure hash.
+	#[pallet::storage]
+	pub type JudgementRequests<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		RegistrarIndex,
+		(BlockNumberFor<T>, BlockNumberFor<T>),
+		OptionQuery,
+	>;
+
+	/// Evidence supplied by a registrar along with its judgement of an account's identity. First
+	/// tuple item is the hash of the identity the judgement was provided for and second is the
+	/// evidence.
+	///
+	/// TWOX-NOTE: OK ― `AccountId` is a secure hash.
+	#[pallet::storage]
+	pub type JudgementEvidence<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		RegistrarIndex,
+		(T::Hash, Data),
+		OptionQuery,
+	>;
+
+	/// Performance statistics of each registrar over the judgement requests it received.
+	#[pallet::storage]
+	pub type RegistrarStats<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		RegistrarIndex,
+		RegistrarStatistics<BlockNumberFor<T>>,
+		ValueQuery,
+	>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Too many subs-accounts.
@@ -426,6 +482,8 @@ pub mod pallet {
 		/// The action cannot be performed because of insufficient privileges (e.g. authority
 		/// trying to unbind a username provided by the system).
 		InsufficientPrivileges,
+		/// The judgement request cannot be refunded because its deadline has not passed yet.
+		RequestNotExpired,
 	}
 
 	#[pallet::event]
@@ -443,6 +501,8 @@ pub mod pallet {
 		JudgementUnrequested { who: T::AccountId, registrar_index: RegistrarIndex },
 		/// A judgement was given by a registrar.
 		JudgementGiven { target: T::AccountId, registrar_index: RegistrarIndex },
+		/// A judgement request passed its deadline and the fee was refunded.
+		JudgementRefunded { who: T::AccountId, registrar_index: RegistrarIndex, fee: BalanceOf<T> },
 		/// A registrar was added.
 		RegistrarAdded { registrar_index: RegistrarIndex },
 		/// A sub-identity was added to an identity and the deposit paid.
@@ -536,7 +596,10 @@ pub mod pallet {
 
 			let mut id = match IdentityOf::<T>::get(&sender) {
 				Some(mut id) => {
-					// Only keep non-positive judgements.
+					// Only keep non-positive judgements, dropping the evidence of the others.
+					for (reg_index, _) in id.judgements.iter().filter(|j| !j.1.is_sticky()) {
+						JudgementEvidence::<T>::remove(&sender, reg_index);
+					}
 					id.judgements.retain(|j| j.1.is_sticky());
 					id.info = *info;
 					id
@@ -657,6 +720,7 @@ pub mod pallet {
 			for sub in sub_ids.iter() {
 				SuperOf::<T>::remove(sub);
 			}
+			Self::clear_judgement_records(&sender);
 
 			let err_amount = T::Currency::unreserve(&sender, deposit);
 			debug_assert!(err_amount.is_zero());
@@ -674,7 +738,8 @@ pub mod pallet {
 		/// Request a judgement from a registrar.
 		///
 		/// Payment: At most `max_fee` will be reserved for payment to the registrar if judgement
-		/// given.
+		/// given. If no judgement is given within `JudgementPeriod` blocks, the fee can be
+		/// refunded with `refund_expired_judgement`.
 		///
 		/// The dispatch origin for this call must be _Signed_ and the sender must have a
 		/// registered identity.
@@ -717,6 +782,10 @@ pub mod pallet {
 
 			T::Currency::reserve(&sender, registrar.fee)?;
 
+			let now = frame_system::Pallet::<T>::block_number();
+			let deadline = now.saturating_add(T::JudgementPeriod::get());
+			JudgementRequests::<T>::insert(&sender, reg_index, (now, deadline));
+
 			let judgements = id.judgements.len();
 			IdentityOf::<T>::insert(&sender, id);
 
@@ -759,6 +828,7 @@ pub mod pallet {
 
 			let err_amount = T::Currency::unreserve(&sender, fee);
 			debug_assert!(err_amount.is_zero());
+			JudgementRequests::<T>::remove(&sender, reg_index);
 			let judgements = id.judgements.len();
 			IdentityOf::<T>::insert(&sender, id);
 
@@ -893,43 +963,10 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
 			let target = T::Lookup::lookup(target)?;
-			ensure!(!judgement.has_deposit(), Error::<T>::InvalidJudgement);
-			Registrars::<T>::get()
-				.get(reg_index as usize)
-				.and_then(Option::as_ref)
-				.filter(|r| r.account == sender)
-				.ok_or(Error::<T>::InvalidIndex)?;
-			let mut id = IdentityOf::<T>::get(&target).ok_or(Error::<T>::InvalidTarget)?;
-
-			if T::Hashing::hash_of(&id.info) != identity {
-				return Err(Error::<T>::JudgementForDifferentIdentity.into())
-			}
-
-			let item = (reg_index, judgement);
-			match id.judgements.binary_search_by_key(&reg_index, |x| x.0) {
-				Ok(position) => {
-					if let Judgement::FeePaid(fee) = id.judgements[position].1 {
-						T::Currency::repatriate_reserved(
-							&target,
-							&sender,
-							fee,
-							BalanceStatus::Free,
-						)
-						.map_err(|_| Error::<T>::JudgementPaymentFailed)?;
-					}
-					id.judgements[position] = item
-				},
-				Err(position) => id
-					.judgements
-					.try_insert(position, item)
-					.map_err(|_| Error::<T>::TooManyRegistrars)?,
-			}
-
-			let judgements = id.judgements.len();
-			IdentityOf::<T>::insert(&target, id);
-			Self::deposit_event(Event::JudgementGiven { target, registrar_index: reg_index });
+			let judgements =
+				Self::do_provide_judgement(sender, reg_index, target, judgement, identity, None)?;
 
-			Ok(Some(T::WeightInfo::provide_judgement(judgements as u32)).into())
+			Ok(Some(T::WeightInfo::provide_judgement(judgements)).into())
 		}
 
 		/// Remove an account's identity and sub-account information and slash the deposits.
@@ -964,6 +1001,7 @@ pub mod pallet {
 			for sub in sub_ids.iter() {
 				SuperOf::<T>::remove(sub);
 			}
+			Self::clear_judgement_records(&target);
 			// Slash their deposit from them.
 			T::Slashed::on_unbalanced(T::Currency::slash_reserved(&target, deposit).0);
 
@@ -1401,6 +1439,98 @@ pub mod pallet {
 			Self::deposit_event(Event::UsernameKilled { username });
 			Ok((Some(actual_weight), Pays::No).into())
 		}
+
+		/// Provide a judgement for an account's identity, along with evidence backing it.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must be the account
+		/// of the registrar whose index is `reg_index`.
+		///
+		/// - `reg_index`: the index of the registrar whose judgement is being made.
+		/// - `target`: the account whose identity the judgement is upon. This must be an account
+		///   with a registered identity.
+		/// - `judgement`: the judgement of the registrar of index `reg_index` about `target`.
+		/// - `identity`: The hash of the [`IdentityInformationProvider`] for that the judgement is
+		///   provided.
+		/// - `evidence`: the evidence the judgement is based on, e.g. the hash of a statement or of
+		///   a signed attestation. It is kept in [`JudgementEvidence`] until the judgement is
+		///   replaced or removed.
+		///
+		/// Emits `JudgementGiven` if successful.
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::provide_judgement_with_evidence(T::MaxRegistrars::get()))]
+		pub fn provide_judgement_with_evidence(
+			origin: OriginFor<T>,
+			#[pallet::compact] reg_index: RegistrarIndex,
+			target: AccountIdLookupOf<T>,
+			judgement: Judgement<BalanceOf<T>>,
+			identity: T::Hash,
+			evidence: Data,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			let judgements = Self::do_provide_judgement(
+				sender,
+				reg_index,
+				target,
+				judgement,
+				identity,
+				Some(evidence),
+			)?;
+
+			Ok(Some(T::WeightInfo::provide_judgement_with_evidence(judgements)).into())
+		}
+
+		/// Refund the fee of a judgement request which the registrar did not answer before its
+		/// deadline.
+		///
+		/// The dispatch origin for this call must be _Signed_. Any account may trigger the refund
+		/// once the deadline has passed, and the call is free in that case.
+		///
+		/// - `target`: the account that requested the judgement.
+		/// - `reg_index`: the index of the registrar the judgement was requested from.
+		///
+		/// Emits `JudgementRefunded` if successful.
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::refund_expired_judgement(T::MaxRegistrars::get()))]
+		pub fn refund_expired_judgement(
+			origin: OriginFor<T>,
+			target: AccountIdLookupOf<T>,
+			reg_index: RegistrarIndex,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let target = T::Lookup::lookup(target)?;
+
+			let (_, deadline) =
+				JudgementRequests::<T>::get(&target, reg_index).ok_or(Error::<T>::NotFound)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now > deadline, Error::<T>::RequestNotExpired);
+
+			let mut id = IdentityOf::<T>::get(&target).ok_or(Error::<T>::NoIdentity)?;
+			let pos = id
+				.judgements
+				.binary_search_by_key(&reg_index, |x| x.0)
+				.map_err(|_| Error::<T>::NotFound)?;
+			let fee = if let Judgement::FeePaid(fee) = id.judgements.remove(pos).1 {
+				fee
+			} else {
+				return Err(Error::<T>::JudgementGiven.into())
+			};
+
+			let err_amount = T::Currency::unreserve(&target, fee);
+			debug_assert!(err_amount.is_zero());
+			JudgementRequests::<T>::remove(&target, reg_index);
+			RegistrarStats::<T>::mutate(reg_index, |stats| stats.requests_expired.saturating_inc());
+			let judgements = id.judgements.len();
+			IdentityOf::<T>::insert(&target, id);
+
+			Self::deposit_event(Event::JudgementRefunded {
+				who: target,
+				registrar_index: reg_index,
+				fee,
+			});
+
+			Ok((Some(T::WeightInfo::refund_expired_judgement(judgements as u32)), Pays::No).into())
+		}
 	}
 }
 
@@ -1414,6 +1544,77 @@ impl<T: Config> Pallet<T> {
 			.collect()
 	}
 
+	/// Record the judgement of registrar `reg_index` on the identity of `target`, paying the
+	/// registrar any fee reserved for it and updating the registrar's statistics.
+	///
+	/// Any evidence previously kept for this judgement is replaced by `evidence`.
+	///
+	/// Returns the number of judgements on the identity.
+	fn do_provide_judgement(
+		sender: T::AccountId,
+		reg_index: RegistrarIndex,
+		target: T::AccountId,
+		judgement: Judgement<BalanceOf<T>>,
+		identity: T::Hash,
+		evidence: Option<Data>,
+	) -> Result<u32, DispatchError> {
+		ensure!(!judgement.has_deposit(), Error::<T>::InvalidJudgement);
+		Registrars::<T>::get()
+			.get(reg_index as usize)
+			.and_then(Option::as_ref)
+			.filter(|r| r.account == sender)
+			.ok_or(Error::<T>::InvalidIndex)?;
+		let mut id = IdentityOf::<T>::get(&target).ok_or(Error::<T>::InvalidTarget)?;
+
+		if T::Hashing::hash_of(&id.info) != identity {
+			return Err(Error::<T>::JudgementForDifferentIdentity.into())
+		}
+
+		let item = (reg_index, judgement);
+		match id.judgements.binary_search_by_key(&reg_index, |x| x.0) {
+			Ok(position) => {
+				if let Judgement::FeePaid(fee) = id.judgements[position].1 {
+					T::Currency::repatriate_reserved(&target, &sender, fee, BalanceStatus::Free)
+						.map_err(|_| Error::<T>::JudgementPaymentFailed)?;
+					if let Some((requested_at, _)) =
+						JudgementRequests::<T>::take(&target, reg_index)
+					{
+						let now = frame_system::Pallet::<T>::block_number();
+						RegistrarStats::<T>::mutate(reg_index, |stats| {
+							stats.judgements_given.saturating_inc();
+							stats
+								.total_response_time
+								.saturating_accrue(now.saturating_sub(requested_at));
+						});
+					}
+				}
+				id.judgements[position] = item
+			},
+			Err(position) => id
+				.judgements
+				.try_insert(position, item)
+				.map_err(|_| Error::<T>::TooManyRegistrars)?,
+		}
+
+		match evidence {
+			Some(evidence) =>
+				JudgementEvidence::<T>::insert(&target, reg_index, (identity, evidence)),
+			None => JudgementEvidence::<T>::remove(&target, reg_index),
+		}
+
+		let judgements = id.judgements.len() as u32;
+		IdentityOf::<T>::insert(&target, id);
+		Self::deposit_event(Event::JudgementGiven { target, registrar_index: reg_index });
+
+		Ok(judgements)
+	}
+
+	/// Remove the pending judgement requests and the judgement evidence kept for `who`.
+	fn clear_judgement_records(who: &T::AccountId) {
+		let _ = JudgementRequests::<T>::clear_prefix(who, T::MaxRegistrars::get(), None);
+		let _ = JudgementEvidence::<T>::clear_prefix(who, T::MaxRegistrars::get(), None);
+	}
+
 	/// Calculate the deposit required for a number of `sub` accounts.
 	fn subs_deposit(subs: u32) -> BalanceOf<T> {
 		T::SubAccountDeposit::get().saturating_mul(BalanceOf::<T>::from(subs))
@@ -1587,6 +1788,9 @@ impl<T: Config> Pallet<T> {
 			SuperOf::<T>::remove(sub);
 		}
 
+		// pending judgement requests and evidence
+		Self::clear_judgement_records(&who);
+
 		// unreserve any deposits
 		let deposit = id.total_deposit().saturating_add(subs_deposit);
 		let err_amount = T::Currency::unreserve(&who, deposit);
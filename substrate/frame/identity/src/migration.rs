@@ -42,6 +42,14 @@ pub mod versioned {
 		crate::pallet::Pallet<T>,
 		<T as frame_system::Config>::DbWeight,
 	>;
+
+	pub type V2ToV3<T> = VersionedMigration<
+		2,
+		3,
+		v3::VersionUncheckedMigrateV2ToV3<T>,
+		crate::pallet::Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
 }
 
 /// The old identity types in v0.
@@ -848,3 +856,143 @@ pub mod v2 {
 		}
 	}
 }
+
+pub mod v3 {
+	use super::*;
+
+	/// The log target.
+	const TARGET: &'static str = "runtime::identity::migration::v3";
+
+	/// The pending judgement requests which have no deadline yet.
+	fn requests_without_deadline<T: Config>() -> impl Iterator<Item = (T::AccountId, RegistrarIndex)>
+	{
+		IdentityOf::<T>::iter().flat_map(|(account, registration)| {
+			registration
+				.judgements
+				.into_iter()
+				.filter(|(_, judgement)| matches!(judgement, Judgement::FeePaid(_)))
+				.map(move |(reg_index, _)| (account.clone(), reg_index))
+				.filter(|(account, reg_index)| {
+					!JudgementRequests::<T>::contains_key(account, reg_index)
+				})
+		})
+	}
+
+	/// Migration to give the judgement requests made before `JudgementRequests` existed a
+	/// deadline, so that their fees can be refunded once it expires.
+	///
+	/// Each request is treated as if it was made at the upgrade, expiring `JudgementPeriod` blocks
+	/// later.
+	pub struct VersionUncheckedMigrateV2ToV3<T>(PhantomData<T>);
+	impl<T: Config> UncheckedOnRuntimeUpgrade for VersionUncheckedMigrateV2ToV3<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let requests = requests_without_deadline::<T>().count() as u64;
+			log::info!(
+				target: TARGET,
+				"pre-upgrade state contains '{}' judgement requests without a deadline.",
+				requests
+			);
+			Ok(requests.encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			log::info!(
+				target: TARGET,
+				"running storage migration from version 2 to version 3."
+			);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let deadline = now.saturating_add(T::JudgementPeriod::get());
+
+			let mut identities: u64 = 0;
+			let mut judgements: u64 = 0;
+			let mut migrated: u64 = 0;
+			for (account, registration) in IdentityOf::<T>::iter() {
+				identities.saturating_inc();
+				for (reg_index, judgement) in registration.judgements {
+					if !matches!(judgement, Judgement::FeePaid(_)) {
+						continue
+					}
+					judgements.saturating_inc();
+					if !JudgementRequests::<T>::contains_key(&account, reg_index) {
+						JudgementRequests::<T>::insert(&account, reg_index, (now, deadline));
+						migrated.saturating_inc();
+					}
+				}
+			}
+			log::info!(target: TARGET, "gave {} judgement requests a deadline", migrated);
+
+			// The block number read and the version write.
+			let mut weight = T::DbWeight::get().reads_writes(1, 1);
+			weight
+				.saturating_accrue(T::DbWeight::get().reads(identities.saturating_add(judgements)));
+			weight.saturating_accrue(T::DbWeight::get().writes(migrated));
+			weight
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let requests: u64 = Decode::decode(&mut &state[..])
+				.expect("failed to decode the state from pre-upgrade.");
+			log::info!(
+				target: TARGET,
+				"post-upgrade expects '{}' judgement requests to have been given a deadline.",
+				requests
+			);
+			ensure!(
+				requests_without_deadline::<T>().next().is_none(),
+				"all judgement requests must have a deadline."
+			);
+			Ok(())
+		}
+	}
+
+	#[cfg(test)]
+	mod tests {
+		use super::*;
+		use crate::tests::{new_test_ext, Test};
+		use frame_support::traits::OnRuntimeUpgrade;
+
+		#[test]
+		fn migrate_to_v3() {
+			new_test_ext().execute_with(|| {
+				StorageVersion::new(2).put::<Pallet<Test>>();
+				frame_system::Pallet::<Test>::set_block_number(7);
+
+				// Account 1 requested judgements from registrars 0 and 2 before the upgrade and
+				// was judged by registrar 1. Account 2 requested one after `JudgementRequests`
+				// existed already.
+				let account =
+					|byte: u8| -> <Test as frame_system::Config>::AccountId { [byte; 32].into() };
+				let judgements = vec![
+					(0, Judgement::FeePaid(10)),
+					(1, Judgement::Reasonable),
+					(2, Judgement::FeePaid(10)),
+				];
+				let registration = Registration {
+					judgements: judgements.try_into().unwrap(),
+					deposit: 10,
+					info: Default::default(),
+				};
+				IdentityOf::<Test>::insert(account(1), registration);
+				let registration = Registration {
+					judgements: vec![(0, Judgement::FeePaid(10))].try_into().unwrap(),
+					deposit: 10,
+					info: Default::default(),
+				};
+				IdentityOf::<Test>::insert(account(2), registration);
+				JudgementRequests::<Test>::insert(account(2), 0, (3, 13));
+
+				versioned::V2ToV3::<Test>::on_runtime_upgrade();
+				assert_eq!(Pallet::<Test>::on_chain_storage_version(), 3);
+
+				assert_eq!(JudgementRequests::<Test>::get(account(1), 0), Some((7, 17)));
+				assert_eq!(JudgementRequests::<Test>::get(account(1), 1), None);
+				assert_eq!(JudgementRequests::<Test>::get(account(1), 2), Some((7, 17)));
+				// Existing requests keep their deadline.
+				assert_eq!(JudgementRequests::<Test>::get(account(2), 0), Some((3, 13)));
+			});
+		}
+	}
+}
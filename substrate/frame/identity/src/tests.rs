@@ -25,7 +25,9 @@ use crate::{
 
 use codec::{Decode, Encode};
 use frame_support::{
-	assert_err, assert_noop, assert_ok, derive_impl, parameter_types,
+	assert_err, assert_noop, assert_ok, derive_impl,
+	dispatch::Pays,
+	parameter_types,
 	traits::{ConstU32, ConstU64, Get},
 	BoundedVec,
 };
@@ -91,6 +93,7 @@ impl pallet_identity::Config for Test {
 	type UsernameGracePeriod = ConstU64<2>;
 	type MaxSuffixLength = ConstU32<7>;
 	type MaxUsernameLength = ConstU32<32>;
+	type JudgementPeriod = ConstU64<10>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 	type WeightInfo = ();
@@ -777,6 +780,180 @@ fn provide_judgement_should_return_judgement_payment_failed_error() {
 	});
 }
 
+#[test]
+fn expired_judgement_request_can_be_refunded() {
+	new_test_ext().execute_with(|| {
+		let [one, _, three, _, ten, _, _, _] = accounts();
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
+		assert_ok!(Identity::set_fee(RuntimeOrigin::signed(three.clone()), 0, 10));
+		let ten_info = infoof_ten();
+		let id_deposit = id_deposit(&ten_info);
+		assert_ok!(Identity::set_identity(RuntimeOrigin::signed(ten.clone()), Box::new(ten_info)));
+		assert_noop!(
+			Identity::refund_expired_judgement(RuntimeOrigin::signed(one.clone()), ten.clone(), 0),
+			Error::<Test>::NotFound
+		);
+
+		assert_ok!(Identity::request_judgement(RuntimeOrigin::signed(ten.clone()), 0, 10));
+		let period = <Test as Config>::JudgementPeriod::get();
+		assert_eq!(JudgementRequests::<Test>::get(&ten, 0), Some((1, 1 + period)));
+
+		// The registrar still has time to respond.
+		System::set_block_number(1 + period);
+		assert_noop!(
+			Identity::refund_expired_judgement(RuntimeOrigin::signed(one.clone()), ten.clone(), 0),
+			Error::<Test>::RequestNotExpired
+		);
+
+		// Anyone can trigger the refund once the deadline has passed, and it is free.
+		System::set_block_number(2 + period);
+		let info =
+			Identity::refund_expired_judgement(RuntimeOrigin::signed(one.clone()), ten.clone(), 0)
+				.unwrap();
+		assert_eq!(info.pays_fee, Pays::No);
+		System::assert_last_event(
+			Event::JudgementRefunded { who: ten.clone(), registrar_index: 0, fee: 10 }.into(),
+		);
+
+		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit);
+		assert!(IdentityOf::<Test>::get(&ten).unwrap().judgements.is_empty());
+		assert!(!JudgementRequests::<Test>::contains_key(&ten, 0));
+		assert_eq!(RegistrarStats::<Test>::get(0).requests_expired, 1);
+		assert_noop!(
+			Identity::refund_expired_judgement(RuntimeOrigin::signed(one), ten.clone(), 0),
+			Error::<Test>::NotFound
+		);
+
+		// The judgement can be requested again.
+		assert_ok!(Identity::request_judgement(RuntimeOrigin::signed(ten), 0, 10));
+	});
+}
+
+#[test]
+fn registrar_statistics_are_tracked() {
+	new_test_ext().execute_with(|| {
+		let [_, _, three, _, ten, twenty, _, _] = accounts();
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
+		assert_ok!(Identity::set_fee(RuntimeOrigin::signed(three.clone()), 0, 10));
+		let ten_info = infoof_ten();
+		let twenty_info = infoof_twenty();
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(ten_info.clone())
+		));
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(twenty.clone()),
+			Box::new(twenty_info.clone())
+		));
+		assert_ok!(Identity::request_judgement(RuntimeOrigin::signed(ten.clone()), 0, 10));
+
+		System::set_block_number(5);
+		assert_ok!(Identity::request_judgement(RuntimeOrigin::signed(twenty.clone()), 0, 10));
+		assert_ok!(Identity::provide_judgement(
+			RuntimeOrigin::signed(three.clone()),
+			0,
+			ten.clone(),
+			Judgement::Reasonable,
+			BlakeTwo256::hash_of(&ten_info)
+		));
+
+		System::set_block_number(8);
+		assert_ok!(Identity::provide_judgement(
+			RuntimeOrigin::signed(three.clone()),
+			0,
+			twenty.clone(),
+			Judgement::KnownGood,
+			BlakeTwo256::hash_of(&twenty_info)
+		));
+		assert_eq!(
+			RegistrarStats::<Test>::get(0),
+			RegistrarStatistics {
+				judgements_given: 2,
+				requests_expired: 0,
+				total_response_time: 7
+			}
+		);
+		assert!(!JudgementRequests::<Test>::contains_key(&ten, 0));
+		assert!(!JudgementRequests::<Test>::contains_key(&twenty, 0));
+
+		// Uninvited judgements are not counted.
+		assert_ok!(Identity::provide_judgement(
+			RuntimeOrigin::signed(three),
+			0,
+			ten,
+			Judgement::LowQuality,
+			BlakeTwo256::hash_of(&ten_info)
+		));
+		assert_eq!(RegistrarStats::<Test>::get(0).judgements_given, 2);
+	});
+}
+
+#[test]
+fn judgement_evidence_is_stored_and_cleared() {
+	new_test_ext().execute_with(|| {
+		let [_, _, three, _, ten, _, _, _] = accounts();
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
+		let ten_info = infoof_ten();
+		let ten_hash = BlakeTwo256::hash_of(&ten_info);
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(ten_info.clone())
+		));
+
+		let evidence = Data::Sha256([7; 32]);
+		assert_ok!(Identity::provide_judgement_with_evidence(
+			RuntimeOrigin::signed(three.clone()),
+			0,
+			ten.clone(),
+			Judgement::Reasonable,
+			ten_hash,
+			evidence.clone(),
+		));
+		System::assert_last_event(
+			Event::JudgementGiven { target: ten.clone(), registrar_index: 0 }.into(),
+		);
+		assert_eq!(JudgementEvidence::<Test>::get(&ten, 0), Some((ten_hash, evidence.clone())));
+
+		// A judgement without evidence drops the previous evidence.
+		assert_ok!(Identity::provide_judgement(
+			RuntimeOrigin::signed(three.clone()),
+			0,
+			ten.clone(),
+			Judgement::KnownGood,
+			ten_hash
+		));
+		assert!(!JudgementEvidence::<Test>::contains_key(&ten, 0));
+
+		// Evidence goes along with a non-sticky judgement when the identity changes.
+		assert_ok!(Identity::provide_judgement_with_evidence(
+			RuntimeOrigin::signed(three.clone()),
+			0,
+			ten.clone(),
+			Judgement::Reasonable,
+			ten_hash,
+			evidence.clone(),
+		));
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(infoof_twenty())
+		));
+		assert!(IdentityOf::<Test>::get(&ten).unwrap().judgements.is_empty());
+		assert!(!JudgementEvidence::<Test>::contains_key(&ten, 0));
+
+		// Clearing the identity removes the evidence.
+		assert_ok!(Identity::provide_judgement_with_evidence(
+			RuntimeOrigin::signed(three),
+			0,
+			ten.clone(),
+			Judgement::Reasonable,
+			BlakeTwo256::hash_of(&infoof_twenty()),
+			evidence,
+		));
+		assert_ok!(Identity::clear_identity(RuntimeOrigin::signed(ten.clone())));
+		assert!(!JudgementEvidence::<Test>::contains_key(&ten, 0));
+	});
+}
+
 #[test]
 fn field_deposit_should_work() {
 	new_test_ext().execute_with(|| {
@@ -331,6 +331,22 @@ pub struct RegistrarInfo<
 	pub fields: IdField,
 }
 
+/// Performance statistics of a registrar, covering the judgement requests it has received.
+#[derive(
+	Clone, Copy, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo,
+)]
+pub struct RegistrarStatistics<BlockNumber> {
+	/// The number of requested judgements the registrar has provided.
+	pub judgements_given: u32,
+
+	/// The number of requests the registrar let pass their deadline, and whose fee was refunded.
+	pub requests_expired: u32,
+
+	/// The total number of blocks elapsed between request and judgement, over all
+	/// `judgements_given`.
+	pub total_response_time: BlockNumber,
+}
+
 /// The number of usernames that an authority may allocate.
 type Allocation = u32;
 /// A byte vec used to represent a username.
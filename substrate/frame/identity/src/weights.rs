@@ -83,6 +83,8 @@ pub trait WeightInfo {
 	fn set_account_id(r: u32, ) -> Weight;
 	fn set_fields(r: u32, ) -> Weight;
 	fn provide_judgement(r: u32, ) -> Weight;
+	fn provide_judgement_with_evidence(r: u32, ) -> Weight;
+	fn refund_expired_judgement(r: u32, ) -> Weight;
 	fn kill_identity(r: u32, s: u32, ) -> Weight;
 	fn add_sub(s: u32, ) -> Weight;
 	fn rename_sub(s: u32, ) -> Weight;
@@ -274,6 +276,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
 	/// Storage: `Identity::IdentityOf` (r:1 w:1)
 	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::JudgementRequests` (r:1 w:1)
+	/// Proof: `Identity::JudgementRequests` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RegistrarStats` (r:1 w:1)
+	/// Proof: `Identity::RegistrarStats` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::JudgementEvidence` (r:0 w:1)
+	/// Proof: `Identity::JudgementEvidence` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 19]`.
 	fn provide_judgement(r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -283,8 +291,50 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(99_508_071, 11003)
 			// Standard Error: 4_757
 			.saturating_add(Weight::from_parts(99_527, 0).saturating_mul(r.into()))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Identity::Registrars` (r:1 w:0)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:1)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::JudgementRequests` (r:1 w:1)
+	/// Proof: `Identity::JudgementRequests` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RegistrarStats` (r:1 w:1)
+	/// Proof: `Identity::RegistrarStats` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::JudgementEvidence` (r:0 w:1)
+	/// Proof: `Identity::JudgementEvidence` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 19]`.
+	fn provide_judgement_with_evidence(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7024 + r * (57 ±0)`
+		//  Estimated: `11003`
+		// Minimum execution time: 97_919_000 picoseconds.
+		Weight::from_parts(99_508_071, 11003)
+			// Standard Error: 4_757
+			.saturating_add(Weight::from_parts(99_527, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Identity::JudgementRequests` (r:1 w:1)
+	/// Proof: `Identity::JudgementRequests` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:1)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RegistrarStats` (r:1 w:1)
+	/// Proof: `Identity::RegistrarStats` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 20]`.
+	fn refund_expired_judgement(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6976`
+		//  Estimated: `11003`
+		// Minimum execution time: 73_927_000 picoseconds.
+		Weight::from_parts(75_928_402, 11003)
+			// Standard Error: 4_193
+			.saturating_add(Weight::from_parts(74_407, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Identity::SubsOf` (r:1 w:1)
 	/// Proof: `Identity::SubsOf` (`max_values`: None, `max_size`: Some(3258), added: 5733, mode: `MaxEncodedLen`)
@@ -763,6 +813,12 @@ impl WeightInfo for () {
 	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
 	/// Storage: `Identity::IdentityOf` (r:1 w:1)
 	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::JudgementRequests` (r:1 w:1)
+	/// Proof: `Identity::JudgementRequests` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RegistrarStats` (r:1 w:1)
+	/// Proof: `Identity::RegistrarStats` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::JudgementEvidence` (r:0 w:1)
+	/// Proof: `Identity::JudgementEvidence` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 19]`.
 	fn provide_judgement(r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -772,8 +828,50 @@ impl WeightInfo for () {
 		Weight::from_parts(99_508_071, 11003)
 			// Standard Error: 4_757
 			.saturating_add(Weight::from_parts(99_527, 0).saturating_mul(r.into()))
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Identity::Registrars` (r:1 w:0)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:1)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::JudgementRequests` (r:1 w:1)
+	/// Proof: `Identity::JudgementRequests` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RegistrarStats` (r:1 w:1)
+	/// Proof: `Identity::RegistrarStats` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::JudgementEvidence` (r:0 w:1)
+	/// Proof: `Identity::JudgementEvidence` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 19]`.
+	fn provide_judgement_with_evidence(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7024 + r * (57 ±0)`
+		//  Estimated: `11003`
+		// Minimum execution time: 97_919_000 picoseconds.
+		Weight::from_parts(99_508_071, 11003)
+			// Standard Error: 4_757
+			.saturating_add(Weight::from_parts(99_527, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Identity::JudgementRequests` (r:1 w:1)
+	/// Proof: `Identity::JudgementRequests` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:1)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RegistrarStats` (r:1 w:1)
+	/// Proof: `Identity::RegistrarStats` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 20]`.
+	fn refund_expired_judgement(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6976`
+		//  Estimated: `11003`
+		// Minimum execution time: 73_927_000 picoseconds.
+		Weight::from_parts(75_928_402, 11003)
+			// Standard Error: 4_193
+			.saturating_add(Weight::from_parts(74_407, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Identity::SubsOf` (r:1 w:1)
 	/// Proof: `Identity::SubsOf` (`max_values`: None, `max_size`: Some(3258), added: 5733, mode: `MaxEncodedLen`)
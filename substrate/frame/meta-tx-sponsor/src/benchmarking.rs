@@ -0,0 +1,200 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Meta Transaction Sponsor pallet benchmarking.
+
+use super::*;
+use crate::Pallet as MetaTxSponsor;
+use alloc::{vec, vec::Vec};
+use frame_benchmarking::{v2::*, whitelisted_caller};
+use frame_support::{
+	assert_ok,
+	traits::fungible::{Inspect as _, Mutate as _},
+};
+use frame_system::{Pallet as System, RawOrigin};
+use sp_runtime::traits::{Bounded, One};
+
+/// Benchmark Helper
+pub trait BenchmarkHelper<AssetId, AccountId, AssetBalance> {
+	/// Creates an asset to take reimbursements in and mints `amount` of it to `who`.
+	fn create_asset(who: &AccountId, amount: AssetBalance) -> AssetId;
+}
+
+type RuntimeCallOf<T> = <T as frame_system::Config>::RuntimeCall;
+
+fn sponsored_call<T: Config>() -> RuntimeCallOf<T> {
+	frame_system::Call::<T>::remark { remark: vec![] }.into()
+}
+
+/// The maximum number of call filters, of which only the last one matches [`sponsored_call`].
+fn call_filters<T: Config>() -> BoundedVec<CallFilterOf<T>, T::MaxSponsoredCalls> {
+	let name = NameOf::<T>::truncate_from(vec![0u8; T::MaxNameLen::get() as usize]);
+	let mut filters: Vec<_> = (1..T::MaxSponsoredCalls::get())
+		.map(|_| CallFilter { pallet: name.clone(), call: Some(name.clone()) })
+		.collect();
+	let CallMetadata { pallet_name, function_name } = sponsored_call::<T>().get_call_metadata();
+	filters.push(CallFilter {
+		pallet: NameOf::<T>::truncate_from(pallet_name.as_bytes().to_vec()),
+		call: Some(NameOf::<T>::truncate_from(function_name.as_bytes().to_vec())),
+	});
+	BoundedVec::truncate_from(filters)
+}
+
+/// A reimbursement in a new asset, of which `signer` holds plenty.
+fn reimbursement<T: Config>(signer: &T::AccountId) -> ReimbursementOf<T> {
+	let asset = T::BenchmarkHelper::create_asset(signer, 1_000_000u32.into());
+	let amount =
+		<T::Assets as fungibles::Inspect<_>>::minimum_balance(asset.clone()).max(One::one());
+	Reimbursement { asset, amount }
+}
+
+/// Set the worst-case policy for `sponsor` and fund its pool.
+fn create_sponsor<T: Config>(sponsor: &T::AccountId, signer: &T::AccountId) -> BalanceOf<T> {
+	let amount = BalanceOf::<T>::max_value() / 4u32.into();
+	T::Currency::set_balance(sponsor, amount.saturating_mul(3u32.into()));
+	assert_ok!(MetaTxSponsor::<T>::set_policy(
+		RawOrigin::Signed(sponsor.clone()).into(),
+		call_filters::<T>(),
+		BalanceOf::<T>::max_value(),
+		Some(reimbursement::<T>(signer)),
+	));
+	assert_ok!(MetaTxSponsor::<T>::fund(RawOrigin::Signed(sponsor.clone()).into(), amount));
+	amount
+}
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	System::<T>::assert_last_event(generic_event.into());
+}
+
+#[benchmarks(
+	where
+		T: Config,
+		<T as pallet_meta_tx::Config>::Extension: Default,
+	)]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn set_policy() {
+		let sponsor: T::AccountId = whitelisted_caller();
+		let reimbursement = reimbursement::<T>(&account("signer", 0, 0));
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(sponsor.clone()),
+			call_filters::<T>(),
+			BalanceOf::<T>::max_value(),
+			Some(reimbursement),
+		);
+
+		assert_last_event::<T>(Event::PolicySet { sponsor }.into());
+	}
+
+	#[benchmark]
+	fn fund() {
+		let sponsor: T::AccountId = whitelisted_caller();
+		let amount = create_sponsor::<T>(&sponsor, &account("signer", 0, 0));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(sponsor.clone()), amount);
+
+		assert_last_event::<T>(Event::Funded { sponsor, amount }.into());
+	}
+
+	#[benchmark]
+	fn withdraw() {
+		let sponsor: T::AccountId = whitelisted_caller();
+		let amount = create_sponsor::<T>(&sponsor, &account("signer", 0, 0));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(sponsor.clone()), amount);
+
+		assert_last_event::<T>(Event::Withdrawn { sponsor, amount }.into());
+	}
+
+	#[benchmark]
+	fn remove_sponsor() {
+		let sponsor: T::AccountId = whitelisted_caller();
+		let released = create_sponsor::<T>(&sponsor, &account("signer", 0, 0));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(sponsor.clone()));
+
+		assert_last_event::<T>(Event::SponsorRemoved { sponsor, released }.into());
+	}
+
+	#[benchmark]
+	fn approve_sponsor() {
+		let signer: T::AccountId = whitelisted_caller();
+		let sponsor: T::AccountId = account("sponsor", 0, 0);
+		let max_reimbursement = AssetBalanceOf::<T>::max_value();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(signer.clone()), sponsor.clone(), max_reimbursement);
+
+		assert_last_event::<T>(
+			Event::SponsorApproved { signer, sponsor, max_reimbursement }.into(),
+		);
+	}
+
+	#[benchmark]
+	fn revoke_sponsor() {
+		let signer: T::AccountId = whitelisted_caller();
+		let sponsor: T::AccountId = account("sponsor", 0, 0);
+		assert_ok!(MetaTxSponsor::<T>::approve_sponsor(
+			RawOrigin::Signed(signer.clone()).into(),
+			sponsor.clone(),
+			AssetBalanceOf::<T>::max_value(),
+		));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(signer.clone()), sponsor.clone());
+
+		assert_last_event::<T>(Event::ApprovalRevoked { signer, sponsor }.into());
+	}
+
+	#[benchmark]
+	fn dispatch_sponsored() {
+		// the meta transaction extension sets the whitelisted caller as the signer.
+		let signer: T::AccountId = whitelisted_caller();
+		let sponsor: T::AccountId = account("sponsor", 0, 0);
+		let relayer: T::AccountId = account("relayer", 0, 0);
+		create_sponsor::<T>(&sponsor, &signer);
+		assert_ok!(MetaTxSponsor::<T>::approve_sponsor(
+			RawOrigin::Signed(signer.clone()).into(),
+			sponsor.clone(),
+			AssetBalanceOf::<T>::max_value(),
+		));
+		T::Currency::set_balance(&relayer, T::Currency::minimum_balance());
+
+		let meta_tx = MetaTxFor::<T>::new(
+			sponsored_call::<T>(),
+			0u8,
+			<T as pallet_meta_tx::Config>::Extension::default(),
+		);
+
+		// the relayer's transaction being applied, of which the length is read.
+		System::<T>::note_extrinsic(meta_tx.encode());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(relayer), sponsor.clone(), Box::new(meta_tx));
+
+		assert!(!SignerUsage::<T>::get(&sponsor, &signer).spent.is_zero());
+	}
+
+	impl_benchmark_test_suite!(MetaTxSponsor, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,686 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # FRAME Meta Transaction Sponsor Pallet
+//!
+//! Lets sponsors pay relayers for dispatching meta transactions on behalf of signers.
+//!
+//! ## Overview
+//!
+//! [`pallet_meta_tx`] lets an untrusted relayer dispatch a call authorized by a signer, with the
+//! relayer covering all the fees. This pallet adds an on-chain registry of sponsors that reimburse
+//! relayers from a pool of funds, so that any relayer can pick up a sponsored meta transaction
+//! without having to trust the signer or the sponsor.
+//!
+//! A sponsor registers a [`SponsorPolicy`] with [`set_policy`](Pallet::set_policy) and places
+//! funds into its pool with [`fund`](Pallet::fund). The pool is held in the sponsor's account
+//! under [`HoldReason::SponsorPool`]. The policy defines:
+//! - the pallets and calls the sponsor is willing to pay for;
+//! - the maximum fees paid on behalf of a single signer in each [`Config::CapPeriod`];
+//! - an optional reimbursement in a [`Config::Assets`] token, taken from the signer for every
+//!   sponsored transaction.
+//!
+//! A relayer submits the meta transaction via
+//! [`dispatch_sponsored`](Pallet::dispatch_sponsored), naming the sponsor. After the target call
+//! is dispatched, the fee for the relayer's transaction is paid to the relayer out of the sponsor
+//! pool. A sponsor cannot sign the meta transactions it pays for, since the dispatched call could
+//! then spend the pool the fee is paid from.
+//!
+//! A signer must opt in to paying a sponsor's reimbursement with
+//! [`approve_sponsor`](Pallet::approve_sponsor), which also caps the reimbursement taken per
+//! transaction, so that a sponsor cannot raise its price once the signer signed a meta
+//! transaction.
+//!
+//! ## Fees
+//!
+//! The fee paid to the relayer is computed by [`pallet_transaction_payment`] from the actual weight
+//! and the length of the relayer's transaction, adjusted by the current fee multiplier, without
+//! any tip. It never exceeds the fee withdrawn from the relayer for the worst-case weight of the
+//! transaction. Before dispatch, the sponsor pool must cover the fee of the worst-case weight,
+//! which is also what counts against the signer's cap.
+#![deny(missing_docs)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub use pallet::*;
+
+use alloc::boxed::Box;
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::{
+	dispatch::{DispatchInfo, GetDispatchInfo, PostDispatchInfo},
+	traits::{
+		fungible, fungibles,
+		tokens::{Fortitude, Precision, Preservation, Restriction},
+		CallMetadata, GetCallMetadata, OriginTrait,
+	},
+	weights::Weight,
+	BoundedVec, RuntimeDebug,
+};
+use pallet_meta_tx::MetaTxFor;
+use scale_info::TypeInfo;
+use sp_runtime::traits::{CheckedDiv, Get, Saturating, TransactionExtension, Zero};
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(all(test, not(feature = "runtime-benchmarks")))]
+mod tests;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+/// The native balance of the sponsor pools.
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as fungible::Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// The id of an asset used for reimbursements.
+pub type AssetIdOf<T> =
+	<<T as Config>::Assets as fungibles::Inspect<<T as frame_system::Config>::AccountId>>::AssetId;
+
+/// The balance of an asset used for reimbursements.
+pub type AssetBalanceOf<T> =
+	<<T as Config>::Assets as fungibles::Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// A pallet or call name, as given by [`GetCallMetadata`].
+pub type NameOf<T> = BoundedVec<u8, <T as Config>::MaxNameLen>;
+
+/// Convenience alias for `CallFilter`.
+pub type CallFilterOf<T> = CallFilter<NameOf<T>>;
+
+/// Convenience alias for `Reimbursement`.
+pub type ReimbursementOf<T> = Reimbursement<AssetIdOf<T>, AssetBalanceOf<T>>;
+
+/// Convenience alias for `SponsorPolicy`.
+pub type SponsorPolicyOf<T> = SponsorPolicy<
+	NameOf<T>,
+	BalanceOf<T>,
+	AssetIdOf<T>,
+	AssetBalanceOf<T>,
+	<T as Config>::MaxSponsoredCalls,
+>;
+
+/// Convenience alias for `Usage`.
+pub type UsageOf<T> = Usage<frame_system::pallet_prelude::BlockNumberFor<T>, BalanceOf<T>>;
+
+/// Calls a sponsor is willing to pay for.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Clone,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub struct CallFilter<Name> {
+	/// The name of the pallet.
+	pub pallet: Name,
+	/// The name of the call within the pallet, or `None` for all calls of the pallet.
+	pub call: Option<Name>,
+}
+
+/// An amount of an asset taken from the signer of each sponsored transaction.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Clone,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub struct Reimbursement<AssetId, AssetBalance> {
+	/// The asset paid by the signer.
+	pub asset: AssetId,
+	/// The amount paid by the signer per transaction.
+	pub amount: AssetBalance,
+}
+
+/// The terms under which a sponsor pays for meta transactions.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Clone,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(MaxCalls))]
+#[codec(mel_bound(
+	Name: MaxEncodedLen, Balance: MaxEncodedLen, AssetId: MaxEncodedLen,
+	AssetBalance: MaxEncodedLen,
+))]
+pub struct SponsorPolicy<Name, Balance, AssetId, AssetBalance, MaxCalls>
+where
+	MaxCalls: Get<u32>,
+{
+	/// The calls the sponsor pays for.
+	pub calls: BoundedVec<CallFilter<Name>, MaxCalls>,
+	/// The maximum fees paid on behalf of a single signer per [`Config::CapPeriod`].
+	pub signer_cap: Balance,
+	/// The reimbursement taken from the signer of each sponsored transaction, if any.
+	pub reimbursement: Option<Reimbursement<AssetId, AssetBalance>>,
+}
+
+impl<Name: AsRef<[u8]>, Balance, AssetId, AssetBalance, MaxCalls: Get<u32>>
+	SponsorPolicy<Name, Balance, AssetId, AssetBalance, MaxCalls>
+{
+	/// Whether the policy covers the call with the given metadata.
+	pub fn allows(&self, metadata: &CallMetadata) -> bool {
+		self.calls.iter().any(|filter| {
+			filter.pallet.as_ref() == metadata.pallet_name.as_bytes() &&
+				filter
+					.call
+					.as_ref()
+					.is_none_or(|call| call.as_ref() == metadata.function_name.as_bytes())
+		})
+	}
+}
+
+/// The fees paid by a sponsor on behalf of a signer.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Clone,
+	Default,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub struct Usage<BlockNumber, Balance> {
+	/// The [`Config::CapPeriod`] the fees were paid in.
+	pub period: BlockNumber,
+	/// The fees paid within `period`.
+	pub spent: Balance,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{dispatch::DispatchResultWithPostInfo, pallet_prelude::*};
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// A reason for the pallet placing a hold on funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// Funds of a sponsor pool, paying relayers of sponsored meta transactions.
+		#[codec(index = 0)]
+		SponsorPool,
+	}
+
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config<RuntimeCall: GetCallMetadata>
+		+ pallet_meta_tx::Config
+		+ pallet_transaction_payment::Config
+	{
+		/// Overarching event type.
+		#[allow(deprecated)]
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The currency sponsor pools are funded with and relayers are paid in.
+		///
+		/// Must be the currency transaction fees are paid in.
+		type Currency: fungible::Mutate<
+				Self::AccountId,
+				Balance = <Self::OnChargeTransaction as pallet_transaction_payment::OnChargeTransaction<
+					Self,
+				>>::Balance,
+			> + fungible::MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+
+		/// The overarching hold reason.
+		type RuntimeHoldReason: From<HoldReason>;
+
+		/// The assets reimbursements can be taken in.
+		type Assets: fungibles::Mutate<Self::AccountId>;
+
+		/// The period over which the fees paid on behalf of a signer are capped.
+		#[pallet::constant]
+		type CapPeriod: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of call filters in a sponsor policy.
+		#[pallet::constant]
+		type MaxSponsoredCalls: Get<u32>;
+
+		/// Maximum length for pallet and call names in call filters.
+		#[pallet::constant]
+		type MaxNameLen: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Helper for benchmarking.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: benchmarking::BenchmarkHelper<
+			AssetIdOf<Self>,
+			Self::AccountId,
+			AssetBalanceOf<Self>,
+		>;
+	}
+
+	/// The policy of each sponsor.
+	#[pallet::storage]
+	pub type Policies<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, SponsorPolicyOf<T>>;
+
+	/// The fees paid by a sponsor on behalf of each signer in the latest period.
+	#[pallet::storage]
+	pub type SignerUsage<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		UsageOf<T>,
+		ValueQuery,
+	>;
+
+	/// The sponsors each signer agreed to reimburse, along with the maximum reimbursement per
+	/// transaction.
+	#[pallet::storage]
+	pub type Approvals<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		AssetBalanceOf<T>,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A sponsor set its policy.
+		PolicySet {
+			/// The sponsor.
+			sponsor: T::AccountId,
+		},
+		/// A sponsor placed funds into its pool.
+		Funded {
+			/// The sponsor.
+			sponsor: T::AccountId,
+			/// The amount placed on hold.
+			amount: BalanceOf<T>,
+		},
+		/// A sponsor withdrew funds from its pool.
+		Withdrawn {
+			/// The sponsor.
+			sponsor: T::AccountId,
+			/// The amount released.
+			amount: BalanceOf<T>,
+		},
+		/// A sponsor removed its policy and withdrew its pool.
+		SponsorRemoved {
+			/// The sponsor.
+			sponsor: T::AccountId,
+			/// The amount released.
+			released: BalanceOf<T>,
+		},
+		/// A signer agreed to reimburse a sponsor.
+		SponsorApproved {
+			/// The signer.
+			signer: T::AccountId,
+			/// The sponsor.
+			sponsor: T::AccountId,
+			/// The maximum reimbursement per transaction.
+			max_reimbursement: AssetBalanceOf<T>,
+		},
+		/// A signer revoked its approval of a sponsor.
+		ApprovalRevoked {
+			/// The signer.
+			signer: T::AccountId,
+			/// The sponsor.
+			sponsor: T::AccountId,
+		},
+		/// A sponsored meta transaction has been dispatched.
+		SponsoredDispatched {
+			/// The sponsor that paid the fee.
+			sponsor: T::AccountId,
+			/// The signer of the meta transaction.
+			signer: T::AccountId,
+			/// The relayer that was paid the fee.
+			relayer: T::AccountId,
+			/// The fee paid to the relayer.
+			fee: BalanceOf<T>,
+			/// The dispatch result of the meta transaction.
+			result: DispatchResultWithPostInfo,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account has not set a sponsor policy.
+		NotSponsor,
+		/// The sponsor does not pay for the call.
+		CallNotSponsored,
+		/// The sponsor pool cannot cover the fee of the transaction.
+		InsufficientPool,
+		/// The fees paid on behalf of the signer would exceed the sponsor's cap for this period.
+		SignerCapExceeded,
+		/// The signer has not approved the reimbursement asked by the sponsor.
+		ReimbursementNotApproved,
+		/// The meta transaction did not authorize a signed origin.
+		NotSigned,
+		/// The signer has not approved the sponsor.
+		NotApproved,
+		/// The sponsor cannot pay for its own meta transactions.
+		SignerIsSponsor,
+	}
+
+	/// Pallet's callable functions.
+	#[pallet::call(weight(<T as Config>::WeightInfo))]
+	impl<T: Config> Pallet<T> {
+		/// Set the policy under which the caller sponsors meta transactions.
+		///
+		/// Replaces any existing policy; the pool is kept.
+		///
+		/// Parameters:
+		/// - `origin`: the sponsor;
+		/// - `calls`: the pallets and calls sponsored;
+		/// - `signer_cap`: the maximum fees paid on behalf of a single signer per
+		///   [`Config::CapPeriod`];
+		/// - `reimbursement`: the amount of an asset taken from the signer of each sponsored
+		///   transaction, if any.
+		///
+		/// Emits [`Event::PolicySet`].
+		#[pallet::call_index(0)]
+		pub fn set_policy(
+			origin: OriginFor<T>,
+			calls: BoundedVec<CallFilterOf<T>, T::MaxSponsoredCalls>,
+			signer_cap: BalanceOf<T>,
+			reimbursement: Option<ReimbursementOf<T>>,
+		) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+
+			Policies::<T>::insert(&sponsor, SponsorPolicy { calls, signer_cap, reimbursement });
+
+			Self::deposit_event(Event::PolicySet { sponsor });
+			Ok(())
+		}
+
+		/// Place `amount` into the caller's sponsor pool.
+		///
+		/// The caller must have set a policy.
+		///
+		/// Emits [`Event::Funded`].
+		#[pallet::call_index(1)]
+		pub fn fund(
+			origin: OriginFor<T>,
+			#[pallet::compact] amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+			ensure!(Policies::<T>::contains_key(&sponsor), Error::<T>::NotSponsor);
+
+			<T::Currency as fungible::MutateHold<_>>::hold(
+				&HoldReason::SponsorPool.into(),
+				&sponsor,
+				amount,
+			)?;
+
+			Self::deposit_event(Event::Funded { sponsor, amount });
+			Ok(())
+		}
+
+		/// Release `amount` from the caller's sponsor pool.
+		///
+		/// Emits [`Event::Withdrawn`].
+		#[pallet::call_index(2)]
+		pub fn withdraw(
+			origin: OriginFor<T>,
+			#[pallet::compact] amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+
+			<T::Currency as fungible::MutateHold<_>>::release(
+				&HoldReason::SponsorPool.into(),
+				&sponsor,
+				amount,
+				Precision::Exact,
+			)?;
+
+			Self::deposit_event(Event::Withdrawn { sponsor, amount });
+			Ok(())
+		}
+
+		/// Remove the caller's policy and release its whole pool.
+		///
+		/// Emits [`Event::SponsorRemoved`].
+		#[pallet::call_index(3)]
+		pub fn remove_sponsor(origin: OriginFor<T>) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+			Policies::<T>::take(&sponsor).ok_or(Error::<T>::NotSponsor)?;
+
+			let released = <T::Currency as fungible::MutateHold<_>>::release_all(
+				&HoldReason::SponsorPool.into(),
+				&sponsor,
+				Precision::BestEffort,
+			)?;
+
+			Self::deposit_event(Event::SponsorRemoved { sponsor, released });
+			Ok(())
+		}
+
+		/// Agree to pay the reimbursement asked by `sponsor`, up to `max_reimbursement` per
+		/// sponsored transaction.
+		///
+		/// Emits [`Event::SponsorApproved`].
+		#[pallet::call_index(4)]
+		pub fn approve_sponsor(
+			origin: OriginFor<T>,
+			sponsor: T::AccountId,
+			max_reimbursement: AssetBalanceOf<T>,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+
+			Approvals::<T>::insert(&signer, &sponsor, max_reimbursement);
+
+			Self::deposit_event(Event::SponsorApproved { signer, sponsor, max_reimbursement });
+			Ok(())
+		}
+
+		/// Revoke the caller's approval of `sponsor`.
+		///
+		/// Emits [`Event::ApprovalRevoked`].
+		#[pallet::call_index(5)]
+		pub fn revoke_sponsor(origin: OriginFor<T>, sponsor: T::AccountId) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			Approvals::<T>::take(&signer, &sponsor).ok_or(Error::<T>::NotApproved)?;
+
+			Self::deposit_event(Event::ApprovalRevoked { signer, sponsor });
+			Ok(())
+		}
+
+		/// Dispatch a meta transaction on behalf of its signer, paying the caller out of the
+		/// pool of `sponsor`.
+		///
+		/// The call of the meta transaction must be covered by the sponsor's policy, and the
+		/// meta transaction must authorize a signed origin other than the sponsor. The signer is
+		/// charged the reimbursement of the policy, if any, before the call is dispatched.
+		///
+		/// Parameters:
+		/// - `origin`: the relayer, paid the fee of this transaction;
+		/// - `sponsor`: the sponsor paying for the transaction;
+		/// - `meta_tx`: the meta transaction to dispatch.
+		///
+		/// Emits [`Event::SponsoredDispatched`].
+		#[pallet::call_index(6)]
+		#[pallet::weight({
+			let dispatch_info = meta_tx.call().get_dispatch_info();
+			let extension_weight = meta_tx.extension().weight(meta_tx.call());
+			(
+				Pallet::<T>::max_weight(dispatch_info.call_weight.saturating_add(extension_weight)),
+				dispatch_info.class,
+			)
+		})]
+		pub fn dispatch_sponsored(
+			origin: OriginFor<T>,
+			sponsor: T::AccountId,
+			meta_tx: Box<MetaTxFor<T>>,
+		) -> DispatchResultWithPostInfo {
+			let relayer = ensure_signed(origin)?;
+			let policy = Policies::<T>::get(&sponsor).ok_or(Error::<T>::NotSponsor)?;
+			ensure!(
+				policy.allows(&meta_tx.call().get_call_metadata()),
+				Error::<T>::CallNotSponsored
+			);
+
+			// The relayer pays for its whole transaction, which wraps the meta transaction.
+			let len = Self::current_extrinsic_len().max(meta_tx.encoded_size() as u32);
+			let dispatch_info = meta_tx.call().get_dispatch_info();
+			let info = DispatchInfo {
+				call_weight: Self::max_weight(
+					dispatch_info
+						.call_weight
+						.saturating_add(meta_tx.extension().weight(meta_tx.call())),
+				),
+				class: dispatch_info.class,
+				..Default::default()
+			};
+			let max_fee =
+				pallet_transaction_payment::Pallet::<T>::compute_fee(len, &info, Zero::zero());
+			ensure!(Self::pool(&sponsor) >= max_fee, Error::<T>::InsufficientPool);
+
+			let mut signer = None;
+			let (result, meta_weight) =
+				pallet_meta_tx::Pallet::<T>::dispatch_meta_tx(*meta_tx, |origin| {
+					let who = origin.as_signer().ok_or(Error::<T>::NotSigned)?;
+					// Otherwise the call could spend the pool before the relayer is paid.
+					ensure!(who != &sponsor, Error::<T>::SignerIsSponsor);
+					Self::charge_signer(&sponsor, who, &policy, max_fee)?;
+					signer = Some(who.clone());
+					Ok(())
+				})?;
+			let signer = signer.ok_or(Error::<T>::NotSigned)?;
+
+			let actual_weight =
+				<T as Config>::WeightInfo::dispatch_sponsored().saturating_add(meta_weight);
+			let fee = pallet_transaction_payment::Pallet::<T>::compute_actual_fee(
+				len,
+				&info,
+				&PostDispatchInfo { actual_weight: Some(actual_weight), ..Default::default() },
+				Zero::zero(),
+			)
+			.min(max_fee);
+			<T::Currency as fungible::MutateHold<_>>::transfer_on_hold(
+				&HoldReason::SponsorPool.into(),
+				&sponsor,
+				&relayer,
+				fee,
+				Precision::Exact,
+				Restriction::Free,
+				Fortitude::Polite,
+			)?;
+			let mut usage = Self::current_usage(&sponsor, &signer);
+			usage.spent.saturating_accrue(fee);
+			SignerUsage::<T>::insert(&sponsor, &signer, usage);
+
+			Self::deposit_event(Event::SponsoredDispatched {
+				sponsor,
+				signer,
+				relayer,
+				fee,
+				result,
+			});
+
+			Ok(Some(actual_weight).into())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The funds in the pool of `sponsor`.
+		pub fn pool(sponsor: &T::AccountId) -> BalanceOf<T> {
+			<T::Currency as fungible::InspectHold<_>>::balance_on_hold(
+				&HoldReason::SponsorPool.into(),
+				sponsor,
+			)
+		}
+
+		/// The length of the extrinsic being applied, or zero outside of block execution.
+		fn current_extrinsic_len() -> u32 {
+			let index = frame_system::Pallet::<T>::extrinsic_index().unwrap_or_default();
+			frame_system::Pallet::<T>::extrinsic_data(index).len() as u32
+		}
+
+		/// The worst-case weight of [`Pallet::dispatch_sponsored`] for a meta transaction of
+		/// `meta_weight`.
+		fn max_weight(meta_weight: Weight) -> Weight {
+			<T as Config>::WeightInfo::dispatch_sponsored().saturating_add(meta_weight)
+		}
+
+		/// The [`Config::CapPeriod`] the current block falls into.
+		fn current_period() -> BlockNumberFor<T> {
+			frame_system::Pallet::<T>::block_number()
+				.checked_div(&T::CapPeriod::get())
+				.unwrap_or_else(Zero::zero)
+		}
+
+		/// The fees paid by `sponsor` on behalf of `signer` in the current period.
+		fn current_usage(sponsor: &T::AccountId, signer: &T::AccountId) -> UsageOf<T> {
+			let period = Self::current_period();
+			let usage = SignerUsage::<T>::get(sponsor, signer);
+			if usage.period == period {
+				usage
+			} else {
+				Usage { period, spent: Zero::zero() }
+			}
+		}
+
+		/// Check the signer's cap for a transaction of up to `max_fee` and take the reimbursement
+		/// of `policy` from the signer.
+		fn charge_signer(
+			sponsor: &T::AccountId,
+			signer: &T::AccountId,
+			policy: &SponsorPolicyOf<T>,
+			max_fee: BalanceOf<T>,
+		) -> DispatchResult {
+			ensure!(
+				Self::current_usage(sponsor, signer).spent.saturating_add(max_fee) <=
+					policy.signer_cap,
+				Error::<T>::SignerCapExceeded
+			);
+
+			if let Some(Reimbursement { asset, amount }) = &policy.reimbursement {
+				let approved = Approvals::<T>::get(signer, sponsor)
+					.ok_or(Error::<T>::ReimbursementNotApproved)?;
+				ensure!(*amount <= approved, Error::<T>::ReimbursementNotApproved);
+				<T::Assets as fungibles::Mutate<_>>::transfer(
+					asset.clone(),
+					signer,
+					sponsor,
+					*amount,
+					Preservation::Preserve,
+				)?;
+			}
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,180 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test environment for Meta Transaction Sponsor pallet.
+
+use super::*;
+use crate as pallet_meta_tx_sponsor;
+use frame_support::{
+	construct_runtime, derive_impl,
+	traits::{AsEnsureOriginWithArg, ConstU32, ConstU64, ConstU8},
+	weights::{ConstantMultiplier, FixedFee},
+};
+use frame_system::EnsureSigned;
+use sp_runtime::BuildStorage;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Assets: pallet_assets,
+		MetaTx: pallet_meta_tx,
+		TransactionPayment: pallet_transaction_payment,
+		MetaTxSponsor: pallet_meta_tx_sponsor,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+}
+
+#[derive_impl(pallet_assets::config_preludes::TestDefaultConfig as pallet_assets::DefaultConfig)]
+impl pallet_assets::Config for Test {
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<u64>>;
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type Currency = Balances;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub type MetaTxExtension = pallet_meta_tx::WeightlessExtension<Test>;
+
+#[cfg(not(feature = "runtime-benchmarks"))]
+pub use tx_ext::*;
+
+#[cfg(not(feature = "runtime-benchmarks"))]
+mod tx_ext {
+	use super::*;
+	use frame_support::pallet_prelude::{TransactionSource, ValidTransaction};
+	use sp_runtime::{
+		impl_tx_ext_default,
+		traits::{DispatchInfoOf, ValidateResult},
+	};
+
+	/// Meta transaction extension authorizing the signed origin of the given account.
+	#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Eq, PartialEq, TypeInfo, Debug)]
+	pub struct SignedBy(pub u64);
+
+	impl TransactionExtension<RuntimeCall> for SignedBy {
+		const IDENTIFIER: &'static str = "SignedBy";
+		type Implicit = ();
+		type Val = ();
+		type Pre = ();
+
+		fn weight(&self, _call: &RuntimeCall) -> Weight {
+			Weight::zero()
+		}
+
+		fn validate(
+			&self,
+			mut origin: RuntimeOrigin,
+			_call: &RuntimeCall,
+			_info: &DispatchInfoOf<RuntimeCall>,
+			_len: usize,
+			_self_implicit: (),
+			_inherited_implication: &impl Encode,
+			_source: TransactionSource,
+		) -> ValidateResult<(), RuntimeCall> {
+			origin.set_caller_from_signed(self.0);
+			Ok((ValidTransaction::default(), (), origin))
+		}
+
+		impl_tx_ext_default!(RuntimeCall; prepare);
+	}
+
+	pub type MetaTxExtension = SignedBy;
+}
+
+impl pallet_meta_tx::Config for Test {
+	type WeightInfo = ();
+	type RuntimeEvent = RuntimeEvent;
+	type Extension = MetaTxExtension;
+}
+
+pub const TX_FEE: u32 = 10;
+
+impl pallet_transaction_payment::Config for Test {
+	type WeightInfo = ();
+	type RuntimeEvent = RuntimeEvent;
+	type OnChargeTransaction = pallet_transaction_payment::FungibleAdapter<Balances, ()>;
+	type OperationalFeeMultiplier = ConstU8<1>;
+	type WeightToFee = FixedFee<TX_FEE, u64>;
+	type LengthToFee = ConstantMultiplier<u64, ConstU64<1>>;
+	type FeeMultiplierUpdate = ();
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct MetaTxSponsorBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl benchmarking::BenchmarkHelper<u32, u64, u64> for MetaTxSponsorBenchmarkHelper {
+	fn create_asset(who: &u64, amount: u64) -> u32 {
+		use frame_support::traits::fungibles::{Create, Inspect, Mutate};
+		if !<Assets as Inspect<u64>>::asset_exists(1) {
+			<Assets as Create<u64>>::create(1, *who, true, 1).unwrap();
+		}
+		<Assets as Mutate<u64>>::mint_into(1, who, amount).unwrap();
+		1
+	}
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Assets = Assets;
+	type CapPeriod = ConstU64<10>;
+	type MaxSponsoredCalls = ConstU32<4>;
+	type MaxNameLen = ConstU32<32>;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = MetaTxSponsorBenchmarkHelper;
+}
+
+pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+	pallet_assets::GenesisConfig::<Test> {
+		// Genesis assets: id, owner, is_sufficient, min_balance
+		assets: vec![(1, 1, true, 1)],
+		metadata: vec![],
+		// Genesis accounts: id, account_id, balance
+		accounts: vec![(1, 2, 100)],
+		next_asset_id: None,
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(1, 1000), (2, 100), (3, 100)],
+		..Default::default()
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
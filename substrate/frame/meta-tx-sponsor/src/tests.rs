@@ -0,0 +1,377 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{mock::*, *};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{fungible, fungibles},
+};
+use pallet_transaction_payment::{Multiplier, NextFeeMultiplier};
+use sp_runtime::{traits::Hash, FixedPointNumber, TokenError};
+
+const SPONSOR: u64 = 1;
+const SIGNER: u64 = 2;
+const RELAYER: u64 = 3;
+const ASSET: u32 = 1;
+
+fn filter(pallet: &str, call: Option<&str>) -> CallFilterOf<Test> {
+	CallFilter {
+		pallet: pallet.as_bytes().to_vec().try_into().unwrap(),
+		call: call.map(|call| call.as_bytes().to_vec().try_into().unwrap()),
+	}
+}
+
+fn set_policy(
+	calls: Vec<CallFilterOf<Test>>,
+	signer_cap: u64,
+	reimbursement: Option<ReimbursementOf<Test>>,
+) {
+	assert_ok!(MetaTxSponsor::set_policy(
+		RuntimeOrigin::signed(SPONSOR),
+		calls.try_into().unwrap(),
+		signer_cap,
+		reimbursement
+	));
+}
+
+/// Sets the policy of [`SPONSOR`] and places 500 into its pool.
+fn create_sponsor(
+	calls: Vec<CallFilterOf<Test>>,
+	signer_cap: u64,
+	reimbursement: Option<ReimbursementOf<Test>>,
+) {
+	set_policy(calls, signer_cap, reimbursement);
+	assert_ok!(MetaTxSponsor::fund(RuntimeOrigin::signed(SPONSOR), 500));
+}
+
+fn remark() -> RuntimeCall {
+	frame_system::Call::remark_with_event { remark: vec![1] }.into()
+}
+
+fn meta_tx(call: RuntimeCall) -> Box<MetaTxFor<Test>> {
+	Box::new(MetaTxFor::<Test>::new(call, 0, SignedBy(SIGNER)))
+}
+
+/// The fee paid to the relayer for `meta_tx` outside of block execution, made of the base fee,
+/// the weight fee adjusted by the fee multiplier and the length fee.
+fn fee_of(meta_tx: &MetaTxFor<Test>) -> u64 {
+	let weight_fee = NextFeeMultiplier::<Test>::get().saturating_mul_int(TX_FEE as u64);
+	TX_FEE as u64 + weight_fee + meta_tx.encoded_size() as u64
+}
+
+fn free(who: u64) -> u64 {
+	<Balances as fungible::Inspect<_>>::balance(&who)
+}
+
+fn asset_balance(who: u64) -> u64 {
+	<Assets as fungibles::Inspect<_>>::balance(ASSET, &who)
+}
+
+#[test]
+fn sponsor_pool_works() {
+	new_test_ext().execute_with(|| {
+		// a policy is required to fund a pool.
+		assert_noop!(
+			MetaTxSponsor::fund(RuntimeOrigin::signed(SPONSOR), 500),
+			Error::<Test>::NotSponsor
+		);
+
+		create_sponsor(vec![filter("System", None)], 100, None);
+		System::assert_has_event(Event::PolicySet { sponsor: SPONSOR }.into());
+		System::assert_last_event(Event::Funded { sponsor: SPONSOR, amount: 500 }.into());
+		assert_eq!(MetaTxSponsor::pool(&SPONSOR), 500);
+		assert_eq!(free(SPONSOR), 500);
+
+		assert_ok!(MetaTxSponsor::withdraw(RuntimeOrigin::signed(SPONSOR), 100));
+		System::assert_last_event(Event::Withdrawn { sponsor: SPONSOR, amount: 100 }.into());
+		assert_eq!(MetaTxSponsor::pool(&SPONSOR), 400);
+		assert_noop!(
+			MetaTxSponsor::withdraw(RuntimeOrigin::signed(SPONSOR), 401),
+			TokenError::FundsUnavailable
+		);
+
+		// updating the policy keeps the pool.
+		set_policy(vec![filter("Balances", None)], 50, None);
+		assert_eq!(Policies::<Test>::get(SPONSOR).unwrap().signer_cap, 50);
+		assert_eq!(MetaTxSponsor::pool(&SPONSOR), 400);
+
+		assert_ok!(MetaTxSponsor::remove_sponsor(RuntimeOrigin::signed(SPONSOR)));
+		System::assert_last_event(Event::SponsorRemoved { sponsor: SPONSOR, released: 400 }.into());
+		assert!(Policies::<Test>::get(SPONSOR).is_none());
+		assert_eq!(MetaTxSponsor::pool(&SPONSOR), 0);
+		assert_eq!(free(SPONSOR), 1000);
+		assert_noop!(
+			MetaTxSponsor::remove_sponsor(RuntimeOrigin::signed(SPONSOR)),
+			Error::<Test>::NotSponsor
+		);
+	});
+}
+
+#[test]
+fn dispatch_sponsored_works() {
+	new_test_ext().execute_with(|| {
+		create_sponsor(vec![filter("System", Some("remark_with_event"))], 100, None);
+
+		let meta_tx = meta_tx(remark());
+		let fee = fee_of(&meta_tx);
+		let call_weight = remark().get_dispatch_info().call_weight;
+		assert_ok!(MetaTxSponsor::dispatch_sponsored(
+			RuntimeOrigin::signed(RELAYER),
+			SPONSOR,
+			meta_tx
+		));
+
+		// the call was dispatched on behalf of the signer.
+		System::assert_has_event(
+			frame_system::Event::Remarked {
+				sender: SIGNER,
+				hash: <Test as frame_system::Config>::Hashing::hash(&[1]),
+			}
+			.into(),
+		);
+		System::assert_last_event(
+			Event::SponsoredDispatched {
+				sponsor: SPONSOR,
+				signer: SIGNER,
+				relayer: RELAYER,
+				fee,
+				result: Ok(Some(call_weight).into()),
+			}
+			.into(),
+		);
+
+		// the relayer was paid out of the sponsor pool.
+		assert_eq!(free(RELAYER), 100 + fee);
+		assert_eq!(MetaTxSponsor::pool(&SPONSOR), 500 - fee);
+		assert_eq!(free(SIGNER), 100);
+		assert_eq!(SignerUsage::<Test>::get(SPONSOR, SIGNER), Usage { period: 0, spent: fee });
+	});
+}
+
+#[test]
+fn dispatch_sponsored_applies_fee_multiplier() {
+	new_test_ext().execute_with(|| {
+		create_sponsor(vec![filter("System", None)], 100, None);
+		NextFeeMultiplier::<Test>::put(Multiplier::saturating_from_rational(1, 2));
+
+		let meta_tx = meta_tx(remark());
+		let fee = fee_of(&meta_tx);
+		assert_eq!(fee, TX_FEE as u64 + TX_FEE as u64 / 2 + meta_tx.encoded_size() as u64);
+		assert_ok!(MetaTxSponsor::dispatch_sponsored(
+			RuntimeOrigin::signed(RELAYER),
+			SPONSOR,
+			meta_tx
+		));
+
+		// the relayer is paid what it was charged for its transaction.
+		assert_eq!(free(RELAYER), 100 + fee);
+		assert_eq!(MetaTxSponsor::pool(&SPONSOR), 500 - fee);
+		assert_eq!(SignerUsage::<Test>::get(SPONSOR, SIGNER), Usage { period: 0, spent: fee });
+	});
+}
+
+#[test]
+fn dispatch_sponsored_pays_for_relayer_transaction_length() {
+	new_test_ext().execute_with(|| {
+		create_sponsor(vec![filter("System", None)], 100, None);
+		// the relayer's transaction being applied, which wraps the meta transaction.
+		System::note_extrinsic(vec![0; 200]);
+
+		assert_ok!(MetaTxSponsor::dispatch_sponsored(
+			RuntimeOrigin::signed(RELAYER),
+			SPONSOR,
+			meta_tx(remark())
+		));
+
+		let fee = 2 * TX_FEE as u64 + 200;
+		assert_eq!(free(RELAYER), 100 + fee);
+		assert_eq!(MetaTxSponsor::pool(&SPONSOR), 500 - fee);
+	});
+}
+
+#[test]
+fn sponsor_cannot_sign_sponsored_meta_tx() {
+	new_test_ext().execute_with(|| {
+		create_sponsor(vec![filter("MetaTxSponsor", None)], 1000, None);
+
+		// the call could otherwise empty the pool before the relayer is paid.
+		let withdraw: RuntimeCall = Call::<Test>::withdraw { amount: 500 }.into();
+		assert_noop!(
+			MetaTxSponsor::dispatch_sponsored(
+				RuntimeOrigin::signed(RELAYER),
+				SPONSOR,
+				Box::new(MetaTxFor::<Test>::new(withdraw, 0, SignedBy(SPONSOR)))
+			),
+			Error::<Test>::SignerIsSponsor
+		);
+		assert_eq!(MetaTxSponsor::pool(&SPONSOR), 500);
+	});
+}
+
+#[test]
+fn dispatch_sponsored_handles_errors() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			MetaTxSponsor::dispatch_sponsored(
+				RuntimeOrigin::signed(RELAYER),
+				SPONSOR,
+				meta_tx(remark())
+			),
+			Error::<Test>::NotSponsor
+		);
+
+		create_sponsor(vec![filter("System", Some("remark"))], 100, None);
+		assert_noop!(
+			MetaTxSponsor::dispatch_sponsored(
+				RuntimeOrigin::signed(RELAYER),
+				SPONSOR,
+				meta_tx(remark())
+			),
+			Error::<Test>::CallNotSponsored
+		);
+
+		// a filter without call name covers the whole pallet.
+		set_policy(vec![filter("Balances", None), filter("System", None)], 100, None);
+		let meta_tx = meta_tx(remark());
+		let fee = fee_of(&meta_tx);
+
+		// the pool must cover the fee.
+		assert_ok!(MetaTxSponsor::withdraw(
+			RuntimeOrigin::signed(SPONSOR),
+			MetaTxSponsor::pool(&SPONSOR) - fee + 1
+		));
+		assert_noop!(
+			MetaTxSponsor::dispatch_sponsored(
+				RuntimeOrigin::signed(RELAYER),
+				SPONSOR,
+				meta_tx.clone()
+			),
+			Error::<Test>::InsufficientPool
+		);
+
+		assert_ok!(MetaTxSponsor::fund(RuntimeOrigin::signed(SPONSOR), 1));
+		assert_ok!(MetaTxSponsor::dispatch_sponsored(
+			RuntimeOrigin::signed(RELAYER),
+			SPONSOR,
+			meta_tx
+		));
+		assert_eq!(MetaTxSponsor::pool(&SPONSOR), 0);
+	});
+}
+
+#[test]
+fn signer_cap_works() {
+	new_test_ext().execute_with(|| {
+		let fee = fee_of(&meta_tx(remark()));
+		create_sponsor(vec![filter("System", None)], 2 * fee, None);
+
+		for _ in 0..2 {
+			assert_ok!(MetaTxSponsor::dispatch_sponsored(
+				RuntimeOrigin::signed(RELAYER),
+				SPONSOR,
+				meta_tx(remark())
+			));
+		}
+		assert_noop!(
+			MetaTxSponsor::dispatch_sponsored(
+				RuntimeOrigin::signed(RELAYER),
+				SPONSOR,
+				meta_tx(remark())
+			),
+			Error::<Test>::SignerCapExceeded
+		);
+
+		// the cap does not apply to other signers.
+		assert_ok!(MetaTxSponsor::dispatch_sponsored(
+			RuntimeOrigin::signed(RELAYER),
+			SPONSOR,
+			Box::new(MetaTxFor::<Test>::new(remark(), 0, SignedBy(4)))
+		));
+
+		// the cap resets with the next period.
+		System::set_block_number(10);
+		assert_ok!(MetaTxSponsor::dispatch_sponsored(
+			RuntimeOrigin::signed(RELAYER),
+			SPONSOR,
+			meta_tx(remark())
+		));
+		assert_eq!(SignerUsage::<Test>::get(SPONSOR, SIGNER), Usage { period: 1, spent: fee });
+	});
+}
+
+#[test]
+fn reimbursement_works() {
+	new_test_ext().execute_with(|| {
+		create_sponsor(
+			vec![filter("System", None)],
+			100,
+			Some(Reimbursement { asset: ASSET, amount: 5 }),
+		);
+
+		// the signer must approve the sponsor.
+		assert_noop!(
+			MetaTxSponsor::dispatch_sponsored(
+				RuntimeOrigin::signed(RELAYER),
+				SPONSOR,
+				meta_tx(remark())
+			),
+			Error::<Test>::ReimbursementNotApproved
+		);
+		assert_noop!(
+			MetaTxSponsor::revoke_sponsor(RuntimeOrigin::signed(SIGNER), SPONSOR),
+			Error::<Test>::NotApproved
+		);
+
+		// for at least the reimbursement asked.
+		assert_ok!(MetaTxSponsor::approve_sponsor(RuntimeOrigin::signed(SIGNER), SPONSOR, 4));
+		System::assert_last_event(
+			Event::SponsorApproved { signer: SIGNER, sponsor: SPONSOR, max_reimbursement: 4 }
+				.into(),
+		);
+		assert_noop!(
+			MetaTxSponsor::dispatch_sponsored(
+				RuntimeOrigin::signed(RELAYER),
+				SPONSOR,
+				meta_tx(remark())
+			),
+			Error::<Test>::ReimbursementNotApproved
+		);
+
+		assert_ok!(MetaTxSponsor::approve_sponsor(RuntimeOrigin::signed(SIGNER), SPONSOR, 5));
+		assert_ok!(MetaTxSponsor::dispatch_sponsored(
+			RuntimeOrigin::signed(RELAYER),
+			SPONSOR,
+			meta_tx(remark())
+		));
+		assert_eq!(asset_balance(SIGNER), 95);
+		assert_eq!(asset_balance(SPONSOR), 5);
+
+		// the signer cannot be charged once the approval is revoked.
+		assert_ok!(MetaTxSponsor::revoke_sponsor(RuntimeOrigin::signed(SIGNER), SPONSOR));
+		System::assert_last_event(
+			Event::ApprovalRevoked { signer: SIGNER, sponsor: SPONSOR }.into(),
+		);
+		assert_noop!(
+			MetaTxSponsor::dispatch_sponsored(
+				RuntimeOrigin::signed(RELAYER),
+				SPONSOR,
+				meta_tx(remark())
+			),
+			Error::<Test>::ReimbursementNotApproved
+		);
+	});
+}
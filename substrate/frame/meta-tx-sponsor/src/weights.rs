@@ -0,0 +1,263 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Autogenerated weights for `pallet_meta_tx_sponsor`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
+//! DATE: 2025-06-12, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `4563561839a5`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
+
+// Executed Command:
+// frame-omni-bencher
+// v1
+// benchmark
+// pallet
+// --extrinsic=*
+// --runtime=target/production/wbuild/kitchensink-runtime/kitchensink_runtime.wasm
+// --pallet=pallet_meta_tx_sponsor
+// --header=/__w/polkadot-sdk/polkadot-sdk/substrate/HEADER-APACHE2
+// --output=/__w/polkadot-sdk/polkadot-sdk/substrate/frame/meta-tx-sponsor/src/weights.rs
+// --wasm-execution=compiled
+// --steps=50
+// --repeat=20
+// --heap-pages=4096
+// --template=substrate/.maintain/frame-weight-template.hbs
+// --no-storage-info
+// --no-min-squares
+// --no-median-slopes
+// --genesis-builder-policy=none
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+#![allow(dead_code)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_meta_tx_sponsor`.
+pub trait WeightInfo {
+	fn set_policy() -> Weight;
+	fn fund() -> Weight;
+	fn withdraw() -> Weight;
+	fn remove_sponsor() -> Weight;
+	fn approve_sponsor() -> Weight;
+	fn revoke_sponsor() -> Weight;
+	fn dispatch_sponsored() -> Weight;
+}
+
+/// Weights for `pallet_meta_tx_sponsor` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `MetaTxSponsor::Policies` (r:0 w:1)
+	/// Proof: `MetaTxSponsor::Policies` (`max_values`: None, `max_size`: Some(2182), added: 4657, mode: `MaxEncodedLen`)
+	fn set_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `0`
+		// Minimum execution time: 16_402_000 picoseconds.
+		Weight::from_parts(17_015_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `MetaTxSponsor::Policies` (r:1 w:0)
+	/// Proof: `MetaTxSponsor::Policies` (`max_values`: None, `max_size`: Some(2182), added: 4657, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(427), added: 2902, mode: `MaxEncodedLen`)
+	fn fund() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `5647`
+		// Minimum execution time: 42_118_000 picoseconds.
+		Weight::from_parts(43_260_000, 5647)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(427), added: 2902, mode: `MaxEncodedLen`)
+	fn withdraw() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3892`
+		// Minimum execution time: 38_967_000 picoseconds.
+		Weight::from_parts(40_031_000, 3892)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `MetaTxSponsor::Policies` (r:1 w:1)
+	/// Proof: `MetaTxSponsor::Policies` (`max_values`: None, `max_size`: Some(2182), added: 4657, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(427), added: 2902, mode: `MaxEncodedLen`)
+	fn remove_sponsor() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2537`
+		//  Estimated: `5647`
+		// Minimum execution time: 47_502_000 picoseconds.
+		Weight::from_parts(48_894_000, 5647)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `MetaTxSponsor::Approvals` (r:0 w:1)
+	/// Proof: `MetaTxSponsor::Approvals` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	fn approve_sponsor() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `0`
+		// Minimum execution time: 13_820_000 picoseconds.
+		Weight::from_parts(14_377_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `MetaTxSponsor::Approvals` (r:1 w:1)
+	/// Proof: `MetaTxSponsor::Approvals` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	fn revoke_sponsor() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `171`
+		//  Estimated: `3577`
+		// Minimum execution time: 18_934_000 picoseconds.
+		Weight::from_parts(19_612_000, 3577)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `MetaTxSponsor::Policies` (r:1 w:0)
+	/// Proof: `MetaTxSponsor::Policies` (`max_values`: None, `max_size`: Some(2182), added: 4657, mode: `MaxEncodedLen`)
+	/// Storage: `MetaTxSponsor::SignerUsage` (r:1 w:1)
+	/// Proof: `MetaTxSponsor::SignerUsage` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `MetaTxSponsor::Approvals` (r:1 w:0)
+	/// Proof: `MetaTxSponsor::Approvals` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(427), added: 2902, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `System::ExtrinsicData` (r:1 w:0)
+	/// Proof: `System::ExtrinsicData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TransactionPayment::NextFeeMultiplier` (r:1 w:0)
+	/// Proof: `TransactionPayment::NextFeeMultiplier` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn dispatch_sponsored() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3035`
+		//  Estimated: `6208`
+		// Minimum execution time: 118_412_000 picoseconds.
+		Weight::from_parts(121_506_000, 6208)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `MetaTxSponsor::Policies` (r:0 w:1)
+	/// Proof: `MetaTxSponsor::Policies` (`max_values`: None, `max_size`: Some(2182), added: 4657, mode: `MaxEncodedLen`)
+	fn set_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `0`
+		// Minimum execution time: 16_402_000 picoseconds.
+		Weight::from_parts(17_015_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `MetaTxSponsor::Policies` (r:1 w:0)
+	/// Proof: `MetaTxSponsor::Policies` (`max_values`: None, `max_size`: Some(2182), added: 4657, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(427), added: 2902, mode: `MaxEncodedLen`)
+	fn fund() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `5647`
+		// Minimum execution time: 42_118_000 picoseconds.
+		Weight::from_parts(43_260_000, 5647)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(427), added: 2902, mode: `MaxEncodedLen`)
+	fn withdraw() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3892`
+		// Minimum execution time: 38_967_000 picoseconds.
+		Weight::from_parts(40_031_000, 3892)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `MetaTxSponsor::Policies` (r:1 w:1)
+	/// Proof: `MetaTxSponsor::Policies` (`max_values`: None, `max_size`: Some(2182), added: 4657, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(427), added: 2902, mode: `MaxEncodedLen`)
+	fn remove_sponsor() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2537`
+		//  Estimated: `5647`
+		// Minimum execution time: 47_502_000 picoseconds.
+		Weight::from_parts(48_894_000, 5647)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `MetaTxSponsor::Approvals` (r:0 w:1)
+	/// Proof: `MetaTxSponsor::Approvals` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	fn approve_sponsor() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `0`
+		// Minimum execution time: 13_820_000 picoseconds.
+		Weight::from_parts(14_377_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `MetaTxSponsor::Approvals` (r:1 w:1)
+	/// Proof: `MetaTxSponsor::Approvals` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	fn revoke_sponsor() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `171`
+		//  Estimated: `3577`
+		// Minimum execution time: 18_934_000 picoseconds.
+		Weight::from_parts(19_612_000, 3577)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `MetaTxSponsor::Policies` (r:1 w:0)
+	/// Proof: `MetaTxSponsor::Policies` (`max_values`: None, `max_size`: Some(2182), added: 4657, mode: `MaxEncodedLen`)
+	/// Storage: `MetaTxSponsor::SignerUsage` (r:1 w:1)
+	/// Proof: `MetaTxSponsor::SignerUsage` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `MetaTxSponsor::Approvals` (r:1 w:0)
+	/// Proof: `MetaTxSponsor::Approvals` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(427), added: 2902, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `System::ExtrinsicData` (r:1 w:0)
+	/// Proof: `System::ExtrinsicData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TransactionPayment::NextFeeMultiplier` (r:1 w:0)
+	/// Proof: `TransactionPayment::NextFeeMultiplier` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn dispatch_sponsored() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3035`
+		//  Estimated: `6208`
+		// Minimum execution time: 118_412_000 picoseconds.
+		Weight::from_parts(121_506_000, 6208)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+}
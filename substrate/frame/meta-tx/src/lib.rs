@@ -74,7 +74,8 @@ use frame_system::{pallet_prelude::*, RawOrigin as SystemOrigin};
 use sp_runtime::{
 	generic::ExtensionVersion,
 	traits::{
-		AsTransactionAuthorizedOrigin, DispatchTransaction, Dispatchable, TransactionExtension,
+		AsTransactionAuthorizedOrigin, DispatchTransaction, Dispatchable,
+		ExtensionPostDispatchWeightHandler, TransactionExtension,
 	},
 };
 use sp_std::prelude::*;
@@ -97,6 +98,16 @@ impl<Call, Extension> MetaTx<Call, Extension> {
 	pub fn new(call: Call, extension_version: ExtensionVersion, extension: Extension) -> Self {
 		Self { call, extension_version, extension }
 	}
+
+	/// The target call to be executed on behalf of the signer.
+	pub fn call(&self) -> &Call {
+		&self.call
+	}
+
+	/// The extension/s for the meta transaction.
+	pub fn extension(&self) -> &Extension {
+		&self.extension
+	}
 }
 
 /// The [`MetaTx`] for the given config.
@@ -189,6 +200,29 @@ pub mod pallet {
 			_origin: OriginFor<T>,
 			meta_tx: Box<MetaTxFor<T>>,
 		) -> DispatchResultWithPostInfo {
+			let (meta_dispatch_res, meta_weight) = Self::dispatch_meta_tx(*meta_tx, |_| Ok(()))?;
+
+			Self::deposit_event(Event::Dispatched { result: meta_dispatch_res });
+
+			Ok((Some(T::WeightInfo::bare_dispatch().saturating_add(meta_weight)), true.into())
+				.into())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Validate and dispatch the given meta transaction.
+		///
+		/// `before_dispatch` is called with the origin authorized by the meta transaction
+		/// extensions right before the target call is dispatched; returning an error aborts the
+		/// dispatch. This allows other pallets to build on top of meta transactions, e.g. to
+		/// charge or restrict the signer.
+		///
+		/// Returns the dispatch result of the target call and the weight consumed by the call and
+		/// the extensions after possible refunds.
+		pub fn dispatch_meta_tx(
+			meta_tx: MetaTxFor<T>,
+			before_dispatch: impl FnOnce(&<T as frame_system::Config>::RuntimeOrigin) -> DispatchResult,
+		) -> Result<(DispatchResultWithPostInfo, Weight), DispatchError> {
 			let origin = SystemOrigin::None;
 			let meta_tx_size = meta_tx.encoded_size();
 			// `info` with worst-case call weight and extension weight.
@@ -198,27 +232,36 @@ pub mod pallet {
 				info
 			};
 
-			// dispatch the meta transaction.
-			let meta_dispatch_res = meta_tx
+			let (pre, origin) = meta_tx
 				.extension
-				.dispatch_transaction(
+				.validate_and_prepare(
 					origin.into(),
-					meta_tx.call,
+					&meta_tx.call,
 					&info,
 					meta_tx_size,
 					meta_tx.extension_version,
 				)
 				.map_err(Error::<T>::from)?;
 
-			Self::deposit_event(Event::Dispatched { result: meta_dispatch_res });
+			before_dispatch(&origin)?;
+
+			// dispatch the meta transaction.
+			let mut meta_dispatch_res = meta_tx.call.dispatch(origin);
+			let post_dispatch_res = meta_dispatch_res.map(|_| ()).map_err(|e| e.error);
+			let post_info = match &mut meta_dispatch_res {
+				Ok(info) => info,
+				Err(err) => &mut err.post_info,
+			};
+			post_info.set_extension_weight(&info);
+			T::Extension::post_dispatch(pre, &info, post_info, meta_tx_size, &post_dispatch_res)
+				.map_err(Error::<T>::from)?;
 
 			// meta weight after possible refunds.
 			let meta_weight = meta_dispatch_res
 				.map_or_else(|err| err.post_info.actual_weight, |info| info.actual_weight)
 				.unwrap_or(info.total_weight());
 
-			Ok((Some(T::WeightInfo::bare_dispatch().saturating_add(meta_weight)), true.into())
-				.into())
+			Ok((meta_dispatch_res, meta_weight))
 		}
 	}
 
@@ -359,5 +359,93 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn clear_expired() -> Result<(), BenchmarkError> {
+		let expiry = T::OperationExpiry::get().ok_or(BenchmarkError::Weightless)?;
+		let s = T::MaxSignatories::get();
+		// The call is neither in storage or an argument, so just use any:
+		let call_len = 10_000;
+		let (mut signatories, call) = setup_multi::<T>(s, call_len)?;
+		let multi_account_id = Multisig::<T>::multi_account_id(&signatories, s.try_into().unwrap());
+		let caller = signatories.pop().ok_or("signatories should have len 2 or more")?;
+		let call_hash = call.using_encoded(blake2_256);
+		let timepoint = Multisig::<T>::timepoint();
+		// Create the multi
+		let o = RawOrigin::Signed(caller.clone()).into();
+		Multisig::<T>::as_multi(o, s as u16, signatories, None, call, Weight::zero())?;
+		T::BlockNumberProvider::set_block_number(timepoint.height.saturating_add(expiry));
+		let cleaner: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(cleaner), multi_account_id.clone(), call_hash);
+
+		assert!(!Multisigs::<T>::contains_key(multi_account_id, call_hash));
+		assert!(T::Currency::reserved_balance(&caller).is_zero());
+
+		Ok(())
+	}
+
+	/// `s`: Signatories, need at least 2 people
+	#[benchmark]
+	fn create_managed(s: Linear<2, { T::MaxSignatories::get() }>) -> Result<(), BenchmarkError> {
+		let (signatories, _) = setup_multi::<T>(s, 0)?;
+		let caller = signatories[0].clone();
+		let managed = Multisig::<T>::managed_account_id(&caller, Multisig::<T>::timepoint(), 0);
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
+		add_to_whitelist(caller_key.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), signatories, s as u16, 0);
+
+		assert!(ManagedMultisigs::<T>::contains_key(managed));
+
+		Ok(())
+	}
+
+	/// `s`: New signatories, need at least 2 people
+	#[benchmark]
+	fn rotate_managed(s: Linear<2, { T::MaxSignatories::get() }>) -> Result<(), BenchmarkError> {
+		let (signatories, _) = setup_multi::<T>(s, 0)?;
+		let caller = signatories[0].clone();
+		let managed = Multisig::<T>::managed_account_id(&caller, Multisig::<T>::timepoint(), 0);
+		Multisig::<T>::create_managed(
+			RawOrigin::Signed(caller).into(),
+			signatories[..2].to_vec(),
+			2,
+			0,
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(managed.clone()), signatories, s as u16);
+
+		let m = ManagedMultisigs::<T>::get(managed).ok_or("managed multisig not created")?;
+		assert_eq!(m.threshold, s as u16);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn destroy_managed() -> Result<(), BenchmarkError> {
+		let s = T::MaxSignatories::get();
+		let (signatories, _) = setup_multi::<T>(s, 0)?;
+		let caller = signatories[0].clone();
+		let managed = Multisig::<T>::managed_account_id(&caller, Multisig::<T>::timepoint(), 0);
+		Multisig::<T>::create_managed(
+			RawOrigin::Signed(caller.clone()).into(),
+			signatories,
+			s as u16,
+			0,
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(managed.clone()));
+
+		assert!(!ManagedMultisigs::<T>::contains_key(managed));
+		assert!(T::Currency::reserved_balance(&caller).is_zero());
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Multisig, crate::tests::new_test_ext(), crate::tests::Test);
 }
@@ -31,6 +31,16 @@
 //! operation. This is useful for multisig wallets where cryptographic threshold signatures are
 //! not available or desired.
 //!
+//! Open operations may be given a limited lifetime through [`Config::OperationExpiry`]. Once
+//! expired, an operation can no longer be approved and anyone may clear it, returning the deposit
+//! to its depositor. Operations opened before expiry was introduced count their lifetime from
+//! [`ExpiryStart`], which is set by [`migrations::v2::MigrateToV2`].
+//!
+//! Managed multisigs are composite accounts whose signatories and threshold are kept in storage
+//! rather than being part of the account derivation. The account is derived once on creation and
+//! its signatories and threshold may later be rotated by a call dispatched from the account
+//! itself, i.e. approved by the current signatories.
+//!
 //! ## Interface
 //!
 //! ### Dispatchable Functions
@@ -39,6 +49,13 @@
 //!   number of signed origins.
 //! * `approve_as_multi` - Approve a call from a composite origin.
 //! * `cancel_as_multi` - Cancel a call from a composite origin.
+//! * `clear_expired` - Remove an expired operation and return its deposit.
+//! * `create_managed` - Create a managed multisig account.
+//! * `as_managed` - Approve and if possible dispatch a call from a managed multisig account.
+//! * `approve_as_managed` - Approve a call from a managed multisig account.
+//! * `cancel_as_managed` - Cancel a call from a managed multisig account.
+//! * `rotate_managed` - Change the signatories and threshold of a managed multisig account.
+//! * `destroy_managed` - Remove a managed multisig account and return its deposit.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -120,6 +137,23 @@ where
 	pub approvals: BoundedVec<AccountId, MaxApprovals>,
 }
 
+/// A managed multisig account.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxSignatories))]
+pub struct ManagedMultisig<Balance, AccountId, MaxSignatories>
+where
+	MaxSignatories: Get<u32>,
+{
+	/// The accounts who can approve calls of the managed multisig. Always sorted.
+	pub signatories: BoundedVec<AccountId, MaxSignatories>,
+	/// The number of approvals needed to dispatch a call.
+	pub threshold: u16,
+	/// The amount held in reserve of the `depositor`.
+	pub deposit: Balance,
+	/// The account who created the managed multisig.
+	pub depositor: AccountId,
+}
+
 type CallHash = [u8; 32];
 
 enum CallOrHash<T: Config> {
@@ -165,6 +199,14 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxSignatories: Get<u32>;
 
+		/// The number of blocks after which an open multisig operation expires, counted from the
+		/// block it was opened in.
+		///
+		/// Expired operations can no longer be approved; anyone can clear them, which returns the
+		/// deposit to the depositor. `None` means operations never expire.
+		#[pallet::constant]
+		type OperationExpiry: Get<Option<BlockNumberFor<Self>>>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: weights::WeightInfo;
 
@@ -194,7 +236,7 @@ pub mod pallet {
 	}
 
 	/// The in-code storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -211,6 +253,22 @@ pub mod pallet {
 		Multisig<BlockNumberFor<T>, BalanceOf<T>, T::AccountId, T::MaxSignatories>,
 	>;
 
+	/// The block from which the lifetime of operations opened before it is counted.
+	///
+	/// Set when operation expiry is introduced, so that operations already underway do not
+	/// expire at once.
+	#[pallet::storage]
+	pub type ExpiryStart<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+	/// The managed multisig accounts.
+	#[pallet::storage]
+	pub type ManagedMultisigs<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		ManagedMultisig<BalanceOf<T>, T::AccountId, T::MaxSignatories>,
+	>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Threshold must be 2 or greater.
@@ -242,6 +300,20 @@ pub mod pallet {
 		MaxWeightTooLow,
 		/// The data to be stored is already stored.
 		AlreadyStored,
+		/// The multisig operation has expired.
+		Expired,
+		/// The multisig operation has not expired yet.
+		NotExpired,
+		/// The threshold is greater than the number of signatories.
+		ThresholdTooHigh,
+		/// The account is not a managed multisig.
+		NotManaged,
+		/// The managed multisig account already exists.
+		AlreadyManaged,
+		/// The sender is not a signatory of the managed multisig.
+		NotSignatory,
+		/// The managed multisig still has operations underway.
+		OperationsPending,
 	}
 
 	#[pallet::event]
@@ -278,6 +350,18 @@ pub mod pallet {
 			old_deposit: BalanceOf<T>,
 			new_deposit: BalanceOf<T>,
 		},
+		/// An expired multisig operation has been cleared.
+		MultisigExpired {
+			timepoint: Timepoint<BlockNumberFor<T>>,
+			multisig: T::AccountId,
+			call_hash: CallHash,
+		},
+		/// A managed multisig account has been created.
+		ManagedMultisigCreated { creator: T::AccountId, managed: T::AccountId, threshold: u16 },
+		/// The signatories and threshold of a managed multisig account have been rotated.
+		ManagedMultisigRotated { managed: T::AccountId, threshold: u16 },
+		/// A managed multisig account has been destroyed.
+		ManagedMultisigDestroyed { managed: T::AccountId },
 	}
 
 	#[pallet::hooks]
@@ -510,21 +594,7 @@ pub mod pallet {
 
 			let id = Self::multi_account_id(&signatories, threshold);
 
-			let m = <Multisigs<T>>::get(&id, call_hash).ok_or(Error::<T>::NotFound)?;
-			ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
-			ensure!(m.depositor == who, Error::<T>::NotOwner);
-
-			let err_amount = T::Currency::unreserve(&m.depositor, m.deposit);
-			debug_assert!(err_amount.is_zero());
-			<Multisigs<T>>::remove(&id, &call_hash);
-
-			Self::deposit_event(Event::MultisigCancelled {
-				cancelling: who,
-				timepoint,
-				multisig: id,
-				call_hash,
-			});
-			Ok(())
+			Self::cancel(who, id, timepoint, call_hash)
 		}
 
 		/// Poke the deposit reserved for an existing multisig operation.
@@ -604,6 +674,256 @@ pub mod pallet {
 				},
 			)
 		}
+
+		/// Clear an expired multisig operation, returning its deposit to the depositor.
+		///
+		/// The dispatch origin for this call must be _Signed_. It may be any account.
+		///
+		/// The transaction fee is waived if the operation was cleared.
+		///
+		/// - `multisig`: The composite account the operation was opened for.
+		/// - `call_hash`: The hash of the call of the operation.
+		///
+		/// Emits `MultisigExpired` if successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::clear_expired())]
+		pub fn clear_expired(
+			origin: OriginFor<T>,
+			multisig: T::AccountId,
+			call_hash: [u8; 32],
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let m = <Multisigs<T>>::get(&multisig, call_hash).ok_or(Error::<T>::NotFound)?;
+			ensure!(Self::is_expired(&m.when), Error::<T>::NotExpired);
+
+			let err_amount = T::Currency::unreserve(&m.depositor, m.deposit);
+			debug_assert!(err_amount.is_zero());
+			<Multisigs<T>>::remove(&multisig, &call_hash);
+
+			Self::deposit_event(Event::MultisigExpired { timepoint: m.when, multisig, call_hash });
+			Ok(Pays::No.into())
+		}
+
+		/// Create a managed multisig account.
+		///
+		/// Unlike the accounts used by `as_multi`, the account does not depend on its signatories
+		/// and threshold, which can be changed later through `rotate_managed`.
+		///
+		/// Payment: `DepositBase` plus `MaxSignatories` times `DepositFactor` will be reserved for
+		/// the lifetime of the managed multisig.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `signatories`: The accounts who can approve calls of the managed multisig. Must be
+		/// sorted and may include the sender.
+		/// - `threshold`: The total number of approvals needed to dispatch a call.
+		/// - `index`: A disambiguation index, in case this is called multiple times in the same
+		/// transaction. Use 0 if unsure.
+		///
+		/// Emits `ManagedMultisigCreated` with the new account if successful. The account can
+		/// also be computed with `managed_account_id`.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::create_managed(signatories.len() as u32))]
+		pub fn create_managed(
+			origin: OriginFor<T>,
+			signatories: Vec<T::AccountId>,
+			threshold: u16,
+			index: u16,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let signatories = Self::ensure_valid_managed(signatories, threshold)?;
+
+			let managed = Self::managed_account_id(&who, Self::timepoint(), index);
+			ensure!(!<ManagedMultisigs<T>>::contains_key(&managed), Error::<T>::AlreadyManaged);
+
+			let deposit = Self::managed_deposit();
+			T::Currency::reserve(&who, deposit)?;
+
+			<ManagedMultisigs<T>>::insert(
+				&managed,
+				ManagedMultisig { signatories, threshold, deposit, depositor: who.clone() },
+			);
+			Self::deposit_event(Event::ManagedMultisigCreated { creator: who, managed, threshold });
+			Ok(())
+		}
+
+		/// Register approval for a dispatch to be made from a managed multisig account.
+		///
+		/// If there are enough approvals, then dispatch the call.
+		///
+		/// Payment: `DepositBase` will be reserved if this is the first approval, plus
+		/// `threshold` times `DepositFactor`. It is returned once this dispatch happens or
+		/// is cancelled.
+		///
+		/// The dispatch origin for this call must be _Signed_ by one of the signatories of the
+		/// managed multisig.
+		///
+		/// - `managed`: The managed multisig account.
+		/// - `maybe_timepoint`: If this is the first approval, then this must be `None`. If it is
+		/// not the first approval, then it must be `Some`, with the timepoint (block number and
+		/// transaction index) of the first approval transaction.
+		/// - `call`: The call to be executed.
+		///
+		/// Approvals of accounts which are no longer signatories are not counted.
+		#[pallet::call_index(7)]
+		#[pallet::weight({
+			let s = T::MaxSignatories::get().saturating_sub(1);
+			let z = call.using_encoded(|d| d.len()) as u32;
+
+			T::WeightInfo::as_multi_create(s, z)
+				.max(T::WeightInfo::as_multi_approve(s, z))
+				.max(T::WeightInfo::as_multi_complete(s, z))
+				.saturating_add(T::DbWeight::get().reads(1))
+				.saturating_add(*max_weight)
+		})]
+		pub fn as_managed(
+			origin: OriginFor<T>,
+			managed: T::AccountId,
+			maybe_timepoint: Option<Timepoint<BlockNumberFor<T>>>,
+			call: Box<<T as Config>::RuntimeCall>,
+			max_weight: Weight,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::operate_managed(
+				who,
+				managed,
+				maybe_timepoint,
+				CallOrHash::Call(*call),
+				max_weight,
+			)
+		}
+
+		/// Register approval for a dispatch to be made from a managed multisig account.
+		///
+		/// Payment: `DepositBase` will be reserved if this is the first approval, plus
+		/// `threshold` times `DepositFactor`. It is returned once this dispatch happens or
+		/// is cancelled.
+		///
+		/// The dispatch origin for this call must be _Signed_ by one of the signatories of the
+		/// managed multisig.
+		///
+		/// - `managed`: The managed multisig account.
+		/// - `maybe_timepoint`: If this is the first approval, then this must be `None`. If it is
+		/// not the first approval, then it must be `Some`, with the timepoint (block number and
+		/// transaction index) of the first approval transaction.
+		/// - `call_hash`: The hash of the call to be executed.
+		///
+		/// NOTE: If this is the final approval, you will want to use `as_managed` instead.
+		#[pallet::call_index(8)]
+		#[pallet::weight({
+			let s = T::MaxSignatories::get().saturating_sub(1);
+
+			T::WeightInfo::approve_as_multi_create(s)
+				.max(T::WeightInfo::approve_as_multi_approve(s))
+				.saturating_add(T::DbWeight::get().reads(1))
+				.saturating_add(*max_weight)
+		})]
+		pub fn approve_as_managed(
+			origin: OriginFor<T>,
+			managed: T::AccountId,
+			maybe_timepoint: Option<Timepoint<BlockNumberFor<T>>>,
+			call_hash: [u8; 32],
+			max_weight: Weight,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::operate_managed(
+				who,
+				managed,
+				maybe_timepoint,
+				CallOrHash::Hash(call_hash),
+				max_weight,
+			)
+		}
+
+		/// Cancel a pre-existing, on-going operation of a managed multisig account. Any deposit
+		/// reserved previously for this operation will be unreserved on success.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the account who opened the
+		/// operation.
+		///
+		/// - `managed`: The managed multisig account.
+		/// - `timepoint`: The timepoint (block number and transaction index) of the first approval
+		/// transaction for this dispatch.
+		/// - `call_hash`: The hash of the call to be executed.
+		#[pallet::call_index(9)]
+		#[pallet::weight(
+			T::WeightInfo::cancel_as_multi(T::MaxSignatories::get().saturating_sub(1))
+				.saturating_add(T::DbWeight::get().reads(1))
+		)]
+		pub fn cancel_as_managed(
+			origin: OriginFor<T>,
+			managed: T::AccountId,
+			timepoint: Timepoint<BlockNumberFor<T>>,
+			call_hash: [u8; 32],
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(<ManagedMultisigs<T>>::contains_key(&managed), Error::<T>::NotManaged);
+
+			Self::cancel(who, managed, timepoint, call_hash)
+		}
+
+		/// Change the signatories and threshold of a managed multisig account.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the managed multisig account
+		/// itself, i.e. the rotation must be approved by its current signatories through
+		/// `as_managed`.
+		///
+		/// - `signatories`: The new signatories. Must be sorted.
+		/// - `threshold`: The new number of approvals needed to dispatch a call.
+		///
+		/// Operations already underway are kept. Only the approvals of the new signatories count
+		/// towards their new threshold.
+		///
+		/// Emits `ManagedMultisigRotated` if successful.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::rotate_managed(signatories.len() as u32))]
+		pub fn rotate_managed(
+			origin: OriginFor<T>,
+			signatories: Vec<T::AccountId>,
+			threshold: u16,
+		) -> DispatchResult {
+			let managed = ensure_signed(origin)?;
+			let signatories = Self::ensure_valid_managed(signatories, threshold)?;
+
+			<ManagedMultisigs<T>>::try_mutate(&managed, |maybe_managed| -> DispatchResult {
+				let m = maybe_managed.as_mut().ok_or(Error::<T>::NotManaged)?;
+				m.signatories = signatories;
+				m.threshold = threshold;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::ManagedMultisigRotated { managed, threshold });
+			Ok(())
+		}
+
+		/// Destroy a managed multisig account, returning its deposit to its creator.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the managed multisig account
+		/// itself, i.e. the destruction must be approved by its current signatories through
+		/// `as_managed`.
+		///
+		/// There must be no other operations underway; they must be executed, cancelled or
+		/// cleared first.
+		///
+		/// Emits `ManagedMultisigDestroyed` if successful.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::destroy_managed())]
+		pub fn destroy_managed(origin: OriginFor<T>) -> DispatchResult {
+			let managed = ensure_signed(origin)?;
+			let m = <ManagedMultisigs<T>>::get(&managed).ok_or(Error::<T>::NotManaged)?;
+			ensure!(
+				<Multisigs<T>>::iter_prefix(&managed).next().is_none(),
+				Error::<T>::OperationsPending
+			);
+
+			let err_amount = T::Currency::unreserve(&m.depositor, m.deposit);
+			debug_assert!(err_amount.is_zero());
+			<ManagedMultisigs<T>>::remove(&managed);
+
+			Self::deposit_event(Event::ManagedMultisigDestroyed { managed });
+			Ok(())
+		}
 	}
 }
 
@@ -618,6 +938,20 @@ impl<T: Config> Pallet<T> {
 			.expect("infinite length input; no invalid inputs for type; qed")
 	}
 
+	/// Derive the account of a managed multisig created by `who` at `when`.
+	///
+	/// `index` disambiguates managed multisigs created by the same account in the same
+	/// transaction.
+	pub fn managed_account_id(
+		who: &T::AccountId,
+		when: Timepoint<BlockNumberFor<T>>,
+		index: u16,
+	) -> T::AccountId {
+		let entropy = (b"modlpy/mngdmulti", who, when, index).using_encoded(blake2_256);
+		Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+			.expect("infinite length input; no invalid inputs for type; qed")
+	}
+
 	fn operate(
 		who: T::AccountId,
 		threshold: u16,
@@ -635,6 +969,59 @@ impl<T: Config> Pallet<T> {
 
 		let id = Self::multi_account_id(&signatories, threshold);
 
+		Self::operate_as(
+			who,
+			id,
+			&signatories,
+			threshold,
+			maybe_timepoint,
+			call_or_hash,
+			max_weight,
+		)
+	}
+
+	fn operate_managed(
+		who: T::AccountId,
+		managed: T::AccountId,
+		maybe_timepoint: Option<Timepoint<BlockNumberFor<T>>>,
+		call_or_hash: CallOrHash<T>,
+		max_weight: Weight,
+	) -> DispatchResultWithPostInfo {
+		let m = <ManagedMultisigs<T>>::get(&managed).ok_or(Error::<T>::NotManaged)?;
+		ensure!(m.signatories.binary_search(&who).is_ok(), Error::<T>::NotSignatory);
+
+		Self::operate_as(
+			who,
+			managed,
+			&m.signatories,
+			m.threshold,
+			maybe_timepoint,
+			call_or_hash,
+			max_weight,
+		)
+		.map(|post_info| {
+			// Account for reading the managed multisig.
+			post_info
+				.actual_weight
+				.map(|actual_weight| actual_weight.saturating_add(T::DbWeight::get().reads(1)))
+				.into()
+		})
+	}
+
+	/// Approve and if possible dispatch a call from the composite account `id`.
+	///
+	/// NOTE: `signatories` must be sorted and contain `who`.
+	fn operate_as(
+		who: T::AccountId,
+		id: T::AccountId,
+		signatories: &[T::AccountId],
+		threshold: u16,
+		maybe_timepoint: Option<Timepoint<BlockNumberFor<T>>>,
+		call_or_hash: CallOrHash<T>,
+		max_weight: Weight,
+	) -> DispatchResultWithPostInfo {
+		let other_signatories_len = signatories.len().saturating_sub(1);
+
 		// Threshold > 1; this means it's a multi-step operation. We extract the `call_hash`.
 		let (call_hash, call_len, maybe_call) = match call_or_hash {
 			CallOrHash::Call(call) => {
@@ -649,6 +1036,11 @@ impl<T: Config> Pallet<T> {
 			// Yes; ensure that the timepoint exists and agrees.
 			let timepoint = maybe_timepoint.ok_or(Error::<T>::NoTimepoint)?;
 			ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
+			ensure!(!Self::is_expired(&m.when), Error::<T>::Expired);
+
+			// Approvals of accounts which are no longer signatories do not count. This only
+			// happens after the signatories of a managed multisig have been rotated.
+			m.approvals.retain(|approval| signatories.binary_search(approval).is_ok());
 
 			// Ensure that either we have not yet signed or that it is at threshold.
 			let mut approvals = m.approvals.len() as u16;
@@ -747,6 +1139,40 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Cancel the operation of the composite account `id`, opened by `who` at `timepoint`.
+	fn cancel(
+		who: T::AccountId,
+		id: T::AccountId,
+		timepoint: Timepoint<BlockNumberFor<T>>,
+		call_hash: [u8; 32],
+	) -> DispatchResult {
+		let m = <Multisigs<T>>::get(&id, call_hash).ok_or(Error::<T>::NotFound)?;
+		ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
+		ensure!(m.depositor == who, Error::<T>::NotOwner);
+
+		let err_amount = T::Currency::unreserve(&m.depositor, m.deposit);
+		debug_assert!(err_amount.is_zero());
+		<Multisigs<T>>::remove(&id, &call_hash);
+
+		Self::deposit_event(Event::MultisigCancelled {
+			cancelling: who,
+			timepoint,
+			multisig: id,
+			call_hash,
+		});
+		Ok(())
+	}
+
+	/// Whether an operation opened at `when` has expired.
+	///
+	/// The lifetime of operations opened before [`ExpiryStart`] is counted from it.
+	pub fn is_expired(when: &Timepoint<BlockNumberFor<T>>) -> bool {
+		T::OperationExpiry::get().map_or(false, |expiry| {
+			let start = when.height.max(ExpiryStart::<T>::get());
+			start.saturating_add(expiry) <= T::BlockNumberProvider::current_block_number()
+		})
+	}
+
 	/// The current `Timepoint`.
 	pub fn timepoint() -> Timepoint<BlockNumberFor<T>> {
 		Timepoint {
@@ -777,6 +1203,28 @@ impl<T: Config> Pallet<T> {
 		Ok(signatories)
 	}
 
+	/// Check the signatories and threshold of a managed multisig.
+	fn ensure_valid_managed(
+		signatories: Vec<T::AccountId>,
+		threshold: u16,
+	) -> Result<BoundedVec<T::AccountId, T::MaxSignatories>, DispatchError> {
+		ensure!(threshold >= 2, Error::<T>::MinimumThreshold);
+		ensure!(usize::from(threshold) <= signatories.len(), Error::<T>::ThresholdTooHigh);
+		ensure!(
+			signatories.windows(2).all(|pair| pair[0] < pair[1]),
+			Error::<T>::SignatoriesOutOfOrder
+		);
+		signatories.try_into().map_err(|_| Error::<T>::TooManySignatories.into())
+	}
+
+	/// Calculate the deposit for a managed multisig.
+	///
+	/// The deposit covers `MaxSignatories` signatories, so that it does not change when the
+	/// signatories are rotated.
+	pub fn managed_deposit() -> BalanceOf<T> {
+		Self::deposit(T::MaxSignatories::get().try_into().unwrap_or(u16::MAX))
+	}
+
 	/// Calculate the deposit for a multisig operation.
 	///
 	/// The deposit is calculated as `DepositBase + DepositFactor * threshold`.
@@ -78,3 +78,47 @@ pub mod v1 {
 		}
 	}
 }
+
+pub mod v2 {
+	use super::*;
+
+	/// Starts counting the lifetime of operations already underway from the current block.
+	///
+	/// Without it, introducing [`Config::OperationExpiry`] would make every operation opened more
+	/// than the expiry ago clearable at once.
+	pub struct MigrateToV2<T>(core::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let current = Pallet::<T>::in_code_storage_version();
+			let onchain = Pallet::<T>::on_chain_storage_version();
+
+			if onchain != 1 {
+				log!(info, "MigrateToV2 should be removed");
+				return T::DbWeight::get().reads(1)
+			}
+
+			let now = T::BlockNumberProvider::current_block_number();
+			ExpiryStart::<T>::put(now);
+			current.put::<Pallet<T>>();
+
+			log!(info, "Counting the lifetime of open operations from block {:?}", now);
+
+			// Reads: Get version + Get block number
+			// Writes: Set expiry start + Set version
+			T::DbWeight::get().reads_writes(2, 2)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), frame::try_runtime::TryRuntimeError> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 2,
+				"the storage version should have been bumped"
+			);
+			ensure!(
+				!ExpiryStart::<T>::get().is_zero(),
+				"the lifetime of open operations should be counted from now"
+			);
+			Ok(())
+		}
+	}
+}
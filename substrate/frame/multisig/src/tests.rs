@@ -52,6 +52,8 @@ impl Contains<RuntimeCall> for TestBaseCallFilter {
 	fn contains(c: &RuntimeCall) -> bool {
 		match *c {
 			RuntimeCall::Balances(_) => true,
+			RuntimeCall::Multisig(Call::rotate_managed { .. }) => true,
+			RuntimeCall::Multisig(Call::destroy_managed { .. }) => true,
 			// Needed for benchmarking
 			RuntimeCall::System(frame_system::Call::remark { .. }) => true,
 			_ => false,
@@ -62,6 +64,7 @@ impl Contains<RuntimeCall> for TestBaseCallFilter {
 parameter_types! {
 	pub static MultisigDepositBase: u64 = 1;
 	pub static MultisigDepositFactor: u64 = 1;
+	pub static MultisigOperationExpiry: Option<u32> = None;
 }
 
 impl Config for Test {
@@ -71,6 +74,7 @@ impl Config for Test {
 	type DepositBase = MultisigDepositBase;
 	type DepositFactor = MultisigDepositFactor;
 	type MaxSignatories = ConstU32<3>;
+	type OperationExpiry = MultisigOperationExpiry;
 	type WeightInfo = ();
 	type BlockNumberProvider = frame_system::Pallet<Test>;
 }
@@ -1005,3 +1009,394 @@ fn poke_deposit_handles_insufficient_balance() {
 		);
 	});
 }
+
+#[test]
+fn expired_multisig_cannot_be_approved_and_can_be_cleared() {
+	new_test_ext().execute_with(|| {
+		MultisigOperationExpiry::set(Some(10));
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 3);
+		let hash = blake2_256(&call_transfer(6, 15).encode());
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(1),
+			3,
+			vec![2, 3],
+			None,
+			hash,
+			Weight::zero()
+		));
+		let timepoint = now();
+		assert_eq!(Balances::reserved_balance(1), 4);
+
+		System::set_block_number(10);
+		assert_noop!(
+			Multisig::clear_expired(RuntimeOrigin::signed(4), multi, hash),
+			Error::<Test>::NotExpired,
+		);
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(2),
+			3,
+			vec![1, 3],
+			Some(timepoint),
+			hash,
+			Weight::zero()
+		));
+
+		System::set_block_number(11);
+		assert_noop!(
+			Multisig::approve_as_multi(
+				RuntimeOrigin::signed(3),
+				3,
+				vec![1, 2],
+				Some(timepoint),
+				hash,
+				Weight::zero()
+			),
+			Error::<Test>::Expired,
+		);
+
+		// Anyone can clear the operation, the deposit goes back to the depositor.
+		let result = Multisig::clear_expired(RuntimeOrigin::signed(4), multi, hash);
+		assert_eq!(result.unwrap().pays_fee, Pays::No);
+		assert_eq!(Balances::free_balance(1), 10);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert!(!Multisigs::<Test>::contains_key(multi, hash));
+		System::assert_last_event(
+			Event::MultisigExpired { timepoint, multisig: multi, call_hash: hash }.into(),
+		);
+
+		assert_noop!(
+			Multisig::clear_expired(RuntimeOrigin::signed(4), multi, hash),
+			Error::<Test>::NotFound,
+		);
+	});
+}
+
+#[test]
+fn multisig_without_expiry_never_expires() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		let hash = blake2_256(&call_transfer(6, 15).encode());
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			None,
+			hash,
+			Weight::zero()
+		));
+
+		System::set_block_number(u32::MAX);
+		assert_noop!(
+			Multisig::clear_expired(RuntimeOrigin::signed(4), multi, hash),
+			Error::<Test>::NotExpired,
+		);
+	});
+}
+
+#[test]
+fn create_managed_multisig_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Multisig::create_managed(RuntimeOrigin::signed(1), vec![1, 2], 1, 0),
+			Error::<Test>::MinimumThreshold,
+		);
+		assert_noop!(
+			Multisig::create_managed(RuntimeOrigin::signed(1), vec![1, 2], 3, 0),
+			Error::<Test>::ThresholdTooHigh,
+		);
+		assert_noop!(
+			Multisig::create_managed(RuntimeOrigin::signed(1), vec![2, 1], 2, 0),
+			Error::<Test>::SignatoriesOutOfOrder,
+		);
+		assert_noop!(
+			Multisig::create_managed(RuntimeOrigin::signed(1), vec![1, 1], 2, 0),
+			Error::<Test>::SignatoriesOutOfOrder,
+		);
+		assert_noop!(
+			Multisig::create_managed(RuntimeOrigin::signed(1), vec![1, 2, 3, 4], 2, 0),
+			Error::<Test>::TooManySignatories,
+		);
+
+		assert_ok!(Multisig::create_managed(RuntimeOrigin::signed(1), vec![2, 3], 2, 0));
+		let managed = Multisig::managed_account_id(&1, now(), 0);
+		let m = ManagedMultisigs::<Test>::get(managed).unwrap();
+		assert_eq!(m.signatories.into_inner(), vec![2, 3]);
+		assert_eq!((m.threshold, m.deposit, m.depositor), (2, 4, 1));
+		assert_eq!(Balances::reserved_balance(1), 4);
+		System::assert_last_event(
+			Event::ManagedMultisigCreated { creator: 1, managed, threshold: 2 }.into(),
+		);
+
+		assert_noop!(
+			Multisig::create_managed(RuntimeOrigin::signed(1), vec![2, 3], 2, 0),
+			Error::<Test>::AlreadyManaged,
+		);
+		assert_ok!(Multisig::create_managed(RuntimeOrigin::signed(1), vec![2, 3], 2, 1));
+		assert_ne!(Multisig::managed_account_id(&1, now(), 1), managed);
+	});
+}
+
+#[test]
+fn managed_multisig_2_of_3_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Multisig::create_managed(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, 0));
+		let managed = Multisig::managed_account_id(&1, now(), 0);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), managed, 5));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), managed, 10));
+
+		let call = call_transfer(6, 15);
+		let call_weight = call.get_dispatch_info().call_weight;
+		let hash = blake2_256(&call.encode());
+		assert_noop!(
+			Multisig::approve_as_managed(
+				RuntimeOrigin::signed(4),
+				managed,
+				None,
+				hash,
+				Weight::zero()
+			),
+			Error::<Test>::NotSignatory,
+		);
+		assert_noop!(
+			Multisig::approve_as_managed(RuntimeOrigin::signed(1), 4, None, hash, Weight::zero()),
+			Error::<Test>::NotManaged,
+		);
+
+		assert_ok!(Multisig::approve_as_managed(
+			RuntimeOrigin::signed(2),
+			managed,
+			None,
+			hash,
+			Weight::zero()
+		));
+		assert_eq!(Balances::free_balance(6), 0);
+
+		assert_ok!(Multisig::as_managed(
+			RuntimeOrigin::signed(3),
+			managed,
+			Some(now()),
+			call,
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 15);
+		assert_eq!(Balances::reserved_balance(2), 0);
+	});
+}
+
+#[test]
+fn cancel_managed_multisig_returns_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Multisig::create_managed(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, 0));
+		let managed = Multisig::managed_account_id(&1, now(), 0);
+
+		let hash = blake2_256(&call_transfer(6, 15).encode());
+		assert_ok!(Multisig::approve_as_managed(
+			RuntimeOrigin::signed(2),
+			managed,
+			None,
+			hash,
+			Weight::zero()
+		));
+		assert_eq!(Balances::reserved_balance(2), 3);
+
+		assert_noop!(
+			Multisig::cancel_as_managed(RuntimeOrigin::signed(3), managed, now(), hash),
+			Error::<Test>::NotOwner,
+		);
+		assert_ok!(Multisig::cancel_as_managed(RuntimeOrigin::signed(2), managed, now(), hash));
+		assert_eq!(Balances::free_balance(2), 10);
+		assert_eq!(Balances::reserved_balance(2), 0);
+	});
+}
+
+#[test]
+fn managed_multisig_rotation_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Multisig::create_managed(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, 0));
+		let managed = Multisig::managed_account_id(&1, now(), 0);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), managed, 10));
+
+		// Signatory 1 approves a transfer before being rotated out.
+		let transfer = call_transfer(6, 5);
+		let transfer_weight = transfer.get_dispatch_info().call_weight;
+		let transfer_hash = blake2_256(&transfer.encode());
+		assert_ok!(Multisig::approve_as_managed(
+			RuntimeOrigin::signed(1),
+			managed,
+			None,
+			transfer_hash,
+			Weight::zero()
+		));
+		let transfer_timepoint = now();
+
+		// Rotation can only be done by the managed multisig itself.
+		assert_noop!(
+			Multisig::rotate_managed(RuntimeOrigin::signed(1), vec![2, 3, 4], 3),
+			Error::<Test>::NotManaged,
+		);
+
+		let rotate = Box::new(RuntimeCall::Multisig(Call::rotate_managed {
+			signatories: vec![2, 3, 4],
+			threshold: 2,
+		}));
+		let rotate_weight = rotate.get_dispatch_info().call_weight;
+		assert_ok!(Multisig::as_managed(
+			RuntimeOrigin::signed(2),
+			managed,
+			None,
+			rotate.clone(),
+			Weight::zero()
+		));
+		assert_ok!(Multisig::as_managed(
+			RuntimeOrigin::signed(3),
+			managed,
+			Some(now()),
+			rotate,
+			rotate_weight
+		));
+		System::assert_has_event(Event::ManagedMultisigRotated { managed, threshold: 2 }.into());
+		let m = ManagedMultisigs::<Test>::get(managed).unwrap();
+		assert_eq!((m.signatories.into_inner(), m.threshold), (vec![2, 3, 4], 2));
+
+		// The account and its deposit are unchanged.
+		assert_eq!(Balances::free_balance(managed), 10);
+		assert_eq!(Balances::reserved_balance(1), 4 + 3);
+
+		// Signatory 1 can no longer approve, and its approval no longer counts.
+		assert_noop!(
+			Multisig::approve_as_managed(
+				RuntimeOrigin::signed(1),
+				managed,
+				None,
+				transfer_hash,
+				Weight::zero()
+			),
+			Error::<Test>::NotSignatory,
+		);
+		assert_ok!(Multisig::as_managed(
+			RuntimeOrigin::signed(4),
+			managed,
+			Some(transfer_timepoint),
+			transfer.clone(),
+			transfer_weight
+		));
+		assert_eq!(Balances::free_balance(6), 0);
+		assert_eq!(
+			Multisigs::<Test>::get(managed, transfer_hash).unwrap().approvals.into_inner(),
+			vec![4]
+		);
+
+		assert_ok!(Multisig::as_managed(
+			RuntimeOrigin::signed(2),
+			managed,
+			Some(transfer_timepoint),
+			transfer,
+			transfer_weight
+		));
+		assert_eq!(Balances::free_balance(6), 5);
+		// The deposit of the rotated out signatory has been returned.
+		assert_eq!(Balances::reserved_balance(1), 4);
+	});
+}
+
+#[test]
+fn destroy_managed_multisig_returns_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Multisig::create_managed(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, 0));
+		let managed = Multisig::managed_account_id(&1, now(), 0);
+		assert_eq!(Balances::reserved_balance(1), 4);
+
+		// Only the managed multisig itself can destroy it.
+		assert_noop!(
+			Multisig::destroy_managed(RuntimeOrigin::signed(1)),
+			Error::<Test>::NotManaged
+		);
+
+		// Operations underway must be finished first.
+		let transfer_hash = blake2_256(&call_transfer(6, 5).encode());
+		assert_ok!(Multisig::approve_as_managed(
+			RuntimeOrigin::signed(3),
+			managed,
+			None,
+			transfer_hash,
+			Weight::zero()
+		));
+		let transfer_timepoint = now();
+		assert_noop!(
+			Multisig::destroy_managed(RuntimeOrigin::signed(managed)),
+			Error::<Test>::OperationsPending
+		);
+		assert_ok!(Multisig::cancel_as_managed(
+			RuntimeOrigin::signed(3),
+			managed,
+			transfer_timepoint,
+			transfer_hash
+		));
+
+		let destroy = Box::new(RuntimeCall::Multisig(Call::destroy_managed {}));
+		let destroy_weight = destroy.get_dispatch_info().call_weight;
+		assert_ok!(Multisig::as_managed(
+			RuntimeOrigin::signed(2),
+			managed,
+			None,
+			destroy.clone(),
+			Weight::zero()
+		));
+		assert_ok!(Multisig::as_managed(
+			RuntimeOrigin::signed(3),
+			managed,
+			Some(now()),
+			destroy,
+			destroy_weight
+		));
+		System::assert_has_event(Event::ManagedMultisigDestroyed { managed }.into());
+		assert!(!ManagedMultisigs::<Test>::contains_key(managed));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(1), 10);
+
+		assert_noop!(
+			Multisig::approve_as_managed(
+				RuntimeOrigin::signed(2),
+				managed,
+				None,
+				transfer_hash,
+				Weight::zero()
+			),
+			Error::<Test>::NotManaged,
+		);
+	});
+}
+
+#[test]
+fn migration_to_v2_resets_the_expiry_clock() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(1).put::<Multisig>();
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		let hash = blake2_256(&call_transfer(6, 15).encode());
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			None,
+			hash,
+			Weight::zero()
+		));
+
+		// Expiry is introduced long after the operation was opened.
+		System::set_block_number(100);
+		MultisigOperationExpiry::set(Some(10));
+		migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+		assert_eq!(ExpiryStart::<Test>::get(), 100);
+		assert_eq!(Multisig::on_chain_storage_version(), 2);
+
+		assert_noop!(
+			Multisig::clear_expired(RuntimeOrigin::signed(4), multi, hash),
+			Error::<Test>::NotExpired,
+		);
+
+		System::set_block_number(110);
+		assert_ok!(Multisig::clear_expired(RuntimeOrigin::signed(4), multi, hash));
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
@@ -79,6 +79,10 @@ pub trait WeightInfo {
 	fn approve_as_multi_approve(s: u32, ) -> Weight;
 	fn cancel_as_multi(s: u32, ) -> Weight;
 	fn poke_deposit(s: u32, ) -> Weight;
+	fn clear_expired() -> Weight;
+	fn create_managed(s: u32, ) -> Weight;
+	fn rotate_managed(s: u32, ) -> Weight;
+	fn destroy_managed() -> Weight;
 }
 
 /// Weights for `pallet_multisig` using the Substrate node and recommended hardware.
@@ -212,6 +216,59 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::ExpiryStart` (r:1 w:0)
+	/// Proof: `Multisig::ExpiryStart` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn clear_expired() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `457`
+		//  Estimated: `6811`
+		// Minimum execution time: 27_301_000 picoseconds.
+		Weight::from_parts(28_410_000, 6811)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Multisig::ManagedMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::ManagedMultisigs` (`max_values`: None, `max_size`: Some(3275), added: 5750, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn create_managed(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `6740`
+		// Minimum execution time: 30_112_000 picoseconds.
+		Weight::from_parts(31_035_417, 6740)
+			// Standard Error: 1_033
+			.saturating_add(Weight::from_parts(48_270, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Multisig::ManagedMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::ManagedMultisigs` (`max_values`: None, `max_size`: Some(3275), added: 5750, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn rotate_managed(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `160 + s * (32 ±0)`
+		//  Estimated: `6740`
+		// Minimum execution time: 15_806_000 picoseconds.
+		Weight::from_parts(16_524_309, 6740)
+			// Standard Error: 871
+			.saturating_add(Weight::from_parts(61_942, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}	/// Storage: `Multisig::ManagedMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::ManagedMultisigs` (`max_values`: None, `max_size`: Some(3275), added: 5750, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:0)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	fn destroy_managed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3365`
+		//  Estimated: `6811`
+		// Minimum execution time: 31_120_000 picoseconds.
+		Weight::from_parts(32_407_000, 6811)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -344,4 +401,57 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::ExpiryStart` (r:1 w:0)
+	/// Proof: `Multisig::ExpiryStart` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn clear_expired() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `457`
+		//  Estimated: `6811`
+		// Minimum execution time: 27_301_000 picoseconds.
+		Weight::from_parts(28_410_000, 6811)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Multisig::ManagedMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::ManagedMultisigs` (`max_values`: None, `max_size`: Some(3275), added: 5750, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn create_managed(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `6740`
+		// Minimum execution time: 30_112_000 picoseconds.
+		Weight::from_parts(31_035_417, 6740)
+			// Standard Error: 1_033
+			.saturating_add(Weight::from_parts(48_270, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Multisig::ManagedMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::ManagedMultisigs` (`max_values`: None, `max_size`: Some(3275), added: 5750, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn rotate_managed(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `160 + s * (32 ±0)`
+		//  Estimated: `6740`
+		// Minimum execution time: 15_806_000 picoseconds.
+		Weight::from_parts(16_524_309, 6740)
+			// Standard Error: 871
+			.saturating_add(Weight::from_parts(61_942, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}	/// Storage: `Multisig::ManagedMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::ManagedMultisigs` (`max_values`: None, `max_size`: Some(3275), added: 5750, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:0)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	fn destroy_managed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3365`
+		//  Estimated: `6811`
+		// Minimum execution time: 31_120_000 picoseconds.
+		Weight::from_parts(32_407_000, 6811)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
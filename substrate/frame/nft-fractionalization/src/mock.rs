@@ -87,6 +87,8 @@ impl pallet_nfts::Config for Test {
 	type CollectionId = u32;
 	type ItemId = u32;
 	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type NativeBalance = Balances;
 	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<Self::AccountId>>;
 	type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
 	type Locker = ();
@@ -101,7 +103,9 @@ impl pallet_nfts::Config for Test {
 	type ApprovalsLimit = ConstU32<10>;
 	type ItemAttributesApprovalsLimit = ConstU32<2>;
 	type MaxTips = ConstU32<10>;
+	type MaxRoyaltyRecipients = ConstU32<10>;
 	type MaxDeadlineDuration = ConstU64<10000>;
+	type AuctionExtensionPeriod = ConstU64<3>;
 	type MaxAttributesPerCall = ConstU32<2>;
 	type Features = Features;
 	type OffchainSignature = Signature;
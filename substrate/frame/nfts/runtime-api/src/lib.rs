@@ -25,6 +25,7 @@ use alloc::vec::Vec;
 use codec::{Decode, Encode};
 
 sp_api::decl_runtime_apis! {
+	#[api_version(2)]
 	pub trait NftsApi<AccountId, CollectionId, ItemId>
 	where
 		AccountId: Encode + Decode,
@@ -55,5 +56,10 @@ sp_api::decl_runtime_apis! {
 		) -> Option<Vec<u8>>;
 
 		fn collection_attribute(collection: CollectionId, key: Vec<u8>) -> Option<Vec<u8>>;
+
+		/// The royalty paid out of every sale of the item, as a list of recipients along with
+		/// their shares of the sale price in basis points.
+		#[api_version(2)]
+		fn royalty(collection: CollectionId, item: ItemId) -> Option<Vec<(AccountId, u16)>>;
 	}
 }
@@ -189,6 +189,45 @@ fn add_collection_attribute<T: Config<I>, I: 'static>(
 	(key, caller, caller_lookup)
 }
 
+fn auction_price<T: Config<I>, I: 'static>(multiplier: u32) -> ItemPrice<T, I> {
+	T::Currency::minimum_balance().max(One::one()).saturating_mul(multiplier.into())
+}
+
+fn funded_bidder<T: Config<I>, I: 'static>(index: u32) -> T::AccountId {
+	let bidder: T::AccountId = account("bidder", index, SEED);
+	T::Currency::make_free_balance_be(&bidder, auction_price::<T, I>(100));
+	bidder
+}
+
+fn create_english_auction<T: Config<I>, I: 'static>() -> (T::CollectionId, T::ItemId, T::AccountId)
+{
+	let (collection, seller, _) = create_collection::<T, I>();
+	let (item, ..) = mint_item::<T, I>(0);
+	T::BlockNumberProvider::set_block_number(One::one());
+	assert_ok!(Nfts::<T, I>::create_auction(
+		SystemOrigin::Signed(seller.clone()).into(),
+		collection,
+		item,
+		AuctionKind::English {
+			reserve_price: auction_price::<T, I>(1),
+			min_increment: Zero::zero()
+		},
+		One::one(),
+	));
+	// Leave room for the proceeds of the sale.
+	T::Currency::make_free_balance_be(&seller, T::Currency::minimum_balance());
+	(collection, item, seller)
+}
+
+fn royalty_recipients<T: Config<I>, I: 'static>(n: u32) -> RoyaltyRecipientsOf<T, I> {
+	let basis_points = MAX_ROYALTY_BASIS_POINTS / T::MaxRoyaltyRecipients::get().max(1) as u16;
+	(0..n)
+		.map(|i| RoyaltyRecipient { account: account("recipient", i, SEED), basis_points })
+		.collect::<Vec<_>>()
+		.try_into()
+		.unwrap()
+}
+
 fn assert_last_event<T: Config<I>, I: 'static>(generic_event: <T as Config<I>>::RuntimeEvent) {
 	let events = frame_system::Pallet::<T>::events();
 	let system_event: <T as frame_system::Config>::RuntimeEvent = generic_event.into();
@@ -878,5 +917,120 @@ benchmarks_instance_pallet! {
 		);
 	}
 
+	create_auction {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let kind = AuctionKind::English {
+			reserve_price: auction_price::<T, I>(1),
+			min_increment: Zero::zero(),
+		};
+		let duration = T::MaxDeadlineDuration::get();
+		T::BlockNumberProvider::set_block_number(One::one());
+	}: _(SystemOrigin::Signed(caller.clone()), collection, item, kind.clone(), duration)
+	verify {
+		assert_last_event::<T, I>(
+			Event::AuctionCreated {
+				collection,
+				item,
+				seller: caller,
+				kind,
+				end: duration.saturating_add(One::one()),
+			}
+			.into(),
+		);
+	}
+
+	place_bid {
+		let (collection, item, _) = create_english_auction::<T, I>();
+		// Worst case: the highest bidder is outbid and the auction gets extended.
+		let outbid = funded_bidder::<T, I>(0);
+		Nfts::<T, I>::place_bid(
+			SystemOrigin::Signed(outbid).into(),
+			collection,
+			item,
+			auction_price::<T, I>(1),
+		)?;
+		let bidder = funded_bidder::<T, I>(1);
+		let amount = auction_price::<T, I>(2);
+	}: _(SystemOrigin::Signed(bidder.clone()), collection, item, amount)
+	verify {
+		assert_eq!(
+			Auctions::<T, I>::get(collection, item).unwrap().highest_bid,
+			Some((bidder, amount))
+		);
+	}
+
+	cancel_auction {
+		let (collection, item, seller) = create_english_auction::<T, I>();
+	}: _(SystemOrigin::Signed(seller), collection, item)
+	verify {
+		assert_last_event::<T, I>(Event::AuctionCancelled { collection, item }.into());
+	}
+
+	settle_auction {
+		let (collection, item, seller) = create_english_auction::<T, I>();
+		let bidder = funded_bidder::<T, I>(0);
+		let amount = auction_price::<T, I>(1);
+		Nfts::<T, I>::place_bid(SystemOrigin::Signed(bidder.clone()).into(), collection, item, amount)?;
+		let end = Auctions::<T, I>::get(collection, item).unwrap().end;
+		T::BlockNumberProvider::set_block_number(end);
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(SystemOrigin::Signed(caller), collection, item)
+	verify {
+		assert_last_event::<T, I>(
+			Event::AuctionSettled {
+				collection,
+				item,
+				seller,
+				winner: Some(bidder),
+				price: Some(amount),
+			}
+			.into(),
+		);
+	}
+
+	set_royalty {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let recipients = royalty_recipients::<T, I>(T::MaxRoyaltyRecipients::get());
+	}: _(SystemOrigin::Signed(caller), collection, Some(item), recipients.clone())
+	verify {
+		assert_last_event::<T, I>(
+			Event::RoyaltySet { collection, maybe_item: Some(item), recipients }.into(),
+		);
+	}
+
+	clear_royalty {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let recipients = royalty_recipients::<T, I>(T::MaxRoyaltyRecipients::get());
+		let origin = SystemOrigin::Signed(caller.clone()).into();
+		Nfts::<T, I>::set_royalty(origin, collection, Some(item), recipients)?;
+	}: _(SystemOrigin::Signed(caller), collection, Some(item))
+	verify {
+		assert_last_event::<T, I>(
+			Event::RoyaltyCleared { collection, maybe_item: Some(item) }.into(),
+		);
+	}
+
+	pay_royalties {
+		let n in 0 .. T::MaxRoyaltyRecipients::get();
+		let (collection, seller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		// The royalty of the collection is looked up after the one of the item.
+		let origin = SystemOrigin::Signed(seller.clone()).into();
+		Nfts::<T, I>::set_royalty(origin, collection, None, royalty_recipients::<T, I>(n))?;
+		let buyer = funded_bidder::<T, I>(0);
+		let price = auction_price::<T, I>(20);
+	}: {
+		Nfts::<T, I>::pay_with_royalties(collection, item, &buyer, &seller, price)?;
+	}
+	verify {
+		if !n.is_zero() {
+			let recipient: T::AccountId = account("recipient", n - 1, SEED);
+			assert!(!T::Currency::free_balance(&recipient).is_zero());
+		}
+	}
+
 	impl_benchmark_test_suite!(Nfts, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -0,0 +1,386 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This module contains helper functions to run English and Dutch auctions of items in the NFTs
+//! pallet.
+//! The bitflag [`PalletFeature::Auctions`] needs to be set in the [`Config::Features`] for NFTs
+//! to have the functionality defined in this module.
+
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	storage::with_storage_layer,
+	traits::{fungible::MutateHold, tokens::Precision},
+	weights::WeightMeter,
+};
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding, Rounding, SaturatedConversion,
+};
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Starts an auction for the specified item.
+	///
+	/// The `seller` must be the owner of the item and the item must be transferable. The auction
+	/// ends `duration` blocks from now, which can't be longer than [`Config::MaxDeadlineDuration`].
+	/// Until then the item can't be transferred or burned.
+	///
+	/// - `seller`: The owner of the item who starts the auction.
+	/// - `collection`: The identifier of the collection containing the item.
+	/// - `item`: The identifier of the item to be auctioned.
+	/// - `kind`: The kind of the auction along with its pricing parameters.
+	/// - `duration`: The duration (in block numbers) of the auction.
+	pub(crate) fn do_create_auction(
+		seller: T::AccountId,
+		collection: T::CollectionId,
+		item: T::ItemId,
+		kind: AuctionKind<ItemPrice<T, I>>,
+		duration: BlockNumberFor<T, I>,
+	) -> DispatchResult {
+		ensure!(
+			Self::is_pallet_feature_enabled(PalletFeature::Auctions),
+			Error::<T, I>::MethodDisabled
+		);
+		ensure!(
+			!duration.is_zero() && duration <= T::MaxDeadlineDuration::get(),
+			Error::<T, I>::WrongDuration
+		);
+		if let AuctionKind::Dutch { start_price, floor_price } = kind {
+			ensure!(start_price > floor_price, Error::<T, I>::WrongAuctionPrice);
+		}
+
+		let details = Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownItem)?;
+		ensure!(details.owner == seller, Error::<T, I>::NoPermission);
+		ensure!(!Auctions::<T, I>::contains_key(&collection, &item), Error::<T, I>::ItemInAuction);
+
+		// The item must be transferable to the winner once the auction ends.
+		ensure!(!T::Locker::is_locked(collection, item), Error::<T, I>::ItemLocked);
+		ensure!(
+			!Self::has_system_attribute(&collection, &item, PalletAttributes::TransferDisabled)?,
+			Error::<T, I>::ItemLocked
+		);
+		let collection_config = Self::get_collection_config(&collection)?;
+		ensure!(
+			collection_config.is_setting_enabled(CollectionSetting::TransferableItems),
+			Error::<T, I>::ItemsNonTransferable
+		);
+		let item_config = Self::get_item_config(&collection, &item)?;
+		ensure!(
+			item_config.is_setting_enabled(ItemSetting::Transferable),
+			Error::<T, I>::ItemLocked
+		);
+
+		let now = T::BlockNumberProvider::current_block_number();
+		let end = now.saturating_add(duration);
+
+		Auctions::<T, I>::insert(
+			&collection,
+			&item,
+			AuctionDetails {
+				seller: seller.clone(),
+				kind: kind.clone(),
+				start: now,
+				end,
+				highest_bid: None,
+			},
+		);
+		AuctionEndings::<T, I>::insert(end, (collection, item), ());
+		// An auction may end before those already running, so the cursor must not skip its end.
+		AuctionSettlementCursor::<T, I>::mutate(|cursor| {
+			*cursor = Some(cursor.map_or(end, |cursor| cursor.min(end)));
+		});
+
+		Self::deposit_event(Event::AuctionCreated { collection, item, seller, kind, end });
+
+		Ok(())
+	}
+
+	/// Places a bid on the specified auction.
+	///
+	/// For an English auction the `amount` must be at least the reserve price and exceed the
+	/// highest bid by the minimum increment. It is placed on hold, while the previous highest bid
+	/// is released. A bid placed less than [`Config::AuctionExtensionPeriod`] blocks before the
+	/// end extends the auction.
+	///
+	/// For a Dutch auction the item is bought at the current price right away, as long as it
+	/// doesn't exceed `amount`.
+	///
+	/// - `bidder`: The account placing the bid.
+	/// - `collection`: The identifier of the collection containing the item.
+	/// - `item`: The identifier of the auctioned item.
+	/// - `amount`: The bid, or the maximum price for a Dutch auction.
+	pub(crate) fn do_place_bid(
+		bidder: T::AccountId,
+		collection: T::CollectionId,
+		item: T::ItemId,
+		amount: ItemPrice<T, I>,
+	) -> DispatchResult {
+		ensure!(
+			Self::is_pallet_feature_enabled(PalletFeature::Auctions),
+			Error::<T, I>::MethodDisabled
+		);
+
+		let mut auction =
+			Auctions::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownAuction)?;
+		ensure!(auction.seller != bidder, Error::<T, I>::NoPermission);
+
+		let now = T::BlockNumberProvider::current_block_number();
+		ensure!(now < auction.end, Error::<T, I>::AuctionEnded);
+
+		match auction.kind {
+			AuctionKind::Dutch { start_price, floor_price } => {
+				let price = Self::dutch_auction_price(
+					start_price,
+					floor_price,
+					auction.start,
+					auction.end,
+					now,
+				);
+				ensure!(amount >= price, Error::<T, I>::BidTooLow);
+
+				Self::remove_auction(collection, item, auction.end);
+				Self::pay_with_royalties(collection, item, &bidder, &auction.seller, price)?;
+				Self::do_transfer(collection, item, bidder.clone(), |_, _| Ok(()))?;
+
+				Self::deposit_event(Event::AuctionSettled {
+					collection,
+					item,
+					seller: auction.seller,
+					winner: Some(bidder),
+					price: Some(price),
+				});
+			},
+			AuctionKind::English { reserve_price, min_increment } => {
+				ensure!(amount >= reserve_price, Error::<T, I>::BidTooLow);
+				let reason: T::RuntimeHoldReason = HoldReason::<I>::AuctionBid.into();
+
+				match auction.highest_bid.take() {
+					Some((highest_bidder, highest)) => {
+						ensure!(
+							amount > highest && amount >= highest.saturating_add(min_increment),
+							Error::<T, I>::BidTooLow
+						);
+						if highest_bidder == bidder {
+							T::NativeBalance::hold(
+								&reason,
+								&bidder,
+								amount.saturating_sub(highest),
+							)?;
+						} else {
+							T::NativeBalance::hold(&reason, &bidder, amount)?;
+							T::NativeBalance::release(
+								&reason,
+								&highest_bidder,
+								highest,
+								Precision::BestEffort,
+							)?;
+						}
+					},
+					None => T::NativeBalance::hold(&reason, &bidder, amount)?,
+				}
+				auction.highest_bid = Some((bidder.clone(), amount));
+
+				// Give the other bidders the chance to respond to a late bid.
+				let extended_end = now.saturating_add(T::AuctionExtensionPeriod::get());
+				if extended_end > auction.end {
+					AuctionEndings::<T, I>::remove(auction.end, (collection, item));
+					AuctionEndings::<T, I>::insert(extended_end, (collection, item), ());
+					auction.end = extended_end;
+				}
+
+				let end = auction.end;
+				Auctions::<T, I>::insert(&collection, &item, auction);
+
+				Self::deposit_event(Event::BidPlaced { collection, item, bidder, amount, end });
+			},
+		}
+
+		Ok(())
+	}
+
+	/// Cancels the specified auction.
+	///
+	/// Only the seller can cancel an auction, and only as long as no bid has been placed.
+	///
+	/// - `caller`: The account cancelling the auction.
+	/// - `collection`: The identifier of the collection containing the item.
+	/// - `item`: The identifier of the auctioned item.
+	pub(crate) fn do_cancel_auction(
+		caller: T::AccountId,
+		collection: T::CollectionId,
+		item: T::ItemId,
+	) -> DispatchResult {
+		let auction =
+			Auctions::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownAuction)?;
+		ensure!(auction.seller == caller, Error::<T, I>::NoPermission);
+		ensure!(auction.highest_bid.is_none(), Error::<T, I>::AuctionHasBids);
+
+		Self::remove_auction(collection, item, auction.end);
+
+		Self::deposit_event(Event::AuctionCancelled { collection, item });
+
+		Ok(())
+	}
+
+	/// Settles the specified auction once it has ended.
+	///
+	/// - `collection`: The identifier of the collection containing the item.
+	/// - `item`: The identifier of the auctioned item.
+	pub(crate) fn do_settle_auction(
+		collection: T::CollectionId,
+		item: T::ItemId,
+	) -> DispatchResult {
+		let auction =
+			Auctions::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownAuction)?;
+		let now = T::BlockNumberProvider::current_block_number();
+		ensure!(now >= auction.end, Error::<T, I>::AuctionNotEnded);
+
+		Self::settle(collection, item, auction);
+
+		Ok(())
+	}
+
+	/// Settles the auctions which have ended, for as long as `limit` allows.
+	///
+	/// Walks the blocks from [`AuctionSettlementCursor`] up to the current one and settles the
+	/// auctions ending at each of them. Returns the consumed weight.
+	pub(crate) fn settle_ended_auctions(limit: Weight) -> Weight {
+		let mut meter = WeightMeter::with_limit(limit);
+		// Reading the cursor and the current block, checking for any remaining auctions and
+		// writing the cursor.
+		if meter.try_consume(T::DbWeight::get().reads_writes(3, 1)).is_err() {
+			return meter.consumed()
+		}
+		let Some(mut cursor) = AuctionSettlementCursor::<T, I>::get() else {
+			return meter.consumed()
+		};
+		let now = T::BlockNumberProvider::current_block_number();
+
+		while cursor <= now {
+			// One read to look up the next auction ending at `cursor`.
+			if meter.try_consume(T::DbWeight::get().reads(1)).is_err() {
+				break
+			}
+			let Some((collection, item)) = AuctionEndings::<T, I>::iter_key_prefix(cursor).next()
+			else {
+				cursor.saturating_inc();
+				continue
+			};
+			let settle_weight = T::WeightInfo::settle_auction()
+				.saturating_add(T::WeightInfo::pay_royalties(T::MaxRoyaltyRecipients::get()));
+			if meter.try_consume(settle_weight).is_err() {
+				break
+			}
+			AuctionEndings::<T, I>::remove(cursor, (collection, item));
+			if let Some(auction) = Auctions::<T, I>::get(&collection, &item) {
+				Self::settle(collection, item, auction);
+			}
+		}
+
+		if Auctions::<T, I>::iter_keys().next().is_none() {
+			AuctionSettlementCursor::<T, I>::kill();
+		} else {
+			AuctionSettlementCursor::<T, I>::put(cursor);
+		}
+
+		meter.consumed()
+	}
+
+	/// Closes an ended auction.
+	///
+	/// The item is transferred to the highest bidder, who pays for it and its royalties with the
+	/// held bid. If the item can't be transferred anymore, e.g. because it got locked, the bid is
+	/// released instead and the seller keeps the item.
+	fn settle(collection: T::CollectionId, item: T::ItemId, auction: AuctionDetailsFor<T, I>) {
+		Self::remove_auction(collection, item, auction.end);
+
+		let winner = auction.highest_bid.and_then(|(bidder, amount)| {
+			let reason: T::RuntimeHoldReason = HoldReason::<I>::AuctionBid.into();
+			let sold = with_storage_layer::<_, DispatchError, _>(|| {
+				Self::do_transfer(collection, item, bidder.clone(), |_, _| Ok(()))?;
+				Self::pay_held_with_royalties(
+					collection,
+					item,
+					&reason,
+					&bidder,
+					&auction.seller,
+					amount,
+				)
+			});
+			match sold {
+				Ok(_) => Some((bidder, amount)),
+				Err(_) => {
+					let _ =
+						T::NativeBalance::release(&reason, &bidder, amount, Precision::BestEffort);
+					None
+				},
+			}
+		});
+		let (winner, price) = winner.unzip();
+
+		Self::deposit_event(Event::AuctionSettled {
+			collection,
+			item,
+			seller: auction.seller,
+			winner,
+			price,
+		});
+	}
+
+	/// Removes the auction of the specified item which ends at `end`.
+	fn remove_auction(collection: T::CollectionId, item: T::ItemId, end: BlockNumberFor<T, I>) {
+		Auctions::<T, I>::remove(&collection, &item);
+		AuctionEndings::<T, I>::remove(end, (collection, item));
+	}
+
+	/// Returns the price of a Dutch auction at block `now`, declining linearly from `start_price`
+	/// at `start` to `floor_price` at `end`.
+	pub(crate) fn dutch_auction_price(
+		start_price: ItemPrice<T, I>,
+		floor_price: ItemPrice<T, I>,
+		start: BlockNumberFor<T, I>,
+		end: BlockNumberFor<T, I>,
+		now: BlockNumberFor<T, I>,
+	) -> ItemPrice<T, I> {
+		let elapsed = now.saturating_sub(start).min(end.saturating_sub(start));
+		let decline = multiply_by_rational_with_rounding(
+			start_price.saturating_sub(floor_price).saturated_into(),
+			elapsed.saturated_into(),
+			end.saturating_sub(start).saturated_into(),
+			Rounding::Down,
+		)
+		.unwrap_or_default();
+		start_price.saturating_sub(decline.saturated_into())
+	}
+
+	/// Returns the current price of the item if it is in a Dutch auction.
+	pub fn current_auction_price(
+		collection: T::CollectionId,
+		item: T::ItemId,
+	) -> Option<ItemPrice<T, I>> {
+		let auction = Auctions::<T, I>::get(&collection, &item)?;
+		match auction.kind {
+			AuctionKind::Dutch { start_price, floor_price } => Some(Self::dutch_auction_price(
+				start_price,
+				floor_price,
+				auction.start,
+				auction.end,
+				T::BlockNumberProvider::current_block_number(),
+			)),
+			AuctionKind::English { .. } => None,
+		}
+	}
+}
@@ -60,7 +60,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	///
 	/// The origin must have the `Freezer` role within the collection to lock the transfer of the
 	/// item. This function disables the `Transferable` setting on the item, preventing it from
-	/// being transferred to other accounts.
+	/// being transferred to other accounts. An item can't be locked while it is being auctioned.
 	///
 	/// - `origin`: The origin of the transaction, representing the account attempting to lock the
 	///   item transfer.
@@ -75,6 +75,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Self::has_role(&collection, &origin, CollectionRole::Freezer),
 			Error::<T, I>::NoPermission
 		);
+		// The auctioned item must stay transferable to the winner.
+		ensure!(!Auctions::<T, I>::contains_key(&collection, &item), Error::<T, I>::ItemInAuction);
 
 		let mut config = Self::get_item_config(&collection, &item)?;
 		if !config.has_disabled_setting(ItemSetting::Transferable) {
@@ -0,0 +1,186 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This module contains helper functions to configure and pay out the creator royalties of the
+//! items in the NFTs pallet.
+//! Royalties are deducted from the price the seller receives on every sale of an item, whether it
+//! is sold with `buy_item`, swapped for a price or auctioned.
+
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{
+		fungible::MutateHold,
+		tokens::{Fortitude, Precision, Restriction},
+		Currency,
+		ExistenceRequirement::KeepAlive,
+	},
+};
+use sp_runtime::Permill;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Sets the royalty of a collection or of a single item.
+	///
+	/// - `maybe_check_origin`: An optional account that must be the Admin of the collection.
+	/// - `collection`: The identifier of the collection.
+	/// - `maybe_item`: The item whose royalty overrides the royalty of the collection, or `None` to
+	///   set the royalty of the collection.
+	/// - `recipients`: The royalty recipients along with their shares of the sale price.
+	pub(crate) fn do_set_royalty(
+		maybe_check_origin: Option<T::AccountId>,
+		collection: T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		recipients: RoyaltyRecipientsOf<T, I>,
+	) -> DispatchResult {
+		Self::ensure_can_change_royalty(maybe_check_origin, collection, maybe_item)?;
+
+		let total: u32 = recipients.iter().map(|r| u32::from(r.basis_points)).sum();
+		ensure!(total <= u32::from(MAX_ROYALTY_BASIS_POINTS), Error::<T, I>::RoyaltyTooHigh);
+
+		match maybe_item {
+			Some(item) => ItemRoyaltyOf::<T, I>::insert(&collection, &item, &recipients),
+			None => CollectionRoyaltyOf::<T, I>::insert(&collection, &recipients),
+		}
+
+		Self::deposit_event(Event::RoyaltySet { collection, maybe_item, recipients });
+		Ok(())
+	}
+
+	/// Clears the royalty of a collection or of a single item.
+	///
+	/// - `maybe_check_origin`: An optional account that must be the Admin of the collection.
+	/// - `collection`: The identifier of the collection.
+	/// - `maybe_item`: The item whose royalty to clear, or `None` to clear the royalty of the
+	///   collection.
+	pub(crate) fn do_clear_royalty(
+		maybe_check_origin: Option<T::AccountId>,
+		collection: T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+	) -> DispatchResult {
+		Self::ensure_can_change_royalty(maybe_check_origin, collection, maybe_item)?;
+
+		match maybe_item {
+			Some(item) => ItemRoyaltyOf::<T, I>::remove(&collection, &item),
+			None => CollectionRoyaltyOf::<T, I>::remove(&collection),
+		}
+
+		Self::deposit_event(Event::RoyaltyCleared { collection, maybe_item });
+		Ok(())
+	}
+
+	/// Ensures the royalty of the specified collection or item can be changed by
+	/// `maybe_check_origin`.
+	fn ensure_can_change_royalty(
+		maybe_check_origin: Option<T::AccountId>,
+		collection: T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+	) -> DispatchResult {
+		if let Some(check_origin) = &maybe_check_origin {
+			ensure!(
+				Self::has_role(&collection, &check_origin, CollectionRole::Admin),
+				Error::<T, I>::NoPermission
+			);
+		}
+		ensure!(Collection::<T, I>::contains_key(&collection), Error::<T, I>::UnknownCollection);
+		if let Some(item) = maybe_item {
+			ensure!(Item::<T, I>::contains_key(&collection, &item), Error::<T, I>::UnknownItem);
+		}
+		Ok(())
+	}
+
+	/// Returns the royalty applying to the sales of the specified item: its own royalty if set,
+	/// the royalty of its collection otherwise.
+	pub fn royalty(
+		collection: T::CollectionId,
+		item: T::ItemId,
+	) -> Option<RoyaltyRecipientsOf<T, I>> {
+		ItemRoyaltyOf::<T, I>::get(&collection, &item)
+			.or_else(|| CollectionRoyaltyOf::<T, I>::get(&collection))
+	}
+
+	/// Returns the non-zero royalties owed out of a sale of the specified item for `price`.
+	pub(crate) fn royalty_payouts(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		price: ItemPrice<T, I>,
+	) -> Vec<(T::AccountId, ItemPrice<T, I>)> {
+		Self::royalty(collection, item)
+			.unwrap_or_default()
+			.into_iter()
+			.filter_map(|RoyaltyRecipient { account, basis_points }| {
+				let share = Permill::from_rational(
+					u32::from(basis_points),
+					u32::from(MAX_ROYALTY_BASIS_POINTS),
+				);
+				let amount = share.mul_floor(price);
+				(!amount.is_zero()).then_some((account, amount))
+			})
+			.collect()
+	}
+
+	/// Pays `price` for the specified item from `buyer` to `seller`, minus the royalties which
+	/// are paid to their recipients.
+	pub(crate) fn pay_with_royalties(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		buyer: &T::AccountId,
+		seller: &T::AccountId,
+		price: ItemPrice<T, I>,
+	) -> DispatchResult {
+		let mut proceeds = price;
+		for (recipient, amount) in Self::royalty_payouts(collection, item, price) {
+			T::Currency::transfer(buyer, &recipient, amount, KeepAlive)?;
+			proceeds.saturating_reduce(amount);
+			Self::deposit_event(Event::RoyaltyPaid { collection, item, recipient, amount });
+		}
+		T::Currency::transfer(buyer, seller, proceeds, KeepAlive)
+	}
+
+	/// Pays `price` for the specified item out of the funds `buyer` has on hold for `reason`, in
+	/// the same way as [`Self::pay_with_royalties`].
+	pub(crate) fn pay_held_with_royalties(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		reason: &T::RuntimeHoldReason,
+		buyer: &T::AccountId,
+		seller: &T::AccountId,
+		price: ItemPrice<T, I>,
+	) -> DispatchResult {
+		let transfer_on_hold = |dest: &T::AccountId, amount| {
+			T::NativeBalance::transfer_on_hold(
+				reason,
+				buyer,
+				dest,
+				amount,
+				Precision::Exact,
+				Restriction::Free,
+				Fortitude::Polite,
+			)
+		};
+
+		let mut proceeds = price;
+		for (recipient, amount) in Self::royalty_payouts(collection, item, price) {
+			transfer_on_hold(&recipient, amount)?;
+			proceeds.saturating_reduce(amount);
+			Self::deposit_event(Event::RoyaltyPaid { collection, item, recipient, amount });
+		}
+		if !proceeds.is_zero() {
+			transfer_on_hold(seller, proceeds)?;
+		}
+		Ok(())
+	}
+}
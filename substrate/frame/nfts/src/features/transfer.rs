@@ -59,6 +59,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		// Ensure the item is not locked.
 		ensure!(!T::Locker::is_locked(collection, item), Error::<T, I>::ItemLocked);
 
+		// Ensure the item is not being auctioned.
+		ensure!(!Auctions::<T, I>::contains_key(&collection, &item), Error::<T, I>::ItemInAuction);
+
 		// Ensure the item is not transfer disabled on the system level attribute.
 		ensure!(
 			!Self::has_system_attribute(&collection, &item, PalletAttributes::TransferDisabled)?,
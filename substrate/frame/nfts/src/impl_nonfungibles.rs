@@ -123,6 +123,9 @@ impl<T: Config<I>, I: 'static> Inspect<<T as SystemConfig>::AccountId> for Palle
 			Ok(transfer_disabled) if transfer_disabled => return false,
 			_ => (),
 		}
+		if Auctions::<T, I>::contains_key(collection, item) {
+			return false
+		}
 		match (
 			CollectionConfigOf::<T, I>::get(collection),
 			ItemConfigOf::<T, I>::get(collection, item),
@@ -423,6 +426,8 @@ impl<T: Config<I>, I: 'static> Transfer<T::AccountId> for Pallet<T, I> {
 		if transfer_disabled {
 			return Err(Error::<T, I>::ItemLocked.into())
 		}
+		// The auctioned item must stay transferable to the winner.
+		ensure!(!Auctions::<T, I>::contains_key(collection, item), Error::<T, I>::ItemInAuction);
 
 		<Self as Mutate<T::AccountId, ItemConfig>>::set_attribute(
 			collection,
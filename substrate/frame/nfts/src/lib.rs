@@ -53,8 +53,8 @@ extern crate alloc;
 use alloc::{boxed::Box, vec, vec::Vec};
 use codec::{Decode, Encode};
 use frame_support::traits::{
-	tokens::Locker, BalanceStatus::Reserved, Currency, EnsureOriginWithArg, Incrementable,
-	ReservableCurrency,
+	fungible, tokens::Locker, BalanceStatus::Reserved, Currency, EnsureOriginWithArg,
+	Incrementable, ReservableCurrency,
 };
 use frame_system::Config as SystemConfig;
 use sp_runtime::{
@@ -76,7 +76,10 @@ type AccountIdLookupOf<T> = <<T as SystemConfig>::Lookup as StaticLookup>::Sourc
 pub mod pallet {
 	use super::*;
 	use frame_support::{pallet_prelude::*, traits::ExistenceRequirement};
-	use frame_system::{ensure_signed, pallet_prelude::OriginFor};
+	use frame_system::{
+		ensure_signed,
+		pallet_prelude::{BlockNumberFor as SystemBlockNumberFor, OriginFor},
+	};
 
 	/// The in-code storage version.
 	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
@@ -149,6 +152,15 @@ pub mod pallet {
 		/// The currency mechanism, used for paying for reserves.
 		type Currency: ReservableCurrency<Self::AccountId>;
 
+		/// The overarching hold reason.
+		type RuntimeHoldReason: From<HoldReason<I>>;
+
+		/// The fungible used to place the bids of English auctions on hold. It must be the same
+		/// currency as [`Config::Currency`].
+		type NativeBalance: fungible::Inspect<Self::AccountId, Balance = DepositBalanceOf<Self, I>>
+			+ fungible::Mutate<Self::AccountId>
+			+ fungible::MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+
 		/// The origin which may forcibly create or destroy an item or otherwise alter privileged
 		/// attributes.
 		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
@@ -209,10 +221,19 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxTips: Get<u32>;
 
+		/// The max number of accounts a royalty of a collection or an item could be split between.
+		#[pallet::constant]
+		type MaxRoyaltyRecipients: Get<u32>;
+
 		/// The max duration in blocks for deadlines.
 		#[pallet::constant]
 		type MaxDeadlineDuration: Get<BlockNumberFor<Self, I>>;
 
+		/// A bid placed on an English auction less than this many blocks before its end extends
+		/// the auction to end this many blocks after the bid.
+		#[pallet::constant]
+		type AuctionExtensionPeriod: Get<BlockNumberFor<Self, I>>;
+
 		/// The max number of attributes a user could set per call.
 		#[pallet::constant]
 		type MaxAttributesPerCall: Get<u32>;
@@ -397,6 +418,53 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Auctions of items which are currently running.
+	#[pallet::storage]
+	pub type Auctions<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		AuctionDetailsFor<T, I>,
+		OptionQuery,
+	>;
+
+	/// The running auctions keyed by the block at which they end.
+	#[pallet::storage]
+	pub type AuctionEndings<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T, I>,
+		Blake2_128Concat,
+		(T::CollectionId, T::ItemId),
+		(),
+		OptionQuery,
+	>;
+
+	/// The first block whose ending auctions may not all be settled yet. `None` if there are no
+	/// auctions to settle.
+	#[pallet::storage]
+	pub type AuctionSettlementCursor<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BlockNumberFor<T, I>, OptionQuery>;
+
+	/// The royalty paid out of every sale of an item of a collection.
+	#[pallet::storage]
+	pub type CollectionRoyaltyOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, RoyaltyRecipientsOf<T, I>, OptionQuery>;
+
+	/// The royalty of an item, overriding the royalty of its collection.
+	#[pallet::storage]
+	pub type ItemRoyaltyOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		RoyaltyRecipientsOf<T, I>,
+		OptionQuery,
+	>;
+
 	/// Config of a collection.
 	#[pallet::storage]
 	pub type CollectionConfigOf<T: Config<I>, I: 'static = ()> =
@@ -594,6 +662,56 @@ pub mod pallet {
 			attribute: PalletAttributes<T::CollectionId>,
 			value: BoundedVec<u8, T::ValueLimit>,
 		},
+		/// An auction was started for an `item`.
+		AuctionCreated {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			seller: T::AccountId,
+			kind: AuctionKind<ItemPrice<T, I>>,
+			end: BlockNumberFor<T, I>,
+		},
+		/// A bid was placed on an English auction. `end` is the end of the auction after any
+		/// extension caused by the bid.
+		BidPlaced {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			bidder: T::AccountId,
+			amount: ItemPrice<T, I>,
+			end: BlockNumberFor<T, I>,
+		},
+		/// An auction was cancelled by the seller.
+		AuctionCancelled { collection: T::CollectionId, item: T::ItemId },
+		/// An auction was closed. `winner` is `None` if the item was not sold.
+		AuctionSettled {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			seller: T::AccountId,
+			winner: Option<T::AccountId>,
+			price: Option<ItemPrice<T, I>>,
+		},
+		/// The royalty of a `collection` or `item` was set.
+		RoyaltySet {
+			collection: T::CollectionId,
+			maybe_item: Option<T::ItemId>,
+			recipients: RoyaltyRecipientsOf<T, I>,
+		},
+		/// The royalty of a `collection` or `item` was cleared.
+		RoyaltyCleared { collection: T::CollectionId, maybe_item: Option<T::ItemId> },
+		/// A royalty was paid out of the sale of an item.
+		RoyaltyPaid {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			recipient: T::AccountId,
+			amount: ItemPrice<T, I>,
+		},
+	}
+
+	/// A reason for the pallet placing a hold on funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason<I: 'static = ()> {
+		/// Funds are held for the highest bid of an English auction.
+		#[codec(index = 0)]
+		AuctionBid,
 	}
 
 	#[pallet::error]
@@ -688,6 +806,27 @@ pub mod pallet {
 		CollectionNotEmpty,
 		/// The witness data should be provided.
 		WitnessRequired,
+		/// The item is being auctioned.
+		ItemInAuction,
+		/// The item is not being auctioned.
+		UnknownAuction,
+		/// The auction has already ended.
+		AuctionEnded,
+		/// The auction has not ended yet.
+		AuctionNotEnded,
+		/// The auction can't be cancelled as bids were already placed.
+		AuctionHasBids,
+		/// The start price of a Dutch auction must be above its floor price.
+		WrongAuctionPrice,
+		/// The royalty shares add up to more than the whole sale price.
+		RoyaltyTooHigh,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<SystemBlockNumberFor<T>> for Pallet<T, I> {
+		fn on_idle(_n: SystemBlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::settle_ended_auctions(remaining_weight)
+		}
 	}
 
 	#[pallet::call]
@@ -1760,7 +1899,10 @@ pub mod pallet {
 		///
 		/// Emits `ItemBought` on success.
 		#[pallet::call_index(32)]
-		#[pallet::weight(T::WeightInfo::buy_item())]
+		#[pallet::weight(
+			T::WeightInfo::buy_item()
+				.saturating_add(T::WeightInfo::pay_royalties(T::MaxRoyaltyRecipients::get()))
+		)]
 		pub fn buy_item(
 			origin: OriginFor<T>,
 			collection: T::CollectionId,
@@ -1860,7 +2002,10 @@ pub mod pallet {
 		///
 		/// Emits `SwapClaimed` on success.
 		#[pallet::call_index(36)]
-		#[pallet::weight(T::WeightInfo::claim_swap())]
+		#[pallet::weight(
+			T::WeightInfo::claim_swap()
+				.saturating_add(T::WeightInfo::pay_royalties(T::MaxRoyaltyRecipients::get()))
+		)]
 		pub fn claim_swap(
 			origin: OriginFor<T>,
 			send_collection: T::CollectionId,
@@ -1931,6 +2076,157 @@ pub mod pallet {
 			Self::validate_signature(&Encode::encode(&data), &signature, &signer)?;
 			Self::do_set_attributes_pre_signed(origin, data, signer)
 		}
+
+		/// Put an item up for auction.
+		///
+		/// Origin must be Signed and must be the owner of the `item`.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The item to be auctioned.
+		/// - `kind`: Whether this is an English or a Dutch auction, along with its prices.
+		/// - `duration`: The number of blocks the auction runs for.
+		///
+		/// The item can't be transferred while the auction runs. The auction is settled
+		/// automatically once it ends, or by anyone calling `settle_auction`.
+		///
+		/// Emits `AuctionCreated` on success.
+		#[pallet::call_index(39)]
+		#[pallet::weight(T::WeightInfo::create_auction())]
+		pub fn create_auction(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			kind: AuctionKind<ItemPrice<T, I>>,
+			duration: BlockNumberFor<T, I>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_create_auction(origin, collection, item, kind, duration)
+		}
+
+		/// Bid on an auctioned item.
+		///
+		/// Origin must be Signed and must not be the seller.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The auctioned item.
+		/// - `amount`: For an English auction, the bid, which is placed on hold until the bidder is
+		///   outbid or the auction is settled. For a Dutch auction, the maximum price the bidder is
+		///   willing to pay. The item is bought at the current price right away.
+		///
+		/// Emits `BidPlaced` for English auctions and `AuctionSettled` for Dutch auctions.
+		#[pallet::call_index(40)]
+		#[pallet::weight(
+			T::WeightInfo::place_bid()
+				.saturating_add(T::WeightInfo::pay_royalties(T::MaxRoyaltyRecipients::get()))
+		)]
+		pub fn place_bid(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			amount: ItemPrice<T, I>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_place_bid(origin, collection, item, amount)
+		}
+
+		/// Cancel an auction which has no bids.
+		///
+		/// Origin must be Signed and must be the seller.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The auctioned item.
+		///
+		/// Emits `AuctionCancelled` on success.
+		#[pallet::call_index(41)]
+		#[pallet::weight(T::WeightInfo::cancel_auction())]
+		pub fn cancel_auction(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_cancel_auction(origin, collection, item)
+		}
+
+		/// Settle an auction which has ended.
+		///
+		/// Origin must be Signed. Anyone can settle an ended auction.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The auctioned item.
+		///
+		/// Emits `AuctionSettled` on success.
+		#[pallet::call_index(42)]
+		#[pallet::weight(
+			T::WeightInfo::settle_auction()
+				.saturating_add(T::WeightInfo::pay_royalties(T::MaxRoyaltyRecipients::get()))
+		)]
+		pub fn settle_auction(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_settle_auction(collection, item)
+		}
+
+		/// Set the royalty paid out of every sale of the items of a collection, or of a single
+		/// item.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Admin of the
+		/// `collection`.
+		///
+		/// The royalty is deducted from the price the seller receives on every `buy_item`,
+		/// `claim_swap` with a price and auction sale. The royalty of an item overrides the
+		/// royalty of its collection.
+		///
+		/// - `collection`: The collection whose royalty to set.
+		/// - `maybe_item`: The item whose royalty to set, or `None` to set the royalty of the
+		///   collection.
+		/// - `recipients`: The accounts receiving the royalty along with their shares of the sale
+		///   price, in basis points. The shares must not add up to more than 10,000.
+		///
+		/// Emits `RoyaltySet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(43)]
+		#[pallet::weight(T::WeightInfo::set_royalty())]
+		pub fn set_royalty(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			maybe_item: Option<T::ItemId>,
+			recipients: RoyaltyRecipientsOf<T, I>,
+		) -> DispatchResult {
+			let maybe_check_origin = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			Self::do_set_royalty(maybe_check_origin, collection, maybe_item, recipients)
+		}
+
+		/// Clear the royalty of a collection or an item.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Admin of the
+		/// `collection`.
+		///
+		/// - `collection`: The collection whose royalty to clear.
+		/// - `maybe_item`: The item whose royalty to clear, or `None` to clear the royalty of the
+		///   collection.
+		///
+		/// Emits `RoyaltyCleared`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(44)]
+		#[pallet::weight(T::WeightInfo::clear_royalty())]
+		pub fn clear_royalty(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			maybe_item: Option<T::ItemId>,
+		) -> DispatchResult {
+			let maybe_check_origin = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			Self::do_clear_royalty(maybe_check_origin, collection, maybe_item)
+		}
 	}
 }
 
@@ -22,9 +22,11 @@ use enumflags2::BitFlags;
 use frame_support::{
 	assert_noop, assert_ok,
 	traits::{
+		fungible,
 		tokens::nonfungibles_v2::{Create, Destroy, Inspect, Mutate},
-		Currency, Get,
+		Currency, Get, Hooks,
 	},
+	weights::Weight,
 };
 use pallet_balances::Error as BalancesError;
 use sp_core::{bounded::BoundedVec, Pair};
@@ -3875,3 +3877,670 @@ fn clear_collection_metadata_works() {
 		assert_eq!(Balances::reserved_balance(&account(1)), 10);
 	});
 }
+
+fn bid_on_hold(who: &AccountIdOf<Test>) -> u64 {
+	<Balances as fungible::InspectHold<_>>::balance_on_hold(
+		&RuntimeHoldReason::Nfts(HoldReason::AuctionBid),
+		who,
+	)
+}
+
+fn english_auction(reserve_price: u64, min_increment: u64) -> AuctionKind<u64> {
+	AuctionKind::English { reserve_price, min_increment }
+}
+
+/// Creates a collection owned by `owner` with a single item `0` minted to them.
+fn setup_auction_item(owner: &AccountIdOf<Test>) {
+	assert_ok!(Nfts::force_create(
+		RuntimeOrigin::root(),
+		owner.clone(),
+		default_collection_config()
+	));
+	assert_ok!(Nfts::mint(RuntimeOrigin::signed(owner.clone()), 0, 0, owner.clone(), None));
+}
+
+#[test]
+fn english_auction_should_work() {
+	new_test_ext().execute_with(|| {
+		let seller = account(1);
+		let bidder_1 = account(2);
+		let bidder_2 = account(3);
+		Balances::make_free_balance_be(&seller, 100);
+		Balances::make_free_balance_be(&bidder_1, 100);
+		Balances::make_free_balance_be(&bidder_2, 100);
+		setup_auction_item(&seller);
+
+		assert_noop!(
+			Nfts::create_auction(
+				RuntimeOrigin::signed(bidder_1.clone()),
+				0,
+				0,
+				english_auction(10, 5),
+				10
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::create_auction(
+				RuntimeOrigin::signed(seller.clone()),
+				0,
+				0,
+				english_auction(10, 5),
+				0
+			),
+			Error::<Test>::WrongDuration
+		);
+		assert_ok!(Nfts::create_auction(
+			RuntimeOrigin::signed(seller.clone()),
+			0,
+			0,
+			english_auction(10, 5),
+			10
+		));
+		assert!(events().contains(&Event::<Test>::AuctionCreated {
+			collection: 0,
+			item: 0,
+			seller: seller.clone(),
+			kind: english_auction(10, 5),
+			end: 11,
+		}));
+		assert_noop!(
+			Nfts::create_auction(
+				RuntimeOrigin::signed(seller.clone()),
+				0,
+				0,
+				english_auction(10, 5),
+				10
+			),
+			Error::<Test>::ItemInAuction
+		);
+
+		// The item can't change hands while it is auctioned.
+		assert_noop!(
+			Nfts::transfer(RuntimeOrigin::signed(seller.clone()), 0, 0, bidder_1.clone()),
+			Error::<Test>::ItemInAuction
+		);
+		assert_noop!(
+			Nfts::burn(RuntimeOrigin::signed(seller.clone()), 0, 0),
+			Error::<Test>::ItemInAuction
+		);
+
+		assert_noop!(
+			Nfts::place_bid(RuntimeOrigin::signed(seller.clone()), 0, 0, 10),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::place_bid(RuntimeOrigin::signed(bidder_1.clone()), 0, 0, 9),
+			Error::<Test>::BidTooLow
+		);
+		assert_ok!(Nfts::place_bid(RuntimeOrigin::signed(bidder_1.clone()), 0, 0, 10));
+		assert_eq!(bid_on_hold(&bidder_1), 10);
+
+		// A new bid must exceed the highest one by the minimum increment.
+		assert_noop!(
+			Nfts::place_bid(RuntimeOrigin::signed(bidder_2.clone()), 0, 0, 14),
+			Error::<Test>::BidTooLow
+		);
+		assert_ok!(Nfts::place_bid(RuntimeOrigin::signed(bidder_2.clone()), 0, 0, 15));
+		assert_eq!(bid_on_hold(&bidder_1), 0);
+		assert_eq!(bid_on_hold(&bidder_2), 15);
+
+		// The highest bidder only puts the difference on hold when raising their own bid.
+		assert_ok!(Nfts::place_bid(RuntimeOrigin::signed(bidder_2.clone()), 0, 0, 20));
+		assert_eq!(bid_on_hold(&bidder_2), 20);
+		assert_eq!(Balances::free_balance(&bidder_2), 80);
+
+		assert_noop!(
+			Nfts::cancel_auction(RuntimeOrigin::signed(seller.clone()), 0, 0),
+			Error::<Test>::AuctionHasBids
+		);
+		assert_noop!(
+			Nfts::settle_auction(RuntimeOrigin::signed(bidder_1.clone()), 0, 0),
+			Error::<Test>::AuctionNotEnded
+		);
+
+		System::set_block_number(11);
+		assert_noop!(
+			Nfts::place_bid(RuntimeOrigin::signed(bidder_1.clone()), 0, 0, 30),
+			Error::<Test>::AuctionEnded
+		);
+		assert_ok!(Nfts::settle_auction(RuntimeOrigin::signed(bidder_1.clone()), 0, 0));
+		assert!(events().contains(&Event::<Test>::AuctionSettled {
+			collection: 0,
+			item: 0,
+			seller: seller.clone(),
+			winner: Some(bidder_2.clone()),
+			price: Some(20),
+		}));
+
+		assert_eq!(Item::<Test>::get(0, 0).unwrap().owner, bidder_2);
+		assert_eq!(bid_on_hold(&bidder_2), 0);
+		assert_eq!(Balances::total_balance(&bidder_2), 80);
+		assert_eq!(Balances::total_balance(&seller), 120);
+		assert!(!Auctions::<Test>::contains_key(0, 0));
+		assert_eq!(AuctionEndings::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn late_bids_extend_english_auctions() {
+	new_test_ext().execute_with(|| {
+		let seller = account(1);
+		let bidder = account(2);
+		Balances::make_free_balance_be(&seller, 100);
+		Balances::make_free_balance_be(&bidder, 100);
+		setup_auction_item(&seller);
+		let extension: u64 = <Test as Config>::AuctionExtensionPeriod::get();
+
+		assert_ok!(Nfts::create_auction(
+			RuntimeOrigin::signed(seller.clone()),
+			0,
+			0,
+			english_auction(10, 1),
+			10
+		));
+
+		// A bid well before the end doesn't extend the auction.
+		assert_ok!(Nfts::place_bid(RuntimeOrigin::signed(bidder.clone()), 0, 0, 10));
+		assert_eq!(Auctions::<Test>::get(0, 0).unwrap().end, 11);
+
+		// A bid in the last blocks does.
+		System::set_block_number(10);
+		assert_ok!(Nfts::place_bid(RuntimeOrigin::signed(bidder.clone()), 0, 0, 11));
+		assert_eq!(Auctions::<Test>::get(0, 0).unwrap().end, 10 + extension);
+		assert!(events().contains(&Event::<Test>::BidPlaced {
+			collection: 0,
+			item: 0,
+			bidder: bidder.clone(),
+			amount: 11,
+			end: 10 + extension,
+		}));
+		assert!(!AuctionEndings::<Test>::contains_key(11, (0, 0)));
+		assert!(AuctionEndings::<Test>::contains_key(10 + extension, (0, 0)));
+
+		System::set_block_number(11);
+		assert_noop!(
+			Nfts::settle_auction(RuntimeOrigin::signed(bidder.clone()), 0, 0),
+			Error::<Test>::AuctionNotEnded
+		);
+	});
+}
+
+#[test]
+fn ended_auctions_are_settled_on_idle() {
+	new_test_ext().execute_with(|| {
+		let seller = account(1);
+		let bidder = account(2);
+		Balances::make_free_balance_be(&seller, 100);
+		Balances::make_free_balance_be(&bidder, 100);
+		setup_auction_item(&seller);
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(seller.clone()), 0, 1, seller.clone(), None));
+
+		assert_ok!(Nfts::create_auction(
+			RuntimeOrigin::signed(seller.clone()),
+			0,
+			0,
+			english_auction(10, 1),
+			5
+		));
+		assert_ok!(Nfts::create_auction(
+			RuntimeOrigin::signed(seller.clone()),
+			0,
+			1,
+			english_auction(10, 1),
+			10
+		));
+		assert_eq!(AuctionSettlementCursor::<Test>::get(), Some(6));
+		assert_ok!(Nfts::place_bid(RuntimeOrigin::signed(bidder.clone()), 0, 0, 10));
+
+		// Nothing has ended yet.
+		Nfts::on_idle(System::block_number(), Weight::MAX);
+		assert!(Auctions::<Test>::contains_key(0, 0));
+		assert_eq!(AuctionSettlementCursor::<Test>::get(), Some(6));
+
+		// The settlement is limited by the available weight.
+		System::set_block_number(12);
+		let consumed = Nfts::on_idle(System::block_number(), Weight::zero());
+		assert_eq!(consumed, Weight::zero());
+		assert!(Auctions::<Test>::contains_key(0, 0));
+
+		Nfts::on_idle(System::block_number(), Weight::MAX);
+		assert_eq!(Item::<Test>::get(0, 0).unwrap().owner, bidder);
+		assert_eq!(Item::<Test>::get(0, 1).unwrap().owner, seller);
+		assert!(events().contains(&Event::<Test>::AuctionSettled {
+			collection: 0,
+			item: 1,
+			seller: seller.clone(),
+			winner: None,
+			price: None,
+		}));
+		assert_eq!(Auctions::<Test>::iter().count(), 0);
+		assert_eq!(AuctionEndings::<Test>::iter().count(), 0);
+		assert_eq!(AuctionSettlementCursor::<Test>::get(), None);
+	});
+}
+
+#[test]
+fn earlier_ending_auction_moves_the_settlement_cursor_back() {
+	new_test_ext().execute_with(|| {
+		let seller = account(1);
+		Balances::make_free_balance_be(&seller, 100);
+		setup_auction_item(&seller);
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(seller.clone()), 0, 1, seller.clone(), None));
+
+		assert_ok!(Nfts::create_auction(
+			RuntimeOrigin::signed(seller.clone()),
+			0,
+			1,
+			english_auction(10, 1),
+			10
+		));
+		assert_eq!(AuctionSettlementCursor::<Test>::get(), Some(11));
+
+		// The second auction ends before the first one.
+		assert_ok!(Nfts::create_auction(
+			RuntimeOrigin::signed(seller.clone()),
+			0,
+			0,
+			english_auction(10, 1),
+			5
+		));
+		assert_eq!(AuctionSettlementCursor::<Test>::get(), Some(6));
+
+		System::set_block_number(6);
+		Nfts::on_idle(System::block_number(), Weight::MAX);
+		assert!(!Auctions::<Test>::contains_key(0, 0));
+		assert!(Auctions::<Test>::contains_key(0, 1));
+		assert_eq!(AuctionSettlementCursor::<Test>::get(), Some(7));
+
+		System::set_block_number(11);
+		Nfts::on_idle(System::block_number(), Weight::MAX);
+		assert_eq!(Auctions::<Test>::iter().count(), 0);
+		assert_eq!(AuctionSettlementCursor::<Test>::get(), None);
+	});
+}
+
+#[test]
+fn dutch_auction_should_work() {
+	new_test_ext().execute_with(|| {
+		let seller = account(1);
+		let buyer = account(2);
+		Balances::make_free_balance_be(&seller, 100);
+		Balances::make_free_balance_be(&buyer, 100);
+		setup_auction_item(&seller);
+		let dutch = |start_price, floor_price| AuctionKind::Dutch { start_price, floor_price };
+
+		assert_noop!(
+			Nfts::create_auction(RuntimeOrigin::signed(seller.clone()), 0, 0, dutch(10, 10), 10),
+			Error::<Test>::WrongAuctionPrice
+		);
+		assert_ok!(Nfts::create_auction(
+			RuntimeOrigin::signed(seller.clone()),
+			0,
+			0,
+			dutch(60, 10),
+			10
+		));
+		assert_eq!(Nfts::current_auction_price(0, 0), Some(60));
+
+		// The price declines linearly towards the floor price.
+		System::set_block_number(5);
+		assert_eq!(Nfts::current_auction_price(0, 0), Some(40));
+		assert_noop!(
+			Nfts::place_bid(RuntimeOrigin::signed(buyer.clone()), 0, 0, 39),
+			Error::<Test>::BidTooLow
+		);
+
+		// The buyer pays the current price, not their maximum.
+		assert_ok!(Nfts::place_bid(RuntimeOrigin::signed(buyer.clone()), 0, 0, 50));
+		assert!(events().contains(&Event::<Test>::AuctionSettled {
+			collection: 0,
+			item: 0,
+			seller: seller.clone(),
+			winner: Some(buyer.clone()),
+			price: Some(40),
+		}));
+		assert_eq!(Item::<Test>::get(0, 0).unwrap().owner, buyer);
+		assert_eq!(Balances::total_balance(&buyer), 60);
+		assert_eq!(Balances::total_balance(&seller), 140);
+		assert!(!Auctions::<Test>::contains_key(0, 0));
+		assert!(!AuctionEndings::<Test>::contains_key(11, (0, 0)));
+	});
+}
+
+#[test]
+fn cancel_auction_should_work() {
+	new_test_ext().execute_with(|| {
+		let seller = account(1);
+		let other = account(2);
+		Balances::make_free_balance_be(&seller, 100);
+		setup_auction_item(&seller);
+
+		assert_noop!(
+			Nfts::cancel_auction(RuntimeOrigin::signed(seller.clone()), 0, 0),
+			Error::<Test>::UnknownAuction
+		);
+		assert_ok!(Nfts::create_auction(
+			RuntimeOrigin::signed(seller.clone()),
+			0,
+			0,
+			english_auction(10, 1),
+			10
+		));
+		assert_noop!(
+			Nfts::cancel_auction(RuntimeOrigin::signed(other.clone()), 0, 0),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Nfts::cancel_auction(RuntimeOrigin::signed(seller.clone()), 0, 0));
+		assert!(events().contains(&Event::<Test>::AuctionCancelled { collection: 0, item: 0 }));
+		assert!(!Auctions::<Test>::contains_key(0, 0));
+		assert!(!AuctionEndings::<Test>::contains_key(11, (0, 0)));
+
+		// The item is transferable again.
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(seller), 0, 0, other));
+	});
+}
+
+#[test]
+fn auctions_respect_item_locks() {
+	new_test_ext().execute_with(|| {
+		let seller = account(1);
+		let bidder = account(2);
+		Balances::make_free_balance_be(&seller, 100);
+		Balances::make_free_balance_be(&bidder, 100);
+		setup_auction_item(&seller);
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(seller.clone()), 0, 1, seller.clone(), None));
+
+		// A locked item can't be auctioned.
+		assert_ok!(Nfts::lock_item_transfer(RuntimeOrigin::signed(seller.clone()), 0, 1));
+		assert_noop!(
+			Nfts::create_auction(
+				RuntimeOrigin::signed(seller.clone()),
+				0,
+				1,
+				english_auction(10, 1),
+				10
+			),
+			Error::<Test>::ItemLocked
+		);
+
+		// An auctioned item can't be locked.
+		assert_ok!(Nfts::create_auction(
+			RuntimeOrigin::signed(seller.clone()),
+			0,
+			0,
+			english_auction(10, 1),
+			10
+		));
+		assert_ok!(Nfts::place_bid(RuntimeOrigin::signed(bidder.clone()), 0, 0, 10));
+		assert_noop!(
+			Nfts::lock_item_transfer(RuntimeOrigin::signed(seller.clone()), 0, 0),
+			Error::<Test>::ItemInAuction
+		);
+
+		// An item which became non-transferable during the auction is kept by the seller and the
+		// bid is released.
+		assert_ok!(Nfts::lock_collection(
+			RuntimeOrigin::signed(seller.clone()),
+			0,
+			CollectionSettings::from_disabled(CollectionSetting::TransferableItems.into())
+		));
+
+		System::set_block_number(11);
+		assert_ok!(Nfts::settle_auction(RuntimeOrigin::signed(bidder.clone()), 0, 0));
+		assert!(events().contains(&Event::<Test>::AuctionSettled {
+			collection: 0,
+			item: 0,
+			seller: seller.clone(),
+			winner: None,
+			price: None,
+		}));
+		assert_eq!(Item::<Test>::get(0, 0).unwrap().owner, seller);
+		assert_eq!(bid_on_hold(&bidder), 0);
+		assert_eq!(Balances::free_balance(&bidder), 100);
+	});
+}
+
+#[test]
+fn auctions_can_be_disabled() {
+	new_test_ext().execute_with(|| {
+		let seller = account(1);
+		Balances::make_free_balance_be(&seller, 100);
+		setup_auction_item(&seller);
+		Features::set(&PalletFeatures::from_disabled(PalletFeature::Auctions.into()));
+
+		assert_noop!(
+			Nfts::create_auction(
+				RuntimeOrigin::signed(seller.clone()),
+				0,
+				0,
+				english_auction(10, 1),
+				10
+			),
+			Error::<Test>::MethodDisabled
+		);
+	});
+}
+
+fn royalty(recipients: &[(u8, u16)]) -> RoyaltyRecipientsOf<Test> {
+	recipients
+		.iter()
+		.map(|(id, basis_points)| RoyaltyRecipient {
+			account: account(*id),
+			basis_points: *basis_points,
+		})
+		.collect::<Vec<_>>()
+		.try_into()
+		.unwrap()
+}
+
+#[test]
+fn set_and_clear_royalty_should_work() {
+	new_test_ext().execute_with(|| {
+		let owner = account(1);
+		Balances::make_free_balance_be(&owner, 100);
+		setup_auction_item(&owner);
+
+		assert_noop!(
+			Nfts::set_royalty(RuntimeOrigin::signed(account(2)), 0, None, royalty(&[(3, 500)])),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::set_royalty(
+				RuntimeOrigin::signed(owner.clone()),
+				0,
+				None,
+				royalty(&[(3, 6_000), (4, 4_001)])
+			),
+			Error::<Test>::RoyaltyTooHigh
+		);
+		assert_noop!(
+			Nfts::set_royalty(RuntimeOrigin::signed(owner.clone()), 0, Some(1), royalty(&[])),
+			Error::<Test>::UnknownItem
+		);
+		assert_noop!(
+			Nfts::set_royalty(RuntimeOrigin::root(), 1, None, royalty(&[])),
+			Error::<Test>::UnknownCollection
+		);
+
+		assert_ok!(Nfts::set_royalty(
+			RuntimeOrigin::signed(owner.clone()),
+			0,
+			None,
+			royalty(&[(3, 6_000), (4, 4_000)])
+		));
+		assert!(events().contains(&Event::<Test>::RoyaltySet {
+			collection: 0,
+			maybe_item: None,
+			recipients: royalty(&[(3, 6_000), (4, 4_000)]),
+		}));
+		assert_eq!(Nfts::royalty(0, 0), Some(royalty(&[(3, 6_000), (4, 4_000)])));
+
+		// The royalty of an item overrides the one of its collection.
+		assert_ok!(Nfts::set_royalty(RuntimeOrigin::root(), 0, Some(0), royalty(&[(5, 100)])));
+		assert_eq!(Nfts::royalty(0, 0), Some(royalty(&[(5, 100)])));
+
+		assert_ok!(Nfts::clear_royalty(RuntimeOrigin::signed(owner.clone()), 0, Some(0)));
+		assert!(events()
+			.contains(&Event::<Test>::RoyaltyCleared { collection: 0, maybe_item: Some(0) }));
+		assert_eq!(Nfts::royalty(0, 0), Some(royalty(&[(3, 6_000), (4, 4_000)])));
+
+		// The royalties are removed along with the item and the collection.
+		assert_ok!(Nfts::set_royalty(RuntimeOrigin::root(), 0, Some(0), royalty(&[(5, 100)])));
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(owner.clone()), 0, 0));
+		assert!(!ItemRoyaltyOf::<Test>::contains_key(0, 0));
+		assert_ok!(Nfts::destroy(
+			RuntimeOrigin::signed(owner.clone()),
+			0,
+			Nfts::get_destroy_witness(&0).unwrap()
+		));
+		assert!(!CollectionRoyaltyOf::<Test>::contains_key(0));
+	});
+}
+
+#[test]
+fn royalties_are_paid_on_buy_item() {
+	new_test_ext().execute_with(|| {
+		let seller = account(1);
+		let buyer = account(2);
+		Balances::make_free_balance_be(&seller, 100);
+		Balances::make_free_balance_be(&buyer, 1000);
+		setup_auction_item(&seller);
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(seller.clone()), 0, 1, seller.clone(), None));
+		assert_ok!(Nfts::set_royalty(
+			RuntimeOrigin::signed(seller.clone()),
+			0,
+			None,
+			royalty(&[(3, 500), (4, 250)])
+		));
+		assert_ok!(Nfts::set_royalty(
+			RuntimeOrigin::signed(seller.clone()),
+			0,
+			Some(1),
+			royalty(&[(5, 1_000)])
+		));
+		assert_ok!(Nfts::set_price(RuntimeOrigin::signed(seller.clone()), 0, 0, Some(200), None));
+		assert_ok!(Nfts::set_price(RuntimeOrigin::signed(seller.clone()), 0, 1, Some(300), None));
+		let seller_balance = Balances::total_balance(&seller);
+		events();
+
+		assert_ok!(Nfts::buy_item(RuntimeOrigin::signed(buyer.clone()), 0, 0, 200));
+		assert!(events().contains(&Event::<Test>::RoyaltyPaid {
+			collection: 0,
+			item: 0,
+			recipient: account(3),
+			amount: 10,
+		}));
+		assert_eq!(Balances::free_balance(&account(3)), 10);
+		assert_eq!(Balances::free_balance(&account(4)), 5);
+		assert_eq!(Balances::total_balance(&seller), seller_balance + 185);
+		assert_eq!(Balances::free_balance(&buyer), 800);
+
+		// Only the royalty of the item applies to it.
+		assert_ok!(Nfts::buy_item(RuntimeOrigin::signed(buyer.clone()), 0, 1, 300));
+		assert_eq!(Balances::free_balance(&account(5)), 30);
+		assert_eq!(Balances::free_balance(&account(3)), 10);
+		assert_eq!(Balances::total_balance(&seller), seller_balance + 455);
+		assert_eq!(Balances::free_balance(&buyer), 500);
+	});
+}
+
+#[test]
+fn royalties_are_paid_on_swaps() {
+	new_test_ext().execute_with(|| {
+		let user_1 = account(1);
+		let user_2 = account(2);
+		Balances::make_free_balance_be(&user_1, 1000);
+		Balances::make_free_balance_be(&user_2, 1000);
+		setup_auction_item(&user_1);
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(user_1.clone()), 0, 1, user_2.clone(), None));
+		assert_ok!(Nfts::set_royalty(
+			RuntimeOrigin::signed(user_1.clone()),
+			0,
+			Some(0),
+			royalty(&[(3, 1_000)])
+		));
+		assert_ok!(Nfts::set_royalty(
+			RuntimeOrigin::signed(user_1.clone()),
+			0,
+			Some(1),
+			royalty(&[(4, 2_000)])
+		));
+		let price = PriceWithDirection { amount: 100, direction: PriceDirection::Receive };
+
+		// `user_1` gives item `0` away and receives the price for it.
+		assert_ok!(Nfts::create_swap(
+			RuntimeOrigin::signed(user_1.clone()),
+			0,
+			0,
+			0,
+			Some(1),
+			Some(price.clone()),
+			2
+		));
+		let user_1_balance = Balances::total_balance(&user_1);
+		let user_2_balance = Balances::total_balance(&user_2);
+		assert_ok!(Nfts::claim_swap(
+			RuntimeOrigin::signed(user_2.clone()),
+			0,
+			1,
+			0,
+			0,
+			Some(price)
+		));
+		assert_eq!(Balances::free_balance(&account(3)), 10);
+		assert_eq!(Balances::free_balance(&account(4)), 0);
+		assert_eq!(Balances::total_balance(&user_1), user_1_balance + 90);
+		assert_eq!(Balances::total_balance(&user_2), user_2_balance - 100);
+	});
+}
+
+#[test]
+fn royalties_are_paid_on_auctions() {
+	new_test_ext().execute_with(|| {
+		let seller = account(1);
+		let bidder = account(2);
+		Balances::make_free_balance_be(&seller, 100);
+		Balances::make_free_balance_be(&bidder, 100);
+		setup_auction_item(&seller);
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(seller.clone()), 0, 1, seller.clone(), None));
+		assert_ok!(Nfts::set_royalty(
+			RuntimeOrigin::signed(seller.clone()),
+			0,
+			None,
+			royalty(&[(3, 1_000)])
+		));
+		let seller_balance = Balances::total_balance(&seller);
+
+		// The royalty is paid out of the held bid of an English auction.
+		assert_ok!(Nfts::create_auction(
+			RuntimeOrigin::signed(seller.clone()),
+			0,
+			0,
+			english_auction(10, 5),
+			10
+		));
+		assert_ok!(Nfts::place_bid(RuntimeOrigin::signed(bidder.clone()), 0, 0, 50));
+		System::set_block_number(11);
+		assert_ok!(Nfts::settle_auction(RuntimeOrigin::signed(account(4)), 0, 0));
+		assert_eq!(Item::<Test>::get(0, 0).unwrap().owner, bidder);
+		assert_eq!(bid_on_hold(&bidder), 0);
+		assert_eq!(Balances::free_balance(&account(3)), 5);
+		assert_eq!(Balances::total_balance(&seller), seller_balance + 45);
+		assert_eq!(Balances::total_balance(&bidder), 50);
+
+		// And out of the price of a Dutch auction.
+		assert_ok!(Nfts::create_auction(
+			RuntimeOrigin::signed(seller.clone()),
+			0,
+			1,
+			AuctionKind::Dutch { start_price: 40, floor_price: 20 },
+			10
+		));
+		assert_ok!(Nfts::place_bid(RuntimeOrigin::signed(bidder.clone()), 0, 1, 40));
+		assert_eq!(Item::<Test>::get(0, 1).unwrap().owner, bidder);
+		assert_eq!(Balances::free_balance(&account(3)), 9);
+		assert_eq!(Balances::total_balance(&seller), seller_balance + 81);
+		assert_eq!(Balances::total_balance(&bidder), 10);
+	});
+}
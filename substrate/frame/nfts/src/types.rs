@@ -73,6 +73,14 @@ pub type ItemTipOf<T, I = ()> = ItemTip<
 /// A type alias for the settings configuration of a collection.
 pub type CollectionConfigFor<T, I = ()> =
 	CollectionConfig<BalanceOf<T, I>, BlockNumberFor<T, I>, <T as Config<I>>::CollectionId>;
+/// A type alias for the details of a running auction.
+pub type AuctionDetailsFor<T, I = ()> =
+	AuctionDetails<<T as SystemConfig>::AccountId, ItemPrice<T, I>, BlockNumberFor<T, I>>;
+/// A type alias for the royalty recipients of a collection or an item.
+pub type RoyaltyRecipientsOf<T, I = ()> = BoundedVec<
+	RoyaltyRecipient<<T as SystemConfig>::AccountId>,
+	<T as Config<I>>::MaxRoyaltyRecipients,
+>;
 /// A type alias for the pre-signed minting configuration for a specified collection.
 pub type PreSignedMintOf<T, I = ()> = PreSignedMint<
 	<T as Config<I>>::CollectionId,
@@ -239,6 +247,73 @@ pub struct PendingSwap<CollectionId, ItemId, ItemPriceWithDirection, Deadline> {
 	pub deadline: Deadline,
 }
 
+/// The kind of an auction along with its pricing parameters.
+#[derive(
+	Clone,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub enum AuctionKind<Amount> {
+	/// An ascending-price auction. The highest bid placed before the end wins the item.
+	English {
+		/// The minimum amount of the first bid.
+		reserve_price: Amount,
+		/// The minimum amount by which every following bid must exceed the highest one.
+		min_increment: Amount,
+	},
+	/// A descending-price auction. The price declines linearly from `start_price` to
+	/// `floor_price` over the duration of the auction and the first bid sells the item.
+	Dutch {
+		/// The price at the start of the auction.
+		start_price: Amount,
+		/// The price at the end of the auction.
+		floor_price: Amount,
+	},
+}
+
+/// Information about a running auction.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct AuctionDetails<AccountId, Amount, BlockNumber> {
+	/// The owner of the item who put it up for auction.
+	pub seller: AccountId,
+	/// The kind of the auction.
+	pub kind: AuctionKind<Amount>,
+	/// The block at which the auction started.
+	pub start: BlockNumber,
+	/// The block at which the auction ends. Bids are accepted up to the block before.
+	pub end: BlockNumber,
+	/// The highest bid of an English auction along with the bidder. The amount is on hold.
+	pub highest_bid: Option<(AccountId, Amount)>,
+}
+
+/// The maximum share of a sale price that can be paid out as royalties, in basis points.
+pub const MAX_ROYALTY_BASIS_POINTS: u16 = 10_000;
+
+/// A creator royalty paid out of every sale of an item.
+#[derive(
+	Clone,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub struct RoyaltyRecipient<AccountId> {
+	/// The account receiving the royalty.
+	pub account: AccountId,
+	/// The share of the sale price paid to `account`, in basis points.
+	pub basis_points: u16,
+}
+
 /// Information about the reserved attribute deposit.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct AttributeDeposit<DepositBalance, AccountId> {
@@ -570,6 +645,8 @@ pub enum PalletFeature {
 	Approvals,
 	/// Allow/disallow atomic items swap.
 	Swaps,
+	/// Allow/disallow item auctions.
+	Auctions,
 }
 
 /// Wrapper type for `BitFlags<PalletFeature>` that implements `Codec`.
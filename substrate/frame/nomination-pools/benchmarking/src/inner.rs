@@ -31,10 +31,11 @@ use frame_support::{
 use frame_system::RawOrigin as RuntimeOrigin;
 use pallet_nomination_pools::{
 	adapter::{Member, Pool, StakeStrategy, StakeStrategyType},
-	BalanceOf, BondExtra, BondedPoolInner, BondedPools, ClaimPermission, ClaimPermissions,
-	Commission, CommissionChangeRate, CommissionClaimPermission, ConfigOp, GlobalMaxCommission,
-	MaxPoolMembers, MaxPoolMembersPerPool, MaxPools, Metadata, MinCreateBond, MinJoinBond,
-	Pallet as Pools, PoolId, PoolMembers, PoolRoles, PoolState, RewardPools, SubPoolsStorage,
+	AutoCompoundMembers, BalanceOf, BondExtra, BondedPoolInner, BondedPools, ClaimPermission,
+	ClaimPermissions, Commission, CommissionChangeRate, CommissionClaimPermission, ConfigOp,
+	GlobalMaxCommission, LiquidBalances, LiquidSupply, MaxPoolMembers, MaxPoolMembersPerPool,
+	MaxPools, Metadata, MinCreateBond, MinJoinBond, Pallet as Pools, PoolId, PoolMembers,
+	PoolRoles, PoolState, RewardPools, SubPoolsStorage,
 };
 use pallet_staking::MaxNominationsOf;
 use sp_runtime::{
@@ -109,6 +110,36 @@ fn create_pool_account<T: pallet_nomination_pools::Config>(
 	(pool_creator, pool_account)
 }
 
+// Create an auto-compounding pool in which a member already tokenized some of their points, and
+// give the pool some rewards so that the liquid account has rewards to compound.
+//
+// Returns the member holding the liquid points and their amount.
+fn create_liquid_pool<T: pallet_nomination_pools::Config>() -> (T::AccountId, BalanceOf<T>) {
+	let ed = CurrencyOf::<T>::minimum_balance();
+	let origin_weight = Pools::<T>::depositor_min_bond() * 2u32.into();
+	let (depositor, _pool_account) = create_pool_account::<T>(0, origin_weight, None);
+	assert_ok!(Pools::<T>::set_auto_compound(RuntimeOrigin::Signed(depositor).into(), 1, true));
+
+	let min_join_bond = MinJoinBond::<T>::get().max(ed);
+	let holder = create_funded_user_with_balance::<T>("holder", 0, min_join_bond * 8u32.into());
+	assert_ok!(Pools::<T>::join(
+		RuntimeOrigin::Signed(holder.clone()).into(),
+		min_join_bond * 4u32.into(),
+		1
+	));
+	assert_ok!(Pools::<T>::tokenize_points(
+		RuntimeOrigin::Signed(holder.clone()).into(),
+		min_join_bond * 2u32.into()
+	));
+
+	let reward_account = Pools::<T>::generate_reward_account(1);
+	CurrencyOf::<T>::set_balance(&reward_account, ed + origin_weight);
+
+	let tokens = LiquidBalances::<T>::get(1, &holder);
+	assert!(!tokens.is_zero());
+	(holder, tokens)
+}
+
 fn migrate_to_transfer_stake<T: Config>(pool_id: PoolId) {
 	if T::StakeAdapter::strategy_type() == StakeStrategyType::Transfer {
 		// should already be in the correct strategy
@@ -1172,5 +1203,91 @@ mod benchmarks {
 		assert_eq!(PoolMembers::<T>::get(&depositor).unwrap().total_balance(), deposit_amount);
 	}
 
+	#[benchmark]
+	fn set_auto_compound() {
+		let (depositor, _) =
+			create_pool_account::<T>(0, Pools::<T>::depositor_min_bond() * 2u32.into(), None);
+		whitelist_account!(depositor);
+
+		#[extrinsic_call]
+		_(RuntimeOrigin::Signed(depositor), 1, true);
+
+		assert!(pallet_nomination_pools::AutoCompoundPools::<T>::contains_key(1));
+	}
+
+	#[benchmark]
+	fn tokenize_points() {
+		let (_holder, tokens) = create_liquid_pool::<T>();
+
+		// a second member tokenizes, paying out their rewards and compounding the liquid account.
+		let min_join_bond = MinJoinBond::<T>::get().max(CurrencyOf::<T>::minimum_balance());
+		let joiner = create_funded_user_with_balance::<T>("joiner", 0, min_join_bond * 8u32.into());
+		assert_ok!(Pools::<T>::join(
+			RuntimeOrigin::Signed(joiner.clone()).into(),
+			min_join_bond * 4u32.into(),
+			1
+		));
+		whitelist_account!(joiner);
+
+		#[extrinsic_call]
+		_(RuntimeOrigin::Signed(joiner.clone()), min_join_bond * 2u32.into());
+
+		assert!(!LiquidBalances::<T>::get(1, &joiner).is_zero());
+		assert!(LiquidSupply::<T>::get(1) > tokens);
+	}
+
+	#[benchmark]
+	fn redeem_points() {
+		let (holder, tokens) = create_liquid_pool::<T>();
+
+		// the worst case is a new member redeeming all liquid points.
+		let redeemer =
+			create_funded_user_with_balance::<T>("redeemer", 0, CurrencyOf::<T>::minimum_balance());
+		assert_ok!(Pools::<T>::transfer_liquid_points(
+			RuntimeOrigin::Signed(holder).into(),
+			1,
+			T::Lookup::unlookup(redeemer.clone()),
+			tokens
+		));
+		whitelist_account!(redeemer);
+
+		#[extrinsic_call]
+		_(RuntimeOrigin::Signed(redeemer.clone()), 1, tokens);
+
+		assert!(PoolMembers::<T>::contains_key(&redeemer));
+		assert!(LiquidSupply::<T>::get(1).is_zero());
+	}
+
+	#[benchmark]
+	fn transfer_liquid_points() {
+		let (holder, tokens) = create_liquid_pool::<T>();
+		let dest: T::AccountId = account("dest", 0, USER_SEED);
+		whitelist_account!(holder);
+
+		#[extrinsic_call]
+		_(RuntimeOrigin::Signed(holder.clone()), 1, T::Lookup::unlookup(dest.clone()), tokens);
+
+		assert!(LiquidBalances::<T>::get(1, &holder).is_zero());
+		assert_eq!(LiquidBalances::<T>::get(1, &dest), tokens);
+	}
+
+	#[benchmark]
+	fn register_auto_compound_member() {
+		// the depositor joined before the pool became auto-compounding.
+		let (depositor, _) =
+			create_pool_account::<T>(0, Pools::<T>::depositor_min_bond() * 2u32.into(), None);
+		assert_ok!(Pools::<T>::set_auto_compound(
+			RuntimeOrigin::Signed(depositor.clone()).into(),
+			1,
+			true
+		));
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RuntimeOrigin::Signed(caller), T::Lookup::unlookup(depositor.clone()));
+
+		assert!(AutoCompoundMembers::<T>::contains_key(1, &depositor));
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Runtime);
 }
@@ -184,6 +184,16 @@ pub trait StakeStrategy {
 		num_slashing_spans: u32,
 	) -> DispatchResult;
 
+	/// Move `amount` of the pool stake backed by `from` to be backed by `to` instead.
+	///
+	/// Both accounts must be members of the pool. The stake of the pool itself is unaffected.
+	fn member_transfer(
+		from: Member<Self::AccountId>,
+		to: Member<Self::AccountId>,
+		pool_account: Pool<Self::AccountId>,
+		amount: Self::Balance,
+	) -> DispatchResult;
+
 	/// Dissolve the pool account.
 	fn dissolve(pool_account: Pool<Self::AccountId>) -> DispatchResult;
 
@@ -312,6 +322,15 @@ impl<T: Config, Staking: StakingInterface<Balance = BalanceOf<T>, AccountId = T:
 		Ok(())
 	}
 
+	fn member_transfer(
+		_from: Member<Self::AccountId>,
+		_to: Member<Self::AccountId>,
+		_pool_account: Pool<Self::AccountId>,
+		_amount: BalanceOf<T>,
+	) -> DispatchResult {
+		Err(Error::<T>::Defensive(DefensiveError::DelegationUnsupported).into())
+	}
+
 	fn dissolve(pool_account: Pool<Self::AccountId>) -> DispatchResult {
 		defensive_assert!(
 			T::Currency::total_balance(&pool_account.clone().get()).is_zero(),
@@ -430,6 +449,15 @@ impl<
 		Delegation::withdraw_delegation(who.into(), pool_account.into(), amount, num_slashing_spans)
 	}
 
+	fn member_transfer(
+		from: Member<Self::AccountId>,
+		to: Member<Self::AccountId>,
+		pool_account: Pool<Self::AccountId>,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		Delegation::transfer_delegation(pool_account.into(), from.into(), to.into(), amount)
+	}
+
 	fn dissolve(pool_account: Pool<Self::AccountId>) -> DispatchResult {
 		Delegation::remove_agent(pool_account.into())
 	}
@@ -158,6 +158,30 @@
 //! changing the commission rate to a very high value after rewards are accumulated, and thus claim
 //! an unexpectedly high chunk of the reward.
 //!
+//! ### Auto-compounding and liquid points
+//!
+//! A pool can be opted into auto-compounding with [`Call::set_auto_compound`] by its root. Once
+//! per era, the pending rewards of all members of such pools are bonded back into the pool when
+//! blocks have weight to spare, see [`Hooks::on_idle`](frame_support::traits::Hooks::on_idle).
+//! Members joining such a pool are compounded from then on, while members that joined before it
+//! became auto-compounding need to be registered by anyone with
+//! [`Call::register_auto_compound_member`].
+//!
+//! Members of an auto-compounding pool that uses [`adapter::StakeStrategyType::Delegate`] can turn
+//! some of their active points into liquid points with [`Call::tokenize_points`]. The points are
+//! moved to a key-less liquid account of the pool and the member receives transferable tokens in
+//! return, which are exposed as a `fungibles` asset with the pool id as asset id. Any holder of
+//! these tokens can turn them back into active points of the pool with [`Call::redeem_points`],
+//! becoming a member if they are not one already. The liquid account keeps compounding its
+//! rewards, so each token is redeemed for a growing number of points. Pending slashes are applied
+//! to both sides before points change hands.
+//!
+//! Whoever tokenizes the first points of a pool provides the existential deposit of its liquid
+//! account. As liquid points are first issued one to one with points, the existential deposit of
+//! the native currency is also the minimum balance of liquid points, any less is burnt as dust.
+//! The liquid account cannot be unbonded, hence a pool with outstanding liquid points cannot be
+//! dismantled until all of them are redeemed.
+//!
 //! ### Dismantling
 //!
 //! As noted, a pool is destroyed once
@@ -360,12 +384,14 @@ use core::{fmt::Debug, ops::Div};
 use frame_support::{
 	defensive, defensive_assert, ensure,
 	pallet_prelude::{MaxEncodedLen, *},
-	storage::bounded_btree_map::BoundedBTreeMap,
+	storage::{bounded_btree_map::BoundedBTreeMap, with_storage_layer},
 	traits::{
 		fungible::{Inspect, InspectFreeze, Mutate, MutateFreeze},
-		tokens::{Fortitude, Preservation},
+		fungibles,
+		tokens::{Fortitude, Precision, Preservation},
 		Contains, Defensive, DefensiveOption, DefensiveResult, DefensiveSaturating, Get,
 	},
+	weights::WeightMeter,
 	DefaultNoBound, PalletError,
 };
 use scale_info::TypeInfo;
@@ -401,6 +427,7 @@ pub mod mock;
 mod tests;
 
 pub mod adapter;
+mod liquid;
 pub mod migration;
 pub mod weights;
 
@@ -463,6 +490,7 @@ pub enum BondExtra<Balance> {
 enum AccountType {
 	Bonded,
 	Reward,
+	Liquid,
 }
 
 /// The permission a pool member can set for other accounts to claim rewards on their behalf.
@@ -1837,6 +1865,48 @@ pub mod pallet {
 	pub type ClaimPermissions<T: Config> =
 		StorageMap<_, Twox64Concat, T::AccountId, ClaimPermission, ValueQuery>;
 
+	/// Pools whose members have their rewards compounded automatically every era.
+	#[pallet::storage]
+	pub type AutoCompoundPools<T: Config> = CountedStorageMap<_, Twox64Concat, PoolId, ()>;
+
+	/// The last era in which all members of auto-compounding pools were compounded.
+	#[pallet::storage]
+	pub type LastCompoundedEra<T: Config> = StorageValue<_, EraIndex, OptionQuery>;
+
+	/// Members of auto-compounding pools whose rewards are compounded every era.
+	///
+	/// Members joining an auto-compounding pool are added automatically, others with
+	/// [`Call::register_auto_compound_member`]. Entries of members that left their pool, or of
+	/// pools that stopped auto-compounding, are removed during the next compounding pass.
+	///
+	/// TWOX-NOTE: SAFE since `AccountId` is a secure hash.
+	#[pallet::storage]
+	pub type AutoCompoundMembers<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, PoolId, Twox64Concat, T::AccountId, ()>;
+
+	/// The last member compounded in an ongoing compounding pass over [`AutoCompoundMembers`].
+	#[pallet::storage]
+	pub type CompoundingCursor<T: Config> = StorageValue<_, (PoolId, T::AccountId), OptionQuery>;
+
+	/// Liquid points held by each account, per pool.
+	///
+	/// TWOX-NOTE: SAFE since `AccountId` is a secure hash.
+	#[pallet::storage]
+	pub type LiquidBalances<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		PoolId,
+		Twox64Concat,
+		T::AccountId,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	/// Total liquid points issued for each pool.
+	#[pallet::storage]
+	pub type LiquidSupply<T: Config> =
+		StorageMap<_, Twox64Concat, PoolId, BalanceOf<T>, ValueQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub min_join_bond: BalanceOf<T>,
@@ -1980,6 +2050,31 @@ pub mod pallet {
 			max_members_per_pool: Option<u32>,
 			global_max_commission: Option<Perbill>,
 		},
+		/// Auto-compounding of the rewards of a pool has been enabled or disabled.
+		AutoCompoundSet { pool_id: PoolId, enabled: bool },
+		/// A member of an auto-compounding pool has been registered for compounding.
+		AutoCompoundMemberRegistered { member: T::AccountId, pool_id: PoolId },
+		/// A member turned `points` of their active points into `tokens` liquid points.
+		PointsTokenized {
+			member: T::AccountId,
+			pool_id: PoolId,
+			points: BalanceOf<T>,
+			tokens: BalanceOf<T>,
+		},
+		/// An account redeemed `tokens` liquid points for `points` active points of the pool.
+		PointsRedeemed {
+			member: T::AccountId,
+			pool_id: PoolId,
+			points: BalanceOf<T>,
+			tokens: BalanceOf<T>,
+		},
+		/// Liquid points of a pool have been transferred.
+		LiquidPointsTransferred {
+			pool_id: PoolId,
+			from: T::AccountId,
+			to: T::AccountId,
+			amount: BalanceOf<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -2070,6 +2165,16 @@ pub mod pallet {
 		/// Account is restricted from participation in pools. This may happen if the account is
 		/// staking in another way already.
 		Restricted,
+		/// The pool does not compound the rewards of its members automatically.
+		NotAutoCompounding,
+		/// The rewards of the member are already compounded automatically.
+		AlreadyAutoCompounding,
+		/// The member does not have enough active points.
+		NotEnoughPoints,
+		/// The amount of points or liquid points is too low to be moved.
+		NothingToMove,
+		/// The liquid account of a pool cannot be unbonded.
+		LiquidAccount,
 	}
 
 	#[derive(
@@ -2169,6 +2274,7 @@ pub mod pallet {
 					unbonding_eras: Default::default(),
 				},
 			);
+			Self::register_if_auto_compounding(pool_id, &who);
 
 			Self::deposit_event(Event::<T>::Bonded {
 				member: who,
@@ -2280,6 +2386,11 @@ pub mod pallet {
 			let (mut member, mut bonded_pool, mut reward_pool) =
 				Self::get_member_with_pools(&member_account)?;
 
+			// the points of the liquid account back the liquid points of the pool.
+			ensure!(
+				member_account != Self::generate_liquid_account(member.pool_id),
+				Error::<T>::LiquidAccount
+			);
 			bonded_pool.ok_to_unbond_with(&who, &member_account, &member, unbonding_points)?;
 
 			// Claim the the payout prior to unbonding. Once the user is unbonding their points no
@@ -3250,10 +3361,140 @@ pub mod pallet {
 			Self::migrate_to_delegate_stake(pool_id)?;
 			Ok(Pays::No.into())
 		}
+
+		/// Enable or disable automatic compounding of the rewards of all members of a pool.
+		///
+		/// The pending rewards of the members of an auto-compounding pool are bonded back into the
+		/// pool once per era in [`Hooks::on_idle`], without any action of the members. Only
+		/// auto-compounding pools allow [`Call::tokenize_points`].
+		///
+		/// Members that joined the pool before auto-compounding was enabled must be registered
+		/// with [`Call::register_auto_compound_member`].
+		///
+		/// The dispatch origin of this call must be signed by the root role of the pool, or be the
+		/// `Root` origin.
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::set_auto_compound())]
+		pub fn set_auto_compound(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			enabled: bool,
+		) -> DispatchResult {
+			let bonded_pool = match ensure_root(origin.clone()) {
+				Ok(()) => BondedPool::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?,
+				Err(sp_runtime::traits::BadOrigin) => {
+					let who = ensure_signed(origin)?;
+					let bonded_pool =
+						BondedPool::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+					ensure!(bonded_pool.is_root(&who), Error::<T>::DoesNotHavePermission);
+					bonded_pool
+				},
+			};
+			ensure!(!bonded_pool.is_destroying(), Error::<T>::CanNotChangeState);
+
+			if enabled {
+				AutoCompoundPools::<T>::insert(pool_id, ());
+			} else {
+				AutoCompoundPools::<T>::remove(pool_id);
+			}
+
+			Self::deposit_event(Event::<T>::AutoCompoundSet { pool_id, enabled });
+			Ok(())
+		}
+
+		/// Turn `points` of the active points of the caller into liquid points of their pool.
+		///
+		/// The points are moved to the liquid account of the pool, and the caller is issued liquid
+		/// points (a `fungibles` asset with the pool id as asset id) in proportion to the points
+		/// already held by the liquid account. Rewards of the caller are paid out and pending
+		/// slashes applied before the points are moved.
+		///
+		/// The pool must be open, auto-compounding and use
+		/// [`adapter::StakeStrategyType::Delegate`]. The caller is subject to the same minimum
+		/// bond as for [`Call::unbond`], and the depositor can never tokenize all of their points.
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::tokenize_points())]
+		pub fn tokenize_points(
+			origin: OriginFor<T>,
+			#[pallet::compact] points: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_tokenize_points(who, points)
+		}
+
+		/// Redeem `tokens` liquid points of pool `pool_id` for active points of that pool.
+		///
+		/// The caller becomes a member of the pool if they are not one already, in which case the
+		/// redeemed points must be worth at least [`MinJoinBond`]. Any account that is not a member
+		/// of another pool may redeem liquid points, in any pool state.
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::redeem_points())]
+		pub fn redeem_points(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			#[pallet::compact] tokens: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_redeem_points(who, pool_id, tokens)
+		}
+
+		/// Transfer `amount` liquid points of pool `pool_id` to `dest`.
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::transfer_liquid_points())]
+		pub fn transfer_liquid_points(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			dest: AccountIdLookupOf<T>,
+			#[pallet::compact] amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let dest = T::Lookup::lookup(dest)?;
+			<Self as fungibles::Mutate<_>>::transfer(
+				pool_id,
+				&who,
+				&dest,
+				amount,
+				Preservation::Expendable,
+			)
+			.map(|_| ())
+		}
+
+		/// Register `member` of an auto-compounding pool to have their rewards compounded every
+		/// era.
+		///
+		/// Members joining an auto-compounding pool are registered automatically, hence this is
+		/// only needed for members that joined before auto-compounding was enabled.
+		///
+		/// The dispatch origin of this call must be signed, by any account. The fee is waived if
+		/// the member is registered.
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::register_auto_compound_member())]
+		pub fn register_auto_compound_member(
+			origin: OriginFor<T>,
+			member: AccountIdLookupOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let _caller = ensure_signed(origin)?;
+			let member = T::Lookup::lookup(member)?;
+			let pool_id =
+				PoolMembers::<T>::get(&member).ok_or(Error::<T>::PoolMemberNotFound)?.pool_id;
+			ensure!(AutoCompoundPools::<T>::contains_key(pool_id), Error::<T>::NotAutoCompounding);
+			ensure!(
+				!AutoCompoundMembers::<T>::contains_key(pool_id, &member),
+				Error::<T>::AlreadyAutoCompounding
+			);
+
+			AutoCompoundMembers::<T>::insert(pool_id, &member, ());
+			Self::deposit_event(Event::<T>::AutoCompoundMemberRegistered { member, pool_id });
+			Ok(Pays::No.into())
+		}
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<SystemBlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: SystemBlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::do_compound_pools(remaining_weight)
+		}
+
 		#[cfg(feature = "try-runtime")]
 		fn try_state(_n: SystemBlockNumberFor<T>) -> Result<(), TryRuntimeError> {
 			Self::do_try_state(u8::MAX)
@@ -3350,6 +3591,7 @@ impl<T: Config> Pallet<T> {
 		Self::deposit_event(Event::<T>::Destroyed { pool_id: bonded_pool.id });
 		// Remove bonded pool metadata.
 		Metadata::<T>::remove(bonded_pool.id);
+		AutoCompoundPools::<T>::remove(bonded_pool.id);
 
 		bonded_pool.remove();
 	}
@@ -3373,6 +3615,13 @@ impl<T: Config> Pallet<T> {
 		T::PalletId::get().into_sub_account_truncating((AccountType::Reward, id))
 	}
 
+	/// Create the liquid account of a pool with the given id.
+	///
+	/// This account is a member of the pool holding the points that back its liquid points.
+	pub fn generate_liquid_account(id: PoolId) -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating((AccountType::Liquid, id))
+	}
+
 	/// Get the member with their associated bonded and reward pool.
 	fn get_member_with_pools(
 		who: &T::AccountId,
@@ -3795,6 +4044,345 @@ impl<T: Config> Pallet<T> {
 		Ok(actual_balance.saturating_sub(expected_balance))
 	}
 
+	/// The minimum balance a new member created by moving points must be backed with.
+	fn min_new_member_balance() -> BalanceOf<T> {
+		MinJoinBond::<T>::get().max(T::Currency::minimum_balance())
+	}
+
+	/// Apply any pending slash of `member_account`, so that their delegation matches the balance
+	/// of their points.
+	fn apply_pending_slash(member_account: &T::AccountId) -> DispatchResult {
+		match Self::do_apply_slash(member_account, None, false) {
+			Err(e) if e == Error::<T>::NothingToSlash.into() => Ok(()),
+			result => result,
+		}
+	}
+
+	/// Bond the pending rewards of the liquid account of `pool_id` back into the pool, if the
+	/// pool has one.
+	fn do_compound_liquid(pool_id: PoolId) -> DispatchResult {
+		let liquid_account = Self::generate_liquid_account(pool_id);
+		if !PoolMembers::<T>::contains_key(&liquid_account) {
+			return Ok(())
+		}
+
+		let (mut member, mut bonded_pool, mut reward_pool) =
+			Self::get_member_with_pools(&liquid_account)?;
+		reward_pool.update_records(
+			bonded_pool.id,
+			bonded_pool.points,
+			bonded_pool.commission.current(),
+		)?;
+		let claimed = Self::do_reward_payout(
+			&liquid_account,
+			&mut member,
+			&mut bonded_pool,
+			&mut reward_pool,
+		)?;
+
+		if !claimed.is_zero() {
+			let points_issued =
+				bonded_pool.try_bond_funds(&liquid_account, claimed, BondType::Extra)?;
+			member.points =
+				member.points.checked_add(&points_issued).ok_or(Error::<T>::OverflowRisk)?;
+			Self::deposit_event(Event::<T>::Bonded {
+				member: liquid_account.clone(),
+				pool_id,
+				bonded: claimed,
+				joined: false,
+			});
+		}
+
+		Self::put_member_with_pools(&liquid_account, member, bonded_pool, reward_pool);
+		Ok(())
+	}
+
+	/// Move `points` active points of pool `pool_id` from member `from` to `to`, together with the
+	/// delegation backing them.
+	///
+	/// Pending slashes of both sides are applied and their pending rewards paid out first. `to`
+	/// becomes a member of the pool if it is not one already, and `from` stops being a member once
+	/// it has no points left.
+	fn do_move_points(
+		pool_id: PoolId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		points: BalanceOf<T>,
+	) -> DispatchResult {
+		ensure!(from != to, Error::<T>::NothingToMove);
+		Self::apply_pending_slash(from)?;
+		if PoolMembers::<T>::contains_key(to) {
+			Self::apply_pending_slash(to)?;
+		}
+
+		let (mut from_member, mut bonded_pool, mut reward_pool) =
+			Self::get_member_with_pools(from)?;
+		debug_assert_eq!(from_member.pool_id, pool_id);
+		ensure!(from_member.active_points() >= points, Error::<T>::NotEnoughPoints);
+
+		reward_pool.update_records(
+			bonded_pool.id,
+			bonded_pool.points,
+			bonded_pool.commission.current(),
+		)?;
+		Self::do_reward_payout(from, &mut from_member, &mut bonded_pool, &mut reward_pool)?;
+
+		let mut to_member = match PoolMembers::<T>::get(to) {
+			Some(mut to_member) => {
+				ensure!(to_member.pool_id == pool_id, Error::<T>::AccountBelongsToOtherPool);
+				if !to_member.active_points().is_zero() {
+					Self::do_reward_payout(to, &mut to_member, &mut bonded_pool, &mut reward_pool)?;
+				}
+				to_member
+			},
+			None => {
+				bonded_pool.try_inc_members()?;
+				Self::register_if_auto_compounding(pool_id, to);
+				PoolMember::<T> {
+					pool_id,
+					points: Zero::zero(),
+					last_recorded_reward_counter: Zero::zero(),
+					unbonding_eras: Default::default(),
+				}
+			},
+		};
+		// the moved points only earn rewards from now on.
+		to_member.last_recorded_reward_counter = reward_pool.last_recorded_reward_counter();
+
+		let delegation = if from_member.total_points() == points {
+			// move all of the delegation so that `from` is not left with a dangling delegation.
+			T::StakeAdapter::member_delegation_balance(Member::from(from.clone()))
+				.unwrap_or_else(|| bonded_pool.points_to_balance(points))
+		} else {
+			bonded_pool.points_to_balance(points)
+		};
+		T::StakeAdapter::member_transfer(
+			Member::from(from.clone()),
+			Member::from(to.clone()),
+			Pool::from(bonded_pool.bonded_account()),
+			delegation,
+		)?;
+
+		from_member.points = from_member.points.saturating_sub(points);
+		to_member.points = to_member.points.checked_add(&points).ok_or(Error::<T>::OverflowRisk)?;
+
+		PoolMembers::<T>::insert(to, to_member);
+		if from_member.total_points().is_zero() {
+			ClaimPermissions::<T>::remove(from);
+			PoolMembers::<T>::remove(from);
+			bonded_pool = bonded_pool.dec_members();
+			Self::deposit_event(Event::<T>::MemberRemoved {
+				pool_id,
+				member: from.clone(),
+				released_balance: Zero::zero(),
+			});
+		} else {
+			PoolMembers::<T>::insert(from, from_member);
+		}
+		bonded_pool.put();
+		RewardPools::<T>::insert(pool_id, reward_pool);
+
+		Ok(())
+	}
+
+	fn do_tokenize_points(who: T::AccountId, points: BalanceOf<T>) -> DispatchResult {
+		ensure!(
+			T::StakeAdapter::strategy_type() == adapter::StakeStrategyType::Delegate,
+			Error::<T>::NotSupported
+		);
+		ensure!(!T::Filter::contains(&who), Error::<T>::Restricted);
+		ensure!(!points.is_zero(), Error::<T>::NothingToMove);
+
+		let member = PoolMembers::<T>::get(&who).ok_or(Error::<T>::PoolMemberNotFound)?;
+		let pool_id = member.pool_id;
+		let liquid_account = Self::generate_liquid_account(pool_id);
+		ensure!(who != liquid_account, Error::<T>::LiquidAccount);
+		ensure!(AutoCompoundPools::<T>::contains_key(pool_id), Error::<T>::NotAutoCompounding);
+
+		let bonded_pool = BondedPool::<T>::get(pool_id)
+			.defensive_ok_or::<Error<T>>(DefensiveError::PoolNotFound.into())?;
+		ensure!(bonded_pool.state == PoolState::Open, Error::<T>::NotOpen);
+		ensure!(
+			T::StakeAdapter::pool_strategy(Pool::from(bonded_pool.bonded_account())) ==
+				adapter::StakeStrategyType::Delegate,
+			Error::<T>::NotMigrated
+		);
+		ensure!(!Self::api_member_needs_delegate_migration(who.clone()), Error::<T>::NotMigrated);
+		ensure!(points <= member.active_points(), Error::<T>::NotEnoughPoints);
+
+		// the points left behind are subject to the same minimum bond as for unbonding.
+		let is_full = points == member.active_points();
+		let balance_left =
+			bonded_pool.points_to_balance(member.active_points().saturating_sub(points));
+		let min_bond_met = if who == bonded_pool.roles.depositor {
+			!is_full && balance_left >= Self::depositor_min_bond()
+		} else {
+			is_full || balance_left >= MinJoinBond::<T>::get()
+		};
+		ensure!(min_bond_met, Error::<T>::MinimumBondNotMet);
+
+		// the liquid points are priced against the liquid account including its latest rewards.
+		Self::do_compound_liquid(pool_id)?;
+		let liquid_member = PoolMembers::<T>::get(&liquid_account);
+		let liquid_points = liquid_member.as_ref().map_or(Zero::zero(), |m| m.active_points());
+		// a new member must hold enough to exist, as for joining the pool.
+		ensure!(
+			liquid_member.is_some() ||
+				bonded_pool.points_to_balance(points) >= Self::min_new_member_balance(),
+			Error::<T>::MinimumBondNotMet
+		);
+		let tokens = Self::balance_to_point(liquid_points, LiquidSupply::<T>::get(pool_id), points);
+		ensure!(!tokens.is_zero(), Error::<T>::NothingToMove);
+
+		if liquid_member.is_none() {
+			// the liquid account needs an existential deposit to receive and bond its rewards,
+			// provided by whoever tokenizes the first points.
+			let missing = T::Currency::minimum_balance()
+				.saturating_sub(T::Currency::balance(&liquid_account));
+			if !missing.is_zero() {
+				T::Currency::transfer(&who, &liquid_account, missing, Preservation::Preserve)?;
+			}
+		}
+		Self::do_move_points(pool_id, &who, &liquid_account, points)?;
+		if liquid_member.is_none() {
+			// allow anyone to compound the rewards of the liquid account.
+			ClaimPermissions::<T>::insert(&liquid_account, ClaimPermission::PermissionlessCompound);
+		}
+		<Self as fungibles::Mutate<_>>::mint_into(pool_id, &who, tokens)?;
+
+		Self::deposit_event(Event::<T>::PointsTokenized { member: who, pool_id, points, tokens });
+		Ok(())
+	}
+
+	fn do_redeem_points(
+		who: T::AccountId,
+		pool_id: PoolId,
+		tokens: BalanceOf<T>,
+	) -> DispatchResult {
+		ensure!(!T::Filter::contains(&who), Error::<T>::Restricted);
+		ensure!(!tokens.is_zero(), Error::<T>::NothingToMove);
+		ensure!(BondedPools::<T>::contains_key(pool_id), Error::<T>::PoolNotFound);
+
+		let liquid_account = Self::generate_liquid_account(pool_id);
+		ensure!(who != liquid_account, Error::<T>::LiquidAccount);
+		let is_member = match PoolMembers::<T>::get(&who) {
+			Some(member) => {
+				ensure!(member.pool_id == pool_id, Error::<T>::AccountBelongsToOtherPool);
+				true
+			},
+			None => false,
+		};
+		ensure!(!Self::api_member_needs_delegate_migration(who.clone()), Error::<T>::NotMigrated);
+
+		Self::do_compound_liquid(pool_id)?;
+		let liquid_points = PoolMembers::<T>::get(&liquid_account)
+			.map_or(Zero::zero(), |member| member.active_points());
+		let supply = LiquidSupply::<T>::get(pool_id);
+		let points = if tokens == supply {
+			// the last liquid points redeem everything, leaving no dust behind.
+			liquid_points
+		} else {
+			Self::point_to_balance(liquid_points, supply, tokens)
+		};
+		ensure!(!points.is_zero(), Error::<T>::NothingToMove);
+
+		let bonded_pool = BondedPool::<T>::get(pool_id)
+			.defensive_ok_or::<Error<T>>(DefensiveError::PoolNotFound.into())?;
+		ensure!(
+			is_member || bonded_pool.points_to_balance(points) >= Self::min_new_member_balance(),
+			Error::<T>::MinimumBondNotMet
+		);
+
+		<Self as fungibles::Mutate<_>>::burn_from(
+			pool_id,
+			&who,
+			tokens,
+			Preservation::Expendable,
+			Precision::Exact,
+			Fortitude::Polite,
+		)?;
+		Self::do_move_points(pool_id, &liquid_account, &who, points)?;
+
+		Self::deposit_event(Event::<T>::PointsRedeemed { member: who, pool_id, points, tokens });
+		Ok(())
+	}
+
+	/// Bond the pending rewards of the members of auto-compounding pools, once per era.
+	///
+	/// A pass over all [`AutoCompoundMembers`] may span several blocks, in which case
+	/// [`CompoundingCursor`] records where the next block resumes. Returns the weight consumed.
+	pub(crate) fn do_compound_pools(remaining_weight: Weight) -> Weight {
+		let db_weight = T::DbWeight::get();
+		let mut meter = WeightMeter::with_limit(remaining_weight);
+		if meter.try_consume(db_weight.reads(1)).is_err() ||
+			AutoCompoundPools::<T>::count().is_zero()
+		{
+			return meter.consumed()
+		}
+		// includes updating the cursor and the era at the end of this block.
+		if meter.try_consume(db_weight.reads_writes(3, 2)).is_err() {
+			return meter.consumed()
+		}
+
+		let cursor = CompoundingCursor::<T>::get();
+		let current_era = T::StakeAdapter::current_era();
+		if cursor.is_none() && LastCompoundedEra::<T>::get().map_or(false, |era| era >= current_era)
+		{
+			// all members have been compounded in this era already.
+			return meter.consumed()
+		}
+
+		let mut members = match cursor {
+			Some((pool_id, who)) => AutoCompoundMembers::<T>::iter_keys_from(
+				AutoCompoundMembers::<T>::hashed_key_for(pool_id, who),
+			),
+			None => AutoCompoundMembers::<T>::iter_keys(),
+		};
+		let compound_weight = db_weight.reads(3).saturating_add(T::WeightInfo::bond_extra_other());
+		let mut last_visited = None;
+		loop {
+			// the next member, their pool and whether it is auto-compounding, or removing a stale
+			// entry.
+			if meter.try_consume(db_weight.reads_writes(3, 1)).is_err() {
+				break
+			}
+			let Some((pool_id, who)) = members.next() else {
+				CompoundingCursor::<T>::kill();
+				LastCompoundedEra::<T>::put(current_era);
+				return meter.consumed()
+			};
+
+			let is_member = PoolMembers::<T>::get(&who).map_or(false, |m| m.pool_id == pool_id);
+			if !is_member || !AutoCompoundPools::<T>::contains_key(pool_id) {
+				AutoCompoundMembers::<T>::remove(pool_id, &who);
+			} else if !Self::api_member_needs_delegate_migration(who.clone()) {
+				if meter.try_consume(compound_weight).is_err() {
+					break
+				}
+				if Self::api_pending_rewards(who.clone()).map_or(false, |r| !r.is_zero()) {
+					// a failure, e.g. of a restricted member or a destroying pool, must not stop
+					// the pass.
+					let _ = with_storage_layer(|| {
+						Self::do_bond_extra(who.clone(), who.clone(), BondExtra::Rewards)
+					});
+				}
+			}
+			last_visited = Some((pool_id, who));
+		}
+
+		if let Some(last) = last_visited {
+			CompoundingCursor::<T>::put(last);
+		}
+		meter.consumed()
+	}
+
+	/// Register `who` for compounding if they are a new member of an auto-compounding pool.
+	fn register_if_auto_compounding(pool_id: PoolId, who: &T::AccountId) {
+		if AutoCompoundPools::<T>::contains_key(pool_id) {
+			AutoCompoundMembers::<T>::insert(pool_id, who, ());
+		}
+	}
+
 	/// Apply freeze on reward account to restrict it from going below ED.
 	pub(crate) fn freeze_pool_deposit(reward_acc: &T::AccountId) -> DispatchResult {
 		T::Currency::set_freeze(
@@ -3937,6 +4525,20 @@ impl<T: Config> Pallet<T> {
 			Ok(())
 		})?;
 
+		LiquidSupply::<T>::iter().try_for_each(|(id, supply)| -> Result<(), TryRuntimeError> {
+			ensure!(
+				PoolMembers::<T>::contains_key(Self::generate_liquid_account(id)),
+				"Liquid points must be backed by the points of the liquid account"
+			);
+			ensure!(
+				LiquidBalances::<T>::iter_prefix_values(id)
+					.fold(BalanceOf::<T>::zero(), |acc, balance| acc.saturating_add(balance)) ==
+					supply,
+				"The liquid supply must be equal to the sum of all liquid balances"
+			);
+			Ok(())
+		})?;
+
 		let mut expected_tvl: BalanceOf<T> = Default::default();
 		BondedPools::<T>::iter().try_for_each(|(id, inner)| -> Result<(), TryRuntimeError> {
 			let bonded_pool = BondedPool { id, inner };
@@ -0,0 +1,160 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Liquid points of auto-compounding pools, exposed as a [`fungibles`] asset per [`PoolId`].
+//!
+//! Each liquid point token is a claim on a share of the points held by the pool's liquid account
+//! (see [`Pallet::generate_liquid_account`]). Tokens are minted by [`Call::tokenize_points`] and
+//! burnt by [`Call::redeem_points`]. Any other minting or burning through these implementations
+//! dilutes or concentrates the claims of the existing token holders, so the `Unbalanced` and
+//! `Mutate` implementations should only be handed to trusted components of the runtime.
+//!
+//! The first liquid points of a pool are issued one to one with its points, so the existential
+//! deposit of the native currency is used as the minimum balance of every holder. Balances left
+//! below it are burnt as dust, raising the value of the other liquid points of the pool.
+
+use crate::*;
+use frame_support::traits::tokens::{
+	fungibles, DepositConsequence, Fortitude, Preservation, Provenance, WithdrawConsequence,
+};
+
+impl<T: Config> fungibles::Inspect<T::AccountId> for Pallet<T> {
+	type AssetId = PoolId;
+	type Balance = BalanceOf<T>;
+
+	fn total_issuance(pool_id: PoolId) -> BalanceOf<T> {
+		LiquidSupply::<T>::get(pool_id)
+	}
+
+	fn minimum_balance(_pool_id: PoolId) -> BalanceOf<T> {
+		T::Currency::minimum_balance()
+	}
+
+	fn total_balance(pool_id: PoolId, who: &T::AccountId) -> BalanceOf<T> {
+		LiquidBalances::<T>::get(pool_id, who)
+	}
+
+	fn balance(pool_id: PoolId, who: &T::AccountId) -> BalanceOf<T> {
+		LiquidBalances::<T>::get(pool_id, who)
+	}
+
+	fn reducible_balance(
+		pool_id: PoolId,
+		who: &T::AccountId,
+		preservation: Preservation,
+		_force: Fortitude,
+	) -> BalanceOf<T> {
+		let balance = LiquidBalances::<T>::get(pool_id, who);
+		match preservation {
+			Preservation::Expendable => balance,
+			Preservation::Protect | Preservation::Preserve =>
+				balance.saturating_sub(Self::minimum_balance(pool_id)),
+		}
+	}
+
+	fn can_deposit(
+		pool_id: PoolId,
+		who: &T::AccountId,
+		amount: BalanceOf<T>,
+		provenance: Provenance,
+	) -> DepositConsequence {
+		if !BondedPools::<T>::contains_key(pool_id) {
+			return DepositConsequence::UnknownAsset
+		}
+		if provenance == Provenance::Minted &&
+			LiquidSupply::<T>::get(pool_id).checked_add(&amount).is_none()
+		{
+			return DepositConsequence::Overflow
+		}
+		match LiquidBalances::<T>::get(pool_id, who).checked_add(&amount) {
+			None => DepositConsequence::Overflow,
+			Some(new_balance) if new_balance < Self::minimum_balance(pool_id) =>
+				DepositConsequence::BelowMinimum,
+			Some(_) => DepositConsequence::Success,
+		}
+	}
+
+	fn can_withdraw(
+		pool_id: PoolId,
+		who: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> WithdrawConsequence<BalanceOf<T>> {
+		if amount.is_zero() {
+			return WithdrawConsequence::Success
+		}
+		if !BondedPools::<T>::contains_key(pool_id) {
+			return WithdrawConsequence::UnknownAsset
+		}
+		if LiquidSupply::<T>::get(pool_id).checked_sub(&amount).is_none() {
+			return WithdrawConsequence::Underflow
+		}
+		match LiquidBalances::<T>::get(pool_id, who).checked_sub(&amount) {
+			None => WithdrawConsequence::BalanceLow,
+			Some(rest) if !rest.is_zero() && rest < Self::minimum_balance(pool_id) =>
+				WithdrawConsequence::ReducedToZero(rest),
+			Some(_) => WithdrawConsequence::Success,
+		}
+	}
+
+	fn asset_exists(pool_id: PoolId) -> bool {
+		BondedPools::<T>::contains_key(pool_id)
+	}
+}
+
+impl<T: Config> fungibles::Unbalanced<T::AccountId> for Pallet<T> {
+	fn handle_dust(dust: fungibles::Dust<T::AccountId, Self>) {
+		// the dust is burnt, its points stay with the liquid account.
+		LiquidSupply::<T>::mutate(dust.0, |supply| supply.saturating_reduce(dust.1));
+	}
+
+	fn write_balance(
+		pool_id: PoolId,
+		who: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> Result<Option<BalanceOf<T>>, DispatchError> {
+		if amount < Self::minimum_balance(pool_id) {
+			LiquidBalances::<T>::remove(pool_id, who);
+			return Ok((!amount.is_zero()).then_some(amount))
+		}
+		LiquidBalances::<T>::insert(pool_id, who, amount);
+		Ok(None)
+	}
+
+	fn set_total_issuance(pool_id: PoolId, amount: BalanceOf<T>) {
+		if amount.is_zero() {
+			LiquidSupply::<T>::remove(pool_id);
+		} else {
+			LiquidSupply::<T>::insert(pool_id, amount);
+		}
+	}
+}
+
+impl<T: Config> fungibles::Mutate<T::AccountId> for Pallet<T> {
+	fn done_transfer(
+		pool_id: PoolId,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: BalanceOf<T>,
+	) {
+		Self::deposit_event(Event::<T>::LiquidPointsTransferred {
+			pool_id,
+			from: source.clone(),
+			to: dest.clone(),
+			amount,
+		});
+	}
+}
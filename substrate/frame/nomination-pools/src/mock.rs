@@ -337,6 +337,29 @@ impl DelegationInterface for DelegateMock {
 		Ok(())
 	}
 
+	fn transfer_delegation(
+		_agent: Agent<Self::AccountId>,
+		from: Delegator<Self::AccountId>,
+		to: Delegator<Self::AccountId>,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		let (from, to) = (from.get(), to.get());
+		let mut delegators = DelegatorBalanceMap::get();
+		let remaining = delegators
+			.get(&from)
+			.and_then(|b| b.checked_sub(amount))
+			.ok_or(DispatchError::Other("not enough delegation"))?;
+		if remaining == 0 {
+			delegators.remove(&from);
+		} else {
+			delegators.insert(from, remaining);
+		}
+		delegators.entry(to).and_modify(|b| *b += amount).or_insert(amount);
+		DelegatorBalanceMap::set(&delegators);
+
+		Ok(())
+	}
+
 	fn pending_slash(agent: Agent<Self::AccountId>) -> Option<Self::Balance> {
 		AgentBalanceMap::get()
 			.get(&agent.get())
@@ -7638,3 +7638,402 @@ mod filter {
 		});
 	}
 }
+
+mod liquid_points {
+	use super::*;
+	use frame_support::traits::{fungibles::Inspect as FungiblesInspect, Hooks};
+	use sp_runtime::TokenError;
+
+	fn liquid_account() -> AccountId {
+		Pools::generate_liquid_account(1)
+	}
+
+	#[test]
+	fn set_auto_compound_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			// only the root of the pool or the root origin can change it.
+			assert_noop!(
+				Pools::set_auto_compound(RuntimeOrigin::signed(10), 1, true),
+				Error::<Runtime>::DoesNotHavePermission
+			);
+			assert_noop!(
+				Pools::set_auto_compound(RuntimeOrigin::signed(900), 2, true),
+				Error::<Runtime>::PoolNotFound
+			);
+
+			assert_ok!(Pools::set_auto_compound(RuntimeOrigin::signed(900), 1, true));
+			assert!(AutoCompoundPools::<Runtime>::contains_key(1));
+			assert_eq!(AutoCompoundPools::<Runtime>::count(), 1);
+
+			assert_ok!(Pools::set_auto_compound(RuntimeOrigin::root(), 1, false));
+			assert!(!AutoCompoundPools::<Runtime>::contains_key(1));
+
+			// cannot be changed once the pool is being destroyed.
+			unsafe_set_state(1, PoolState::Destroying);
+			assert_noop!(
+				Pools::set_auto_compound(RuntimeOrigin::signed(900), 1, true),
+				Error::<Runtime>::CanNotChangeState
+			);
+
+			assert_eq!(
+				pool_events_since_last_call(),
+				vec![
+					Event::Created { depositor: 10, pool_id: 1 },
+					Event::Bonded { member: 10, pool_id: 1, bonded: 10, joined: true },
+					Event::MetadataUpdated { pool_id: 1, caller: 900 },
+					Event::AutoCompoundSet { pool_id: 1, enabled: true },
+					Event::AutoCompoundSet { pool_id: 1, enabled: false },
+				]
+			);
+		})
+	}
+
+	#[test]
+	fn on_idle_compounds_once_per_era() {
+		ExtBuilder::default().add_members(vec![(20, 20)]).build_and_execute(|| {
+			assert_ok!(Pools::set_auto_compound(RuntimeOrigin::signed(900), 1, true));
+			assert_ok!(Pools::register_auto_compound_member(RuntimeOrigin::signed(30), 10));
+			assert_ok!(Pools::register_auto_compound_member(RuntimeOrigin::signed(30), 20));
+			// 30 claimable rewards, 10 for 10 and 20 for 20.
+			Currency::set_balance(&default_reward_account(), ExistentialDeposit::get() + 30);
+
+			// when
+			Pools::on_idle(System::block_number(), Weight::MAX);
+
+			// then
+			assert_eq!(PoolMembers::<Runtime>::get(10).unwrap().points, 20);
+			assert_eq!(PoolMembers::<Runtime>::get(20).unwrap().points, 40);
+			assert_eq!(member_delegation(20), 40);
+			assert_eq!(BondedPools::<Runtime>::get(1).unwrap().points, 60);
+			assert_eq!(LastCompoundedEra::<Runtime>::get(), Some(0));
+			assert!(CompoundingCursor::<Runtime>::get().is_none());
+
+			// new rewards are not compounded again in the same era.
+			Currency::set_balance(&default_reward_account(), ExistentialDeposit::get() + 60);
+			Pools::on_idle(System::block_number(), Weight::MAX);
+			assert_eq!(BondedPools::<Runtime>::get(1).unwrap().points, 60);
+
+			// but they are in the next one.
+			CurrentEra::set(1);
+			Pools::on_idle(System::block_number(), Weight::MAX);
+			assert_eq!(PoolMembers::<Runtime>::get(10).unwrap().points, 40);
+			assert_eq!(PoolMembers::<Runtime>::get(20).unwrap().points, 80);
+			assert_eq!(LastCompoundedEra::<Runtime>::get(), Some(1));
+		})
+	}
+
+	#[test]
+	fn on_idle_compounding_resumes_from_cursor() {
+		ExtBuilder::default().add_members(vec![(20, 20)]).build_and_execute(|| {
+			assert_ok!(Pools::set_auto_compound(RuntimeOrigin::signed(900), 1, true));
+			assert_ok!(Pools::register_auto_compound_member(RuntimeOrigin::signed(30), 10));
+			assert_ok!(Pools::register_auto_compound_member(RuntimeOrigin::signed(30), 20));
+			Currency::set_balance(&default_reward_account(), ExistentialDeposit::get() + 30);
+
+			// the weight of compounding a single member.
+			let limit = <Runtime as Config>::WeightInfo::bond_extra_other();
+
+			// when
+			Pools::on_idle(System::block_number(), limit);
+
+			// then only one of the members is compounded, whose rewards are equal to their account
+			// id.
+			let (pool_id, cursor) = CompoundingCursor::<Runtime>::get().unwrap();
+			assert_eq!(pool_id, 1);
+			assert_eq!(BondedPools::<Runtime>::get(1).unwrap().points, 30 + cursor);
+			assert_eq!(LastCompoundedEra::<Runtime>::get(), None);
+
+			// when
+			Pools::on_idle(System::block_number(), limit);
+
+			// then the pass completes.
+			assert_eq!(BondedPools::<Runtime>::get(1).unwrap().points, 60);
+			assert!(CompoundingCursor::<Runtime>::get().is_none());
+			assert_eq!(LastCompoundedEra::<Runtime>::get(), Some(0));
+		})
+	}
+
+	#[test]
+	fn register_auto_compound_member_works() {
+		ExtBuilder::default().add_members(vec![(20, 20)]).build_and_execute(|| {
+			assert_noop!(
+				Pools::register_auto_compound_member(RuntimeOrigin::signed(30), 20),
+				Error::<Runtime>::NotAutoCompounding
+			);
+			assert_ok!(Pools::set_auto_compound(RuntimeOrigin::signed(900), 1, true));
+			assert_noop!(
+				Pools::register_auto_compound_member(RuntimeOrigin::signed(30), 30),
+				Error::<Runtime>::PoolMemberNotFound
+			);
+
+			// when anyone registers a member that joined before auto-compounding was enabled.
+			let result = Pools::register_auto_compound_member(RuntimeOrigin::signed(30), 20);
+
+			// then it is free.
+			assert_eq!(result.unwrap().pays_fee, Pays::No);
+			assert!(AutoCompoundMembers::<Runtime>::contains_key(1, 20));
+			assert_noop!(
+				Pools::register_auto_compound_member(RuntimeOrigin::signed(30), 20),
+				Error::<Runtime>::AlreadyAutoCompounding
+			);
+
+			// members joining from now on are registered automatically.
+			Currency::set_balance(&30, 100);
+			assert_ok!(Pools::join(RuntimeOrigin::signed(30), 10, 1));
+			assert!(AutoCompoundMembers::<Runtime>::contains_key(1, 30));
+			assert!(!AutoCompoundMembers::<Runtime>::contains_key(1, 10));
+
+			assert_eq!(
+				pool_events_since_last_call(),
+				vec![
+					Event::Created { depositor: 10, pool_id: 1 },
+					Event::Bonded { member: 10, pool_id: 1, bonded: 10, joined: true },
+					Event::MetadataUpdated { pool_id: 1, caller: 900 },
+					Event::Bonded { member: 20, pool_id: 1, bonded: 20, joined: true },
+					Event::AutoCompoundSet { pool_id: 1, enabled: true },
+					Event::AutoCompoundMemberRegistered { member: 20, pool_id: 1 },
+					Event::Bonded { member: 30, pool_id: 1, bonded: 10, joined: true },
+				]
+			);
+		})
+	}
+
+	#[test]
+	fn on_idle_only_visits_registered_members() {
+		ExtBuilder::default().add_members(vec![(20, 20)]).build_and_execute(|| {
+			assert_ok!(Pools::set_auto_compound(RuntimeOrigin::signed(900), 1, true));
+			assert_ok!(Pools::register_auto_compound_member(RuntimeOrigin::signed(30), 20));
+			Currency::set_balance(&default_reward_account(), ExistentialDeposit::get() + 30);
+
+			// when
+			Pools::on_idle(System::block_number(), Weight::MAX);
+
+			// then the unregistered depositor is not compounded.
+			assert_eq!(PoolMembers::<Runtime>::get(10).unwrap().points, 10);
+			assert_eq!(PoolMembers::<Runtime>::get(20).unwrap().points, 40);
+
+			// when 20 leaves the pool.
+			assert_ok!(fully_unbond_permissioned(20));
+			CurrentEra::set(3);
+			assert_ok!(Pools::withdraw_unbonded(RuntimeOrigin::signed(20), 20, 0));
+			assert!(AutoCompoundMembers::<Runtime>::contains_key(1, 20));
+			Pools::on_idle(System::block_number(), Weight::MAX);
+
+			// then their entry is removed by the next pass.
+			assert!(!AutoCompoundMembers::<Runtime>::contains_key(1, 20));
+			assert_eq!(LastCompoundedEra::<Runtime>::get(), Some(3));
+
+			// entries of pools that stopped auto-compounding are removed as well.
+			assert_ok!(Pools::register_auto_compound_member(RuntimeOrigin::signed(30), 10));
+			assert_ok!(Pools::set_auto_compound(RuntimeOrigin::signed(900), 1, false));
+			assert_ok!(Pools::create(RuntimeOrigin::signed(20), 10, 20, 20, 20));
+			assert_ok!(Pools::set_auto_compound(RuntimeOrigin::signed(20), 2, true));
+			CurrentEra::set(4);
+			Pools::on_idle(System::block_number(), Weight::MAX);
+			assert!(!AutoCompoundMembers::<Runtime>::contains_key(1, 10));
+			assert_eq!(PoolMembers::<Runtime>::get(10).unwrap().points, 10);
+		})
+	}
+
+	#[test]
+	fn tokenize_points_handles_errors() {
+		ExtBuilder::default().add_members(vec![(20, 20)]).build_and_execute(|| {
+			assert_noop!(
+				Pools::tokenize_points(RuntimeOrigin::signed(20), 10),
+				Error::<Runtime>::NotAutoCompounding
+			);
+			assert_ok!(Pools::set_auto_compound(RuntimeOrigin::signed(900), 1, true));
+
+			assert_noop!(
+				Pools::tokenize_points(RuntimeOrigin::signed(30), 10),
+				Error::<Runtime>::PoolMemberNotFound
+			);
+			assert_noop!(
+				Pools::tokenize_points(RuntimeOrigin::signed(20), 0),
+				Error::<Runtime>::NothingToMove
+			);
+			assert_noop!(
+				Pools::tokenize_points(RuntimeOrigin::signed(20), 21),
+				Error::<Runtime>::NotEnoughPoints
+			);
+			// 19 points would leave 20 with less than the minimum join bond.
+			assert_noop!(
+				Pools::tokenize_points(RuntimeOrigin::signed(20), 19),
+				Error::<Runtime>::MinimumBondNotMet
+			);
+			// the depositor cannot go below the minimum create bond.
+			assert_noop!(
+				Pools::tokenize_points(RuntimeOrigin::signed(10), 1),
+				Error::<Runtime>::MinimumBondNotMet
+			);
+			// the first points must be enough for the liquid account to become a member.
+			assert_noop!(
+				Pools::tokenize_points(RuntimeOrigin::signed(20), 1),
+				Error::<Runtime>::MinimumBondNotMet
+			);
+
+			unsafe_set_state(1, PoolState::Blocked);
+			assert_noop!(
+				Pools::tokenize_points(RuntimeOrigin::signed(20), 10),
+				Error::<Runtime>::NotOpen
+			);
+		})
+	}
+
+	#[test]
+	fn tokenize_and_redeem_points_works() {
+		ExtBuilder::default().add_members(vec![(20, 20)]).build_and_execute(|| {
+			assert_ok!(Pools::set_auto_compound(RuntimeOrigin::signed(900), 1, true));
+			let ed = ExistentialDeposit::get();
+			let free_before = Currency::free_balance(&20);
+
+			// when
+			assert_ok!(Pools::tokenize_points(RuntimeOrigin::signed(20), 10));
+
+			// then the first liquid points are issued one to one.
+			assert_eq!(PoolMembers::<Runtime>::get(20).unwrap().points, 10);
+			assert_eq!(PoolMembers::<Runtime>::get(liquid_account()).unwrap().points, 10);
+			assert_eq!(BondedPools::<Runtime>::get(1).unwrap().points, 30);
+			assert_eq!(BondedPools::<Runtime>::get(1).unwrap().member_counter, 3);
+			assert_eq!(LiquidBalances::<Runtime>::get(1, 20), 10);
+			assert_eq!(Pools::total_issuance(1), 10);
+			assert_eq!(member_delegation(20), 10);
+			assert_eq!(member_delegation(liquid_account()), 10);
+			// 20 provided the existential deposit of the liquid account.
+			assert_eq!(Currency::free_balance(&20), free_before - ed);
+			assert_eq!(Currency::free_balance(&liquid_account()), ed);
+			assert_eq!(
+				ClaimPermissions::<Runtime>::get(liquid_account()),
+				ClaimPermission::PermissionlessCompound
+			);
+
+			// the liquid account cannot be unbonded.
+			assert_noop!(
+				Pools::unbond(RuntimeOrigin::signed(liquid_account()), liquid_account(), 10),
+				Error::<Runtime>::LiquidAccount
+			);
+
+			// 30 claimable rewards, 10 for each of 10, 20 and the liquid account.
+			Currency::set_balance(&default_reward_account(), ed + 30);
+
+			// when 20 redeems half of their liquid points.
+			assert_ok!(Pools::redeem_points(RuntimeOrigin::signed(20), 1, 5));
+
+			// then the rewards of the liquid account are compounded first, doubling the value of
+			// each liquid point.
+			assert_eq!(PoolMembers::<Runtime>::get(20).unwrap().points, 20);
+			assert_eq!(PoolMembers::<Runtime>::get(liquid_account()).unwrap().points, 10);
+			assert_eq!(BondedPools::<Runtime>::get(1).unwrap().points, 40);
+			assert_eq!(LiquidBalances::<Runtime>::get(1, 20), 5);
+			assert_eq!(Pools::total_issuance(1), 5);
+			assert_eq!(member_delegation(20), 20);
+			assert_eq!(member_delegation(liquid_account()), 10);
+
+			// when 20 gives their remaining liquid points to 30, who redeems them all.
+			assert_ok!(Pools::transfer_liquid_points(RuntimeOrigin::signed(20), 1, 30, 5));
+			assert_eq!(LiquidBalances::<Runtime>::get(1, 20), 0);
+			assert_eq!(LiquidBalances::<Runtime>::get(1, 30), 5);
+			assert_ok!(Pools::redeem_points(RuntimeOrigin::signed(30), 1, 5));
+
+			// then 30 joined the pool and the liquid account is gone.
+			assert_eq!(PoolMembers::<Runtime>::get(30).unwrap().points, 10);
+			assert_eq!(member_delegation(30), 10);
+			assert!(!PoolMembers::<Runtime>::contains_key(liquid_account()));
+			assert_eq!(Pools::total_issuance(1), 0);
+			assert_eq!(BondedPools::<Runtime>::get(1).unwrap().member_counter, 3);
+
+			assert_eq!(
+				pool_events_since_last_call(),
+				vec![
+					Event::Created { depositor: 10, pool_id: 1 },
+					Event::Bonded { member: 10, pool_id: 1, bonded: 10, joined: true },
+					Event::MetadataUpdated { pool_id: 1, caller: 900 },
+					Event::Bonded { member: 20, pool_id: 1, bonded: 20, joined: true },
+					Event::AutoCompoundSet { pool_id: 1, enabled: true },
+					Event::PointsTokenized { member: 20, pool_id: 1, points: 10, tokens: 10 },
+					Event::PaidOut { member: liquid_account(), pool_id: 1, payout: 10 },
+					Event::Bonded {
+						member: liquid_account(),
+						pool_id: 1,
+						bonded: 10,
+						joined: false
+					},
+					Event::PaidOut { member: 20, pool_id: 1, payout: 10 },
+					Event::PointsRedeemed { member: 20, pool_id: 1, points: 10, tokens: 5 },
+					Event::LiquidPointsTransferred { pool_id: 1, from: 20, to: 30, amount: 5 },
+					Event::MemberRemoved {
+						pool_id: 1,
+						member: liquid_account(),
+						released_balance: 0
+					},
+					Event::PointsRedeemed { member: 30, pool_id: 1, points: 10, tokens: 5 },
+				]
+			);
+		})
+	}
+
+	#[test]
+	fn liquid_points_respect_minimum_balance() {
+		ExtBuilder::default().add_members(vec![(20, 20)]).build_and_execute(|| {
+			assert_ok!(Pools::set_auto_compound(RuntimeOrigin::signed(900), 1, true));
+			assert_ok!(Pools::tokenize_points(RuntimeOrigin::signed(20), 10));
+			let ed = ExistentialDeposit::get();
+			assert_eq!(Pools::minimum_balance(1), ed);
+
+			// a new holder must receive at least the minimum balance.
+			assert_noop!(
+				Pools::transfer_liquid_points(RuntimeOrigin::signed(20), 1, 30, ed - 1),
+				TokenError::BelowMinimum
+			);
+
+			// when 20 keeps less than the minimum balance.
+			assert_ok!(Pools::transfer_liquid_points(RuntimeOrigin::signed(20), 1, 30, 7));
+
+			// then the rest is burnt as dust, and the points stay with the liquid account.
+			assert_eq!(LiquidBalances::<Runtime>::get(1, 30), 7);
+			assert!(!LiquidBalances::<Runtime>::contains_key(1, 20));
+			assert_eq!(Pools::total_issuance(1), 7);
+			assert_eq!(PoolMembers::<Runtime>::get(liquid_account()).unwrap().points, 10);
+
+			// and all of the points of the liquid account are redeemed by the last holder.
+			assert_ok!(Pools::redeem_points(RuntimeOrigin::signed(30), 1, 7));
+			assert_eq!(PoolMembers::<Runtime>::get(30).unwrap().points, 10);
+			assert!(!PoolMembers::<Runtime>::contains_key(liquid_account()));
+		})
+	}
+
+	#[test]
+	fn redeem_points_handles_errors() {
+		ExtBuilder::default()
+			.add_members(vec![(20, 20), (30, 30)])
+			.build_and_execute(|| {
+				assert_ok!(Pools::set_auto_compound(RuntimeOrigin::signed(900), 1, true));
+				assert_ok!(Pools::tokenize_points(RuntimeOrigin::signed(20), 10));
+
+				assert_noop!(
+					Pools::redeem_points(RuntimeOrigin::signed(20), 2, 5),
+					Error::<Runtime>::PoolNotFound
+				);
+				assert_noop!(
+					Pools::redeem_points(RuntimeOrigin::signed(20), 1, 0),
+					Error::<Runtime>::NothingToMove
+				);
+				// 30 has no liquid points.
+				assert_noop!(
+					Pools::redeem_points(RuntimeOrigin::signed(30), 1, 5),
+					TokenError::FundsUnavailable
+				);
+
+				// a new member must redeem at least the minimum join bond.
+				MinJoinBond::<Runtime>::put(8);
+				assert_ok!(Pools::transfer_liquid_points(RuntimeOrigin::signed(20), 1, 40, 5));
+				assert_noop!(
+					Pools::redeem_points(RuntimeOrigin::signed(40), 1, 5),
+					Error::<Runtime>::MinimumBondNotMet
+				);
+				MinJoinBond::<Runtime>::put(2);
+				assert_ok!(Pools::redeem_points(RuntimeOrigin::signed(40), 1, 5));
+				assert_eq!(PoolMembers::<Runtime>::get(40).unwrap().points, 5);
+			})
+	}
+}
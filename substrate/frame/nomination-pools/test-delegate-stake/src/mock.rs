@@ -207,6 +207,18 @@ impl pallet_nomination_pools::adapter::StakeStrategy for MockAdapter {
 		DelegateStake::member_withdraw(who, pool_account, amount, num_slashing_spans)
 	}
 
+	fn member_transfer(
+		from: Member<Self::AccountId>,
+		to: Member<Self::AccountId>,
+		pool_account: Pool<Self::AccountId>,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		if LegacyAdapter::get() {
+			return TransferStake::member_transfer(from, to, pool_account, amount)
+		}
+		DelegateStake::member_transfer(from, to, pool_account, amount)
+	}
+
 	fn dissolve(pool_account: Pool<Self::AccountId>) -> DispatchResult {
 		if LegacyAdapter::get() {
 			return TransferStake::dissolve(pool_account)
@@ -0,0 +1,175 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Payment Streams pallet benchmarking.
+
+use super::*;
+use crate::Pallet as PaymentStreams;
+use frame_benchmarking::{v2::*, whitelisted_caller};
+use frame_support::{
+	assert_ok,
+	traits::{Consideration, Footprint},
+};
+use frame_system::{Pallet as System, RawOrigin};
+use sp_runtime::traits::One;
+
+/// Benchmark Helper
+pub trait BenchmarkHelper<AssetKind, AccountId, Balance> {
+	/// Returns the asset to stream in benchmarks.
+	fn asset_kind() -> AssetKind;
+	/// Ensures `who` has at least `amount` of `asset_kind` available to place on hold, creating
+	/// the asset if needed.
+	fn fund(asset_kind: &AssetKind, who: &AccountId, amount: Balance);
+}
+
+/// The rate of the benchmarked stream and the amount held for it.
+fn stream_terms<T: Config>() -> (T::Balance, T::Balance) {
+	let asset_kind = T::BenchmarkHelper::asset_kind();
+	let rate = T::Assets::minimum_balance(asset_kind).max(T::Balance::one());
+	(rate, rate.saturating_mul(100u32.into()))
+}
+
+fn fund_sender<T: Config>(sender: &T::AccountId) {
+	let (_, amount) = stream_terms::<T>();
+	T::BenchmarkHelper::fund(
+		&T::BenchmarkHelper::asset_kind(),
+		sender,
+		amount.saturating_mul(10u32.into()),
+	);
+	let footprint = Footprint::from_mel::<(StreamId, StreamInfoFor<T>)>();
+	T::Consideration::ensure_successful(sender, footprint);
+}
+
+fn create_stream<T: Config>() -> (T::AccountId, T::AccountId) {
+	let sender: T::AccountId = whitelisted_caller();
+	let recipient: T::AccountId = account("recipient", 0, 0);
+	fund_sender::<T>(&sender);
+	let (rate, amount) = stream_terms::<T>();
+
+	assert_ok!(PaymentStreams::<T>::create_stream(
+		RawOrigin::Signed(sender.clone()).into(),
+		T::Lookup::unlookup(recipient.clone()),
+		Box::new(T::BenchmarkHelper::asset_kind()),
+		rate,
+		amount,
+	));
+
+	(sender, recipient)
+}
+
+/// Move the clock past the point where the benchmarked stream has paid out all its funds.
+fn run_stream_to_end<T: Config>() {
+	let now = T::Clock::current_block_number();
+	T::Clock::set_block_number(now.saturating_add(1_000u32.into()));
+}
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	System::<T>::assert_last_event(generic_event.into());
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn create_stream() {
+		let sender: T::AccountId = whitelisted_caller();
+		let recipient: T::AccountId = account("recipient", 0, 0);
+		fund_sender::<T>(&sender);
+		let (rate, amount) = stream_terms::<T>();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(sender.clone()),
+			T::Lookup::unlookup(recipient.clone()),
+			Box::new(T::BenchmarkHelper::asset_kind()),
+			rate,
+			amount,
+		);
+
+		assert_last_event::<T>(
+			Event::StreamCreated {
+				stream_id: 0,
+				sender,
+				recipient,
+				asset_kind: T::BenchmarkHelper::asset_kind(),
+				rate,
+				amount,
+			}
+			.into(),
+		);
+	}
+
+	#[benchmark]
+	fn withdraw() {
+		let (_, recipient) = create_stream::<T>();
+		// Worst case: the stream is depleted and removed.
+		run_stream_to_end::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(recipient), 0);
+
+		assert_last_event::<T>(Event::Depleted { stream_id: 0 }.into());
+	}
+
+	#[benchmark]
+	fn top_up() {
+		let (sender, _) = create_stream::<T>();
+		let (_, amount) = stream_terms::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(sender), 0, amount);
+
+		assert_last_event::<T>(Event::ToppedUp { stream_id: 0, amount }.into());
+	}
+
+	#[benchmark]
+	fn pause() {
+		let (sender, _) = create_stream::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(sender), 0);
+
+		assert_last_event::<T>(Event::Paused { stream_id: 0 }.into());
+	}
+
+	#[benchmark]
+	fn resume() {
+		let (sender, _) = create_stream::<T>();
+		assert_ok!(PaymentStreams::<T>::pause(RawOrigin::Signed(sender.clone()).into(), 0));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(sender), 0);
+
+		assert_last_event::<T>(Event::Resumed { stream_id: 0 }.into());
+	}
+
+	#[benchmark]
+	fn cancel() {
+		let (sender, _) = create_stream::<T>();
+		// Worst case: part of the funds are paid out and the rest refunded.
+		let now = T::Clock::current_block_number();
+		T::Clock::set_block_number(now.saturating_add(10u32.into()));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(sender), 0);
+
+		assert!(Streams::<T>::get(0).is_none());
+	}
+
+	impl_benchmark_test_suite!(PaymentStreams, crate::mock::new_test_ext(), crate::mock::Test);
+}
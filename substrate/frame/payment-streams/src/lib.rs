@@ -0,0 +1,648 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # FRAME Payment Streams Pallet
+//!
+//! Allows any account to pay another account continuously over time.
+//!
+//! ## Overview
+//!
+//! A sender opens a stream to a recipient by choosing an asset, a rate and an initial amount. The
+//! amount is placed on hold in the sender's account under [`HoldReason::StreamFunds`], and from
+//! then on accrues to the recipient at `rate` per unit of [`Config::Clock`].
+//!
+//! The clock is configurable: use `frame_system::Pallet` for per-block streams, a relay chain
+//! block number provider on parachains, or [`UnixSeconds`] for per-second streams.
+//!
+//! The recipient may withdraw whatever has accrued at any time. The withdrawn funds are moved
+//! straight out of the sender's hold into the recipient's free balance.
+//!
+//! The sender may:
+//! - top up the stream, placing more funds on hold;
+//! - pause the stream, stopping accrual until it is resumed;
+//! - cancel the stream, paying the recipient everything accrued up to that moment and releasing the
+//!   remainder back to the sender. If the accrued funds cannot be paid out, e.g. because the
+//!   recipient is blocked, they stay held for the recipient to withdraw later.
+//!
+//! A stream whose held funds have been fully withdrawn is removed.
+//!
+//! Any asset exposed through
+//! [`fungibles::MutateHold`](frame_support::traits::fungibles::MutateHold) can be streamed. The
+//! native currency and `pallet-assets` tokens can be combined with `UnionOf`, using
+//! `pallet-assets-holder` to provide holds for the latter.
+//!
+//! ## Storage Cost
+//!
+//! Opening a stream incurs a [`Config::Consideration`] for the stream information kept on-chain.
+//! It is dropped when the stream is cancelled or depleted.
+//!
+//! ## Runtime API
+//!
+//! [`PaymentStreamsApi`] exposes the amount currently claimable by a stream's recipient, without
+//! the need to replicate the settlement logic off-chain.
+#![deny(missing_docs)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub use pallet::*;
+
+use alloc::{boxed::Box, vec::Vec};
+use codec::{Codec, Decode, Encode, MaxEncodedLen};
+use core::marker::PhantomData;
+use frame_support::traits::{
+	fungibles::{Inspect, MutateHold},
+	tokens::Balance,
+	UnixTime,
+};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{BlockNumberProvider, Convert, Saturating, StaticLookup, Zero},
+	DispatchError,
+};
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+/// Unique id type for each stream.
+pub type StreamId = u32;
+
+/// A moment in time according to [`Config::Clock`].
+pub type MomentOf<T> = <<T as Config>::Clock as BlockNumberProvider>::BlockNumber;
+
+/// Convenience alias for `StreamInfo`.
+pub type StreamInfoFor<T> = StreamInfo<
+	<T as frame_system::Config>::AccountId,
+	<T as Config>::AssetKind,
+	<T as Config>::Balance,
+	MomentOf<T>,
+>;
+
+type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
+
+/// Whether a stream is accruing funds to its recipient.
+#[derive(Debug, Clone, Copy, Decode, Encode, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
+pub enum StreamStatus {
+	/// Funds accrue to the recipient.
+	Active,
+	/// Accrual is suspended until the sender resumes the stream.
+	Paused,
+	/// The sender cancelled the stream, but the accrued funds could not be paid out at the time.
+	/// They stay held until the recipient withdraws them.
+	Cancelled,
+}
+
+/// The state and configuration of a payment stream.
+#[derive(Debug, Clone, Decode, Encode, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
+pub struct StreamInfo<AccountId, AssetKind, Balance, Moment> {
+	/// The account funding the stream.
+	pub sender: AccountId,
+	/// The account the stream pays out to.
+	pub recipient: AccountId,
+	/// The asset being streamed.
+	pub asset_kind: AssetKind,
+	/// The amount accruing to the recipient per unit of [`Config::Clock`].
+	pub rate: Balance,
+	/// The sender's funds held for this stream, including the accrued amount.
+	pub held: Balance,
+	/// The amount accrued to the recipient up to `settled_at` and not yet withdrawn.
+	pub accrued: Balance,
+	/// The moment up to which `accrued` has been accounted for.
+	pub settled_at: Moment,
+	/// Whether the stream is accruing.
+	pub status: StreamStatus,
+}
+
+/// A [`BlockNumberProvider`] counting seconds since the Unix epoch, to be used as
+/// [`Config::Clock`] for per-second streams.
+pub struct UnixSeconds<Time>(PhantomData<Time>);
+impl<Time: UnixTime> BlockNumberProvider for UnixSeconds<Time> {
+	type BlockNumber = u64;
+
+	fn current_block_number() -> Self::BlockNumber {
+		Time::now().as_secs()
+	}
+}
+
+sp_api::decl_runtime_apis! {
+	/// The runtime API for the payment streams pallet.
+	pub trait PaymentStreamsApi<AccountId, AssetKind, Balance>
+	where
+		AccountId: Codec,
+		AssetKind: Codec,
+		Balance: Codec,
+	{
+		/// The amount the recipient of `stream_id` could withdraw right now.
+		///
+		/// Returns `None` if the stream does not exist.
+		fn claimable(stream_id: StreamId) -> Option<Balance>;
+
+		/// All streams paying out to `recipient`, with their asset and currently claimable amount.
+		fn claimable_by(recipient: AccountId) -> Vec<(StreamId, AssetKind, Balance)>;
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*,
+		storage::with_storage_layer,
+		traits::{
+			tokens::{Fortitude, Precision, Restriction},
+			Consideration, Footprint,
+		},
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{EnsureAdd, EnsureAddAssign, EnsureSub, EnsureSubAssign};
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// A reason for the pallet placing a hold on funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// Funds backing an open payment stream.
+		#[codec(index = 0)]
+		StreamFunds,
+		/// Cost associated with storing stream information on-chain.
+		#[codec(index = 1)]
+		StreamCreation,
+	}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Overarching event type.
+		#[allow(deprecated)]
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Identifier for each type of asset that can be streamed.
+		type AssetKind: Parameter + MaxEncodedLen;
+
+		/// The type in which the assets are measured.
+		type Balance: Balance;
+
+		/// Registry of assets that can be streamed. Stream funds are held in the sender's account
+		/// under [`HoldReason::StreamFunds`].
+		type Assets: MutateHold<
+			Self::AccountId,
+			AssetId = Self::AssetKind,
+			Balance = Self::Balance,
+			Reason = Self::RuntimeHoldReason,
+		>;
+
+		/// The overarching hold reason.
+		type RuntimeHoldReason: From<HoldReason>;
+
+		/// The clock streams accrue against.
+		///
+		/// A stream's rate is expressed per unit of this clock, e.g. per block for
+		/// `frame_system::Pallet` or per second for [`UnixSeconds`].
+		type Clock: BlockNumberProvider;
+
+		/// Converts an elapsed duration of [`Config::Clock`] into a balance multiplier.
+		type MomentToBalance: Convert<MomentOf<Self>, Self::Balance>;
+
+		/// Means for associating a cost with the on-chain storage of stream information, which
+		/// is incurred by the stream sender.
+		type Consideration: Consideration<Self::AccountId, Footprint>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Helper for benchmarking.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: benchmarking::BenchmarkHelper<
+			Self::AssetKind,
+			Self::AccountId,
+			Self::Balance,
+		>;
+	}
+
+	/// State and configuration of each open stream.
+	#[pallet::storage]
+	pub type Streams<T: Config> = StorageMap<_, Blake2_128Concat, StreamId, StreamInfoFor<T>>;
+
+	/// The cost associated with storing stream information on-chain which was incurred by the
+	/// stream sender.
+	///
+	/// This cost may be [`None`], as determined by [`Config::Consideration`].
+	#[pallet::storage]
+	pub type StreamCost<T: Config> =
+		StorageMap<_, Blake2_128Concat, StreamId, (T::AccountId, T::Consideration)>;
+
+	/// Index of the open streams paying out to each recipient.
+	#[pallet::storage]
+	pub type RecipientStreams<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		StreamId,
+		(),
+		OptionQuery,
+	>;
+
+	/// Stores the [`StreamId`] to use for the next stream.
+	///
+	/// Incremented when a new stream is created.
+	#[pallet::storage]
+	pub type NextStreamId<T: Config> = StorageValue<_, StreamId, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A new stream was opened.
+		StreamCreated {
+			/// The unique ID for the new stream.
+			stream_id: StreamId,
+			/// The account funding the stream.
+			sender: T::AccountId,
+			/// The account the stream pays out to.
+			recipient: T::AccountId,
+			/// The streamed asset.
+			asset_kind: T::AssetKind,
+			/// The amount accruing per unit of time.
+			rate: T::Balance,
+			/// The amount initially placed on hold.
+			amount: T::Balance,
+		},
+		/// The recipient withdrew accrued funds from a stream.
+		Withdrawn {
+			/// The stream.
+			stream_id: StreamId,
+			/// The account that received the funds.
+			recipient: T::AccountId,
+			/// The amount withdrawn.
+			amount: T::Balance,
+		},
+		/// The sender placed more funds on hold for a stream.
+		ToppedUp {
+			/// The stream.
+			stream_id: StreamId,
+			/// The additional amount placed on hold.
+			amount: T::Balance,
+		},
+		/// A stream stopped accruing.
+		Paused {
+			/// The stream.
+			stream_id: StreamId,
+		},
+		/// A paused stream started accruing again.
+		Resumed {
+			/// The stream.
+			stream_id: StreamId,
+		},
+		/// A stream was cancelled by its sender and settled.
+		Cancelled {
+			/// The stream.
+			stream_id: StreamId,
+			/// The amount paid out to the recipient on cancellation. Zero if the accrued funds
+			/// could not be paid out and are left for the recipient to withdraw.
+			paid: T::Balance,
+			/// The amount released back to the sender.
+			refunded: T::Balance,
+		},
+		/// All of a stream's funds were withdrawn and the stream was removed.
+		Depleted {
+			/// The stream.
+			stream_id: StreamId,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// An operation was attempted on a non-existent stream.
+		UnknownStream,
+		/// The asset does not exist.
+		NonExistentAsset,
+		/// A stream cannot pay out to its own sender.
+		SelfStream,
+		/// The stream rate must be greater than zero.
+		ZeroRate,
+		/// The amount must be greater than zero.
+		ZeroAmount,
+		/// The caller is not the stream sender.
+		NotSender,
+		/// The caller is not the stream recipient.
+		NotRecipient,
+		/// Nothing has accrued to the recipient yet.
+		NothingToWithdraw,
+		/// The stream is already paused.
+		AlreadyPaused,
+		/// The stream is not paused.
+		NotPaused,
+		/// The stream was cancelled.
+		StreamCancelled,
+	}
+
+	/// Pallet's callable functions.
+	#[pallet::call(weight(<T as Config>::WeightInfo))]
+	impl<T: Config> Pallet<T> {
+		/// Open a stream paying `rate` of `asset_kind` per unit of time to `recipient`.
+		///
+		/// Parameters:
+		/// - `origin`: the sender, whose funds back the stream;
+		/// - `recipient`: the account the stream pays out to;
+		/// - `asset_kind`: the asset to stream;
+		/// - `rate`: the amount accruing to the recipient per unit of [`Config::Clock`];
+		/// - `amount`: the amount initially placed on hold for the stream.
+		#[pallet::call_index(0)]
+		pub fn create_stream(
+			origin: OriginFor<T>,
+			recipient: AccountIdLookupOf<T>,
+			asset_kind: Box<T::AssetKind>,
+			rate: T::Balance,
+			amount: T::Balance,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let recipient = T::Lookup::lookup(recipient)?;
+
+			ensure!(sender != recipient, Error::<T>::SelfStream);
+			ensure!(!rate.is_zero(), Error::<T>::ZeroRate);
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+			ensure!(T::Assets::asset_exists(*asset_kind.clone()), Error::<T>::NonExistentAsset);
+
+			T::Assets::hold(*asset_kind.clone(), &HoldReason::StreamFunds.into(), &sender, amount)?;
+
+			let stream_id = NextStreamId::<T>::get();
+
+			let cost = T::Consideration::new(&sender, Self::stream_footprint())?;
+			StreamCost::<T>::insert(stream_id, (sender.clone(), cost));
+
+			let stream = StreamInfoFor::<T> {
+				sender: sender.clone(),
+				recipient: recipient.clone(),
+				asset_kind: *asset_kind.clone(),
+				rate,
+				held: amount,
+				accrued: Zero::zero(),
+				settled_at: T::Clock::current_block_number(),
+				status: StreamStatus::Active,
+			};
+			Streams::<T>::insert(stream_id, stream);
+			RecipientStreams::<T>::insert(&recipient, stream_id, ());
+
+			NextStreamId::<T>::put(stream_id.ensure_add(1)?);
+
+			Self::deposit_event(Event::StreamCreated {
+				stream_id,
+				sender,
+				recipient,
+				asset_kind: *asset_kind,
+				rate,
+				amount,
+			});
+
+			Ok(())
+		}
+
+		/// Withdraw everything accrued to the recipient of a stream.
+		///
+		/// Origin must be the stream recipient. If the stream's funds are exhausted by the
+		/// withdrawal, the stream is removed.
+		#[pallet::call_index(1)]
+		pub fn withdraw(origin: OriginFor<T>, stream_id: StreamId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let stream = Streams::<T>::get(stream_id).ok_or(Error::<T>::UnknownStream)?;
+			ensure!(stream.recipient == who, Error::<T>::NotRecipient);
+
+			let mut stream = Self::settle(&stream, T::Clock::current_block_number())?;
+			ensure!(!stream.accrued.is_zero(), Error::<T>::NothingToWithdraw);
+
+			let amount = stream.accrued;
+			Self::pay_out(&stream, amount)?;
+			stream.held.ensure_sub_assign(amount)?;
+			stream.accrued = Zero::zero();
+
+			Self::deposit_event(Event::Withdrawn { stream_id, recipient: who, amount });
+
+			if stream.held.is_zero() {
+				Self::remove_stream(stream_id, &stream)?;
+				Self::deposit_event(Event::Depleted { stream_id });
+			} else {
+				Streams::<T>::insert(stream_id, stream);
+			}
+
+			Ok(())
+		}
+
+		/// Place more funds on hold for a stream, extending how long it can pay out.
+		///
+		/// Origin must be the stream sender.
+		#[pallet::call_index(2)]
+		pub fn top_up(
+			origin: OriginFor<T>,
+			stream_id: StreamId,
+			amount: T::Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+
+			let stream = Streams::<T>::get(stream_id).ok_or(Error::<T>::UnknownStream)?;
+			ensure!(stream.sender == who, Error::<T>::NotSender);
+			ensure!(stream.status != StreamStatus::Cancelled, Error::<T>::StreamCancelled);
+
+			// Settle first, so the new funds do not accrue retroactively for a drained stream.
+			let mut stream = Self::settle(&stream, T::Clock::current_block_number())?;
+
+			T::Assets::hold(
+				stream.asset_kind.clone(),
+				&HoldReason::StreamFunds.into(),
+				&who,
+				amount,
+			)?;
+			stream.held.ensure_add_assign(amount)?;
+
+			Streams::<T>::insert(stream_id, stream);
+
+			Self::deposit_event(Event::ToppedUp { stream_id, amount });
+
+			Ok(())
+		}
+
+		/// Stop a stream from accruing.
+		///
+		/// Origin must be the stream sender. Funds accrued until now remain withdrawable.
+		#[pallet::call_index(3)]
+		pub fn pause(origin: OriginFor<T>, stream_id: StreamId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let stream = Streams::<T>::get(stream_id).ok_or(Error::<T>::UnknownStream)?;
+			ensure!(stream.sender == who, Error::<T>::NotSender);
+			ensure!(stream.status != StreamStatus::Cancelled, Error::<T>::StreamCancelled);
+			ensure!(stream.status == StreamStatus::Active, Error::<T>::AlreadyPaused);
+
+			let mut stream = Self::settle(&stream, T::Clock::current_block_number())?;
+			stream.status = StreamStatus::Paused;
+
+			Streams::<T>::insert(stream_id, stream);
+
+			Self::deposit_event(Event::Paused { stream_id });
+
+			Ok(())
+		}
+
+		/// Resume a paused stream.
+		///
+		/// Origin must be the stream sender. The time spent paused does not accrue.
+		#[pallet::call_index(4)]
+		pub fn resume(origin: OriginFor<T>, stream_id: StreamId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let stream = Streams::<T>::get(stream_id).ok_or(Error::<T>::UnknownStream)?;
+			ensure!(stream.sender == who, Error::<T>::NotSender);
+			ensure!(stream.status != StreamStatus::Cancelled, Error::<T>::StreamCancelled);
+			ensure!(stream.status == StreamStatus::Paused, Error::<T>::NotPaused);
+
+			let mut stream = Self::settle(&stream, T::Clock::current_block_number())?;
+			stream.status = StreamStatus::Active;
+
+			Streams::<T>::insert(stream_id, stream);
+
+			Self::deposit_event(Event::Resumed { stream_id });
+
+			Ok(())
+		}
+
+		/// Cancel a stream.
+		///
+		/// Origin must be the stream sender. Everything accrued up to now is paid out to the
+		/// recipient and the remaining funds are released back to the sender. Storage is cleaned
+		/// up and any associated storage cost is released.
+		///
+		/// If the accrued funds cannot be paid out, the remaining funds are still released, while
+		/// the accrued funds stay held and the stream is kept for the recipient to withdraw them.
+		#[pallet::call_index(5)]
+		pub fn cancel(origin: OriginFor<T>, stream_id: StreamId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let stream = Streams::<T>::get(stream_id).ok_or(Error::<T>::UnknownStream)?;
+			ensure!(stream.sender == who, Error::<T>::NotSender);
+			ensure!(stream.status != StreamStatus::Cancelled, Error::<T>::StreamCancelled);
+
+			let mut stream = Self::settle(&stream, T::Clock::current_block_number())?;
+
+			let refunded = stream.held.ensure_sub(stream.accrued)?;
+			if !refunded.is_zero() {
+				T::Assets::release(
+					stream.asset_kind.clone(),
+					&HoldReason::StreamFunds.into(),
+					&stream.sender,
+					refunded,
+					Precision::Exact,
+				)?;
+			}
+			stream.held = stream.accrued;
+
+			let paid = stream.accrued;
+			if paid.is_zero() || with_storage_layer(|| Self::pay_out(&stream, paid)).is_ok() {
+				Self::remove_stream(stream_id, &stream)?;
+				Self::deposit_event(Event::Cancelled { stream_id, paid, refunded });
+			} else {
+				stream.status = StreamStatus::Cancelled;
+				Streams::<T>::insert(stream_id, stream);
+				Self::deposit_event(Event::Cancelled { stream_id, paid: Zero::zero(), refunded });
+			}
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The stream footprint.
+		pub fn stream_footprint() -> Footprint {
+			Footprint::from_mel::<(StreamId, StreamInfoFor<T>)>()
+		}
+
+		/// Computes the stream state with accrual accounted for up to `now`.
+		///
+		/// A stream never accrues more than the funds held for it.
+		///
+		/// NOTE: this function has no side-effects. Side-effects such as storage modifications are
+		/// the responsibility of the caller.
+		pub fn settle(
+			stream: &StreamInfoFor<T>,
+			now: MomentOf<T>,
+		) -> Result<StreamInfoFor<T>, DispatchError> {
+			let mut new_stream = stream.clone();
+			if stream.status == StreamStatus::Active {
+				let elapsed = T::MomentToBalance::convert(now.saturating_sub(stream.settled_at));
+				let unaccrued = stream.held.ensure_sub(stream.accrued)?;
+				new_stream
+					.accrued
+					.ensure_add_assign(stream.rate.saturating_mul(elapsed).min(unaccrued))?;
+			}
+			new_stream.settled_at = now.max(stream.settled_at);
+			Ok(new_stream)
+		}
+
+		/// The amount the recipient of `stream_id` could withdraw right now.
+		pub fn claimable(stream_id: StreamId) -> Option<T::Balance> {
+			let stream = Streams::<T>::get(stream_id)?;
+			Self::settle(&stream, T::Clock::current_block_number())
+				.ok()
+				.map(|stream| stream.accrued)
+		}
+
+		/// All streams paying out to `recipient`, with their asset and currently claimable
+		/// amount.
+		pub fn claimable_by(recipient: T::AccountId) -> Vec<(StreamId, T::AssetKind, T::Balance)> {
+			let now = T::Clock::current_block_number();
+			RecipientStreams::<T>::iter_key_prefix(&recipient)
+				.filter_map(|stream_id| {
+					let stream = Streams::<T>::get(stream_id)?;
+					let stream = Self::settle(&stream, now).ok()?;
+					Some((stream_id, stream.asset_kind, stream.accrued))
+				})
+				.collect()
+		}
+
+		/// Move `amount` from the sender's hold to the recipient's free balance.
+		fn pay_out(stream: &StreamInfoFor<T>, amount: T::Balance) -> DispatchResult {
+			T::Assets::transfer_on_hold(
+				stream.asset_kind.clone(),
+				&HoldReason::StreamFunds.into(),
+				&stream.sender,
+				&stream.recipient,
+				amount,
+				Precision::Exact,
+				Restriction::Free,
+				Fortitude::Polite,
+			)?;
+			Ok(())
+		}
+
+		/// Remove a stream from storage and drop its storage cost.
+		fn remove_stream(stream_id: StreamId, stream: &StreamInfoFor<T>) -> DispatchResult {
+			Streams::<T>::remove(stream_id);
+			RecipientStreams::<T>::remove(&stream.recipient, stream_id);
+			if let Some((who, cost)) = StreamCost::<T>::take(stream_id) {
+				T::Consideration::drop(cost, &who)?;
+			}
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,136 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test environment for Payment Streams pallet.
+
+use super::*;
+use crate as pallet_payment_streams;
+use frame_support::{
+	construct_runtime, derive_impl, parameter_types,
+	traits::{
+		tokens::fungible::{HoldConsideration, NativeFromLeft, NativeOrWithId, UnionOf},
+		AsEnsureOriginWithArg, ConstU64, LinearStoragePrice,
+	},
+};
+use frame_system::EnsureSigned;
+use sp_runtime::{traits::ConvertInto, BuildStorage};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Assets: pallet_assets,
+		AssetsHolder: pallet_assets_holder,
+		PaymentStreams: pallet_payment_streams,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+}
+
+#[derive_impl(pallet_assets::config_preludes::TestDefaultConfig as pallet_assets::DefaultConfig)]
+impl pallet_assets::Config for Test {
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<u64>>;
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type Currency = Balances;
+	type Holder = AssetsHolder;
+}
+
+/// Allow holds for the `Assets` pallet.
+impl pallet_assets_holder::Config for Test {
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type RuntimeEvent = RuntimeEvent;
+}
+
+pub type NativeAndAssets =
+	UnionOf<Balances, AssetsHolder, NativeFromLeft, NativeOrWithId<u32>, u64>;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct PaymentStreamsBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl benchmarking::BenchmarkHelper<NativeOrWithId<u32>, u64, u64>
+	for PaymentStreamsBenchmarkHelper
+{
+	fn asset_kind() -> NativeOrWithId<u32> {
+		NativeOrWithId::Native
+	}
+	fn fund(_asset_kind: &NativeOrWithId<u32>, who: &u64, amount: u64) {
+		use frame_support::traits::fungible::Mutate;
+		Balances::set_balance(who, amount);
+	}
+}
+
+parameter_types! {
+	pub const StreamCreationHoldReason: RuntimeHoldReason =
+		RuntimeHoldReason::PaymentStreams(pallet_payment_streams::HoldReason::StreamCreation);
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetKind = NativeOrWithId<u32>;
+	type Balance = <Self as pallet_balances::Config>::Balance;
+	type Assets = NativeAndAssets;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Clock = System;
+	type MomentToBalance = ConvertInto;
+	type Consideration = HoldConsideration<
+		u64,
+		Balances,
+		StreamCreationHoldReason,
+		LinearStoragePrice<ConstU64<10>, ConstU64<0>, u64>,
+	>;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = PaymentStreamsBenchmarkHelper;
+}
+
+pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+	pallet_assets::GenesisConfig::<Test> {
+		// Genesis assets: id, owner, is_sufficient, min_balance
+		assets: vec![(1, 1, true, 1)],
+		metadata: vec![],
+		// Genesis accounts: id, account_id, balance
+		accounts: vec![(1, 1, 1000), (1, 2, 1000)],
+		next_asset_id: None,
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(1, 1000), (2, 1000), (3, 1000)],
+		..Default::default()
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
@@ -0,0 +1,364 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{mock::*, *};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{
+		fungible::{self, NativeOrWithId},
+		fungibles,
+	},
+};
+use sp_runtime::TokenError;
+
+const SENDER: u64 = 1;
+const RECIPIENT: u64 = 2;
+const NATIVE: NativeOrWithId<u32> = NativeOrWithId::Native;
+const ASSET: NativeOrWithId<u32> = NativeOrWithId::WithId(1);
+
+/// Creates a stream from [`SENDER`] to [`RECIPIENT`] at block 1 and returns its id.
+fn create_stream(asset_kind: NativeOrWithId<u32>, rate: u64, amount: u64) -> StreamId {
+	let stream_id = NextStreamId::<Test>::get();
+	assert_ok!(PaymentStreams::create_stream(
+		RuntimeOrigin::signed(SENDER),
+		RECIPIENT,
+		Box::new(asset_kind),
+		rate,
+		amount
+	));
+	stream_id
+}
+
+fn native_stream_hold(who: u64) -> u64 {
+	<Balances as fungible::InspectHold<_>>::balance_on_hold(&HoldReason::StreamFunds.into(), &who)
+}
+
+fn native_free(who: u64) -> u64 {
+	<Balances as fungible::Inspect<_>>::balance(&who)
+}
+
+#[test]
+fn create_stream_works() {
+	new_test_ext().execute_with(|| {
+		let stream_id = create_stream(NATIVE, 10, 500);
+
+		assert_eq!(
+			Streams::<Test>::get(stream_id),
+			Some(StreamInfo {
+				sender: SENDER,
+				recipient: RECIPIENT,
+				asset_kind: NATIVE,
+				rate: 10,
+				held: 500,
+				accrued: 0,
+				settled_at: 1,
+				status: StreamStatus::Active,
+			})
+		);
+		assert!(RecipientStreams::<Test>::contains_key(RECIPIENT, stream_id));
+		assert!(StreamCost::<Test>::contains_key(stream_id));
+		assert_eq!(NextStreamId::<Test>::get(), 1);
+
+		// The stream funds and the storage cost are both on hold.
+		assert_eq!(native_stream_hold(SENDER), 500);
+		assert_eq!(
+			<Balances as fungible::InspectHold<_>>::balance_on_hold(
+				&HoldReason::StreamCreation.into(),
+				&SENDER
+			),
+			10
+		);
+		assert_eq!(native_free(SENDER), 490);
+
+		System::assert_last_event(
+			Event::<Test>::StreamCreated {
+				stream_id,
+				sender: SENDER,
+				recipient: RECIPIENT,
+				asset_kind: NATIVE,
+				rate: 10,
+				amount: 500,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn create_stream_fails() {
+	new_test_ext().execute_with(|| {
+		let create = |recipient, asset_kind, rate, amount| {
+			PaymentStreams::create_stream(
+				RuntimeOrigin::signed(SENDER),
+				recipient,
+				Box::new(asset_kind),
+				rate,
+				amount,
+			)
+		};
+
+		assert_noop!(create(SENDER, NATIVE, 10, 500), Error::<Test>::SelfStream);
+		assert_noop!(create(RECIPIENT, NATIVE, 0, 500), Error::<Test>::ZeroRate);
+		assert_noop!(create(RECIPIENT, NATIVE, 10, 0), Error::<Test>::ZeroAmount);
+		assert_noop!(
+			create(RECIPIENT, NativeOrWithId::WithId(42), 10, 500),
+			Error::<Test>::NonExistentAsset
+		);
+		assert_noop!(create(RECIPIENT, NATIVE, 10, 5000), TokenError::FundsUnavailable);
+	});
+}
+
+#[test]
+fn withdraw_works() {
+	new_test_ext().execute_with(|| {
+		let stream_id = create_stream(NATIVE, 10, 500);
+
+		assert_noop!(
+			PaymentStreams::withdraw(RuntimeOrigin::signed(RECIPIENT), stream_id),
+			Error::<Test>::NothingToWithdraw
+		);
+
+		System::set_block_number(11);
+		assert_eq!(PaymentStreams::claimable(stream_id), Some(100));
+
+		assert_noop!(
+			PaymentStreams::withdraw(RuntimeOrigin::signed(SENDER), stream_id),
+			Error::<Test>::NotRecipient
+		);
+		assert_ok!(PaymentStreams::withdraw(RuntimeOrigin::signed(RECIPIENT), stream_id));
+		System::assert_last_event(
+			Event::<Test>::Withdrawn { stream_id, recipient: RECIPIENT, amount: 100 }.into(),
+		);
+
+		assert_eq!(native_free(RECIPIENT), 1100);
+		assert_eq!(native_stream_hold(SENDER), 400);
+		assert_eq!(PaymentStreams::claimable(stream_id), Some(0));
+
+		let stream = Streams::<Test>::get(stream_id).unwrap();
+		assert_eq!((stream.held, stream.accrued, stream.settled_at), (400, 0, 11));
+	});
+}
+
+#[test]
+fn depleted_stream_is_removed() {
+	new_test_ext().execute_with(|| {
+		let stream_id = create_stream(NATIVE, 10, 500);
+
+		// Accrual is capped by the funds held for the stream.
+		System::set_block_number(1_000);
+		assert_eq!(PaymentStreams::claimable(stream_id), Some(500));
+
+		assert_ok!(PaymentStreams::withdraw(RuntimeOrigin::signed(RECIPIENT), stream_id));
+		System::assert_last_event(Event::<Test>::Depleted { stream_id }.into());
+
+		assert_eq!(native_free(RECIPIENT), 1500);
+		assert_eq!(native_stream_hold(SENDER), 0);
+		assert!(Streams::<Test>::get(stream_id).is_none());
+		assert!(!RecipientStreams::<Test>::contains_key(RECIPIENT, stream_id));
+		assert!(!StreamCost::<Test>::contains_key(stream_id));
+		// The storage cost was released.
+		assert_eq!(native_free(SENDER), 500);
+	});
+}
+
+#[test]
+fn top_up_works() {
+	new_test_ext().execute_with(|| {
+		let stream_id = create_stream(NATIVE, 10, 100);
+
+		// The stream runs dry at block 11.
+		System::set_block_number(21);
+		assert_eq!(PaymentStreams::claimable(stream_id), Some(100));
+
+		assert_noop!(
+			PaymentStreams::top_up(RuntimeOrigin::signed(RECIPIENT), stream_id, 100),
+			Error::<Test>::NotSender
+		);
+		assert_noop!(
+			PaymentStreams::top_up(RuntimeOrigin::signed(SENDER), stream_id, 0),
+			Error::<Test>::ZeroAmount
+		);
+		assert_ok!(PaymentStreams::top_up(RuntimeOrigin::signed(SENDER), stream_id, 200));
+		System::assert_last_event(Event::<Test>::ToppedUp { stream_id, amount: 200 }.into());
+		assert_eq!(native_stream_hold(SENDER), 300);
+
+		// The new funds only accrue from the moment of the top up.
+		System::set_block_number(26);
+		assert_eq!(PaymentStreams::claimable(stream_id), Some(150));
+	});
+}
+
+#[test]
+fn pause_and_resume_works() {
+	new_test_ext().execute_with(|| {
+		let stream_id = create_stream(NATIVE, 10, 500);
+
+		System::set_block_number(6);
+		assert_noop!(
+			PaymentStreams::pause(RuntimeOrigin::signed(RECIPIENT), stream_id),
+			Error::<Test>::NotSender
+		);
+		assert_noop!(
+			PaymentStreams::resume(RuntimeOrigin::signed(SENDER), stream_id),
+			Error::<Test>::NotPaused
+		);
+		assert_ok!(PaymentStreams::pause(RuntimeOrigin::signed(SENDER), stream_id));
+		System::assert_last_event(Event::<Test>::Paused { stream_id }.into());
+		assert_noop!(
+			PaymentStreams::pause(RuntimeOrigin::signed(SENDER), stream_id),
+			Error::<Test>::AlreadyPaused
+		);
+
+		// Nothing accrues while paused, but the accrued amount stays withdrawable.
+		System::set_block_number(16);
+		assert_eq!(PaymentStreams::claimable(stream_id), Some(50));
+		assert_ok!(PaymentStreams::withdraw(RuntimeOrigin::signed(RECIPIENT), stream_id));
+		assert_eq!(native_free(RECIPIENT), 1050);
+
+		System::set_block_number(21);
+		assert_ok!(PaymentStreams::resume(RuntimeOrigin::signed(SENDER), stream_id));
+		System::assert_last_event(Event::<Test>::Resumed { stream_id }.into());
+
+		System::set_block_number(24);
+		assert_eq!(PaymentStreams::claimable(stream_id), Some(30));
+	});
+}
+
+#[test]
+fn cancel_settles_pro_rata() {
+	new_test_ext().execute_with(|| {
+		let stream_id = create_stream(NATIVE, 10, 500);
+
+		System::set_block_number(31);
+		assert_noop!(
+			PaymentStreams::cancel(RuntimeOrigin::signed(RECIPIENT), stream_id),
+			Error::<Test>::NotSender
+		);
+		assert_ok!(PaymentStreams::cancel(RuntimeOrigin::signed(SENDER), stream_id));
+		System::assert_last_event(
+			Event::<Test>::Cancelled { stream_id, paid: 300, refunded: 200 }.into(),
+		);
+
+		assert_eq!(native_free(RECIPIENT), 1300);
+		assert_eq!(native_free(SENDER), 700);
+		assert_eq!(native_stream_hold(SENDER), 0);
+		assert!(Streams::<Test>::get(stream_id).is_none());
+		assert!(!RecipientStreams::<Test>::contains_key(RECIPIENT, stream_id));
+		assert!(!StreamCost::<Test>::contains_key(stream_id));
+
+		assert_noop!(
+			PaymentStreams::cancel(RuntimeOrigin::signed(SENDER), stream_id),
+			Error::<Test>::UnknownStream
+		);
+	});
+}
+
+#[test]
+fn cancel_keeps_accrued_funds_the_recipient_cannot_receive() {
+	new_test_ext().execute_with(|| {
+		let stream_id = create_stream(ASSET, 5, 200);
+		let asset_hold = |who| {
+			<AssetsHolder as fungibles::InspectHold<_>>::balance_on_hold(
+				1,
+				&HoldReason::StreamFunds.into(),
+				&who,
+			)
+		};
+
+		// The asset owner blocks the recipient, who can no longer receive the asset.
+		assert_ok!(Assets::block(RuntimeOrigin::signed(SENDER), 1, RECIPIENT));
+
+		// The remainder is released to the sender, while the accrued funds stay held.
+		System::set_block_number(21);
+		assert_ok!(PaymentStreams::cancel(RuntimeOrigin::signed(SENDER), stream_id));
+		System::assert_last_event(
+			Event::<Test>::Cancelled { stream_id, paid: 0, refunded: 100 }.into(),
+		);
+		assert_eq!(Assets::balance(1, SENDER), 900);
+		assert_eq!(asset_hold(SENDER), 100);
+		let stream = Streams::<Test>::get(stream_id).unwrap();
+		assert_eq!(stream.status, StreamStatus::Cancelled);
+		assert_eq!((stream.held, stream.accrued), (100, 100));
+
+		// Nothing accrues anymore and the sender can not revive the stream.
+		System::set_block_number(31);
+		assert_eq!(PaymentStreams::claimable(stream_id), Some(100));
+		assert_noop!(
+			PaymentStreams::top_up(RuntimeOrigin::signed(SENDER), stream_id, 100),
+			Error::<Test>::StreamCancelled
+		);
+		assert_noop!(
+			PaymentStreams::resume(RuntimeOrigin::signed(SENDER), stream_id),
+			Error::<Test>::StreamCancelled
+		);
+		assert_noop!(
+			PaymentStreams::cancel(RuntimeOrigin::signed(SENDER), stream_id),
+			Error::<Test>::StreamCancelled
+		);
+
+		// Once unblocked, the recipient withdraws the accrued funds and the stream is removed.
+		assert_ok!(Assets::thaw(RuntimeOrigin::signed(SENDER), 1, RECIPIENT));
+		assert_ok!(PaymentStreams::withdraw(RuntimeOrigin::signed(RECIPIENT), stream_id));
+		assert_eq!(Assets::balance(1, RECIPIENT), 1100);
+		assert_eq!(asset_hold(SENDER), 0);
+		assert!(Streams::<Test>::get(stream_id).is_none());
+		assert!(!StreamCost::<Test>::contains_key(stream_id));
+	});
+}
+
+#[test]
+fn streams_pallet_assets_tokens() {
+	new_test_ext().execute_with(|| {
+		let stream_id = create_stream(ASSET, 5, 200);
+
+		let asset_hold = |who| {
+			<AssetsHolder as fungibles::InspectHold<_>>::balance_on_hold(
+				1,
+				&HoldReason::StreamFunds.into(),
+				&who,
+			)
+		};
+		assert_eq!(asset_hold(SENDER), 200);
+		assert_eq!(Assets::balance(1, SENDER), 800);
+
+		System::set_block_number(11);
+		assert_ok!(PaymentStreams::withdraw(RuntimeOrigin::signed(RECIPIENT), stream_id));
+		assert_eq!(Assets::balance(1, RECIPIENT), 1050);
+		assert_eq!(asset_hold(SENDER), 150);
+
+		System::set_block_number(21);
+		assert_ok!(PaymentStreams::cancel(RuntimeOrigin::signed(SENDER), stream_id));
+		assert_eq!(Assets::balance(1, RECIPIENT), 1100);
+		assert_eq!(Assets::balance(1, SENDER), 900);
+		assert_eq!(asset_hold(SENDER), 0);
+	});
+}
+
+#[test]
+fn claimable_by_lists_recipient_streams() {
+	new_test_ext().execute_with(|| {
+		let native_stream = create_stream(NATIVE, 10, 500);
+		let asset_stream = create_stream(ASSET, 1, 100);
+
+		System::set_block_number(11);
+		let mut claimable = PaymentStreams::claimable_by(RECIPIENT);
+		claimable.sort_by_key(|(stream_id, ..)| *stream_id);
+		assert_eq!(claimable, vec![(native_stream, NATIVE, 100), (asset_stream, ASSET, 10)]);
+		assert!(PaymentStreams::claimable_by(SENDER).is_empty());
+		assert_eq!(PaymentStreams::claimable(42), None);
+	});
+}
@@ -96,6 +96,14 @@ mod benchmarks {
 		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
 		// ... and "real" is the traditional caller. This is not a typo.
 		let real: T::AccountId = whitelisted_caller();
+		// the expiry of the proxy is checked as well.
+		Proxy::<T>::set_proxy_expiry(
+			RawOrigin::Signed(real.clone()).into(),
+			T::Lookup::unlookup(caller.clone()),
+			T::ProxyType::default(),
+			BlockNumberFor::<T>::zero(),
+			Some(BlockNumberFor::<T>::max_value()),
+		)?;
 		let real_lookup = T::Lookup::unlookup(real);
 		let call: <T as Config>::RuntimeCall =
 			frame_system::Call::<T>::remark { remark: vec![] }.into();
@@ -121,6 +129,14 @@ mod benchmarks {
 		T::Currency::make_free_balance_be(&delegate, BalanceOf::<T>::max_value() / 2u32.into());
 		// ... and "real" is the traditional caller. This is not a typo.
 		let real: T::AccountId = whitelisted_caller();
+		// the expiry of the proxy is checked as well.
+		Proxy::<T>::set_proxy_expiry(
+			RawOrigin::Signed(real.clone()).into(),
+			delegate_lookup.clone(),
+			T::ProxyType::default(),
+			BlockNumberFor::<T>::zero(),
+			Some(BlockNumberFor::<T>::max_value()),
+		)?;
 		let real_lookup = T::Lookup::unlookup(real);
 		let call: <T as Config>::RuntimeCall =
 			frame_system::Call::<T>::remark { remark: vec![] }.into();
@@ -457,5 +473,63 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_proxy_expiry(p: Linear<1, { T::MaxProxies::get() - 1 }>) -> Result<(), BenchmarkError> {
+		add_proxies::<T>(p, None)?;
+		let caller: T::AccountId = whitelisted_caller();
+		let delegate: T::AccountId = account("target", p - 1, SEED);
+		let expiry = Some(BlockNumberFor::<T>::max_value());
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			T::Lookup::unlookup(delegate.clone()),
+			T::ProxyType::default(),
+			BlockNumberFor::<T>::zero(),
+			expiry,
+		);
+
+		assert_last_event::<T>(
+			Event::ProxyExpirySet {
+				delegator: caller,
+				delegatee: delegate,
+				proxy_type: T::ProxyType::default(),
+				delay: BlockNumberFor::<T>::zero(),
+				expiry,
+			}
+			.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn track_outflow() -> Result<(), BenchmarkError> {
+		let (proxy_type, call) =
+			T::SpendingLimits::benchmark_outflow().ok_or(BenchmarkError::Weightless)?;
+		let (asset, _) = T::SpendingLimits::outflow(&call).ok_or(BenchmarkError::Weightless)?;
+		let real: T::AccountId = whitelisted_caller();
+		let def = ProxyDefinition {
+			delegate: account("target", 0, SEED),
+			proxy_type,
+			delay: BlockNumberFor::<T>::zero(),
+		};
+		// the worst case resets the spending of an earlier period.
+		ProxySpending::<T>::insert(
+			(&real, &def, asset),
+			Spending { period: BlockNumberFor::<T>::max_value(), spent: Zero::zero() },
+		);
+		let allowed;
+
+		#[block]
+		{
+			allowed = Proxy::<T>::track_outflow(&real, &def, &call);
+		}
+
+		assert!(allowed);
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Proxy, crate::tests::new_test_ext(), crate::tests::Test);
 }
@@ -23,6 +23,12 @@
 //! wish to execute some duration prior to execution happens. In this case, the target account may
 //! reject the announcement and in doing so, veto the execution.
 //!
+//! A proxy may be given an expiry with `set_proxy_expiry`, after which it can no longer act on
+//! behalf of the delegator. Proxy types may further carry per-period limits on the value sent out
+//! of the delegator's account, as defined by [`Config::SpendingLimits`]. The outflows of each call
+//! dispatched through a proxy are tracked against these limits, and calls exceeding them are
+//! filtered.
+//!
 //! - [`Config`]
 //! - [`Call`]
 
@@ -36,6 +42,7 @@ pub mod weights;
 extern crate alloc;
 use alloc::{boxed::Box, vec};
 use frame::{
+	deps::frame_support::storage::with_storage_layer,
 	prelude::*,
 	traits::{Currency, InstanceFilter, ReservableCurrency},
 };
@@ -52,6 +59,26 @@ pub type BlockNumberFor<T> =
 
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 
+type SpendingLimitsOf<T> = <T as Config>::SpendingLimits;
+
+type SpendingAssetOf<T> = <SpendingLimitsOf<T> as SpendingLimits<
+	<T as Config>::ProxyType,
+	<T as Config>::RuntimeCall,
+	BlockNumberFor<T>,
+>>::AssetKind;
+
+type SpendingBalanceOf<T> = <SpendingLimitsOf<T> as SpendingLimits<
+	<T as Config>::ProxyType,
+	<T as Config>::RuntimeCall,
+	BlockNumberFor<T>,
+>>::Balance;
+
+type ProxyDefinitionOf<T> = ProxyDefinition<
+	<T as frame_system::Config>::AccountId,
+	<T as Config>::ProxyType,
+	BlockNumberFor<T>,
+>;
+
 /// The parameters under which a particular account has a proxy relationship with some other
 /// account.
 #[derive(
@@ -109,6 +136,75 @@ pub enum DepositKind {
 	Announcements,
 }
 
+/// A limit on the value a proxy may send out of the delegator's account.
+#[derive(Clone, Copy, Eq, PartialEq, RuntimeDebug)]
+pub struct SpendingLimit<Balance, BlockNumber> {
+	/// The maximum value sent out per period.
+	pub amount: Balance,
+	/// The length of a period, in blocks of [`Config::BlockNumberProvider`].
+	pub period: BlockNumber,
+}
+
+/// The value a proxy has sent out of the delegator's account within a period.
+#[derive(
+	Encode, Decode, Clone, Copy, Default, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo,
+)]
+pub struct Spending<Balance, BlockNumber> {
+	/// The index of the period, i.e. the block number divided by the period length.
+	pub period: BlockNumber,
+	/// The value sent out within `period`.
+	pub spent: Balance,
+}
+
+/// Spending limits carried by proxy types.
+///
+/// Calls dispatched through a proxy are measured with [`SpendingLimits::outflow`]. If the proxy
+/// type has a [`SpendingLimits::limit`] on the asset sent out, the outflow is counted against the
+/// limit and the call is filtered once the limit would be exceeded.
+///
+/// Only calls recognised by [`SpendingLimits::outflow`] are tracked; the `InstanceFilter` of
+/// limited proxy types should not allow any other call moving funds out of the account.
+pub trait SpendingLimits<ProxyType, Call, BlockNumber> {
+	/// The kind of asset limits apply to.
+	type AssetKind: Parameter + MaxEncodedLen;
+
+	/// The balance the limits are expressed in.
+	type Balance: Parameter + MaxEncodedLen + AtLeast32BitUnsigned + Copy + Default;
+
+	/// The maximum number of assets a single proxy type has a non-zero limit on.
+	type MaxAssets: Get<u32>;
+
+	/// The limit of `proxy_type` on the outflows of `asset`, if any.
+	fn limit(
+		proxy_type: &ProxyType,
+		asset: &Self::AssetKind,
+	) -> Option<SpendingLimit<Self::Balance, BlockNumber>>;
+
+	/// The asset and amount `call` sends out of the origin account, if any.
+	fn outflow(call: &Call) -> Option<(Self::AssetKind, Self::Balance)>;
+
+	/// A proxy type and a call sending out less than its limit, to benchmark the tracking of
+	/// outflows. `None` if no proxy type has a spending limit.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn benchmark_outflow() -> Option<(ProxyType, Call)> {
+		None
+	}
+}
+
+impl<ProxyType, Call, BlockNumber> SpendingLimits<ProxyType, Call, BlockNumber> for () {
+	type AssetKind = ();
+	type Balance = u128;
+	type MaxAssets = ConstU32<0>;
+
+	fn limit(_: &ProxyType, _: &()) -> Option<SpendingLimit<u128, BlockNumber>> {
+		None
+	}
+
+	fn outflow(_: &Call) -> Option<((), u128)> {
+		None
+	}
+}
+
 #[frame::pallet]
 pub mod pallet {
 	use super::*;
@@ -212,6 +308,15 @@ pub mod pallet {
 		///     providers can be used. Relay provider can be a bit better in cases where the
 		///     parachain is lagging its block production to avoid clock skew.
 		type BlockNumberProvider: BlockNumberProvider;
+
+		/// The spending limits of proxy types, enforced on the calls dispatched through proxies.
+		///
+		/// Use `()` for proxy types without spending limits.
+		type SpendingLimits: SpendingLimits<
+			Self::ProxyType,
+			<Self as Config>::RuntimeCall,
+			BlockNumberFor<Self>,
+		>;
 	}
 
 	#[pallet::call]
@@ -231,6 +336,7 @@ pub mod pallet {
 			(T::WeightInfo::proxy(T::MaxProxies::get())
 				 // AccountData for inner call origin accountdata.
 				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				.saturating_add(T::WeightInfo::track_outflow())
 				.saturating_add(di.call_weight),
 			di.class)
 		})]
@@ -280,7 +386,9 @@ pub mod pallet {
 		/// - `proxy`: The account that the `caller` would like to remove as a proxy.
 		/// - `proxy_type`: The permissions currently enabled for the removed proxy account.
 		#[pallet::call_index(2)]
-		#[pallet::weight(T::WeightInfo::remove_proxy(T::MaxProxies::get()))]
+		#[pallet::weight(T::WeightInfo::remove_proxy(T::MaxProxies::get())
+			 // Proxy expiry and spending.
+			.saturating_add(T::DbWeight::get().writes(1 + Pallet::<T>::max_spending_assets() as u64)))]
 		pub fn remove_proxy(
 			origin: OriginFor<T>,
 			delegate: AccountIdLookupOf<T>,
@@ -299,7 +407,9 @@ pub mod pallet {
 		/// WARNING: This may be called on accounts created by `pure`, however if done, then
 		/// the unreserved fees will be inaccessible. **All access to this account will be lost.**
 		#[pallet::call_index(3)]
-		#[pallet::weight(T::WeightInfo::remove_proxies(T::MaxProxies::get()))]
+		#[pallet::weight(T::WeightInfo::remove_proxies(T::MaxProxies::get())
+			 // Proxy expiries and spending.
+			.saturating_add(T::DbWeight::get().writes(Pallet::<T>::max_proxy_records())))]
 		pub fn remove_proxies(origin: OriginFor<T>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 			Self::remove_all_proxy_delegates(&who);
@@ -373,7 +483,9 @@ pub mod pallet {
 		/// Fails with `NoPermission` in case the caller is not a previously created pure
 		/// account whose `pure` call has corresponding parameters.
 		#[pallet::call_index(5)]
-		#[pallet::weight(T::WeightInfo::kill_pure(T::MaxProxies::get()))]
+		#[pallet::weight(T::WeightInfo::kill_pure(T::MaxProxies::get())
+			 // Proxy expiries and spending.
+			.saturating_add(T::DbWeight::get().writes(Pallet::<T>::max_proxy_records())))]
 		pub fn kill_pure(
 			origin: OriginFor<T>,
 			spawner: AccountIdLookupOf<T>,
@@ -390,6 +502,7 @@ pub mod pallet {
 			ensure!(proxy == who, Error::<T>::NoPermission);
 
 			let (_, deposit) = Proxies::<T>::take(&who);
+			Self::clear_proxy_records(&who);
 			T::Currency::unreserve(&spawner, deposit);
 
 			Self::deposit_event(Event::PureKilled {
@@ -418,7 +531,9 @@ pub mod pallet {
 		/// - `real`: The account that the proxy will make a call on behalf of.
 		/// - `call_hash`: The hash of the call to be made by the `real` account.
 		#[pallet::call_index(6)]
-		#[pallet::weight(T::WeightInfo::announce(T::MaxPending::get(), T::MaxProxies::get()))]
+		#[pallet::weight(T::WeightInfo::announce(T::MaxPending::get(), T::MaxProxies::get())
+			 // Proxy expiry.
+			.saturating_add(T::DbWeight::get().reads(1)))]
 		pub fn announce(
 			origin: OriginFor<T>,
 			real: AccountIdLookupOf<T>,
@@ -426,17 +541,14 @@ pub mod pallet {
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 			let real = T::Lookup::lookup(real)?;
+			let now = T::BlockNumberProvider::current_block_number();
 			Proxies::<T>::get(&real)
 				.0
 				.into_iter()
-				.find(|x| x.delegate == who)
+				.find(|x| x.delegate == who && !Self::is_expired(&real, x, now))
 				.ok_or(Error::<T>::NotProxy)?;
 
-			let announcement = Announcement {
-				real: real.clone(),
-				call_hash,
-				height: T::BlockNumberProvider::current_block_number(),
-			};
+			let announcement = Announcement { real: real.clone(), call_hash, height: now };
 
 			Announcements::<T>::try_mutate(&who, |(ref mut pending, ref mut deposit)| {
 				pending.try_push(announcement).map_err(|_| Error::<T>::TooMany)?;
@@ -530,6 +642,7 @@ pub mod pallet {
 			(T::WeightInfo::proxy_announced(T::MaxPending::get(), T::MaxProxies::get())
 				 // AccountData for inner call origin accountdata.
 				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				.saturating_add(T::WeightInfo::track_outflow())
 				.saturating_add(di.call_weight),
 			di.class)
 		})]
@@ -657,6 +770,53 @@ pub mod pallet {
 
 			Ok(if deposit_updated { Pays::No.into() } else { Pays::Yes.into() })
 		}
+
+		/// Set or clear the expiry of a proxy account of the sender.
+		///
+		/// An expired proxy can no longer act on behalf of the sender, but keeps its deposit
+		/// reserved until it is removed.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Parameters:
+		/// - `delegate`: The proxy account.
+		/// - `proxy_type`: The permissions currently enabled for the proxy account.
+		/// - `delay`: The announcement period currently required of the proxy account.
+		/// - `expiry`: The block from which the proxy is no longer valid, or `None` for a proxy
+		///   without expiry.
+		///
+		/// Emits `ProxyExpirySet` if successful.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::set_proxy_expiry(T::MaxProxies::get()))]
+		pub fn set_proxy_expiry(
+			origin: OriginFor<T>,
+			delegate: AccountIdLookupOf<T>,
+			proxy_type: T::ProxyType,
+			delay: BlockNumberFor<T>,
+			expiry: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+			let proxy_def = ProxyDefinition { delegate, proxy_type, delay };
+			ensure!(
+				Proxies::<T>::get(&who).0.binary_search(&proxy_def).is_ok(),
+				Error::<T>::NotFound
+			);
+
+			match expiry {
+				Some(expiry) => ProxyExpiries::<T>::insert(&who, &proxy_def, expiry),
+				None => ProxyExpiries::<T>::remove(&who, &proxy_def),
+			}
+			Self::deposit_event(Event::ProxyExpirySet {
+				delegator: who,
+				delegatee: proxy_def.delegate,
+				proxy_type: proxy_def.proxy_type,
+				delay: proxy_def.delay,
+				expiry,
+			});
+
+			Ok(())
+		}
 	}
 
 	#[pallet::event]
@@ -706,6 +866,14 @@ pub mod pallet {
 			old_deposit: BalanceOf<T>,
 			new_deposit: BalanceOf<T>,
 		},
+		/// The expiry of a proxy was set or cleared.
+		ProxyExpirySet {
+			delegator: T::AccountId,
+			delegatee: T::AccountId,
+			proxy_type: T::ProxyType,
+			delay: BlockNumberFor<T>,
+			expiry: Option<BlockNumberFor<T>>,
+		},
 	}
 
 	#[pallet::error]
@@ -758,6 +926,33 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// The block from which a proxy is no longer valid, by delegator and proxy definition.
+	#[pallet::storage]
+	pub type ProxyExpiries<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		ProxyDefinitionOf<T>,
+		BlockNumberFor<T>,
+	>;
+
+	/// The value each proxy has sent out of the delegator's account within the current spending
+	/// period, by delegator, proxy definition and asset.
+	///
+	/// Records are removed along with the proxy.
+	#[pallet::storage]
+	pub type ProxySpending<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, T::AccountId>,
+			NMapKey<Blake2_128Concat, ProxyDefinitionOf<T>>,
+			NMapKey<Blake2_128Concat, SpendingAssetOf<T>>,
+		),
+		Spending<SpendingBalanceOf<T>, BlockNumberFor<T>>,
+		ValueQuery,
+	>;
+
 	#[pallet::view_functions]
 	impl<T: Config> Pallet<T> {
 		/// Check if a `RuntimeCall` is allowed for a given `ProxyType`.
@@ -889,6 +1084,12 @@ impl<T: Config> Pallet<T> {
 			};
 			let i = proxies.binary_search(&proxy_def).ok().ok_or(Error::<T>::NotFound)?;
 			proxies.remove(i);
+			ProxyExpiries::<T>::remove(delegator, &proxy_def);
+			let _ = ProxySpending::<T>::clear_prefix(
+				(delegator, &proxy_def),
+				Self::max_spending_assets(),
+				None,
+			);
 			let new_deposit = Self::deposit(proxies.len() as u32);
 			if new_deposit > old_deposit {
 				T::Currency::reserve(delegator, new_deposit - old_deposit)?;
@@ -963,14 +1164,25 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Whether the proxy `def` of `delegator` has expired at `now`.
+	pub fn is_expired(
+		delegator: &T::AccountId,
+		def: &ProxyDefinition<T::AccountId, T::ProxyType, BlockNumberFor<T>>,
+		now: BlockNumberFor<T>,
+	) -> bool {
+		ProxyExpiries::<T>::get(delegator, def).is_some_and(|expiry| expiry <= now)
+	}
+
 	pub fn find_proxy(
 		real: &T::AccountId,
 		delegate: &T::AccountId,
 		force_proxy_type: Option<T::ProxyType>,
 	) -> Result<ProxyDefinition<T::AccountId, T::ProxyType, BlockNumberFor<T>>, DispatchError> {
+		let now = T::BlockNumberProvider::current_block_number();
 		let f = |x: &ProxyDefinition<T::AccountId, T::ProxyType, BlockNumberFor<T>>| -> bool {
 			&x.delegate == delegate &&
-				force_proxy_type.as_ref().map_or(true, |y| &x.proxy_type == y)
+				force_proxy_type.as_ref().map_or(true, |y| &x.proxy_type == y) &&
+				!Self::is_expired(real, x, now)
 		};
 		Ok(Proxies::<T>::get(real).0.into_iter().find(f).ok_or(Error::<T>::NotProxy)?)
 	}
@@ -982,7 +1194,7 @@ impl<T: Config> Pallet<T> {
 	) {
 		use frame::traits::{InstanceFilter as _, OriginTrait as _};
 		// This is a freshly authenticated new account, the origin restrictions doesn't apply.
-		let mut origin: T::RuntimeOrigin = frame_system::RawOrigin::Signed(real).into();
+		let mut origin: T::RuntimeOrigin = frame_system::RawOrigin::Signed(real.clone()).into();
 		origin.add_filter(move |c: &<T as frame_system::Config>::RuntimeCall| {
 			let c = <T as Config>::RuntimeCall::from_ref(c);
 			// We make sure the proxy call does access this pallet to change modify proxies.
@@ -993,24 +1205,86 @@ impl<T: Config> Pallet<T> {
 				Some(Call::remove_proxy { ref proxy_type, .. })
 					if !def.proxy_type.is_superset(proxy_type) =>
 					false,
-				// Proxy call cannot remove all proxies or kill pure proxies unless it has full
-				// permissions.
-				Some(Call::remove_proxies { .. }) | Some(Call::kill_pure { .. })
+				// Proxy call cannot remove all proxies, kill pure proxies or change the expiry of
+				// proxies unless it has full permissions.
+				Some(Call::remove_proxies { .. }) |
+				Some(Call::kill_pure { .. }) |
+				Some(Call::set_proxy_expiry { .. })
 					if def.proxy_type != T::ProxyType::default() =>
 					false,
-				_ => def.proxy_type.filter(c),
+				_ => def.proxy_type.filter(c) && Self::track_outflow(&real, &def, c),
 			}
 		});
-		let e = call.dispatch(origin);
+		// Outflows counted by the filter are reverted along with a failed call.
+		let e = with_storage_layer(|| call.dispatch(origin));
 		Self::deposit_event(Event::ProxyExecuted { result: e.map(|_| ()).map_err(|e| e.error) });
 	}
 
+	/// Count the outflow of `call` against the spending limit of the proxy `def` of `real`.
+	///
+	/// Returns `false` if the outflow would exceed the limit, in which case nothing is counted.
+	fn track_outflow(
+		real: &T::AccountId,
+		def: &ProxyDefinition<T::AccountId, T::ProxyType, BlockNumberFor<T>>,
+		call: &<T as Config>::RuntimeCall,
+	) -> bool {
+		let Some((asset, amount)) = T::SpendingLimits::outflow(call) else { return true };
+		// Nothing to count, and no record for assets the proxy cannot spend.
+		if amount.is_zero() {
+			return true
+		}
+		let Some(limit) = T::SpendingLimits::limit(&def.proxy_type, &asset) else { return true };
+
+		let period = T::BlockNumberProvider::current_block_number()
+			.checked_div(&limit.period)
+			.unwrap_or_else(Zero::zero);
+		ProxySpending::<T>::try_mutate((real, def, asset), |spending| {
+			if spending.period != period {
+				*spending = Spending { period, spent: Zero::zero() };
+			}
+			let spent = spending.spent.checked_add(&amount).ok_or(())?;
+			if spent > limit.amount {
+				return Err(())
+			}
+			spending.spent = spent;
+			Ok(())
+		})
+		.is_ok()
+	}
+
 	/// Removes all proxy delegates for a given delegator.
 	///
 	/// Parameters:
 	/// - `delegator`: The delegator account.
 	pub fn remove_all_proxy_delegates(delegator: &T::AccountId) {
 		let (_, old_deposit) = Proxies::<T>::take(&delegator);
+		Self::clear_proxy_records(delegator);
 		T::Currency::unreserve(&delegator, old_deposit);
 	}
+
+	/// Removes the expiries and spending records of all proxies of `delegator`.
+	fn clear_proxy_records(delegator: &T::AccountId) {
+		let _ = ProxyExpiries::<T>::clear_prefix(delegator, T::MaxProxies::get(), None);
+		let _ = ProxySpending::<T>::clear_prefix(
+			(delegator,),
+			T::MaxProxies::get().saturating_mul(Self::max_spending_assets()),
+			None,
+		);
+	}
+
+	/// The maximum number of spending records of a single proxy.
+	fn max_spending_assets() -> u32 {
+		<SpendingLimitsOf<T> as SpendingLimits<
+			T::ProxyType,
+			<T as Config>::RuntimeCall,
+			BlockNumberFor<T>,
+		>>::MaxAssets::get()
+	}
+
+	/// The maximum number of expiries and spending records of all proxies of an account.
+	fn max_proxy_records() -> u64 {
+		T::MaxProxies::get()
+			.saturating_mul(Self::max_spending_assets().saturating_add(1))
+			.into()
+	}
 }
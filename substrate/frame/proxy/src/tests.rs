@@ -73,6 +73,7 @@ pub enum ProxyType {
 	Any,
 	JustTransfer,
 	JustUtility,
+	Allowance(u64),
 }
 impl Default for ProxyType {
 	fn default() -> Self {
@@ -90,6 +91,13 @@ impl frame::traits::InstanceFilter<RuntimeCall> for ProxyType {
 				)
 			},
 			ProxyType::JustUtility => matches!(c, RuntimeCall::Utility { .. }),
+			ProxyType::Allowance(_) => matches!(
+				c,
+				RuntimeCall::Balances(
+					pallet_balances::Call::transfer_allow_death { .. } |
+						pallet_balances::Call::transfer_keep_alive { .. }
+				) | RuntimeCall::Utility { .. }
+			),
 		}
 	}
 	fn is_superset(&self, o: &Self) -> bool {
@@ -108,6 +116,35 @@ impl Contains<RuntimeCall> for BaseFilter {
 	}
 }
 
+pub struct TestSpendingLimits;
+impl SpendingLimits<ProxyType, RuntimeCall, u64> for TestSpendingLimits {
+	type AssetKind = ();
+	type Balance = u64;
+	type MaxAssets = ConstU32<1>;
+
+	fn limit(proxy_type: &ProxyType, _: &()) -> Option<SpendingLimit<u64, u64>> {
+		match proxy_type {
+			ProxyType::Allowance(amount) => Some(SpendingLimit { amount: *amount, period: 10 }),
+			_ => None,
+		}
+	}
+
+	fn outflow(call: &RuntimeCall) -> Option<((), u64)> {
+		match call {
+			RuntimeCall::Balances(
+				BalancesCall::transfer_allow_death { value, .. } |
+				BalancesCall::transfer_keep_alive { value, .. },
+			) => Some(((), *value)),
+			_ => None,
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn benchmark_outflow() -> Option<(ProxyType, RuntimeCall)> {
+		Some((ProxyType::Allowance(10), call_transfer(6, 1)))
+	}
+}
+
 parameter_types! {
 	pub static ProxyDepositBase: u64 = 1;
 	pub static ProxyDepositFactor: u64 = 1;
@@ -129,6 +166,7 @@ impl Config for Test {
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
 	type BlockNumberProvider = frame_system::Pallet<Test>;
+	type SpendingLimits = TestSpendingLimits;
 }
 
 use super::{Call as ProxyCall, Event as ProxyEvent};
@@ -534,6 +572,171 @@ fn proxying_works() {
 	});
 }
 
+#[test]
+fn proxy_expiry_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0));
+		assert_noop!(
+			Proxy::set_proxy_expiry(RuntimeOrigin::signed(1), 3, ProxyType::Any, 0, Some(3)),
+			Error::<Test>::NotFound
+		);
+		assert_ok!(Proxy::set_proxy_expiry(
+			RuntimeOrigin::signed(1),
+			2,
+			ProxyType::Any,
+			0,
+			Some(3)
+		));
+		System::assert_last_event(
+			ProxyEvent::ProxyExpirySet {
+				delegator: 1,
+				delegatee: 2,
+				proxy_type: ProxyType::Any,
+				delay: 0,
+				expiry: Some(3),
+			}
+			.into(),
+		);
+
+		let call = Box::new(call_transfer(6, 1));
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, call.clone()));
+		assert_eq!(Balances::free_balance(6), 1);
+
+		// the proxy can no longer be used from the expiry block.
+		System::set_block_number(3);
+		assert_noop!(
+			Proxy::proxy(RuntimeOrigin::signed(2), 1, None, call.clone()),
+			Error::<Test>::NotProxy
+		);
+		assert_noop!(
+			Proxy::announce(RuntimeOrigin::signed(2), 1, [1; 32].into()),
+			Error::<Test>::NotProxy
+		);
+
+		// clearing the expiry restores it.
+		assert_ok!(Proxy::set_proxy_expiry(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0, None));
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, call.clone()));
+		assert_eq!(Balances::free_balance(6), 2);
+
+		// only proxies with full permissions can change the expiry of proxies.
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 3, ProxyType::JustUtility, 0));
+		let call = Box::new(RuntimeCall::Utility(UtilityCall::batch {
+			calls: vec![RuntimeCall::Proxy(ProxyCall::set_proxy_expiry {
+				delegate: 2,
+				proxy_type: ProxyType::Any,
+				delay: 0,
+				expiry: Some(10),
+			})],
+		}));
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(3), 1, None, call));
+		expect_events(vec![
+			UtilityEvent::BatchInterrupted { index: 0, error: SystemError::CallFiltered.into() }
+				.into(),
+			ProxyEvent::ProxyExecuted { result: Ok(()) }.into(),
+		]);
+		assert_eq!(
+			ProxyExpiries::<Test>::get(
+				1,
+				ProxyDefinition { delegate: 2, proxy_type: ProxyType::Any, delay: 0 }
+			),
+			None
+		);
+
+		// the expiry is removed along with the proxy.
+		assert_ok!(Proxy::set_proxy_expiry(
+			RuntimeOrigin::signed(1),
+			2,
+			ProxyType::Any,
+			0,
+			Some(10)
+		));
+		assert_ok!(Proxy::remove_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0));
+		assert_eq!(ProxyExpiries::<Test>::iter_prefix(1).count(), 0);
+	});
+}
+
+#[test]
+fn spending_limits_work() {
+	new_test_ext().execute_with(|| {
+		let proxy_type = ProxyType::Allowance(5);
+		let def = ProxyDefinition { delegate: 2, proxy_type, delay: 0 };
+		let spending = || ProxySpending::<Test>::get((1, &def, ()));
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 2, proxy_type, 0));
+
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, Box::new(call_transfer(6, 3))));
+		System::assert_last_event(ProxyEvent::ProxyExecuted { result: Ok(()) }.into());
+		assert_eq!(spending(), Spending { period: 0, spent: 3 });
+
+		// transfers beyond the allowance are filtered.
+		let call = Box::new(RuntimeCall::Balances(BalancesCall::transfer_keep_alive {
+			dest: 6,
+			value: 3,
+		}));
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, call.clone()));
+		System::assert_last_event(
+			ProxyEvent::ProxyExecuted { result: Err(SystemError::CallFiltered.into()) }.into(),
+		);
+		assert_eq!(Balances::free_balance(6), 3);
+		assert_eq!(spending(), Spending { period: 0, spent: 3 });
+
+		// also when batched.
+		let batch = Box::new(RuntimeCall::Utility(UtilityCall::batch {
+			calls: vec![call_transfer(6, 1), call_transfer(6, 2)],
+		}));
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, batch));
+		expect_events(vec![
+			UtilityEvent::BatchInterrupted { index: 1, error: SystemError::CallFiltered.into() }
+				.into(),
+			ProxyEvent::ProxyExecuted { result: Ok(()) }.into(),
+		]);
+		assert_eq!(Balances::free_balance(6), 4);
+		assert_eq!(spending(), Spending { period: 0, spent: 4 });
+
+		// failed transfers do not count against the allowance.
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(5), 2, proxy_type, 0));
+		let call = Box::new(RuntimeCall::Balances(BalancesCall::transfer_keep_alive {
+			dest: 6,
+			value: 1,
+		}));
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 5, None, call));
+		assert_eq!(Balances::free_balance(6), 4);
+		assert_eq!(ProxySpending::<Test>::get((5, &def, ())).spent, 0);
+
+		// the allowance resets with the next period.
+		System::set_block_number(10);
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, Box::new(call_transfer(6, 3))));
+		System::assert_last_event(ProxyEvent::ProxyExecuted { result: Ok(()) }.into());
+		assert_eq!(spending(), Spending { period: 1, spent: 3 });
+	});
+}
+
+#[test]
+fn spending_records_are_removed_with_the_proxy() {
+	new_test_ext().execute_with(|| {
+		let proxy_type = ProxyType::Allowance(5);
+		let def = ProxyDefinition { delegate: 2, proxy_type, delay: 0 };
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 2, proxy_type, 0));
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 3, proxy_type, 0));
+		// outflows of nothing are not recorded.
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, Box::new(call_transfer(6, 0))));
+		assert_eq!(ProxySpending::<Test>::iter_prefix((1,)).count(), 0);
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, Box::new(call_transfer(6, 3))));
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(3), 1, None, Box::new(call_transfer(6, 1))));
+		assert_eq!(ProxySpending::<Test>::iter_prefix((1,)).count(), 2);
+
+		// when
+		assert_ok!(Proxy::remove_proxy(RuntimeOrigin::signed(1), 2, proxy_type, 0));
+
+		// then only the records of the removed proxy are gone.
+		assert!(!ProxySpending::<Test>::contains_key((1, &def, ())));
+		assert_eq!(ProxySpending::<Test>::iter_prefix((1,)).count(), 1);
+
+		// and all of them are gone with all proxies.
+		assert_ok!(Proxy::remove_proxies(RuntimeOrigin::signed(1)));
+		assert_eq!(ProxySpending::<Test>::iter_prefix((1,)).count(), 0);
+	});
+}
+
 #[test]
 fn pure_works() {
 	new_test_ext().execute_with(|| {
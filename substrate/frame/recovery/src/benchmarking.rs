@@ -124,6 +124,44 @@ fn setup_active_recovery_with_max_friends<T: Config>(
 	<ActiveRecoveries<T>>::insert(lost_account, caller, active_recovery);
 }
 
+fn insert_inheritance_config<T: Config>(
+	account: &T::AccountId,
+	beneficiaries: Vec<<T as frame_system::Config>::AccountId>,
+) {
+	T::Currency::make_free_balance_be(&account, BalanceOf::<T>::max_value());
+
+	let bounded_beneficiaries: FriendsOf<T> = beneficiaries.try_into().unwrap();
+
+	// Get deposit for inheritance
+	let total_deposit = get_total_deposit::<T>(&bounded_beneficiaries).unwrap();
+
+	let inheritance_config = InheritanceConfig {
+		inactivity_period: DEFAULT_DELAY.into(),
+		challenge_period: DEFAULT_DELAY.into(),
+		deposit: total_deposit,
+		beneficiaries: bounded_beneficiaries,
+		last_active: DEFAULT_DELAY.into(),
+		nonce: frame_system::Pallet::<T>::account_nonce(account),
+	};
+
+	// Reserve deposit for inheritance
+	T::Currency::reserve(&account, total_deposit).unwrap();
+
+	<Inheritances<T>>::insert(&account, inheritance_config);
+}
+
+fn insert_inheritance_claim<T: Config>(account: &T::AccountId, beneficiary: &T::AccountId) {
+	let deposit = T::RecoveryDeposit::get();
+	T::Currency::reserve(beneficiary, deposit).unwrap();
+
+	let claim = InheritanceClaim {
+		beneficiary: beneficiary.clone(),
+		created: DEFAULT_DELAY.into(),
+		deposit,
+	};
+	<InheritanceClaims<T>>::insert(account, claim);
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
@@ -388,18 +426,25 @@ mod benchmarks {
 		// 2. Setup active recovery for lost account
 		setup_active_recovery_with_max_friends::<T>(&caller, &lost_account);
 
-		// 3. Get initial deposits
+		// 3. Setup inheritance config for caller
+		insert_inheritance_config::<T>(&caller, generate_friends::<T>(T::MaxFriends::get()));
+
+		// 4. Get initial deposits
 		let initial_config = <Recoverable<T>>::get(&caller).unwrap();
 		let initial_config_deposit = initial_config.deposit;
 		let initial_recovery_deposit = T::RecoveryDeposit::get();
+		let initial_inheritance_deposit = <Inheritances<T>>::get(&caller).unwrap().deposit;
 		assert_eq!(
 			T::Currency::reserved_balance(&caller),
-			initial_config_deposit.saturating_add(initial_recovery_deposit)
+			initial_config_deposit
+				.saturating_add(initial_recovery_deposit)
+				.saturating_add(initial_inheritance_deposit)
 		);
 
-		// 4. Artificially increase deposits
+		// 5. Artificially increase deposits
 		let increased_config_deposit = initial_config_deposit.saturating_add(2u32.into());
 		let increased_recovery_deposit = initial_recovery_deposit.saturating_add(2u32.into());
+		let increased_inheritance_deposit = initial_inheritance_deposit.saturating_add(2u32.into());
 
 		<Recoverable<T>>::try_mutate(&caller, |maybe_config| -> Result<(), BenchmarkError> {
 			let config = maybe_config.as_mut().unwrap();
@@ -427,22 +472,37 @@ mod benchmarks {
 		)
 		.map_err(|_| BenchmarkError::Stop("Failed to mutate storage"))?;
 
-		// 5. Verify increased deposits
+		<Inheritances<T>>::try_mutate(&caller, |maybe_config| -> Result<(), BenchmarkError> {
+			let config = maybe_config.as_mut().unwrap();
+			T::Currency::reserve(
+				&caller,
+				increased_inheritance_deposit.saturating_sub(initial_inheritance_deposit),
+			)?;
+			config.deposit = increased_inheritance_deposit;
+			Ok(())
+		})
+		.map_err(|_| BenchmarkError::Stop("Failed to mutate storage"))?;
+
+		// 6. Verify increased deposits
 		assert_eq!(
 			T::Currency::reserved_balance(&caller),
-			increased_config_deposit.saturating_add(increased_recovery_deposit)
+			increased_config_deposit
+				.saturating_add(increased_recovery_deposit)
+				.saturating_add(increased_inheritance_deposit)
 		);
 
 		#[extrinsic_call]
 		_(RawOrigin::Signed(caller.clone()), Some(T::Lookup::unlookup(lost_account.clone())));
 
-		// 6. Assert final state
+		// 7. Assert final state
 		assert_eq!(
 			T::Currency::reserved_balance(&caller),
-			initial_config_deposit.saturating_add(initial_recovery_deposit)
+			initial_config_deposit
+				.saturating_add(initial_recovery_deposit)
+				.saturating_add(initial_inheritance_deposit)
 		);
 
-		// 7. Check events were emitted
+		// 8. Check events were emitted
 		assert_has_event::<T>(
 			Event::DepositPoked {
 				who: caller.clone(),
@@ -454,16 +514,136 @@ mod benchmarks {
 		);
 		assert_has_event::<T>(
 			Event::DepositPoked {
-				who: caller,
+				who: caller.clone(),
 				kind: DepositKind::ActiveRecoveryFor(lost_account),
 				old_deposit: increased_recovery_deposit,
 				new_deposit: initial_recovery_deposit,
 			}
 			.into(),
 		);
+		assert_has_event::<T>(
+			Event::DepositPoked {
+				who: caller,
+				kind: DepositKind::InheritanceConfig,
+				old_deposit: increased_inheritance_deposit,
+				new_deposit: initial_inheritance_deposit,
+			}
+			.into(),
+		);
 
 		Ok(())
 	}
 
+	#[benchmark]
+	fn create_inheritance(n: Linear<1, { T::MaxFriends::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+
+		// Create beneficiaries
+		let beneficiaries = generate_friends::<T>(n);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			beneficiaries,
+			DEFAULT_DELAY.into(),
+			DEFAULT_DELAY.into(),
+		);
+
+		assert_last_event::<T>(Event::InheritanceCreated { account: caller }.into());
+	}
+
+	#[benchmark]
+	fn heartbeat(n: Linear<1, { T::MaxFriends::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		insert_inheritance_config::<T>(&caller, generate_friends::<T>(n));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		assert_last_event::<T>(Event::ActivityObserved { account: caller }.into());
+	}
+
+	#[benchmark]
+	fn initiate_inheritance(n: Linear<1, { T::MaxFriends::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let account: T::AccountId = account("account", 0, SEED);
+		let account_lookup = T::Lookup::unlookup(account.clone());
+		insert_inheritance_config::<T>(
+			&account,
+			add_caller_and_generate_friends::<T>(caller.clone(), n),
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), account_lookup);
+
+		assert_last_event::<T>(
+			Event::InheritanceClaimInitiated { account, beneficiary: caller }.into(),
+		);
+	}
+
+	#[benchmark]
+	fn cancel_inheritance_claim(n: Linear<1, { T::MaxFriends::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let beneficiaries = generate_friends::<T>(n);
+		let beneficiary = beneficiaries[0].clone();
+		insert_inheritance_config::<T>(&caller, beneficiaries);
+		insert_inheritance_claim::<T>(&caller, &beneficiary);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		assert_has_event::<T>(
+			Event::InheritanceClaimCancelled { account: caller, beneficiary }.into(),
+		);
+	}
+
+	#[benchmark]
+	fn claim_inheritance(n: Linear<1, { T::MaxFriends::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let account: T::AccountId = account("account", 0, SEED);
+		let account_lookup = T::Lookup::unlookup(account.clone());
+		insert_inheritance_config::<T>(
+			&account,
+			add_caller_and_generate_friends::<T>(caller.clone(), n),
+		);
+		insert_inheritance_claim::<T>(&account, &caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), account_lookup);
+
+		assert_last_event::<T>(
+			Event::AccountRecovered { lost_account: account, rescuer_account: caller }.into(),
+		);
+	}
+
+	#[benchmark]
+	fn remove_inheritance(n: Linear<1, { T::MaxFriends::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		insert_inheritance_config::<T>(&caller, generate_friends::<T>(n));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		assert_last_event::<T>(Event::InheritanceRemoved { account: caller }.into());
+	}
+
+	#[benchmark]
+	fn observe_activity(n: Linear<1, { T::MaxFriends::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let account: T::AccountId = account("account", 0, SEED);
+		let account_lookup = T::Lookup::unlookup(account.clone());
+		insert_inheritance_config::<T>(
+			&account,
+			add_caller_and_generate_friends::<T>(caller.clone(), n),
+		);
+		frame_system::Pallet::<T>::inc_account_nonce(&account);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), account_lookup);
+
+		assert_last_event::<T>(Event::ActivityObserved { account }.into());
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -91,6 +91,33 @@
 //! this pallet makes it near-zero cost to re-configure the recovery settings and
 //! remove/replace friends who are acting inappropriately.
 //!
+//! ### Inheritance
+//!
+//! Besides social recovery, an account owner can set up inheritance of their account with
+//! `create_inheritance`, acting as a "dead man's switch". They choose:
+//! * `beneficiaries` - The accounts that may claim the account once it has been inactive.
+//! * `inactivity_period` - The number of blocks the account must sign no transaction before a
+//!   beneficiary can claim it.
+//! * `challenge_period` - The number of blocks a claim stays pending, during which the owner can
+//!   cancel it.
+//!
+//! The account counts as active whenever its nonce changes, i.e. it signs a transaction, or when it
+//! calls `heartbeat`. Since the nonce is only compared when a beneficiary looks at the account, the
+//! inactivity period restarts from the block at which new activity is observed. A beneficiary whose
+//! claim is rejected because the account signed a transaction can record that activity with
+//! `observe_activity`, so that the inactivity period restarts from then.
+//!
+//! Once the account has been inactive for `inactivity_period` blocks, a beneficiary can call
+//! `initiate_inheritance`, placing the `RecoveryDeposit`. The owner can cancel the claim with
+//! `cancel_inheritance_claim` and receive the deposit, much like `close_recovery`. If the owner
+//! signs any transaction during the challenge period, the claim is dropped and the deposit returned
+//! to the beneficiary. Otherwise, after `challenge_period` blocks the beneficiary calls
+//! `claim_inheritance` and can then call `as_recovered` on behalf of the account, just like a
+//! successful rescuer.
+//!
+//! The inheritance configuration deposit is computed like the recovery configuration deposit, with
+//! one `FriendDepositFactor` per beneficiary.
+//!
 //! ### Safety Considerations
 //!
 //! It is important to note that this is a powerful pallet that can compromise the
@@ -141,6 +168,17 @@
 //!   deposit.
 //! * `remove_recovery` - Remove the recovery configuration from the account, making it
 //!   un-recoverable.
+//! * `create_inheritance` - Name beneficiaries that may claim the account once it is inactive.
+//! * `heartbeat` - Signal activity, restarting the inactivity period.
+//! * `cancel_inheritance_claim` - Cancel a pending inheritance claim and take its deposit.
+//! * `remove_inheritance` - Remove the inheritance configuration from the account.
+//!
+//! #### For Beneficiaries of an Inheritable Account
+//!
+//! * `initiate_inheritance` - Start a claim on an account that has been inactive.
+//! * `observe_activity` - Record new activity of an account, restarting its inactivity period.
+//! * `claim_inheritance` - Complete a claim after its challenge period, gaining access to the
+//!   account through `as_recovered`.
 //!
 //! #### For Super Users
 //!
@@ -178,6 +216,17 @@ pub type BlockNumberFromProviderOf<T> =
 	<<T as Config>::BlockNumberProvider as BlockNumberProvider>::BlockNumber;
 pub type FriendsOf<T> =
 	BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxFriends>;
+pub type InheritanceConfigOf<T> = InheritanceConfig<
+	BlockNumberFromProviderOf<T>,
+	BalanceOf<T>,
+	FriendsOf<T>,
+	<T as frame_system::Config>::Nonce,
+>;
+pub type InheritanceClaimOf<T> = InheritanceClaim<
+	<T as frame_system::Config>::AccountId,
+	BlockNumberFromProviderOf<T>,
+	BalanceOf<T>,
+>;
 
 /// An active recovery process.
 #[derive(Clone, Eq, PartialEq, Encode, Decode, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -206,6 +255,36 @@ pub struct RecoveryConfig<BlockNumber, Balance, Friends> {
 	pub threshold: u16,
 }
 
+/// Configuration for inheriting an account once it has been inactive.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct InheritanceConfig<BlockNumber, Balance, Beneficiaries, Nonce> {
+	/// The number of blocks without activity after which the account can be claimed.
+	pub inactivity_period: BlockNumber,
+	/// The number of blocks a claim must be pending before it can be completed.
+	pub challenge_period: BlockNumber,
+	/// The amount held in reserve of the owner,
+	/// to be returned once this configuration is removed.
+	pub deposit: Balance,
+	/// The accounts which can claim the account. Always sorted.
+	pub beneficiaries: Beneficiaries,
+	/// The block number at which activity of the account was last observed.
+	pub last_active: BlockNumber,
+	/// The nonce of the account when its activity was last observed.
+	pub nonce: Nonce,
+}
+
+/// A pending claim on an inactive account.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct InheritanceClaim<AccountId, BlockNumber, Balance> {
+	/// The beneficiary which initiated the claim.
+	pub beneficiary: AccountId,
+	/// The block number when the claim was initiated.
+	pub created: BlockNumber,
+	/// The amount held in reserve of the `beneficiary`,
+	/// to be returned once the claim is completed.
+	pub deposit: Balance,
+}
+
 /// The type of deposit
 #[derive(
 	Clone,
@@ -223,6 +302,8 @@ pub enum DepositKind<T: Config> {
 	RecoveryConfig,
 	/// Active recovery deposit for an account
 	ActiveRecoveryFor(<T as frame_system::Config>::AccountId),
+	/// Inheritance configuration deposit
+	InheritanceConfig,
 }
 
 #[frame::pallet]
@@ -330,6 +411,16 @@ pub mod pallet {
 		AccountRecovered { lost_account: T::AccountId, rescuer_account: T::AccountId },
 		/// A recovery process has been removed for an account.
 		RecoveryRemoved { lost_account: T::AccountId },
+		/// Inheritance has been set up for an account.
+		InheritanceCreated { account: T::AccountId },
+		/// Inheritance has been removed for an account.
+		InheritanceRemoved { account: T::AccountId },
+		/// Activity of an inheritable account has been observed, restarting its inactivity period.
+		ActivityObserved { account: T::AccountId },
+		/// A beneficiary has initiated a claim on an inactive account.
+		InheritanceClaimInitiated { account: T::AccountId, beneficiary: T::AccountId },
+		/// A pending claim on an account has been cancelled.
+		InheritanceClaimCancelled { account: T::AccountId, beneficiary: T::AccountId },
 		/// A deposit has been updated.
 		DepositPoked {
 			who: T::AccountId,
@@ -373,6 +464,32 @@ pub mod pallet {
 		AlreadyProxy,
 		/// Some internal state is broken.
 		BadState,
+		/// This account is not set up for inheritance
+		NotInheritable,
+		/// This account is already set up for inheritance
+		AlreadyInheritable,
+		/// This account is not a beneficiary of the inheritable account
+		NotBeneficiary,
+		/// The account has not been inactive for the inactivity period
+		InactivityPeriod,
+		/// The claim must be pending for the challenge period before it can be completed
+		ChallengePeriod,
+		/// Beneficiaries list must not be empty
+		NotEnoughBeneficiaries,
+		/// Beneficiaries list must be less than max friends
+		MaxBeneficiaries,
+		/// Beneficiaries list must be sorted and free of duplicates
+		BeneficiariesNotSorted,
+		/// A claim has already been initiated on this account
+		ClaimAlreadyStarted,
+		/// There is no pending claim of this beneficiary on this account
+		ClaimNotStarted,
+		/// There is still a pending claim that needs to be cancelled
+		ClaimStillPending,
+		/// The account signed a transaction since its activity was last observed
+		AccountActive,
+		/// The account signed no transaction since its activity was last observed
+		NoNewActivity,
 	}
 
 	/// The set of recoverable accounts and their recovery configuration.
@@ -407,6 +524,16 @@ pub mod pallet {
 	#[pallet::getter(fn proxy)]
 	pub type Proxy<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId>;
 
+	/// The set of inheritable accounts and their inheritance configuration.
+	#[pallet::storage]
+	pub type Inheritances<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, InheritanceConfigOf<T>>;
+
+	/// Pending claims on inheritable accounts, at most one per account.
+	#[pallet::storage]
+	pub type InheritanceClaims<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, InheritanceClaimOf<T>>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Send a call through a recovered account.
@@ -743,7 +870,7 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Poke deposits for recovery configurations and / or active recoveries.
+		/// Poke deposits for recovery and inheritance configurations and / or active recoveries.
 		///
 		/// This can be used by accounts to possibly lower their locked amount.
 		///
@@ -753,9 +880,10 @@ pub mod pallet {
 		/// - `maybe_account`: Optional recoverable account for which you have an active recovery
 		/// and want to adjust the deposit for the active recovery.
 		///
-		/// This function checks both recovery configuration deposit and active recovery deposits
-		/// of the caller:
+		/// This function checks the recovery and inheritance configuration deposits and active
+		/// recovery deposits of the caller:
 		/// - If the caller has created a recovery configuration, checks and adjusts its deposit
+		/// - If the caller has created an inheritance configuration, checks and adjusts its deposit
 		/// - If the caller has initiated any active recoveries, and provides the account in
 		/// `maybe_account`, checks and adjusts those deposits
 		///
@@ -778,6 +906,9 @@ pub mod pallet {
 			// Check and update recovery config deposit
 			deposit_updated |= Self::poke_recovery_config_deposit(&who)?;
 
+			// Check and update inheritance config deposit
+			deposit_updated |= Self::poke_inheritance_config_deposit(&who)?;
+
 			// Check and update active recovery deposit
 			if let Some(lost_account) = maybe_account {
 				let lost_account = T::Lookup::lookup(lost_account)?;
@@ -786,6 +917,250 @@ pub mod pallet {
 
 			Ok(if deposit_updated { Pays::No } else { Pays::Yes }.into())
 		}
+
+		/// Set up inheritance for your account, letting the beneficiaries claim it once it has
+		/// been inactive.
+		///
+		/// Payment: `ConfigDepositBase` + `FriendDepositFactor` * #_of_beneficiaries balance
+		/// will be reserved for storing the inheritance configuration. This deposit is returned
+		/// in full when the user calls `remove_inheritance`.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Parameters:
+		/// - `beneficiaries`: The accounts which can claim your account. Should be ordered and
+		///   contain no duplicate values.
+		/// - `inactivity_period`: The number of blocks your account must sign no transaction before
+		///   a beneficiary can initiate a claim.
+		/// - `challenge_period`: The number of blocks a claim is pending before it can be
+		///   completed, during which you can cancel it.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::create_inheritance(beneficiaries.len() as u32))]
+		pub fn create_inheritance(
+			origin: OriginFor<T>,
+			beneficiaries: Vec<T::AccountId>,
+			inactivity_period: BlockNumberFromProviderOf<T>,
+			challenge_period: BlockNumberFromProviderOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!Inheritances::<T>::contains_key(&who), Error::<T>::AlreadyInheritable);
+			ensure!(!beneficiaries.is_empty(), Error::<T>::NotEnoughBeneficiaries);
+			let beneficiaries: FriendsOf<T> =
+				beneficiaries.try_into().map_err(|_| Error::<T>::MaxBeneficiaries)?;
+			ensure!(Self::is_sorted_and_unique(&beneficiaries), Error::<T>::BeneficiariesNotSorted);
+			let deposit = Self::get_recovery_config_deposit(beneficiaries.len())?;
+			T::Currency::reserve(&who, deposit)?;
+			let config = InheritanceConfig {
+				inactivity_period,
+				challenge_period,
+				deposit,
+				beneficiaries,
+				last_active: T::BlockNumberProvider::current_block_number(),
+				nonce: frame_system::Pallet::<T>::account_nonce(&who),
+			};
+			Inheritances::<T>::insert(&who, config);
+
+			Self::deposit_event(Event::<T>::InheritanceCreated { account: who });
+			Ok(())
+		}
+
+		/// Signal that your account is still in use, restarting its inactivity period.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be an inheritable account.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::heartbeat(T::MaxFriends::get()))]
+		pub fn heartbeat(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut config = Inheritances::<T>::get(&who).ok_or(Error::<T>::NotInheritable)?;
+			Self::record_activity(&who, &mut config);
+			Inheritances::<T>::insert(&who, config);
+			Ok(())
+		}
+
+		/// Initiate a claim on an inheritable account which has been inactive for its inactivity
+		/// period.
+		///
+		/// Payment: `RecoveryDeposit` balance will be reserved for initiating the claim. It is
+		/// returned once the claim is completed, and repatriated to the account if the owner
+		/// cancels the claim. See `cancel_inheritance_claim`.
+		///
+		/// Fails with `AccountActive` if the account signed a transaction since its activity was
+		/// last observed. See `observe_activity`.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be a beneficiary of the
+		/// account.
+		///
+		/// Parameters:
+		/// - `account`: The inheritable account you want to claim.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::initiate_inheritance(T::MaxFriends::get()))]
+		pub fn initiate_inheritance(
+			origin: OriginFor<T>,
+			account: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let account = T::Lookup::lookup(account)?;
+			let config = Inheritances::<T>::get(&account).ok_or(Error::<T>::NotInheritable)?;
+			ensure!(Self::is_friend(&config.beneficiaries, &who), Error::<T>::NotBeneficiary);
+			ensure!(
+				!InheritanceClaims::<T>::contains_key(&account),
+				Error::<T>::ClaimAlreadyStarted
+			);
+			ensure!(!Self::has_signed_since(&account, &config), Error::<T>::AccountActive);
+			// Make sure the inactivity period has passed
+			let current_block_number = T::BlockNumberProvider::current_block_number();
+			let inheritable_block_number = config
+				.last_active
+				.checked_add(&config.inactivity_period)
+				.ok_or(ArithmeticError::Overflow)?;
+			ensure!(inheritable_block_number <= current_block_number, Error::<T>::InactivityPeriod);
+			let deposit = T::RecoveryDeposit::get();
+			T::Currency::reserve(&who, deposit)?;
+			let claim = InheritanceClaim {
+				beneficiary: who.clone(),
+				created: current_block_number,
+				deposit,
+			};
+			InheritanceClaims::<T>::insert(&account, claim);
+			Self::deposit_event(Event::<T>::InheritanceClaimInitiated {
+				account,
+				beneficiary: who,
+			});
+			Ok(())
+		}
+
+		/// As the owner of an inheritable account, cancel the pending claim on your account.
+		///
+		/// Payment: By calling this function, the account will receive the deposit
+		/// `RecoveryDeposit` placed by the beneficiary.
+		///
+		/// This also restarts the inactivity period of the account.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be an inheritable account
+		/// with a pending claim on it.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::cancel_inheritance_claim(T::MaxFriends::get()))]
+		pub fn cancel_inheritance_claim(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut config = Inheritances::<T>::get(&who).ok_or(Error::<T>::NotInheritable)?;
+			let claim = InheritanceClaims::<T>::take(&who).ok_or(Error::<T>::ClaimNotStarted)?;
+			// Acts like a slashing mechanism, as for `close_recovery`.
+			let res = T::Currency::repatriate_reserved(
+				&claim.beneficiary,
+				&who,
+				claim.deposit,
+				BalanceStatus::Free,
+			);
+			debug_assert!(res.is_ok());
+			Self::deposit_event(Event::<T>::InheritanceClaimCancelled {
+				account: who.clone(),
+				beneficiary: claim.beneficiary,
+			});
+			Self::record_activity(&who, &mut config);
+			Inheritances::<T>::insert(&who, config);
+			Ok(())
+		}
+
+		/// Complete your claim on an inheritable account, allowing you to make calls on its
+		/// behalf with `as_recovered`.
+		///
+		/// If the account signed a transaction since the claim was initiated, the claim is
+		/// dropped and its deposit returned instead.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be the beneficiary who
+		/// initiated the claim at least `challenge_period` blocks ago.
+		///
+		/// Parameters:
+		/// - `account`: The inheritable account you want to claim.
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::claim_inheritance(T::MaxFriends::get()))]
+		pub fn claim_inheritance(
+			origin: OriginFor<T>,
+			account: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let account = T::Lookup::lookup(account)?;
+			let mut config = Inheritances::<T>::get(&account).ok_or(Error::<T>::NotInheritable)?;
+			let claim = InheritanceClaims::<T>::get(&account)
+				.filter(|claim| claim.beneficiary == who)
+				.ok_or(Error::<T>::ClaimNotStarted)?;
+			if Self::has_signed_since(&account, &config) {
+				InheritanceClaims::<T>::remove(&account);
+				T::Currency::unreserve(&who, claim.deposit);
+				Self::deposit_event(Event::<T>::InheritanceClaimCancelled {
+					account: account.clone(),
+					beneficiary: who,
+				});
+				Self::record_activity(&account, &mut config);
+				Inheritances::<T>::insert(&account, config);
+				return Ok(());
+			}
+			// An activity of the account cancels the claim even if the beneficiary is a proxy
+			ensure!(!Proxy::<T>::contains_key(&who), Error::<T>::AlreadyProxy);
+			// Make sure the challenge period has passed
+			let current_block_number = T::BlockNumberProvider::current_block_number();
+			let claimable_block_number = claim
+				.created
+				.checked_add(&config.challenge_period)
+				.ok_or(ArithmeticError::Overflow)?;
+			ensure!(claimable_block_number <= current_block_number, Error::<T>::ChallengePeriod);
+			frame_system::Pallet::<T>::inc_consumers(&who).map_err(|_| Error::<T>::BadState)?;
+			InheritanceClaims::<T>::remove(&account);
+			T::Currency::unreserve(&who, claim.deposit);
+			// Create the recovery storage item
+			Proxy::<T>::insert(&who, &account);
+			Self::deposit_event(Event::<T>::AccountRecovered {
+				lost_account: account,
+				rescuer_account: who,
+			});
+			Ok(())
+		}
+
+		/// Remove the inheritance configuration of your account. Inherited accounts are still
+		/// accessible.
+		///
+		/// NOTE: The user must make sure to call `cancel_inheritance_claim` on a pending claim
+		/// before calling this function else it will fail.
+		///
+		/// Payment: By calling this function the account will unreserve its inheritance
+		/// configuration deposit.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be an inheritable account.
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::remove_inheritance(T::MaxFriends::get()))]
+		pub fn remove_inheritance(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!InheritanceClaims::<T>::contains_key(&who), Error::<T>::ClaimStillPending);
+			let config = Inheritances::<T>::take(&who).ok_or(Error::<T>::NotInheritable)?;
+
+			T::Currency::unreserve(&who, config.deposit);
+			Self::deposit_event(Event::<T>::InheritanceRemoved { account: who });
+			Ok(())
+		}
+
+		/// Record that an inheritable account signed a transaction since its activity was last
+		/// observed, restarting its inactivity period.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be a beneficiary of the
+		/// account.
+		///
+		/// Parameters:
+		/// - `account`: The inheritable account whose activity you observed.
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::observe_activity(T::MaxFriends::get()))]
+		pub fn observe_activity(
+			origin: OriginFor<T>,
+			account: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let account = T::Lookup::lookup(account)?;
+			let mut config = Inheritances::<T>::get(&account).ok_or(Error::<T>::NotInheritable)?;
+			ensure!(Self::is_friend(&config.beneficiaries, &who), Error::<T>::NotBeneficiary);
+			ensure!(Self::has_signed_since(&account, &config), Error::<T>::NoNewActivity);
+			Self::record_activity(&account, &mut config);
+			Inheritances::<T>::insert(&account, config);
+			Ok(())
+		}
 	}
 }
 
@@ -800,6 +1175,18 @@ impl<T: Config> Pallet<T> {
 		friends.binary_search(&friend).is_ok()
 	}
 
+	/// Check whether `account` signed any transaction since its activity was last observed.
+	fn has_signed_since(account: &T::AccountId, config: &InheritanceConfigOf<T>) -> bool {
+		frame_system::Pallet::<T>::account_nonce(account) != config.nonce
+	}
+
+	/// Record the activity of `account` at the current block, restarting its inactivity period.
+	fn record_activity(account: &T::AccountId, config: &mut InheritanceConfigOf<T>) {
+		config.last_active = T::BlockNumberProvider::current_block_number();
+		config.nonce = frame_system::Pallet::<T>::account_nonce(account);
+		Self::deposit_event(Event::<T>::ActivityObserved { account: account.clone() });
+	}
+
 	/// Helper function to calculate recovery config deposit
 	/// Total deposit is base fee + number of friends * factor fee
 	fn get_recovery_config_deposit(friends_count: usize) -> Result<BalanceOf<T>, DispatchError> {
@@ -847,6 +1234,42 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Helper function to poke the deposit reserved for creating an inheritance config
+	fn poke_inheritance_config_deposit(who: &T::AccountId) -> Result<bool, DispatchError> {
+		<Inheritances<T>>::try_mutate(&who, |maybe_config| -> Result<bool, DispatchError> {
+			let Some(config) = maybe_config.as_mut() else { return Ok(false) };
+			let old_deposit = config.deposit;
+			let new_deposit = Self::get_recovery_config_deposit(config.beneficiaries.len())?;
+
+			if old_deposit == new_deposit {
+				return Ok(false);
+			}
+
+			if new_deposit > old_deposit {
+				let extra = new_deposit.saturating_sub(old_deposit);
+				T::Currency::reserve(&who, extra)?;
+			} else {
+				let excess = old_deposit.saturating_sub(new_deposit);
+				let remaining_unreserved = T::Currency::unreserve(&who, excess);
+				if !remaining_unreserved.is_zero() {
+					defensive!(
+						"Failed to unreserve full amount. (Requested, Actual)",
+						(excess, excess.saturating_sub(remaining_unreserved))
+					);
+				}
+			}
+			config.deposit = new_deposit;
+
+			Self::deposit_event(Event::<T>::DepositPoked {
+				who: who.clone(),
+				kind: DepositKind::InheritanceConfig,
+				old_deposit,
+				new_deposit,
+			});
+			Ok(true)
+		})
+	}
+
 	/// Helper function to poke the deposit reserved for an active recovery
 	fn poke_active_recovery_deposit(
 		who: &T::AccountId,
@@ -786,3 +786,242 @@ fn poke_deposit_handles_insufficient_balance() {
 		assert_eq!(Balances::reserved_balance(5), 13);
 	});
 }
+
+#[test]
+fn inheritance_life_cycle_works() {
+	new_test_ext().execute_with(|| {
+		// Account 5 names accounts 1 and 2 as beneficiaries
+		assert_ok!(Recovery::create_inheritance(RuntimeOrigin::signed(5), vec![1, 2], 10, 5));
+		System::assert_last_event(Event::<Test>::InheritanceCreated { account: 5 }.into());
+		assert_eq!(Balances::reserved_balance(5), 12);
+		// The account has not been inactive for long enough
+		System::run_to_block::<AllPalletsWithSystem>(10);
+		assert_noop!(
+			Recovery::initiate_inheritance(RuntimeOrigin::signed(1), 5),
+			Error::<Test>::InactivityPeriod
+		);
+		// Account 1 initiates a claim once the account has been inactive
+		System::run_to_block::<AllPalletsWithSystem>(11);
+		assert_ok!(Recovery::initiate_inheritance(RuntimeOrigin::signed(1), 5));
+		System::assert_last_event(
+			Event::<Test>::InheritanceClaimInitiated { account: 5, beneficiary: 1 }.into(),
+		);
+		assert_eq!(Balances::reserved_balance(1), 10);
+		assert_noop!(
+			Recovery::initiate_inheritance(RuntimeOrigin::signed(2), 5),
+			Error::<Test>::ClaimAlreadyStarted
+		);
+		// The claim can only be completed after the challenge period
+		assert_noop!(
+			Recovery::claim_inheritance(RuntimeOrigin::signed(1), 5),
+			Error::<Test>::ChallengePeriod
+		);
+		System::run_to_block::<AllPalletsWithSystem>(16);
+		assert_noop!(
+			Recovery::claim_inheritance(RuntimeOrigin::signed(2), 5),
+			Error::<Test>::ClaimNotStarted
+		);
+		assert_ok!(Recovery::claim_inheritance(RuntimeOrigin::signed(1), 5));
+		System::assert_last_event(
+			Event::<Test>::AccountRecovered { lost_account: 5, rescuer_account: 1 }.into(),
+		);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert!(!<InheritanceClaims<Test>>::contains_key(&5));
+		// Account 1 can use account 5 to remove the inheritance and take all of its funds
+		let call = Box::new(RuntimeCall::Recovery(RecoveryCall::remove_inheritance {}));
+		assert_ok!(Recovery::as_recovered(RuntimeOrigin::signed(1), 5, call));
+		let call = Box::new(RuntimeCall::Balances(BalancesCall::transfer_allow_death {
+			dest: 1,
+			value: 100,
+		}));
+		assert_ok!(Recovery::as_recovered(RuntimeOrigin::signed(1), 5, call));
+		assert_eq!(Balances::free_balance(1), 200);
+		assert!(!<Inheritances<Test>>::contains_key(&5));
+	});
+}
+
+#[test]
+fn inheritance_handles_basic_errors() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Recovery::create_inheritance(RuntimeOrigin::signed(5), vec![], 10, 5),
+			Error::<Test>::NotEnoughBeneficiaries
+		);
+		assert_noop!(
+			Recovery::create_inheritance(RuntimeOrigin::signed(5), vec![2, 1], 10, 5),
+			Error::<Test>::BeneficiariesNotSorted
+		);
+		assert_noop!(
+			Recovery::initiate_inheritance(RuntimeOrigin::signed(1), 5),
+			Error::<Test>::NotInheritable
+		);
+		assert_noop!(Recovery::heartbeat(RuntimeOrigin::signed(5)), Error::<Test>::NotInheritable);
+		assert_ok!(Recovery::create_inheritance(RuntimeOrigin::signed(5), vec![1, 2], 10, 5));
+		assert_noop!(
+			Recovery::create_inheritance(RuntimeOrigin::signed(5), vec![1, 2], 10, 5),
+			Error::<Test>::AlreadyInheritable
+		);
+		assert_noop!(
+			Recovery::cancel_inheritance_claim(RuntimeOrigin::signed(5)),
+			Error::<Test>::ClaimNotStarted
+		);
+		System::run_to_block::<AllPalletsWithSystem>(11);
+		assert_noop!(
+			Recovery::initiate_inheritance(RuntimeOrigin::signed(3), 5),
+			Error::<Test>::NotBeneficiary
+		);
+		assert_ok!(Recovery::initiate_inheritance(RuntimeOrigin::signed(1), 5));
+		// A pending claim must be cancelled before removing the inheritance
+		assert_noop!(
+			Recovery::remove_inheritance(RuntimeOrigin::signed(5)),
+			Error::<Test>::ClaimStillPending
+		);
+	});
+}
+
+#[test]
+fn inheritance_claim_can_be_cancelled() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Recovery::create_inheritance(RuntimeOrigin::signed(5), vec![1, 2], 10, 5));
+		System::run_to_block::<AllPalletsWithSystem>(11);
+		assert_ok!(Recovery::initiate_inheritance(RuntimeOrigin::signed(1), 5));
+		// The owner cancels the claim during the challenge period, taking the deposit
+		System::run_to_block::<AllPalletsWithSystem>(13);
+		assert_ok!(Recovery::cancel_inheritance_claim(RuntimeOrigin::signed(5)));
+		System::assert_has_event(
+			Event::<Test>::InheritanceClaimCancelled { account: 5, beneficiary: 1 }.into(),
+		);
+		assert_eq!(Balances::free_balance(1), 90);
+		assert_eq!(Balances::free_balance(5), 98);
+		assert!(!<InheritanceClaims<Test>>::contains_key(&5));
+		// Which also restarts the inactivity period
+		assert_eq!(<Inheritances<Test>>::get(&5).unwrap().last_active, 13);
+		assert_noop!(
+			Recovery::initiate_inheritance(RuntimeOrigin::signed(1), 5),
+			Error::<Test>::InactivityPeriod
+		);
+		// The inheritance can then be removed, returning the deposit
+		assert_ok!(Recovery::remove_inheritance(RuntimeOrigin::signed(5)));
+		System::assert_last_event(Event::<Test>::InheritanceRemoved { account: 5 }.into());
+		assert_eq!(Balances::free_balance(5), 110);
+	});
+}
+
+#[test]
+fn activity_restarts_inactivity_period() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Recovery::create_inheritance(RuntimeOrigin::signed(5), vec![1, 2], 10, 5));
+		// A heartbeat restarts the inactivity period
+		System::run_to_block::<AllPalletsWithSystem>(5);
+		assert_ok!(Recovery::heartbeat(RuntimeOrigin::signed(5)));
+		System::assert_last_event(Event::<Test>::ActivityObserved { account: 5 }.into());
+		System::run_to_block::<AllPalletsWithSystem>(11);
+		assert_noop!(
+			Recovery::initiate_inheritance(RuntimeOrigin::signed(1), 5),
+			Error::<Test>::InactivityPeriod
+		);
+		// So does signing any transaction, once observed
+		System::inc_account_nonce(&5);
+		System::run_to_block::<AllPalletsWithSystem>(15);
+		assert_noop!(
+			Recovery::initiate_inheritance(RuntimeOrigin::signed(1), 5),
+			Error::<Test>::AccountActive
+		);
+		assert_noop!(
+			Recovery::observe_activity(RuntimeOrigin::signed(3), 5),
+			Error::<Test>::NotBeneficiary
+		);
+		assert_ok!(Recovery::observe_activity(RuntimeOrigin::signed(1), 5));
+		System::assert_last_event(Event::<Test>::ActivityObserved { account: 5 }.into());
+		assert_eq!(<Inheritances<Test>>::get(&5).unwrap().last_active, 15);
+		assert_noop!(
+			Recovery::observe_activity(RuntimeOrigin::signed(1), 5),
+			Error::<Test>::NoNewActivity
+		);
+		assert_noop!(
+			Recovery::initiate_inheritance(RuntimeOrigin::signed(1), 5),
+			Error::<Test>::InactivityPeriod
+		);
+		System::run_to_block::<AllPalletsWithSystem>(25);
+		assert_ok!(Recovery::initiate_inheritance(RuntimeOrigin::signed(1), 5));
+		// Signing a transaction during the challenge period drops the claim
+		System::inc_account_nonce(&5);
+		System::run_to_block::<AllPalletsWithSystem>(30);
+		assert_ok!(Recovery::claim_inheritance(RuntimeOrigin::signed(1), 5));
+		System::assert_has_event(
+			Event::<Test>::InheritanceClaimCancelled { account: 5, beneficiary: 1 }.into(),
+		);
+		assert!(!<InheritanceClaims<Test>>::contains_key(&5));
+		assert!(!<Proxy<Test>>::contains_key(&1));
+		// The deposit is returned to the beneficiary
+		assert_eq!(Balances::free_balance(1), 100);
+	});
+}
+
+#[test]
+fn claim_inheritance_observes_activity_before_proxy_check() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Recovery::create_inheritance(RuntimeOrigin::signed(5), vec![1, 2], 10, 5));
+		System::run_to_block::<AllPalletsWithSystem>(11);
+		assert_ok!(Recovery::initiate_inheritance(RuntimeOrigin::signed(1), 5));
+		// The beneficiary already acts as a proxy for another account
+		<Proxy<Test>>::insert(&1, &4);
+		System::run_to_block::<AllPalletsWithSystem>(16);
+		assert_noop!(
+			Recovery::claim_inheritance(RuntimeOrigin::signed(1), 5),
+			Error::<Test>::AlreadyProxy
+		);
+		// An activity of the account still cancels the claim and returns the deposit
+		System::inc_account_nonce(&5);
+		assert_ok!(Recovery::claim_inheritance(RuntimeOrigin::signed(1), 5));
+		System::assert_has_event(
+			Event::<Test>::InheritanceClaimCancelled { account: 5, beneficiary: 1 }.into(),
+		);
+		assert!(!<InheritanceClaims<Test>>::contains_key(&5));
+		assert_eq!(<Inheritances<Test>>::get(&5).unwrap().last_active, 16);
+		assert_eq!(<Proxy<Test>>::get(&1), Some(4));
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn poke_deposit_works_for_inheritance_config_deposits() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Recovery::create_inheritance(RuntimeOrigin::signed(5), vec![1, 2], 10, 5));
+
+		// Verify initial state
+		let old_deposit = Balances::reserved_balance(5);
+		// Base 10 + 1 per beneficiary
+		assert_eq!(old_deposit, 12);
+		assert_eq!(<Inheritances<Test>>::get(&5).unwrap().deposit, old_deposit);
+
+		// Change ConfigDepositBase to trigger deposit update
+		ConfigDepositBase::set(20);
+
+		// Poke deposit should work and be free
+		let result = Recovery::poke_deposit(RuntimeOrigin::signed(5), None);
+		assert_ok!(result.as_ref());
+		assert_eq!(result.unwrap(), Pays::No.into());
+
+		// Verify final state
+		let new_deposit = Balances::reserved_balance(5);
+		// New base 20 + 1 per beneficiary
+		assert_eq!(new_deposit, 22);
+		assert_eq!(<Inheritances<Test>>::get(&5).unwrap().deposit, new_deposit);
+
+		// Check event was emitted
+		System::assert_has_event(
+			Event::<Test>::DepositPoked {
+				who: 5,
+				kind: DepositKind::InheritanceConfig,
+				old_deposit,
+				new_deposit,
+			}
+			.into(),
+		);
+
+		// Poking again changes nothing and is paid
+		let result = Recovery::poke_deposit(RuntimeOrigin::signed(5), None);
+		assert_eq!(result.unwrap(), Pays::Yes.into());
+	});
+}
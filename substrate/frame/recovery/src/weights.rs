@@ -80,6 +80,13 @@ pub trait WeightInfo {
 	fn remove_recovery(n: u32, ) -> Weight;
 	fn cancel_recovered() -> Weight;
 	fn poke_deposit(n: u32, ) -> Weight;
+	fn create_inheritance(n: u32, ) -> Weight;
+	fn heartbeat(n: u32, ) -> Weight;
+	fn initiate_inheritance(n: u32, ) -> Weight;
+	fn cancel_inheritance_claim(n: u32, ) -> Weight;
+	fn claim_inheritance(n: u32, ) -> Weight;
+	fn remove_inheritance(n: u32, ) -> Weight;
+	fn observe_activity(n: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_recovery` using the Substrate node and recommended hardware.
@@ -97,7 +104,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Estimated: `3997`
 		// Minimum execution time: 18_724_000 picoseconds.
 		Weight::from_parts(19_503_000, 3997)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 	}
 	/// Storage: `Recovery::Proxy` (r:0 w:1)
 	/// Proof: `Recovery::Proxy` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
@@ -217,6 +224,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Recovery::Recoverable` (`max_values`: None, `max_size`: Some(351), added: 2826, mode: `MaxEncodedLen`)
 	/// Storage: `Recovery::ActiveRecoveries` (r:1 w:1)
 	/// Proof: `Recovery::ActiveRecoveries` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::Inheritances` (r:1 w:1)
+	/// Proof: `Recovery::Inheritances` (`max_values`: None, `max_size`: Some(361), added: 2836, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 9]`.
 	fn poke_deposit(_n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -224,9 +233,121 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Estimated: `3854`
 		// Minimum execution time: 46_517_000 picoseconds.
 		Weight::from_parts(49_326_481, 3854)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Recovery::Inheritances` (r:1 w:1)
+	/// Proof: `Recovery::Inheritances` (`max_values`: None, `max_size`: Some(361), added: 2836, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn create_inheritance(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `3826`
+		// Minimum execution time: 32_518_000 picoseconds.
+		Weight::from_parts(33_906_212, 3826)
+			// Standard Error: 3_112
+			.saturating_add(Weight::from_parts(118_402, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Recovery::Inheritances` (r:1 w:1)
+	/// Proof: `Recovery::Inheritances` (`max_values`: None, `max_size`: Some(361), added: 2836, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn heartbeat(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `281 + n * (32 ±0)`
+		//  Estimated: `3826`
+		// Minimum execution time: 14_203_000 picoseconds.
+		Weight::from_parts(15_118_377, 3826)
+			// Standard Error: 3_112
+			.saturating_add(Weight::from_parts(35_611, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Recovery::Inheritances` (r:1 w:0)
+	/// Proof: `Recovery::Inheritances` (`max_values`: None, `max_size`: Some(361), added: 2836, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::InheritanceClaims` (r:1 w:1)
+	/// Proof: `Recovery::InheritanceClaims` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn initiate_inheritance(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `438 + n * (32 ±0)`
+		//  Estimated: `3826`
+		// Minimum execution time: 31_877_000 picoseconds.
+		Weight::from_parts(33_250_918, 3826)
+			// Standard Error: 3_112
+			.saturating_add(Weight::from_parts(61_245, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Recovery::Inheritances` (r:1 w:1)
+	/// Proof: `Recovery::Inheritances` (`max_values`: None, `max_size`: Some(361), added: 2836, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::InheritanceClaims` (r:1 w:1)
+	/// Proof: `Recovery::InheritanceClaims` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn cancel_inheritance_claim(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `596 + n * (32 ±0)`
+		//  Estimated: `3826`
+		// Minimum execution time: 41_065_000 picoseconds.
+		Weight::from_parts(42_619_304, 3826)
+			// Standard Error: 3_112
+			.saturating_add(Weight::from_parts(54_180, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Recovery::Inheritances` (r:1 w:0)
+	/// Proof: `Recovery::Inheritances` (`max_values`: None, `max_size`: Some(361), added: 2836, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::InheritanceClaims` (r:1 w:1)
+	/// Proof: `Recovery::InheritanceClaims` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::Proxy` (r:1 w:1)
+	/// Proof: `Recovery::Proxy` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn claim_inheritance(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `530 + n * (32 ±0)`
+		//  Estimated: `3826`
+		// Minimum execution time: 36_742_000 picoseconds.
+		Weight::from_parts(38_017_561, 3826)
+			// Standard Error: 3_112
+			.saturating_add(Weight::from_parts(49_833, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: `Recovery::InheritanceClaims` (r:1 w:0)
+	/// Proof: `Recovery::InheritanceClaims` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::Inheritances` (r:1 w:1)
+	/// Proof: `Recovery::Inheritances` (`max_values`: None, `max_size`: Some(361), added: 2836, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn remove_inheritance(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `281 + n * (32 ±0)`
+		//  Estimated: `3826`
+		// Minimum execution time: 29_604_000 picoseconds.
+		Weight::from_parts(30_935_177, 3826)
+			// Standard Error: 3_112
+			.saturating_add(Weight::from_parts(98_526, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Recovery::Inheritances` (r:1 w:1)
+	/// Proof: `Recovery::Inheritances` (`max_values`: None, `max_size`: Some(361), added: 2836, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn observe_activity(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `438 + n * (32 ±0)`
+		//  Estimated: `3826`
+		// Minimum execution time: 17_915_000 picoseconds.
+		Weight::from_parts(18_842_306, 3826)
+			// Standard Error: 3_112
+			.saturating_add(Weight::from_parts(41_387, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -363,6 +484,8 @@ impl WeightInfo for () {
 	/// Proof: `Recovery::Recoverable` (`max_values`: None, `max_size`: Some(351), added: 2826, mode: `MaxEncodedLen`)
 	/// Storage: `Recovery::ActiveRecoveries` (r:1 w:1)
 	/// Proof: `Recovery::ActiveRecoveries` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::Inheritances` (r:1 w:1)
+	/// Proof: `Recovery::Inheritances` (`max_values`: None, `max_size`: Some(361), added: 2836, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 9]`.
 	fn poke_deposit(_n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -370,7 +493,119 @@ impl WeightInfo for () {
 		//  Estimated: `3854`
 		// Minimum execution time: 46_517_000 picoseconds.
 		Weight::from_parts(49_326_481, 3854)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Recovery::Inheritances` (r:1 w:1)
+	/// Proof: `Recovery::Inheritances` (`max_values`: None, `max_size`: Some(361), added: 2836, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn create_inheritance(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `3826`
+		// Minimum execution time: 32_518_000 picoseconds.
+		Weight::from_parts(33_906_212, 3826)
+			// Standard Error: 3_112
+			.saturating_add(Weight::from_parts(118_402, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Recovery::Inheritances` (r:1 w:1)
+	/// Proof: `Recovery::Inheritances` (`max_values`: None, `max_size`: Some(361), added: 2836, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn heartbeat(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `281 + n * (32 ±0)`
+		//  Estimated: `3826`
+		// Minimum execution time: 14_203_000 picoseconds.
+		Weight::from_parts(15_118_377, 3826)
+			// Standard Error: 3_112
+			.saturating_add(Weight::from_parts(35_611, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Recovery::Inheritances` (r:1 w:0)
+	/// Proof: `Recovery::Inheritances` (`max_values`: None, `max_size`: Some(361), added: 2836, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::InheritanceClaims` (r:1 w:1)
+	/// Proof: `Recovery::InheritanceClaims` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn initiate_inheritance(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `438 + n * (32 ±0)`
+		//  Estimated: `3826`
+		// Minimum execution time: 31_877_000 picoseconds.
+		Weight::from_parts(33_250_918, 3826)
+			// Standard Error: 3_112
+			.saturating_add(Weight::from_parts(61_245, 0).saturating_mul(n.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Recovery::Inheritances` (r:1 w:1)
+	/// Proof: `Recovery::Inheritances` (`max_values`: None, `max_size`: Some(361), added: 2836, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::InheritanceClaims` (r:1 w:1)
+	/// Proof: `Recovery::InheritanceClaims` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn cancel_inheritance_claim(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `596 + n * (32 ±0)`
+		//  Estimated: `3826`
+		// Minimum execution time: 41_065_000 picoseconds.
+		Weight::from_parts(42_619_304, 3826)
+			// Standard Error: 3_112
+			.saturating_add(Weight::from_parts(54_180, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Recovery::Inheritances` (r:1 w:0)
+	/// Proof: `Recovery::Inheritances` (`max_values`: None, `max_size`: Some(361), added: 2836, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::InheritanceClaims` (r:1 w:1)
+	/// Proof: `Recovery::InheritanceClaims` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::Proxy` (r:1 w:1)
+	/// Proof: `Recovery::Proxy` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn claim_inheritance(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `530 + n * (32 ±0)`
+		//  Estimated: `3826`
+		// Minimum execution time: 36_742_000 picoseconds.
+		Weight::from_parts(38_017_561, 3826)
+			// Standard Error: 3_112
+			.saturating_add(Weight::from_parts(49_833, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: `Recovery::InheritanceClaims` (r:1 w:0)
+	/// Proof: `Recovery::InheritanceClaims` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::Inheritances` (r:1 w:1)
+	/// Proof: `Recovery::Inheritances` (`max_values`: None, `max_size`: Some(361), added: 2836, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn remove_inheritance(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `281 + n * (32 ±0)`
+		//  Estimated: `3826`
+		// Minimum execution time: 29_604_000 picoseconds.
+		Weight::from_parts(30_935_177, 3826)
+			// Standard Error: 3_112
+			.saturating_add(Weight::from_parts(98_526, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Recovery::Inheritances` (r:1 w:1)
+	/// Proof: `Recovery::Inheritances` (`max_values`: None, `max_size`: Some(361), added: 2836, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn observe_activity(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `438 + n * (32 ±0)`
+		//  Estimated: `3826`
+		// Minimum execution time: 17_915_000 picoseconds.
+		Weight::from_parts(18_842_306, 3826)
+			// Standard Error: 3_112
+			.saturating_add(Weight::from_parts(41_387, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
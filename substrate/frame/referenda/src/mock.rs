@@ -72,6 +72,14 @@ impl pallet_preimage::Config for Test {
 	type ManagerOrigin = EnsureRoot<u64>;
 	type Consideration = ();
 }
+/// The cron tasks of the scheduler are not used here, so the time does not move.
+pub struct FixedTime;
+impl frame_support::traits::UnixTime for FixedTime {
+	fn now() -> core::time::Duration {
+		core::time::Duration::from_secs(1_700_000_000)
+	}
+}
+
 impl pallet_scheduler::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeOrigin = RuntimeOrigin;
@@ -84,6 +92,9 @@ impl pallet_scheduler::Config for Test {
 	type OriginPrivilegeCmp = EqualPrivilegeOnly;
 	type Preimages = Preimage;
 	type BlockNumberProvider = frame_system::Pallet<Test>;
+	type TimeProvider = FixedTime;
+	type MaxCronTasks = ConstU32<10>;
+	type CronGracePeriod = ConstU64<600>;
 }
 #[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
 impl pallet_balances::Config for Test {
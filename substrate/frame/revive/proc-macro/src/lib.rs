@@ -508,6 +508,14 @@ fn expand_functions(def: &EnvDef) -> TokenStream2 {
 			.sync_from_executor(memory.gas())
 			.map_err(TrapReason::from)?;
 
+		crate::tracing::if_tracing(|tracer| {
+			tracer.enter_ecall(
+				memory.program_counter(),
+				__syscall_symbol__,
+				self.ext.gas_meter().gas_left(),
+			);
+		});
+
 		// This is the overhead to call an empty syscall that always needs to be charged.
 		self.charge_gas(crate::wasm::RuntimeCosts::HostFn).map_err(TrapReason::from)?;
 
@@ -520,6 +528,10 @@ fn expand_functions(def: &EnvDef) -> TokenStream2 {
 			_ => Err(TrapReason::SupervisorError(Error::<E::T>::InvalidSyscall.into()))
 		})();
 
+		crate::tracing::if_tracing(|tracer| {
+			tracer.exit_ecall(self.ext.gas_meter().gas_left());
+		});
+
 		// Write gas from pallet-revive into polkavm after leaving the host function.
 		let gas = self.ext.gas_meter_mut().sync_to_executor(__gas_left_before__).map_err(TrapReason::from)?;
 		memory.set_gas(gas.into());
@@ -0,0 +1,209 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::*;
+use jsonrpsee::{
+	core::{RpcResult, SubscriptionResult},
+	proc_macros::rpc,
+	ConnectionId, Extensions, PendingSubscriptionSink, SubscriptionMessage, SubscriptionSink,
+};
+use tokio::sync::broadcast::{error::RecvError, Receiver};
+
+/// Ethereum filter and subscription JSON-RPC apis.
+#[rpc(server, client)]
+pub trait EthFilterRpc {
+	/// Creates a filter object, based on filter options, to notify when the state changes (logs).
+	#[method(name = "eth_newFilter", with_extensions)]
+	async fn new_filter(&self, filter: Filter) -> RpcResult<U256>;
+
+	/// Creates a filter in the node, to notify when a new block arrives.
+	#[method(name = "eth_newBlockFilter", with_extensions)]
+	async fn new_block_filter(&self) -> RpcResult<U256>;
+
+	/// Creates a filter in the node, to notify when new pending transactions arrive.
+	#[method(name = "eth_newPendingTransactionFilter", with_extensions)]
+	async fn new_pending_transaction_filter(&self) -> RpcResult<U256>;
+
+	/// Polling method for a filter, which returns an array of logs or hashes which occurred since
+	/// last poll.
+	#[method(name = "eth_getFilterChanges", with_extensions)]
+	async fn get_filter_changes(&self, filter_id: U256) -> RpcResult<FilterResults>;
+
+	/// Returns an array of all logs matching the log filter with the given id.
+	#[method(name = "eth_getFilterLogs", with_extensions)]
+	async fn get_filter_logs(&self, filter_id: U256) -> RpcResult<FilterResults>;
+
+	/// Uninstalls a filter with the given id.
+	#[method(name = "eth_uninstallFilter", with_extensions)]
+	async fn uninstall_filter(&self, filter_id: U256) -> RpcResult<bool>;
+
+	/// Subscribes to new block headers, logs matching a filter, or pending transactions.
+	///
+	/// ## References
+	///
+	/// - <https://geth.ethereum.org/docs/interacting-with-geth/rpc/pubsub>
+	#[subscription(
+		name = "eth_subscribe" => "eth_subscription",
+		unsubscribe = "eth_unsubscribe",
+		item = SubscriptionItem,
+	)]
+	async fn subscribe(&self, kind: SubscriptionKind, filter: Option<Filter>)
+		-> SubscriptionResult;
+}
+
+pub struct EthFilterRpcServerImpl {
+	client: client::Client,
+}
+
+impl EthFilterRpcServerImpl {
+	pub fn new(client: client::Client) -> Self {
+		Self { client }
+	}
+}
+
+fn connection_id(ext: &Extensions) -> ConnectionId {
+	ext.get::<ConnectionId>()
+		.copied()
+		.expect("ConnectionId is always set by jsonrpsee; qed")
+}
+
+/// Forward the items received from `receiver` to the subscription `sink` until either side is
+/// closed.
+async fn pipe_from_receiver<T: Clone>(
+	sink: SubscriptionSink,
+	mut receiver: Receiver<T>,
+	to_items: impl Fn(T) -> Vec<SubscriptionItem>,
+) -> SubscriptionResult {
+	loop {
+		let notification = tokio::select! {
+			_ = sink.closed() => return Ok(()),
+			notification = receiver.recv() => notification,
+		};
+
+		match notification {
+			Ok(notification) =>
+				for item in to_items(notification) {
+					sink.send(SubscriptionMessage::from_json(&item)?).await?;
+				},
+			Err(RecvError::Lagged(skipped)) => {
+				log::debug!(target: LOG_TARGET, "Subscription lagged, skipped {skipped} notifications");
+			},
+			Err(RecvError::Closed) => return Ok(()),
+		}
+	}
+}
+
+#[async_trait]
+impl EthFilterRpcServer for EthFilterRpcServerImpl {
+	async fn new_filter(&self, ext: &Extensions, filter: Filter) -> RpcResult<U256> {
+		let filter_provider = self.client.filter_provider();
+		let id = filter_provider
+			.new_filter(connection_id(ext), filter)
+			.await
+			.map_err(EthRpcError::from)?;
+		Ok(id)
+	}
+
+	async fn new_block_filter(&self, ext: &Extensions) -> RpcResult<U256> {
+		let filter_provider = self.client.filter_provider();
+		let id = filter_provider
+			.new_block_filter(connection_id(ext))
+			.await
+			.map_err(EthRpcError::from)?;
+		Ok(id)
+	}
+
+	async fn new_pending_transaction_filter(&self, ext: &Extensions) -> RpcResult<U256> {
+		let filter_provider = self.client.filter_provider();
+		let id = filter_provider
+			.new_pending_transaction_filter(connection_id(ext))
+			.await
+			.map_err(EthRpcError::from)?;
+		Ok(id)
+	}
+
+	async fn get_filter_changes(
+		&self,
+		ext: &Extensions,
+		filter_id: U256,
+	) -> RpcResult<FilterResults> {
+		let changes = self
+			.client
+			.filter_provider()
+			.filter_changes(connection_id(ext), filter_id)
+			.await
+			.map_err(EthRpcError::from)?;
+		Ok(changes)
+	}
+
+	async fn get_filter_logs(&self, ext: &Extensions, filter_id: U256) -> RpcResult<FilterResults> {
+		let filter = self
+			.client
+			.filter_provider()
+			.log_filter(connection_id(ext), filter_id)
+			.await
+			.map_err(EthRpcError::from)?;
+		let logs = self.client.logs(Some(filter)).await?;
+		Ok(FilterResults::Logs(logs))
+	}
+
+	async fn uninstall_filter(&self, ext: &Extensions, filter_id: U256) -> RpcResult<bool> {
+		Ok(self
+			.client
+			.filter_provider()
+			.uninstall_filter(connection_id(ext), filter_id)
+			.await)
+	}
+
+	async fn subscribe(
+		&self,
+		pending: PendingSubscriptionSink,
+		kind: SubscriptionKind,
+		filter: Option<Filter>,
+	) -> SubscriptionResult {
+		let filter_provider = self.client.filter_provider();
+		match kind {
+			SubscriptionKind::NewHeads => {
+				let receiver = filter_provider.subscribe_new_blocks();
+				let sink = pending.accept().await?;
+				pipe_from_receiver(sink, receiver, |notification| {
+					vec![SubscriptionItem::Header(Box::new(notification.block.clone()))]
+				})
+				.await
+			},
+			SubscriptionKind::Logs => {
+				let filter = filter.unwrap_or_default();
+				let receiver = filter_provider.subscribe_new_blocks();
+				let sink = pending.accept().await?;
+				pipe_from_receiver(sink, receiver, |notification| {
+					notification
+						.matching_logs(&filter)
+						.map(|log| SubscriptionItem::Log(log.clone()))
+						.collect()
+				})
+				.await
+			},
+			SubscriptionKind::NewPendingTransactions => {
+				let receiver = filter_provider.subscribe_pending_transactions();
+				let sink = pending.accept().await?;
+				pipe_from_receiver(sink, receiver, |hash| {
+					vec![SubscriptionItem::TransactionHash(hash)]
+				})
+				.await
+			},
+		}
+	}
+}
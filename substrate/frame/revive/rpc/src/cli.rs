@@ -17,9 +17,9 @@
 //! The Ethereum JSON-RPC server.
 use crate::{
 	client::{connect, Client, SubscriptionType, SubstrateBlockNumber},
-	DebugRpcServer, DebugRpcServerImpl, EthRpcServer, EthRpcServerImpl, ReceiptExtractor,
-	ReceiptProvider, SubxtBlockInfoProvider, SystemHealthRpcServer, SystemHealthRpcServerImpl,
-	LOG_TARGET,
+	DebugRpcServer, DebugRpcServerImpl, EthFilterRpcServer, EthFilterRpcServerImpl, EthRpcServer,
+	EthRpcServerImpl, FilterProvider, ReceiptExtractor, ReceiptProvider, SubxtBlockInfoProvider,
+	SystemHealthRpcServer, SystemHealthRpcServerImpl, LOG_TARGET,
 };
 use clap::Parser;
 use futures::{pin_mut, FutureExt};
@@ -29,6 +29,7 @@ use sc_service::{
 	config::{PrometheusConfig, RpcConfiguration},
 	start_rpc_servers, TaskManager,
 };
+use std::time::Duration;
 
 // Default port if --prometheus-port is not specified
 const DEFAULT_PROMETHEUS_PORT: u16 = 9616;
@@ -64,6 +65,19 @@ pub struct CliCommand {
 	#[clap(long)]
 	pub index_last_n_blocks: Option<SubstrateBlockNumber>,
 
+	/// The maximum number of filters a single connection can install with `eth_newFilter`,
+	/// `eth_newBlockFilter` or `eth_newPendingTransactionFilter`.
+	#[clap(long, default_value = "64")]
+	pub max_filters_per_connection: usize,
+
+	/// The maximum number of filters installed across all connections.
+	#[clap(long, default_value = "4096")]
+	pub max_filters: usize,
+
+	/// Number of seconds after which a filter that has not been polled is removed.
+	#[clap(long, default_value = "300")]
+	pub filter_timeout: u64,
+
 	#[allow(missing_docs)]
 	#[clap(flatten)]
 	pub shared_params: SharedParams,
@@ -104,6 +118,7 @@ fn build_client(
 	earliest_receipt_block: Option<SubstrateBlockNumber>,
 	node_rpc_url: &str,
 	database_url: &str,
+	filter_provider: FilterProvider,
 	abort_signal: Signals,
 ) -> anyhow::Result<Client> {
 	let fut = async {
@@ -129,8 +144,15 @@ fn build_client(
 			)
 			.await?;
 
-		let client =
-			Client::new(api, rpc_client, rpc, block_provider, receipt_provider).await?;
+		let client = Client::new(
+			api,
+			rpc_client,
+			rpc,
+			block_provider,
+			receipt_provider,
+			filter_provider,
+		)
+		.await?;
 
 		Ok(client)
 	}
@@ -154,6 +176,9 @@ pub fn run(cmd: CliCommand) -> anyhow::Result<()> {
 		database_url,
 		earliest_receipt_block,
 		index_last_n_blocks,
+		max_filters_per_connection,
+		max_filters,
+		filter_timeout,
 		shared_params,
 		..
 	} = cmd;
@@ -196,6 +221,11 @@ pub fn run(cmd: CliCommand) -> anyhow::Result<()> {
 		earliest_receipt_block,
 		&node_rpc_url,
 		&database_url,
+		FilterProvider::new(
+			max_filters_per_connection,
+			max_filters,
+			Duration::from_secs(filter_timeout),
+		),
 		tokio_runtime.block_on(async { Signals::capture() })?,
 	)?;
 
@@ -246,11 +276,13 @@ fn rpc_module(is_dev: bool, client: Client) -> Result<RpcModule<()>, sc_service:
 		.with_accounts(if is_dev { vec![crate::Account::default()] } else { vec![] })
 		.into_rpc();
 
+	let filter_api = EthFilterRpcServerImpl::new(client.clone()).into_rpc();
 	let health_api = SystemHealthRpcServerImpl::new(client.clone()).into_rpc();
 	let debug_api = DebugRpcServerImpl::new(client).into_rpc();
 
 	let mut module = RpcModule::new(());
 	module.merge(eth_api).map_err(|e| sc_service::Error::Application(e.into()))?;
+	module.merge(filter_api).map_err(|e| sc_service::Error::Application(e.into()))?;
 	module.merge(health_api).map_err(|e| sc_service::Error::Application(e.into()))?;
 	module.merge(debug_api).map_err(|e| sc_service::Error::Application(e.into()))?;
 	Ok(module)
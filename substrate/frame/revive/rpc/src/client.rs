@@ -25,8 +25,8 @@ use storage_api::StorageApi;
 
 use crate::{
 	subxt_client::{self, revive::calls::types::EthTransact, SrcChainConfig},
-	BlockInfoProvider, BlockTag, FeeHistoryProvider, ReceiptProvider, SubxtBlockInfoProvider,
-	TracerType, TransactionInfo, LOG_TARGET,
+	BlockInfoProvider, BlockTag, FeeHistoryProvider, FilterProvider, ReceiptProvider,
+	SubxtBlockInfoProvider, TracerType, TransactionInfo, LOG_TARGET,
 };
 use jsonrpsee::{
 	core::traits::ToRpcParams,
@@ -161,6 +161,7 @@ pub struct Client {
 	receipt_provider: ReceiptProvider,
 	block_provider: SubxtBlockInfoProvider,
 	fee_history_provider: FeeHistoryProvider,
+	filter_provider: FilterProvider,
 	chain_id: u64,
 	max_block_weight: Weight,
 }
@@ -217,6 +218,7 @@ impl Client {
 		rpc: LegacyRpcMethods<SrcChainConfig>,
 		block_provider: SubxtBlockInfoProvider,
 		receipt_provider: ReceiptProvider,
+		filter_provider: FilterProvider,
 	) -> Result<Self, ClientError> {
 		let (chain_id, max_block_weight) =
 			tokio::try_join!(chain_id(&api), max_block_weight(&api))?;
@@ -228,6 +230,7 @@ impl Client {
 			receipt_provider,
 			block_provider,
 			fee_history_provider: FeeHistoryProvider::default(),
+			filter_provider,
 			chain_id,
 			max_block_weight,
 		})
@@ -333,6 +336,11 @@ impl Client {
 			self.block_provider.update_latest(block, subscription_type).await;
 
 			self.fee_history_provider.update_fee_history(&evm_block, &receipts).await;
+
+			if matches!(subscription_type, SubscriptionType::BestBlocks) {
+				let logs = receipts.into_iter().flat_map(|receipt| receipt.logs).collect();
+				self.filter_provider.notify_new_block(evm_block, logs).await;
+			}
 			Ok(())
 		})
 		.await
@@ -673,6 +681,11 @@ impl Client {
 		}
 	}
 
+	/// Get the filter provider, used to manage installed filters and subscriptions.
+	pub fn filter_provider(&self) -> &FilterProvider {
+		&self.filter_provider
+	}
+
 	/// Get the chain ID.
 	pub fn chain_id(&self) -> u64 {
 		self.chain_id
@@ -0,0 +1,570 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Installed filters and `eth_subscribe` notifications.
+use crate::{AddressOrAddresses, Block, BlockNumberOrTag, Filter, FilterTopic, LOG_TARGET};
+use jsonrpsee::ConnectionId;
+use pallet_revive::evm::{FilterResults, Log};
+use serde::{Deserialize, Serialize};
+use sp_core::{H256, U256};
+use std::{
+	collections::{HashMap, VecDeque},
+	sync::Arc,
+	time::{Duration, Instant},
+};
+use tokio::sync::{broadcast, Mutex};
+
+/// Capacity of the channels used to notify subscribers.
+const NOTIFICATION_CHANNEL_CAPACITY: usize = 256;
+
+/// Maximum number of changes kept for a filter between two polls.
+/// Once reached, the oldest changes are dropped.
+const MAX_PENDING_CHANGES: usize = 10_000;
+
+/// The subscription kinds supported by `eth_subscribe`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq)]
+pub enum SubscriptionKind {
+	/// New block headers.
+	#[serde(rename = "newHeads")]
+	NewHeads,
+	/// Logs matching a filter.
+	#[serde(rename = "logs")]
+	Logs,
+	/// Hashes of transactions submitted to the node.
+	#[serde(rename = "newPendingTransactions")]
+	NewPendingTransactions,
+}
+
+/// An item sent to `eth_subscribe` subscribers.
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(untagged)]
+pub enum SubscriptionItem {
+	/// A new block header.
+	Header(Box<Block>),
+	/// A log matching the subscription filter.
+	Log(Log),
+	/// The hash of a pending transaction.
+	TransactionHash(H256),
+}
+
+/// A new best block and the logs it emitted.
+#[derive(Debug, Clone)]
+pub struct NewBlockNotification {
+	/// The EVM block.
+	pub block: Block,
+	/// The logs emitted by the transactions of the block.
+	pub logs: Vec<Log>,
+}
+
+impl NewBlockNotification {
+	/// Return the logs of the block matching the filter.
+	pub fn matching_logs<'a>(&'a self, filter: &'a Filter) -> impl Iterator<Item = &'a Log> {
+		let block_matches = block_matches(filter, &self.block);
+		self.logs.iter().filter(move |log| block_matches && log_matches(filter, log))
+	}
+}
+
+/// The state of an installed filter.
+enum FilterState {
+	/// A log filter and the matching logs since the last poll.
+	Logs(Filter, VecDeque<Log>),
+	/// The hashes of the new blocks since the last poll.
+	Blocks(VecDeque<H256>),
+	/// The hashes of the pending transactions since the last poll.
+	PendingTransactions(VecDeque<H256>),
+}
+
+/// A filter installed with `eth_newFilter`, `eth_newBlockFilter` or
+/// `eth_newPendingTransactionFilter`.
+struct InstalledFilter {
+	/// The connection that installed the filter.
+	connection_id: ConnectionId,
+	/// The last time the filter was polled.
+	last_poll: Instant,
+	/// The filter state.
+	state: FilterState,
+}
+
+/// Errors returned when installing or polling filters.
+#[derive(Debug, thiserror::Error, Eq, PartialEq)]
+pub enum FilterError {
+	/// The filter does not exist or has expired.
+	#[error("filter not found")]
+	NotFound,
+	/// The connection has reached the maximum number of installed filters.
+	#[error("too many filters installed, the limit is {0}")]
+	TooManyFilters(usize),
+	/// The maximum number of filters installed across all connections has been reached.
+	#[error("too many filters installed on the node, the limit is {0}")]
+	NodeFilterLimit(usize),
+	/// The filter is not a log filter.
+	#[error("filter is not a log filter")]
+	NotALogFilter,
+}
+
+#[derive(Default)]
+struct Filters {
+	/// The installed filters.
+	filters: HashMap<U256, InstalledFilter>,
+}
+
+impl Filters {
+	/// Return the filter with the given id, if it was installed by the given connection.
+	fn get_mut(
+		&mut self,
+		id: &U256,
+		connection_id: ConnectionId,
+	) -> Result<&mut InstalledFilter, FilterError> {
+		// Filters of other connections are reported as missing, so that their ids can't be
+		// probed.
+		self.filters
+			.get_mut(id)
+			.filter(|filter| filter.connection_id == connection_id)
+			.ok_or(FilterError::NotFound)
+	}
+}
+
+/// FilterProvider keeps track of installed filters and feeds `eth_subscribe` subscriptions.
+#[derive(Clone)]
+pub struct FilterProvider {
+	/// The installed filters.
+	filters: Arc<Mutex<Filters>>,
+	/// The maximum number of filters a connection can install.
+	max_filters_per_connection: usize,
+	/// The maximum number of filters installed across all connections.
+	max_filters: usize,
+	/// The duration after which a filter that is not polled is removed.
+	filter_timeout: Duration,
+	/// New best blocks notifications.
+	new_blocks: broadcast::Sender<Arc<NewBlockNotification>>,
+	/// Pending transactions notifications.
+	pending_transactions: broadcast::Sender<H256>,
+}
+
+impl FilterProvider {
+	/// Create a new `FilterProvider`.
+	pub fn new(
+		max_filters_per_connection: usize,
+		max_filters: usize,
+		filter_timeout: Duration,
+	) -> Self {
+		let (new_blocks, _) = broadcast::channel(NOTIFICATION_CHANNEL_CAPACITY);
+		let (pending_transactions, _) = broadcast::channel(NOTIFICATION_CHANNEL_CAPACITY);
+		Self {
+			filters: Default::default(),
+			max_filters_per_connection,
+			max_filters,
+			filter_timeout,
+			new_blocks,
+			pending_transactions,
+		}
+	}
+
+	/// Subscribe to new best blocks.
+	pub fn subscribe_new_blocks(&self) -> broadcast::Receiver<Arc<NewBlockNotification>> {
+		self.new_blocks.subscribe()
+	}
+
+	/// Subscribe to pending transactions.
+	pub fn subscribe_pending_transactions(&self) -> broadcast::Receiver<H256> {
+		self.pending_transactions.subscribe()
+	}
+
+	/// Notify subscribers and installed filters of a new best block.
+	pub async fn notify_new_block(&self, block: Block, logs: Vec<Log>) {
+		let mut filters = self.filters.lock().await;
+		self.remove_expired(&mut filters, Instant::now());
+		for filter in filters.filters.values_mut() {
+			match &mut filter.state {
+				FilterState::Logs(log_filter, changes) => {
+					if !block_matches(log_filter, &block) {
+						continue;
+					}
+					let logs = logs.iter().filter(|log| log_matches(log_filter, log)).cloned();
+					push_bounded(changes, logs);
+				},
+				FilterState::Blocks(changes) => push_bounded(changes, [block.hash]),
+				FilterState::PendingTransactions(_) => {},
+			}
+		}
+		drop(filters);
+
+		// Sending only fails if there are no subscribers.
+		let _ = self.new_blocks.send(Arc::new(NewBlockNotification { block, logs }));
+	}
+
+	/// Notify subscribers and installed filters of a new pending transaction.
+	pub async fn notify_pending_transaction(&self, hash: H256) {
+		let mut filters = self.filters.lock().await;
+		self.remove_expired(&mut filters, Instant::now());
+		for filter in filters.filters.values_mut() {
+			if let FilterState::PendingTransactions(changes) = &mut filter.state {
+				push_bounded(changes, [hash]);
+			}
+		}
+		drop(filters);
+
+		// Sending only fails if there are no subscribers.
+		let _ = self.pending_transactions.send(hash);
+	}
+
+	/// Install a log filter for the given connection.
+	pub async fn new_filter(
+		&self,
+		connection_id: ConnectionId,
+		filter: Filter,
+	) -> Result<U256, FilterError> {
+		self.install(connection_id, FilterState::Logs(filter, Default::default())).await
+	}
+
+	/// Install a new block filter for the given connection.
+	pub async fn new_block_filter(&self, connection_id: ConnectionId) -> Result<U256, FilterError> {
+		self.install(connection_id, FilterState::Blocks(Default::default())).await
+	}
+
+	/// Install a pending transaction filter for the given connection.
+	pub async fn new_pending_transaction_filter(
+		&self,
+		connection_id: ConnectionId,
+	) -> Result<U256, FilterError> {
+		self.install(connection_id, FilterState::PendingTransactions(Default::default()))
+			.await
+	}
+
+	/// Return the changes of the filter since the last poll.
+	///
+	/// Only the connection that installed the filter can poll it.
+	pub async fn filter_changes(
+		&self,
+		connection_id: ConnectionId,
+		id: U256,
+	) -> Result<FilterResults, FilterError> {
+		let now = Instant::now();
+		let mut filters = self.filters.lock().await;
+		self.remove_expired(&mut filters, now);
+		let filter = filters.get_mut(&id, connection_id)?;
+		filter.last_poll = now;
+		let results = match &mut filter.state {
+			FilterState::Logs(_, changes) => FilterResults::Logs(changes.drain(..).collect()),
+			FilterState::Blocks(changes) | FilterState::PendingTransactions(changes) =>
+				FilterResults::Hashes(changes.drain(..).collect()),
+		};
+		Ok(results)
+	}
+
+	/// Return the log filter with the given id.
+	///
+	/// Only the connection that installed the filter can read it.
+	pub async fn log_filter(
+		&self,
+		connection_id: ConnectionId,
+		id: U256,
+	) -> Result<Filter, FilterError> {
+		let now = Instant::now();
+		let mut filters = self.filters.lock().await;
+		self.remove_expired(&mut filters, now);
+		let filter = filters.get_mut(&id, connection_id)?;
+		filter.last_poll = now;
+		match &filter.state {
+			FilterState::Logs(log_filter, _) => Ok(log_filter.clone()),
+			_ => Err(FilterError::NotALogFilter),
+		}
+	}
+
+	/// Uninstall the filter, returns `true` if the filter existed.
+	///
+	/// Only the connection that installed the filter can uninstall it.
+	pub async fn uninstall_filter(&self, connection_id: ConnectionId, id: U256) -> bool {
+		let mut filters = self.filters.lock().await;
+		if filters.get_mut(&id, connection_id).is_err() {
+			return false;
+		}
+		filters.filters.remove(&id).is_some()
+	}
+
+	async fn install(
+		&self,
+		connection_id: ConnectionId,
+		state: FilterState,
+	) -> Result<U256, FilterError> {
+		let now = Instant::now();
+		let mut filters = self.filters.lock().await;
+		self.remove_expired(&mut filters, now);
+
+		// Each HTTP request may come from a new connection, so the per connection limit alone
+		// does not bound the number of filters.
+		if filters.filters.len() >= self.max_filters {
+			log::debug!(target: LOG_TARGET, "Reached the node filter limit");
+			return Err(FilterError::NodeFilterLimit(self.max_filters));
+		}
+
+		let installed =
+			filters.filters.values().filter(|f| f.connection_id == connection_id).count();
+		if installed >= self.max_filters_per_connection {
+			log::debug!(target: LOG_TARGET, "Connection {connection_id:?} reached the filter limit");
+			return Err(FilterError::TooManyFilters(self.max_filters_per_connection));
+		}
+
+		// Filter ids are random, so that they can't be guessed by other connections.
+		let id = loop {
+			let id = U256::from(rand::random::<u128>());
+			if !filters.filters.contains_key(&id) {
+				break id;
+			}
+		};
+		filters
+			.filters
+			.insert(id, InstalledFilter { connection_id, last_poll: now, state });
+		log::trace!(target: LOG_TARGET, "Installed filter {id:?} for connection {connection_id:?}");
+		Ok(id)
+	}
+
+	/// Remove the filters that have not been polled within the filter timeout.
+	fn remove_expired(&self, filters: &mut Filters, now: Instant) {
+		filters.filters.retain(|id, filter| {
+			let keep = now.saturating_duration_since(filter.last_poll) < self.filter_timeout;
+			if !keep {
+				log::trace!(target: LOG_TARGET, "Filter {id:?} expired");
+			}
+			keep
+		});
+	}
+}
+
+/// Push the items into the queue, dropping the oldest items once the queue is full.
+fn push_bounded<T>(queue: &mut VecDeque<T>, items: impl IntoIterator<Item = T>) {
+	for item in items {
+		if queue.len() == MAX_PENDING_CHANGES {
+			queue.pop_front();
+		}
+		queue.push_back(item);
+	}
+}
+
+/// Check if the block is within the block range or matches the block hash of the filter.
+pub fn block_matches(filter: &Filter, block: &Block) -> bool {
+	if let Some(hash) = filter.block_hash {
+		return hash == block.hash;
+	}
+
+	let number = block.number;
+	let after_start = match filter.from_block {
+		Some(BlockNumberOrTag::U256(from)) => number >= from,
+		_ => true,
+	};
+	let before_end = match filter.to_block {
+		Some(BlockNumberOrTag::U256(to)) => number <= to,
+		_ => true,
+	};
+	after_start && before_end
+}
+
+/// Check if the log matches the address and topics of the filter.
+pub fn log_matches(filter: &Filter, log: &Log) -> bool {
+	let address_matches = match &filter.address {
+		None => true,
+		Some(AddressOrAddresses::Address(address)) => &log.address == address,
+		Some(AddressOrAddresses::Addresses(addresses)) =>
+			addresses.is_empty() || addresses.contains(&log.address),
+	};
+	if !address_matches {
+		return false;
+	}
+
+	let Some(topics) = &filter.topics else { return true };
+	topics.iter().enumerate().all(|(i, topic)| match topic {
+		FilterTopic::Single(hash) => log.topics.get(i) == Some(hash),
+		FilterTopic::Multiple(hashes) =>
+			hashes.is_empty() || log.topics.get(i).is_some_and(|t| hashes.contains(t)),
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::Address;
+	use pretty_assertions::assert_eq;
+
+	fn provider() -> FilterProvider {
+		FilterProvider::new(2, 4, Duration::from_secs(60))
+	}
+
+	fn block(number: u64) -> Block {
+		Block { number: number.into(), hash: H256::from_low_u64_be(number), ..Default::default() }
+	}
+
+	fn log(address: Address, topics: Vec<H256>) -> Log {
+		Log { address, topics, ..Default::default() }
+	}
+
+	#[tokio::test]
+	async fn test_filter_limit_per_connection() {
+		let provider = provider();
+		let (conn_1, conn_2) = (ConnectionId(1), ConnectionId(2));
+
+		let id = provider.new_block_filter(conn_1).await.unwrap();
+		provider.new_pending_transaction_filter(conn_1).await.unwrap();
+		assert_eq!(
+			provider.new_filter(conn_1, Filter::default()).await,
+			Err(FilterError::TooManyFilters(2))
+		);
+
+		// Other connections are not affected.
+		provider.new_block_filter(conn_2).await.unwrap();
+
+		// Uninstalling a filter frees a slot.
+		assert!(provider.uninstall_filter(conn_1, id).await);
+		assert!(!provider.uninstall_filter(conn_1, id).await);
+		provider.new_filter(conn_1, Filter::default()).await.unwrap();
+	}
+
+	#[tokio::test]
+	async fn test_filter_limit_per_node() {
+		let provider = provider();
+
+		// Every filter is installed from a different connection, as with HTTP requests.
+		for i in 0..4 {
+			provider.new_block_filter(ConnectionId(i)).await.unwrap();
+		}
+		assert_eq!(
+			provider.new_block_filter(ConnectionId(4)).await,
+			Err(FilterError::NodeFilterLimit(4))
+		);
+	}
+
+	#[tokio::test]
+	async fn test_filters_are_owned_by_their_connection() {
+		let provider = provider();
+		let (owner, other) = (ConnectionId(1), ConnectionId(2));
+
+		let id = provider.new_filter(owner, Filter::default()).await.unwrap();
+		assert_ne!(id, provider.new_filter(owner, Filter::default()).await.unwrap());
+
+		// Other connections can't see, poll or uninstall the filter.
+		assert_eq!(provider.filter_changes(other, id).await, Err(FilterError::NotFound));
+		assert_eq!(provider.log_filter(other, id).await, Err(FilterError::NotFound));
+		assert!(!provider.uninstall_filter(other, id).await);
+
+		assert_eq!(provider.log_filter(owner, id).await, Ok(Filter::default()));
+		assert_eq!(provider.filter_changes(owner, id).await, Ok(FilterResults::Logs(vec![])));
+		assert!(provider.uninstall_filter(owner, id).await);
+	}
+
+	#[tokio::test]
+	async fn test_subscriptions() {
+		let provider = provider();
+		let mut new_blocks = provider.subscribe_new_blocks();
+		let mut pending_transactions = provider.subscribe_pending_transactions();
+
+		let address = Address::from_low_u64_be(1);
+		let matching = log(address, vec![]);
+		let logs = vec![matching.clone(), log(Address::from_low_u64_be(2), vec![])];
+		provider.notify_new_block(block(1), logs).await;
+		provider.notify_pending_transaction(H256::from_low_u64_be(7)).await;
+
+		let notification = new_blocks.recv().await.unwrap();
+		assert_eq!(notification.block, block(1));
+
+		let filter =
+			Filter { address: Some(AddressOrAddresses::Address(address)), ..Default::default() };
+		assert_eq!(notification.matching_logs(&filter).collect::<Vec<_>>(), vec![&matching]);
+		let filter = Filter { from_block: Some(U256::from(2).into()), ..filter };
+		assert_eq!(notification.matching_logs(&filter).count(), 0);
+
+		assert_eq!(pending_transactions.recv().await.unwrap(), H256::from_low_u64_be(7));
+	}
+
+	#[tokio::test]
+	async fn test_filter_changes() {
+		let provider = provider();
+		let address = Address::from_low_u64_be(1);
+		let topic = H256::from_low_u64_be(42);
+		let log_filter = Filter {
+			address: Some(AddressOrAddresses::Address(address)),
+			topics: Some(vec![FilterTopic::Single(topic)]),
+			..Default::default()
+		};
+
+		let (conn_1, conn_2) = (ConnectionId(1), ConnectionId(2));
+		let block_filter = provider.new_block_filter(conn_1).await.unwrap();
+		let tx_filter = provider.new_pending_transaction_filter(conn_1).await.unwrap();
+		let log_filter = provider.new_filter(conn_2, log_filter).await.unwrap();
+
+		let matching = log(address, vec![topic]);
+		let logs = vec![
+			matching.clone(),
+			log(Address::from_low_u64_be(2), vec![topic]),
+			log(address, vec![H256::from_low_u64_be(1)]),
+		];
+		provider.notify_new_block(block(1), logs).await;
+		provider.notify_pending_transaction(H256::from_low_u64_be(7)).await;
+
+		assert_eq!(
+			provider.filter_changes(conn_1, block_filter).await,
+			Ok(FilterResults::Hashes(vec![block(1).hash]))
+		);
+		assert_eq!(
+			provider.filter_changes(conn_1, tx_filter).await,
+			Ok(FilterResults::Hashes(vec![H256::from_low_u64_be(7)]))
+		);
+		assert_eq!(
+			provider.filter_changes(conn_2, log_filter).await,
+			Ok(FilterResults::Logs(vec![matching]))
+		);
+
+		// Changes are drained on poll.
+		assert_eq!(
+			provider.filter_changes(conn_1, block_filter).await,
+			Ok(FilterResults::Hashes(vec![]))
+		);
+		assert_eq!(
+			provider.log_filter(conn_1, block_filter).await,
+			Err(FilterError::NotALogFilter)
+		);
+	}
+
+	#[tokio::test]
+	async fn test_filter_timeout() {
+		let provider = provider();
+		let id = provider.new_block_filter(ConnectionId(1)).await.unwrap();
+
+		let mut filters = provider.filters.lock().await;
+		provider.remove_expired(&mut filters, Instant::now() + Duration::from_secs(30));
+		assert!(filters.filters.contains_key(&id));
+		provider.remove_expired(&mut filters, Instant::now() + Duration::from_secs(61));
+		assert!(filters.filters.is_empty());
+		drop(filters);
+
+		assert_eq!(provider.filter_changes(ConnectionId(1), id).await, Err(FilterError::NotFound));
+	}
+
+	#[test]
+	fn test_block_matches() {
+		let filter = Filter {
+			from_block: Some(U256::from(2).into()),
+			to_block: Some(U256::from(3).into()),
+			..Default::default()
+		};
+		assert!(!block_matches(&filter, &block(1)));
+		assert!(block_matches(&filter, &block(2)));
+		assert!(block_matches(&filter, &block(3)));
+		assert!(!block_matches(&filter, &block(4)));
+
+		let filter = Filter { block_hash: Some(block(5).hash), ..Default::default() };
+		assert!(block_matches(&filter, &block(5)));
+		assert!(!block_matches(&filter, &block(6)));
+	}
+}
@@ -16,7 +16,7 @@
 // limitations under the License.
 
 use crate::evm::Bytes;
-use alloc::{string::String, vec::Vec};
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
 use codec::{Decode, Encode};
 use derive_more::From;
 use scale_info::TypeInfo;
@@ -24,12 +24,20 @@ use serde::{Deserialize, Serialize};
 use sp_core::{H160, H256, U256};
 
 /// The type of tracer to use.
-/// Only "callTracer" is supported for now.
 #[derive(TypeInfo, Debug, Clone, Encode, Decode, Serialize, Deserialize, PartialEq)]
 #[serde(tag = "tracer", content = "tracerConfig", rename_all = "camelCase")]
 pub enum TracerType {
 	/// A tracer that traces calls.
 	CallTracer(Option<CallTracerConfig>),
+
+	/// A tracer that reports the state of the accounts touched by a transaction.
+	PrestateTracer(Option<PrestateTracerConfig>),
+
+	/// A tracer that logs every host function executed by the contracts.
+	///
+	/// Unlike the geth struct logger, steps are recorded per host function call (ecall) and not
+	/// per opcode, as the contracts are executed by PolkaVM.
+	StructLogger(Option<StructLoggerConfig>),
 }
 
 impl From<CallTracerConfig> for TracerType {
@@ -38,6 +46,18 @@ impl From<CallTracerConfig> for TracerType {
 	}
 }
 
+impl From<PrestateTracerConfig> for TracerType {
+	fn from(config: PrestateTracerConfig) -> Self {
+		TracerType::PrestateTracer(Some(config))
+	}
+}
+
+impl From<StructLoggerConfig> for TracerType {
+	fn from(config: StructLoggerConfig) -> Self {
+		TracerType::StructLogger(Some(config))
+	}
+}
+
 impl Default for TracerType {
 	fn default() -> Self {
 		TracerType::CallTracer(Some(CallTracerConfig::default()))
@@ -74,6 +94,34 @@ impl Default for CallTracerConfig {
 	}
 }
 
+/// The configuration for the prestate tracer.
+#[derive(Clone, Debug, Default, Decode, Serialize, Deserialize, Encode, PartialEq, TypeInfo)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PrestateTracerConfig {
+	/// Whether to return the state changes of the transaction instead of the prestate.
+	pub diff_mode: bool,
+
+	/// Whether to omit the code of the contracts from the trace.
+	pub disable_code: bool,
+
+	/// Whether to omit the storage of the contracts from the trace.
+	pub disable_storage: bool,
+}
+
+/// The configuration for the struct logger.
+#[derive(Clone, Debug, Default, Decode, Serialize, Deserialize, Encode, PartialEq, TypeInfo)]
+#[serde(default, rename_all = "camelCase")]
+pub struct StructLoggerConfig {
+	/// Whether to omit the storage accessed by each step from the trace.
+	pub disable_storage: bool,
+
+	/// Whether to include the return data of the last call in each step.
+	pub enable_return_data: bool,
+
+	/// The maximum number of steps to log. `0` means no limit.
+	pub limit: u64,
+}
+
 /// Serialization should support the following JSON format:
 ///
 /// ```json
@@ -111,6 +159,30 @@ fn test_tracer_config_serialization() {
 				timeout: Some(core::time::Duration::from_millis(10)),
 			},
 		),
+		(
+			r#"{"tracer": "prestateTracer", "tracerConfig": { "diffMode": true }}"#,
+			TracerConfig {
+				config: PrestateTracerConfig {
+					diff_mode: true,
+					disable_code: false,
+					disable_storage: false,
+				}
+				.into(),
+				timeout: None,
+			},
+		),
+		(
+			r#"{"tracer": "structLogger", "tracerConfig": { "enableReturnData": true, "limit": 10 }}"#,
+			TracerConfig {
+				config: StructLoggerConfig {
+					disable_storage: false,
+					enable_return_data: true,
+					limit: 10,
+				}
+				.into(),
+				timeout: None,
+			},
+		),
 	];
 
 	for (json_data, expected) in tracers {
@@ -141,6 +213,10 @@ pub enum CallType {
 pub enum Trace {
 	/// A call trace.
 	Call(CallTrace),
+	/// A struct logger trace.
+	StructLogger(StructLoggerTrace),
+	/// A prestate trace.
+	Prestate(PrestateTrace),
 }
 
 /// A smart contract execution call trace.
@@ -200,6 +276,106 @@ pub struct CallLog {
 	pub position: u32,
 }
 
+/// A prestate trace.
+#[derive(TypeInfo, Encode, Decode, Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(untagged)]
+pub enum PrestateTrace {
+	/// The state of all the accounts touched by the transaction, before it was executed.
+	Prestate(BTreeMap<H160, PrestateTraceInfo>),
+
+	/// The state changes caused by the transaction.
+	///
+	/// Only the accounts that were modified are included.
+	DiffMode {
+		/// The state of the modified accounts before the transaction.
+		pre: BTreeMap<H160, PrestateTraceInfo>,
+		/// The modified fields of the accounts after the transaction.
+		post: BTreeMap<H160, PrestateTraceInfo>,
+	},
+}
+
+impl Default for PrestateTrace {
+	fn default() -> Self {
+		PrestateTrace::Prestate(Default::default())
+	}
+}
+
+/// The state of an account in a [`PrestateTrace`].
+#[derive(
+	TypeInfo, Default, Encode, Decode, Serialize, Deserialize, Clone, Debug, Eq, PartialEq,
+)]
+pub struct PrestateTraceInfo {
+	/// The balance of the account.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub balance: Option<U256>,
+	/// The nonce of the account.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub nonce: Option<u64>,
+	/// The code of the contract.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub code: Option<Bytes>,
+	/// The storage slots accessed by the transaction.
+	///
+	/// A `None` value means that the slot is empty.
+	#[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+	pub storage: BTreeMap<Bytes, Option<Bytes>>,
+}
+
+impl PrestateTraceInfo {
+	/// Returns `true` if no field of the account is set.
+	pub fn is_empty(&self) -> bool {
+		self.balance.is_none() &&
+			self.nonce.is_none() &&
+			self.code.is_none() &&
+			self.storage.is_empty()
+	}
+}
+
+/// The trace produced by the struct logger.
+#[derive(
+	TypeInfo, Default, Encode, Decode, Serialize, Deserialize, Clone, Debug, Eq, PartialEq,
+)]
+pub struct StructLoggerTrace<Gas = U256> {
+	/// Amount of gas used by the transaction.
+	pub gas: Gas,
+	/// Whether the transaction failed or reverted.
+	pub failed: bool,
+	/// Return data of the transaction.
+	#[serde(rename = "returnValue")]
+	pub return_value: Bytes,
+	/// The logged steps, one per host function call.
+	#[serde(rename = "structLogs")]
+	pub struct_logs: Vec<StructLog<Gas>>,
+}
+
+/// A single step logged by the struct logger.
+///
+/// A step is a host function call (ecall) made by a contract, recorded between entering and
+/// exiting the host function. The instructions executed between two host function calls are not
+/// logged individually.
+#[derive(
+	TypeInfo, Default, Encode, Decode, Serialize, Deserialize, Clone, Debug, Eq, PartialEq,
+)]
+pub struct StructLog<Gas = U256> {
+	/// The program counter of the ecall instruction in the contract code.
+	pub pc: u32,
+	/// The name of the host function, in place of the opcode name.
+	pub op: String,
+	/// Amount of gas left before the step.
+	pub gas: Gas,
+	/// Amount of gas consumed by the step.
+	#[serde(rename = "gasCost")]
+	pub gas_cost: Gas,
+	/// Call depth of the contract, starting at `1`.
+	pub depth: u32,
+	/// The return data of the last call made by the contract.
+	#[serde(rename = "returnData", skip_serializing_if = "Option::is_none")]
+	pub return_data: Option<Bytes>,
+	/// The storage of the contract accessed so far, included on steps that access storage.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub storage: Option<BTreeMap<Bytes, Bytes>>,
+}
+
 /// A transaction trace
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct TransactionTrace {
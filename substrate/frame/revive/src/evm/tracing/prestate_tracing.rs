@@ -0,0 +1,223 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::{
+	evm::{Bytes, PrestateTrace, PrestateTraceInfo, PrestateTracerConfig},
+	primitives::ExecReturnValue,
+	tracing::Tracing,
+	AddressMapper, BalanceOf, Config, ContractInfoOf, DispatchError, MomentOf, Pallet,
+	PristineCode, Weight,
+};
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::marker::PhantomData;
+use frame_support::{traits::IsType, DebugNoBound};
+use sp_core::{H160, H256, U256};
+use sp_runtime::{traits::Bounded, SaturatedConversion};
+
+/// Storage writes grouped by contract address.
+type StorageWrites = BTreeMap<H160, BTreeMap<Bytes, Option<Bytes>>>;
+
+/// A Tracer that reports the state of the accounts touched by a transaction.
+#[derive(DebugNoBound)]
+pub struct PrestateTracer<T> {
+	/// The tracer configuration.
+	config: PrestateTracerConfig,
+	/// The state of the touched accounts, before the transaction modified them.
+	pre: BTreeMap<H160, PrestateTraceInfo>,
+	/// Stack of the active call frames, with the contract whose storage they access and their
+	/// pending storage writes.
+	frames: Vec<(H160, StorageWrites)>,
+	/// The storage writes of the frames that were committed up to the transaction.
+	committed: StorageWrites,
+	_phantom: PhantomData<T>,
+}
+
+impl<T: Config> PrestateTracer<T>
+where
+	BalanceOf<T>: Into<U256> + TryFrom<U256> + Bounded,
+	MomentOf<T>: Into<U256>,
+	T::Hash: IsType<H256>,
+{
+	/// Create a new [`PrestateTracer`] instance.
+	pub fn new(config: PrestateTracerConfig) -> Self {
+		Self {
+			config,
+			pre: Default::default(),
+			frames: Default::default(),
+			committed: Default::default(),
+			_phantom: PhantomData,
+		}
+	}
+
+	/// Collect the trace and return it.
+	pub fn collect_trace(&mut self) -> Option<PrestateTrace> {
+		let pre = core::mem::take(&mut self.pre);
+		let mut committed = core::mem::take(&mut self.committed);
+		self.frames.clear();
+
+		if pre.is_empty() {
+			return None
+		}
+
+		if !self.config.diff_mode {
+			return Some(PrestateTrace::Prestate(pre))
+		}
+
+		let mut pre_diff = BTreeMap::new();
+		let mut post_diff = BTreeMap::new();
+		for (address, mut pre_info) in pre {
+			let current = self.read_account(&address);
+			let mut post_info = PrestateTraceInfo {
+				balance: current.balance.filter(|_| current.balance != pre_info.balance),
+				nonce: current.nonce.filter(|_| current.nonce != pre_info.nonce),
+				code: current.code.clone().filter(|_| current.code != pre_info.code),
+				storage: Default::default(),
+			};
+
+			for (key, value) in committed.remove(&address).unwrap_or_default() {
+				if pre_info.storage.get(&key) != Some(&value) {
+					post_info.storage.insert(key, value);
+				}
+			}
+
+			if post_info.is_empty() {
+				continue
+			}
+
+			pre_info.storage.retain(|key, _| post_info.storage.contains_key(key));
+			pre_diff.insert(address, pre_info);
+			post_diff.insert(address, post_info);
+		}
+
+		Some(PrestateTrace::DiffMode { pre: pre_diff, post: post_diff })
+	}
+
+	/// Read the current state of the given account, without its storage.
+	fn read_account(&self, address: &H160) -> PrestateTraceInfo {
+		let account_id = T::AddressMapper::to_account_id(address);
+		let code = if self.config.disable_code {
+			None
+		} else {
+			ContractInfoOf::<T>::get(address)
+				.and_then(|info| PristineCode::<T>::get(info.code_hash))
+				.map(|code| code.into_inner().into())
+		};
+
+		PrestateTraceInfo {
+			balance: Some(Pallet::<T>::evm_balance(address)),
+			nonce: Some(frame_system::Pallet::<T>::account_nonce(&account_id).saturated_into()),
+			code,
+			storage: Default::default(),
+		}
+	}
+
+	/// Record the state of the given account, if it was not touched yet.
+	fn touch_account(&mut self, address: H160) {
+		if !self.pre.contains_key(&address) {
+			let info = self.read_account(&address);
+			self.pre.insert(address, info);
+		}
+	}
+
+	/// Record the value of a storage slot of the current contract, if it was not touched yet.
+	fn touch_storage(&mut self, key: &[u8], value: Option<&[u8]>) {
+		let Some((address, _)) = self.frames.last() else { return };
+		self.pre
+			.entry(*address)
+			.or_default()
+			.storage
+			.entry(key.to_vec().into())
+			.or_insert_with(|| value.map(|value| value.to_vec().into()));
+	}
+
+	/// Pop the current frame, and merge its storage writes into its parent if it succeeded.
+	fn exit_frame(&mut self, success: bool) {
+		let Some((_, writes)) = self.frames.pop() else { return };
+		if !success {
+			return
+		}
+
+		let parent = match self.frames.last_mut() {
+			Some((_, parent)) => parent,
+			None => &mut self.committed,
+		};
+		for (address, slots) in writes {
+			parent.entry(address).or_default().extend(slots);
+		}
+	}
+}
+
+impl<T: Config> Tracing for PrestateTracer<T>
+where
+	BalanceOf<T>: Into<U256> + TryFrom<U256> + Bounded,
+	MomentOf<T>: Into<U256>,
+	T::Hash: IsType<H256>,
+{
+	fn enter_child_span(
+		&mut self,
+		from: H160,
+		to: H160,
+		_is_delegate_call: bool,
+		_is_read_only: bool,
+		_value: U256,
+		_input: &[u8],
+		_gas_left: Weight,
+	) {
+		self.touch_account(from);
+		self.touch_account(to);
+		self.frames.push((to, Default::default()));
+	}
+
+	fn storage_read(&mut self, key: &[u8], value: Option<&[u8]>) {
+		if !self.config.disable_storage {
+			self.touch_storage(key, value);
+		}
+	}
+
+	fn storage_write(&mut self, key: &[u8], old_value: Option<Vec<u8>>, new_value: Option<&[u8]>) {
+		if self.config.disable_storage {
+			return
+		}
+
+		self.touch_storage(key, old_value.as_deref());
+		if let Some((address, writes)) = self.frames.last_mut() {
+			writes
+				.entry(*address)
+				.or_default()
+				.insert(key.to_vec().into(), new_value.map(|value| value.to_vec().into()));
+		}
+	}
+
+	// Transient storage is empty before and discarded after every transaction, so like geth the
+	// prestate tracer leaves it out of the reported state.
+	fn transient_storage_read(&mut self, _key: &[u8], _value: Option<&[u8]>) {}
+
+	fn transient_storage_write(
+		&mut self,
+		_key: &[u8],
+		_old_value: Option<Vec<u8>>,
+		_new_value: Option<&[u8]>,
+	) {
+	}
+
+	fn exit_child_span(&mut self, output: &ExecReturnValue, _gas_used: Weight) {
+		self.exit_frame(!output.did_revert());
+	}
+
+	fn exit_child_span_with_error(&mut self, _error: DispatchError, _gas_used: Weight) {
+		self.exit_frame(false);
+	}
+}
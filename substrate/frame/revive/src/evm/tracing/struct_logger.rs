@@ -0,0 +1,169 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::{
+	evm::{Bytes, StructLog, StructLoggerConfig, StructLoggerTrace},
+	primitives::ExecReturnValue,
+	tracing::Tracing,
+	DispatchError, Weight,
+};
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use sp_core::{H160, U256};
+
+/// The state of a call frame tracked by the [`StructLogger`].
+#[derive(Default, Debug, Clone, PartialEq)]
+struct Frame {
+	/// The storage accessed by the contract so far.
+	storage: BTreeMap<Bytes, Bytes>,
+	/// Whether the host function being executed accessed the storage.
+	storage_accessed: bool,
+	/// The return data of the last call made by the contract.
+	return_data: Vec<u8>,
+	/// The index of the step being executed and the gas left before it.
+	pending_step: Option<(usize, Weight)>,
+}
+
+/// A Tracer that logs each host function executed by the contracts of a transaction.
+///
+/// Steps are opened in [`Tracing::enter_ecall`] and closed in [`Tracing::exit_ecall`], so a step
+/// covers a whole host function call rather than a single opcode.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct StructLogger<Gas, GasMapper> {
+	/// Map Weight to Gas equivalent.
+	gas_mapper: GasMapper,
+	/// The trace being built.
+	trace: Option<StructLoggerTrace<Gas>>,
+	/// Stack of the active call frames.
+	frames: Vec<Frame>,
+	/// The tracer configuration.
+	config: StructLoggerConfig,
+}
+
+impl<Gas, GasMapper> StructLogger<Gas, GasMapper> {
+	/// Create a new [`StructLogger`] instance.
+	pub fn new(config: StructLoggerConfig, gas_mapper: GasMapper) -> Self {
+		Self { gas_mapper, trace: None, frames: Vec::new(), config }
+	}
+
+	/// Collect the trace and return it.
+	pub fn collect_trace(&mut self) -> Option<StructLoggerTrace<Gas>> {
+		self.frames.clear();
+		self.trace.take()
+	}
+
+	/// Pop the current frame, and record the outcome of the transaction if it was the top one.
+	fn exit_frame(&mut self, output: &[u8], failed: bool, gas_used: Weight)
+	where
+		GasMapper: Fn(Weight) -> Gas,
+	{
+		self.frames.pop();
+
+		match self.frames.last_mut() {
+			Some(parent) => parent.return_data = output.to_vec(),
+			None =>
+				if let Some(trace) = self.trace.as_mut() {
+					trace.gas = (self.gas_mapper)(gas_used);
+					trace.failed = failed;
+					trace.return_value = output.to_vec().into();
+				},
+		}
+	}
+}
+
+impl<Gas: Default, GasMapper: Fn(Weight) -> Gas> Tracing for StructLogger<Gas, GasMapper> {
+	fn enter_child_span(
+		&mut self,
+		_from: H160,
+		_to: H160,
+		_is_delegate_call: bool,
+		_is_read_only: bool,
+		_value: U256,
+		_input: &[u8],
+		_gas_left: Weight,
+	) {
+		if self.frames.is_empty() {
+			self.trace = Some(Default::default());
+		}
+
+		self.frames.push(Default::default());
+	}
+
+	fn storage_read(&mut self, key: &[u8], value: Option<&[u8]>) {
+		if self.config.disable_storage {
+			return;
+		}
+
+		if let Some(frame) = self.frames.last_mut() {
+			frame
+				.storage
+				.insert(key.to_vec().into(), value.unwrap_or_default().to_vec().into());
+			frame.storage_accessed = true;
+		}
+	}
+
+	fn storage_write(&mut self, key: &[u8], _old_value: Option<Vec<u8>>, new_value: Option<&[u8]>) {
+		self.storage_read(key, new_value);
+	}
+
+	fn enter_ecall(&mut self, pc: u32, name: &[u8], gas_left: Weight) {
+		let depth = self.frames.len() as u32;
+		let (Some(trace), Some(frame)) = (self.trace.as_mut(), self.frames.last_mut()) else {
+			return;
+		};
+
+		if self.config.limit != 0 && trace.struct_logs.len() as u64 >= self.config.limit {
+			return;
+		}
+
+		let return_data = (self.config.enable_return_data && !frame.return_data.is_empty())
+			.then(|| frame.return_data.clone().into());
+
+		trace.struct_logs.push(StructLog {
+			pc,
+			op: String::from_utf8_lossy(name).into_owned(),
+			gas: (self.gas_mapper)(gas_left),
+			gas_cost: Default::default(),
+			depth,
+			return_data,
+			storage: None,
+		});
+		frame.storage_accessed = false;
+		frame.pending_step = Some((trace.struct_logs.len() - 1, gas_left));
+	}
+
+	fn exit_ecall(&mut self, gas_left: Weight) {
+		let (Some(trace), Some(frame)) = (self.trace.as_mut(), self.frames.last_mut()) else {
+			return;
+		};
+
+		let Some((index, gas_before)) = frame.pending_step.take() else { return };
+		let step = &mut trace.struct_logs[index];
+		step.gas_cost = (self.gas_mapper)(gas_before.saturating_sub(gas_left));
+
+		if frame.storage_accessed {
+			step.storage = Some(frame.storage.clone());
+			frame.storage_accessed = false;
+		}
+	}
+
+	fn exit_child_span(&mut self, output: &ExecReturnValue, gas_used: Weight) {
+		self.exit_frame(&output.data, output.did_revert(), gas_used);
+	}
+
+	fn exit_child_span_with_error(&mut self, _error: DispatchError, gas_used: Weight) {
+		self.exit_frame(&[], true, gas_used);
+	}
+}
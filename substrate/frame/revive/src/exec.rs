@@ -89,6 +89,11 @@ impl Key {
 	/// Only used by benchmarking in order to generate storage collisions on purpose.
 	#[cfg(feature = "runtime-benchmarks")]
 	pub fn unhashed(&self) -> &[u8] {
+		self.traced()
+	}
+
+	/// Reference to the raw unhashed key, as reported to tracers.
+	pub(crate) fn traced(&self) -> &[u8] {
 		match self {
 			Key::Fix(v) => v.as_ref(),
 			Key::Var(v) => v.as_ref(),
@@ -1629,7 +1634,11 @@ where
 	T::Hash: frame_support::traits::IsType<H256>,
 {
 	fn get_storage(&mut self, key: &Key) -> Option<Vec<u8>> {
-		self.top_frame_mut().contract_info().read(key)
+		let value = self.top_frame_mut().contract_info().read(key);
+		if_tracing(|tracer| {
+			tracer.storage_read(key.traced(), value.as_deref());
+		});
+		value
 	}
 
 	fn get_storage_size(&mut self, key: &Key) -> Option<u32> {
@@ -1642,13 +1651,22 @@ where
 		value: Option<Vec<u8>>,
 		take_old: bool,
 	) -> Result<WriteOutcome, DispatchError> {
+		// Tracers are handed the old value, which is only read when it is taken.
+		let mut traced_value = None;
+		if_tracing(|_| traced_value = Some(value.clone()));
 		let frame = self.top_frame_mut();
-		frame.contract_info.get(&frame.account_id).write(
+		let outcome = frame.contract_info.get(&frame.account_id).write(
 			key.into(),
 			value,
 			Some(&mut frame.nested_storage),
-			take_old,
-		)
+			take_old || traced_value.is_some(),
+		)?;
+		let Some(new_value) = traced_value else { return Ok(outcome) };
+		let (old_value, outcome) = outcome.split_taken(take_old);
+		if_tracing(|tracer| {
+			tracer.storage_write(key.traced(), old_value, new_value.as_deref());
+		});
+		Ok(outcome)
 	}
 
 	fn charge_storage(&mut self, diff: &Diff) {
@@ -1799,7 +1817,11 @@ where
 	}
 
 	fn get_transient_storage(&self, key: &Key) -> Option<Vec<u8>> {
-		self.transient_storage.read(self.account_id(), key)
+		let value = self.transient_storage.read(self.account_id(), key);
+		if_tracing(|tracer| {
+			tracer.transient_storage_read(key.traced(), value.as_deref());
+		});
+		value
 	}
 
 	fn get_transient_storage_size(&self, key: &Key) -> Option<u32> {
@@ -1815,7 +1837,21 @@ where
 		take_old: bool,
 	) -> Result<WriteOutcome, DispatchError> {
 		let account_id = self.account_id().clone();
-		self.transient_storage.write(&account_id, key, value, take_old)
+		// Tracers are handed the old value, which is only read when it is taken.
+		let mut traced_value = None;
+		if_tracing(|_| traced_value = Some(value.clone()));
+		let outcome = self.transient_storage.write(
+			&account_id,
+			key,
+			value,
+			take_old || traced_value.is_some(),
+		)?;
+		let Some(new_value) = traced_value else { return Ok(outcome) };
+		let (old_value, outcome) = outcome.split_taken(take_old);
+		if_tracing(|tracer| {
+			tracer.transient_storage_write(key.traced(), old_value, new_value.as_deref());
+		});
+		Ok(outcome)
 	}
 
 	fn account_id(&self) -> &T::AccountId {
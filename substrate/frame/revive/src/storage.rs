@@ -351,6 +351,19 @@ impl WriteOutcome {
 		}
 	}
 
+	/// Splits the outcome of a write that took the old value into that value and the outcome
+	/// the write would have had with `take`.
+	pub(crate) fn split_taken(self, take: bool) -> (Option<Vec<u8>>, Self) {
+		match self {
+			Self::Taken(value) if take => (Some(value.clone()), Self::Taken(value)),
+			Self::Taken(value) => {
+				let len = value.len() as u32;
+				(Some(value), Self::Overwritten(len))
+			},
+			outcome => (None, outcome),
+		}
+	}
+
 	/// Extracts the size of the overwritten value or `SENTINEL` if there
 	/// was no value in storage.
 	///
@@ -263,6 +263,7 @@ impl pallet_proxy::Config for Test {
 	type AnnouncementDepositBase = ConstU64<1>;
 	type AnnouncementDepositFactor = ConstU64<1>;
 	type BlockNumberProvider = frame_system::Pallet<Test>;
+	type SpendingLimits = ();
 }
 
 parameter_types! {
@@ -4215,6 +4216,215 @@ fn tracing_works() {
 	});
 }
 
+#[test]
+fn prestate_tracing_works() {
+	use crate::evm::*;
+	let (code, _) = compile_module("multi_store").unwrap();
+	ExtBuilder::default().existential_deposit(200).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 100_000_000);
+		let Contract { addr, .. } =
+			builder::bare_instantiate(Code::Upload(code.clone())).build_and_unwrap_contract();
+
+		let slot_1: Bytes = [1u8; 32].to_vec().into();
+		let slot_2: Bytes = [2u8; 32].to_vec().into();
+
+		// Without diff mode the state before the call is reported.
+		let mut tracer = PrestateTracer::<Test>::new(Default::default());
+		trace(&mut tracer, || {
+			builder::bare_call(addr).data((2u32, 0u32).encode()).build_and_unwrap_result();
+		});
+
+		let Some(PrestateTrace::Prestate(pre)) = tracer.collect_trace() else {
+			panic!("expected a prestate trace");
+		};
+		assert!(pre.contains_key(&ALICE_ADDR));
+		assert_eq!(pre[&addr].code, Some(code.into()));
+		assert_eq!(
+			pre[&addr].storage,
+			[(slot_1.clone(), None), (slot_2.clone(), None)].into_iter().collect()
+		);
+
+		// With diff mode only the modified slots are reported.
+		let config =
+			PrestateTracerConfig { diff_mode: true, disable_code: true, ..Default::default() };
+		let mut tracer = PrestateTracer::<Test>::new(config.clone());
+		trace(&mut tracer, || {
+			builder::bare_call(addr).data((4u32, 0u32).encode()).build_and_unwrap_result();
+		});
+
+		let Some(PrestateTrace::DiffMode { pre, post }) = tracer.collect_trace() else {
+			panic!("expected a diff mode trace");
+		};
+		assert_eq!(
+			pre[&addr].storage,
+			[(slot_1.clone(), Some(vec![0u8; 2].into()))].into_iter().collect()
+		);
+		assert_eq!(
+			post[&addr].storage,
+			[(slot_1, Some(vec![0u8; 4].into()))].into_iter().collect()
+		);
+		assert_eq!(post[&addr].code, None);
+
+		// Reverted calls don't modify the state.
+		let mut tracer = PrestateTracer::<Test>::new(config);
+		trace(&mut tracer, || {
+			let _ = builder::bare_call(addr).data(vec![]).build();
+		});
+		let Some(PrestateTrace::DiffMode { post, .. }) = tracer.collect_trace() else {
+			panic!("expected a diff mode trace");
+		};
+		assert!(post.get(&addr).map_or(true, |info| info.storage.is_empty()));
+	});
+}
+
+#[test]
+fn struct_logger_works() {
+	use crate::evm::*;
+	let (code, _) = compile_module("storage").unwrap();
+	ExtBuilder::default().existential_deposit(200).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 100_000_000);
+		let Contract { addr, .. } =
+			builder::bare_instantiate(Code::Upload(code)).build_and_unwrap_contract();
+
+		let mut tracer = StructLogger::new(Default::default(), |w: Weight| w.ref_time());
+		trace(&mut tracer, || {
+			builder::bare_call(addr).build_and_unwrap_result();
+		});
+
+		let trace = tracer.collect_trace().unwrap();
+		assert!(!trace.failed);
+
+		let set_storage = trace
+			.struct_logs
+			.iter()
+			.find(|step| step.op == "set_storage")
+			.expect("set_storage is logged");
+		assert_eq!(set_storage.depth, 1);
+		assert!(set_storage.gas_cost > 0);
+		assert_eq!(
+			set_storage.storage,
+			Some([([1u8; 32].to_vec().into(), [1u8; 4].to_vec().into())].into_iter().collect())
+		);
+
+		// The number of logged steps can be limited.
+		let config = StructLoggerConfig { limit: 1, ..Default::default() };
+		let mut tracer = StructLogger::new(config, |w: Weight| w.ref_time());
+		trace(&mut tracer, || {
+			builder::bare_call(addr).build_and_unwrap_result();
+		});
+		assert_eq!(tracer.collect_trace().unwrap().struct_logs.len(), 1);
+	});
+}
+
+/// Records the storage accesses reported to the tracer, flagged by whether they are transient.
+#[derive(Default)]
+struct StorageRecorder {
+	reads: Vec<(bool, Vec<u8>, Option<Vec<u8>>)>,
+	writes: Vec<(bool, Vec<u8>, Option<Vec<u8>>, Option<Vec<u8>>)>,
+}
+
+impl crate::tracing::Tracing for StorageRecorder {
+	fn storage_read(&mut self, key: &[u8], value: Option<&[u8]>) {
+		self.reads.push((false, key.to_vec(), value.map(|v| v.to_vec())));
+	}
+
+	fn storage_write(&mut self, key: &[u8], old_value: Option<Vec<u8>>, new_value: Option<&[u8]>) {
+		self.writes
+			.push((false, key.to_vec(), old_value, new_value.map(|v| v.to_vec())));
+	}
+
+	fn transient_storage_read(&mut self, key: &[u8], value: Option<&[u8]>) {
+		self.reads.push((true, key.to_vec(), value.map(|v| v.to_vec())));
+	}
+
+	fn transient_storage_write(
+		&mut self,
+		key: &[u8],
+		old_value: Option<Vec<u8>>,
+		new_value: Option<&[u8]>,
+	) {
+		self.writes.push((true, key.to_vec(), old_value, new_value.map(|v| v.to_vec())));
+	}
+}
+
+#[test]
+fn storage_writes_are_traced() {
+	let (code, _) = compile_module("storage").unwrap();
+	ExtBuilder::default().existential_deposit(200).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 100_000_000);
+		let Contract { addr, .. } =
+			builder::bare_instantiate(Code::Upload(code)).build_and_unwrap_contract();
+
+		let mut tracer = StorageRecorder::default();
+		trace(&mut tracer, || {
+			builder::bare_call(addr).build_and_unwrap_result();
+		});
+
+		// Set, clear and take are all reported together with the value they replace.
+		let key = vec![1u8; 32];
+		let write = |old: Option<[u8; 4]>, new: Option<[u8; 4]>| {
+			(false, key.clone(), old.map(|v| v.to_vec()), new.map(|v| v.to_vec()))
+		};
+		assert_eq!(
+			tracer.writes,
+			vec![
+				write(None, Some([1; 4])),
+				write(Some([1; 4]), Some([2; 4])),
+				write(Some([2; 4]), None),
+				write(None, Some([3; 4])),
+				write(Some([3; 4]), None),
+				write(None, Some([3; 4])),
+				write(Some([3; 4]), None),
+			]
+		);
+		assert!(tracer.reads.iter().all(|(transient, ..)| !transient));
+	});
+}
+
+#[test]
+fn transient_storage_is_traced_separately() {
+	use crate::evm::*;
+	let (code, _) = compile_module("transient_storage").unwrap();
+	ExtBuilder::default().existential_deposit(200).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 100_000_000);
+		let Contract { addr, .. } =
+			builder::bare_instantiate(Code::Upload(code)).build_and_unwrap_contract();
+
+		let mut tracer = StorageRecorder::default();
+		trace(&mut tracer, || {
+			builder::bare_call(addr).build_and_unwrap_result();
+		});
+
+		let key = vec![1u8; 32];
+		assert_eq!(
+			tracer.writes,
+			vec![
+				(true, key.clone(), None, Some(vec![1; 4])),
+				(true, key.clone(), Some(vec![1; 4]), Some(vec![2; 5])),
+				(true, key.clone(), Some(vec![2; 5]), None),
+				(true, key.clone(), None, Some(vec![3; 6])),
+				(true, key.clone(), Some(vec![3; 6]), None),
+			]
+		);
+		assert_eq!(
+			tracer.reads,
+			vec![(true, key.clone(), Some(vec![1; 4])), (true, key, Some(vec![2; 5]))]
+		);
+
+		// Transient storage is not part of the state reported by the prestate tracer.
+		let config = PrestateTracerConfig { diff_mode: true, ..Default::default() };
+		let mut tracer = PrestateTracer::<Test>::new(config);
+		trace(&mut tracer, || {
+			builder::bare_call(addr).build_and_unwrap_result();
+		});
+		let Some(PrestateTrace::DiffMode { pre, post }) = tracer.collect_trace() else {
+			panic!("expected a diff mode trace");
+		};
+		assert!(pre.get(&addr).map_or(true, |info| info.storage.is_empty()));
+		assert!(post.get(&addr).map_or(true, |info| info.storage.is_empty()));
+	});
+}
+
 #[test]
 fn unknown_precompiles_revert() {
 	let (code, _code_hash) = compile_module("read_only_call").unwrap();
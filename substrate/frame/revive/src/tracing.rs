@@ -16,6 +16,7 @@
 // limitations under the License.
 
 use crate::{primitives::ExecReturnValue, DispatchError, Weight};
+use alloc::vec::Vec;
 use environmental::environmental;
 use sp_core::{H160, H256, U256};
 
@@ -57,6 +58,36 @@ pub trait Tracing {
 	/// Record a log event
 	fn log_event(&mut self, _event: H160, _topics: &[H256], _data: &[u8]) {}
 
+	/// Called when the current contract reads a storage slot
+	fn storage_read(&mut self, _key: &[u8], _value: Option<&[u8]>) {}
+
+	/// Called when the current contract writes, clears or takes a storage slot
+	fn storage_write(
+		&mut self,
+		_key: &[u8],
+		_old_value: Option<Vec<u8>>,
+		_new_value: Option<&[u8]>,
+	) {
+	}
+
+	/// Called when the current contract reads a transient storage slot
+	fn transient_storage_read(&mut self, _key: &[u8], _value: Option<&[u8]>) {}
+
+	/// Called when the current contract writes, clears or takes a transient storage slot
+	fn transient_storage_write(
+		&mut self,
+		_key: &[u8],
+		_old_value: Option<Vec<u8>>,
+		_new_value: Option<&[u8]>,
+	) {
+	}
+
+	/// Called before the current contract executes a host function
+	fn enter_ecall(&mut self, _pc: u32, _name: &[u8], _gas_left: Weight) {}
+
+	/// Called after the current contract executed a host function
+	fn exit_ecall(&mut self, _gas_left: Weight) {}
+
 	/// Called after a contract call is executed
 	fn exit_child_span(&mut self, _output: &ExecReturnValue, _gas_left: Weight) {}
 
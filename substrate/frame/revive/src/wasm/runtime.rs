@@ -121,6 +121,7 @@ pub trait PolkaVmInstance<T: Config>: Memory<T> {
 	fn set_gas(&mut self, gas: polkavm::Gas);
 	fn read_input_regs(&self) -> (u64, u64, u64, u64, u64, u64);
 	fn write_output(&mut self, output: u64);
+	fn program_counter(&self) -> u32;
 }
 
 // Memory implementation used in benchmarking where guest memory is mapped into the host.
@@ -191,6 +192,10 @@ impl<T: Config> PolkaVmInstance<T> for polkavm::RawInstance {
 	fn write_output(&mut self, output: u64) {
 		self.set_reg(polkavm::Reg::A0, output);
 	}
+
+	fn program_counter(&self) -> u32 {
+		self.program_counter().map_or(0, |pc| pc.0)
+	}
 }
 
 impl From<&ExecReturnValue> for ReturnErrorCode {
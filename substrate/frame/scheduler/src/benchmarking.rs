@@ -62,6 +62,25 @@ fn fill_schedule<T: Config>(when: BlockNumberFor<T>, n: u32) -> Result<(), &'sta
 	Ok(())
 }
 
+/// Add `n` cron tasks occurring every minute.
+fn fill_cron<T: Config>(n: u32) -> Result<(), &'static str> {
+	let schedule = CronSchedule::from_expression(b"* * * * *").ok_or("invalid schedule")?;
+	let origin: <T as Config>::PalletsOrigin = frame_system::RawOrigin::Root.into();
+	for i in 0..n {
+		let call = make_call::<T>(None);
+		Pallet::<T>::do_schedule_cron(
+			u32_to_name(i),
+			schedule,
+			CatchUp::RunOnce,
+			None,
+			origin.clone(),
+			call,
+		)?;
+	}
+	ensure!(CronQueue::<T>::get().len() == n as usize, "didn't fill cron queue");
+	Ok(())
+}
+
 fn u32_to_name(i: u32) -> TaskName {
 	i.using_encoded(blake2_256)
 }
@@ -506,6 +525,94 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn schedule_cron(s: Linear<0, { T::MaxCronTasks::get() - 1 }>) -> Result<(), BenchmarkError> {
+		let schedule = CronSchedule::from_expression(b"* * * * *").unwrap();
+		// Essentially a no-op call.
+		let call = Box::new(SystemCall::set_storage { items: vec![] }.into());
+
+		fill_cron::<T>(s)?;
+		let name = u32_to_name(s);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, name, schedule, CatchUp::RunAll, Some(100), call);
+
+		ensure!(CronTasks::<T>::contains_key(name), "didn't add cron task");
+		ensure!(CronQueue::<T>::get().len() == s as usize + 1, "didn't add to cron queue");
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_cron_schedule(s: Linear<1, { T::MaxCronTasks::get() }>) -> Result<(), BenchmarkError> {
+		let schedule = CronSchedule::from_expression(b"0 0 * * *").unwrap();
+
+		fill_cron::<T>(s)?;
+		let name = u32_to_name(0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, name, schedule, CatchUp::Skip);
+
+		ensure!(
+			CronTasks::<T>::get(name).map(|task| task.schedule) == Some(schedule),
+			"didn't change the schedule"
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_cron(s: Linear<1, { T::MaxCronTasks::get() }>) -> Result<(), BenchmarkError> {
+		fill_cron::<T>(s)?;
+		let name = u32_to_name(0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, name);
+
+		ensure!(!CronTasks::<T>::contains_key(name), "didn't remove cron task");
+		ensure!(CronQueue::<T>::get().len() == s as usize - 1, "didn't remove from cron queue");
+
+		Ok(())
+	}
+
+	// `service_cron` when no cron task is due.
+	#[benchmark]
+	fn service_cron_base(s: Linear<0, { T::MaxCronTasks::get() }>) -> Result<(), BenchmarkError> {
+		fill_cron::<T>(s)?;
+		let now = T::TimeProvider::now().as_secs().max(1);
+		let mut executed = 0;
+
+		#[block]
+		{
+			Pallet::<T>::service_cron(&mut WeightMeter::new(), &mut executed, now);
+		}
+
+		assert_eq!(executed, 0);
+
+		Ok(())
+	}
+
+	// `service_cron_task` when the task is a non-fetched call which is not dispatched (e.g. due to
+	// being overweight).
+	#[benchmark]
+	fn service_cron_task() -> Result<(), BenchmarkError> {
+		fill_cron::<T>(1)?;
+		let name = u32_to_name(0);
+		let task = CronTasks::<T>::get(name).ok_or(BenchmarkError::Weightless)?;
+		let now = task.next;
+		// prevent any tasks from actually being executed as we only want the surrounding weight.
+		let mut counter = WeightMeter::with_limit(Weight::zero());
+		let mut executed = 1;
+		let _result;
+
+		#[block]
+		{
+			_result = Pallet::<T>::service_cron_task(&mut counter, now, &mut executed, name, task);
+		}
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite! {
 		Pallet,
 		mock::new_test_ext(),
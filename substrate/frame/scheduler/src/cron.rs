@@ -0,0 +1,451 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Calendar based recurring tasks.
+//!
+//! Unlike the periodic tasks of the agendas, which recur every given number of blocks, cron tasks
+//! recur at the UTC calendar times matched by a [`CronSchedule`], according to
+//! [`Config::TimeProvider`]. Since the timestamp of a block is usually only set after its
+//! `on_initialize`, the cron tasks are serviced against the time of the previous block, after the
+//! agendas.
+
+use super::*;
+
+/// The number of days searched for the next occurrence of a [`CronSchedule`], which covers the
+/// eight years that may separate two leap days.
+const MAX_SEARCH_DAYS: u64 = 366 * 8;
+
+const ALL_MINUTES: u64 = (1 << 60) - 1;
+const ALL_HOURS: u32 = (1 << 24) - 1;
+const ALL_DAYS_OF_MONTH: u32 = !1;
+const ALL_MONTHS: u16 = 0b1_1111_1111_1110;
+const ALL_DAYS_OF_WEEK: u8 = 0b111_1111;
+
+/// UTC calendar times at which a cron task recurs, at the granularity of minutes.
+///
+/// Each field is the bit set of the values it matches. As with cron, when both the days of the
+/// month and the days of the week are restricted, a day matching either of them is matched.
+#[derive(
+	Clone,
+	Copy,
+	RuntimeDebug,
+	PartialEq,
+	Eq,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+pub struct CronSchedule {
+	/// Minutes of the hour, bits `0..=59`.
+	pub minutes: u64,
+	/// Hours of the day, bits `0..=23`.
+	pub hours: u32,
+	/// Days of the month, bits `1..=31`.
+	pub days_of_month: u32,
+	/// Months of the year, bits `1..=12`.
+	pub months: u16,
+	/// Days of the week, bits `0..=6` starting on Sunday.
+	pub days_of_week: u8,
+}
+
+impl CronSchedule {
+	/// Parses a cron expression of five fields: minutes, hours, days of the month, months and days
+	/// of the week.
+	///
+	/// Each field is a comma separated list of `*`, values `n`, ranges `n-m` and steps `*/s`,
+	/// `n/s` or `n-m/s`. Sunday is either `0` or `7`. Names of months and days are not supported.
+	pub fn from_expression(expression: &[u8]) -> Option<Self> {
+		let mut fields =
+			expression.split(u8::is_ascii_whitespace).filter(|field| !field.is_empty());
+		let minutes = parse_field(fields.next()?, 0, 59)?;
+		let hours = parse_field(fields.next()?, 0, 23)?;
+		let days_of_month = parse_field(fields.next()?, 1, 31)?;
+		let months = parse_field(fields.next()?, 1, 12)?;
+		let days_of_week = parse_field(fields.next()?, 0, 7)?;
+		if fields.next().is_some() {
+			return None
+		}
+		let schedule = Self {
+			minutes,
+			hours: hours as u32,
+			days_of_month: days_of_month as u32,
+			months: months as u16,
+			days_of_week: ((days_of_week | days_of_week >> 7) & ALL_DAYS_OF_WEEK as u64) as u8,
+		};
+		schedule.is_valid().then_some(schedule)
+	}
+
+	/// Whether every field matches some of its values and nothing else.
+	pub fn is_valid(&self) -> bool {
+		self.minutes != 0 &&
+			self.minutes & !ALL_MINUTES == 0 &&
+			self.hours != 0 &&
+			self.hours & !ALL_HOURS == 0 &&
+			self.days_of_month != 0 &&
+			self.days_of_month & !ALL_DAYS_OF_MONTH == 0 &&
+			self.months != 0 &&
+			self.months & !ALL_MONTHS == 0 &&
+			self.days_of_week != 0 &&
+			self.days_of_week & !ALL_DAYS_OF_WEEK == 0
+	}
+
+	/// The unix time in seconds of the first minute matched by the schedule after `moment`.
+	///
+	/// Returns `None` if the schedule does not match any minute within the next eight years.
+	pub fn next_after(&self, moment: u64) -> Option<u64> {
+		let minute = moment / 60 + 1;
+		let (mut day, mut minute_of_day) = (minute / 1440, minute % 1440);
+		for _ in 0..MAX_SEARCH_DAYS {
+			if self.matches_day(day) {
+				if let Some(minute) = self.first_minute_from(minute_of_day) {
+					return day.checked_mul(1440)?.checked_add(minute)?.checked_mul(60)
+				}
+			}
+			day = day.saturating_add(1);
+			minute_of_day = 0;
+		}
+		None
+	}
+
+	/// Whether the schedule matches the `day` since the unix epoch.
+	fn matches_day(&self, day: u64) -> bool {
+		let (month, day_of_month) = civil_from_days(day);
+		// The unix epoch was a Thursday.
+		let day_of_week = (day + 4) % 7;
+		if self.months & (1 << month) == 0 {
+			return false
+		}
+		let month_matches = self.days_of_month & (1 << day_of_month) != 0;
+		let week_matches = self.days_of_week & (1 << day_of_week) != 0;
+		if self.days_of_month == ALL_DAYS_OF_MONTH || self.days_of_week == ALL_DAYS_OF_WEEK {
+			month_matches && week_matches
+		} else {
+			month_matches || week_matches
+		}
+	}
+
+	/// The first minute of a matched day, from `minute_of_day` on.
+	fn first_minute_from(&self, minute_of_day: u64) -> Option<u64> {
+		let (hour, minute) = (minute_of_day / 60, minute_of_day % 60);
+		(hour..24).filter(|h| self.hours & (1 << h) != 0).find_map(|h| {
+			let minutes =
+				if h == hour { self.minutes & (ALL_MINUTES << minute) } else { self.minutes };
+			(minutes != 0).then(|| h * 60 + minutes.trailing_zeros() as u64)
+		})
+	}
+}
+
+/// The month and day of the month of the `days` since the unix epoch, in the proleptic Gregorian
+/// calendar.
+fn civil_from_days(days: u64) -> (u64, u64) {
+	// Shift the epoch to 0000-03-01, so that leap days end the years.
+	let days = days + 719_468;
+	let day_of_era = days % 146_097;
+	let year_of_era =
+		(day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+	let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+	let shifted_month = (5 * day_of_year + 2) / 153;
+	let day_of_month = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+	let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+	(month, day_of_month)
+}
+
+/// Parses a field of a cron expression into the bit set of its values within `min..=max`.
+fn parse_field(field: &[u8], min: u64, max: u64) -> Option<u64> {
+	let mut bits = 0u64;
+	for part in field.split(|b| *b == b',') {
+		let (range, step) = match part.iter().position(|b| *b == b'/') {
+			Some(i) => (&part[..i], Some(parse_number(&part[i + 1..])?)),
+			None => (part, None),
+		};
+		let (start, end) = if range == b"*" {
+			(min, max)
+		} else if let Some(i) = range.iter().position(|b| *b == b'-') {
+			(parse_number(&range[..i])?, parse_number(&range[i + 1..])?)
+		} else {
+			let start = parse_number(range)?;
+			(start, if step.is_some() { max } else { start })
+		};
+		let step = step.unwrap_or(1);
+		if step == 0 || start < min || start > end || end > max {
+			return None
+		}
+		for value in (start..=end).step_by(step as usize) {
+			bits |= 1 << value;
+		}
+	}
+	Some(bits)
+}
+
+/// Parses a number of at most two decimal digits.
+fn parse_number(digits: &[u8]) -> Option<u64> {
+	if digits.is_empty() || digits.len() > 2 {
+		return None
+	}
+	digits
+		.iter()
+		.try_fold(0, |n, d| d.is_ascii_digit().then(|| n * 10 + (d - b'0') as u64))
+}
+
+/// What to do with the occurrences of a cron task which were missed, e.g. because the chain
+/// stalled or the scheduler ran out of weight.
+#[derive(
+	Clone,
+	Copy,
+	RuntimeDebug,
+	PartialEq,
+	Eq,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+pub enum CatchUp {
+	/// Dispatch the task only for an occurrence at most [`Config::CronGracePeriod`] old, skipping
+	/// the older ones.
+	Skip,
+	/// Dispatch the task once for all of its missed occurrences.
+	RunOnce,
+	/// Dispatch the task once for each of its missed occurrences, over several blocks if needed.
+	RunAll,
+}
+
+/// A task dispatched at the times matched by a [`CronSchedule`].
+#[derive(Clone, RuntimeDebug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct CronTask<Call, PalletsOrigin> {
+	/// The times at which the task recurs.
+	pub schedule: CronSchedule,
+	/// What to do with the missed occurrences of the task.
+	pub catch_up: CatchUp,
+	/// The call to be dispatched.
+	pub call: Call,
+	/// The origin with which to dispatch the call.
+	pub origin: PalletsOrigin,
+	/// The unix time in seconds of the next occurrence of the task.
+	pub next: u64,
+	/// The number of dispatches left, if limited.
+	pub remaining: Option<u32>,
+}
+
+pub type CronTaskOf<T> = CronTask<BoundedCallOf<T>, <T as Config>::PalletsOrigin>;
+
+pub type CronQueueOf<T> = BoundedVec<(u64, TaskName), <T as Config>::MaxCronTasks>;
+
+impl<T: Config> Pallet<T> {
+	pub(crate) fn do_schedule_cron(
+		id: TaskName,
+		schedule: CronSchedule,
+		catch_up: CatchUp,
+		maybe_runs: Option<u32>,
+		origin: T::PalletsOrigin,
+		call: BoundedCallOf<T>,
+	) -> Result<u64, DispatchError> {
+		// ensure id it is unique
+		ensure!(!CronTasks::<T>::contains_key(&id), Error::<T>::FailedToSchedule);
+		ensure!(maybe_runs != Some(0), Error::<T>::InvalidCronSchedule);
+		let next = Self::next_cron_time(&schedule)?;
+		CronQueue::<T>::try_mutate(|queue| Self::enqueue_cron(queue, next, id))?;
+
+		if let Some(hash) = call.lookup_hash() {
+			// Request the call to be made available.
+			T::Preimages::request(&hash);
+		}
+		CronTasks::<T>::insert(
+			id,
+			CronTask { schedule, catch_up, call, origin, next, remaining: maybe_runs },
+		);
+		Self::deposit_event(Event::CronScheduled { id, next });
+		Ok(next)
+	}
+
+	pub(crate) fn do_set_cron_schedule(
+		origin: &T::PalletsOrigin,
+		id: TaskName,
+		schedule: CronSchedule,
+		catch_up: CatchUp,
+	) -> Result<u64, DispatchError> {
+		let next = Self::next_cron_time(&schedule)?;
+		CronTasks::<T>::try_mutate(id, |maybe_task| -> DispatchResult {
+			let task = maybe_task.as_mut().ok_or(Error::<T>::NotFound)?;
+			Self::ensure_privilege(origin, &task.origin)?;
+			CronQueue::<T>::try_mutate(|queue| {
+				queue.retain(|(_, name)| name != &id);
+				Self::enqueue_cron(queue, next, id)
+			})?;
+			task.schedule = schedule;
+			task.catch_up = catch_up;
+			task.next = next;
+			Ok(())
+		})?;
+		Self::deposit_event(Event::CronScheduled { id, next });
+		Ok(next)
+	}
+
+	pub(crate) fn do_cancel_cron(origin: &T::PalletsOrigin, id: TaskName) -> DispatchResult {
+		let task = CronTasks::<T>::get(id).ok_or(Error::<T>::NotFound)?;
+		Self::ensure_privilege(origin, &task.origin)?;
+		CronQueue::<T>::mutate(|queue| queue.retain(|(_, name)| name != &id));
+		CronTasks::<T>::remove(id);
+		T::Preimages::drop(&task.call);
+		Self::deposit_event(Event::CronCanceled { id });
+		Ok(())
+	}
+
+	/// The unix time of the first occurrence of `schedule` after the current time.
+	fn next_cron_time(schedule: &CronSchedule) -> Result<u64, DispatchError> {
+		ensure!(schedule.is_valid(), Error::<T>::InvalidCronSchedule);
+		schedule
+			.next_after(T::TimeProvider::now().as_secs())
+			.ok_or(Error::<T>::InvalidCronSchedule.into())
+	}
+
+	/// Insert the task `id` into the `queue`, after the tasks due at the same time.
+	fn enqueue_cron(queue: &mut CronQueueOf<T>, next: u64, id: TaskName) -> DispatchResult {
+		let index = queue.partition_point(|(due, _)| *due <= next);
+		queue
+			.try_insert(index, (next, id))
+			.map_err(|_| Error::<T>::TooManyCronTasks.into())
+	}
+
+	/// Service the cron tasks due by the unix time `now`, dispatching at most
+	/// `T::MaxScheduledPerBlock` of them.
+	pub(crate) fn service_cron(weight: &mut WeightMeter, executed: &mut u32, now: u64) {
+		// The time is unknown until the first timestamp is set.
+		if now.is_zero() ||
+			weight
+				.try_consume(T::WeightInfo::service_cron_base(T::MaxCronTasks::get()))
+				.is_err()
+		{
+			return
+		}
+
+		let mut queue = CronQueue::<T>::get();
+		let mut changed = false;
+		// The cron tasks share the budget of `MaxScheduledPerBlock` dispatches per block with the
+		// agendas.
+		let mut count_down = T::MaxScheduledPerBlock::get().saturating_sub(*executed);
+		while count_down > 0 {
+			let Some(&(due, id)) = queue.first() else { break };
+			if due > now {
+				break
+			}
+			let Some(task) = CronTasks::<T>::get(id) else {
+				queue.remove(0);
+				changed = true;
+				continue
+			};
+			let base_weight =
+				T::WeightInfo::service_cron(task.call.lookup_len().map(|x| x as usize));
+			if !weight.can_consume(base_weight) {
+				break
+			}
+			queue.remove(0);
+			changed = true;
+			count_down.saturating_dec();
+
+			match Self::service_cron_task(weight, now, executed, id, task) {
+				Ok(Some(task)) => {
+					// will always succeed since the task was just removed from the queue.
+					let _ = Self::enqueue_cron(&mut queue, task.next, id);
+					CronTasks::<T>::insert(id, task);
+				},
+				Ok(None) => {},
+				Err(task) => {
+					let _ = Self::enqueue_cron(&mut queue, task.next, id);
+					break
+				},
+			}
+		}
+		if changed {
+			CronQueue::<T>::put(queue);
+		}
+	}
+
+	/// Service (i.e. execute) the due cron task `id`, being careful not to overflow the `weight`
+	/// counter.
+	///
+	/// Returns the task to be queued for its next occurrence, if any, or the unchanged task as an
+	/// error if it did not fit in the remaining weight.
+	pub(crate) fn service_cron_task(
+		weight: &mut WeightMeter,
+		now: u64,
+		executed: &mut u32,
+		id: TaskName,
+		mut task: CronTaskOf<T>,
+	) -> Result<Option<CronTaskOf<T>>, CronTaskOf<T>> {
+		let (call, lookup_len) = match T::Preimages::peek(&task.call) {
+			Ok(c) => c,
+			Err(_) => {
+				let _ = weight.try_consume(T::WeightInfo::service_cron(
+					task.call.lookup_len().map(|x| x as usize),
+				));
+				T::Preimages::drop(&task.call);
+				CronTasks::<T>::remove(id);
+				Self::deposit_event(Event::CronCallUnavailable { id });
+				return Ok(None)
+			},
+		};
+		let _ = weight.try_consume(T::WeightInfo::service_cron(lookup_len.map(|x| x as usize)));
+
+		// The occurrence for which the task is dispatched, if any, and the last occurrence handled.
+		let (occurrence, handled) = match task.catch_up {
+			CatchUp::Skip => {
+				let oldest = now.saturating_sub(T::CronGracePeriod::get());
+				let occurrence =
+					task.schedule.next_after(oldest.saturating_sub(1)).filter(|o| *o <= now);
+				(occurrence, now)
+			},
+			CatchUp::RunOnce => (Some(task.next), now),
+			CatchUp::RunAll => (Some(task.next), task.next),
+		};
+
+		if let Some(moment) = occurrence {
+			match Self::execute_dispatch(weight, task.origin.clone(), call) {
+				Err(()) if *executed == 0 => {
+					T::Preimages::drop(&task.call);
+					CronTasks::<T>::remove(id);
+					Self::deposit_event(Event::CronPermanentlyOverweight { id });
+					return Ok(None)
+				},
+				Err(()) => return Err(task),
+				Ok(result) => {
+					*executed += 1;
+					task.remaining = task.remaining.map(|n| n.saturating_sub(1));
+					Self::deposit_event(Event::CronDispatched { id, moment, result });
+				},
+			}
+		} else {
+			Self::deposit_event(Event::CronSkipped { id, moment: task.next });
+		}
+
+		match task.schedule.next_after(handled) {
+			Some(next) if task.remaining != Some(0) => {
+				task.next = next;
+				Ok(Some(task))
+			},
+			_ => {
+				T::Preimages::drop(&task.call);
+				CronTasks::<T>::remove(id);
+				Self::deposit_event(Event::CronCompleted { id });
+				Ok(None)
+			},
+		}
+	}
+}
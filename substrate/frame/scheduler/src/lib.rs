@@ -33,6 +33,12 @@
 //! number or at a specified period. These scheduled runtime calls may be named or anonymous and may
 //! be canceled.
 //!
+//! Named runtime calls may also recur at UTC calendar times, e.g. on the first day of every month,
+//! given by a cron-like [`CronSchedule`]. Such cron tasks follow the time of the chain rather than
+//! its block numbers, so they do not drift as the block times vary, and their [`CatchUp`] policy
+//! sets what happens with the occurrences missed when the chain stalls. Their schedule may be
+//! changed in place with `set_cron_schedule`.
+//!
 //! __NOTE:__ Instead of using the filter contained in the origin to call `fn schedule`, scheduled
 //! runtime calls will be dispatched with the default filter for the origin: namely
 //! `frame_system::Config::BaseCallFilter` for all origin types (except root which will get no
@@ -78,6 +84,7 @@
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+mod cron;
 pub mod migration;
 #[cfg(test)]
 mod mock;
@@ -96,7 +103,7 @@ use frame_support::{
 	traits::{
 		schedule::{self, DispatchTime, MaybeHashed},
 		Bounded, CallerTrait, EnsureOrigin, Get, IsType, OriginTrait, PalletInfoAccess,
-		PrivilegeCmp, QueryPreimage, StorageVersion, StorePreimage,
+		PrivilegeCmp, QueryPreimage, StorageVersion, StorePreimage, UnixTime,
 	},
 	weights::{Weight, WeightMeter},
 };
@@ -108,6 +115,7 @@ use sp_runtime::{
 	BoundedVec, DispatchError, RuntimeDebug,
 };
 
+pub use cron::*;
 pub use pallet::*;
 pub use weights::WeightInfo;
 
@@ -234,6 +242,16 @@ pub(crate) trait MarginalWeightInfo: WeightInfo {
 		}
 		total
 	}
+
+	fn service_cron(maybe_lookup_len: Option<usize>) -> Weight {
+		let mut total = Self::service_cron_task();
+		if let Some(l) = maybe_lookup_len {
+			total.saturating_accrue(
+				Self::service_task_fetched(l as u32).saturating_sub(Self::service_task_base()),
+			);
+		}
+		total
+	}
 }
 impl<T: WeightInfo> MarginalWeightInfo for T {}
 
@@ -293,6 +311,8 @@ pub mod pallet {
 
 		/// The maximum number of scheduled calls in the queue for a single block.
 		///
+		/// The cron tasks dispatched in a block count towards the same limit.
+		///
 		/// NOTE:
 		/// + Dependent pallets' benchmarks might require a higher limit for the setting. Set a
 		/// higher limit under `runtime-benchmarks` feature.
@@ -333,6 +353,20 @@ pub mod pallet {
 		/// swap block number providers on the fly, then please at least ensure that you do not run
 		/// any pallet migration in the same runtime upgrade.
 		type BlockNumberProvider: BlockNumberProvider;
+
+		/// The time at which cron tasks are dispatched, usually `pallet_timestamp`.
+		///
+		/// A time of zero is considered unknown and pauses the cron tasks.
+		type TimeProvider: UnixTime;
+
+		/// The maximum number of cron tasks.
+		#[pallet::constant]
+		type MaxCronTasks: Get<u32>;
+
+		/// The number of seconds after which an occurrence of a cron task with [`CatchUp::Skip`]
+		/// is missed.
+		#[pallet::constant]
+		type CronGracePeriod: Get<u64>;
 	}
 
 	/// Block number at which the agenda began incomplete execution.
@@ -367,6 +401,14 @@ pub mod pallet {
 	pub type Lookup<T: Config> =
 		StorageMap<_, Twox64Concat, TaskName, TaskAddress<BlockNumberFor<T>>>;
 
+	/// Cron tasks, indexed by their name.
+	#[pallet::storage]
+	pub type CronTasks<T: Config> = StorageMap<_, Twox64Concat, TaskName, CronTaskOf<T>>;
+
+	/// The names of the cron tasks, ordered by the unix time of their next occurrence.
+	#[pallet::storage]
+	pub type CronQueue<T: Config> = StorageValue<_, CronQueueOf<T>, ValueQuery>;
+
 	/// Events type.
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -401,6 +443,21 @@ pub mod pallet {
 		PermanentlyOverweight { task: TaskAddress<BlockNumberFor<T>>, id: Option<TaskName> },
 		/// Agenda is incomplete from `when`.
 		AgendaIncomplete { when: BlockNumberFor<T> },
+		/// Scheduled some cron task, or changed its schedule, to next occur at the unix time
+		/// `next`.
+		CronScheduled { id: TaskName, next: u64 },
+		/// Canceled some cron task.
+		CronCanceled { id: TaskName },
+		/// Dispatched some cron task for its occurrence at the unix time `moment`.
+		CronDispatched { id: TaskName, moment: u64, result: DispatchResult },
+		/// Skipped the missed occurrences of some cron task from the unix time `moment`.
+		CronSkipped { id: TaskName, moment: u64 },
+		/// The given cron task has no occurrences or dispatches left and has been removed.
+		CronCompleted { id: TaskName },
+		/// The call for the provided hash was not found so the cron task has been removed.
+		CronCallUnavailable { id: TaskName },
+		/// The given cron task can never be executed since it is overweight.
+		CronPermanentlyOverweight { id: TaskName },
 	}
 
 	#[pallet::error]
@@ -415,6 +472,10 @@ pub mod pallet {
 		RescheduleNoChange,
 		/// Attempt to use a non-named function on a named task.
 		Named,
+		/// The cron schedule is invalid or never occurs.
+		InvalidCronSchedule,
+		/// There are already `MaxCronTasks` cron tasks.
+		TooManyCronTasks,
 	}
 
 	#[pallet::hooks]
@@ -667,6 +728,63 @@ pub mod pallet {
 			Self::deposit_event(Event::RetryCancelled { task, id: Some(id) });
 			Ok(())
 		}
+
+		/// Schedule a named task to be dispatched at the UTC calendar times matched by
+		/// `schedule`, at most `maybe_runs` times.
+		///
+		/// The times are those of `T::TimeProvider`, so the task does not drift with the block
+		/// times. `catch_up` sets what happens with the occurrences missed when the chain stalls
+		/// or the scheduler runs out of weight.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::schedule_cron(T::MaxCronTasks::get()))]
+		pub fn schedule_cron(
+			origin: OriginFor<T>,
+			id: TaskName,
+			schedule: CronSchedule,
+			catch_up: CatchUp,
+			maybe_runs: Option<u32>,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_schedule_cron(
+				id,
+				schedule,
+				catch_up,
+				maybe_runs,
+				origin.caller().clone(),
+				T::Preimages::bound(*call)?,
+			)?;
+			Ok(())
+		}
+
+		/// Change the schedule and the catch up policy of a cron task in place, keeping its call,
+		/// origin and remaining dispatches.
+		///
+		/// The task next occurs at the first time matched by the new `schedule`.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_cron_schedule(T::MaxCronTasks::get()))]
+		pub fn set_cron_schedule(
+			origin: OriginFor<T>,
+			id: TaskName,
+			schedule: CronSchedule,
+			catch_up: CatchUp,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_set_cron_schedule(origin.caller(), id, schedule, catch_up)?;
+			Ok(())
+		}
+
+		/// Cancel a cron task.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_cron(T::MaxCronTasks::get()))]
+		pub fn cancel_cron(origin: OriginFor<T>, id: TaskName) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_cancel_cron(origin.caller(), id)?;
+			Ok(())
+		}
 	}
 }
 
@@ -1216,7 +1334,8 @@ enum ServiceTaskError {
 use ServiceTaskError::*;
 
 impl<T: Config> Pallet<T> {
-	/// Service up to `max` agendas queue starting from earliest incompletely executed agenda.
+	/// Service up to `max` agendas queue starting from earliest incompletely executed agenda, then
+	/// the due cron tasks.
 	fn service_agendas(weight: &mut WeightMeter, now: BlockNumberFor<T>, max: u32) {
 		if weight.try_consume(T::WeightInfo::service_agendas_base()).is_err() {
 			return
@@ -1241,6 +1360,8 @@ impl<T: Config> Pallet<T> {
 			Self::deposit_event(Event::AgendaIncomplete { when: incomplete_since });
 			IncompleteSince::<T>::put(incomplete_since);
 		}
+
+		Self::service_cron(weight, &mut executed, T::TimeProvider::now().as_secs());
 	}
 
 	/// Returns `true` if the agenda was fully completed, `false` if it should be revisited at a
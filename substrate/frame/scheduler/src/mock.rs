@@ -22,7 +22,7 @@ use super::*;
 use crate as scheduler;
 use frame_support::{
 	derive_impl, ord_parameter_types, parameter_types,
-	traits::{ConstU32, Contains, EitherOfDiverse, EqualPrivilegeOnly},
+	traits::{ConstU32, ConstU64, Contains, EitherOfDiverse, EqualPrivilegeOnly, UnixTime},
 };
 use frame_system::{EnsureRoot, EnsureSignedBy};
 use sp_runtime::{BuildStorage, Perbill};
@@ -211,10 +211,34 @@ impl WeightInfo for TestWeightInfo {
 	fn cancel_retry_named() -> Weight {
 		Weight::from_parts(50, 0)
 	}
+	fn schedule_cron(_s: u32) -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn set_cron_schedule(_s: u32) -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn cancel_cron(_s: u32) -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn service_cron_base(_s: u32) -> Weight {
+		Weight::from_parts(0b0000_0001, 0)
+	}
+	fn service_cron_task() -> Weight {
+		Weight::from_parts(0b0000_0100, 0)
+	}
 }
 parameter_types! {
 	pub storage MaximumSchedulerWeight: Weight = Perbill::from_percent(80) *
 		BlockWeights::get().max_block;
+	pub static UnixNow: u64 = 0;
+}
+
+// Unix time in seconds, set by the tests.
+pub struct MockTime;
+impl UnixTime for MockTime {
+	fn now() -> core::time::Duration {
+		core::time::Duration::from_secs(UnixNow::get())
+	}
 }
 
 impl Config for Test {
@@ -229,6 +253,9 @@ impl Config for Test {
 	type WeightInfo = TestWeightInfo;
 	type Preimages = Preimage;
 	type BlockNumberProvider = frame_system::Pallet<Self>;
+	type TimeProvider = MockTime;
+	type MaxCronTasks = ConstU32<10>;
+	type CronGracePeriod = ConstU64<600>;
 }
 
 pub type LoggerCall = logger::Call<Test>;
@@ -3076,3 +3076,355 @@ fn postponed_task_is_still_available() {
 		assert!(Agenda::<Test>::get(4).is_empty());
 	});
 }
+
+/// Monday, 1 January 2024 00:00:00 UTC.
+const JAN_1_2024: u64 = 1_704_067_200;
+const HOUR: u64 = 60 * 60;
+const DAY: u64 = 24 * HOUR;
+
+fn cron(expression: &[u8]) -> CronSchedule {
+	CronSchedule::from_expression(expression).expect("valid cron expression")
+}
+
+fn log_call(i: u32) -> Box<RuntimeCall> {
+	Box::new(RuntimeCall::Logger(LoggerCall::log { i, weight: Weight::from_parts(10, 0) }))
+}
+
+#[test]
+fn cron_schedule_from_expression_works() {
+	assert_eq!(
+		cron(b"* * * * *"),
+		CronSchedule {
+			minutes: (1 << 60) - 1,
+			hours: (1 << 24) - 1,
+			days_of_month: !1,
+			months: 0b1_1111_1111_1110,
+			days_of_week: 0b111_1111,
+		}
+	);
+	assert_eq!(
+		cron(b"30 9,17 1 */3 1-5"),
+		CronSchedule {
+			minutes: 1 << 30,
+			hours: 1 << 9 | 1 << 17,
+			days_of_month: 1 << 1,
+			months: 1 << 1 | 1 << 4 | 1 << 7 | 1 << 10,
+			days_of_week: 0b11_1110,
+		}
+	);
+	// Sunday is either `0` or `7`.
+	assert_eq!(cron(b"0 0 * * 7"), cron(b"0 0 * * 0"));
+
+	for invalid in [
+		&b""[..],
+		b"* * * *",
+		b"* * * * * *",
+		b"60 * * * *",
+		b"* * 0 * *",
+		b"* * * 13 *",
+		b"*/0 * * * *",
+		b"5-1 * * * *",
+		b"a * * * *",
+	] {
+		assert_eq!(CronSchedule::from_expression(invalid), None);
+	}
+}
+
+#[test]
+fn cron_schedule_next_after_works() {
+	// Monthly, on the first day of the month.
+	assert_eq!(cron(b"0 0 1 * *").next_after(JAN_1_2024), Some(JAN_1_2024 + 31 * DAY));
+	// On weekdays, from Friday morning to Monday morning.
+	assert_eq!(
+		cron(b"30 9 * * 1-5").next_after(JAN_1_2024 + 4 * DAY + 10 * HOUR),
+		Some(JAN_1_2024 + 7 * DAY + 9 * HOUR + 30 * 60)
+	);
+	// Every quarter of an hour.
+	assert_eq!(cron(b"*/15 * * * *").next_after(JAN_1_2024 + 7 * 60 + 5), Some(JAN_1_2024 + 900));
+	// On the 13th or on Fridays, whichever comes first.
+	assert_eq!(cron(b"0 0 13 * 5").next_after(JAN_1_2024), Some(JAN_1_2024 + 4 * DAY));
+	// On leap days.
+	let leap_day = cron(b"0 12 29 2 *");
+	assert_eq!(leap_day.next_after(JAN_1_2024), Some(JAN_1_2024 + 59 * DAY + 12 * HOUR));
+	assert_eq!(leap_day.next_after(JAN_1_2024 + 60 * DAY), Some(1_835_438_400));
+	// Never.
+	assert_eq!(cron(b"0 0 30 2 *").next_after(JAN_1_2024), None);
+}
+
+#[test]
+fn cron_task_runs_at_calendar_times() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		UnixNow::set(JAN_1_2024);
+		let id = [1u8; 32];
+		assert_ok!(Scheduler::schedule_cron(
+			RuntimeOrigin::root(),
+			id,
+			cron(b"0 * * * *"),
+			CatchUp::RunOnce,
+			None,
+			log_call(42)
+		));
+		System::assert_last_event(Event::CronScheduled { id, next: JAN_1_2024 + HOUR }.into());
+
+		UnixNow::set(JAN_1_2024 + HOUR - 1);
+		System::run_to_block::<AllPalletsWithSystem>(2);
+		assert!(logger::log().is_empty());
+
+		// However long the blocks are, the task runs once the time has come.
+		UnixNow::set(JAN_1_2024 + HOUR + 6);
+		System::run_to_block::<AllPalletsWithSystem>(3);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+		System::assert_has_event(
+			Event::CronDispatched { id, moment: JAN_1_2024 + HOUR, result: Ok(()) }.into(),
+		);
+		assert_eq!(CronTasks::<Test>::get(id).unwrap().next, JAN_1_2024 + 2 * HOUR);
+
+		System::run_to_block::<AllPalletsWithSystem>(4);
+		assert_eq!(logger::log().len(), 1);
+
+		UnixNow::set(JAN_1_2024 + 2 * HOUR + 30);
+		System::run_to_block::<AllPalletsWithSystem>(5);
+		assert_eq!(logger::log(), vec![(root(), 42u32); 2]);
+	});
+}
+
+#[test]
+fn cron_catch_up_policies_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		UnixNow::set(JAN_1_2024);
+		for (i, catch_up) in [(1, CatchUp::Skip), (2, CatchUp::RunOnce), (3, CatchUp::RunAll)] {
+			assert_ok!(Scheduler::schedule_cron(
+				RuntimeOrigin::root(),
+				[i as u8; 32],
+				cron(b"0 * * * *"),
+				catch_up,
+				None,
+				log_call(i)
+			));
+		}
+		let runs = |i| logger::log().iter().filter(|(_, j)| *j == i).count();
+
+		// The chain stalls for three and a half hours, missing three occurrences.
+		UnixNow::set(JAN_1_2024 + 3 * HOUR + 30 * 60);
+		System::run_to_block::<AllPalletsWithSystem>(2);
+		// The last occurrence is older than the grace period.
+		assert_eq!(runs(1), 0);
+		System::assert_has_event(
+			Event::CronSkipped { id: [1; 32], moment: JAN_1_2024 + HOUR }.into(),
+		);
+		assert_eq!(runs(2), 1);
+		assert_eq!(runs(3), 3);
+		for i in 1..=3 {
+			assert_eq!(CronTasks::<Test>::get([i; 32]).unwrap().next, JAN_1_2024 + 4 * HOUR);
+		}
+
+		// Within the grace period, the occurrence is not skipped.
+		UnixNow::set(JAN_1_2024 + 4 * HOUR + 5 * 60);
+		System::run_to_block::<AllPalletsWithSystem>(3);
+		assert_eq!((runs(1), runs(2), runs(3)), (1, 2, 4));
+	});
+}
+
+#[test]
+fn cron_catch_up_is_bounded_per_block() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		UnixNow::set(JAN_1_2024);
+		let id = [1u8; 32];
+		assert_ok!(Scheduler::schedule_cron(
+			RuntimeOrigin::root(),
+			id,
+			cron(b"* * * * *"),
+			CatchUp::RunAll,
+			None,
+			log_call(42)
+		));
+
+		// 15 occurrences are missed, but at most `MaxScheduledPerBlock` are run per block.
+		UnixNow::set(JAN_1_2024 + 15 * 60);
+		System::run_to_block::<AllPalletsWithSystem>(2);
+		assert_eq!(logger::log().len(), 10);
+		assert_eq!(CronTasks::<Test>::get(id).unwrap().next, JAN_1_2024 + 11 * 60);
+
+		System::run_to_block::<AllPalletsWithSystem>(3);
+		assert_eq!(logger::log().len(), 15);
+		assert_eq!(CronTasks::<Test>::get(id).unwrap().next, JAN_1_2024 + 16 * 60);
+	});
+}
+
+#[test]
+fn cron_tasks_share_the_per_block_budget_with_agendas() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		UnixNow::set(JAN_1_2024);
+		for i in 0..4 {
+			assert_ok!(Scheduler::do_schedule(
+				DispatchTime::At(2),
+				None,
+				127,
+				root(),
+				Preimage::bound(*log_call(i)).unwrap()
+			));
+		}
+		let id = [1u8; 32];
+		assert_ok!(Scheduler::schedule_cron(
+			RuntimeOrigin::root(),
+			id,
+			cron(b"* * * * *"),
+			CatchUp::RunAll,
+			None,
+			log_call(42)
+		));
+
+		// The 4 agenda tasks leave room for 6 of the 15 missed occurrences.
+		UnixNow::set(JAN_1_2024 + 15 * 60);
+		System::run_to_block::<AllPalletsWithSystem>(2);
+		assert_eq!(logger::log().len(), 10);
+		assert_eq!(logger::log().iter().filter(|(_, i)| *i == 42).count(), 6);
+		assert_eq!(CronTasks::<Test>::get(id).unwrap().next, JAN_1_2024 + 7 * 60);
+	});
+}
+
+#[test]
+fn cron_schedule_can_be_changed_in_place() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		UnixNow::set(JAN_1_2024);
+		let id = [1u8; 32];
+		let half_past = cron(b"30 * * * *");
+		assert_ok!(Scheduler::schedule_cron(
+			RuntimeOrigin::root(),
+			id,
+			cron(b"0 0 * * *"),
+			CatchUp::Skip,
+			Some(2),
+			log_call(42)
+		));
+		assert_eq!(CronTasks::<Test>::get(id).unwrap().next, JAN_1_2024 + DAY);
+
+		assert_noop!(
+			Scheduler::set_cron_schedule(RuntimeOrigin::signed(1), id, half_past, CatchUp::RunOnce),
+			BadOrigin
+		);
+		assert_noop!(
+			Scheduler::set_cron_schedule(
+				RuntimeOrigin::root(),
+				[2; 32],
+				half_past,
+				CatchUp::RunOnce
+			),
+			Error::<Test>::NotFound
+		);
+		assert_noop!(
+			Scheduler::set_cron_schedule(
+				RuntimeOrigin::root(),
+				id,
+				cron(b"0 0 30 2 *"),
+				CatchUp::RunOnce
+			),
+			Error::<Test>::InvalidCronSchedule
+		);
+
+		assert_ok!(Scheduler::set_cron_schedule(
+			RuntimeOrigin::root(),
+			id,
+			half_past,
+			CatchUp::RunOnce
+		));
+		System::assert_last_event(Event::CronScheduled { id, next: JAN_1_2024 + 30 * 60 }.into());
+		let task = CronTasks::<Test>::get(id).unwrap();
+		assert_eq!(
+			(task.schedule, task.catch_up, task.next, task.remaining),
+			(half_past, CatchUp::RunOnce, JAN_1_2024 + 30 * 60, Some(2))
+		);
+		assert_eq!(CronQueue::<Test>::get().into_inner(), vec![(JAN_1_2024 + 30 * 60, id)]);
+
+		// The task is removed after its last dispatch.
+		UnixNow::set(JAN_1_2024 + 31 * 60);
+		System::run_to_block::<AllPalletsWithSystem>(2);
+		UnixNow::set(JAN_1_2024 + HOUR + 31 * 60);
+		System::run_to_block::<AllPalletsWithSystem>(3);
+		assert_eq!(logger::log(), vec![(root(), 42u32); 2]);
+		System::assert_last_event(Event::CronCompleted { id }.into());
+		assert!(!CronTasks::<Test>::contains_key(id));
+		assert!(CronQueue::<Test>::get().is_empty());
+	});
+}
+
+#[test]
+fn schedule_and_cancel_cron_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		UnixNow::set(JAN_1_2024);
+		let id = [1u8; 32];
+		let hourly = cron(b"0 * * * *");
+		assert_noop!(
+			Scheduler::schedule_cron(
+				RuntimeOrigin::root(),
+				id,
+				hourly,
+				CatchUp::RunOnce,
+				Some(0),
+				log_call(42)
+			),
+			Error::<Test>::InvalidCronSchedule
+		);
+		assert_noop!(
+			Scheduler::schedule_cron(
+				RuntimeOrigin::root(),
+				id,
+				CronSchedule { minutes: 1 << 60, ..hourly },
+				CatchUp::RunOnce,
+				None,
+				log_call(42)
+			),
+			Error::<Test>::InvalidCronSchedule
+		);
+
+		for i in 1..=10 {
+			assert_ok!(Scheduler::schedule_cron(
+				RuntimeOrigin::root(),
+				[i; 32],
+				hourly,
+				CatchUp::RunOnce,
+				None,
+				log_call(42)
+			));
+		}
+		assert_noop!(
+			Scheduler::schedule_cron(
+				RuntimeOrigin::root(),
+				id,
+				hourly,
+				CatchUp::RunOnce,
+				None,
+				log_call(42)
+			),
+			Error::<Test>::FailedToSchedule
+		);
+		assert_noop!(
+			Scheduler::schedule_cron(
+				RuntimeOrigin::root(),
+				[11; 32],
+				hourly,
+				CatchUp::RunOnce,
+				None,
+				log_call(42)
+			),
+			Error::<Test>::TooManyCronTasks
+		);
+
+		assert_noop!(Scheduler::cancel_cron(RuntimeOrigin::signed(1), id), BadOrigin);
+		assert_ok!(Scheduler::cancel_cron(RuntimeOrigin::root(), id));
+		System::assert_last_event(Event::CronCanceled { id }.into());
+		assert!(!CronTasks::<Test>::contains_key(id));
+		assert_eq!(CronQueue::<Test>::get().len(), 9);
+		assert_noop!(Scheduler::cancel_cron(RuntimeOrigin::root(), id), Error::<Test>::NotFound);
+
+		UnixNow::set(JAN_1_2024 + HOUR);
+		System::run_to_block::<AllPalletsWithSystem>(2);
+		assert_eq!(logger::log().len(), 9);
+	});
+}
@@ -89,6 +89,11 @@ pub trait WeightInfo {
 	fn set_retry_named() -> Weight;
 	fn cancel_retry() -> Weight;
 	fn cancel_retry_named() -> Weight;
+	fn service_cron_base(s: u32, ) -> Weight;
+	fn service_cron_task() -> Weight;
+	fn schedule_cron(s: u32, ) -> Weight;
+	fn set_cron_schedule(s: u32, ) -> Weight;
+	fn cancel_cron(s: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_scheduler` using the Substrate node and recommended hardware.
@@ -319,6 +324,79 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Scheduler::CronQueue` (r:1 w:1)
+	/// Proof: `Scheduler::CronQueue` (`max_values`: Some(1), `max_size`: Some(5122), added: 5617, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 128]`.
+	fn service_cron_base(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6 + s * (40 ±0)`
+		//  Estimated: `6607`
+		// Minimum execution time: 2_118_000 picoseconds.
+		Weight::from_parts(2_204_000, 6607)
+			// Standard Error: 1_204
+			.saturating_add(Weight::from_parts(41_262, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Scheduler::CronTasks` (r:1 w:1)
+	/// Proof: `Scheduler::CronTasks` (`max_values`: None, `max_size`: Some(238), added: 2713, mode: `MaxEncodedLen`)
+	fn service_cron_task() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `241`
+		//  Estimated: `3703`
+		// Minimum execution time: 8_912_000 picoseconds.
+		Weight::from_parts(9_307_000, 3703)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Scheduler::CronTasks` (r:1 w:1)
+	/// Proof: `Scheduler::CronTasks` (`max_values`: None, `max_size`: Some(238), added: 2713, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::CronQueue` (r:1 w:1)
+	/// Proof: `Scheduler::CronQueue` (`max_values`: Some(1), `max_size`: Some(5122), added: 5617, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 127]`.
+	fn schedule_cron(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6 + s * (40 ±0)`
+		//  Estimated: `6607`
+		// Minimum execution time: 13_204_000 picoseconds.
+		Weight::from_parts(12_861_410, 6607)
+			// Standard Error: 1_204
+			.saturating_add(Weight::from_parts(152_735, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Scheduler::CronTasks` (r:1 w:1)
+	/// Proof: `Scheduler::CronTasks` (`max_values`: None, `max_size`: Some(238), added: 2713, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::CronQueue` (r:1 w:1)
+	/// Proof: `Scheduler::CronQueue` (`max_values`: Some(1), `max_size`: Some(5122), added: 5617, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 128]`.
+	fn set_cron_schedule(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `247 + s * (40 ±0)`
+		//  Estimated: `6607`
+		// Minimum execution time: 15_386_000 picoseconds.
+		Weight::from_parts(14_027_583, 6607)
+			// Standard Error: 1_204
+			.saturating_add(Weight::from_parts(163_118, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Scheduler::CronTasks` (r:1 w:1)
+	/// Proof: `Scheduler::CronTasks` (`max_values`: None, `max_size`: Some(238), added: 2713, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::CronQueue` (r:1 w:1)
+	/// Proof: `Scheduler::CronQueue` (`max_values`: Some(1), `max_size`: Some(5122), added: 5617, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 128]`.
+	fn cancel_cron(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `247 + s * (40 ±0)`
+		//  Estimated: `6607`
+		// Minimum execution time: 14_821_000 picoseconds.
+		Weight::from_parts(13_698_204, 6607)
+			// Standard Error: 1_204
+			.saturating_add(Weight::from_parts(158_460, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -548,4 +626,77 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Scheduler::CronQueue` (r:1 w:1)
+	/// Proof: `Scheduler::CronQueue` (`max_values`: Some(1), `max_size`: Some(5122), added: 5617, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 128]`.
+	fn service_cron_base(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6 + s * (40 ±0)`
+		//  Estimated: `6607`
+		// Minimum execution time: 2_118_000 picoseconds.
+		Weight::from_parts(2_204_000, 6607)
+			// Standard Error: 1_204
+			.saturating_add(Weight::from_parts(41_262, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Scheduler::CronTasks` (r:1 w:1)
+	/// Proof: `Scheduler::CronTasks` (`max_values`: None, `max_size`: Some(238), added: 2713, mode: `MaxEncodedLen`)
+	fn service_cron_task() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `241`
+		//  Estimated: `3703`
+		// Minimum execution time: 8_912_000 picoseconds.
+		Weight::from_parts(9_307_000, 3703)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Scheduler::CronTasks` (r:1 w:1)
+	/// Proof: `Scheduler::CronTasks` (`max_values`: None, `max_size`: Some(238), added: 2713, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::CronQueue` (r:1 w:1)
+	/// Proof: `Scheduler::CronQueue` (`max_values`: Some(1), `max_size`: Some(5122), added: 5617, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 127]`.
+	fn schedule_cron(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6 + s * (40 ±0)`
+		//  Estimated: `6607`
+		// Minimum execution time: 13_204_000 picoseconds.
+		Weight::from_parts(12_861_410, 6607)
+			// Standard Error: 1_204
+			.saturating_add(Weight::from_parts(152_735, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Scheduler::CronTasks` (r:1 w:1)
+	/// Proof: `Scheduler::CronTasks` (`max_values`: None, `max_size`: Some(238), added: 2713, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::CronQueue` (r:1 w:1)
+	/// Proof: `Scheduler::CronQueue` (`max_values`: Some(1), `max_size`: Some(5122), added: 5617, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 128]`.
+	fn set_cron_schedule(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `247 + s * (40 ±0)`
+		//  Estimated: `6607`
+		// Minimum execution time: 15_386_000 picoseconds.
+		Weight::from_parts(14_027_583, 6607)
+			// Standard Error: 1_204
+			.saturating_add(Weight::from_parts(163_118, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Scheduler::CronTasks` (r:1 w:1)
+	/// Proof: `Scheduler::CronTasks` (`max_values`: None, `max_size`: Some(238), added: 2713, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::CronQueue` (r:1 w:1)
+	/// Proof: `Scheduler::CronQueue` (`max_values`: Some(1), `max_size`: Some(5122), added: 5617, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 128]`.
+	fn cancel_cron(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `247 + s * (40 ±0)`
+		//  Estimated: `6607`
+		// Minimum execution time: 14_821_000 picoseconds.
+		Weight::from_parts(13_698_204, 6607)
+			// Standard Error: 1_204
+			.saturating_add(Weight::from_parts(158_460, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
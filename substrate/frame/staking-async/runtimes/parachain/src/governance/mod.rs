@@ -61,6 +61,9 @@ impl pallet_conviction_voting::Config for Runtime {
 	type Polls = Referenda;
 	type BlockNumberProvider = RelayChainBlockNumberProvider;
 	type VotingHooks = ();
+	type PrivateClasses = frame_support::traits::Nothing;
+	type RevealWindow = ();
+	type UnrevealedLockPeriods = ConstU32<32>;
 }
 
 parameter_types! {
@@ -166,6 +169,7 @@ impl pallet_treasury::Config for Runtime {
 		AssetRate,
 	>;
 	type PayoutPeriod = PayoutSpendPeriod;
+	type MaxInstallments = ConstU32<24>;
 	type BlockNumberProvider = RelayChainBlockNumberProvider;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = polkadot_runtime_common::impls::benchmarks::TreasuryArguments;
@@ -187,6 +191,8 @@ parameter_types! {
 		RuntimeBlockWeights::get().max_block;
 	pub const MaxScheduledPerBlock: u32 = 50;
 	pub const NoPreimagePostponement: Option<u32> = Some(10);
+	pub const MaxCronTasks: u32 = 50;
+	pub const CronGracePeriod: u64 = 10 * 60;
 }
 
 impl pallet_scheduler::Config for Runtime {
@@ -201,6 +207,9 @@ impl pallet_scheduler::Config for Runtime {
 	type OriginPrivilegeCmp = frame_support::traits::EqualPrivilegeOnly;
 	type Preimages = Preimage;
 	type BlockNumberProvider = RelayChainBlockNumberProvider;
+	type TimeProvider = Timestamp;
+	type MaxCronTasks = MaxCronTasks;
+	type CronGracePeriod = CronGracePeriod;
 }
 
 parameter_types! {
@@ -85,6 +85,10 @@ impl<T: frame_system::Config> pallet_nomination_pools::WeightInfo for WeightInfo
 	/// Proof: `VoterList::ListNodes` (`max_values`: None, `max_size`: Some(154), added: 2629, mode: `MaxEncodedLen`)
 	/// Storage: `VoterList::ListBags` (r:2 w:2)
 	/// Proof: `VoterList::ListBags` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `NominationPools::AutoCompoundPools` (r:1 w:0)
+	/// Proof: `NominationPools::AutoCompoundPools` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `NominationPools::AutoCompoundMembers` (r:0 w:1)
+	/// Proof: `NominationPools::AutoCompoundMembers` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `NominationPools::TotalValueLocked` (r:1 w:1)
 	/// Proof: `NominationPools::TotalValueLocked` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	fn join() -> Weight {
@@ -94,8 +98,8 @@ impl<T: frame_system::Config> pallet_nomination_pools::WeightInfo for WeightInfo
 		// Minimum execution time: 204_877_000 picoseconds.
 		Weight::from_parts(210_389_000, 0)
 			.saturating_add(Weight::from_parts(0, 8877))
-			.saturating_add(T::DbWeight::get().reads(24))
-			.saturating_add(T::DbWeight::get().writes(15))
+			.saturating_add(T::DbWeight::get().reads(25))
+			.saturating_add(T::DbWeight::get().writes(16))
 	}
 	/// Storage: `NominationPools::PoolMembers` (r:1 w:1)
 	/// Proof: `NominationPools::PoolMembers` (`max_values`: None, `max_size`: Some(717), added: 3192, mode: `MaxEncodedLen`)
@@ -822,4 +826,24 @@ impl<T: frame_system::Config> pallet_nomination_pools::WeightInfo for WeightInfo
 			.saturating_add(T::DbWeight::get().reads(15))
 			.saturating_add(T::DbWeight::get().writes(6))
 	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn set_auto_compound() -> Weight {
+		<pallet_nomination_pools::weights::SubstrateWeight<T> as pallet_nomination_pools::WeightInfo>::set_auto_compound()
+	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn tokenize_points() -> Weight {
+		<pallet_nomination_pools::weights::SubstrateWeight<T> as pallet_nomination_pools::WeightInfo>::tokenize_points()
+	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn redeem_points() -> Weight {
+		<pallet_nomination_pools::weights::SubstrateWeight<T> as pallet_nomination_pools::WeightInfo>::redeem_points()
+	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn transfer_liquid_points() -> Weight {
+		<pallet_nomination_pools::weights::SubstrateWeight<T> as pallet_nomination_pools::WeightInfo>::transfer_liquid_points()
+	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn register_auto_compound_member() -> Weight {
+		<pallet_nomination_pools::weights::SubstrateWeight<T> as pallet_nomination_pools::WeightInfo>::register_auto_compound_member()
+	}
 }
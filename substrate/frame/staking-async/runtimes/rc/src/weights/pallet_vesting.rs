@@ -51,212 +51,52 @@ use core::marker::PhantomData;
 /// Weight functions for `pallet_vesting`.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
-	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
-	/// The range of component `l` is `[0, 49]`.
-	/// The range of component `s` is `[1, 28]`.
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
 	fn vest_locked(l: u32, s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `345 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 38_225_000 picoseconds.
-		Weight::from_parts(37_860_470, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 1_479
-			.saturating_add(Weight::from_parts(41_149, 0).saturating_mul(l.into()))
-			// Standard Error: 2_631
-			.saturating_add(Weight::from_parts(76_064, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(2))
+		<pallet_vesting::weights::SubstrateWeight<T> as pallet_vesting::WeightInfo>::vest_locked(l, s)
 	}
-	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
-	/// The range of component `l` is `[0, 49]`.
-	/// The range of component `s` is `[1, 28]`.
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
 	fn vest_unlocked(l: u32, s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `345 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 40_682_000 picoseconds.
-		Weight::from_parts(40_558_815, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 1_473
-			.saturating_add(Weight::from_parts(35_138, 0).saturating_mul(l.into()))
-			// Standard Error: 2_620
-			.saturating_add(Weight::from_parts(72_425, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(2))
+		<pallet_vesting::weights::SubstrateWeight<T> as pallet_vesting::WeightInfo>::vest_unlocked(l, s)
 	}
-	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
-	/// Storage: `System::Account` (r:1 w:1)
-	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// The range of component `l` is `[0, 49]`.
-	/// The range of component `s` is `[1, 28]`.
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
 	fn vest_other_locked(l: u32, s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `448 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 40_813_000 picoseconds.
-		Weight::from_parts(40_248_990, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 1_925
-			.saturating_add(Weight::from_parts(47_778, 0).saturating_mul(l.into()))
-			// Standard Error: 3_425
-			.saturating_add(Weight::from_parts(88_421, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<pallet_vesting::weights::SubstrateWeight<T> as pallet_vesting::WeightInfo>::vest_other_locked(l, s)
 	}
-	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
-	/// Storage: `System::Account` (r:1 w:1)
-	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// The range of component `l` is `[0, 49]`.
-	/// The range of component `s` is `[1, 28]`.
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
 	fn vest_other_unlocked(l: u32, s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `448 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 43_330_000 picoseconds.
-		Weight::from_parts(43_588_745, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 2_075
-			.saturating_add(Weight::from_parts(35_838, 0).saturating_mul(l.into()))
-			// Standard Error: 3_693
-			.saturating_add(Weight::from_parts(73_951, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<pallet_vesting::weights::SubstrateWeight<T> as pallet_vesting::WeightInfo>::vest_other_unlocked(l, s)
 	}
-	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
-	/// Storage: `System::Account` (r:1 w:1)
-	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
-	/// The range of component `l` is `[0, 49]`.
-	/// The range of component `s` is `[0, 27]`.
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
 	fn vested_transfer(l: u32, s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `519 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 80_026_000 picoseconds.
-		Weight::from_parts(82_148_674, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 3_243
-			.saturating_add(Weight::from_parts(30_866, 0).saturating_mul(l.into()))
-			// Standard Error: 5_770
-			.saturating_add(Weight::from_parts(99_755, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<pallet_vesting::weights::SubstrateWeight<T> as pallet_vesting::WeightInfo>::vested_transfer(l, s)
 	}
-	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
-	/// Storage: `System::Account` (r:2 w:2)
-	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
-	/// The range of component `l` is `[0, 49]`.
-	/// The range of component `s` is `[0, 27]`.
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
 	fn force_vested_transfer(l: u32, s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `622 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `6196`
-		// Minimum execution time: 81_979_000 picoseconds.
-		Weight::from_parts(83_373_383, 0)
-			.saturating_add(Weight::from_parts(0, 6196))
-			// Standard Error: 3_069
-			.saturating_add(Weight::from_parts(49_002, 0).saturating_mul(l.into()))
-			// Standard Error: 5_460
-			.saturating_add(Weight::from_parts(105_265, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(4))
+		<pallet_vesting::weights::SubstrateWeight<T> as pallet_vesting::WeightInfo>::force_vested_transfer(l, s)
 	}
-	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
-	/// The range of component `l` is `[0, 49]`.
-	/// The range of component `s` is `[2, 28]`.
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
 	fn not_unlocking_merge_schedules(l: u32, s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `345 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 39_190_000 picoseconds.
-		Weight::from_parts(38_673_517, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 1_789
-			.saturating_add(Weight::from_parts(38_146, 0).saturating_mul(l.into()))
-			// Standard Error: 3_305
-			.saturating_add(Weight::from_parts(97_870, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(2))
+		<pallet_vesting::weights::SubstrateWeight<T> as pallet_vesting::WeightInfo>::not_unlocking_merge_schedules(l, s)
 	}
-	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
-	/// The range of component `l` is `[0, 49]`.
-	/// The range of component `s` is `[2, 28]`.
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
 	fn unlocking_merge_schedules(l: u32, s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `345 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 42_229_000 picoseconds.
-		Weight::from_parts(42_040_081, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 1_659
-			.saturating_add(Weight::from_parts(38_531, 0).saturating_mul(l.into()))
-			// Standard Error: 3_065
-			.saturating_add(Weight::from_parts(76_527, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(2))
+		<pallet_vesting::weights::SubstrateWeight<T> as pallet_vesting::WeightInfo>::unlocking_merge_schedules(l, s)
 	}
-	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
-	/// Storage: `System::Account` (r:1 w:1)
-	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// The range of component `l` is `[0, 49]`.
-	/// The range of component `s` is `[2, 28]`.
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
 	fn force_remove_vesting_schedule(l: u32, s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `519 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 46_474_000 picoseconds.
-		Weight::from_parts(46_105_020, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 1_706
-			.saturating_add(Weight::from_parts(39_879, 0).saturating_mul(l.into()))
-			// Standard Error: 3_151
-			.saturating_add(Weight::from_parts(87_824, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+		<pallet_vesting::weights::SubstrateWeight<T> as pallet_vesting::WeightInfo>::force_remove_vesting_schedule(l, s)
+	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn grant_vesting(l: u32, s: u32, ) -> Weight {
+		<pallet_vesting::weights::SubstrateWeight<T> as pallet_vesting::WeightInfo>::grant_vesting(l, s)
+	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn revoke_vesting(l: u32, s: u32, ) -> Weight {
+		<pallet_vesting::weights::SubstrateWeight<T> as pallet_vesting::WeightInfo>::revoke_vesting(l, s)
+	}
+	/// Not benchmarked for this runtime yet, uses the reference weight of the pallet.
+	fn claw_back(l: u32, s: u32, ) -> Weight {
+		<pallet_vesting::weights::SubstrateWeight<T> as pallet_vesting::WeightInfo>::claw_back(l, s)
 	}
 }
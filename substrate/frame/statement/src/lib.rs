@@ -27,12 +27,17 @@
 //! statement author balance:
 //! `max_count`: Maximum number of statements allowed for the author (signer) of this statement.
 //! `max_size`: Maximum total size of statements allowed for the author (signer) of this statement.
+//! It also returns the configured `TopicLimits` of the statement topics, which apply to the
+//! statements of all authors sharing a topic.
 //!
 //! This pallet also contains an offchain worker that turns on-chain statement events into
 //! statements. These statements are placed in the store and propagated over the network.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+use alloc::vec::Vec;
 use frame_support::{
 	pallet_prelude::*,
 	sp_runtime::{traits::CheckedDiv, SaturatedConversion},
@@ -40,8 +45,8 @@ use frame_support::{
 };
 use frame_system::pallet_prelude::*;
 use sp_statement_store::{
-	runtime_api::{InvalidStatement, StatementSource, ValidStatement},
-	Proof, SignatureVerificationResult, Statement,
+	runtime_api::{InvalidStatement, StatementSource, TopicLimit, ValidStatement},
+	Proof, SignatureVerificationResult, Statement, MAX_TOPICS,
 };
 
 #[cfg(test)]
@@ -92,6 +97,10 @@ pub mod pallet {
 		/// Maximum data bytes allowed per account.
 		#[pallet::constant]
 		type MaxAllowedBytes: Get<u32>;
+		/// Limits on the statements sharing a topic, regardless of their account.
+		///
+		/// Only the limits of the topics of a statement are returned when validating it.
+		type TopicLimits: Get<Vec<TopicLimit>>;
 	}
 
 	#[pallet::pallet]
@@ -196,7 +205,12 @@ where
 			.saturated_into::<u32>()
 			.clamp(min_allowed_bytes, max_allowed_bytes);
 
-		Ok(ValidStatement { max_count, max_size })
+		let topic_limits = T::TopicLimits::get()
+			.into_iter()
+			.filter(|limit| (0..MAX_TOPICS).any(|i| statement.topic(i) == Some(limit.topic)))
+			.collect();
+
+		Ok(ValidStatement { max_count, max_size, topic_limits })
 	}
 
 	/// Submit a statement event. The statement will be picked up by the offchain worker and
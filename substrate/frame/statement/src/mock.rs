@@ -21,7 +21,7 @@ use super::*;
 
 use crate as pallet_statement;
 use frame_support::{
-	derive_impl, ord_parameter_types,
+	derive_impl, ord_parameter_types, parameter_types,
 	traits::{ConstU32, ConstU64},
 };
 use sp_core::Pair;
@@ -33,6 +33,7 @@ pub const MIN_ALLOWED_STATEMENTS: u32 = 4;
 pub const MAX_ALLOWED_STATEMENTS: u32 = 10;
 pub const MIN_ALLOWED_BYTES: u32 = 1024;
 pub const MAX_ALLOWED_BYTES: u32 = 4096;
+pub const LIMITED_TOPIC: sp_statement_store::Topic = [1; 32];
 
 frame_support::construct_runtime!(
 	pub enum Test
@@ -61,6 +62,11 @@ ord_parameter_types! {
 	pub const One: u64 = 1;
 }
 
+parameter_types! {
+	pub TopicLimits: Vec<TopicLimit> =
+		vec![TopicLimit { topic: LIMITED_TOPIC, max_count: 2, max_size: 512 }];
+}
+
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
@@ -70,6 +76,7 @@ impl Config for Test {
 	type MaxAllowedStatements = ConstU32<MAX_ALLOWED_STATEMENTS>;
 	type MinAllowedBytes = ConstU32<MIN_ALLOWED_BYTES>;
 	type MaxAllowedBytes = ConstU32<MAX_ALLOWED_BYTES>;
+	type TopicLimits = TopicLimits;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
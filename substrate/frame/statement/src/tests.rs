@@ -36,7 +36,11 @@ fn sign_and_validate_no_balance() {
 		statement.sign_sr25519_private(&pair);
 		let result = Pallet::<Test>::validate_statement(StatementSource::Chain, statement);
 		assert_eq!(
-			Ok(ValidStatement { max_count: MIN_ALLOWED_STATEMENTS, max_size: MIN_ALLOWED_BYTES }),
+			Ok(ValidStatement {
+				max_count: MIN_ALLOWED_STATEMENTS,
+				max_size: MIN_ALLOWED_BYTES,
+				topic_limits: vec![]
+			}),
 			result
 		);
 
@@ -45,7 +49,11 @@ fn sign_and_validate_no_balance() {
 		statement.sign_ed25519_private(&pair);
 		let result = Pallet::<Test>::validate_statement(StatementSource::Chain, statement);
 		assert_eq!(
-			Ok(ValidStatement { max_count: MIN_ALLOWED_STATEMENTS, max_size: MIN_ALLOWED_BYTES }),
+			Ok(ValidStatement {
+				max_count: MIN_ALLOWED_STATEMENTS,
+				max_size: MIN_ALLOWED_BYTES,
+				topic_limits: vec![]
+			}),
 			result
 		);
 
@@ -54,7 +62,11 @@ fn sign_and_validate_no_balance() {
 		statement.sign_ecdsa_private(&pair);
 		let result = Pallet::<Test>::validate_statement(StatementSource::Chain, statement);
 		assert_eq!(
-			Ok(ValidStatement { max_count: MIN_ALLOWED_STATEMENTS, max_size: MIN_ALLOWED_BYTES }),
+			Ok(ValidStatement {
+				max_count: MIN_ALLOWED_STATEMENTS,
+				max_size: MIN_ALLOWED_BYTES,
+				topic_limits: vec![]
+			}),
 			result
 		);
 	});
@@ -67,19 +79,48 @@ fn validate_with_balance() {
 		let mut statement = Statement::new();
 		statement.sign_sr25519_private(&pair);
 		let result = Pallet::<Test>::validate_statement(StatementSource::Chain, statement);
-		assert_eq!(Ok(ValidStatement { max_count: 6, max_size: 3000 }), result);
+		assert_eq!(
+			Ok(ValidStatement { max_count: 6, max_size: 3000, topic_limits: vec![] }),
+			result
+		);
 
 		let pair = sp_core::sr25519::Pair::from_string("//Charlie", None).unwrap();
 		let mut statement = Statement::new();
 		statement.sign_sr25519_private(&pair);
 		let result = Pallet::<Test>::validate_statement(StatementSource::Chain, statement);
 		assert_eq!(
-			Ok(ValidStatement { max_count: MAX_ALLOWED_STATEMENTS, max_size: MAX_ALLOWED_BYTES }),
+			Ok(ValidStatement {
+				max_count: MAX_ALLOWED_STATEMENTS,
+				max_size: MAX_ALLOWED_BYTES,
+				topic_limits: vec![]
+			}),
 			result
 		);
 	});
 }
 
+#[test]
+fn validate_returns_limits_of_statement_topics() {
+	new_test_ext().execute_with(|| {
+		let pair = sp_core::sr25519::Pair::from_string("//Alice", None).unwrap();
+		let mut statement = Statement::new();
+		statement.set_topic(0, [2; 32]);
+		statement.sign_sr25519_private(&pair);
+		let result = Pallet::<Test>::validate_statement(StatementSource::Chain, statement);
+		assert_eq!(result.unwrap().topic_limits, vec![]);
+
+		let mut statement = Statement::new();
+		statement.set_topic(0, [2; 32]);
+		statement.set_topic(1, LIMITED_TOPIC);
+		statement.sign_sr25519_private(&pair);
+		let result = Pallet::<Test>::validate_statement(StatementSource::Chain, statement);
+		assert_eq!(
+			result.unwrap().topic_limits,
+			vec![TopicLimit { topic: LIMITED_TOPIC, max_count: 2, max_size: 512 }]
+		);
+	});
+}
+
 #[test]
 fn validate_no_proof_fails() {
 	new_test_ext().execute_with(|| {
@@ -117,7 +158,10 @@ fn validate_event() {
 			block_hash: parent_hash.into(),
 		});
 		let result = Pallet::<Test>::validate_statement(StatementSource::Chain, statement.clone());
-		assert_eq!(Ok(ValidStatement { max_count: 6, max_size: 3000 }), result);
+		assert_eq!(
+			Ok(ValidStatement { max_count: 6, max_size: 3000, topic_limits: vec![] }),
+			result
+		);
 
 		// Use wrong event index
 		statement.set_proof(Proof::OnChain {
@@ -21,6 +21,7 @@
 
 use super::{Pallet as Treasury, *};
 
+use alloc::vec::Vec;
 use frame_benchmarking::{
 	v1::{account, BenchmarkError},
 	v2::*,
@@ -110,6 +111,36 @@ fn create_spend_arguments<T: Config<I>, I: 'static>(
 	(asset_kind, 100u32.into(), beneficiary, beneficiary_lookup)
 }
 
+// Create `n` installments of the arguments of the `spend` dispatchable, one block apart.
+fn create_installments<T: Config<I>, I: 'static>(n: u32) -> InstallmentsOf<T, I> {
+	let now = T::BlockNumberProvider::current_block_number();
+	(0..n)
+		.map(|i| Installment { amount: 1u32.into(), valid_from: now.saturating_add(i.into()) })
+		.collect::<Vec<_>>()
+		.try_into()
+		.expect("`n` is bounded by `MaxInstallments`; qed")
+}
+
+// Create a milestone spend of two installments curated by `curator`.
+fn create_milestone_spend<T: Config<I>, I: 'static>(
+	curator: &T::AccountId,
+) -> Result<bool, BenchmarkError> {
+	let (asset_kind, _, _, beneficiary_lookup) = create_spend_arguments::<T, I>(SEED);
+	T::BalanceConverter::ensure_successful(asset_kind.clone());
+	if let Ok(origin) = T::SpendOrigin::try_successful_origin() {
+		Treasury::<T, I>::spend_in_installments(
+			origin,
+			Box::new(asset_kind),
+			Box::new(beneficiary_lookup),
+			create_installments::<T, I>(2.min(T::MaxInstallments::get())),
+			Some(T::Lookup::unlookup(curator.clone())),
+		)?;
+		Ok(true)
+	} else {
+		Ok(false)
+	}
+}
+
 #[instance_benchmarks]
 mod benchmarks {
 	use super::*;
@@ -350,6 +381,79 @@ mod benchmarks {
 		Ok(())
 	}
 
+	/// This benchmark is short-circuited if `SpendOrigin` cannot provide
+	/// a successful origin, in which case `spend_in_installments` is un-callable and can use
+	/// weight=0.
+	#[benchmark]
+	fn spend_in_installments(
+		n: Linear<1, { T::MaxInstallments::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let origin =
+			T::SpendOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let (asset_kind, _, _, beneficiary_lookup) = create_spend_arguments::<T, _>(SEED);
+		T::BalanceConverter::ensure_successful(asset_kind.clone());
+		let installments = create_installments::<T, _>(n);
+
+		// without a curator, every installment becomes a spend.
+		#[extrinsic_call]
+		_(
+			origin as T::RuntimeOrigin,
+			Box::new(asset_kind),
+			Box::new(beneficiary_lookup),
+			installments,
+			None,
+		);
+
+		assert_eq!(SpendCount::<T, I>::get(), n);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn approve_milestone() -> Result<(), BenchmarkError> {
+		let curator: T::AccountId = account("curator", 0, SEED);
+		let spend_exists = create_milestone_spend::<T, _>(&curator)?;
+
+		#[block]
+		{
+			let res =
+				Treasury::<T, _>::approve_milestone(RawOrigin::Signed(curator.clone()).into(), 0);
+
+			if spend_exists {
+				assert_ok!(res);
+			} else {
+				assert_err!(res, crate::Error::<T, _>::InvalidIndex);
+			}
+		}
+
+		if spend_exists {
+			assert!(Spends::<T, I>::get(0).is_some());
+		}
+		Ok(())
+	}
+
+	#[benchmark]
+	fn void_milestone_spend() -> Result<(), BenchmarkError> {
+		let curator: T::AccountId = account("curator", 0, SEED);
+		let spend_exists = create_milestone_spend::<T, _>(&curator)?;
+
+		let origin =
+			T::RejectOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[block]
+		{
+			let res = Treasury::<T, _>::void_milestone_spend(origin as T::RuntimeOrigin, 0);
+
+			if spend_exists {
+				assert_ok!(res);
+			} else {
+				assert_err!(res, crate::Error::<T, _>::InvalidIndex);
+			}
+		}
+
+		assert!(MilestoneSpends::<T, I>::get(0).is_none());
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(
 		Treasury,
 		crate::tests::ExtBuilder::default().build(),
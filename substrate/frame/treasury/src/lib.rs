@@ -69,6 +69,13 @@
 //! [`pallet::Config::Paymaster`]. To claim these spends, the `payout` dispatchable should be called
 //! within some temporal bounds, starting from the moment they become valid and within one
 //! [`pallet::Config::PayoutPeriod`].
+//!
+//! Grants and retainers can be approved at once with the `spend_in_installments` dispatchable,
+//! which takes a schedule of up to [`pallet::Config::MaxInstallments`] tranches. Without a curator,
+//! every tranche becomes a separate spend right away, valid from its scheduled block. With a
+//! curator, the tranches are held back as a milestone spend, and each of them only becomes a spend
+//! once the curator approves the corresponding milestone with `approve_milestone`. In both cases,
+//! the tranches are claimed, checked and voided like any other spend.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -93,7 +100,7 @@ use sp_runtime::{
 		AccountIdConversion, BlockNumberProvider, CheckedAdd, One, Saturating, StaticLookup,
 		UniqueSaturatedInto, Zero,
 	},
-	PerThing, Permill, RuntimeDebug,
+	ArithmeticError, PerThing, Permill, RuntimeDebug,
 };
 
 use frame_support::{
@@ -196,6 +203,45 @@ pub struct SpendStatus<AssetKind, AssetBalance, Beneficiary, BlockNumber, Paymen
 /// Index of an approved treasury spend.
 pub type SpendIndex = u32;
 
+/// A tranche of a spend paid out in installments.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct Installment<AssetBalance, BlockNumber> {
+	/// The asset amount of the installment.
+	pub amount: AssetBalance,
+	/// The block number from which the installment can be claimed.
+	pub valid_from: BlockNumber,
+}
+
+/// A spend paid out in installments, each of which has to be approved by a curator.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct MilestoneSpend<AssetKind, Beneficiary, AccountId, Installments> {
+	/// The kind of asset to be spent.
+	asset_kind: AssetKind,
+	/// The beneficiary of the spend.
+	beneficiary: Beneficiary,
+	/// The account approving the milestones of the spend.
+	curator: AccountId,
+	/// The installments of the spend, in order of release.
+	installments: Installments,
+	/// The number of installments released as spends so far.
+	released: u32,
+}
+
+/// Index of an approved milestone spend.
+pub type MilestoneSpendIndex = u32;
+
+pub type InstallmentOf<T, I = ()> = Installment<AssetBalanceOf<T, I>, BlockNumberFor<T, I>>;
+pub type InstallmentsOf<T, I = ()> =
+	BoundedVec<InstallmentOf<T, I>, <T as Config<I>>::MaxInstallments>;
+pub type MilestoneSpendOf<T, I = ()> = MilestoneSpend<
+	<T as Config<I>>::AssetKind,
+	<T as Config<I>>::Beneficiary,
+	<T as frame_system::Config>::AccountId,
+	InstallmentsOf<T, I>,
+>;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -282,6 +328,11 @@ pub mod pallet {
 		#[pallet::constant]
 		type PayoutPeriod: Get<BlockNumberFor<Self, I>>;
 
+		/// The maximum number of installments of a spend approved with
+		/// [`Pallet::spend_in_installments`].
+		#[pallet::constant]
+		type MaxInstallments: Get<u32>;
+
 		/// Helper type for benchmarks.
 		#[cfg(feature = "runtime-benchmarks")]
 		type BenchmarkHelper: ArgumentsFactory<Self::AssetKind, Self::Beneficiary>;
@@ -356,6 +407,16 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type LastSpendPeriod<T, I = ()> = StorageValue<_, BlockNumberFor<T, I>, OptionQuery>;
 
+	/// The count of milestone spends that have been made.
+	#[pallet::storage]
+	pub type MilestoneSpendCount<T, I = ()> = StorageValue<_, MilestoneSpendIndex, ValueQuery>;
+
+	/// Milestone spends with installments that have not been released yet.
+	// Hasher: Twox safe since `MilestoneSpendIndex` is an internal count based index.
+	#[pallet::storage]
+	pub type MilestoneSpends<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, MilestoneSpendIndex, MilestoneSpendOf<T, I>, OptionQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
@@ -414,6 +475,18 @@ pub mod pallet {
 		/// A spend was processed and removed from the storage. It might have been successfully
 		/// paid or it may have expired.
 		SpendProcessed { index: SpendIndex },
+		/// A new spend whose installments are released by a curator has been approved.
+		MilestoneSpendApproved {
+			index: MilestoneSpendIndex,
+			asset_kind: T::AssetKind,
+			beneficiary: T::Beneficiary,
+			curator: T::AccountId,
+			installments: u32,
+		},
+		/// The curator approved a milestone, releasing the next installment as a spend.
+		MilestoneApproved { index: MilestoneSpendIndex, installment: u32, spend_index: SpendIndex },
+		/// The installments of a milestone spend that were not released yet were voided.
+		MilestoneSpendVoided { index: MilestoneSpendIndex },
 	}
 
 	/// Error for the treasury pallet.
@@ -442,6 +515,12 @@ pub mod pallet {
 		NotAttempted,
 		/// The payment has neither failed nor succeeded yet.
 		Inconclusive,
+		/// A spend in installments needs at least one installment.
+		NoInstallments,
+		/// The installments are not in chronological order.
+		UnorderedInstallments,
+		/// The caller is not the curator of the milestone spend.
+		NotCurator,
 	}
 
 	#[pallet::hooks]
@@ -839,6 +918,220 @@ pub mod pallet {
 			Self::deposit_event(Event::<T, I>::AssetSpendVoided { index });
 			Ok(())
 		}
+
+		/// Propose and approve a spend of treasury funds, paid out in installments.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be [`Config::SpendOrigin`] with the `Success` value being at least the sum of all
+		/// `installments` of `asset_kind` in the native asset. The amount of `asset_kind` is
+		/// converted for assertion using the [`Config::BalanceConverter`].
+		///
+		/// ## Details
+		///
+		/// Without a `curator`, each installment is approved as a separate spend right away, valid
+		/// from its own `valid_from` block. The earliest installment must not have expired yet.
+		///
+		/// With a `curator`, the installments are approved as a milestone spend. Each of them only
+		/// becomes a spend once the curator approves the corresponding milestone with
+		/// `approve_milestone`, which allows paying out tranches on deliverables rather than on
+		/// dates.
+		///
+		/// Either way, every released installment is claimed with `payout` and tracked with
+		/// `check_status` and `void_spend` like any other spend.
+		///
+		/// ### Parameters
+		/// - `asset_kind`: An indicator of the specific asset class to be spent.
+		/// - `beneficiary`: The beneficiary of the installments.
+		/// - `installments`: The amounts of the installments and the blocks from which they can be
+		///   claimed, in chronological order.
+		/// - `curator`: The account approving the milestones, if any.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::AssetSpendApproved`] for each installment if there is no `curator`, and
+		/// [`Event::MilestoneSpendApproved`] otherwise.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::spend_in_installments(installments.len() as u32))]
+		pub fn spend_in_installments(
+			origin: OriginFor<T>,
+			asset_kind: Box<T::AssetKind>,
+			beneficiary: Box<BeneficiaryLookupOf<T, I>>,
+			installments: InstallmentsOf<T, I>,
+			curator: Option<AccountIdLookupOf<T>>,
+		) -> DispatchResult {
+			let max_amount = T::SpendOrigin::ensure_origin(origin)?;
+			let beneficiary = T::BeneficiaryLookup::lookup(*beneficiary)?;
+			let curator = curator.map(T::Lookup::lookup).transpose()?;
+
+			let first = installments.first().ok_or(Error::<T, I>::NoInstallments)?;
+			ensure!(
+				installments.windows(2).all(|pair| pair[0].valid_from <= pair[1].valid_from),
+				Error::<T, I>::UnorderedInstallments
+			);
+			if curator.is_none() {
+				let now = T::BlockNumberProvider::current_block_number();
+				ensure!(
+					first.valid_from.saturating_add(T::PayoutPeriod::get()) > now,
+					Error::<T, I>::SpendExpired
+				);
+			}
+
+			let amount = installments
+				.iter()
+				.try_fold(AssetBalanceOf::<T, I>::zero(), |total, installment| {
+					total.checked_add(&installment.amount)
+				})
+				.ok_or(ArithmeticError::Overflow)?;
+			let native_amount =
+				T::BalanceConverter::from_asset_balance(amount, *asset_kind.clone())
+					.map_err(|_| Error::<T, I>::FailedToConvertBalance)?;
+
+			ensure!(native_amount <= max_amount, Error::<T, I>::InsufficientPermission);
+
+			with_context::<SpendContext<BalanceOf<T, I>>, _>(|v| {
+				let context = v.or_default();
+				// We group based on `max_amount`, to distinguish between different kind of
+				// origins. (assumes that all origins have different `max_amount`)
+				//
+				// Worst case is that we reject some "valid" request.
+				let spend = context.spend_in_context.entry(max_amount).or_default();
+
+				// Ensure that we don't overflow nor use more than `max_amount`
+				if spend.checked_add(&native_amount).map(|s| s > max_amount).unwrap_or(true) {
+					Err(Error::<T, I>::InsufficientPermission)
+				} else {
+					*spend = spend.saturating_add(native_amount);
+					Ok(())
+				}
+			})
+			.unwrap_or(Ok(()))?;
+
+			match curator {
+				None =>
+					for installment in installments {
+						Self::approve_spend(
+							*asset_kind.clone(),
+							installment.amount,
+							beneficiary.clone(),
+							installment.valid_from,
+						);
+					},
+				Some(curator) => {
+					let index = MilestoneSpendCount::<T, I>::get();
+					let count = installments.len() as u32;
+					MilestoneSpends::<T, I>::insert(
+						index,
+						MilestoneSpend {
+							asset_kind: *asset_kind.clone(),
+							beneficiary: beneficiary.clone(),
+							curator: curator.clone(),
+							installments,
+							released: 0,
+						},
+					);
+					MilestoneSpendCount::<T, I>::put(index + 1);
+
+					Self::deposit_event(Event::MilestoneSpendApproved {
+						index,
+						asset_kind: *asset_kind,
+						beneficiary,
+						curator,
+						installments: count,
+					});
+				},
+			}
+			Ok(())
+		}
+
+		/// Approve the next milestone of a milestone spend, releasing its installment.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be signed by the curator of the milestone spend.
+		///
+		/// ## Details
+		///
+		/// The installment becomes a spend that can be claimed from its scheduled `valid_from`
+		/// block, or from now if that block has passed already. The milestone spend is removed
+		/// from the storage once its last installment is released.
+		///
+		/// ### Parameters
+		/// - `index`: The milestone spend index.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::MilestoneApproved`] and [`Event::AssetSpendApproved`] if successful.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::approve_milestone())]
+		pub fn approve_milestone(
+			origin: OriginFor<T>,
+			index: MilestoneSpendIndex,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut milestone_spend =
+				MilestoneSpends::<T, I>::get(index).ok_or(Error::<T, I>::InvalidIndex)?;
+			ensure!(milestone_spend.curator == who, Error::<T, I>::NotCurator);
+
+			let installment = milestone_spend
+				.installments
+				.get(milestone_spend.released as usize)
+				.cloned()
+				.ok_or(Error::<T, I>::InvalidIndex)?;
+			let now = T::BlockNumberProvider::current_block_number();
+			let spend_index = Self::approve_spend(
+				milestone_spend.asset_kind.clone(),
+				installment.amount,
+				milestone_spend.beneficiary.clone(),
+				installment.valid_from.max(now),
+			);
+
+			let installment_index = milestone_spend.released;
+			milestone_spend.released.saturating_inc();
+			if milestone_spend.released as usize >= milestone_spend.installments.len() {
+				MilestoneSpends::<T, I>::remove(index);
+			} else {
+				MilestoneSpends::<T, I>::insert(index, milestone_spend);
+			}
+
+			Self::deposit_event(Event::<T, I>::MilestoneApproved {
+				index,
+				installment: installment_index,
+				spend_index,
+			});
+			Ok(())
+		}
+
+		/// Void the installments of a milestone spend that have not been released yet.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be [`Config::RejectOrigin`].
+		///
+		/// ## Details
+		///
+		/// Installments released already are spends on their own and can be voided with
+		/// `void_spend`.
+		///
+		/// ### Parameters
+		/// - `index`: The milestone spend index.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::MilestoneSpendVoided`] if successful.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::void_milestone_spend())]
+		pub fn void_milestone_spend(
+			origin: OriginFor<T>,
+			index: MilestoneSpendIndex,
+		) -> DispatchResult {
+			T::RejectOrigin::ensure_origin(origin)?;
+			ensure!(MilestoneSpends::<T, I>::contains_key(index), Error::<T, I>::InvalidIndex);
+
+			MilestoneSpends::<T, I>::remove(index);
+			Self::deposit_event(Event::<T, I>::MilestoneSpendVoided { index });
+			Ok(())
+		}
 	}
 }
 
@@ -899,6 +1192,40 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Approvals::<T, I>::get()
 	}
 
+	/// Approve a spend of `amount` of `asset_kind` to `beneficiary`, claimable from `valid_from`
+	/// and within one [`Config::PayoutPeriod`] thereafter.
+	fn approve_spend(
+		asset_kind: T::AssetKind,
+		amount: AssetBalanceOf<T, I>,
+		beneficiary: T::Beneficiary,
+		valid_from: BlockNumberFor<T, I>,
+	) -> SpendIndex {
+		let expire_at = valid_from.saturating_add(T::PayoutPeriod::get());
+		let index = SpendCount::<T, I>::get();
+		Spends::<T, I>::insert(
+			index,
+			SpendStatus {
+				asset_kind: asset_kind.clone(),
+				amount,
+				beneficiary: beneficiary.clone(),
+				valid_from,
+				expire_at,
+				status: PaymentState::Pending,
+			},
+		);
+		SpendCount::<T, I>::put(index + 1);
+
+		Self::deposit_event(Event::AssetSpendApproved {
+			index,
+			asset_kind,
+			amount,
+			beneficiary,
+			valid_from,
+			expire_at,
+		});
+		index
+	}
+
 	/// Spend some money! returns number of approvals before spend.
 	pub fn spend_funds(
 		spend_periods_passed: BlockNumberFor<T, I>,
@@ -1003,6 +1330,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
 		Self::try_state_proposals()?;
 		Self::try_state_spends()?;
+		Self::try_state_milestone_spends()?;
 
 		Ok(())
 	}
@@ -1076,6 +1404,29 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		Ok(())
 	}
+
+	/// ## Invariants of milestone spend storage items
+	///
+	/// 1. Each entry in [`MilestoneSpends`] should be saved under a key strictly less than current
+	/// [`MilestoneSpendCount`].
+	/// 2. Each entry in [`MilestoneSpends`] should have installments left to release.
+	#[cfg(any(feature = "try-runtime", test))]
+	fn try_state_milestone_spends() -> Result<(), sp_runtime::TryRuntimeError> {
+		let current_count = MilestoneSpendCount::<T, I>::get();
+		MilestoneSpends::<T, I>::iter().try_for_each(|(index, spend)| -> DispatchResult {
+			ensure!(
+				current_count > index,
+				"`MilestoneSpendCount` should by strictly greater than any MilestoneSpendIndex used as a key for `MilestoneSpends`."
+			);
+			ensure!(
+				(spend.released as usize) < spend.installments.len(),
+				"Milestone spends without installments left should have been removed."
+			);
+			Ok(())
+		})?;
+
+		Ok(())
+	}
 }
 
 impl<T: Config<I>, I: 'static> OnUnbalanced<NegativeImbalanceOf<T, I>> for Pallet<T, I> {
@@ -195,6 +195,7 @@ impl Config for Test {
 	type Paymaster = TestPay;
 	type BalanceConverter = MulBy<ConstU64<2>>;
 	type PayoutPeriod = SpendPayoutPeriod;
+	type MaxInstallments = ConstU32<10>;
 	type BlockNumberProvider = System;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
@@ -788,6 +789,232 @@ fn void_spend_works() {
 	});
 }
 
+fn installments(schedule: Vec<(u64, u64)>) -> InstallmentsOf<Test> {
+	schedule
+		.into_iter()
+		.map(|(amount, valid_from)| Installment { amount, valid_from })
+		.collect::<Vec<_>>()
+		.try_into()
+		.unwrap()
+}
+
+#[test]
+fn spend_in_installments_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		// approve three installments of asset `1` to beneficiary `6`, worth `18` in total.
+		assert_ok!(Treasury::spend_in_installments(
+			RuntimeOrigin::signed(12),
+			Box::new(1),
+			Box::new(6),
+			installments(vec![(2, 1), (3, 10), (4, 20)]),
+			None,
+		));
+
+		// each installment is a spend on its own.
+		assert_eq!(SpendCount::<Test, _>::get(), 3);
+		assert_eq!(
+			Spends::<Test, _>::get(1).unwrap(),
+			SpendStatus {
+				asset_kind: 1,
+				amount: 3,
+				beneficiary: 6,
+				valid_from: 10,
+				expire_at: 15,
+				status: PaymentState::Pending,
+			}
+		);
+		System::assert_last_event(
+			Event::<Test, _>::AssetSpendApproved {
+				index: 2,
+				asset_kind: 1,
+				amount: 4,
+				beneficiary: 6,
+				valid_from: 20,
+				expire_at: 25,
+			}
+			.into(),
+		);
+
+		// the first installment can be claimed right away, the second one only from block `10`.
+		assert_ok!(Treasury::payout(RuntimeOrigin::signed(1), 0));
+		assert_noop!(Treasury::payout(RuntimeOrigin::signed(1), 1), Error::<Test, _>::EarlyPayout);
+		System::set_block_number(10);
+		assert_ok!(Treasury::payout(RuntimeOrigin::signed(1), 1));
+		assert_eq!(paid(6, 1), 5);
+
+		// the last installment can be voided on its own.
+		assert_ok!(Treasury::void_spend(RuntimeOrigin::root(), 2));
+		assert_eq!(Spends::<Test, _>::get(2), None);
+		assert!(Spends::<Test, _>::get(1).is_some());
+	});
+}
+
+#[test]
+fn spend_in_installments_handles_errors() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(10);
+		assert_noop!(
+			Treasury::spend_in_installments(
+				RuntimeOrigin::signed(1),
+				Box::new(1),
+				Box::new(6),
+				installments(vec![(1, 10)]),
+				None,
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			Treasury::spend_in_installments(
+				RuntimeOrigin::signed(12),
+				Box::new(1),
+				Box::new(6),
+				installments(vec![]),
+				None,
+			),
+			Error::<Test, _>::NoInstallments
+		);
+		assert_noop!(
+			Treasury::spend_in_installments(
+				RuntimeOrigin::signed(12),
+				Box::new(1),
+				Box::new(6),
+				installments(vec![(1, 20), (1, 15)]),
+				None,
+			),
+			Error::<Test, _>::UnorderedInstallments
+		);
+		// all installments together are worth `22`, above the `20` allowed for `12`.
+		assert_noop!(
+			Treasury::spend_in_installments(
+				RuntimeOrigin::signed(12),
+				Box::new(1),
+				Box::new(6),
+				installments(vec![(5, 10), (6, 20)]),
+				None,
+			),
+			Error::<Test, _>::InsufficientPermission
+		);
+		// the first installment has expired already.
+		assert_noop!(
+			Treasury::spend_in_installments(
+				RuntimeOrigin::signed(12),
+				Box::new(1),
+				Box::new(6),
+				installments(vec![(1, 5), (1, 20)]),
+				None,
+			),
+			Error::<Test, _>::SpendExpired
+		);
+		// unless its milestone still has to be approved.
+		assert_ok!(Treasury::spend_in_installments(
+			RuntimeOrigin::signed(12),
+			Box::new(1),
+			Box::new(6),
+			installments(vec![(1, 5), (1, 20)]),
+			Some(7),
+		));
+	});
+}
+
+#[test]
+fn milestone_spend_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Treasury::spend_in_installments(
+			RuntimeOrigin::signed(12),
+			Box::new(1),
+			Box::new(6),
+			installments(vec![(2, 5), (3, 5)]),
+			Some(7),
+		));
+		System::assert_last_event(
+			Event::<Test, _>::MilestoneSpendApproved {
+				index: 0,
+				asset_kind: 1,
+				beneficiary: 6,
+				curator: 7,
+				installments: 2,
+			}
+			.into(),
+		);
+		// nothing can be claimed before a milestone is approved.
+		assert_eq!(SpendCount::<Test, _>::get(), 0);
+		assert_eq!(MilestoneSpendCount::<Test, _>::get(), 1);
+
+		// only the curator approves milestones.
+		assert_noop!(
+			Treasury::approve_milestone(RuntimeOrigin::signed(8), 0),
+			Error::<Test, _>::NotCurator
+		);
+		assert_ok!(Treasury::approve_milestone(RuntimeOrigin::signed(7), 0));
+		System::assert_last_event(
+			Event::<Test, _>::MilestoneApproved { index: 0, installment: 0, spend_index: 0 }.into(),
+		);
+
+		// the released installment is still bound to its schedule.
+		assert_eq!(Spends::<Test, _>::get(0).unwrap().valid_from, 5);
+		assert_noop!(Treasury::payout(RuntimeOrigin::signed(1), 0), Error::<Test, _>::EarlyPayout);
+		System::set_block_number(5);
+		assert_ok!(Treasury::payout(RuntimeOrigin::signed(1), 0));
+		assert_eq!(paid(6, 1), 2);
+
+		// a milestone approved late can be claimed right away.
+		System::set_block_number(8);
+		assert_ok!(Treasury::approve_milestone(RuntimeOrigin::signed(7), 0));
+		assert_eq!(
+			Spends::<Test, _>::get(1).unwrap(),
+			SpendStatus {
+				asset_kind: 1,
+				amount: 3,
+				beneficiary: 6,
+				valid_from: 8,
+				expire_at: 13,
+				status: PaymentState::Pending,
+			}
+		);
+
+		// the milestone spend is gone once all installments are released.
+		assert_eq!(MilestoneSpends::<Test, _>::get(0), None);
+		assert_noop!(
+			Treasury::approve_milestone(RuntimeOrigin::signed(7), 0),
+			Error::<Test, _>::InvalidIndex
+		);
+	});
+}
+
+#[test]
+fn void_milestone_spend_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Treasury::spend_in_installments(
+			RuntimeOrigin::signed(12),
+			Box::new(1),
+			Box::new(6),
+			installments(vec![(2, 1), (3, 10)]),
+			Some(7),
+		));
+		assert_ok!(Treasury::approve_milestone(RuntimeOrigin::signed(7), 0));
+
+		assert_noop!(Treasury::void_milestone_spend(RuntimeOrigin::signed(7), 0), BadOrigin);
+		assert_ok!(Treasury::void_milestone_spend(RuntimeOrigin::root(), 0));
+		System::assert_last_event(Event::<Test, _>::MilestoneSpendVoided { index: 0 }.into());
+
+		// the remaining milestone cannot be approved anymore.
+		assert_noop!(
+			Treasury::approve_milestone(RuntimeOrigin::signed(7), 0),
+			Error::<Test, _>::InvalidIndex
+		);
+		assert_noop!(
+			Treasury::void_milestone_spend(RuntimeOrigin::root(), 0),
+			Error::<Test, _>::InvalidIndex
+		);
+		// but the installment released already can still be claimed.
+		assert_ok!(Treasury::payout(RuntimeOrigin::signed(1), 0));
+		assert_eq!(paid(6, 1), 2);
+	});
+}
+
 #[test]
 fn check_status_works() {
 	ExtBuilder::default().build().execute_with(|| {
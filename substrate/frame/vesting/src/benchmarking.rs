@@ -69,6 +69,23 @@ fn add_vesting_schedules<T: Config>(
 	Ok(total_locked)
 }
 
+fn piecewise_curve<T: Config>(locked: BalanceOf<T>) -> VestingCurveOf<T> {
+	// Use as many points as possible, unlocking evenly up to `locked`.
+	let count = T::MaxCurvePoints::get().max(1);
+	let mut points = (1..=count)
+		.map(|i| (i.into(), locked / count.into() * i.into()))
+		.collect::<Vec<(BlockNumberFor<T>, BalanceOf<T>)>>();
+	if let Some(last) = points.last_mut() {
+		last.1 = locked;
+	}
+
+	VestingCurve::Piecewise {
+		locked,
+		starting_block: Zero::zero(),
+		points: points.try_into().expect("there are at most `MaxCurvePoints` points; qed"),
+	}
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
@@ -435,6 +452,126 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn grant_vesting(
+		l: Linear<0, { MaxLocksOf::<T>::get() - 1 }>,
+		s: Linear<0, { T::MAX_VESTING_SCHEDULES - 1 }>,
+	) -> Result<(), BenchmarkError> {
+		let caller = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+
+		let target = account::<T::AccountId>("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		// Give target existing locks.
+		T::Currency::make_free_balance_be(&target, T::Currency::minimum_balance());
+		add_locks::<T>(&target, l as u8);
+		let orig_balance = T::Currency::free_balance(&target);
+		let mut expected_balance = add_vesting_schedules::<T>(&target, s)?;
+
+		let transfer_amount = T::MinVestedTransfer::get();
+		expected_balance += transfer_amount;
+
+		let schedule = piecewise_curve::<T>(transfer_amount);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), target_lookup, schedule, true);
+
+		assert_eq!(
+			orig_balance + expected_balance,
+			T::Currency::free_balance(&target),
+			"Transfer didn't happen",
+		);
+		assert_eq!(
+			Pallet::<T>::vesting_balance(&target),
+			Some(expected_balance),
+			"Lock not correctly updated",
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn revoke_vesting(
+		l: Linear<0, { MaxLocksOf::<T>::get() - 1 }>,
+		s: Linear<1, T::MAX_VESTING_SCHEDULES>,
+	) -> Result<(), BenchmarkError> {
+		let granter: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&granter, BalanceOf::<T>::max_value());
+
+		let target = account::<T::AccountId>("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		// Give target existing locks.
+		T::Currency::make_free_balance_be(&target, T::Currency::minimum_balance());
+		add_locks::<T>(&target, l as u8);
+		add_vesting_schedules::<T>(&target, s - 1)?;
+
+		// The grant is removed from behind all linear schedules.
+		let transfer_amount = T::MinVestedTransfer::get();
+		let schedule = piecewise_curve::<T>(transfer_amount);
+		assert_ok!(Pallet::<T>::do_grant_vesting(&granter, &target, schedule.clone(), true));
+		let grant = VestingGrant { schedule, granter: Some(granter.clone()) };
+		let granter_balance = T::Currency::free_balance(&granter);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(granter.clone()), target_lookup, 0, Box::new(grant));
+
+		assert_eq!(
+			T::Currency::free_balance(&granter),
+			granter_balance + transfer_amount,
+			"Unvested funds were not returned",
+		);
+		assert!(!VestingGrants::<T>::contains_key(&target), "Grant was not revoked");
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn claw_back(
+		l: Linear<0, { MaxLocksOf::<T>::get() - 2 }>,
+		s: Linear<0, { T::MAX_VESTING_SCHEDULES - 1 }>,
+	) -> Result<(), BenchmarkError> {
+		let granter = account::<T::AccountId>("granter", 0, SEED);
+		T::Currency::make_free_balance_be(&granter, BalanceOf::<T>::max_value());
+
+		let target = account::<T::AccountId>("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		// Give target existing locks.
+		T::Currency::make_free_balance_be(&target, T::Currency::minimum_balance());
+		add_locks::<T>(&target, l as u8);
+		add_vesting_schedules::<T>(&target, s)?;
+
+		// Revoke the grant while another lock covers its funds, so that the claw-back is pending.
+		let transfer_amount = T::MinVestedTransfer::get();
+		let schedule = piecewise_curve::<T>(transfer_amount);
+		assert_ok!(Pallet::<T>::do_grant_vesting(&granter, &target, schedule.clone(), true));
+		let grant = VestingGrant { schedule, granter: Some(granter.clone()) };
+		let blocking_id = *b"blocking";
+		T::Currency::set_lock(
+			blocking_id,
+			&target,
+			BalanceOf::<T>::max_value(),
+			WithdrawReasons::all(),
+		);
+		assert_ok!(Pallet::<T>::do_revoke_vesting(None, &target, 0, &grant));
+		assert!(PendingClawbacks::<T>::contains_key(&target), "Claw-back is not pending");
+		T::Currency::remove_lock(blocking_id, &target);
+		let granter_balance = T::Currency::free_balance(&granter);
+
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), target_lookup);
+
+		assert_eq!(
+			T::Currency::free_balance(&granter),
+			granter_balance + transfer_amount,
+			"Unvested funds were not returned",
+		);
+		assert!(!PendingClawbacks::<T>::contains_key(&target), "Claw-back is still pending");
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite! {
 		Pallet,
 		mock::ExtBuilder::default().existential_deposit(256).build(),
@@ -27,6 +27,14 @@
 //! amount for any reason other than the ones specified in `UnvestedFundsAllowedWithdrawReasons`
 //! configuration value.
 //!
+//! Besides the linear [`VestingInfo`] schedules, an account may receive grants that unlock along
+//! a [`VestingCurve`]: linear after a cliff, in equal periodic steps, or along a custom piecewise
+//! curve. A grant may be made revocable, in which case the granter or `RevokeOrigin` can cancel it
+//! and return the funds that are still unvested to the granter. An account's schedules are indexed
+//! with its linear schedules first, followed by its grants. Revoking a grant instead refers to it
+//! by its index among the grants alone, together with the grant that is expected at that index,
+//! so that a revocation never hits another grant after the indices have shifted.
+//!
 //! As the amount vested increases over time, the amount unvested reduces. However, locks remain in
 //! place and explicit action is needed on behalf of the user to ensure that the amount locked is
 //! equivalent to the amount remaining to be vested. This is done through a dispatchable function,
@@ -42,6 +50,8 @@
 //! - `vest` - Update the lock, reducing it in line with the amount "vested" so far.
 //! - `vest_other` - Update the lock of another account, reducing it in line with the amount
 //!   "vested" so far.
+//! - `grant_vesting` - Transfer funds to another account, locked under a vesting curve.
+//! - `revoke_vesting` - Revoke a revocable grant, returning the unvested funds to the granter.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -51,6 +61,7 @@ mod benchmarking;
 mod mock;
 #[cfg(test)]
 mod tests;
+mod vesting_curve;
 mod vesting_info;
 
 pub mod migrations;
@@ -58,16 +69,16 @@ pub mod weights;
 
 extern crate alloc;
 
-use alloc::vec::Vec;
+use alloc::{boxed::Box, vec::Vec};
 use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use core::{fmt::Debug, marker::PhantomData};
 use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
-	storage::bounded_vec::BoundedVec,
+	storage::{bounded_vec::BoundedVec, with_storage_layer},
 	traits::{
-		Currency, ExistenceRequirement, Get, LockIdentifier, LockableCurrency, VestedTransfer,
-		VestingSchedule, WithdrawReasons,
+		Currency, EnsureOrigin, ExistenceRequirement, Get, LockIdentifier, LockableCurrency,
+		VestedTransfer, VestingSchedule, WithdrawReasons,
 	},
 	weights::Weight,
 };
@@ -82,6 +93,7 @@ use sp_runtime::{
 };
 
 pub use pallet::*;
+pub use vesting_curve::*;
 pub use vesting_info::*;
 pub use weights::WeightInfo;
 
@@ -90,6 +102,14 @@ type BalanceOf<T> =
 type MaxLocksOf<T> =
 	<<T as Config>::Currency as LockableCurrency<<T as frame_system::Config>::AccountId>>::MaxLocks;
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
+type VestingCurveOf<T> =
+	VestingCurve<BalanceOf<T>, BlockNumberFor<T>, <T as Config>::MaxCurvePoints>;
+type VestingGrantOf<T> = VestingGrant<
+	BalanceOf<T>,
+	BlockNumberFor<T>,
+	<T as frame_system::Config>::AccountId,
+	<T as Config>::MaxCurvePoints,
+>;
 
 const VESTING_ID: LockIdentifier = *b"vesting ";
 
@@ -129,12 +149,11 @@ impl VestingAction {
 	}
 
 	/// Pick the schedules that this action dictates should continue vesting undisturbed.
-	fn pick_schedules<T: Config>(
-		&self,
-		schedules: Vec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>>,
-	) -> impl Iterator<Item = VestingInfo<BalanceOf<T>, BlockNumberFor<T>>> + '_ {
+	///
+	/// `offset` is the index of the first of `schedules` among all of the account's schedules.
+	fn pick_schedules<S>(&self, schedules: Vec<S>, offset: usize) -> impl Iterator<Item = S> + '_ {
 		schedules.into_iter().enumerate().filter_map(move |(index, schedule)| {
-			if self.should_remove(index) {
+			if self.should_remove(offset + index) {
 				None
 			} else {
 				Some(schedule)
@@ -204,7 +223,16 @@ pub mod pallet {
 		///     parachain is lagging its block production to avoid clock skew.
 		type BlockNumberProvider: BlockNumberProvider<BlockNumber = BlockNumberFor<Self>>;
 
+		/// Origin that may revoke any revocable vesting grant, in addition to its granter.
+		type RevokeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Maximum number of points of a piecewise vesting curve.
+		#[pallet::constant]
+		type MaxCurvePoints: Get<u32>;
+
 		/// Maximum number of vesting schedules an account may have at a given moment.
+		///
+		/// This bounds the linear schedules and vesting grants of an account together.
 		const MAX_VESTING_SCHEDULES: u32;
 	}
 
@@ -232,6 +260,26 @@ pub mod pallet {
 		BoundedVec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>, MaxVestingSchedulesGet<T>>,
 	>;
 
+	/// Vesting grants of a given account, which unlock along a curve and may be revocable.
+	#[pallet::storage]
+	pub type VestingGrants<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<VestingGrantOf<T>, MaxVestingSchedulesGet<T>>,
+	>;
+
+	/// Unvested funds of revoked grants which could not be returned to their granters yet, e.g.
+	/// because another lock of the account overlapped them. They stay locked under this pallet
+	/// until `claw_back` returns them.
+	#[pallet::storage]
+	pub type PendingClawbacks<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<(T::AccountId, BalanceOf<T>), MaxVestingSchedulesGet<T>>,
+	>;
+
 	/// Storage version of the pallet.
 	///
 	/// New networks start with latest version, as determined by the genesis build.
@@ -293,6 +341,13 @@ pub mod pallet {
 		VestingUpdated { account: T::AccountId, unvested: BalanceOf<T> },
 		/// An \[account\] has become fully vested.
 		VestingCompleted { account: T::AccountId },
+		/// A vesting grant has been revoked and its unvested funds returned to the granter.
+		VestingRevoked { account: T::AccountId, granter: T::AccountId, returned: BalanceOf<T> },
+		/// The unvested funds of a revoked grant could not be returned yet and stay locked until
+		/// they are clawed back.
+		ClawbackPending { account: T::AccountId, granter: T::AccountId, amount: BalanceOf<T> },
+		/// Unvested funds of a revoked grant which were pending have been returned to the granter.
+		ClawedBack { account: T::AccountId, granter: T::AccountId, amount: BalanceOf<T> },
 	}
 
 	/// Error for the vesting pallet.
@@ -309,6 +364,22 @@ pub mod pallet {
 		ScheduleIndexOutOfBounds,
 		/// Failed to create a new schedule because some parameter was invalid.
 		InvalidScheduleParams,
+		/// The schedule is not a revocable vesting grant.
+		NotRevocable,
+		/// Revocable vesting grants cannot be merged.
+		CannotMergeRevocable,
+		/// Only linear schedules can be merged while both of them are still vesting.
+		CannotMergeNonLinear,
+		/// The account has too many pending claw-backs of revoked grants.
+		TooManyPendingClawbacks,
+		/// The account has no pending claw-backs of revoked grants.
+		NoPendingClawback,
+		/// None of the pending claw-backs of the account could be returned.
+		ClawbackFailed,
+		/// Revocable vesting grants cannot be force removed, they must be revoked instead.
+		CannotRemoveRevocable,
+		/// The vesting grant at the given index is not the expected one.
+		GrantMismatch,
 	}
 
 	#[pallet::call]
@@ -426,6 +497,12 @@ pub mod pallet {
 		/// - `ending_block`: `MAX(schedule1.ending_block, schedule2.ending_block)`.
 		/// - `locked`: `schedule1.locked_at(current_block) + schedule2.locked_at(current_block)`.
 		///
+		/// Vesting grants can be merged as long as they are not revocable. While both schedules are
+		/// still vesting, only linear ones can be merged, since a single linear schedule could
+		/// unlock the funds of any other curve earlier than the curve itself. A newly merged
+		/// schedule is appended to the account's linear schedules, while a grant that is kept
+		/// unmodified is appended to the account's grants.
+		///
 		/// The dispatch origin for this call must be _Signed_.
 		///
 		/// - `schedule1_index`: index of the first schedule to merge.
@@ -447,13 +524,15 @@ pub mod pallet {
 			let schedule1_index = schedule1_index as usize;
 			let schedule2_index = schedule2_index as usize;
 
-			let schedules = Vesting::<T>::get(&who).ok_or(Error::<T>::NotVesting)?;
+			let (schedules, grants) = Self::schedules_of(&who)?;
 			let merge_action =
 				VestingAction::Merge { index1: schedule1_index, index2: schedule2_index };
 
-			let (schedules, locked_now) = Self::exec_action(schedules.to_vec(), merge_action)?;
+			let (schedules, grants, locked_now) =
+				Self::exec_action(schedules, grants, merge_action)?;
 
 			Self::write_vesting(&who, schedules)?;
+			Self::write_grants(&who, grants)?;
 			Self::write_lock(&who, locked_now);
 
 			Ok(())
@@ -465,6 +544,8 @@ pub mod pallet {
 		///
 		/// - `target`: An account that has a vesting schedule
 		/// - `schedule_index`: The vesting schedule index that should be removed
+		///
+		/// Revocable vesting grants cannot be removed this way, see `revoke_vesting`.
 		#[pallet::call_index(5)]
 		#[pallet::weight(
 			T::WeightInfo::force_remove_vesting_schedule(MaxLocksOf::<T>::get(), T::MAX_VESTING_SCHEDULES)
@@ -477,17 +558,106 @@ pub mod pallet {
 			ensure_root(origin)?;
 			let who = T::Lookup::lookup(target)?;
 
-			let schedules_count = Vesting::<T>::decode_len(&who).unwrap_or_default();
-			ensure!(schedule_index < schedules_count as u32, Error::<T>::InvalidScheduleParams);
+			let schedules_count = Self::schedules_count(&who);
+			ensure!(schedule_index < schedules_count, Error::<T>::InvalidScheduleParams);
+			// Removing a revocable grant would keep its unvested funds from the granter.
+			let grant_index = (schedule_index as usize)
+				.checked_sub(Vesting::<T>::decode_len(&who).unwrap_or_default());
+			if let Some(grant_index) = grant_index {
+				let grants = VestingGrants::<T>::get(&who).unwrap_or_default();
+				ensure!(
+					grants.get(grant_index).map_or(true, |grant| grant.granter.is_none()),
+					Error::<T>::CannotRemoveRevocable
+				);
+			}
 
 			Self::remove_vesting_schedule(&who, schedule_index)?;
 
 			Ok(Some(T::WeightInfo::force_remove_vesting_schedule(
 				MaxLocksOf::<T>::get(),
-				schedules_count as u32,
+				schedules_count,
 			))
 			.into())
 		}
+
+		/// Create a vesting grant, transferring funds that unlock along the given curve.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `target`: The account receiving the vested funds.
+		/// - `schedule`: The vesting curve attached to the transfer.
+		/// - `revocable`: Whether the sender may later revoke the grant and reclaim the funds that
+		///   have not vested yet.
+		///
+		/// Emits `VestingCreated`.
+		///
+		/// NOTE: This will unlock all schedules through the current block.
+		#[pallet::call_index(6)]
+		#[pallet::weight(
+			T::WeightInfo::grant_vesting(MaxLocksOf::<T>::get(), T::MAX_VESTING_SCHEDULES)
+		)]
+		pub fn grant_vesting(
+			origin: OriginFor<T>,
+			target: AccountIdLookupOf<T>,
+			schedule: VestingCurveOf<T>,
+			revocable: bool,
+		) -> DispatchResult {
+			let granter = ensure_signed(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			Self::do_grant_vesting(&granter, &target, schedule, revocable)
+		}
+
+		/// Revoke a revocable vesting grant, returning the funds that have not vested yet to its
+		/// granter. Funds that have already vested stay with the target.
+		///
+		/// The dispatch origin for this call must be `RevokeOrigin` or _Signed_ by the granter.
+		///
+		/// - `target`: The account that received the grant.
+		/// - `grant_index`: The index of the grant among the target's vesting grants, not counting
+		///   its linear schedules.
+		/// - `expected_grant`: The grant that is expected at `grant_index`. The call fails if the
+		///   grants of the target have changed in the meantime.
+		///
+		/// Emits `VestingRevoked`.
+		///
+		/// NOTE: This will unlock all schedules through the current block.
+		#[pallet::call_index(7)]
+		#[pallet::weight(
+			T::WeightInfo::revoke_vesting(MaxLocksOf::<T>::get(), T::MAX_VESTING_SCHEDULES)
+		)]
+		pub fn revoke_vesting(
+			origin: OriginFor<T>,
+			target: AccountIdLookupOf<T>,
+			grant_index: u32,
+			expected_grant: Box<VestingGrantOf<T>>,
+		) -> DispatchResult {
+			let maybe_granter = match T::RevokeOrigin::try_origin(origin) {
+				Ok(_) => None,
+				Err(origin) => Some(ensure_signed(origin)?),
+			};
+			let who = T::Lookup::lookup(target)?;
+			Self::do_revoke_vesting(maybe_granter, &who, grant_index, &expected_grant)
+		}
+
+		/// Return the unvested funds of revoked grants of `target` which could not be returned
+		/// when the grants were revoked. Claw-backs which still cannot be returned stay pending.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `target`: The account that received the revoked grants.
+		///
+		/// Emits `ClawedBack` for each returned claw-back.
+		///
+		/// NOTE: This will unlock all schedules through the current block.
+		#[pallet::call_index(8)]
+		#[pallet::weight(
+			T::WeightInfo::claw_back(MaxLocksOf::<T>::get(), T::MAX_VESTING_SCHEDULES)
+		)]
+		pub fn claw_back(origin: OriginFor<T>, target: AccountIdLookupOf<T>) -> DispatchResult {
+			ensure_signed(origin)?;
+			let who = T::Lookup::lookup(target)?;
+			Self::do_claw_back(&who)
+		}
 	}
 }
 
@@ -500,13 +670,21 @@ impl<T: Config> Pallet<T> {
 		Vesting::<T>::get(account)
 	}
 
-	// Create a new `VestingInfo`, based off of two other `VestingInfo`s.
+	// Public function for accessing vesting grants storage
+	pub fn vesting_grants(
+		account: T::AccountId,
+	) -> Option<BoundedVec<VestingGrantOf<T>, MaxVestingSchedulesGet<T>>> {
+		VestingGrants::<T>::get(account)
+	}
+
+	// Create a new linear schedule, based off of two other linear schedules. If one of the two has
+	// ended, the other is returned unmodified, whatever its curve.
 	// NOTE: We assume both schedules have had funds unlocked up through the current block.
 	fn merge_vesting_info(
 		now: BlockNumberFor<T>,
-		schedule1: VestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
-		schedule2: VestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
-	) -> Option<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>> {
+		schedule1: VestingCurveOf<T>,
+		schedule2: VestingCurveOf<T>,
+	) -> Result<Option<VestingCurveOf<T>>, DispatchError> {
 		let schedule1_ending_block = schedule1.ending_block_as_balance::<T::BlockNumberToBalance>();
 		let schedule2_ending_block = schedule2.ending_block_as_balance::<T::BlockNumberToBalance>();
 		let now_as_balance = T::BlockNumberToBalance::convert(now);
@@ -514,15 +692,21 @@ impl<T: Config> Pallet<T> {
 		// Check if one or both schedules have ended.
 		match (schedule1_ending_block <= now_as_balance, schedule2_ending_block <= now_as_balance) {
 			// If both schedules have ended, we don't merge and exit early.
-			(true, true) => return None,
+			(true, true) => return Ok(None),
 			// If one schedule has ended, we treat the one that has not ended as the new
 			// merged schedule.
-			(true, false) => return Some(schedule2),
-			(false, true) => return Some(schedule1),
+			(true, false) => return Ok(Some(schedule2)),
+			(false, true) => return Ok(Some(schedule1)),
 			// If neither schedule has ended don't exit early.
 			_ => {},
 		}
 
+		let (VestingCurve::Linear(schedule1), VestingCurve::Linear(schedule2)) =
+			(schedule1, schedule2)
+		else {
+			return Err(Error::<T>::CannotMergeNonLinear.into())
+		};
+
 		let locked = schedule1
 			.locked_at::<T::BlockNumberToBalance>(now)
 			.saturating_add(schedule2.locked_at::<T::BlockNumberToBalance>(now));
@@ -546,7 +730,44 @@ impl<T: Config> Pallet<T> {
 		let schedule = VestingInfo::new(locked, per_block, starting_block);
 		debug_assert!(schedule.is_valid(), "merge_vesting_info schedule validation check failed");
 
-		Some(schedule)
+		Ok(Some(schedule.into()))
+	}
+
+	// Get the schedule at `index` among all schedules of an account, as long as it may be merged.
+	fn mergeable_schedule(
+		schedules: &[VestingInfo<BalanceOf<T>, BlockNumberFor<T>>],
+		grants: &[VestingGrantOf<T>],
+		index: usize,
+	) -> Result<VestingCurveOf<T>, DispatchError> {
+		if let Some(schedule) = schedules.get(index) {
+			return Ok((*schedule).into())
+		}
+		let grant = grants
+			.get(index - schedules.len())
+			.ok_or(Error::<T>::ScheduleIndexOutOfBounds)?;
+		ensure!(grant.granter.is_none(), Error::<T>::CannotMergeRevocable);
+		Ok(grant.schedule.clone())
+	}
+
+	// The number of linear schedules and vesting grants of `who` together.
+	fn schedules_count(who: &T::AccountId) -> u32 {
+		let schedules = Vesting::<T>::decode_len(who).unwrap_or_default();
+		let grants = VestingGrants::<T>::decode_len(who).unwrap_or_default();
+		schedules.saturating_add(grants) as u32
+	}
+
+	// Get the linear schedules and vesting grants of `who`, failing if it has neither.
+	fn schedules_of(
+		who: &T::AccountId,
+	) -> Result<
+		(Vec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>>, Vec<VestingGrantOf<T>>),
+		DispatchError,
+	> {
+		let schedules = Vesting::<T>::get(who);
+		let grants = VestingGrants::<T>::get(who);
+		ensure!(schedules.is_some() || grants.is_some(), Error::<T>::NotVesting);
+
+		Ok((schedules.unwrap_or_default().into_inner(), grants.unwrap_or_default().into_inner()))
 	}
 
 	// Execute a vested transfer from `source` to `target` with the given `schedule`.
@@ -585,6 +806,167 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	// Execute a vesting grant from `source` to `target` with the given `schedule`.
+	fn do_grant_vesting(
+		source: &T::AccountId,
+		target: &T::AccountId,
+		schedule: VestingCurveOf<T>,
+		revocable: bool,
+	) -> DispatchResult {
+		// Validate user inputs.
+		ensure!(schedule.locked() >= T::MinVestedTransfer::get(), Error::<T>::AmountLow);
+		ensure!(schedule.is_valid(), Error::<T>::InvalidScheduleParams);
+		ensure!(
+			Self::schedules_count(target) < T::MAX_VESTING_SCHEDULES,
+			Error::<T>::AtMaxVestingSchedules
+		);
+
+		T::Currency::transfer(source, target, schedule.locked(), ExistenceRequirement::AllowDeath)?;
+
+		let schedules = Vesting::<T>::get(target).unwrap_or_default().into_inner();
+		let mut grants = VestingGrants::<T>::get(target).unwrap_or_default().into_inner();
+		grants.push(VestingGrant { schedule, granter: revocable.then(|| source.clone()) });
+
+		Self::deposit_event(Event::<T>::VestingCreated {
+			account: target.clone(),
+			schedule_index: (schedules.len() + grants.len() - 1) as u32,
+		});
+
+		let (schedules, grants, locked_now) =
+			Self::exec_action(schedules, grants, VestingAction::Passive)?;
+
+		Self::write_vesting(target, schedules)?;
+		Self::write_grants(target, grants)?;
+		Self::write_lock(target, locked_now);
+
+		Ok(())
+	}
+
+	// Revoke the vesting grant of `who` at `grant_index` among its grants and return its unvested
+	// funds to the granter. The grant must match `expected_grant`. If `maybe_granter` is given, it
+	// must be the granter of the grant.
+	fn do_revoke_vesting(
+		maybe_granter: Option<T::AccountId>,
+		who: &T::AccountId,
+		grant_index: u32,
+		expected_grant: &VestingGrantOf<T>,
+	) -> DispatchResult {
+		let (schedules, grants) = Self::schedules_of(who)?;
+
+		let grant = grants.get(grant_index as usize).ok_or(Error::<T>::ScheduleIndexOutOfBounds)?;
+		ensure!(grant == expected_grant, Error::<T>::GrantMismatch);
+		// Grants are indexed after the linear schedules by `exec_action`.
+		let index = schedules.len().saturating_add(grant_index as usize);
+		let granter = grant.granter.clone().ok_or(Error::<T>::NotRevocable)?;
+		if let Some(origin) = maybe_granter {
+			ensure!(origin == granter, DispatchError::BadOrigin);
+		}
+		let now = T::BlockNumberProvider::current_block_number();
+		let unvested = grant.schedule.locked_at::<T::BlockNumberToBalance>(now);
+
+		let (schedules, grants, locked_now) =
+			Self::exec_action(schedules, grants, VestingAction::Remove { index })?;
+
+		Self::write_vesting(who, schedules)?;
+		Self::write_grants(who, grants)?;
+		Self::write_lock(who, locked_now);
+
+		// Some of the locked funds may have been spent already, e.g. on transaction fees.
+		let unvested = unvested.min(T::Currency::free_balance(who));
+		let returned = if Self::try_return(who, &granter, unvested) {
+			unvested
+		} else {
+			// Another lock overlaps the unvested funds, so keep them locked under this pallet
+			// until they can be clawed back.
+			Self::add_pending_clawback(who, &granter, unvested)?;
+			Self::write_lock(who, locked_now);
+			Self::deposit_event(Event::<T>::ClawbackPending {
+				account: who.clone(),
+				granter: granter.clone(),
+				amount: unvested,
+			});
+			Zero::zero()
+		};
+
+		Self::deposit_event(Event::<T>::VestingRevoked { account: who.clone(), granter, returned });
+
+		Ok(())
+	}
+
+	// Return the pending claw-backs of `who` to their granters, keeping those which still fail.
+	fn do_claw_back(who: &T::AccountId) -> DispatchResult {
+		let pending = PendingClawbacks::<T>::take(who).unwrap_or_default();
+		let count = pending.len();
+		ensure!(count > 0, Error::<T>::NoPendingClawback);
+
+		let schedules = Vesting::<T>::get(who).unwrap_or_default().into_inner();
+		let grants = VestingGrants::<T>::get(who).unwrap_or_default().into_inner();
+		let (schedules, grants, locked_now) =
+			Self::exec_action(schedules, grants, VestingAction::Passive)?;
+
+		Self::write_vesting(who, schedules)?;
+		Self::write_grants(who, grants)?;
+		// Lift the lock of the pending claw-backs so that they can be returned.
+		Self::write_lock(who, locked_now);
+
+		let mut remaining = BoundedVec::<_, MaxVestingSchedulesGet<T>>::new();
+		for (granter, amount) in pending {
+			let amount = amount.min(T::Currency::free_balance(who));
+			if Self::try_return(who, &granter, amount) {
+				Self::deposit_event(Event::<T>::ClawedBack {
+					account: who.clone(),
+					granter,
+					amount,
+				});
+			} else {
+				// `remaining` is never longer than `pending`.
+				let _ = remaining.try_push((granter, amount));
+			}
+		}
+		ensure!(remaining.len() < count, Error::<T>::ClawbackFailed);
+
+		if !remaining.is_empty() {
+			PendingClawbacks::<T>::insert(who, remaining);
+			Self::write_lock(who, locked_now);
+		}
+
+		Ok(())
+	}
+
+	// Transfer `amount` from `who` to `granter`, reverting any changes if the transfer fails.
+	fn try_return(who: &T::AccountId, granter: &T::AccountId, amount: BalanceOf<T>) -> bool {
+		with_storage_layer(|| {
+			T::Currency::transfer(who, granter, amount, ExistenceRequirement::AllowDeath)
+		})
+		.is_ok()
+	}
+
+	// Record that `amount` of the funds of `who` are owed to `granter`.
+	fn add_pending_clawback(
+		who: &T::AccountId,
+		granter: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		PendingClawbacks::<T>::try_mutate(who, |maybe_pending| {
+			let pending = maybe_pending.get_or_insert_with(Default::default);
+			if let Some((_, owed)) = pending.iter_mut().find(|(g, _)| g == granter) {
+				*owed = owed.saturating_add(amount);
+				return Ok(())
+			}
+			pending
+				.try_push((granter.clone(), amount))
+				.map_err(|_| Error::<T>::TooManyPendingClawbacks.into())
+		})
+	}
+
+	// The total of the unvested funds of `who` owed to the granters of revoked grants.
+	fn pending_clawback(who: &T::AccountId) -> BalanceOf<T> {
+		PendingClawbacks::<T>::get(who)
+			.unwrap_or_default()
+			.iter()
+			.fold(Zero::zero(), |total, (_, amount)| total.saturating_add(*amount))
+	}
+
 	/// Iterate through the schedules to track the current locked amount and
 	/// filter out completed and specified schedules.
 	///
@@ -603,7 +985,7 @@ impl<T: Config> Pallet<T> {
 
 		let mut total_locked_now: BalanceOf<T> = Zero::zero();
 		let filtered_schedules = action
-			.pick_schedules::<T>(schedules)
+			.pick_schedules(schedules, 0)
 			.filter(|schedule| {
 				let locked_now = schedule.locked_at::<T::BlockNumberToBalance>(now);
 				let keep = !locked_now.is_zero();
@@ -617,8 +999,35 @@ impl<T: Config> Pallet<T> {
 		(filtered_schedules, total_locked_now)
 	}
 
-	/// Write an accounts updated vesting lock to storage.
+	/// Like `report_schedule_updates`, but for vesting grants. `offset` is the number of linear
+	/// schedules of the account, which precede its grants when indexing.
+	fn report_grant_updates(
+		grants: Vec<VestingGrantOf<T>>,
+		offset: usize,
+		action: VestingAction,
+	) -> (Vec<VestingGrantOf<T>>, BalanceOf<T>) {
+		let now = T::BlockNumberProvider::current_block_number();
+
+		let mut total_locked_now: BalanceOf<T> = Zero::zero();
+		let filtered_grants = action
+			.pick_schedules(grants, offset)
+			.filter(|grant| {
+				let locked_now = grant.schedule.locked_at::<T::BlockNumberToBalance>(now);
+				let keep = !locked_now.is_zero();
+				if keep {
+					total_locked_now = total_locked_now.saturating_add(locked_now);
+				}
+				keep
+			})
+			.collect::<Vec<_>>();
+
+		(filtered_grants, total_locked_now)
+	}
+
+	/// Write an accounts updated vesting lock to storage. Pending claw-backs of the account stay
+	/// locked on top of `total_locked_now`.
 	fn write_lock(who: &T::AccountId, total_locked_now: BalanceOf<T>) {
+		let total_locked_now = total_locked_now.saturating_add(Self::pending_clawback(who));
 		if total_locked_now.is_zero() {
 			T::Currency::remove_lock(VESTING_ID, who);
 			Self::deposit_event(Event::<T>::VestingCompleted { account: who.clone() });
@@ -651,61 +1060,95 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Write an accounts updated vesting grants to storage.
+	fn write_grants(
+		who: &T::AccountId,
+		grants: Vec<VestingGrantOf<T>>,
+	) -> Result<(), DispatchError> {
+		let grants: BoundedVec<VestingGrantOf<T>, MaxVestingSchedulesGet<T>> =
+			grants.try_into().map_err(|_| Error::<T>::AtMaxVestingSchedules)?;
+
+		if grants.is_empty() {
+			VestingGrants::<T>::remove(who);
+		} else {
+			VestingGrants::<T>::insert(who, grants)
+		}
+
+		Ok(())
+	}
+
 	/// Unlock any vested funds of `who`.
 	fn do_vest(who: T::AccountId) -> DispatchResult {
-		let schedules = Vesting::<T>::get(&who).ok_or(Error::<T>::NotVesting)?;
+		let (schedules, grants) = Self::schedules_of(&who)?;
 
-		let (schedules, locked_now) =
-			Self::exec_action(schedules.to_vec(), VestingAction::Passive)?;
+		let (schedules, grants, locked_now) =
+			Self::exec_action(schedules, grants, VestingAction::Passive)?;
 
 		Self::write_vesting(&who, schedules)?;
+		Self::write_grants(&who, grants)?;
 		Self::write_lock(&who, locked_now);
 
 		Ok(())
 	}
 
-	/// Execute a `VestingAction` against the given `schedules`. Returns the updated schedules
-	/// and locked amount.
+	/// Execute a `VestingAction` against the given `schedules` and `grants`, indexed with the
+	/// schedules first. Returns the updated schedules, grants and locked amount.
 	fn exec_action(
 		schedules: Vec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>>,
+		grants: Vec<VestingGrantOf<T>>,
 		action: VestingAction,
-	) -> Result<(Vec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>>, BalanceOf<T>), DispatchError> {
-		let (schedules, locked_now) = match action {
+	) -> Result<
+		(Vec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>>, Vec<VestingGrantOf<T>>, BalanceOf<T>),
+		DispatchError,
+	> {
+		let offset = schedules.len();
+		let (schedules, grants, locked_now) = match action {
 			VestingAction::Merge { index1: idx1, index2: idx2 } => {
 				// The schedule index is based off of the schedule ordering prior to filtering out
 				// any schedules that may be ending at this block.
-				let schedule1 = *schedules.get(idx1).ok_or(Error::<T>::ScheduleIndexOutOfBounds)?;
-				let schedule2 = *schedules.get(idx2).ok_or(Error::<T>::ScheduleIndexOutOfBounds)?;
-
-				// The length of `schedules` decreases by 2 here since we filter out 2 schedules.
-				// Thus we know below that we can push the new merged schedule without error
-				// (assuming initial state was valid).
-				let (mut schedules, mut locked_now) =
-					Self::report_schedule_updates(schedules.to_vec(), action);
+				let schedule1 = Self::mergeable_schedule(&schedules, &grants, idx1)?;
+				let schedule2 = Self::mergeable_schedule(&schedules, &grants, idx2)?;
+
+				// The length of `schedules` and `grants` decreases by 2 here since we filter out
+				// 2 schedules. Thus we know below that we can push the new merged schedule without
+				// error (assuming initial state was valid).
+				let (mut schedules, schedules_locked) =
+					Self::report_schedule_updates(schedules, action);
+				let (mut grants, grants_locked) =
+					Self::report_grant_updates(grants, offset, action);
+				let mut locked_now = schedules_locked.saturating_add(grants_locked);
 
 				let now = T::BlockNumberProvider::current_block_number();
-				if let Some(new_schedule) = Self::merge_vesting_info(now, schedule1, schedule2) {
+				if let Some(new_schedule) = Self::merge_vesting_info(now, schedule1, schedule2)? {
 					// Merging created a new schedule so we:
-					// 1) need to add it to the accounts vesting schedule collection,
-					schedules.push(new_schedule);
 					// (we use `locked_at` in case this is a schedule that started in the past)
 					let new_schedule_locked =
 						new_schedule.locked_at::<T::BlockNumberToBalance>(now);
+					// 1) need to add it to the accounts vesting schedule collection,
+					match new_schedule {
+						VestingCurve::Linear(schedule) => schedules.push(schedule),
+						schedule => grants.push(VestingGrant { schedule, granter: None }),
+					}
 					// and 2) update the locked amount to reflect the schedule we just added.
 					locked_now = locked_now.saturating_add(new_schedule_locked);
 				} // In the None case there was no new schedule to account for.
 
-				(schedules, locked_now)
+				(schedules, grants, locked_now)
+			},
+			_ => {
+				let (schedules, schedules_locked) =
+					Self::report_schedule_updates(schedules, action);
+				let (grants, grants_locked) = Self::report_grant_updates(grants, offset, action);
+				(schedules, grants, schedules_locked.saturating_add(grants_locked))
 			},
-			_ => Self::report_schedule_updates(schedules.to_vec(), action),
 		};
 
 		debug_assert!(
-			locked_now > Zero::zero() && schedules.len() > 0 ||
-				locked_now == Zero::zero() && schedules.len() == 0
+			locked_now > Zero::zero() && schedules.len() + grants.len() > 0 ||
+				locked_now == Zero::zero() && schedules.len() + grants.len() == 0
 		);
 
-		Ok((schedules, locked_now))
+		Ok((schedules, grants, locked_now))
 	}
 }
 
@@ -717,22 +1160,24 @@ where
 	type Moment = BlockNumberFor<T>;
 
 	/// Get the amount that is currently being vested and cannot be transferred out of this account.
+	///
+	/// This includes the funds locked by vesting grants.
 	fn vesting_balance(who: &T::AccountId) -> Option<BalanceOf<T>> {
-		if let Some(v) = Vesting::<T>::get(who) {
-			let now = T::BlockNumberProvider::current_block_number();
-			let total_locked_now = v.iter().fold(Zero::zero(), |total, schedule| {
-				schedule.locked_at::<T::BlockNumberToBalance>(now).saturating_add(total)
-			});
-			Some(T::Currency::free_balance(who).min(total_locked_now))
-		} else {
-			None
-		}
+		let (schedules, grants) = Self::schedules_of(who).ok()?;
+		let now = T::BlockNumberProvider::current_block_number();
+		let schedules_locked_now = schedules.iter().fold(Zero::zero(), |total, schedule| {
+			schedule.locked_at::<T::BlockNumberToBalance>(now).saturating_add(total)
+		});
+		let total_locked_now = grants.iter().fold(schedules_locked_now, |total, grant| {
+			grant.schedule.locked_at::<T::BlockNumberToBalance>(now).saturating_add(total)
+		});
+		Some(T::Currency::free_balance(who).min(total_locked_now))
 	}
 
 	/// Adds a vesting schedule to a given account.
 	///
-	/// If the account has `MaxVestingSchedules`, an Error is returned and nothing
-	/// is updated.
+	/// If the account has `MaxVestingSchedules`, counting its vesting grants, an Error is
+	/// returned and nothing is updated.
 	///
 	/// On success, a linearly reducing amount of funds will be locked. In order to realise any
 	/// reduction of the lock over time as it diminishes, the account owner must use `vest` or
@@ -758,10 +1203,15 @@ where
 		};
 
 		let mut schedules = Vesting::<T>::get(who).unwrap_or_default();
+		let grants = VestingGrants::<T>::get(who).unwrap_or_default();
 
 		// NOTE: we must push the new schedule so that `exec_action`
 		// will give the correct new locked amount.
-		ensure!(schedules.try_push(vesting_schedule).is_ok(), Error::<T>::AtMaxVestingSchedules);
+		ensure!(
+			schedules.len() + grants.len() < T::MAX_VESTING_SCHEDULES as usize &&
+				schedules.try_push(vesting_schedule).is_ok(),
+			Error::<T>::AtMaxVestingSchedules
+		);
 
 		debug_assert!(schedules.len() > 0, "schedules cannot be empty after insertion");
 		let schedule_index = schedules.len() - 1;
@@ -770,10 +1220,11 @@ where
 			schedule_index: schedule_index as u32,
 		});
 
-		let (schedules, locked_now) =
-			Self::exec_action(schedules.to_vec(), VestingAction::Passive)?;
+		let (schedules, grants, locked_now) =
+			Self::exec_action(schedules.to_vec(), grants.to_vec(), VestingAction::Passive)?;
 
 		Self::write_vesting(who, schedules)?;
+		Self::write_grants(who, grants)?;
 		Self::write_lock(who, locked_now);
 
 		Ok(())
@@ -793,7 +1244,7 @@ where
 		}
 
 		ensure!(
-			(Vesting::<T>::decode_len(who).unwrap_or_default() as u32) < T::MAX_VESTING_SCHEDULES,
+			Self::schedules_count(who) < T::MAX_VESTING_SCHEDULES,
 			Error::<T>::AtMaxVestingSchedules
 		);
 
@@ -801,13 +1252,16 @@ where
 	}
 
 	/// Remove a vesting schedule for a given account.
+	///
+	/// Schedules are indexed with the linear schedules first, followed by the vesting grants.
 	fn remove_vesting_schedule(who: &T::AccountId, schedule_index: u32) -> DispatchResult {
-		let schedules = Vesting::<T>::get(who).ok_or(Error::<T>::NotVesting)?;
+		let (schedules, grants) = Self::schedules_of(who)?;
 		let remove_action = VestingAction::Remove { index: schedule_index as usize };
 
-		let (schedules, locked_now) = Self::exec_action(schedules.to_vec(), remove_action)?;
+		let (schedules, grants, locked_now) = Self::exec_action(schedules, grants, remove_action)?;
 
 		Self::write_vesting(who, schedules)?;
+		Self::write_grants(who, grants)?;
 		Self::write_lock(who, locked_now);
 		Ok(())
 	}
@@ -15,7 +15,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use frame_support::{derive_impl, parameter_types, traits::WithdrawReasons};
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{ConstU32, WithdrawReasons},
+};
 use sp_runtime::{traits::Identity, BuildStorage};
 
 use super::*;
@@ -53,6 +56,8 @@ impl Config for Test {
 	type BlockNumberToBalance = Identity;
 	type Currency = Balances;
 	type RuntimeEvent = RuntimeEvent;
+	type RevokeOrigin = frame_system::EnsureRoot<u64>;
+	type MaxCurvePoints = ConstU32<4>;
 	const MAX_VESTING_SCHEDULES: u32 = 3;
 	type MinVestedTransfer = MinVestedTransfer;
 	type WeightInfo = ();
@@ -16,7 +16,7 @@
 // limitations under the License.
 
 use codec::EncodeLike;
-use frame_support::{assert_noop, assert_ok, assert_storage_noop};
+use frame_support::{assert_noop, assert_ok, assert_storage_noop, bounded_vec, traits::ConstU32};
 use frame_system::RawOrigin;
 use sp_runtime::{
 	traits::{BadOrigin, Identity},
@@ -1088,7 +1088,10 @@ fn merge_vesting_handles_per_block_0() {
 		assert_eq!(sched1.ending_block_as_balance::<Identity>(), 512u64 + 10);
 
 		let merged = VestingInfo::new(764, 1, 10);
-		assert_eq!(Vesting::merge_vesting_info(5, sched0, sched1), Some(merged));
+		assert_eq!(
+			Vesting::merge_vesting_info(5, sched0.into(), sched1.into()),
+			Some(merged.into())
+		);
 	});
 }
 
@@ -1262,3 +1265,350 @@ fn vested_transfer_impl_works() {
 		);
 	});
 }
+
+#[test]
+fn vesting_curve_locked_at_works() {
+	type Curve = VestingCurve<u64, u64, ConstU32<4>>;
+
+	// Nothing unlocks before the cliff, then everything accrued since the start unlocks at once.
+	let cliff = Curve::CliffLinear { schedule: VestingInfo::new(1000, 10, 10), cliff: 30 };
+	assert!(cliff.is_valid());
+	assert_eq!(cliff.locked_at::<Identity>(29), 1000);
+	assert_eq!(cliff.locked_at::<Identity>(30), 800);
+	assert_eq!(cliff.locked_at::<Identity>(50), 600);
+	assert_eq!(cliff.ending_block_as_balance::<Identity>(), 110);
+
+	// Equal instalments every period, the last one including the remainder.
+	let stepped = Curve::Stepped { locked: 1000, starting_block: 10, period: 5, steps: 3 };
+	assert!(stepped.is_valid());
+	assert_eq!(stepped.locked_at::<Identity>(14), 1000);
+	assert_eq!(stepped.locked_at::<Identity>(15), 667);
+	assert_eq!(stepped.locked_at::<Identity>(24), 334);
+	assert_eq!(stepped.locked_at::<Identity>(25), 0);
+	assert_eq!(stepped.ending_block_as_balance::<Identity>(), 25);
+
+	// Linear interpolation between the points of the curve.
+	let piecewise = Curve::Piecewise {
+		locked: 1000,
+		starting_block: 10,
+		points: bounded_vec![(20, 0), (30, 500), (50, 1000)],
+	};
+	assert!(piecewise.is_valid());
+	assert_eq!(piecewise.locked_at::<Identity>(5), 1000);
+	assert_eq!(piecewise.locked_at::<Identity>(20), 1000);
+	assert_eq!(piecewise.locked_at::<Identity>(25), 750);
+	assert_eq!(piecewise.locked_at::<Identity>(40), 250);
+	assert_eq!(piecewise.locked_at::<Identity>(50), 0);
+	assert_eq!(piecewise.ending_block_as_balance::<Identity>(), 50);
+
+	// The cliff must come after the start.
+	let cliff = Curve::CliffLinear { schedule: VestingInfo::new(1000, 10, 10), cliff: 10 };
+	assert!(!cliff.is_valid());
+	// Steps need a non-zero period and count.
+	assert!(!Curve::Stepped { locked: 1000, starting_block: 10, period: 0, steps: 3 }.is_valid());
+	assert!(!Curve::Stepped { locked: 1000, starting_block: 10, period: 5, steps: 0 }.is_valid());
+	// Every step must unlock something.
+	assert!(!Curve::Stepped { locked: 2, starting_block: 10, period: 5, steps: 3 }.is_valid());
+	assert!(Curve::Stepped { locked: 3, starting_block: 10, period: 5, steps: 3 }.is_valid());
+	// Points must move forward, never lock funds again and end at the locked amount.
+	let points = [
+		bounded_vec![(10, 500), (30, 1000)],
+		bounded_vec![(30, 500), (30, 1000)],
+		bounded_vec![(20, 500), (30, 400), (40, 1000)],
+		bounded_vec![(20, 500)],
+		bounded_vec![],
+	];
+	for points in points {
+		assert!(!Curve::Piecewise { locked: 1000, starting_block: 10, points }.is_valid());
+	}
+}
+
+#[test]
+fn grant_vesting_works() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let schedule =
+			VestingCurve::Stepped { locked: ED * 4, starting_block: 10, period: 10, steps: 4 };
+		assert_ok!(Vesting::grant_vesting(Some(3).into(), 4, schedule.clone(), false));
+		assert_eq!(
+			vesting_events_since_last_call(),
+			vec![
+				Event::VestingCreated { account: 4, schedule_index: 0 },
+				Event::VestingUpdated { account: 4, unvested: ED * 4 },
+			]
+		);
+
+		assert_eq!(Balances::free_balance(&4), ED * 44);
+		assert_eq!(VestingStorage::<Test>::get(&4), None);
+		assert_eq!(
+			VestingGrants::<Test>::get(&4).unwrap(),
+			vec![VestingGrant { schedule, granter: None }]
+		);
+		assert_eq!(Vesting::vesting_balance(&4), Some(ED * 4));
+
+		// Nothing unlocks before the first step.
+		System::set_block_number(19);
+		assert_ok!(Vesting::vest(Some(4).into()));
+		assert_eq!(Vesting::vesting_balance(&4), Some(ED * 4));
+
+		// Each step unlocks a quarter of the grant.
+		System::set_block_number(30);
+		assert_ok!(Vesting::vest(Some(4).into()));
+		assert_eq!(Vesting::vesting_balance(&4), Some(ED * 2));
+
+		// Once fully vested the grant is removed.
+		System::set_block_number(50);
+		assert_ok!(Vesting::vest(Some(4).into()));
+		System::assert_last_event(Event::VestingCompleted { account: 4 }.into());
+		assert!(!VestingGrants::<Test>::contains_key(4));
+		assert_eq!(Vesting::vesting_balance(&4), None);
+
+		// Curves must be valid and lock at least `MinVestedTransfer`.
+		let invalid =
+			VestingCurve::Stepped { locked: ED * 4, starting_block: 10, period: 0, steps: 4 };
+		assert_noop!(
+			Vesting::grant_vesting(Some(3).into(), 4, invalid, false),
+			Error::<Test>::InvalidScheduleParams
+		);
+		let too_small =
+			VestingCurve::Stepped { locked: ED, starting_block: 10, period: 10, steps: 4 };
+		assert_noop!(
+			Vesting::grant_vesting(Some(3).into(), 4, too_small, false),
+			Error::<Test>::AmountLow
+		);
+	});
+}
+
+#[test]
+fn revoke_vesting_works() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let cliff =
+			VestingCurve::CliffLinear { schedule: VestingInfo::new(ED * 10, ED, 1), cliff: 6 };
+		assert_ok!(Vesting::grant_vesting(Some(3).into(), 4, cliff.clone(), true));
+		let grant = Box::new(VestingGrant { schedule: cliff.clone(), granter: Some(3) });
+		// Grants are indexed among the grants only, regardless of linear schedules.
+		let linear = VestingInfo::new(ED * 2, ED, 10);
+		assert_ok!(Vesting::vested_transfer(Some(3).into(), 4, linear));
+		assert_eq!(Balances::free_balance(&3), ED * 18);
+		assert_eq!(Balances::free_balance(&4), ED * 52);
+
+		// Only the expected grant can be revoked, by its granter or `RevokeOrigin`.
+		assert_noop!(
+			Vesting::revoke_vesting(Some(3).into(), 4, 1, grant.clone()),
+			Error::<Test>::ScheduleIndexOutOfBounds
+		);
+		assert_noop!(
+			Vesting::revoke_vesting(
+				Some(3).into(),
+				4,
+				0,
+				Box::new(VestingGrant { schedule: cliff, granter: Some(2) })
+			),
+			Error::<Test>::GrantMismatch
+		);
+		assert_noop!(Vesting::revoke_vesting(Some(4).into(), 4, 0, grant.clone()), BadOrigin);
+
+		// Past the cliff, seven blocks worth of funds have vested and stay with the target.
+		System::set_block_number(8);
+		assert_ok!(Vesting::revoke_vesting(Some(3).into(), 4, 0, grant));
+		System::assert_last_event(
+			Event::VestingRevoked { account: 4, granter: 3, returned: ED * 3 }.into(),
+		);
+		assert_eq!(Balances::free_balance(&3), ED * 21);
+		assert_eq!(Balances::free_balance(&4), ED * 49);
+		assert_eq!(VestingGrants::<Test>::get(&4), None);
+		assert_eq!(VestingStorage::<Test>::get(&4).unwrap(), vec![linear]);
+		assert_eq!(Vesting::vesting_balance(&4), Some(ED * 2));
+
+		// `RevokeOrigin` returns everything of a grant which has not started yet.
+		let stepped =
+			VestingCurve::Stepped { locked: ED * 4, starting_block: 10, period: 10, steps: 4 };
+		assert_ok!(Vesting::grant_vesting(Some(3).into(), 4, stepped.clone(), true));
+		let grant = Box::new(VestingGrant { schedule: stepped.clone(), granter: Some(3) });
+		assert_ok!(Vesting::revoke_vesting(RawOrigin::Root.into(), 4, 0, grant));
+		System::assert_last_event(
+			Event::VestingRevoked { account: 4, granter: 3, returned: ED * 4 }.into(),
+		);
+		assert_eq!(Balances::free_balance(&3), ED * 21);
+
+		// Irrevocable grants stay in place.
+		assert_ok!(Vesting::grant_vesting(Some(3).into(), 4, stepped.clone(), false));
+		let grant = Box::new(VestingGrant { schedule: stepped, granter: None });
+		assert_noop!(
+			Vesting::revoke_vesting(RawOrigin::Root.into(), 4, 0, grant),
+			Error::<Test>::NotRevocable
+		);
+	});
+}
+
+#[test]
+fn revoke_vesting_after_grant_indices_shift() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let first =
+			VestingCurve::Stepped { locked: ED * 4, starting_block: 10, period: 10, steps: 4 };
+		let second =
+			VestingCurve::Stepped { locked: ED * 6, starting_block: 10, period: 10, steps: 2 };
+		assert_ok!(Vesting::grant_vesting(Some(3).into(), 4, first.clone(), true));
+		assert_ok!(Vesting::grant_vesting(Some(3).into(), 4, second.clone(), true));
+		let first = Box::new(VestingGrant { schedule: first, granter: Some(3) });
+		let second = Box::new(VestingGrant { schedule: second, granter: Some(3) });
+
+		// Once the first grant is gone, the second one moves to its index.
+		assert_ok!(Vesting::revoke_vesting(Some(3).into(), 4, 0, first.clone()));
+		assert_eq!(VestingGrants::<Test>::get(&4).unwrap(), vec![*second.clone()]);
+
+		// A repeated revocation of the first grant does not hit the second one.
+		assert_noop!(
+			Vesting::revoke_vesting(Some(3).into(), 4, 0, first),
+			Error::<Test>::GrantMismatch
+		);
+
+		// Adding a linear schedule does not shift the grants.
+		assert_ok!(Vesting::vested_transfer(Some(3).into(), 4, VestingInfo::new(ED * 2, ED, 10)));
+		assert_ok!(Vesting::revoke_vesting(Some(3).into(), 4, 0, second));
+		System::assert_last_event(
+			Event::VestingRevoked { account: 4, granter: 3, returned: ED * 6 }.into(),
+		);
+		assert_eq!(VestingGrants::<Test>::get(&4), None);
+	});
+}
+
+#[test]
+fn force_remove_vesting_schedule_rejects_revocable_grants() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let stepped =
+			VestingCurve::Stepped { locked: ED * 4, starting_block: 10, period: 10, steps: 4 };
+		assert_ok!(Vesting::grant_vesting(Some(3).into(), 4, stepped.clone(), true));
+		assert_ok!(Vesting::grant_vesting(Some(3).into(), 4, stepped.clone(), false));
+
+		// The unvested funds of a revocable grant must go back to its granter.
+		assert_noop!(
+			Vesting::force_remove_vesting_schedule(RawOrigin::Root.into(), 4, 0),
+			Error::<Test>::CannotRemoveRevocable
+		);
+
+		// Irrevocable grants can still be removed.
+		assert_ok!(Vesting::force_remove_vesting_schedule(RawOrigin::Root.into(), 4, 1));
+		assert_eq!(
+			VestingGrants::<Test>::get(&4).unwrap(),
+			vec![VestingGrant { schedule: stepped, granter: Some(3) }]
+		);
+	});
+}
+
+#[test]
+fn revoke_vesting_with_overlapping_lock_defers_claw_back() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let stepped =
+			VestingCurve::Stepped { locked: ED * 4, starting_block: 10, period: 10, steps: 4 };
+		assert_ok!(Vesting::grant_vesting(Some(3).into(), 4, stepped.clone(), true));
+		let grant = Box::new(VestingGrant { schedule: stepped, granter: Some(3) });
+		// Another lock covers all funds of the target, including the unvested ones.
+		Balances::set_lock(*b"staking ", &4, ED * 44, WithdrawReasons::all());
+		let _ = vesting_events_since_last_call();
+
+		// Revoking succeeds, but the unvested funds stay locked until they can be returned.
+		assert_ok!(Vesting::revoke_vesting(Some(3).into(), 4, 0, grant));
+		assert_eq!(
+			vesting_events_since_last_call(),
+			vec![
+				Event::VestingCompleted { account: 4 },
+				Event::VestingUpdated { account: 4, unvested: ED * 4 },
+				Event::ClawbackPending { account: 4, granter: 3, amount: ED * 4 },
+				Event::VestingRevoked { account: 4, granter: 3, returned: 0 },
+			]
+		);
+		assert_eq!(VestingGrants::<Test>::get(&4), None);
+		assert_eq!(PendingClawbacks::<Test>::get(&4).unwrap(), vec![(3, ED * 4)]);
+		assert_eq!(Balances::free_balance(&3), ED * 26);
+		assert_eq!(Balances::free_balance(&4), ED * 44);
+
+		// Claw-backs fail as long as the other lock is in place.
+		assert_noop!(Vesting::claw_back(Some(1).into(), 4), Error::<Test>::ClawbackFailed);
+
+		// Anyone can return the funds once the other lock is gone.
+		Balances::remove_lock(*b"staking ", &4);
+		assert_ok!(Vesting::claw_back(Some(1).into(), 4));
+		System::assert_last_event(
+			Event::ClawedBack { account: 4, granter: 3, amount: ED * 4 }.into(),
+		);
+		assert_eq!(PendingClawbacks::<Test>::get(&4), None);
+		assert_eq!(Balances::free_balance(&3), ED * 30);
+		assert_eq!(Balances::free_balance(&4), ED * 40);
+		assert!(pallet_balances::Locks::<Test>::get(&4).is_empty());
+		assert_noop!(Vesting::claw_back(Some(1).into(), 4), Error::<Test>::NoPendingClawback);
+	});
+}
+
+#[test]
+fn merge_schedules_with_grants() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let sched0 = VestingInfo::new(ED * 20, ED, 10);
+		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![sched0]);
+
+		let stepped =
+			VestingCurve::Stepped { locked: ED * 4, starting_block: 20, period: 10, steps: 2 };
+		assert_ok!(Vesting::grant_vesting(Some(3).into(), 2, stepped, false));
+		let revocable = VestingCurve::Linear(VestingInfo::new(ED * 2, ED, 10));
+		assert_ok!(Vesting::grant_vesting(Some(3).into(), 2, revocable.clone(), true));
+
+		// Grants count towards `MaxVestingSchedules`.
+		assert_noop!(
+			Vesting::vested_transfer(Some(3).into(), 2, VestingInfo::new(ED * 2, ED, 10)),
+			Error::<Test>::AtMaxVestingSchedules
+		);
+		assert_noop!(
+			Vesting::grant_vesting(Some(3).into(), 2, revocable, false),
+			Error::<Test>::AtMaxVestingSchedules
+		);
+
+		// Revocable grants can not be merged.
+		assert_noop!(
+			Vesting::merge_schedules(Some(2).into(), 0, 2),
+			Error::<Test>::CannotMergeRevocable
+		);
+
+		// Curves other than linear can not be merged while both schedules are vesting.
+		assert_noop!(
+			Vesting::merge_schedules(Some(2).into(), 0, 1),
+			Error::<Test>::CannotMergeNonLinear
+		);
+
+		// Once the linear schedule has ended, the grant is kept unmodified.
+		System::set_block_number(30);
+		assert_ok!(Vesting::merge_schedules(Some(2).into(), 0, 1));
+		assert_eq!(VestingStorage::<Test>::get(&2), None);
+		assert_eq!(
+			VestingGrants::<Test>::get(&2).unwrap(),
+			vec![VestingGrant { schedule: stepped, granter: None }]
+		);
+		assert_eq!(Vesting::vesting_balance(&2), Some(ED * 2));
+	});
+}
+
+#[test]
+fn merge_schedules_rejects_non_linear_curves() {
+	let curves = [
+		VestingCurve::Stepped { locked: ED * 4, starting_block: 20, period: 10, steps: 2 },
+		VestingCurve::Piecewise {
+			locked: ED * 4,
+			starting_block: 0,
+			points: bounded_vec![(10, ED * 3), (40, ED * 4)],
+		},
+		VestingCurve::CliffLinear { schedule: VestingInfo::new(ED * 4, ED, 0), cliff: 5 },
+	];
+	for curve in curves {
+		ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+			assert_ok!(Vesting::grant_vesting(Some(3).into(), 2, curve.clone(), false));
+			// A linear schedule would unlock the curve's funds at a different pace.
+			assert_noop!(
+				Vesting::merge_schedules(Some(2).into(), 0, 1),
+				Error::<Test>::CannotMergeNonLinear
+			);
+			assert_eq!(VestingStorage::<Test>::get(&2).unwrap().len(), 1);
+			assert_eq!(
+				VestingGrants::<Test>::get(&2).unwrap(),
+				vec![VestingGrant { schedule: curve, granter: None }]
+			);
+		});
+	}
+}
@@ -0,0 +1,205 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Vesting curves other than the plain linear unlock of [`VestingInfo`].
+
+use super::*;
+use frame_support::{CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound};
+use sp_runtime::Rounding;
+
+/// The shape by which a vesting grant unlocks its funds over time.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	RuntimeDebugNoBound,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+#[codec(mel_bound(Balance: MaxEncodedLen, BlockNumber: MaxEncodedLen))]
+#[scale_info(skip_type_params(MaxPoints))]
+pub enum VestingCurve<
+	Balance: Copy + Debug + Eq,
+	BlockNumber: Copy + Debug + Eq,
+	MaxPoints: Get<u32>,
+> {
+	/// Unlock `per_block` every block after `starting_block`.
+	Linear(VestingInfo<Balance, BlockNumber>),
+	/// Like `Linear`, but nothing unlocks before `cliff`. At `cliff` everything that accrued
+	/// since the schedule's `starting_block` unlocks at once, after which it continues linearly.
+	CliffLinear { schedule: VestingInfo<Balance, BlockNumber>, cliff: BlockNumber },
+	/// Unlock `locked` in `steps` equal instalments, one every `period` blocks after
+	/// `starting_block`. Any remainder of the division unlocks with the last instalment. `locked`
+	/// must be at least `steps`, so that every instalment unlocks something.
+	Stepped { locked: Balance, starting_block: BlockNumber, period: BlockNumber, steps: u32 },
+	/// Unlock along a piecewise linear curve starting at `(starting_block, 0)` and passing
+	/// through each `(block, unlocked)` point, where `unlocked` is the total amount unlocked by
+	/// `block`. The last point must unlock `locked`.
+	Piecewise {
+		locked: Balance,
+		starting_block: BlockNumber,
+		points: BoundedVec<(BlockNumber, Balance), MaxPoints>,
+	},
+}
+
+impl<Balance, BlockNumber, MaxPoints> From<VestingInfo<Balance, BlockNumber>>
+	for VestingCurve<Balance, BlockNumber, MaxPoints>
+where
+	Balance: Copy + Debug + Eq,
+	BlockNumber: Copy + Debug + Eq,
+	MaxPoints: Get<u32>,
+{
+	fn from(schedule: VestingInfo<Balance, BlockNumber>) -> Self {
+		Self::Linear(schedule)
+	}
+}
+
+impl<Balance, BlockNumber, MaxPoints> VestingCurve<Balance, BlockNumber, MaxPoints>
+where
+	Balance: AtLeast32BitUnsigned + Copy + Debug,
+	BlockNumber: AtLeast32BitUnsigned + Copy + Bounded + Debug,
+	MaxPoints: Get<u32>,
+{
+	/// Validate the parameters of the curve. Note that this does not check against
+	/// `MinVestedTransfer`.
+	pub fn is_valid(&self) -> bool {
+		match self {
+			Self::Linear(schedule) => schedule.is_valid(),
+			Self::CliffLinear { schedule, cliff } =>
+				schedule.is_valid() && *cliff > schedule.starting_block(),
+			// Every instalment must unlock something.
+			Self::Stepped { locked, period, steps, .. } =>
+				!period.is_zero() && *steps > 0 && *locked >= Balance::from(*steps),
+			Self::Piecewise { locked, starting_block, points } => {
+				let mut previous = (*starting_block, Zero::zero());
+				for &(block, unlocked) in points.iter() {
+					if block <= previous.0 || unlocked < previous.1 {
+						return false
+					}
+					previous = (block, unlocked);
+				}
+				!locked.is_zero() && !points.is_empty() && previous.1 == *locked
+			},
+		}
+	}
+
+	/// Locked amount at creation of the curve.
+	pub fn locked(&self) -> Balance {
+		match self {
+			Self::Linear(schedule) | Self::CliffLinear { schedule, .. } => schedule.locked(),
+			Self::Stepped { locked, .. } | Self::Piecewise { locked, .. } => *locked,
+		}
+	}
+
+	/// Amount locked at block `n`.
+	pub fn locked_at<BlockNumberToBalance: Convert<BlockNumber, Balance>>(
+		&self,
+		n: BlockNumber,
+	) -> Balance {
+		match self {
+			Self::Linear(schedule) => schedule.locked_at::<BlockNumberToBalance>(n),
+			Self::CliffLinear { schedule, cliff } =>
+				if n < *cliff {
+					schedule.locked()
+				} else {
+					schedule.locked_at::<BlockNumberToBalance>(n)
+				},
+			Self::Stepped { locked, starting_block, period, steps } => {
+				let steps_done = n.saturating_sub(*starting_block) / (*period).max(One::one());
+				if steps_done >= BlockNumber::from(*steps) {
+					return Zero::zero()
+				}
+				let per_step = *locked / Balance::from(*steps);
+				locked.saturating_sub(
+					per_step.saturating_mul(BlockNumberToBalance::convert(steps_done)),
+				)
+			},
+			Self::Piecewise { locked, starting_block, points } => {
+				let mut previous = (*starting_block, Zero::zero());
+				for &(block, unlocked) in points.iter() {
+					if n < block {
+						if n <= previous.0 {
+							break
+						}
+						// Interpolate between the previous point and this one.
+						let elapsed = BlockNumberToBalance::convert(n - previous.0);
+						let span = BlockNumberToBalance::convert(block - previous.0);
+						let accrued = unlocked
+							.saturating_sub(previous.1)
+							.multiply_rational(elapsed, span, Rounding::Down)
+							.unwrap_or_else(Zero::zero);
+						return locked.saturating_sub(previous.1.saturating_add(accrued))
+					}
+					previous = (block, unlocked);
+				}
+				locked.saturating_sub(previous.1)
+			},
+		}
+	}
+
+	/// Block number at which the curve ends (as type `Balance`).
+	pub fn ending_block_as_balance<BlockNumberToBalance: Convert<BlockNumber, Balance>>(
+		&self,
+	) -> Balance {
+		match self {
+			Self::Linear(schedule) => schedule.ending_block_as_balance::<BlockNumberToBalance>(),
+			Self::CliffLinear { schedule, cliff } => schedule
+				.ending_block_as_balance::<BlockNumberToBalance>()
+				.max(BlockNumberToBalance::convert(*cliff)),
+			Self::Stepped { starting_block, period, steps, .. } =>
+				BlockNumberToBalance::convert(*starting_block).saturating_add(
+					BlockNumberToBalance::convert(*period).saturating_mul(Balance::from(*steps)),
+				),
+			Self::Piecewise { starting_block, points, .. } => BlockNumberToBalance::convert(
+				points.last().map_or(*starting_block, |(block, _)| *block),
+			),
+		}
+	}
+}
+
+/// A vesting curve along with the account that funded it, if the grant may be revoked.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	RuntimeDebugNoBound,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+#[codec(mel_bound(
+	Balance: MaxEncodedLen,
+	BlockNumber: MaxEncodedLen,
+	AccountId: MaxEncodedLen
+))]
+#[scale_info(skip_type_params(MaxPoints))]
+pub struct VestingGrant<
+	Balance: Copy + Debug + Eq,
+	BlockNumber: Copy + Debug + Eq,
+	AccountId: Clone + Debug + Eq,
+	MaxPoints: Get<u32>,
+> {
+	/// The curve by which the granted funds unlock.
+	pub schedule: VestingCurve<Balance, BlockNumber, MaxPoints>,
+	/// The account that funded the grant and may revoke it. `None` if the grant is irrevocable.
+	pub granter: Option<AccountId>,
+}
@@ -81,6 +81,9 @@ pub trait WeightInfo {
 	fn not_unlocking_merge_schedules(l: u32, s: u32, ) -> Weight;
 	fn unlocking_merge_schedules(l: u32, s: u32, ) -> Weight;
 	fn force_remove_vesting_schedule(l: u32, s: u32, ) -> Weight;
+	fn grant_vesting(l: u32, s: u32, ) -> Weight;
+	fn revoke_vesting(l: u32, s: u32, ) -> Weight;
+	fn claw_back(l: u32, s: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_vesting` using the Substrate node and recommended hardware.
@@ -90,6 +93,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::PendingClawbacks` (r:1 w:0)
+	/// Proof: `Vesting::PendingClawbacks` (`max_values`: None, `max_size`: Some(1393), added: 3868, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[0, 49]`.
@@ -104,13 +109,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(30_514, 0).saturating_mul(l.into()))
 			// Standard Error: 1_698
 			.saturating_add(Weight::from_parts(62_299, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
 	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::PendingClawbacks` (r:1 w:0)
+	/// Proof: `Vesting::PendingClawbacks` (`max_values`: None, `max_size`: Some(1393), added: 3868, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[0, 49]`.
@@ -125,13 +132,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(28_285, 0).saturating_mul(l.into()))
 			// Standard Error: 2_636
 			.saturating_add(Weight::from_parts(66_703, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
 	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::PendingClawbacks` (r:1 w:0)
+	/// Proof: `Vesting::PendingClawbacks` (`max_values`: None, `max_size`: Some(1393), added: 3868, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
@@ -148,13 +157,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(29_734, 0).saturating_mul(l.into()))
 			// Standard Error: 2_133
 			.saturating_add(Weight::from_parts(65_658, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
 	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::PendingClawbacks` (r:1 w:0)
+	/// Proof: `Vesting::PendingClawbacks` (`max_values`: None, `max_size`: Some(1393), added: 3868, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
@@ -171,7 +182,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(34_308, 0).saturating_mul(l.into()))
 			// Standard Error: 2_216
 			.saturating_add(Weight::from_parts(58_989, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
@@ -180,6 +191,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::PendingClawbacks` (r:1 w:0)
+	/// Proof: `Vesting::PendingClawbacks` (`max_values`: None, `max_size`: Some(1393), added: 3868, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[0, 49]`.
@@ -194,7 +207,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(48_357, 0).saturating_mul(l.into()))
 			// Standard Error: 4_979
 			.saturating_add(Weight::from_parts(102_560, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
@@ -203,6 +216,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::PendingClawbacks` (r:1 w:0)
+	/// Proof: `Vesting::PendingClawbacks` (`max_values`: None, `max_size`: Some(1393), added: 3868, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[0, 49]`.
@@ -217,13 +232,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(49_783, 0).saturating_mul(l.into()))
 			// Standard Error: 4_489
 			.saturating_add(Weight::from_parts(114_991, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
 	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::PendingClawbacks` (r:1 w:0)
+	/// Proof: `Vesting::PendingClawbacks` (`max_values`: None, `max_size`: Some(1393), added: 3868, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[0, 49]`.
@@ -238,13 +255,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(30_158, 0).saturating_mul(l.into()))
 			// Standard Error: 2_174
 			.saturating_add(Weight::from_parts(65_109, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
 	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::PendingClawbacks` (r:1 w:0)
+	/// Proof: `Vesting::PendingClawbacks` (`max_values`: None, `max_size`: Some(1393), added: 3868, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[0, 49]`.
@@ -259,13 +278,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(35_827, 0).saturating_mul(l.into()))
 			// Standard Error: 2_097
 			.saturating_add(Weight::from_parts(79_394, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
 	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::PendingClawbacks` (r:1 w:0)
+	/// Proof: `Vesting::PendingClawbacks` (`max_values`: None, `max_size`: Some(1393), added: 3868, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
@@ -282,9 +303,90 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(40_786, 0).saturating_mul(l.into()))
 			// Standard Error: 3_385
 			.saturating_add(Weight::from_parts(81_931, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	/// Storage: `Vesting::Vesting` (r:1 w:1)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingGrants` (r:1 w:1)
+	/// Proof: `Vesting::VestingGrants` (`max_values`: None, `max_size`: Some(8310), added: 10785, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::PendingClawbacks` (r:1 w:0)
+	/// Proof: `Vesting::PendingClawbacks` (`max_values`: None, `max_size`: Some(1393), added: 3868, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[0, 49]`.
+	/// The range of component `s` is `[0, 27]`.
+	fn grant_vesting(l: u32, s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `225 + l * (25 ±0) + s * (36 ±0)`
+		//  Estimated: `11775`
+		// Minimum execution time: 79_815_000 picoseconds.
+		Weight::from_parts(80_417_382, 11775)
+			// Standard Error: 2_815
+			.saturating_add(Weight::from_parts(49_102, 0).saturating_mul(l.into()))
+			// Standard Error: 5_008
+			.saturating_add(Weight::from_parts(104_611, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Vesting::Vesting` (r:1 w:1)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingGrants` (r:1 w:1)
+	/// Proof: `Vesting::VestingGrants` (`max_values`: None, `max_size`: Some(8310), added: 10785, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::PendingClawbacks` (r:1 w:1)
+	/// Proof: `Vesting::PendingClawbacks` (`max_values`: None, `max_size`: Some(1393), added: 3868, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[0, 49]`.
+	/// The range of component `s` is `[1, 28]`.
+	fn revoke_vesting(l: u32, s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `328 + l * (25 ±0) + s * (36 ±0)`
+		//  Estimated: `11775`
+		// Minimum execution time: 84_233_000 picoseconds.
+		Weight::from_parts(85_106_914, 11775)
+			// Standard Error: 2_402
+			.saturating_add(Weight::from_parts(41_977, 0).saturating_mul(l.into()))
+			// Standard Error: 4_412
+			.saturating_add(Weight::from_parts(97_305, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Vesting::Vesting` (r:1 w:1)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingGrants` (r:1 w:1)
+	/// Proof: `Vesting::VestingGrants` (`max_values`: None, `max_size`: Some(8310), added: 10785, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::PendingClawbacks` (r:1 w:1)
+	/// Proof: `Vesting::PendingClawbacks` (`max_values`: None, `max_size`: Some(1393), added: 3868, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[0, 48]`.
+	/// The range of component `s` is `[0, 27]`.
+	fn claw_back(l: u32, s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `380 + l * (25 ±0) + s * (36 ±0)`
+		//  Estimated: `11775`
+		// Minimum execution time: 87_419_000 picoseconds.
+		Weight::from_parts(88_352_061, 11775)
+			// Standard Error: 2_517
+			.saturating_add(Weight::from_parts(43_518, 0).saturating_mul(l.into()))
+			// Standard Error: 4_609
+			.saturating_add(Weight::from_parts(95_207, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -293,6 +395,8 @@ impl WeightInfo for () {
 	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::PendingClawbacks` (r:1 w:0)
+	/// Proof: `Vesting::PendingClawbacks` (`max_values`: None, `max_size`: Some(1393), added: 3868, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[0, 49]`.
@@ -307,13 +411,15 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(30_514, 0).saturating_mul(l.into()))
 			// Standard Error: 1_698
 			.saturating_add(Weight::from_parts(62_299, 0).saturating_mul(s.into()))
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
 	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::PendingClawbacks` (r:1 w:0)
+	/// Proof: `Vesting::PendingClawbacks` (`max_values`: None, `max_size`: Some(1393), added: 3868, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[0, 49]`.
@@ -328,13 +434,15 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(28_285, 0).saturating_mul(l.into()))
 			// Standard Error: 2_636
 			.saturating_add(Weight::from_parts(66_703, 0).saturating_mul(s.into()))
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
 	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::PendingClawbacks` (r:1 w:0)
+	/// Proof: `Vesting::PendingClawbacks` (`max_values`: None, `max_size`: Some(1393), added: 3868, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
@@ -351,13 +459,15 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(29_734, 0).saturating_mul(l.into()))
 			// Standard Error: 2_133
 			.saturating_add(Weight::from_parts(65_658, 0).saturating_mul(s.into()))
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
 	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::PendingClawbacks` (r:1 w:0)
+	/// Proof: `Vesting::PendingClawbacks` (`max_values`: None, `max_size`: Some(1393), added: 3868, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
@@ -374,7 +484,7 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(34_308, 0).saturating_mul(l.into()))
 			// Standard Error: 2_216
 			.saturating_add(Weight::from_parts(58_989, 0).saturating_mul(s.into()))
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
@@ -383,6 +493,8 @@ impl WeightInfo for () {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::PendingClawbacks` (r:1 w:0)
+	/// Proof: `Vesting::PendingClawbacks` (`max_values`: None, `max_size`: Some(1393), added: 3868, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[0, 49]`.
@@ -397,7 +509,7 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(48_357, 0).saturating_mul(l.into()))
 			// Standard Error: 4_979
 			.saturating_add(Weight::from_parts(102_560, 0).saturating_mul(s.into()))
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
@@ -406,6 +518,8 @@ impl WeightInfo for () {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::PendingClawbacks` (r:1 w:0)
+	/// Proof: `Vesting::PendingClawbacks` (`max_values`: None, `max_size`: Some(1393), added: 3868, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[0, 49]`.
@@ -420,13 +534,15 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(49_783, 0).saturating_mul(l.into()))
 			// Standard Error: 4_489
 			.saturating_add(Weight::from_parts(114_991, 0).saturating_mul(s.into()))
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
 	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::PendingClawbacks` (r:1 w:0)
+	/// Proof: `Vesting::PendingClawbacks` (`max_values`: None, `max_size`: Some(1393), added: 3868, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[0, 49]`.
@@ -441,13 +557,15 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(30_158, 0).saturating_mul(l.into()))
 			// Standard Error: 2_174
 			.saturating_add(Weight::from_parts(65_109, 0).saturating_mul(s.into()))
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
 	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::PendingClawbacks` (r:1 w:0)
+	/// Proof: `Vesting::PendingClawbacks` (`max_values`: None, `max_size`: Some(1393), added: 3868, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[0, 49]`.
@@ -462,13 +580,15 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(35_827, 0).saturating_mul(l.into()))
 			// Standard Error: 2_097
 			.saturating_add(Weight::from_parts(79_394, 0).saturating_mul(s.into()))
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
 	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::PendingClawbacks` (r:1 w:0)
+	/// Proof: `Vesting::PendingClawbacks` (`max_values`: None, `max_size`: Some(1393), added: 3868, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
@@ -485,7 +605,88 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(40_786, 0).saturating_mul(l.into()))
 			// Standard Error: 3_385
 			.saturating_add(Weight::from_parts(81_931, 0).saturating_mul(s.into()))
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	/// Storage: `Vesting::Vesting` (r:1 w:1)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingGrants` (r:1 w:1)
+	/// Proof: `Vesting::VestingGrants` (`max_values`: None, `max_size`: Some(8310), added: 10785, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::PendingClawbacks` (r:1 w:0)
+	/// Proof: `Vesting::PendingClawbacks` (`max_values`: None, `max_size`: Some(1393), added: 3868, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[0, 49]`.
+	/// The range of component `s` is `[0, 27]`.
+	fn grant_vesting(l: u32, s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `225 + l * (25 ±0) + s * (36 ±0)`
+		//  Estimated: `11775`
+		// Minimum execution time: 79_815_000 picoseconds.
+		Weight::from_parts(80_417_382, 11775)
+			// Standard Error: 2_815
+			.saturating_add(Weight::from_parts(49_102, 0).saturating_mul(l.into()))
+			// Standard Error: 5_008
+			.saturating_add(Weight::from_parts(104_611, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Vesting::Vesting` (r:1 w:1)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingGrants` (r:1 w:1)
+	/// Proof: `Vesting::VestingGrants` (`max_values`: None, `max_size`: Some(8310), added: 10785, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::PendingClawbacks` (r:1 w:1)
+	/// Proof: `Vesting::PendingClawbacks` (`max_values`: None, `max_size`: Some(1393), added: 3868, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[0, 49]`.
+	/// The range of component `s` is `[1, 28]`.
+	fn revoke_vesting(l: u32, s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `328 + l * (25 ±0) + s * (36 ±0)`
+		//  Estimated: `11775`
+		// Minimum execution time: 84_233_000 picoseconds.
+		Weight::from_parts(85_106_914, 11775)
+			// Standard Error: 2_402
+			.saturating_add(Weight::from_parts(41_977, 0).saturating_mul(l.into()))
+			// Standard Error: 4_412
+			.saturating_add(Weight::from_parts(97_305, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Vesting::Vesting` (r:1 w:1)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingGrants` (r:1 w:1)
+	/// Proof: `Vesting::VestingGrants` (`max_values`: None, `max_size`: Some(8310), added: 10785, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::PendingClawbacks` (r:1 w:1)
+	/// Proof: `Vesting::PendingClawbacks` (`max_values`: None, `max_size`: Some(1393), added: 3868, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[0, 48]`.
+	/// The range of component `s` is `[0, 27]`.
+	fn claw_back(l: u32, s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `380 + l * (25 ±0) + s * (36 ±0)`
+		//  Estimated: `11775`
+		// Minimum execution time: 87_419_000 picoseconds.
+		Weight::from_parts(88_352_061, 11775)
+			// Standard Error: 2_517
+			.saturating_add(Weight::from_parts(43_518, 0).saturating_mul(l.into()))
+			// Standard Error: 4_609
+			.saturating_add(Weight::from_parts(95_207, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
 }
@@ -657,6 +657,17 @@ pub trait DelegationInterface {
 		num_slashing_spans: u32,
 	) -> DispatchResult;
 
+	/// Move `amount` of delegation to `Agent` from one delegator to another.
+	///
+	/// The funds stay staked with the `Agent`, only the delegator backing them changes. The
+	/// destination must either be a new delegator or already be delegating to the same `Agent`.
+	fn transfer_delegation(
+		agent: Agent<Self::AccountId>,
+		from: Delegator<Self::AccountId>,
+		to: Delegator<Self::AccountId>,
+		amount: Self::Balance,
+	) -> DispatchResult;
+
 	/// Returns pending slashes posted to the `Agent` account. None if not an `Agent`.
 	///
 	/// Slashes to `Agent` account are not immediate and are applied lazily. Since `Agent`
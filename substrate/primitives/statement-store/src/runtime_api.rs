@@ -40,6 +40,40 @@ pub struct ValidStatement {
 	pub max_count: u32,
 	/// Max total data size for this account, as calculated by the runtime.
 	pub max_size: u32,
+	/// Limits for some of the statement topics, as calculated by the runtime. Topics without
+	/// limits are only constrained by the account and store limits.
+	pub topic_limits: Vec<TopicLimit>,
+}
+
+/// Information concerning a valid statement, as returned by version 1 of [`ValidateStatement`].
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ValidStatementV1 {
+	/// Max statement count for this account, as calculated by the runtime.
+	pub max_count: u32,
+	/// Max total data size for this account, as calculated by the runtime.
+	pub max_size: u32,
+}
+
+impl From<ValidStatementV1> for ValidStatement {
+	fn from(valid: ValidStatementV1) -> Self {
+		ValidStatement {
+			max_count: valid.max_count,
+			max_size: valid.max_size,
+			topic_limits: Vec::new(),
+		}
+	}
+}
+
+/// Limits on the statements sharing a topic. Once a limit is reached, lower-priority statements
+/// with this topic may be evicted, regardless of their account.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct TopicLimit {
+	/// The limited topic.
+	pub topic: Topic,
+	/// Max statement count for this topic.
+	pub max_count: u32,
+	/// Max total data size for this topic.
+	pub max_size: u32,
 }
 
 /// An reason for an invalid statement.
@@ -79,7 +113,15 @@ impl StatementSource {
 
 sp_api::decl_runtime_apis! {
 	/// Runtime API trait for statement validation.
+	#[api_version(2)]
 	pub trait ValidateStatement {
+		/// Validate the statement.
+		#[changed_in(2)]
+		fn validate_statement(
+			source: StatementSource,
+			statement: Statement,
+		) -> Result<ValidStatementV1, InvalidStatement>;
+
 		/// Validate the statement.
 		fn validate_statement(
 			source: StatementSource,
@@ -16,7 +16,7 @@
 // limitations under the License.
 
 pub use crate::runtime_api::StatementSource;
-use crate::{Hash, Statement, Topic};
+use crate::{DecryptionKey, Hash, Statement, Topic};
 
 /// Statement store error.
 #[derive(Debug, Eq, PartialEq, thiserror::Error)]
@@ -61,6 +61,62 @@ pub enum SubmitResult {
 /// Result type for `Error`
 pub type Result<T> = std::result::Result<T, Error>;
 
+/// Topic filter for statement subscriptions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TopicFilter {
+	/// Match statements regardless of their topics.
+	Any,
+	/// Match statements which include all of the given topics.
+	MatchAll(Vec<Topic>),
+	/// Match statements which include at least one of the given topics.
+	MatchAny(Vec<Topic>),
+}
+
+/// Statement subscription filter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubscriptionFilter {
+	/// Topics the statements must match.
+	pub topics: TopicFilter,
+	/// Decryption key the statements must be posted to. `None` matches only statements which
+	/// have no `DecryptionKey` field.
+	pub decryption_key: Option<DecryptionKey>,
+}
+
+impl SubscriptionFilter {
+	/// Check if a statement with the given topics and decryption key matches the filter.
+	pub fn matches(&self, topics: &[Topic], decryption_key: Option<DecryptionKey>) -> bool {
+		if self.decryption_key != decryption_key {
+			return false
+		}
+		match &self.topics {
+			TopicFilter::Any => true,
+			TopicFilter::MatchAll(all) => all.iter().all(|t| topics.contains(t)),
+			TopicFilter::MatchAny(any) => any.iter().any(|t| topics.contains(t)),
+		}
+	}
+
+	/// Check if the statement matches the filter.
+	pub fn matches_statement(&self, statement: &Statement) -> bool {
+		let topics: Vec<_> = (0..crate::MAX_TOPICS).filter_map(|i| statement.topic(i)).collect();
+		self.matches(&topics, statement.decryption_key())
+	}
+}
+
+/// Statement store event reported to subscribers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StatementEvent {
+	/// A new statement was accepted by the store.
+	New(Statement),
+	/// The statement was evicted to make room for a higher priority statement.
+	Evicted(Hash),
+	/// The statement was removed from the store and marked as expired.
+	Expired(Hash),
+}
+
+/// Stream of statement store events.
+pub type StatementEventStream =
+	std::pin::Pin<Box<dyn futures::Stream<Item = StatementEvent> + Send>>;
+
 /// Statement store API.
 pub trait StatementStore: Send + Sync {
 	/// Return all statements.
@@ -104,4 +160,14 @@ pub trait StatementStore: Send + Sync {
 
 	/// Remove a statement from the store.
 	fn remove(&self, hash: &Hash) -> Result<()>;
+
+	/// Subscribe to statements matching `filter`.
+	///
+	/// Returns the hashes of all currently known matching statements, which can be fetched with
+	/// [`StatementStore::statement`], together with a stream of events for statements matching the
+	/// filter that are accepted, evicted or expired afterwards.
+	fn subscribe_statements(
+		&self,
+		filter: SubscriptionFilter,
+	) -> Result<(Vec<Hash>, StatementEventStream)>;
 }
@@ -188,7 +188,7 @@ pub fn new_full<Network: sc_network::NetworkBackend<Block, <Block as BlockT>::Ha
 		task_manager: &mut task_manager,
 		transaction_pool: transaction_pool.clone(),
 		rpc_builder: rpc_extensions_builder,
-		backend,
+		backend: backend.clone(),
 		system_rpc_tx,
 		tx_handler_controller,
 		sync_service,
@@ -252,6 +252,7 @@ pub fn new_full<Network: sc_network::NetworkBackend<Block, <Block as BlockT>::Ha
 				create_inherent_data_providers: move |_, ()| async move {
 					Ok(sp_timestamp::InherentDataProvider::from_system_time())
 				},
+				backend: Some(backend),
 			};
 			let authorship_future = sc_consensus_manual_seal::run_manual_seal(params);
 
@@ -519,6 +519,10 @@ pub use pallet_message_queue;
 #[cfg(feature = "pallet-meta-tx")]
 pub use pallet_meta_tx;
 
+/// FRAME pallet for sponsor pools paying relayers of meta transactions.
+#[cfg(feature = "pallet-meta-tx-sponsor")]
+pub use pallet_meta_tx_sponsor;
+
 /// FRAME pallet to execute multi-block migrations.
 #[cfg(feature = "pallet-migrations")]
 pub use pallet_migrations;
@@ -587,6 +591,10 @@ pub use pallet_paged_list;
 #[cfg(feature = "pallet-parameters")]
 pub use pallet_parameters;
 
+/// FRAME pallet for open-ended payment streams funded by holds.
+#[cfg(feature = "pallet-payment-streams")]
+pub use pallet_payment_streams;
+
 /// Personhood-tracking pallet.
 #[cfg(feature = "pallet-people")]
 pub use pallet_people;